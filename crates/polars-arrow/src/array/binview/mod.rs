@@ -621,7 +621,7 @@ impl<T: ViewType + ?Sized> Array for BinaryViewArrayGeneric<T> {
     }
 
     fn dtype(&self) -> &ArrowDataType {
-        T::dtype()
+        &self.dtype
     }
 
     fn validity(&self) -> Option<&Bitmap> {
@@ -429,6 +429,126 @@ pub fn new_null_array(dtype: ArrowDataType, length: usize) -> Box<dyn Array> {
     }
 }
 
+macro_rules! with_dtype_offsets_values {
+    ($any:expr, $ty:ty, $dtype:expr, $validity:expr) => {{
+        let arr = $any.downcast_ref::<$ty>().unwrap();
+        // SAFETY: the offsets and values come from a valid array.
+        Box::new(unsafe {
+            <$ty>::new_unchecked(
+                $dtype,
+                arr.offsets().clone(),
+                arr.values().clone(),
+                $validity,
+            )
+        })
+    }};
+}
+
+/// Returns `array` with the [`ArrowDataType`] `dtype`, e.g. to tag an array with an extension type
+/// or to get the storage of an extension array. The buffers are shared.
+///
+/// # Panics
+/// Panics if `dtype` does not have the same layout as the dtype of `array`.
+pub fn with_dtype(array: &dyn Array, dtype: ArrowDataType) -> Box<dyn Array> {
+    use crate::datatypes::PhysicalType::*;
+    assert_eq!(
+        array.dtype().to_physical_type(),
+        dtype.to_physical_type(),
+        "cannot change the physical type of an array"
+    );
+    let any = array.as_any();
+    let validity = array.validity().cloned();
+    match dtype.to_physical_type() {
+        Null => Box::new(NullArray::new(dtype, array.len())),
+        Boolean => {
+            let arr = any.downcast_ref::<BooleanArray>().unwrap();
+            Box::new(BooleanArray::new(dtype, arr.values().clone(), validity))
+        },
+        Primitive(primitive) => with_match_primitive_type_full!(primitive, |$T| {
+            let arr = any.downcast_ref::<PrimitiveArray<$T>>().unwrap();
+            Box::new(arr.clone().to(dtype))
+        }),
+        Binary => with_dtype_offsets_values!(any, BinaryArray<i32>, dtype, validity),
+        LargeBinary => with_dtype_offsets_values!(any, BinaryArray<i64>, dtype, validity),
+        Utf8 => with_dtype_offsets_values!(any, Utf8Array<i32>, dtype, validity),
+        LargeUtf8 => with_dtype_offsets_values!(any, Utf8Array<i64>, dtype, validity),
+        FixedSizeBinary => {
+            let arr = any.downcast_ref::<FixedSizeBinaryArray>().unwrap();
+            Box::new(arr.clone().to(dtype))
+        },
+        List => {
+            let arr = any.downcast_ref::<ListArray<i32>>().unwrap();
+            let (offsets, values) = (arr.offsets().clone(), arr.values().clone());
+            Box::new(ListArray::new(dtype, offsets, values, validity))
+        },
+        LargeList => {
+            let arr = any.downcast_ref::<ListArray<i64>>().unwrap();
+            let (offsets, values) = (arr.offsets().clone(), arr.values().clone());
+            Box::new(ListArray::new(dtype, offsets, values, validity))
+        },
+        FixedSizeList => {
+            let arr = any.downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = arr.values().clone();
+            Box::new(FixedSizeListArray::new(dtype, arr.len(), values, validity))
+        },
+        Struct => {
+            let arr = any.downcast_ref::<StructArray>().unwrap();
+            let values = arr.values().to_vec();
+            Box::new(StructArray::new(dtype, arr.len(), values, validity))
+        },
+        Union => {
+            let arr = any.downcast_ref::<UnionArray>().unwrap();
+            let (types, fields) = (arr.types().clone(), arr.fields().clone());
+            let offsets = arr.offsets().cloned();
+            Box::new(UnionArray::new(dtype, types, fields, offsets))
+        },
+        Map => {
+            let arr = any.downcast_ref::<MapArray>().unwrap();
+            let (offsets, field) = (arr.offsets().clone(), arr.field().clone());
+            Box::new(MapArray::new(dtype, offsets, field, validity))
+        },
+        BinaryView => {
+            let arr = any.downcast_ref::<BinaryViewArray>().unwrap();
+            // SAFETY: the buffers come from a valid array.
+            Box::new(unsafe {
+                BinaryViewArray::new_unchecked(
+                    dtype,
+                    arr.views().clone(),
+                    arr.data_buffers().clone(),
+                    validity,
+                    arr.total_bytes_len(),
+                    arr.total_buffer_len(),
+                )
+            })
+        },
+        Utf8View => {
+            let arr = any.downcast_ref::<Utf8ViewArray>().unwrap();
+            // SAFETY: the buffers come from a valid array.
+            Box::new(unsafe {
+                Utf8ViewArray::new_unchecked(
+                    dtype,
+                    arr.views().clone(),
+                    arr.data_buffers().clone(),
+                    validity,
+                    arr.total_bytes_len(),
+                    arr.total_buffer_len(),
+                )
+            })
+        },
+        Dictionary(key_type) => match_integer_type!(key_type, |$T| {
+            let arr = any.downcast_ref::<DictionaryArray<$T>>().unwrap();
+            // SAFETY: the keys come from a valid array.
+            Box::new(unsafe {
+                DictionaryArray::<$T>::try_new_unchecked(
+                    dtype,
+                    arr.keys().clone(),
+                    arr.values().clone(),
+                )
+            }.unwrap())
+        }),
+    }
+}
+
 macro_rules! clone_dyn {
     ($array:expr, $ty:ty) => {{
         let f = |x: &$ty| Box::new(x.clone());
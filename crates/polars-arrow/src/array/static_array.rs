@@ -7,8 +7,8 @@ use crate::array::fixed_size_list::FixedSizeListArrayBuilder;
 use crate::array::static_array_collect::ArrayFromIterDtype;
 use crate::array::{
     Array, ArrayValuesIter, BinaryArray, BinaryValueIter, BinaryViewArray, BooleanArray,
-    FixedSizeListArray, ListArray, ListValuesIter, MutableBinaryViewArray, PrimitiveArray,
-    StructArray, Utf8Array, Utf8ValuesIter, Utf8ViewArray,
+    FixedSizeBinaryArray, FixedSizeListArray, ListArray, ListValuesIter, MutableBinaryViewArray,
+    PrimitiveArray, StructArray, Utf8Array, Utf8ValuesIter, Utf8ViewArray,
 };
 use crate::bitmap::Bitmap;
 use crate::bitmap::utils::{BitmapIter, ZipValidity};
@@ -341,6 +341,38 @@ impl ParameterFreeDtypeStaticArray for Utf8ViewArray {
     }
 }
 
+impl StaticArray for FixedSizeBinaryArray {
+    type ValueT<'a> = &'a [u8];
+    type ZeroableValueT<'a> = Option<&'a [u8]>;
+    type ValueIterT<'a> = std::slice::ChunksExact<'a, u8>;
+
+    #[inline]
+    unsafe fn value_unchecked(&self, idx: usize) -> Self::ValueT<'_> {
+        self.value_unchecked(idx)
+    }
+
+    fn values_iter(&self) -> Self::ValueIterT<'_> {
+        self.values_iter()
+    }
+
+    fn iter(&self) -> ZipValidity<Self::ValueT<'_>, Self::ValueIterT<'_>, BitmapIter> {
+        self.iter()
+    }
+
+    fn with_validity_typed(self, validity: Option<Bitmap>) -> Self {
+        self.with_validity(validity)
+    }
+
+    fn full_null(length: usize, dtype: ArrowDataType) -> Self {
+        Self::new_null(dtype, length)
+    }
+
+    fn full(length: usize, value: Self::ValueT<'_>, dtype: ArrowDataType) -> Self {
+        let values = value.repeat(length);
+        Self::new(dtype, values.into(), None)
+    }
+}
+
 impl StaticArray for ListArray<i64> {
     type ValueT<'a> = Box<dyn Array>;
     type ZeroableValueT<'a> = Option<Box<dyn Array>>;
@@ -4,9 +4,9 @@ use polars_utils::no_call_const;
 
 use crate::array::static_array::{ParameterFreeDtypeStaticArray, StaticArray};
 use crate::array::{
-    Array, BinaryArray, BinaryViewArray, BooleanArray, FixedSizeListArray, ListArray,
-    MutableBinaryArray, MutableBinaryValuesArray, MutableBinaryViewArray, PrimitiveArray,
-    StructArray, Utf8Array, Utf8ViewArray,
+    Array, BinaryArray, BinaryViewArray, BooleanArray, FixedSizeBinaryArray, FixedSizeListArray,
+    ListArray, MutableBinaryArray, MutableBinaryValuesArray, MutableBinaryViewArray,
+    PrimitiveArray, StructArray, Utf8Array, Utf8ViewArray,
 };
 use crate::bitmap::BitmapBuilder;
 use crate::datatypes::ArrowDataType;
@@ -335,6 +335,59 @@ impl<T: NativeType> ArrayFromIter<Option<T>> for PrimitiveArray<T> {
     }
 }
 
+impl<'a> ArrayFromIterDtype<&'a [u8]> for FixedSizeBinaryArray {
+    fn arr_from_iter_with_dtype<I: IntoIterator<Item = &'a [u8]>>(
+        dtype: ArrowDataType,
+        iter: I,
+    ) -> Self {
+        Self::arr_from_iter_with_dtype(dtype, iter.into_iter().map(Some))
+    }
+
+    fn try_arr_from_iter_with_dtype<E, I: IntoIterator<Item = Result<&'a [u8], E>>>(
+        dtype: ArrowDataType,
+        iter: I,
+    ) -> Result<Self, E> {
+        let iter_values = iter.into_iter().collect::<Result<Vec<_>, E>>()?;
+        Ok(Self::arr_from_iter_with_dtype(dtype, iter_values))
+    }
+}
+
+impl<'a> ArrayFromIterDtype<Option<&'a [u8]>> for FixedSizeBinaryArray {
+    fn arr_from_iter_with_dtype<I: IntoIterator<Item = Option<&'a [u8]>>>(
+        dtype: ArrowDataType,
+        iter: I,
+    ) -> Self {
+        let size = FixedSizeBinaryArray::get_size(&dtype);
+        let iter = iter.into_iter();
+        let n = iter.size_hint().0;
+        let mut values = Vec::with_capacity(n * size);
+        let mut validity = BitmapBuilder::with_capacity(n);
+        for val in iter {
+            match val {
+                Some(v) => {
+                    assert_eq!(
+                        v.len(),
+                        size,
+                        "value does not have the fixed size of the array"
+                    );
+                    values.extend_from_slice(v);
+                },
+                None => values.resize(values.len() + size, 0),
+            }
+            validity.push(val.is_some());
+        }
+        FixedSizeBinaryArray::new(dtype, values.into(), validity.into_opt_validity())
+    }
+
+    fn try_arr_from_iter_with_dtype<E, I: IntoIterator<Item = Result<Option<&'a [u8]>, E>>>(
+        dtype: ArrowDataType,
+        iter: I,
+    ) -> Result<Self, E> {
+        let iter_values = iter.into_iter().collect::<Result<Vec<_>, E>>()?;
+        Ok(Self::arr_from_iter_with_dtype(dtype, iter_values))
+    }
+}
+
 // We don't use AsRef here because it leads to problems with conflicting implementations,
 // as Rust considers that AsRef<[u8]> for Option<&[u8]> could be implemented.
 trait IntoBytes {
@@ -55,6 +55,8 @@ pub enum ArrowDataType {
     UInt32,
     /// An [`u64`]
     UInt64,
+    /// An [`u128`]
+    UInt128,
     /// An 16-bit float
     Float16,
     /// A [`f32`]
@@ -288,6 +290,7 @@ impl ArrowDataType {
             UInt16 => PhysicalType::Primitive(PrimitiveType::UInt16),
             UInt32 => PhysicalType::Primitive(PrimitiveType::UInt32),
             UInt64 => PhysicalType::Primitive(PrimitiveType::UInt64),
+            UInt128 => PhysicalType::Primitive(PrimitiveType::UInt128),
             Float16 => PhysicalType::Primitive(PrimitiveType::Float16),
             Float32 => PhysicalType::Primitive(PrimitiveType::Float32),
             Float64 => PhysicalType::Primitive(PrimitiveType::Float64),
@@ -412,6 +415,8 @@ impl ArrowDataType {
                 | D::UInt16
                 | D::UInt32
                 | D::UInt64
+                | D::UInt128
+                | D::Float16
                 | D::Float32
                 | D::Float64
                 | D::Decimal(_, _)
@@ -447,6 +452,7 @@ impl ArrowDataType {
             | D::UInt32
             | D::UInt64
             | D::Int128
+            | D::UInt128
             | D::Float16
             | D::Float32
             | D::Float64
@@ -515,7 +521,7 @@ impl From<PrimitiveType> for ArrowDataType {
             PrimitiveType::Float64 => ArrowDataType::Float64,
             PrimitiveType::DaysMs => ArrowDataType::Interval(IntervalUnit::DayTime),
             PrimitiveType::MonthDayNano => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
-            PrimitiveType::UInt128 => unimplemented!(),
+            PrimitiveType::UInt128 => ArrowDataType::UInt128,
         }
     }
 }
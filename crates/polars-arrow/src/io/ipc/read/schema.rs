@@ -279,7 +279,11 @@ fn get_dtype(
         Null(_) => (ArrowDataType::Null, IpcField::default()),
         Bool(_) => (ArrowDataType::Boolean, IpcField::default()),
         Int(int) => {
-            let dtype = deserialize_integer(int)?.into();
+            let dtype = match (int.bit_width()?, int.is_signed()?) {
+                // `IntegerType` only covers dictionary keys, which can't be unsigned 128-bit.
+                (128, false) => ArrowDataType::UInt128,
+                _ => deserialize_integer(int)?.into(),
+            };
             (dtype, IpcField::default())
         },
         Binary(_) => (ArrowDataType::Binary, IpcField::default()),
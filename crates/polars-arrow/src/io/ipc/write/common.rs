@@ -243,7 +243,7 @@ fn serialize_compression(
 }
 
 fn set_variadic_buffer_counts(counts: &mut Vec<i64>, array: &dyn Array) {
-    match array.dtype() {
+    match array.dtype().to_logical_type() {
         ArrowDataType::Utf8View => {
             let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
             counts.push(array.data_buffers().len() as i64);
@@ -270,10 +270,28 @@ fn set_variadic_buffer_counts(counts: &mut Vec<i64>, array: &dyn Array) {
                 .sliced(first.to_usize(), last.to_usize() - first.to_usize());
             set_variadic_buffer_counts(counts, &*subslice)
         },
+        ArrowDataType::Map(_, _) => {
+            // The map values are sliced to the offsets when written, same as `LargeList`.
+            let array = array.as_any().downcast_ref::<MapArray>().unwrap();
+            let offsets = array.offsets().buffer();
+            let first = *offsets.first().unwrap();
+            let last = *offsets.last().unwrap();
+            let subslice = array
+                .field()
+                .sliced(first.to_usize(), last.to_usize() - first.to_usize());
+            set_variadic_buffer_counts(counts, &*subslice)
+        },
         ArrowDataType::FixedSizeList(_, _) => {
             let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
             set_variadic_buffer_counts(counts, array.values().as_ref())
         },
+        ArrowDataType::Union(_) => {
+            // The fields of a union are written in full.
+            let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+            for array in array.fields() {
+                set_variadic_buffer_counts(counts, array.as_ref())
+            }
+        },
         // Don't traverse dictionary values as those are set when the `Dictionary` IPC struct
         // is read.
         ArrowDataType::Dictionary(_, _, _) => (),
@@ -307,7 +325,7 @@ pub fn encode_array(
     offset: &mut i64,
 ) {
     // We don't want to write all buffers in sliced arrays.
-    let array = match array.dtype() {
+    let array = match array.dtype().to_logical_type() {
         ArrowDataType::BinaryView => {
             let concrete_arr = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
             gc_bin_view(array, concrete_arr)
@@ -162,6 +162,16 @@ impl_bitwise_kernel! {
     (i128, identity, identity),
 }
 
+#[cfg(feature = "dtype-u128")]
+impl_bitwise_kernel! {
+    (u128, identity, identity),
+}
+
+#[cfg(feature = "dtype-f16")]
+impl_bitwise_kernel! {
+    (arrow::types::f16, arrow::types::f16::to_bits, arrow::types::f16::from_bits),
+}
+
 impl BitwiseKernel for BooleanArray {
     type Scalar = bool;
 
@@ -36,6 +36,8 @@ impl_parse!(u64);
 
 #[cfg(feature = "dtype-i128")]
 impl_parse!(i128);
+#[cfg(feature = "dtype-u128")]
+impl_parse!(u128);
 
 impl Parse for f32 {
     fn parse(val: &[u8]) -> Option<Self>
@@ -45,6 +47,15 @@ impl Parse for f32 {
         fast_float2::parse(val).ok()
     }
 }
+#[cfg(feature = "dtype-f16")]
+impl Parse for arrow::types::f16 {
+    fn parse(val: &[u8]) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        fast_float2::parse::<f32, _>(val).ok().map(Self::from_f32)
+    }
+}
 impl Parse for f64 {
     fn parse(val: &[u8]) -> Option<Self>
     where
@@ -21,6 +21,8 @@ use arrow::array::*;
 use arrow::datatypes::*;
 use arrow::match_integer_type;
 use arrow::offset::{Offset, Offsets};
+#[cfg(feature = "dtype-f16")]
+use arrow::types::f16;
 use binview_to::{
     binview_to_dictionary, utf8view_to_date32_dyn, utf8view_to_dictionary,
     utf8view_to_naive_timestamp_dyn, view_to_binary,
@@ -534,6 +536,10 @@ pub fn cast(
                 Int64 => binview_to_primitive_dyn::<i64>(&arr.to_binview(), to_type, options),
                 #[cfg(feature = "dtype-i128")]
                 Int128 => binview_to_primitive_dyn::<i128>(&arr.to_binview(), to_type, options),
+                #[cfg(feature = "dtype-u128")]
+                UInt128 => binview_to_primitive_dyn::<u128>(&arr.to_binview(), to_type, options),
+                #[cfg(feature = "dtype-f16")]
+                Float16 => binview_to_primitive_dyn::<f16>(&arr.to_binview(), to_type, options),
                 Float32 => binview_to_primitive_dyn::<f32>(&arr.to_binview(), to_type, options),
                 Float64 => binview_to_primitive_dyn::<f64>(&arr.to_binview(), to_type, options),
                 Timestamp(time_unit, None) => {
@@ -568,6 +574,10 @@ pub fn cast(
             Int64 => primitive_to_boolean_dyn::<i64>(array, to_type.clone()),
             #[cfg(feature = "dtype-i128")]
             Int128 => primitive_to_boolean_dyn::<i128>(array, to_type.clone()),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => primitive_to_boolean_dyn::<u128>(array, to_type.clone()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => primitive_to_boolean_dyn::<f16>(array, to_type.clone()),
             Float32 => primitive_to_boolean_dyn::<f32>(array, to_type.clone()),
             Float64 => primitive_to_boolean_dyn::<f64>(array, to_type.clone()),
             Decimal(_, _) => primitive_to_boolean_dyn::<i128>(array, to_type.clone()),
@@ -586,6 +596,10 @@ pub fn cast(
             Int64 => boolean_to_primitive_dyn::<i64>(array),
             #[cfg(feature = "dtype-i128")]
             Int128 => boolean_to_primitive_dyn::<i128>(array),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => boolean_to_primitive_dyn::<u128>(array),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => boolean_to_primitive_dyn::<f16>(array),
             Float32 => boolean_to_primitive_dyn::<f32>(array),
             Float64 => boolean_to_primitive_dyn::<f64>(array),
             Utf8View => boolean_to_utf8view_dyn(array),
@@ -658,6 +672,10 @@ pub fn cast(
             Int64 => binary_to_primitive_dyn::<i64, i64>(array, to_type, options),
             #[cfg(feature = "dtype-i128")]
             Int128 => binary_to_primitive_dyn::<i64, i128>(array, to_type, options),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => binary_to_primitive_dyn::<i64, u128>(array, to_type, options),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => binary_to_primitive_dyn::<i64, f16>(array, to_type, options),
             Float32 => binary_to_primitive_dyn::<i64, f32>(array, to_type, options),
             Float64 => binary_to_primitive_dyn::<i64, f64>(array, to_type, options),
             Binary => {
@@ -697,6 +715,10 @@ pub fn cast(
         (UInt8, Int64) => primitive_to_primitive_dyn::<u8, i64>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (UInt8, Int128) => primitive_to_primitive_dyn::<u8, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt8, UInt128) => primitive_to_primitive_dyn::<u8, u128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-f16")]
+        (UInt8, Float16) => primitive_to_primitive_dyn::<u8, f16>(array, to_type, options),
         (UInt8, Float32) => primitive_to_primitive_dyn::<u8, f32>(array, to_type, as_options),
         (UInt8, Float64) => primitive_to_primitive_dyn::<u8, f64>(array, to_type, as_options),
         (UInt8, Decimal(p, s)) => integer_to_decimal_dyn::<u8>(array, *p, *s),
@@ -710,6 +732,10 @@ pub fn cast(
         (UInt16, Int64) => primitive_to_primitive_dyn::<u16, i64>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (UInt16, Int128) => primitive_to_primitive_dyn::<u16, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt16, UInt128) => primitive_to_primitive_dyn::<u16, u128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-f16")]
+        (UInt16, Float16) => primitive_to_primitive_dyn::<u16, f16>(array, to_type, options),
         (UInt16, Float32) => primitive_to_primitive_dyn::<u16, f32>(array, to_type, as_options),
         (UInt16, Float64) => primitive_to_primitive_dyn::<u16, f64>(array, to_type, as_options),
         (UInt16, Decimal(p, s)) => integer_to_decimal_dyn::<u16>(array, *p, *s),
@@ -723,6 +749,10 @@ pub fn cast(
         (UInt32, Int64) => primitive_to_primitive_dyn::<u32, i64>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (UInt32, Int128) => primitive_to_primitive_dyn::<u32, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt32, UInt128) => primitive_to_primitive_dyn::<u32, u128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-f16")]
+        (UInt32, Float16) => primitive_to_primitive_dyn::<u32, f16>(array, to_type, options),
         (UInt32, Float32) => primitive_to_primitive_dyn::<u32, f32>(array, to_type, as_options),
         (UInt32, Float64) => primitive_to_primitive_dyn::<u32, f64>(array, to_type, as_options),
         (UInt32, Decimal(p, s)) => integer_to_decimal_dyn::<u32>(array, *p, *s),
@@ -736,6 +766,10 @@ pub fn cast(
         (UInt64, Int64) => primitive_to_primitive_dyn::<u64, i64>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (UInt64, Int128) => primitive_to_primitive_dyn::<u64, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt64, UInt128) => primitive_to_primitive_dyn::<u64, u128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-f16")]
+        (UInt64, Float16) => primitive_to_primitive_dyn::<u64, f16>(array, to_type, options),
         (UInt64, Float32) => primitive_to_primitive_dyn::<u64, f32>(array, to_type, as_options),
         (UInt64, Float64) => primitive_to_primitive_dyn::<u64, f64>(array, to_type, as_options),
         (UInt64, Decimal(p, s)) => integer_to_decimal_dyn::<u64>(array, *p, *s),
@@ -749,6 +783,10 @@ pub fn cast(
         (Int8, Int64) => primitive_to_primitive_dyn::<i8, i64>(array, to_type, as_options),
         #[cfg(feature = "dtype-i128")]
         (Int8, Int128) => primitive_to_primitive_dyn::<i8, i128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-u128")]
+        (Int8, UInt128) => primitive_to_primitive_dyn::<i8, u128>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Int8, Float16) => primitive_to_primitive_dyn::<i8, f16>(array, to_type, options),
         (Int8, Float32) => primitive_to_primitive_dyn::<i8, f32>(array, to_type, as_options),
         (Int8, Float64) => primitive_to_primitive_dyn::<i8, f64>(array, to_type, as_options),
         (Int8, Decimal(p, s)) => integer_to_decimal_dyn::<i8>(array, *p, *s),
@@ -762,6 +800,10 @@ pub fn cast(
         (Int16, Int64) => primitive_to_primitive_dyn::<i16, i64>(array, to_type, as_options),
         #[cfg(feature = "dtype-i128")]
         (Int16, Int128) => primitive_to_primitive_dyn::<i16, i128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-u128")]
+        (Int16, UInt128) => primitive_to_primitive_dyn::<i16, u128>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Int16, Float16) => primitive_to_primitive_dyn::<i16, f16>(array, to_type, options),
         (Int16, Float32) => primitive_to_primitive_dyn::<i16, f32>(array, to_type, as_options),
         (Int16, Float64) => primitive_to_primitive_dyn::<i16, f64>(array, to_type, as_options),
         (Int16, Decimal(p, s)) => integer_to_decimal_dyn::<i16>(array, *p, *s),
@@ -775,6 +817,10 @@ pub fn cast(
         (Int32, Int64) => primitive_to_primitive_dyn::<i32, i64>(array, to_type, as_options),
         #[cfg(feature = "dtype-i128")]
         (Int32, Int128) => primitive_to_primitive_dyn::<i32, i128>(array, to_type, as_options),
+        #[cfg(feature = "dtype-u128")]
+        (Int32, UInt128) => primitive_to_primitive_dyn::<i32, u128>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Int32, Float16) => primitive_to_primitive_dyn::<i32, f16>(array, to_type, options),
         (Int32, Float32) => primitive_to_primitive_dyn::<i32, f32>(array, to_type, as_options),
         (Int32, Float64) => primitive_to_primitive_dyn::<i32, f64>(array, to_type, as_options),
         (Int32, Decimal(p, s)) => integer_to_decimal_dyn::<i32>(array, *p, *s),
@@ -788,6 +834,10 @@ pub fn cast(
         (Int64, Int32) => primitive_to_primitive_dyn::<i64, i32>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (Int64, Int128) => primitive_to_primitive_dyn::<i64, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (Int64, UInt128) => primitive_to_primitive_dyn::<i64, u128>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Int64, Float16) => primitive_to_primitive_dyn::<i64, f16>(array, to_type, options),
         (Int64, Float32) => primitive_to_primitive_dyn::<i64, f32>(array, to_type, options),
         (Int64, Float64) => primitive_to_primitive_dyn::<i64, f64>(array, to_type, as_options),
         (Int64, Decimal(p, s)) => integer_to_decimal_dyn::<i64>(array, *p, *s),
@@ -808,6 +858,10 @@ pub fn cast(
         (Int128, Int32) => primitive_to_primitive_dyn::<i128, i32>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (Int128, Int64) => primitive_to_primitive_dyn::<i128, i64>(array, to_type, options),
+        #[cfg(all(feature = "dtype-i128", feature = "dtype-u128"))]
+        (Int128, UInt128) => primitive_to_primitive_dyn::<i128, u128>(array, to_type, options),
+        #[cfg(all(feature = "dtype-i128", feature = "dtype-f16"))]
+        (Int128, Float16) => primitive_to_primitive_dyn::<i128, f16>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
         (Int128, Float32) => primitive_to_primitive_dyn::<i128, f32>(array, to_type, options),
         #[cfg(feature = "dtype-i128")]
@@ -815,10 +869,61 @@ pub fn cast(
         #[cfg(feature = "dtype-i128")]
         (Int128, Decimal(p, s)) => integer_to_decimal_dyn::<i128>(array, *p, *s),
 
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, UInt8) => primitive_to_primitive_dyn::<u128, u8>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, UInt16) => primitive_to_primitive_dyn::<u128, u16>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, UInt32) => primitive_to_primitive_dyn::<u128, u32>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, UInt64) => primitive_to_primitive_dyn::<u128, u64>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Int8) => primitive_to_primitive_dyn::<u128, i8>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Int16) => primitive_to_primitive_dyn::<u128, i16>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Int32) => primitive_to_primitive_dyn::<u128, i32>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Int64) => primitive_to_primitive_dyn::<u128, i64>(array, to_type, options),
+        #[cfg(all(feature = "dtype-u128", feature = "dtype-i128"))]
+        (UInt128, Int128) => primitive_to_primitive_dyn::<u128, i128>(array, to_type, options),
+        #[cfg(all(feature = "dtype-u128", feature = "dtype-f16"))]
+        (UInt128, Float16) => primitive_to_primitive_dyn::<u128, f16>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Float32) => primitive_to_primitive_dyn::<u128, f32>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Float64) => primitive_to_primitive_dyn::<u128, f64>(array, to_type, as_options),
+        #[cfg(feature = "dtype-u128")]
+        (UInt128, Decimal(p, s)) => integer_to_decimal_dyn::<u128>(array, *p, *s),
+
+        #[cfg(feature = "dtype-f16")]
+        (Float16, UInt8) => primitive_to_primitive_dyn::<f16, u8>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Float16, UInt16) => primitive_to_primitive_dyn::<f16, u16>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Float16, UInt32) => primitive_to_primitive_dyn::<f16, u32>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Float16, UInt64) => primitive_to_primitive_dyn::<f16, u64>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Float16, Int8) => primitive_to_primitive_dyn::<f16, i8>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Float16, Int16) => primitive_to_primitive_dyn::<f16, i16>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Float16, Int32) => primitive_to_primitive_dyn::<f16, i32>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Float16, Int64) => primitive_to_primitive_dyn::<f16, i64>(array, to_type, options),
+        #[cfg(all(feature = "dtype-f16", feature = "dtype-i128"))]
+        (Float16, Int128) => primitive_to_primitive_dyn::<f16, i128>(array, to_type, options),
+        #[cfg(all(feature = "dtype-f16", feature = "dtype-u128"))]
+        (Float16, UInt128) => primitive_to_primitive_dyn::<f16, u128>(array, to_type, options),
         (Float16, Float32) => {
             let from = array.as_any().downcast_ref().unwrap();
             Ok(f16_to_f32(from).boxed())
         },
+        #[cfg(feature = "dtype-f16")]
+        (Float16, Float64) => primitive_to_primitive_dyn::<f16, f64>(array, to_type, as_options),
+        #[cfg(feature = "dtype-f16")]
+        (Float16, Decimal(p, s)) => float_to_decimal_dyn::<f16>(array, *p, *s),
 
         (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type, options),
         (Float32, UInt16) => primitive_to_primitive_dyn::<f32, u16>(array, to_type, options),
@@ -829,6 +934,10 @@ pub fn cast(
         (Float32, Int32) => primitive_to_primitive_dyn::<f32, i32>(array, to_type, options),
         (Float32, Int64) => primitive_to_primitive_dyn::<f32, i64>(array, to_type, options),
         (Float32, Int128) => primitive_to_primitive_dyn::<f32, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (Float32, UInt128) => primitive_to_primitive_dyn::<f32, u128>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Float32, Float16) => primitive_to_primitive_dyn::<f32, f16>(array, to_type, options),
         (Float32, Float64) => primitive_to_primitive_dyn::<f32, f64>(array, to_type, as_options),
         (Float32, Decimal(p, s)) => float_to_decimal_dyn::<f32>(array, *p, *s),
 
@@ -841,6 +950,10 @@ pub fn cast(
         (Float64, Int32) => primitive_to_primitive_dyn::<f64, i32>(array, to_type, options),
         (Float64, Int64) => primitive_to_primitive_dyn::<f64, i64>(array, to_type, options),
         (Float64, Int128) => primitive_to_primitive_dyn::<f64, i128>(array, to_type, options),
+        #[cfg(feature = "dtype-u128")]
+        (Float64, UInt128) => primitive_to_primitive_dyn::<f64, u128>(array, to_type, options),
+        #[cfg(feature = "dtype-f16")]
+        (Float64, Float16) => primitive_to_primitive_dyn::<f64, f16>(array, to_type, options),
         (Float64, Float32) => primitive_to_primitive_dyn::<f64, f32>(array, to_type, options),
         (Float64, Decimal(p, s)) => float_to_decimal_dyn::<f64>(array, *p, *s),
 
@@ -853,6 +966,10 @@ pub fn cast(
         (Decimal(_, _), Int32) => decimal_to_integer_dyn::<i32>(array),
         (Decimal(_, _), Int64) => decimal_to_integer_dyn::<i64>(array),
         (Decimal(_, _), Int128) => decimal_to_integer_dyn::<i128>(array),
+        #[cfg(feature = "dtype-u128")]
+        (Decimal(_, _), UInt128) => decimal_to_integer_dyn::<u128>(array),
+        #[cfg(feature = "dtype-f16")]
+        (Decimal(_, _), Float16) => decimal_to_float_dyn::<f16>(array),
         (Decimal(_, _), Float32) => decimal_to_float_dyn::<f32>(array),
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
         (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
@@ -973,6 +1090,10 @@ fn from_to_binview(
         Int32 => primitive_to_binview_dyn::<i32>(array),
         Int64 => primitive_to_binview_dyn::<i64>(array),
         Int128 => primitive_to_binview_dyn::<i128>(array),
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => primitive_to_binview_dyn::<u128>(array),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => primitive_to_binview_dyn::<f16>(array),
         Float32 => primitive_to_binview_dyn::<f32>(array),
         Float64 => primitive_to_binview_dyn::<f64>(array),
         Binary => binary_to_binview::<i32>(array.as_any().downcast_ref().unwrap()),
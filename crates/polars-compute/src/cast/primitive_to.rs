@@ -45,6 +45,19 @@ impl_ser_primitive!(u8);
 impl_ser_primitive!(u16);
 impl_ser_primitive!(u32);
 impl_ser_primitive!(u64);
+impl_ser_primitive!(u128);
+
+impl SerPrimitive for f16 {
+    fn write(f: &mut Vec<u8>, val: Self) -> usize
+    where
+        Self: Sized,
+    {
+        let mut buffer = ryu::Buffer::new();
+        let value = buffer.format(val.to_f32());
+        f.extend_from_slice(value.as_bytes());
+        value.len()
+    }
+}
 
 impl SerPrimitive for f32 {
     fn write(f: &mut Vec<u8>, val: Self) -> usize
@@ -45,6 +45,8 @@ impl<T: NativeType> NotSimdPrimitive for T {}
 impl NotSimdPrimitive for u128 {}
 #[cfg(feature = "simd")]
 impl NotSimdPrimitive for i128 {}
+#[cfg(feature = "simd")]
+impl NotSimdPrimitive for arrow::types::f16 {}
 
 // Trait to allow blanket impl for all SIMD types when simd is enabled.
 #[cfg(feature = "simd")]
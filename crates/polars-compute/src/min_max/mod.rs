@@ -37,6 +37,8 @@ impl<T> NotSimdPrimitive for T {}
 impl NotSimdPrimitive for u128 {}
 #[cfg(feature = "simd")]
 impl NotSimdPrimitive for i128 {}
+#[cfg(feature = "simd")]
+impl NotSimdPrimitive for arrow::types::f16 {}
 
 mod dyn_array;
 mod scalar;
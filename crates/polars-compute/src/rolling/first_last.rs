@@ -0,0 +1,99 @@
+use std::collections::VecDeque;
+
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+
+use super::RollingFnParams;
+use super::no_nulls::RollingAggWindowNoNulls;
+use super::nulls::RollingAggWindowNulls;
+
+/// Returns the first (or, if `LAST`, the last) non-null value in the window.
+pub struct FirstLastWindow<'a, T, const LAST: bool> {
+    values: &'a [T],
+    validity: Option<&'a Bitmap>,
+    // Indices of the non-null values in the window, only maintained if there is a validity.
+    nonnull_idxs: VecDeque<usize>,
+    last_end: usize,
+}
+
+pub type FirstWindow<'a, T> = FirstLastWindow<'a, T, false>;
+pub type LastWindow<'a, T> = FirstLastWindow<'a, T, true>;
+
+impl<'a, T: NativeType, const LAST: bool> RollingAggWindowNulls<'a, T>
+    for FirstLastWindow<'a, T, LAST>
+{
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        assert!(params.is_none());
+        let mut slf = Self {
+            values: slice,
+            validity: Some(validity),
+            nonnull_idxs: VecDeque::new(),
+            last_end: 0,
+        };
+        unsafe {
+            RollingAggWindowNulls::update(&mut slf, start, end);
+        }
+        slf
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        unsafe {
+            let v = self.validity.unwrap_unchecked();
+            while self.nonnull_idxs.front().is_some_and(|idx| *idx < start) {
+                self.nonnull_idxs.pop_front();
+            }
+            for idx in start.max(self.last_end)..end {
+                if v.get_bit_unchecked(idx) {
+                    self.nonnull_idxs.push_back(idx);
+                }
+            }
+            self.last_end = end;
+
+            let idx = if LAST {
+                self.nonnull_idxs.back()
+            } else {
+                self.nonnull_idxs.front()
+            };
+            idx.map(|idx| *self.values.get_unchecked(*idx))
+        }
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.nonnull_idxs.len() >= min_periods
+    }
+}
+
+impl<'a, T: NativeType, const LAST: bool> RollingAggWindowNoNulls<'a, T>
+    for FirstLastWindow<'a, T, LAST>
+{
+    fn new(
+        slice: &'a [T],
+        _start: usize,
+        _end: usize,
+        params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        assert!(params.is_none());
+        Self {
+            values: slice,
+            validity: None,
+            nonnull_idxs: VecDeque::new(),
+            last_end: 0,
+        }
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        if start == end {
+            return None;
+        }
+        let idx = if LAST { end - 1 } else { start };
+        Some(unsafe { *self.values.get_unchecked(idx) })
+    }
+}
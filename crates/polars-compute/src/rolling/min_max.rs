@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 
 use arrow::bitmap::Bitmap;
 use arrow::types::NativeType;
+use polars_utils::IdxSize;
 use polars_utils::min_max::MinMaxPolicy;
 
 use super::RollingFnParams;
@@ -52,6 +53,46 @@ impl<T: NativeType, P: MinMaxPolicy> MinMaxWindow<'_, T, P> {
             self.monotonic_idxs.pop_front();
         }
     }
+
+    /// Slides the window and returns the index of its best value.
+    ///
+    /// # Safety
+    /// `start` and `end` must be in bounds of `values` and `validity`.
+    unsafe fn update_nulls(&mut self, start: usize, end: usize) -> Option<usize> {
+        unsafe {
+            let v = self.validity.unwrap_unchecked();
+            self.remove_old_values(start);
+            for i in self.last_start..start.min(self.last_end) {
+                self.nonnulls_in_window -= v.get_bit_unchecked(i) as usize;
+            }
+            for i in start.max(self.last_end)..end {
+                if v.get_bit_unchecked(i) {
+                    self.insert_nonnull_value(i);
+                }
+            }
+
+            self.last_start = start;
+            self.last_end = end;
+            self.monotonic_idxs.front().copied()
+        }
+    }
+
+    /// Slides the window and returns the index of its best value.
+    ///
+    /// # Safety
+    /// `start` and `end` must be in bounds of `values`.
+    unsafe fn update_no_nulls(&mut self, start: usize, end: usize) -> Option<usize> {
+        unsafe {
+            self.remove_old_values(start);
+            for i in start.max(self.last_end)..end {
+                self.insert_nonnull_value(i);
+            }
+
+            self.last_start = start;
+            self.last_end = end;
+            self.monotonic_idxs.front().copied()
+        }
+    }
 }
 
 impl<'a, T: NativeType, P: MinMaxPolicy> RollingAggWindowNulls<'a, T> for MinMaxWindow<'a, T, P> {
@@ -81,22 +122,8 @@ impl<'a, T: NativeType, P: MinMaxPolicy> RollingAggWindowNulls<'a, T> for MinMax
 
     unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
         unsafe {
-            let v = self.validity.unwrap_unchecked();
-            self.remove_old_values(start);
-            for i in self.last_start..start.min(self.last_end) {
-                self.nonnulls_in_window -= v.get_bit_unchecked(i) as usize;
-            }
-            for i in start.max(self.last_end)..end {
-                if v.get_bit_unchecked(i) {
-                    self.insert_nonnull_value(i);
-                }
-            }
-
-            self.last_start = start;
-            self.last_end = end;
-            self.monotonic_idxs
-                .front()
-                .map(|idx| *self.values.get_unchecked(*idx))
+            self.update_nulls(start, end)
+                .map(|idx| *self.values.get_unchecked(idx))
         }
     }
 
@@ -131,16 +158,75 @@ impl<'a, T: NativeType, P: MinMaxPolicy> RollingAggWindowNoNulls<'a, T> for MinM
 
     unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
         unsafe {
-            self.remove_old_values(start);
-            for i in start.max(self.last_end)..end {
-                self.insert_nonnull_value(i);
+            self.update_no_nulls(start, end)
+                .map(|idx| *self.values.get_unchecked(idx))
+        }
+    }
+}
+
+/// Returns the position of the best value within the window, rather than the value itself.
+///
+/// On ties the earliest position wins.
+pub struct ArgMinMaxWindow<'a, T, P> {
+    inner: MinMaxWindow<'a, T, P>,
+}
+
+impl<'a, T: NativeType, P: MinMaxPolicy> RollingAggWindowNulls<'a, T, IdxSize>
+    for ArgMinMaxWindow<'a, T, P>
+{
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+        window_size: Option<usize>,
+    ) -> Self {
+        unsafe {
+            Self {
+                inner: <MinMaxWindow<'a, T, P> as RollingAggWindowNulls<'a, T>>::new(
+                    slice,
+                    validity,
+                    start,
+                    end,
+                    params,
+                    window_size,
+                ),
             }
+        }
+    }
 
-            self.last_start = start;
-            self.last_end = end;
-            self.monotonic_idxs
-                .front()
-                .map(|idx| *self.values.get_unchecked(*idx))
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<IdxSize> {
+        unsafe { self.inner.update_nulls(start, end) }.map(|idx| (idx - start) as IdxSize)
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.inner.nonnulls_in_window >= min_periods
+    }
+}
+
+impl<'a, T: NativeType, P: MinMaxPolicy> RollingAggWindowNoNulls<'a, T, IdxSize>
+    for ArgMinMaxWindow<'a, T, P>
+{
+    fn new(
+        slice: &'a [T],
+        start: usize,
+        end: usize,
+        params: Option<RollingFnParams>,
+        window_size: Option<usize>,
+    ) -> Self {
+        Self {
+            inner: <MinMaxWindow<'a, T, P> as RollingAggWindowNoNulls<'a, T>>::new(
+                slice,
+                start,
+                end,
+                params,
+                window_size,
+            ),
         }
     }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<IdxSize> {
+        unsafe { self.inner.update_no_nulls(start, end) }.map(|idx| (idx - start) as IdxSize)
+    }
 }
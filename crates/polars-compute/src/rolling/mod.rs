@@ -1,8 +1,12 @@
+mod first_last;
 mod min_max;
 pub mod moment;
 pub mod no_nulls;
 pub mod nulls;
+mod product;
 pub mod quantile_filter;
+pub mod rank;
+mod unique;
 pub(super) mod window;
 use std::hash::Hash;
 use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
@@ -37,6 +41,18 @@ pub enum QuantileMethod {
     Equiprobable,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum RollingRankMethod {
+    #[default]
+    Average,
+    Min,
+    Max,
+    Dense,
+}
+
 #[deprecated(note = "use QuantileMethod instead")]
 pub type QuantileInterpolOptions = QuantileMethod;
 
@@ -48,6 +64,7 @@ pub enum RollingFnParams {
     Var(RollingVarParams),
     Skew { bias: bool },
     Kurtosis { fisher: bool, bias: bool },
+    Rank { method: RollingRankMethod },
 }
 
 fn det_offsets(i: Idx, window_size: WindowSize, _len: Len) -> (usize, usize) {
@@ -0,0 +1,36 @@
+use polars_error::polars_ensure;
+
+pub use super::super::first_last::{FirstWindow, LastWindow};
+use super::*;
+
+macro_rules! rolling_first_last_func {
+    ($rolling_m:ident, $window:ident) => {
+        pub fn $rolling_m<T>(
+            values: &[T],
+            window_size: usize,
+            min_periods: usize,
+            center: bool,
+            weights: Option<&[f64]>,
+            _params: Option<RollingFnParams>,
+        ) -> PolarsResult<ArrayRef>
+        where
+            T: NativeType + Debug + Num,
+        {
+            polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported for `{}`", stringify!($rolling_m));
+            let offset_fn = match center {
+                true => det_offsets_center,
+                false => det_offsets,
+            };
+            rolling_apply_agg_window::<$window<T>, _, _, _>(
+                values,
+                window_size,
+                min_periods,
+                offset_fn,
+                None,
+            )
+        }
+    };
+}
+
+rolling_first_last_func!(rolling_first, FirstWindow);
+rolling_first_last_func!(rolling_last, LastWindow);
@@ -1,8 +1,12 @@
+mod first_last;
 mod mean;
 mod min_max;
 mod moment;
+mod product;
 mod quantile;
+mod rank;
 mod sum;
+mod unique;
 use std::fmt::Debug;
 
 use arrow::array::PrimitiveArray;
@@ -10,16 +14,20 @@ use arrow::datatypes::ArrowDataType;
 use arrow::legacy::error::PolarsResult;
 use arrow::legacy::utils::CustomIterTools;
 use arrow::types::NativeType;
+pub use first_last::*;
 pub use mean::*;
 pub use min_max::*;
 pub use moment::*;
 use num_traits::{Float, Num, NumCast};
+pub use product::*;
 pub use quantile::*;
+pub use rank::*;
 pub use sum::*;
+pub use unique::*;
 
 use super::*;
 
-pub trait RollingAggWindowNoNulls<'a, T: NativeType> {
+pub trait RollingAggWindowNoNulls<'a, T: NativeType, Out: NativeType = T> {
     fn new(
         slice: &'a [T],
         start: usize,
@@ -32,11 +40,11 @@ pub trait RollingAggWindowNoNulls<'a, T: NativeType> {
     ///
     /// # Safety
     /// `start` and `end` must be within the windows bounds
-    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T>;
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<Out>;
 }
 
 // Use an aggregation window that maintains the state
-pub(super) fn rolling_apply_agg_window<'a, Agg, T, Fo>(
+pub(super) fn rolling_apply_agg_window<'a, Agg, T, Out, Fo>(
     values: &'a [T],
     window_size: usize,
     min_periods: usize,
@@ -45,16 +53,17 @@ pub(super) fn rolling_apply_agg_window<'a, Agg, T, Fo>(
 ) -> PolarsResult<ArrayRef>
 where
     Fo: Fn(Idx, WindowSize, Len) -> (Start, End),
-    Agg: RollingAggWindowNoNulls<'a, T>,
+    Agg: RollingAggWindowNoNulls<'a, T, Out>,
     T: Debug + NativeType + Num,
+    Out: NativeType,
 {
     let len = values.len();
     let (start, end) = det_offsets_fn(0, window_size, len);
     let mut agg_window = Agg::new(values, start, end, params, Some(window_size));
     if let Some(validity) = create_validity(min_periods, len, window_size, &det_offsets_fn) {
         if validity.iter().all(|x| !x) {
-            return Ok(Box::new(PrimitiveArray::<T>::new_null(
-                T::PRIMITIVE.into(),
+            return Ok(Box::new(PrimitiveArray::<Out>::new_null(
+                Out::PRIMITIVE.into(),
                 len,
             )));
         }
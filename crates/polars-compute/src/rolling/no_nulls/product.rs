@@ -0,0 +1,52 @@
+use polars_error::polars_ensure;
+
+pub use super::super::product::ProductWindow;
+use super::*;
+
+pub fn rolling_product<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    _params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Debug + Num,
+{
+    polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported for `rolling_product`");
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<ProductWindow<T>, _, _, _>(
+        values,
+        window_size,
+        min_periods,
+        offset_fn,
+        None,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_product() {
+        let values = &[1i64, 2, 0, 3, 4, 5];
+
+        let out = rolling_product(values, 3, 2, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, Some(2), Some(0), Some(0), Some(0), Some(60)]);
+
+        let out = rolling_product(values, 3, 1, true, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(2), Some(0), Some(0), Some(0), Some(60), Some(20)]
+        );
+    }
+}
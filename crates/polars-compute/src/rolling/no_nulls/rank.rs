@@ -0,0 +1,35 @@
+use polars_error::polars_ensure;
+use polars_utils::total_ord::TotalOrd;
+
+use super::*;
+
+pub fn rolling_rank<T>(
+    values: &[T],
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalOrd,
+{
+    polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported for `rolling_rank`");
+    let method = match params {
+        Some(RollingFnParams::Rank { method }) => method,
+        _ => RollingRankMethod::default(),
+    };
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    let len = values.len();
+    let windows = (0..len).map(|idx| offset_fn(idx, window_size, len));
+    Ok(super::super::rank::rolling_rank(
+        values,
+        None,
+        windows,
+        min_periods,
+        method,
+    ))
+}
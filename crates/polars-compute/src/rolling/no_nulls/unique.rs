@@ -0,0 +1,76 @@
+use polars_error::polars_ensure;
+use polars_utils::total_ord::{TotalHash, TotalOrd};
+
+pub use super::super::unique::{ModeWindow, NUniqueWindow};
+use super::*;
+
+macro_rules! rolling_frequency_func {
+    ($rolling_m:ident, $window:ident) => {
+        pub fn $rolling_m<T>(
+            values: &[T],
+            window_size: usize,
+            min_periods: usize,
+            center: bool,
+            weights: Option<&[f64]>,
+            _params: Option<RollingFnParams>,
+        ) -> PolarsResult<ArrayRef>
+        where
+            T: NativeType + Debug + Num + TotalOrd + TotalHash,
+        {
+            polars_ensure!(weights.is_none(), InvalidOperation: "weights are not supported for `{}`", stringify!($rolling_m));
+            let offset_fn = match center {
+                true => det_offsets_center,
+                false => det_offsets,
+            };
+            rolling_apply_agg_window::<$window<T>, _, _, _>(
+                values,
+                window_size,
+                min_periods,
+                offset_fn,
+                None,
+            )
+        }
+    };
+}
+
+rolling_frequency_func!(rolling_n_unique, NUniqueWindow);
+rolling_frequency_func!(rolling_mode, ModeWindow);
+
+#[cfg(test)]
+mod test {
+    use polars_utils::IdxSize;
+
+    use super::*;
+
+    #[test]
+    fn test_rolling_n_unique_mode() {
+        let values = &[1, 2, 2, 3, 3, 3, 1];
+
+        let out = rolling_n_unique(values, 3, 1, false, None, None).unwrap();
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[
+                Some(1),
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(2),
+                Some(1),
+                Some(2)
+            ]
+        );
+
+        let out = rolling_mode(values, 3, 3, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<i32>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[None, None, Some(2), Some(2), Some(3), Some(3), Some(3)]
+        );
+    }
+}
@@ -0,0 +1,68 @@
+pub use super::super::first_last::{FirstWindow, LastWindow};
+use super::*;
+
+macro_rules! rolling_first_last_func {
+    ($rolling_m:ident, $window:ident) => {
+        pub fn $rolling_m<T>(
+            arr: &PrimitiveArray<T>,
+            window_size: usize,
+            min_periods: usize,
+            center: bool,
+            weights: Option<&[f64]>,
+            _params: Option<RollingFnParams>,
+        ) -> ArrayRef
+        where
+            T: NativeType + IsFloat,
+        {
+            if weights.is_some() {
+                panic!("weights not yet supported on array with null values")
+            }
+            let offset_fn = match center {
+                true => det_offsets_center,
+                false => det_offsets,
+            };
+            rolling_apply_agg_window::<$window<T>, _, _, _>(
+                arr.values().as_slice(),
+                arr.validity().as_ref().unwrap(),
+                window_size,
+                min_periods,
+                offset_fn,
+                None,
+            )
+        }
+    };
+}
+
+rolling_first_last_func!(rolling_first, FirstWindow);
+rolling_first_last_func!(rolling_last, LastWindow);
+
+#[cfg(test)]
+mod test {
+    use arrow::buffer::Buffer;
+    use arrow::datatypes::ArrowDataType;
+
+    use super::*;
+
+    #[test]
+    fn test_rolling_first_last_nulls() {
+        let buf = Buffer::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let arr = &PrimitiveArray::new(
+            ArrowDataType::Float64,
+            buf,
+            Some(Bitmap::from(&[true, false, true, false, false])),
+        );
+
+        let out = rolling_first(arr, 2, 1, false, None, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[Some(1.0), Some(1.0), Some(3.0), Some(3.0), None]);
+
+        let out = rolling_last(arr, 3, 1, false, None, None);
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            out,
+            &[Some(1.0), Some(1.0), Some(3.0), Some(3.0), Some(3.0)]
+        );
+    }
+}
@@ -62,7 +62,7 @@ where
         panic!("weights not yet supported on array with null values")
     }
     if center {
-        rolling_apply_agg_window::<MeanWindow<_>, _, _>(
+        rolling_apply_agg_window::<MeanWindow<_>, _, _, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
@@ -71,7 +71,7 @@ where
             None,
         )
     } else {
-        rolling_apply_agg_window::<MeanWindow<_>, _, _>(
+        rolling_apply_agg_window::<MeanWindow<_>, _, _, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
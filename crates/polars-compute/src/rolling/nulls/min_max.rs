@@ -1,9 +1,11 @@
-use polars_utils::min_max::{MaxPropagateNan, MinPropagateNan};
+use polars_utils::min_max::{MaxIgnoreNan, MaxPropagateNan, MinIgnoreNan, MinPropagateNan};
 
-use super::super::min_max::MinMaxWindow;
+use super::super::min_max::{ArgMinMaxWindow, MinMaxWindow};
 
 pub type MinWindow<'a, T> = MinMaxWindow<'a, T, MinPropagateNan>;
 pub type MaxWindow<'a, T> = MinMaxWindow<'a, T, MaxPropagateNan>;
+pub type ArgMinWindow<'a, T> = ArgMinMaxWindow<'a, T, MinIgnoreNan>;
+pub type ArgMaxWindow<'a, T> = ArgMinMaxWindow<'a, T, MaxIgnoreNan>;
 
 use super::*;
 
@@ -22,7 +24,7 @@ where
         panic!("weights not yet supported on array with null values")
     }
     if center {
-        rolling_apply_agg_window::<MinMaxWindow<T, MinPropagateNan>, _, _>(
+        rolling_apply_agg_window::<MinMaxWindow<T, MinPropagateNan>, _, _, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
@@ -31,7 +33,7 @@ where
             None,
         )
     } else {
-        rolling_apply_agg_window::<MinMaxWindow<T, MinPropagateNan>, _, _>(
+        rolling_apply_agg_window::<MinMaxWindow<T, MinPropagateNan>, _, _, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
@@ -57,7 +59,7 @@ where
         panic!("weights not yet supported on array with null values")
     }
     if center {
-        rolling_apply_agg_window::<MinMaxWindow<T, MaxPropagateNan>, _, _>(
+        rolling_apply_agg_window::<MinMaxWindow<T, MaxPropagateNan>, _, _, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
@@ -66,7 +68,7 @@ where
             None,
         )
     } else {
-        rolling_apply_agg_window::<MinMaxWindow<T, MaxPropagateNan>, _, _>(
+        rolling_apply_agg_window::<MinMaxWindow<T, MaxPropagateNan>, _, _, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
@@ -76,3 +78,38 @@ where
         )
     }
 }
+
+macro_rules! rolling_arg_minmax_func {
+    ($rolling_m:ident, $policy:ident) => {
+        pub fn $rolling_m<T>(
+            arr: &PrimitiveArray<T>,
+            window_size: usize,
+            min_periods: usize,
+            center: bool,
+            weights: Option<&[f64]>,
+            _params: Option<RollingFnParams>,
+        ) -> ArrayRef
+        where
+            T: NativeType + IsFloat,
+        {
+            if weights.is_some() {
+                panic!("weights not yet supported on array with null values")
+            }
+            let offset_fn = match center {
+                true => det_offsets_center,
+                false => det_offsets,
+            };
+            rolling_apply_agg_window::<ArgMinMaxWindow<T, $policy>, _, _, _>(
+                arr.values().as_slice(),
+                arr.validity().as_ref().unwrap(),
+                window_size,
+                min_periods,
+                offset_fn,
+                None,
+            )
+        }
+    };
+}
+
+rolling_arg_minmax_func!(rolling_arg_min, MinIgnoreNan);
+rolling_arg_minmax_func!(rolling_arg_max, MaxIgnoreNan);
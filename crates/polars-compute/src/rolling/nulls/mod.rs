@@ -1,19 +1,27 @@
+mod first_last;
 mod mean;
 mod min_max;
 mod moment;
+mod product;
 mod quantile;
+mod rank;
 mod sum;
+mod unique;
 
 use arrow::legacy::utils::CustomIterTools;
+pub use first_last::*;
 pub use mean::*;
 pub use min_max::*;
 pub use moment::*;
+pub use product::*;
 pub use quantile::*;
+pub use rank::*;
 pub use sum::*;
+pub use unique::*;
 
 use super::*;
 
-pub trait RollingAggWindowNulls<'a, T: NativeType> {
+pub trait RollingAggWindowNulls<'a, T: NativeType, Out: NativeType = T> {
     /// # Safety
     /// `start` and `end` must be in bounds for `slice` and `validity`
     unsafe fn new(
@@ -27,13 +35,13 @@ pub trait RollingAggWindowNulls<'a, T: NativeType> {
 
     /// # Safety
     /// `start` and `end` must be in bounds of `slice` and `bitmap`
-    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T>;
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<Out>;
 
     fn is_valid(&self, min_periods: usize) -> bool;
 }
 
 // Use an aggregation window that maintains the state
-pub(super) fn rolling_apply_agg_window<'a, Agg, T, Fo>(
+pub(super) fn rolling_apply_agg_window<'a, Agg, T, Out, Fo>(
     values: &'a [T],
     validity: &'a Bitmap,
     window_size: usize,
@@ -43,8 +51,9 @@ pub(super) fn rolling_apply_agg_window<'a, Agg, T, Fo>(
 ) -> ArrayRef
 where
     Fo: Fn(Idx, WindowSize, Len) -> (Start, End) + Copy,
-    Agg: RollingAggWindowNulls<'a, T>,
+    Agg: RollingAggWindowNulls<'a, T, Out>,
     T: IsFloat + NativeType,
+    Out: NativeType,
 {
     let len = values.len();
     let (start, end) = det_offsets_fn(0, window_size, len);
@@ -72,20 +81,20 @@ where
                     } else {
                         // SAFETY: we are in bounds
                         unsafe { validity.set_unchecked(idx, false) };
-                        T::default()
+                        Out::default()
                     }
                 },
                 None => {
                     // SAFETY: we are in bounds
                     unsafe { validity.set_unchecked(idx, false) };
-                    T::default()
+                    Out::default()
                 },
             }
         })
         .collect_trusted::<Vec<_>>();
 
     Box::new(PrimitiveArray::new(
-        T::PRIMITIVE.into(),
+        Out::PRIMITIVE.into(),
         out.into(),
         Some(validity.into()),
     ))
@@ -249,7 +258,7 @@ mod test {
 
         let arr = Int32Array::new(ArrowDataType::Int32, vals.into(), Some(validity));
 
-        let out = rolling_apply_agg_window::<MinMaxWindow<i32, MaxIgnoreNan>, _, _>(
+        let out = rolling_apply_agg_window::<MinMaxWindow<i32, MaxIgnoreNan>, _, _, _>(
             arr.values().as_slice(),
             arr.validity().as_ref().unwrap(),
             window_size,
@@ -149,7 +149,7 @@ where
     } else {
         det_offsets
     };
-    rolling_apply_agg_window::<MomentWindow<_, VarianceMoment>, _, _>(
+    rolling_apply_agg_window::<MomentWindow<_, VarianceMoment>, _, _, _>(
         arr.values().as_slice(),
         arr.validity().as_ref().unwrap(),
         window_size,
@@ -174,7 +174,7 @@ where
     } else {
         det_offsets
     };
-    rolling_apply_agg_window::<MomentWindow<_, SkewMoment>, _, _>(
+    rolling_apply_agg_window::<MomentWindow<_, SkewMoment>, _, _, _>(
         arr.values().as_slice(),
         arr.validity().as_ref().unwrap(),
         window_size,
@@ -199,7 +199,7 @@ where
     } else {
         det_offsets
     };
-    rolling_apply_agg_window::<MomentWindow<_, KurtosisMoment>, _, _>(
+    rolling_apply_agg_window::<MomentWindow<_, KurtosisMoment>, _, _, _>(
         arr.values().as_slice(),
         arr.validity().as_ref().unwrap(),
         window_size,
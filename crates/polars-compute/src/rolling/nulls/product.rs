@@ -0,0 +1,30 @@
+pub use super::super::product::ProductWindow;
+use super::*;
+
+pub fn rolling_product<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    _params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + IsFloat + One + Mul<Output = T>,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    rolling_apply_agg_window::<ProductWindow<T>, _, _, _>(
+        arr.values().as_slice(),
+        arr.validity().as_ref().unwrap(),
+        window_size,
+        min_periods,
+        offset_fn,
+        None,
+    )
+}
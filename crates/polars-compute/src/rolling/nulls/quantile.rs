@@ -149,7 +149,7 @@ where
         let out: PrimitiveArray<T> = out.into();
         return Box::new(out);
     }
-    rolling_apply_agg_window::<QuantileWindow<_>, _, _>(
+    rolling_apply_agg_window::<QuantileWindow<_>, _, _, _>(
         arr.values().as_slice(),
         arr.validity().as_ref().unwrap(),
         window_size,
@@ -0,0 +1,36 @@
+use polars_utils::total_ord::TotalOrd;
+
+use super::*;
+
+pub fn rolling_rank<T>(
+    arr: &PrimitiveArray<T>,
+    window_size: usize,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    params: Option<RollingFnParams>,
+) -> ArrayRef
+where
+    T: NativeType + TotalOrd,
+{
+    if weights.is_some() {
+        panic!("weights not yet supported on array with null values")
+    }
+    let method = match params {
+        Some(RollingFnParams::Rank { method }) => method,
+        _ => RollingRankMethod::default(),
+    };
+    let offset_fn = match center {
+        true => det_offsets_center,
+        false => det_offsets,
+    };
+    let len = arr.len();
+    let windows = (0..len).map(|idx| offset_fn(idx, window_size, len));
+    super::super::rank::rolling_rank(
+        arr.values().as_slice(),
+        arr.validity(),
+        windows,
+        min_periods,
+        method,
+    )
+}
@@ -0,0 +1,39 @@
+use polars_utils::total_ord::{TotalHash, TotalOrd};
+
+pub use super::super::unique::{ModeWindow, NUniqueWindow};
+use super::*;
+
+macro_rules! rolling_frequency_func {
+    ($rolling_m:ident, $window:ident) => {
+        pub fn $rolling_m<T>(
+            arr: &PrimitiveArray<T>,
+            window_size: usize,
+            min_periods: usize,
+            center: bool,
+            weights: Option<&[f64]>,
+            _params: Option<RollingFnParams>,
+        ) -> ArrayRef
+        where
+            T: NativeType + IsFloat + TotalOrd + TotalHash,
+        {
+            if weights.is_some() {
+                panic!("weights not yet supported on array with null values")
+            }
+            let offset_fn = match center {
+                true => det_offsets_center,
+                false => det_offsets,
+            };
+            rolling_apply_agg_window::<$window<T>, _, _, _>(
+                arr.values().as_slice(),
+                arr.validity().as_ref().unwrap(),
+                window_size,
+                min_periods,
+                offset_fn,
+                None,
+            )
+        }
+    };
+}
+
+rolling_frequency_func!(rolling_n_unique, NUniqueWindow);
+rolling_frequency_func!(rolling_mode, ModeWindow);
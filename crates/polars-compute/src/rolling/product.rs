@@ -0,0 +1,135 @@
+use std::ops::Mul;
+
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use num_traits::One;
+
+use super::RollingFnParams;
+use super::no_nulls::RollingAggWindowNoNulls;
+use super::nulls::RollingAggWindowNulls;
+
+// Values leaving the window cannot be divided out of the product (zeros, integers), so the
+// window is kept as a queue built from two stacks that each carry running products.
+pub struct ProductWindow<'a, T> {
+    values: &'a [T],
+    validity: Option<&'a Bitmap>,
+    // Oldest values of the window; front[i] is the product of the values in front[..=i], the
+    // top of the stack being the oldest value.
+    front: Vec<T>,
+    // Newest values of the window, with their product.
+    back: Vec<T>,
+    back_product: T,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl<'a, T: NativeType + One + Mul<Output = T>> ProductWindow<'a, T> {
+    fn empty(values: &'a [T], validity: Option<&'a Bitmap>) -> Self {
+        Self {
+            values,
+            validity,
+            front: Vec::new(),
+            back: Vec::new(),
+            back_product: T::one(),
+            last_start: 0,
+            last_end: 0,
+        }
+    }
+
+    fn is_nonnull(&self, idx: usize) -> bool {
+        self.validity
+            .is_none_or(|v| unsafe { v.get_bit_unchecked(idx) })
+    }
+
+    fn push(&mut self, value: T) {
+        self.back.push(value);
+        self.back_product = self.back_product * value;
+    }
+
+    fn pop(&mut self) {
+        if self.front.is_empty() {
+            let mut product = T::one();
+            for value in self.back.drain(..).rev() {
+                product = value * product;
+                self.front.push(product);
+            }
+            self.back_product = T::one();
+        }
+        self.front.pop();
+    }
+
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in bounds of `values` and `validity`.
+    unsafe fn slide(&mut self, start: usize, end: usize) -> Option<T> {
+        if start >= self.last_end {
+            self.front.clear();
+            self.back.clear();
+            self.back_product = T::one();
+        } else {
+            for idx in self.last_start..start {
+                if self.is_nonnull(idx) {
+                    self.pop();
+                }
+            }
+        }
+        for idx in start.max(self.last_end)..end {
+            if self.is_nonnull(idx) {
+                self.push(unsafe { *self.values.get_unchecked(idx) });
+            }
+        }
+        self.last_start = start;
+        self.last_end = end;
+
+        let front_product = self.front.last().copied().unwrap_or(T::one());
+        Some(front_product * self.back_product)
+    }
+}
+
+impl<'a, T: NativeType + One + Mul<Output = T>> RollingAggWindowNulls<'a, T>
+    for ProductWindow<'a, T>
+{
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut out = Self::empty(slice, Some(validity));
+        unsafe { out.slide(start, end) };
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        unsafe { self.slide(start, end) }
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.len() >= min_periods
+    }
+}
+
+impl<'a, T: NativeType + One + Mul<Output = T>> RollingAggWindowNoNulls<'a, T>
+    for ProductWindow<'a, T>
+{
+    fn new(
+        slice: &'a [T],
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut out = Self::empty(slice, None);
+        unsafe { out.slide(start, end) };
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
+        unsafe { self.slide(start, end) }
+    }
+}
@@ -539,8 +539,10 @@ impl SealedRolling for u16 {}
 impl SealedRolling for u32 {}
 impl SealedRolling for u64 {}
 impl SealedRolling for i128 {}
+impl SealedRolling for u128 {}
 impl SealedRolling for f32 {}
 impl SealedRolling for f64 {}
+impl SealedRolling for arrow::types::f16 {}
 
 impl<
     T: NativeType
@@ -0,0 +1,325 @@
+use arrow::array::{ArrayRef, PrimitiveArray};
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use arrow::types::NativeType;
+use polars_utils::IdxSize;
+use polars_utils::total_ord::TotalOrd;
+
+use super::RollingRankMethod;
+
+/// Counts per (compressed) value, supporting prefix sums in `O(log n)`.
+struct FenwickTree {
+    tree: Vec<usize>,
+}
+
+impl FenwickTree {
+    fn new(len: usize) -> Self {
+        Self {
+            tree: vec![0; len + 1],
+        }
+    }
+
+    fn add(&mut self, idx: usize, delta: isize) {
+        let mut i = idx + 1;
+        while i < self.tree.len() {
+            self.tree[i] = self.tree[i].wrapping_add_signed(delta);
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Sum of the counts of all values smaller than `idx`.
+    fn prefix_sum(&self, idx: usize) -> usize {
+        let mut i = idx;
+        let mut sum = 0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+}
+
+struct RankWindow<'a> {
+    // Position of every value in the sorted distinct values, `None` for nulls.
+    keys: &'a [Option<usize>],
+    counts: Vec<usize>,
+    less: FenwickTree,
+    // Only maintained for the dense method.
+    distinct_less: Option<FenwickTree>,
+    len: usize,
+    last_start: usize,
+    last_end: usize,
+}
+
+impl RankWindow<'_> {
+    fn insert(&mut self, idx: usize) {
+        if let Some(key) = self.keys[idx] {
+            self.counts[key] += 1;
+            self.less.add(key, 1);
+            if self.counts[key] == 1 {
+                if let Some(distinct) = &mut self.distinct_less {
+                    distinct.add(key, 1);
+                }
+            }
+            self.len += 1;
+        }
+    }
+
+    fn remove(&mut self, idx: usize) {
+        if let Some(key) = self.keys[idx] {
+            self.counts[key] -= 1;
+            self.less.add(key, -1);
+            if self.counts[key] == 0 {
+                if let Some(distinct) = &mut self.distinct_less {
+                    distinct.add(key, -1);
+                }
+            }
+            self.len -= 1;
+        }
+    }
+
+    fn slide(&mut self, start: usize, end: usize) {
+        if start >= self.last_end {
+            for idx in self.last_start..self.last_end {
+                self.remove(idx);
+            }
+            for idx in start..end {
+                self.insert(idx);
+            }
+        } else {
+            for idx in self.last_start..start {
+                self.remove(idx);
+            }
+            for idx in self.last_end..end {
+                self.insert(idx);
+            }
+        }
+        self.last_start = start;
+        self.last_end = end;
+    }
+}
+
+/// Map every non-null value to its position among the sorted distinct values.
+fn compress<T: NativeType + TotalOrd>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+) -> (Vec<Option<usize>>, usize) {
+    let is_valid = |idx: usize| validity.is_none_or(|v| v.get_bit(idx));
+    let mut order = (0..values.len())
+        .filter(|idx| is_valid(*idx))
+        .collect::<Vec<_>>();
+    order.sort_unstable_by(|a, b| values[*a].tot_cmp(&values[*b]));
+
+    let mut keys = vec![None; values.len()];
+    let mut n_distinct = 0;
+    let mut prev: Option<T> = None;
+    for idx in order {
+        let value = values[idx];
+        if prev.is_some_and(|prev| prev.tot_ne(&value)) {
+            n_distinct += 1;
+        }
+        prev = Some(value);
+        keys[idx] = Some(n_distinct);
+    }
+    let n_distinct = if prev.is_some() { n_distinct + 1 } else { 0 };
+    (keys, n_distinct)
+}
+
+fn rank_windows<O, I, F>(
+    keys: &[Option<usize>],
+    n_distinct: usize,
+    windows: I,
+    min_periods: usize,
+    dense: bool,
+    rank: F,
+) -> ArrayRef
+where
+    O: NativeType,
+    I: Iterator<Item = (usize, usize)>,
+    // (number of smaller values, number of equal values, number of smaller distinct values)
+    F: Fn(usize, usize, usize) -> O,
+{
+    let len = keys.len();
+    let mut window = RankWindow {
+        keys,
+        counts: vec![0; n_distinct],
+        less: FenwickTree::new(n_distinct),
+        distinct_less: dense.then(|| FenwickTree::new(n_distinct)),
+        len: 0,
+        last_start: 0,
+        last_end: 0,
+    };
+
+    let mut out = Vec::with_capacity(len);
+    let mut validity = MutableBitmap::with_capacity(len);
+    for (idx, (start, end)) in windows.enumerate() {
+        window.slide(start, end);
+        match keys[idx] {
+            Some(key) if window.len >= min_periods => {
+                // If the current row is not part of its own window, rank it as if it were.
+                let in_window = (start..end).contains(&idx);
+                let less = window.less.prefix_sum(key);
+                let equal = window.counts[key] + !in_window as usize;
+                let distinct_less = window
+                    .distinct_less
+                    .as_ref()
+                    .map_or(0, |distinct| distinct.prefix_sum(key));
+                out.push(rank(less, equal, distinct_less));
+                validity.push(true);
+            },
+            _ => {
+                out.push(O::default());
+                validity.push(false);
+            },
+        }
+    }
+    assert_eq!(out.len(), len);
+
+    Box::new(PrimitiveArray::new(
+        O::PRIMITIVE.into(),
+        out.into(),
+        validity.into(),
+    ))
+}
+
+/// Rank every value within its own window.
+///
+/// `windows` yields the `(start, end)` bounds of the window of every value, in order. Null
+/// values are not ranked and do not count towards `min_periods`. The result is `Float64` for
+/// [`RollingRankMethod::Average`] and `IdxSize` otherwise.
+pub fn rolling_rank<T, I>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    windows: I,
+    min_periods: usize,
+    method: RollingRankMethod,
+) -> ArrayRef
+where
+    T: NativeType + TotalOrd,
+    I: Iterator<Item = (usize, usize)>,
+{
+    let (keys, n_distinct) = compress(values, validity);
+    let keys = keys.as_slice();
+    match method {
+        RollingRankMethod::Average => rank_windows(
+            keys,
+            n_distinct,
+            windows,
+            min_periods,
+            false,
+            |less, eq, _| less as f64 + (eq as f64 + 1.0) / 2.0,
+        ),
+        RollingRankMethod::Min => rank_windows(
+            keys,
+            n_distinct,
+            windows,
+            min_periods,
+            false,
+            |less, _, _| (less + 1) as IdxSize,
+        ),
+        RollingRankMethod::Max => rank_windows(
+            keys,
+            n_distinct,
+            windows,
+            min_periods,
+            false,
+            |less, eq, _| (less + eq) as IdxSize,
+        ),
+        RollingRankMethod::Dense => rank_windows(
+            keys,
+            n_distinct,
+            windows,
+            min_periods,
+            true,
+            |_, _, distinct| (distinct + 1) as IdxSize,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ranks(values: &[f64], window_size: usize, method: RollingRankMethod) -> Vec<Option<f64>> {
+        let windows = (0..values.len()).map(|i| (i.saturating_sub(window_size - 1), i + 1));
+        let out = rolling_rank(values, None, windows, 1, method);
+        match out.dtype() {
+            arrow::datatypes::ArrowDataType::Float64 => out
+                .as_any()
+                .downcast_ref::<PrimitiveArray<f64>>()
+                .unwrap()
+                .iter()
+                .map(|v| v.copied())
+                .collect(),
+            _ => out
+                .as_any()
+                .downcast_ref::<PrimitiveArray<IdxSize>>()
+                .unwrap()
+                .iter()
+                .map(|v| v.map(|v| *v as f64))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_rolling_rank() {
+        let values = &[3.0, 1.0, 3.0, 2.0, 3.0, 0.0];
+        assert_eq!(
+            ranks(values, 3, RollingRankMethod::Average),
+            &[
+                Some(1.0),
+                Some(1.0),
+                Some(2.5),
+                Some(2.0),
+                Some(2.5),
+                Some(1.0)
+            ]
+        );
+        assert_eq!(
+            ranks(values, 3, RollingRankMethod::Min),
+            &[
+                Some(1.0),
+                Some(1.0),
+                Some(2.0),
+                Some(2.0),
+                Some(2.0),
+                Some(1.0)
+            ]
+        );
+        assert_eq!(
+            ranks(values, 3, RollingRankMethod::Max),
+            &[
+                Some(1.0),
+                Some(1.0),
+                Some(3.0),
+                Some(2.0),
+                Some(3.0),
+                Some(1.0)
+            ]
+        );
+        assert_eq!(
+            ranks(values, 3, RollingRankMethod::Dense),
+            &[
+                Some(1.0),
+                Some(1.0),
+                Some(2.0),
+                Some(2.0),
+                Some(2.0),
+                Some(1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rolling_rank_nulls() {
+        let values = &[3, 1, 2, 5];
+        let validity = Bitmap::from(&[true, false, true, true]);
+        let windows = (0..values.len()).map(|i| (i.saturating_sub(1), i + 1));
+        let out = rolling_rank(values, Some(&validity), windows, 2, RollingRankMethod::Min);
+        let out = out
+            .as_any()
+            .downcast_ref::<PrimitiveArray<IdxSize>>()
+            .unwrap();
+        let out = out.iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, None, Some(2)]);
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::BTreeSet;
+use std::marker::PhantomData;
+
+use arrow::bitmap::Bitmap;
+use arrow::types::NativeType;
+use polars_utils::IdxSize;
+use polars_utils::aliases::PlHashMap;
+use polars_utils::total_ord::{TotalHash, TotalOrd, TotalOrdWrap};
+
+use super::RollingFnParams;
+use super::no_nulls::RollingAggWindowNoNulls;
+use super::nulls::RollingAggWindowNulls;
+
+/// State of a window aggregation that only depends on how often each value occurs.
+pub trait FrequencyState<T> {
+    type Out: NativeType;
+
+    fn new() -> Self;
+
+    fn insert_one(&mut self, value: T);
+
+    fn remove_one(&mut self, value: T);
+
+    fn clear(&mut self);
+
+    fn finalize(&self) -> Option<Self::Out>;
+}
+
+pub struct NUniqueState<T> {
+    counts: PlHashMap<TotalOrdWrap<T>, IdxSize>,
+}
+
+impl<T: NativeType + TotalOrd + TotalHash> FrequencyState<T> for NUniqueState<T> {
+    type Out = IdxSize;
+
+    fn new() -> Self {
+        Self {
+            counts: PlHashMap::default(),
+        }
+    }
+
+    fn insert_one(&mut self, value: T) {
+        *self.counts.entry(TotalOrdWrap(value)).or_insert(0) += 1;
+    }
+
+    fn remove_one(&mut self, value: T) {
+        let key = TotalOrdWrap(value);
+        let count = self.counts.get_mut(&key).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            self.counts.remove(&key);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.counts.clear();
+    }
+
+    fn finalize(&self) -> Option<IdxSize> {
+        Some(self.counts.len() as IdxSize)
+    }
+}
+
+pub struct ModeState<T> {
+    counts: PlHashMap<TotalOrdWrap<T>, usize>,
+    // by_count[c] holds the values that occur exactly `c` times in the window.
+    by_count: Vec<BTreeSet<TotalOrdWrap<T>>>,
+    max_count: usize,
+}
+
+impl<T: NativeType + TotalOrd + TotalHash> FrequencyState<T> for ModeState<T> {
+    type Out = T;
+
+    fn new() -> Self {
+        Self {
+            counts: PlHashMap::default(),
+            by_count: vec![BTreeSet::new()],
+            max_count: 0,
+        }
+    }
+
+    fn insert_one(&mut self, value: T) {
+        let key = TotalOrdWrap(value);
+        let count = self.counts.entry(key).or_insert(0);
+        if *count > 0 {
+            self.by_count[*count].remove(&key);
+        }
+        *count += 1;
+        if self.by_count.len() <= *count {
+            self.by_count.push(BTreeSet::new());
+        }
+        self.by_count[*count].insert(key);
+        self.max_count = self.max_count.max(*count);
+    }
+
+    fn remove_one(&mut self, value: T) {
+        let key = TotalOrdWrap(value);
+        let count = self.counts.get_mut(&key).unwrap();
+        self.by_count[*count].remove(&key);
+        *count -= 1;
+        if *count == 0 {
+            self.counts.remove(&key);
+        } else {
+            self.by_count[*count].insert(key);
+        }
+        while self.max_count > 0 && self.by_count[self.max_count].is_empty() {
+            self.max_count -= 1;
+        }
+    }
+
+    fn clear(&mut self) {
+        self.counts.clear();
+        self.by_count.iter_mut().for_each(BTreeSet::clear);
+        self.max_count = 0;
+    }
+
+    fn finalize(&self) -> Option<T> {
+        // Ties are broken by taking the smallest value, so the result is deterministic.
+        self.by_count[self.max_count].first().map(|v| v.0)
+    }
+}
+
+/// Window that keeps track of the frequency of every non-null value it contains.
+pub struct FrequencyWindow<'a, T, S> {
+    values: &'a [T],
+    validity: Option<&'a Bitmap>,
+    state: S,
+    nonnulls_in_window: usize,
+    last_start: usize,
+    last_end: usize,
+    phantom: PhantomData<T>,
+}
+
+pub type NUniqueWindow<'a, T> = FrequencyWindow<'a, T, NUniqueState<T>>;
+pub type ModeWindow<'a, T> = FrequencyWindow<'a, T, ModeState<T>>;
+
+impl<'a, T: NativeType, S: FrequencyState<T>> FrequencyWindow<'a, T, S> {
+    fn empty(values: &'a [T], validity: Option<&'a Bitmap>) -> Self {
+        Self {
+            values,
+            validity,
+            state: S::new(),
+            nonnulls_in_window: 0,
+            last_start: 0,
+            last_end: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /// # Safety
+    /// The index must be in-bounds.
+    unsafe fn insert(&mut self, idx: usize) {
+        unsafe {
+            if self.validity.is_none_or(|v| v.get_bit_unchecked(idx)) {
+                self.state.insert_one(*self.values.get_unchecked(idx));
+                self.nonnulls_in_window += 1;
+            }
+        }
+    }
+
+    /// # Safety
+    /// The index must be in-bounds.
+    unsafe fn remove(&mut self, idx: usize) {
+        unsafe {
+            if self.validity.is_none_or(|v| v.get_bit_unchecked(idx)) {
+                self.state.remove_one(*self.values.get_unchecked(idx));
+                self.nonnulls_in_window -= 1;
+            }
+        }
+    }
+
+    /// # Safety
+    /// `start` and `end` must be in bounds of `values` and `validity`.
+    unsafe fn slide(&mut self, start: usize, end: usize) -> Option<S::Out> {
+        unsafe {
+            if start >= self.last_end {
+                self.state.clear();
+                self.nonnulls_in_window = 0;
+                for idx in start..end {
+                    self.insert(idx);
+                }
+            } else {
+                for idx in self.last_start..start {
+                    self.remove(idx);
+                }
+                for idx in self.last_end..end {
+                    self.insert(idx);
+                }
+            }
+        }
+        self.last_start = start;
+        self.last_end = end;
+        self.state.finalize()
+    }
+}
+
+impl<'a, T: NativeType, S: FrequencyState<T>> RollingAggWindowNulls<'a, T, S::Out>
+    for FrequencyWindow<'a, T, S>
+{
+    unsafe fn new(
+        slice: &'a [T],
+        validity: &'a Bitmap,
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut out = Self::empty(slice, Some(validity));
+        unsafe { out.slide(start, end) };
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<S::Out> {
+        unsafe { self.slide(start, end) }
+    }
+
+    fn is_valid(&self, min_periods: usize) -> bool {
+        self.nonnulls_in_window >= min_periods
+    }
+}
+
+impl<'a, T: NativeType, S: FrequencyState<T>> RollingAggWindowNoNulls<'a, T, S::Out>
+    for FrequencyWindow<'a, T, S>
+{
+    fn new(
+        slice: &'a [T],
+        start: usize,
+        end: usize,
+        _params: Option<RollingFnParams>,
+        _window_size: Option<usize>,
+    ) -> Self {
+        let mut out = Self::empty(slice, None);
+        unsafe { out.slide(start, end) };
+        out
+    }
+
+    unsafe fn update(&mut self, start: usize, end: usize) -> Option<S::Out> {
+        unsafe { self.slide(start, end) }
+    }
+}
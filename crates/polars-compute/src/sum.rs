@@ -43,6 +43,7 @@ wrapping_impl!(WrappingAdd, wrapping_add, i128);
 
 wrapping_impl!(WrappingAdd, add, f32);
 wrapping_impl!(WrappingAdd, add, f64);
+wrapping_impl!(WrappingAdd, add, arrow::types::f16);
 
 #[cfg(feature = "simd")]
 const STRIPE: usize = 16;
@@ -138,6 +139,17 @@ impl WrappingSum for i128 {
     }
 }
 
+#[cfg(feature = "simd")]
+impl WrappingSum for arrow::types::f16 {
+    fn wrapping_sum(vals: &[Self]) -> Self {
+        vals.iter().copied().fold(Self::zero(), |a, b| a + b)
+    }
+
+    fn wrapping_sum_with_validity(vals: &[Self], mask: &BitMask) -> Self {
+        wrapping_sum_with_mask_scalar(vals, mask)
+    }
+}
+
 pub trait WrappingSum: Sized {
     fn wrapping_sum(vals: &[Self]) -> Self;
     fn wrapping_sum_with_validity(vals: &[Self], mask: &BitMask) -> Self;
@@ -1,13 +1,86 @@
+use ethnum::{I256, U256};
+
 use super::*;
+use crate::chunked_array::ops::arity::broadcast_try_binary_elementwise;
+
+/// The maximum number of digits a decimal backed by an `i128` can hold.
+const MAX_DECIMAL_PRECISION: usize = 38;
+
+/// Applies `op` to the physical values of two decimals, checking every result against the
+/// output `precision`.
+///
+/// `op` returns `None` if the computation overflowed. Depending on
+/// [`crate::config::decimal_overflow_to_null`] overflowing values either raise an error or are
+/// set to null.
+fn checked_decimal_arithmetic<F>(
+    lhs: &DecimalChunked,
+    rhs: &DecimalChunked,
+    precision: Option<usize>,
+    scale: usize,
+    op_name: &str,
+    null_on_zero_rhs: bool,
+    op: F,
+) -> PolarsResult<DecimalChunked>
+where
+    F: Fn(i128, i128) -> Option<i128>,
+{
+    polars_ensure!(
+        scale <= MAX_DECIMAL_PRECISION,
+        InvalidOperation: "decimal {} would need a scale of {}, the maximum is {}",
+        op_name, scale, MAX_DECIMAL_PRECISION
+    );
+    let overflow_to_null = crate::config::decimal_overflow_to_null();
+    let upper = 10_i128.pow(precision.unwrap_or(MAX_DECIMAL_PRECISION) as u32);
+    let lower = -upper;
+
+    let out: Int128Chunked =
+        broadcast_try_binary_elementwise(lhs.physical(), rhs.physical(), |l, r| match (l, r) {
+            (Some(_), Some(0)) if null_on_zero_rhs => Ok(None),
+            (Some(l), Some(r)) => match op(l, r) {
+                Some(v) if v > lower && v < upper => Ok(Some(v)),
+                _ if overflow_to_null => Ok(None),
+                _ => polars_bail!(
+                    ComputeError:
+                    "decimal {} overflow: result does not fit in {}",
+                    op_name, DataType::Decimal(precision, Some(scale))
+                ),
+            },
+            _ => Ok(None),
+        })?;
+    Ok(out.into_decimal_unchecked(precision, scale))
+}
+
+#[inline]
+fn rescale(v: i128, factor: i128) -> Option<i128> {
+    if factor == 1 {
+        Some(v)
+    } else {
+        v.checked_mul(factor)
+    }
+}
+
+fn pow10(exp: usize) -> PolarsResult<i128> {
+    10_i128
+        .checked_pow(exp as u32)
+        .ok_or_else(|| polars_err!(ComputeError: "decimal scale factor 10^{} overflows", exp))
+}
 
 impl Add for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn add(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.phys + &rhs.phys).into_decimal_unchecked(None, scale))
+        let precision = _get_decimal_precision_add_sub(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
+        let fl = pow10(scale - self.scale())?;
+        let fr = pow10(scale - rhs.scale())?;
+        checked_decimal_arithmetic(self, rhs, precision, scale, "addition", false, |l, r| {
+            rescale(l, fl)?.checked_add(rescale(r, fr)?)
+        })
     }
 }
 
@@ -16,9 +89,17 @@ impl Sub for &DecimalChunked {
 
     fn sub(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_add_sub(self.scale(), rhs.scale());
-        let lhs = self.to_scale(scale)?;
-        let rhs = rhs.to_scale(scale)?;
-        Ok((&lhs.phys - &rhs.phys).into_decimal_unchecked(None, scale))
+        let precision = _get_decimal_precision_add_sub(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
+        let fl = pow10(scale - self.scale())?;
+        let fr = pow10(scale - rhs.scale())?;
+        checked_decimal_arithmetic(self, rhs, precision, scale, "subtraction", false, |l, r| {
+            rescale(l, fl)?.checked_sub(rescale(r, fr)?)
+        })
     }
 }
 
@@ -27,7 +108,16 @@ impl Mul for &DecimalChunked {
 
     fn mul(self, rhs: Self) -> Self::Output {
         let scale = _get_decimal_scale_mul(self.scale(), rhs.scale());
-        Ok((&self.phys * &rhs.phys).into_decimal_unchecked(None, scale))
+        let precision = _get_decimal_precision_mul(self.precision(), rhs.precision());
+        checked_decimal_arithmetic(
+            self,
+            rhs,
+            precision,
+            scale,
+            "multiplication",
+            false,
+            |l, r| l.checked_mul(r),
+        )
     }
 }
 
@@ -35,9 +125,21 @@ impl Div for &DecimalChunked {
     type Output = PolarsResult<DecimalChunked>;
 
     fn div(self, rhs: Self) -> Self::Output {
-        let scale = _get_decimal_scale_div(self.scale());
-        let lhs = self.to_scale(scale + rhs.scale())?;
-        Ok((&lhs.phys / &rhs.phys).into_decimal_unchecked(None, scale))
+        let scale =
+            _get_decimal_scale_div(self.precision(), self.scale(), rhs.precision(), rhs.scale());
+        let precision = _get_decimal_precision_div(
+            self.precision(),
+            self.scale(),
+            rhs.precision(),
+            rhs.scale(),
+        );
+        // Bring the dividend to `scale + rhs.scale()` so the (truncating) integer division
+        // yields a result at `scale`. The scale never drops below `scale_left - scale_right`,
+        // so the dividend is only ever scaled up.
+        let factor = pow10(scale + rhs.scale() - self.scale())?;
+        checked_decimal_arithmetic(self, rhs, precision, scale, "division", true, |l, r| {
+            rescale(l, factor)?.checked_div(r)
+        })
     }
 }
 
@@ -50,7 +152,313 @@ pub fn _get_decimal_scale_mul(scale_left: usize, scale_right: usize) -> usize {
     scale_left + scale_right
 }
 
-pub fn _get_decimal_scale_div(scale_left: usize) -> usize {
-    // Follow postgres and MySQL adding a fixed scale increment of 4
-    scale_left + 4
+/// The minimum scale of a decimal division result.
+const MIN_DECIMAL_DIV_SCALE: usize = 6;
+
+/// Computes the result scale of a decimal division, following SQL Server.
+///
+/// The scale is `max(6, s1 + p2 + 1)`. If the integer digits `p1 - s1 + s2` and that scale do
+/// not fit in the maximum precision, the scale is reduced (but not below 6) to keep the integer
+/// digits. An unknown precision is treated as the maximum precision.
+pub fn _get_decimal_scale_div(
+    precision_left: Option<usize>,
+    scale_left: usize,
+    precision_right: Option<usize>,
+    scale_right: usize,
+) -> usize {
+    let integer_digits = decimal_div_integer_digits(precision_left, scale_left, scale_right);
+    let scale = MIN_DECIMAL_DIV_SCALE
+        .max(scale_left + precision_right.unwrap_or(MAX_DECIMAL_PRECISION) + 1);
+    if integer_digits + scale <= MAX_DECIMAL_PRECISION {
+        return scale;
+    }
+    scale
+        .min(MAX_DECIMAL_PRECISION.saturating_sub(integer_digits))
+        .max(MIN_DECIMAL_DIV_SCALE)
+        .max(scale_left.saturating_sub(scale_right))
+}
+
+fn decimal_div_integer_digits(
+    precision_left: Option<usize>,
+    scale_left: usize,
+    scale_right: usize,
+) -> usize {
+    precision_left
+        .unwrap_or(MAX_DECIMAL_PRECISION)
+        .saturating_sub(scale_left)
+        + scale_right
+}
+
+// The precision rules follow the SQL standard as implemented by SQL Server and Spark, capped at
+// the maximum precision of 38. If either side has an unknown precision, so does the result.
+pub fn _get_decimal_precision_add_sub(
+    precision_left: Option<usize>,
+    scale_left: usize,
+    precision_right: Option<usize>,
+    scale_right: usize,
+) -> Option<usize> {
+    let integer_digits = precision_left?
+        .saturating_sub(scale_left)
+        .max(precision_right?.saturating_sub(scale_right));
+    let precision = integer_digits + scale_left.max(scale_right) + 1;
+    Some(precision.min(MAX_DECIMAL_PRECISION))
+}
+
+pub fn _get_decimal_precision_mul(
+    precision_left: Option<usize>,
+    precision_right: Option<usize>,
+) -> Option<usize> {
+    Some((precision_left? + precision_right? + 1).min(MAX_DECIMAL_PRECISION))
+}
+
+pub fn _get_decimal_precision_div(
+    precision_left: Option<usize>,
+    scale_left: usize,
+    precision_right: Option<usize>,
+    scale_right: usize,
+) -> Option<usize> {
+    // p1 - s1 + s2 + scale, where scale is max(6, s1 + p2 + 1) (see `_get_decimal_scale_div`).
+    let (p1, p2) = (precision_left?, precision_right?);
+    let integer_digits = decimal_div_integer_digits(Some(p1), scale_left, scale_right);
+    let scale = _get_decimal_scale_div(Some(p1), scale_left, Some(p2), scale_right);
+    Some((integer_digits + scale).min(MAX_DECIMAL_PRECISION))
+}
+
+/// The precision of the sum of decimals with the given precision.
+///
+/// Like Spark, we leave room for 10 extra integer digits.
+pub fn _get_decimal_precision_sum(precision: Option<usize>) -> Option<usize> {
+    precision.map(|p| (p + 10).min(MAX_DECIMAL_PRECISION))
+}
+
+/// The precision of the mean, variance and standard deviation of decimals.
+///
+/// The variance and standard deviation can need more integer digits than their input, so like
+/// SQL Server these use the maximum precision.
+pub fn _get_decimal_precision_mean_var_std() -> Option<usize> {
+    Some(MAX_DECIMAL_PRECISION)
+}
+
+/// The scale of the mean, variance and standard deviation of decimals with the given scale.
+///
+/// Like SQL Server, this keeps at least the minimum scale of a division.
+pub fn _get_decimal_scale_mean_var_std(scale: usize) -> usize {
+    scale.max(MIN_DECIMAL_DIV_SCALE)
+}
+
+/// Divides `numerator` by a positive `denominator`, rounding half away from zero.
+fn div_round_half_away_from_zero(numerator: I256, denominator: I256) -> I256 {
+    let (quot, rem) = (numerator / denominator, numerator % denominator);
+    if rem.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+        quot + numerator.signum()
+    } else {
+        quot
+    }
+}
+
+/// Converts a physical value to an `i128`, `None` if it doesn't fit the maximum precision.
+fn to_decimal_physical(v: I256) -> Option<i128> {
+    let upper = I256::from(10_i128.pow(MAX_DECIMAL_PRECISION as u32));
+    (v > -upper && v < upper).then(|| v.as_i128())
+}
+
+fn pow10_i256(exp: usize) -> Option<I256> {
+    I256::from(10_u8).checked_pow(exp as u32)
+}
+
+/// The mean of `count > 0` decimal physicals at `scale` with the given exact `sum`.
+///
+/// The result is a physical at [`_get_decimal_scale_mean_var_std`], rounded half away from zero,
+/// or `None` if it overflowed.
+pub fn _decimal_mean(sum: i128, count: usize, scale: usize) -> Option<i128> {
+    debug_assert!(count > 0);
+    let factor = pow10_i256(_get_decimal_scale_mean_var_std(scale) - scale)?;
+    let numerator = I256::from(sum).checked_mul(factor)?;
+    to_decimal_physical(div_round_half_away_from_zero(
+        numerator,
+        I256::from(count as u64),
+    ))
+}
+
+/// Exact running sums of decimal physicals, from which the variance is computed at the end.
+///
+/// The sum of squares needs up to 256 bits. A `None` sum means the computation overflowed.
+#[derive(Clone, Copy, Debug)]
+pub struct DecimalVarState {
+    count: u64,
+    sum: Option<I256>,
+    sum_sq: Option<I256>,
+}
+
+impl Default for DecimalVarState {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: Some(I256::ZERO),
+            sum_sq: Some(I256::ZERO),
+        }
+    }
+}
+
+impl DecimalVarState {
+    pub fn insert_one(&mut self, x: i128) {
+        let x = I256::from(x);
+        self.count += 1;
+        self.sum = self.sum.and_then(|s| s.checked_add(x));
+        self.sum_sq = self.sum_sq.and_then(|s| s.checked_add(x.checked_mul(x)?));
+    }
+
+    pub fn combine(&mut self, other: &Self) {
+        self.count += other.count;
+        self.sum = self.sum.zip(other.sum).and_then(|(a, b)| a.checked_add(b));
+        self.sum_sq = self
+            .sum_sq
+            .zip(other.sum_sq)
+            .and_then(|(a, b)| a.checked_add(b));
+    }
+
+    /// Returns the variance, or the standard deviation if `is_std`, of physicals at `scale`.
+    ///
+    /// The result is a physical at [`_get_decimal_scale_mean_var_std`], rounded half away from
+    /// zero. The inner `None` means there were no more than `ddof` values, the outer `None` means
+    /// the result overflowed.
+    pub fn finalize(&self, ddof: u8, scale: usize, is_std: bool) -> Option<Option<i128>> {
+        if self.count <= ddof as u64 {
+            return Some(None);
+        }
+        let out_scale = _get_decimal_scale_mean_var_std(scale);
+        // For values v = x / 10^s, var(v) = (n * sum(x^2) - sum(x)^2) / (n * (n - ddof)) / 10^2s.
+        // The numerator is exact and non-negative.
+        let n = I256::from(self.count);
+        let sum = self.sum?;
+        let numerator = n
+            .checked_mul(self.sum_sq?)?
+            .checked_sub(sum.checked_mul(sum)?)?;
+        let denominator = n.checked_mul(I256::from(self.count - ddof as u64))?;
+        let out = if is_std {
+            // At the output scale S the standard deviation is sqrt(q) with
+            // q = numerator * 10^2(S - s) / denominator, and round(sqrt(q)) = (isqrt(4q) + 1) / 2.
+            let numerator = numerator.checked_mul(pow10_i256(2 * (out_scale - scale))?)?;
+            let (quot, rem) = (numerator / denominator, numerator % denominator);
+            let four_q = quot.checked_mul(I256::from(4))? + rem * 4 / denominator;
+            (isqrt(four_q.as_u256()).as_i256() + 1) / 2
+        } else if out_scale >= 2 * scale {
+            let numerator = numerator.checked_mul(pow10_i256(out_scale - 2 * scale)?)?;
+            div_round_half_away_from_zero(numerator, denominator)
+        } else {
+            let denominator = denominator.checked_mul(pow10_i256(2 * scale - out_scale)?)?;
+            div_round_half_away_from_zero(numerator, denominator)
+        };
+        to_decimal_physical(out).map(Some)
+    }
+}
+
+/// The integer square root of `n`, using Newton's method.
+fn isqrt(n: U256) -> U256 {
+    if n < U256::from(2_u8) {
+        return n;
+    }
+    // Start from a power of two that is at least sqrt(n), the iteration then decreases
+    // monotonically towards the root.
+    let bits = 256 - n.leading_zeros();
+    let mut x = U256::ONE << bits.div_ceil(2);
+    loop {
+        let y = (x + n / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn decimal(values: &[Option<i128>], precision: Option<usize>, scale: usize) -> DecimalChunked {
+        Int128Chunked::from_slice_options(PlSmallStr::from_static("a"), values)
+            .into_decimal_unchecked(precision, scale)
+    }
+
+    #[test]
+    fn test_decimal_precision_propagation() -> PolarsResult<()> {
+        let a = decimal(&[Some(1234), None], Some(5), 2);
+        let b = decimal(&[Some(15), Some(1)], Some(3), 1);
+
+        let out = (&a + &b)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(6), Some(2)));
+        assert_eq!(out.physical().get(0), Some(1384));
+        assert_eq!(out.physical().get(1), None);
+
+        let out = (&a * &b)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(9), Some(3)));
+        assert_eq!(out.physical().get(0), Some(18510));
+
+        let out = (&a / &b)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(10), Some(6)));
+        // 12.34 / 1.5 = 8.226666..
+        assert_eq!(out.physical().get(0), Some(8226666));
+
+        // The division scale is max(6, s1 + p2 + 1), reduced to fit the integer digits.
+        let c = decimal(&[Some(1)], Some(10), 4);
+        let d = decimal(&[Some(3)], Some(10), 2);
+        let out = (&c / &d)?;
+        assert_eq!(out.dtype(), &DataType::Decimal(Some(23), Some(15)));
+        assert_eq!(out.physical().get(0), Some(3333333333333));
+        let c = decimal(&[Some(1)], Some(38), 10);
+        let d = decimal(&[Some(3)], Some(20), 2);
+        assert_eq!((&c / &d)?.dtype(), &DataType::Decimal(Some(38), Some(8)));
+
+        let unknown = decimal(&[Some(1)], None, 0);
+        assert_eq!((&a - &unknown)?.precision(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_mean_var_std_rounding() {
+        // Results keep at least 6 decimals.
+        assert_eq!(_decimal_mean(5, 2, 0), Some(2500000));
+        assert_eq!(_decimal_mean(-2_000_000, 3, 6), Some(-666667));
+        assert_eq!(_decimal_mean(10_i128.pow(37), 1, 0), None);
+
+        // 0.10, 0.20, 0.40: var = 0.0233333.., std = 0.1527525..
+        let mut state = DecimalVarState::default();
+        for x in [10, 20, 40] {
+            state.insert_one(x);
+        }
+        assert_eq!(state.finalize(1, 2, false), Some(Some(23333)));
+        assert_eq!(state.finalize(1, 2, true), Some(Some(152753)));
+        assert_eq!(state.finalize(3, 2, true), Some(None));
+        // At scale 10 the variance is computed at scale 20 and rounded back.
+        let mut state = DecimalVarState::default();
+        for x in [10, 20, 40] {
+            state.insert_one(x * 10_i128.pow(8));
+        }
+        assert_eq!(state.finalize(1, 10, false), Some(Some(233333333)));
+
+        let mut big = DecimalVarState::default();
+        for x in [-(10_i128.pow(31)), 10_i128.pow(31)] {
+            big.insert_one(x);
+        }
+        assert_eq!(big.finalize(1, 6, false), None);
+        assert_eq!(
+            big.finalize(1, 6, true),
+            Some(Some(14142135623730950488016887242097))
+        );
+    }
+
+    #[test]
+    fn test_decimal_overflow() -> PolarsResult<()> {
+        let big = decimal(&[Some(10_i128.pow(37))], Some(38), 0);
+        assert!((&big * &big).is_err());
+        assert!((&big + &big).is_ok());
+
+        let big = decimal(&[Some(6 * 10_i128.pow(37))], None, 0);
+        assert!((&big + &big).is_err());
+
+        let zero = decimal(&[Some(0)], Some(1), 0);
+        let out = (&big / &zero)?;
+        assert_eq!(out.null_count(), 1);
+        Ok(())
+    }
 }
@@ -7,7 +7,12 @@ use std::ops::{Add, Div, Mul, Rem, Sub};
 
 use arrow::compute::utils::combine_validities_and;
 #[cfg(feature = "dtype-decimal")]
-pub use decimal::{_get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul};
+pub use decimal::{
+    _decimal_mean, _get_decimal_precision_add_sub, _get_decimal_precision_div,
+    _get_decimal_precision_mean_var_std, _get_decimal_precision_mul, _get_decimal_precision_sum,
+    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mean_var_std,
+    _get_decimal_scale_mul, DecimalVarState,
+};
 use num_traits::{Num, NumCast, ToPrimitive};
 pub use numeric::ArithmeticChunked;
 
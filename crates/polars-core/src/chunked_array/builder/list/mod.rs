@@ -143,6 +143,12 @@ pub fn get_list_builder(
             list_capacity,
             Some(inner_type_logical.clone()),
         )),
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        DataType::FixedSizeBinary(_) => Box::new(AnonymousOwnedListBuilder::new(
+            name,
+            list_capacity,
+            Some(inner_type_logical.clone()),
+        )),
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(_, _) => Box::new(
             ListPrimitiveChunkedBuilder::<Int128Type>::new_with_values_type(
@@ -93,6 +93,26 @@ fn cast_impl_inner(
             }
             chunks
         },
+        // Intervals only have a meaningful conversion from durations, which is handled on the
+        // logical type.
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => polars_bail!(
+            InvalidOperation: "casting from {:?} to interval not supported",
+            chunks.first().map(|arr| arr.dtype())
+        ),
+        // Fixed-size binary values are created from binary or arrays of u8, which is handled on
+        // those types.
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        DataType::FixedSizeBinary(_) => polars_bail!(
+            InvalidOperation: "casting from {:?} to {} not supported",
+            chunks.first().map(|arr| arr.dtype()), dtype
+        ),
+        // JSON documents are validated from strings, which is handled on the string type.
+        #[cfg(feature = "dtype-json")]
+        DataType::Json => polars_bail!(
+            InvalidOperation: "casting from {:?} to json not supported, cast to string first",
+            chunks.first().map(|arr| arr.dtype())
+        ),
         _ => cast_chunks(chunks, &dtype.to_physical(), options)?,
     };
 
@@ -113,6 +133,8 @@ fn cast_impl_inner(
         Time => out.into_time(),
         #[cfg(feature = "dtype-decimal")]
         Decimal(precision, scale) => out.into_decimal(*precision, scale.unwrap_or(0))?,
+        #[cfg(feature = "dtype-uuid")]
+        Uuid => out.u128()?.clone().into_uuid().into_series(),
         _ => out,
     };
 
@@ -278,6 +300,20 @@ where
                     polars_bail!(ComputeError: "cannot cast numeric types to 'Categorical'");
                 }
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval if self.dtype() == &DataType::Int128 => {
+                // SAFETY:
+                // we are guarded by the type system.
+                let ca = unsafe { &*(self as *const ChunkedArray<T> as *const Int128Chunked) };
+                Ok(ca.clone().into_interval().into_series())
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid if self.dtype() == &DataType::UInt128 => {
+                // SAFETY:
+                // we are guarded by the type system.
+                let ca = unsafe { &*(self as *const ChunkedArray<T> as *const UInt128Chunked) };
+                Ok(ca.clone().into_uuid().into_series())
+            },
             _ => self.cast_impl(dtype, CastOptions::Overflowing),
         }
     }
@@ -317,6 +353,10 @@ impl ChunkCast for StringChunked {
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
             },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => Ok(self.to_uuid(options.is_strict())?.into_series()),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => Ok(self.to_json(options.is_strict())?.into_series()),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => match (precision, scale) {
                 (precision, Some(scale)) => {
@@ -411,6 +451,20 @@ impl ChunkCast for BinaryChunked {
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            DataType::FixedSizeBinary(size) => Ok(self
+                .to_fixed_size_binary(*size, options.is_strict())?
+                .into_series()),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => self
+                .to_fixed_size_binary(16, options.is_strict())?
+                .cast_with_options(dtype, options),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => self
+                .cast_with_options(&DataType::String, options)?
+                .str()?
+                .to_json(options.is_strict())
+                .map(|ca| ca.into_series()),
             _ => cast_impl(self.name().clone(), &self.chunks, dtype, options),
         }
     }
@@ -514,6 +568,11 @@ impl ChunkCast for ListChunked {
                     ))
                 }
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => ca
+                .into_owned()
+                .into_map()?
+                .cast_with_options(dtype, options),
             #[cfg(feature = "dtype-u8")]
             Binary => {
                 polars_ensure!(
@@ -592,6 +651,18 @@ impl ChunkCast for ArrayChunked {
                     },
                 }
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(size) => {
+                polars_ensure!(
+                    *size == ca.width(),
+                    InvalidOperation: "cannot cast Array to a fixed-size binary of a different width"
+                );
+                ca.cast_with_options(&Array(Box::new(UInt8), *size), options)?
+                    .array()?
+                    .clone()
+                    .into_fixed_size_binary()
+                    .map(|ca| ca.into_series())
+            },
             List(child_type) => {
                 let physical_type = dtype.to_physical();
                 // cast to the physical type to avoid logical chunks.
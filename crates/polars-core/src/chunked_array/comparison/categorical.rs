@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use arrow::bitmap::Bitmap;
 use arrow::legacy::utils::FromTrustedLenIterator;
 use polars_compute::comparisons::TotalOrdKernel;
@@ -6,6 +8,19 @@ use crate::chunked_array::cast::CastOptions;
 use crate::prelude::nulls::replace_non_null;
 use crate::prelude::*;
 
+/// Remap the physicals of categoricals that were built with different rev-maps onto a shared
+/// rev-map, so that they can be compared by their physicals.
+fn align_categoricals<'a>(
+    lhs: &'a CategoricalChunked,
+    rhs: &'a CategoricalChunked,
+) -> PolarsResult<(Cow<'a, CategoricalChunked>, Cow<'a, CategoricalChunked>)> {
+    if lhs.is_enum() || rhs.is_enum() || lhs.get_rev_map().same_src(rhs.get_rev_map()) {
+        return Ok((Cow::Borrowed(lhs), Cow::Borrowed(rhs)));
+    }
+    let (lhs, rhs) = make_rhs_categoricals_compatible(lhs, rhs)?;
+    Ok((Cow::Owned(lhs), Cow::Owned(rhs)))
+}
+
 #[cfg(feature = "dtype-categorical")]
 fn cat_equality_helper<'a, Compare, Missing>(
     lhs: &'a CategoricalChunked,
@@ -100,36 +115,40 @@ impl ChunkCompareEq<&CategoricalChunked> for CategoricalChunked {
     type Item = PolarsResult<BooleanChunked>;
 
     fn equal(&self, rhs: &CategoricalChunked) -> Self::Item {
+        let (lhs, rhs) = align_categoricals(self, rhs)?;
         cat_equality_helper(
-            self,
-            rhs,
+            &lhs,
+            &rhs,
             |lhs| replace_non_null(lhs.name().clone(), &lhs.physical().chunks, false),
             UInt32Chunked::equal,
         )
     }
 
     fn equal_missing(&self, rhs: &CategoricalChunked) -> Self::Item {
+        let (lhs, rhs) = align_categoricals(self, rhs)?;
         cat_equality_helper(
-            self,
-            rhs,
+            &lhs,
+            &rhs,
             |lhs| BooleanChunked::full(lhs.name().clone(), false, lhs.len()),
             UInt32Chunked::equal_missing,
         )
     }
 
     fn not_equal(&self, rhs: &CategoricalChunked) -> Self::Item {
+        let (lhs, rhs) = align_categoricals(self, rhs)?;
         cat_equality_helper(
-            self,
-            rhs,
+            &lhs,
+            &rhs,
             |lhs| replace_non_null(lhs.name().clone(), &lhs.physical().chunks, true),
             UInt32Chunked::not_equal,
         )
     }
 
     fn not_equal_missing(&self, rhs: &CategoricalChunked) -> Self::Item {
+        let (lhs, rhs) = align_categoricals(self, rhs)?;
         cat_equality_helper(
-            self,
-            rhs,
+            &lhs,
+            &rhs,
             |lhs| BooleanChunked::full(lhs.name().clone(), true, lhs.len()),
             UInt32Chunked::not_equal_missing,
         )
@@ -140,19 +159,23 @@ impl ChunkCompareIneq<&CategoricalChunked> for CategoricalChunked {
     type Item = PolarsResult<BooleanChunked>;
 
     fn gt(&self, rhs: &CategoricalChunked) -> Self::Item {
-        cat_compare_helper(self, rhs, UInt32Chunked::gt, |l, r| l > r)
+        let (lhs, rhs) = align_categoricals(self, rhs)?;
+        cat_compare_helper(&lhs, &rhs, UInt32Chunked::gt, |l, r| l > r)
     }
 
     fn gt_eq(&self, rhs: &CategoricalChunked) -> Self::Item {
-        cat_compare_helper(self, rhs, UInt32Chunked::gt_eq, |l, r| l >= r)
+        let (lhs, rhs) = align_categoricals(self, rhs)?;
+        cat_compare_helper(&lhs, &rhs, UInt32Chunked::gt_eq, |l, r| l >= r)
     }
 
     fn lt(&self, rhs: &CategoricalChunked) -> Self::Item {
-        cat_compare_helper(self, rhs, UInt32Chunked::lt, |l, r| l < r)
+        let (lhs, rhs) = align_categoricals(self, rhs)?;
+        cat_compare_helper(&lhs, &rhs, UInt32Chunked::lt, |l, r| l < r)
     }
 
     fn lt_eq(&self, rhs: &CategoricalChunked) -> Self::Item {
-        cat_compare_helper(self, rhs, UInt32Chunked::lt_eq, |l, r| l <= r)
+        let (lhs, rhs) = align_categoricals(self, rhs)?;
+        cat_compare_helper(&lhs, &rhs, UInt32Chunked::lt_eq, |l, r| l <= r)
     }
 }
 
@@ -0,0 +1,249 @@
+use arrow::array::{FixedSizeBinaryArray, FixedSizeListArray, PrimitiveArray};
+use arrow::bitmap::BitmapBuilder;
+
+use crate::chunked_array::cast::CastOptions;
+#[cfg(feature = "dtype-uuid")]
+use crate::chunked_array::logical::uuid_array_to_physical;
+use crate::prelude::*;
+
+/// View a fixed-size binary array as a `FixedSizeList(UInt8)` array. This does not copy the
+/// values.
+fn fixed_size_binary_array_to_fixed_size_list(arr: &FixedSizeBinaryArray) -> FixedSizeListArray {
+    let values = PrimitiveArray::new(ArrowDataType::UInt8, arr.values().clone(), None);
+    FixedSizeListArray::new(
+        ArrowDataType::UInt8.to_fixed_size_list(arr.size(), true),
+        arr.len(),
+        values.boxed(),
+        arr.validity().cloned(),
+    )
+}
+
+/// View a `FixedSizeList(UInt8)` array as a fixed-size binary array. This does not copy the
+/// values.
+fn fixed_size_list_array_to_fixed_size_binary(arr: &FixedSizeListArray) -> FixedSizeBinaryArray {
+    let values = arr
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<u8>>()
+        .unwrap();
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(arr.size()),
+        values.values().clone(),
+        arr.validity().cloned(),
+    )
+}
+
+/// Whether a valid array of `arr` contains a null element. The elements of null arrays are
+/// not read.
+fn has_null_elements(arr: &FixedSizeListArray) -> bool {
+    let Some(inner_validity) = arr.values().validity() else {
+        return false;
+    };
+    let size = arr.size();
+    (0..arr.len()).any(|i| arr.is_valid(i) && inner_validity.null_count_range(i * size, size) > 0)
+}
+
+impl ArrayChunked {
+    /// Interpret an array of `UInt8` as binary values of the width of the array.
+    pub fn into_fixed_size_binary(self) -> PolarsResult<FixedSizeBinaryChunked> {
+        polars_ensure!(
+            self.inner_dtype() == &DataType::UInt8,
+            InvalidOperation: "cannot interpret array of {} as fixed-size binary, expected an array of u8",
+            self.inner_dtype()
+        );
+        polars_ensure!(
+            self.width() > 0,
+            InvalidOperation: "fixed-size binary must have a positive size"
+        );
+        polars_ensure!(
+            !self.downcast_iter().any(has_null_elements),
+            InvalidOperation: "cannot interpret an array with null elements as fixed-size binary"
+        );
+        Ok(FixedSizeBinaryChunked::from_array_view(&self))
+    }
+}
+
+impl BinaryChunked {
+    /// Cast binary values to fixed-size binary values of `size` bytes. Values of another length
+    /// raise if `strict`, otherwise they become null.
+    pub fn to_fixed_size_binary(
+        &self,
+        size: usize,
+        strict: bool,
+    ) -> PolarsResult<FixedSizeBinaryChunked> {
+        polars_ensure!(
+            size > 0,
+            InvalidOperation: "fixed-size binary must have a positive size"
+        );
+        let mut values = Vec::with_capacity(self.len() * size);
+        let mut validity = BitmapBuilder::with_capacity(self.len());
+        for opt_v in self.iter() {
+            match opt_v {
+                Some(v) if v.len() == size => {
+                    values.extend_from_slice(v);
+                    validity.push(true);
+                },
+                Some(v) if strict => polars_bail!(
+                    InvalidOperation: "cannot cast binary value of {} bytes to fixed_size_binary[{}]",
+                    v.len(), size
+                ),
+                _ => {
+                    values.resize(values.len() + size, 0);
+                    validity.push(false);
+                },
+            }
+        }
+        let arr = FixedSizeBinaryArray::new(
+            ArrowDataType::FixedSizeBinary(size),
+            values.into(),
+            validity.into_opt_validity(),
+        );
+        Ok(FixedSizeBinaryChunked::from_arrow_chunks(
+            self.name().clone(),
+            &[arr],
+        ))
+    }
+}
+
+impl FixedSizeBinaryChunked {
+    /// Create a [`FixedSizeBinaryChunked`] from Arrow fixed-size binary arrays of the same size.
+    pub fn from_arrow_chunks(name: PlSmallStr, chunks: &[FixedSizeBinaryArray]) -> Self {
+        let size = chunks.first().map_or(1, |arr| arr.size());
+        let chunks = chunks
+            .iter()
+            .map(|arr| {
+                debug_assert_eq!(arr.size(), size);
+                arr.clone().to(ArrowDataType::FixedSizeBinary(size)).boxed()
+            })
+            .collect::<Vec<_>>();
+        unsafe {
+            Self::from_chunks_and_dtype_unchecked(name, chunks, DataType::FixedSizeBinary(size))
+        }
+    }
+
+    /// Create a [`FixedSizeBinaryChunked`] from an iterator of optional values that all have
+    /// `size` bytes.
+    pub fn from_iter_options<'a, I>(name: PlSmallStr, size: usize, v: I) -> PolarsResult<Self>
+    where
+        I: IntoIterator<Item = Option<&'a [u8]>>,
+    {
+        BinaryChunked::from_iter_options(name, v.into_iter()).to_fixed_size_binary(size, true)
+    }
+
+    /// Convert the result of a kernel that ran on [`Self::to_array`] back. The array must have
+    /// a positive width and an inner dtype of `UInt8` without nulls.
+    pub(crate) fn from_array_view(ca: &ArrayChunked) -> Self {
+        // The values of null elements are not read by `ArrayChunked`, so they need not be valid.
+        let chunks = ca
+            .downcast_iter()
+            .map(|arr| fixed_size_list_array_to_fixed_size_binary(arr).boxed())
+            .collect::<Vec<_>>();
+        let mut out = unsafe {
+            Self::from_chunks_and_dtype_unchecked(
+                ca.name().clone(),
+                chunks,
+                DataType::FixedSizeBinary(ca.width()),
+            )
+        };
+        out.set_sorted_flag(ca.is_sorted_flag());
+        out
+    }
+
+    /// The number of bytes of every value.
+    pub fn size(&self) -> usize {
+        match self.dtype() {
+            DataType::FixedSizeBinary(size) => *size,
+            _ => unreachable!(),
+        }
+    }
+
+    /// View the values as an array of `u8`, which the nested kernels operate on. This does not
+    /// copy the values.
+    pub fn to_array(&self) -> ArrayChunked {
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| fixed_size_binary_array_to_fixed_size_list(arr).boxed())
+            .collect::<Vec<_>>();
+        unsafe {
+            ArrayChunked::from_chunks_and_dtype_unchecked(
+                self.name().clone(),
+                chunks,
+                DataType::Array(Box::new(DataType::UInt8), self.size()),
+            )
+        }
+    }
+
+    /// The values as variable-size binary.
+    pub fn to_binary(&self) -> BinaryChunked {
+        BinaryChunked::from_iter_options(self.name().clone(), self.iter())
+    }
+}
+
+impl ChunkCast for FixedSizeBinaryChunked {
+    fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
+        match dtype {
+            DataType::FixedSizeBinary(_) if dtype == self.dtype() => Ok(self.clone().into_series()),
+            DataType::FixedSizeBinary(size) => Ok(self
+                .to_binary()
+                .to_fixed_size_binary(*size, options.is_strict())?
+                .into_series()),
+            DataType::Binary => Ok(self.to_binary().into_series()),
+            DataType::Array(_, _) => self.to_array().cast_with_options(dtype, options),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid if self.size() == 16 => {
+                let chunks = self.downcast_iter().map(uuid_array_to_physical);
+                Ok(UInt128Chunked::from_chunk_iter(self.name().clone(), chunks)
+                    .into_uuid()
+                    .into_series())
+            },
+            dt => polars_bail!(
+                InvalidOperation: "cannot cast {} to {}", self.dtype(), dt
+            ),
+        }
+    }
+
+    unsafe fn cast_unchecked(&self, dtype: &DataType) -> PolarsResult<Series> {
+        self.cast_with_options(dtype, CastOptions::Overflowing)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fixed_size_binary_roundtrip() -> PolarsResult<()> {
+        let values: [Option<&[u8]>; 3] = [Some(b"abc"), None, Some(b"xyz")];
+        let ca = FixedSizeBinaryChunked::from_iter_options("a".into(), 3, values)?;
+        assert_eq!(ca.dtype(), &DataType::FixedSizeBinary(3));
+        assert_eq!(ca.iter().collect::<Vec<_>>(), values);
+
+        // The values are stored without a child array.
+        let s = ca.clone().into_series();
+        assert_eq!(s.chunks()[0].dtype(), &ArrowDataType::FixedSizeBinary(3));
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert_eq!(arr.dtype(), &ArrowDataType::FixedSizeBinary(3));
+        let out = Series::try_from((PlSmallStr::EMPTY, arr))?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+
+        let taken = s.take(&IdxCa::new("".into(), [2, 0]))?;
+        assert_eq!(taken.get(0)?, AnyValue::Binary(b"xyz"));
+        assert_eq!(
+            taken.chunks()[0].dtype(),
+            &ArrowDataType::FixedSizeBinary(3)
+        );
+
+        let sorted = s.sort(SortOptions::default().with_nulls_last(true))?;
+        assert_eq!(sorted.get(0)?, AnyValue::Binary(b"abc"));
+        assert_eq!(sorted.get(2)?, AnyValue::Null);
+
+        let bin = s.cast(&DataType::Binary)?;
+        assert_eq!(bin.get(2)?, AnyValue::Binary(b"xyz"));
+        assert!(bin.strict_cast(&DataType::FixedSizeBinary(2)).is_err());
+        assert_eq!(bin.cast(&DataType::FixedSizeBinary(2))?.null_count(), 3);
+        let back = bin.cast(&DataType::FixedSizeBinary(3))?;
+        assert!(back.equals_missing(&s));
+        Ok(())
+    }
+}
@@ -105,6 +105,21 @@ fn merge_local_rhs_categorical<'a>(
         unreachable!()
     };
 
+    let (idx_mapping, new_rev_map) = merge_local_categories(categories, cats_right);
+    Ok((
+        ca_right
+            .physical
+            .apply(|opt_v| opt_v.map(|v| *idx_mapping.get(&v).unwrap())),
+        new_rev_map,
+    ))
+}
+
+/// Appends the categories of `cats_right` that `categories` misses. Returns the mapping of the
+/// right physicals onto the merged local rev-map.
+fn merge_local_categories(
+    categories: &Utf8ViewArray,
+    cats_right: &Utf8ViewArray,
+) -> (PlHashMap<u32, u32>, Arc<RevMapping>) {
     let cats_left_hashmap = PlHashMap::from_iter(
         categories
             .values_iter()
@@ -123,24 +138,157 @@ fn merge_local_rhs_categorical<'a>(
         }
     }
     let new_rev_map = Arc::new(RevMapping::build_local(new_categories.into()));
-    Ok((
-        ca_right
-            .physical
-            .apply(|opt_v| opt_v.map(|v| *idx_mapping.get(&v).unwrap())),
-        new_rev_map,
-    ))
+    (idx_mapping, new_rev_map)
+}
+
+/// The rev-map two categoricals share after [`make_rhs_categoricals_compatible`].
+///
+/// Rev-maps of the same string cache are merged globally, all others are merged into a local
+/// rev-map that holds the left categories followed by the new right categories.
+pub fn merge_rev_maps(
+    rev_map_left: &Arc<RevMapping>,
+    rev_map_right: &Arc<RevMapping>,
+) -> PolarsResult<Arc<RevMapping>> {
+    match (&**rev_map_left, &**rev_map_right) {
+        (RevMapping::Global(_, _, idl), RevMapping::Global(_, _, idr)) if idl == idr => {
+            let mut rev_map_merger = GlobalRevMapMerger::new(rev_map_left.clone());
+            rev_map_merger.merge_map(rev_map_right)?;
+            Ok(rev_map_merger.finish())
+        },
+        (RevMapping::Local(_, idl), RevMapping::Local(_, idr)) if idl == idr => {
+            Ok(rev_map_left.clone())
+        },
+        _ => {
+            let (_, rev_map) = merge_local_categories(
+                rev_map_left.get_categories(),
+                rev_map_right.get_categories(),
+            );
+            Ok(rev_map)
+        },
+    }
+}
+
+fn categoricals_need_remap(dtype: &DataType, target: &DataType) -> bool {
+    match (dtype, target) {
+        (DataType::Categorical(Some(rev_map), _), DataType::Categorical(Some(target), _)) => {
+            !rev_map.same_src(target)
+        },
+        (DataType::List(inner), DataType::List(target)) => categoricals_need_remap(inner, target),
+        #[cfg(feature = "dtype-array")]
+        (DataType::Array(inner, _), DataType::Array(target, _)) => {
+            categoricals_need_remap(inner, target)
+        },
+        #[cfg(feature = "dtype-struct")]
+        (DataType::Struct(fields), DataType::Struct(targets)) => fields
+            .iter()
+            .zip(targets)
+            .any(|(field, target)| categoricals_need_remap(field.dtype(), target.dtype())),
+        _ => false,
+    }
+}
+
+/// Re-encodes the (nested) categoricals of `s` to the rev-maps in `dtype`, e.g. the dtype
+/// returned by [`merge_dtypes`]. Returns `None` if `s` already uses these rev-maps.
+pub fn remap_nested_categoricals(s: &Series, dtype: &DataType) -> PolarsResult<Option<Series>> {
+    if !categoricals_need_remap(s.dtype(), dtype) {
+        return Ok(None);
+    }
+    let out = match dtype {
+        DataType::Categorical(Some(target), ordering) => {
+            let ca = s.categorical()?;
+            let rev_map = ca.get_rev_map();
+            let mapping = rev_map.physical_map_to(target);
+            let physical = ca.physical().try_apply_nonnull_values_generic(|v| {
+                mapping.get(&v).copied().ok_or_else(|| {
+                    polars_err!(
+                        ComputeError: "category '{}' is missing from the target categorical mapping",
+                        rev_map.get(v)
+                    )
+                })
+            })?;
+            // SAFETY: every physical was mapped to its category in `target`.
+            unsafe {
+                CategoricalChunked::from_cats_and_rev_map_unchecked(
+                    physical,
+                    target.clone(),
+                    false,
+                    *ordering,
+                )
+            }
+            .into_series()
+        },
+        DataType::List(target) => {
+            let ca = s.list()?;
+            let mut out = ca.apply_to_inner(&|inner| {
+                Ok(remap_nested_categoricals(&inner, target)?.unwrap_or(inner))
+            })?;
+            if ca.get_fast_explode_list() {
+                out.set_fast_explode()
+            }
+            out.into_series()
+        },
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(target, _) => s
+            .array()?
+            .apply_to_inner(&|inner| {
+                Ok(remap_nested_categoricals(&inner, target)?.unwrap_or(inner))
+            })?
+            .into_series(),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(targets) => {
+            let ca = s.struct_()?;
+            let fields = ca
+                .fields_as_series()
+                .iter()
+                .zip(targets)
+                .map(|(field, target)| {
+                    Ok(remap_nested_categoricals(field, target.dtype())?
+                        .unwrap_or_else(|| field.clone()))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let mut out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+            out.zip_outer_validity(ca);
+            out.into_series()
+        },
+        _ => unreachable!(),
+    };
+    Ok(Some(out))
 }
 
 pub trait CategoricalMergeOperation {
     fn finish(self, lhs: &UInt32Chunked, rhs: &UInt32Chunked) -> PolarsResult<UInt32Chunked>;
 }
 
+/// Categoricals that were built under different string caches, or with and without one, can't
+/// merge their rev-maps. These are re-encoded to local categoricals, whose physical codes are
+/// then remapped onto each other.
+fn to_mergeable<'a>(
+    cat_left: &'a CategoricalChunked,
+    cat_right: &'a CategoricalChunked,
+) -> (Cow<'a, CategoricalChunked>, Cow<'a, CategoricalChunked>) {
+    let mergeable = match (&**cat_left.get_rev_map(), &**cat_right.get_rev_map()) {
+        (RevMapping::Global(_, _, idl), RevMapping::Global(_, _, idr)) => idl == idr,
+        (RevMapping::Local(_, _), RevMapping::Local(_, _)) => true,
+        _ => false,
+    };
+    if mergeable || cat_left.is_enum() || cat_right.is_enum() {
+        (Cow::Borrowed(cat_left), Cow::Borrowed(cat_right))
+    } else {
+        (
+            Cow::Owned(cat_left.to_local()),
+            Cow::Owned(cat_right.to_local()),
+        )
+    }
+}
+
 // Make the right categorical compatible with the left while applying the merge operation
 pub fn call_categorical_merge_operation<I: CategoricalMergeOperation>(
     cat_left: &CategoricalChunked,
     cat_right: &CategoricalChunked,
     merge_ops: I,
 ) -> PolarsResult<CategoricalChunked> {
+    let (cat_left, cat_right) = to_mergeable(cat_left, cat_right);
+    let (cat_left, cat_right) = (cat_left.as_ref(), cat_right.as_ref());
     let rev_map_left = cat_left.get_rev_map();
     let rev_map_right = cat_right.get_rev_map();
     let (mut new_physical, new_rev_map) = match (&**rev_map_left, &**rev_map_right) {
@@ -205,17 +353,14 @@ pub fn make_rhs_categoricals_compatible(
     ca_left: &CategoricalChunked,
     ca_right: &CategoricalChunked,
 ) -> PolarsResult<(CategoricalChunked, CategoricalChunked)> {
-    let new_ca_right = call_categorical_merge_operation(ca_left, ca_right, DoNothing)?;
+    let (ca_left, ca_right) = to_mergeable(ca_left, ca_right);
+    let new_ca_right = call_categorical_merge_operation(&ca_left, &ca_right, DoNothing)?;
 
     // Alter rev map of left
-    let mut new_ca_left = ca_left.clone();
+    let keep_fast_unique = ca_left.get_rev_map().len() == new_ca_right.get_rev_map().len();
+    let mut new_ca_left = ca_left.into_owned();
     // SAFETY: We just made both rev maps compatible only appended categories
-    unsafe {
-        new_ca_left.set_rev_map(
-            new_ca_right.get_rev_map().clone(),
-            ca_left.get_rev_map().len() == new_ca_right.get_rev_map().len(),
-        )
-    };
+    unsafe { new_ca_left.set_rev_map(new_ca_right.get_rev_map().clone(), keep_fast_unique) };
 
     Ok((new_ca_left, new_ca_right))
 }
@@ -228,20 +373,34 @@ pub fn make_rhs_list_categoricals_compatible(
 
     let cat_left = list_ca_left.get_inner();
     let cat_right = list_ca_right.get_inner();
+    let left_is_global = cat_left.categorical()?.get_rev_map().is_global();
     let (cat_left, cat_right) =
         make_rhs_categoricals_compatible(cat_left.categorical()?, cat_right.categorical()?)?;
 
-    // we only appended categories to the rev_map at the end, so only change the inner dtype
-    list_ca_left.set_inner_dtype(cat_left.dtype().clone());
+    if left_is_global && cat_left.get_rev_map().is_local() {
+        // The left physicals were re-encoded to a local rev-map.
+        list_ca_left = with_list_categoricals(list_ca_left, &cat_left);
+    } else {
+        // we only appended categories to the rev_map at the end, so only change the inner dtype
+        list_ca_left.set_inner_dtype(cat_left.dtype().clone());
+    }
+
+    Ok((
+        list_ca_left,
+        with_list_categoricals(list_ca_right, &cat_right),
+    ))
+}
 
+/// Replace the inner values of `list_ca` by `cat`.
+fn with_list_categoricals(list_ca: ListChunked, cat: &CategoricalChunked) -> ListChunked {
     // We changed the physicals and the rev_map, offsets and validity buffers are still good
-    let (list_ca_right, cat_physical): (Cow<ListChunked>, Cow<UInt32Chunked>) =
-        align_chunks_binary(&list_ca_right, cat_right.physical());
-    let mut list_ca_right = list_ca_right.into_owned();
+    let (list_ca, cat_physical): (Cow<ListChunked>, Cow<UInt32Chunked>) =
+        align_chunks_binary(&list_ca, cat.physical());
+    let mut list_ca = list_ca.into_owned();
     // SAFETY:
     // Chunks are aligned, length / dtype remains correct
     unsafe {
-        list_ca_right
+        list_ca
             .downcast_iter_mut()
             .zip(cat_physical.chunks())
             .for_each(|(arr, new_phys)| {
@@ -254,7 +413,7 @@ pub fn make_rhs_list_categoricals_compatible(
             });
     }
     // reset the sorted flag and add extra categories back in
-    list_ca_right.set_sorted_flag(IsSorted::Not);
-    list_ca_right.set_inner_dtype(cat_right.dtype().clone());
-    Ok((list_ca_left, list_ca_right))
+    list_ca.set_sorted_flag(IsSorted::Not);
+    list_ca.set_inner_dtype(cat.dtype().clone());
+    list_ca
 }
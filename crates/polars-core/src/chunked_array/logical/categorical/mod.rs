@@ -595,4 +595,67 @@ mod test {
         assert_eq!(vals, &["a", "b", "c"]);
         Ok(())
     }
+
+    #[test]
+    fn test_categorical_different_mappings() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        let cat = DataType::Categorical(None, Default::default());
+
+        enable_string_cache();
+        let global = Series::new(PlSmallStr::from_static("a"), ["c", "a"]).cast(&cat)?;
+        disable_string_cache();
+        let local_a = Series::new(PlSmallStr::from_static("a"), ["a", "b", "c"]).cast(&cat)?;
+        let local_b = Series::new(PlSmallStr::from_static("a"), ["c", "b", "x"]).cast(&cat)?;
+
+        let eq = local_a.categorical()?.equal(local_b.categorical()?)?;
+        assert_eq!(Vec::from(&eq), [Some(false), Some(true), Some(false)]);
+        let eq = global
+            .categorical()?
+            .equal(&local_a.slice(0, 2).categorical()?.clone())?;
+        assert_eq!(Vec::from(&eq), [Some(false), Some(false)]);
+
+        for other in [&local_b, &global] {
+            let mut out = local_a.clone();
+            out.append(other)?;
+            let vals = out
+                .categorical()?
+                .iter_str()
+                .map(|v| v.unwrap())
+                .collect::<Vec<_>>();
+            let expected = ["a", "b", "c"]
+                .into_iter()
+                .chain(other.categorical()?.iter_str().map(|v| v.unwrap()))
+                .collect::<Vec<_>>();
+            assert_eq!(vals, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_categorical_different_mappings() -> PolarsResult<()> {
+        use arrow::array::builder::ShareStrategy;
+
+        use crate::series::builder::SeriesBuilder;
+
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        let cat = DataType::Categorical(None, Default::default());
+        let local_a = Series::new(PlSmallStr::from_static("a"), ["a", "b", "c"]).cast(&cat)?;
+        let local_b = Series::new(PlSmallStr::from_static("a"), ["c", "x"]).cast(&cat)?;
+        let expected = ["a", "b", "c", "c", "x"];
+
+        let mut list = local_a.implode()?;
+        list.append(&local_b.implode()?)?;
+        let out = list.into_series().explode(false)?;
+        let vals = out.categorical()?.iter_str().map(|v| v.unwrap());
+        assert_eq!(vals.collect::<Vec<_>>(), expected);
+
+        let mut builder = SeriesBuilder::new(cat);
+        builder.extend(&local_a, ShareStrategy::Always);
+        builder.subslice_extend(&local_b, 0, 2, ShareStrategy::Always);
+        let out = builder.freeze(PlSmallStr::from_static("a"));
+        let vals = out.categorical()?.iter_str().map(|v| v.unwrap());
+        assert_eq!(vals.collect::<Vec<_>>(), expected);
+        Ok(())
+    }
 }
@@ -3,7 +3,7 @@ use std::fmt::{Debug, Formatter};
 use std::hash::{BuildHasher, Hash, Hasher};
 
 use arrow::array::*;
-use polars_utils::aliases::PlFixedStateQuality;
+use polars_utils::aliases::{InitHashMaps, PlFixedStateQuality};
 
 use crate::datatypes::PlHashMap;
 use crate::{StringCache, using_string_cache};
@@ -138,6 +138,36 @@ impl RevMapping {
         }
     }
 
+    /// Calls `f` with every physical and its category.
+    fn for_each_category<'a>(&'a self, mut f: impl FnMut(u32, &'a str)) {
+        match self {
+            Self::Global(map, a, _) => map
+                .iter()
+                .for_each(|(&physical, &idx)| f(physical, a.value(idx as usize))),
+            Self::Local(a, _) => a
+                .values_iter()
+                .enumerate()
+                .for_each(|(physical, v)| f(physical as u32, v)),
+        }
+    }
+
+    /// Maps the physicals of `self` to the physicals of the same categories in `other`.
+    /// Categories that don't exist in `other` are left out.
+    pub fn physical_map_to(&self, other: &Self) -> PlHashMap<u32, u32> {
+        let mut other_physicals = PlHashMap::with_capacity(other.len());
+        other.for_each_category(|physical, v| {
+            other_physicals.insert(v, physical);
+        });
+
+        let mut out = PlHashMap::with_capacity(self.len());
+        self.for_each_category(|physical, v| {
+            if let Some(&other_physical) = other_physicals.get(v) {
+                out.insert(physical, other_physical);
+            }
+        });
+        out
+    }
+
     /// [`str`] to [`Categorical`]
     ///
     ///
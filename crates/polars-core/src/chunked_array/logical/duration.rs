@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "dtype-interval")]
+use crate::chunked_array::ops::arity::unary_elementwise;
 use crate::prelude::*;
 
 pub type DurationChunked = Logical<DurationType, Int64Type>;
@@ -53,6 +55,19 @@ impl LogicalType for DurationChunked {
                 };
                 Ok(out.into_duration(to_unit).into_series())
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let ns_per_unit = match self.time_unit() {
+                    Nanoseconds => 1,
+                    Microseconds => 1_000,
+                    Milliseconds => 1_000_000,
+                };
+                let out: Int128Chunked = unary_elementwise(&self.phys, |v: Option<i64>| {
+                    let ns = v?.checked_mul(ns_per_unit)?;
+                    Some(interval_to_i128(months_days_ns::new(0, 0, ns)))
+                });
+                Ok(out.into_interval().into_series())
+            },
             dt if dt.is_primitive_numeric() => self.phys.cast_with_options(dtype, cast_options),
             dt => {
                 polars_bail!(
@@ -0,0 +1,197 @@
+use std::borrow::Cow;
+
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// A [`Series`] of storage values tagged with a [`DataType::Extension`].
+///
+/// Operations that don't know the extension are applied to the storage and the extension dtype is
+/// kept on the result.
+#[derive(Clone)]
+pub struct ExtensionChunked {
+    storage: Series,
+    dtype: DataType,
+}
+
+impl ExtensionChunked {
+    /// Tag `storage` with the extension `name` and its serialized `metadata`.
+    pub fn new(storage: Series, name: PlSmallStr, metadata: Option<PlSmallStr>) -> Self {
+        let dtype = DataType::Extension {
+            name,
+            metadata,
+            storage: Box::new(storage.dtype().clone()),
+        };
+        Self { storage, dtype }
+    }
+
+    /// # Safety
+    /// `dtype` must be a [`DataType::Extension`] with the dtype of `storage` as storage.
+    pub unsafe fn new_unchecked(storage: Series, dtype: DataType) -> Self {
+        debug_assert!(
+            matches!(&dtype, DataType::Extension { storage: s, .. } if s.as_ref() == storage.dtype())
+        );
+        Self { storage, dtype }
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    /// The `ARROW:extension:name` of this extension type.
+    pub fn extension_name(&self) -> &PlSmallStr {
+        match &self.dtype {
+            DataType::Extension { name, .. } => name,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The serialized `ARROW:extension:metadata` of this extension type.
+    pub fn extension_metadata(&self) -> Option<&PlSmallStr> {
+        match &self.dtype {
+            DataType::Extension { metadata, .. } => metadata.as_ref(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// The registered behavior of this extension type.
+    pub fn extension_impl(&self) -> Option<Arc<dyn ExtensionTypeImpl>> {
+        get_extension_type(self.extension_name())
+    }
+
+    pub fn storage(&self) -> &Series {
+        &self.storage
+    }
+
+    pub(crate) fn storage_mut(&mut self) -> &mut Series {
+        &mut self.storage
+    }
+
+    pub fn into_storage(self) -> Series {
+        self.storage
+    }
+
+    /// Tag `storage` with the extension dtype of `self`.
+    pub(crate) fn with_storage(&self, storage: Series) -> Self {
+        debug_assert_eq!(storage.dtype(), self.dtype.extension_storage());
+        Self {
+            storage,
+            dtype: self.dtype.clone(),
+        }
+    }
+
+    /// The values that define the order and the comparisons of this column, see
+    /// [`ExtensionTypeImpl::comparison_values`].
+    pub fn comparison_values(&self) -> PolarsResult<Cow<'_, Series>> {
+        if let Some(values) = self
+            .extension_impl()
+            .and_then(|ext| ext.comparison_values(self))
+        {
+            let values = values?;
+            polars_ensure!(
+                values.len() == self.storage.len(),
+                ShapeMismatch: "comparison values of extension '{}' have a different length",
+                self.extension_name()
+            );
+            return Ok(Cow::Owned(values));
+        }
+        Ok(Cow::Borrowed(&self.storage))
+    }
+
+    /// Format the value at `index` with the registered behavior, if any.
+    pub fn format_value(&self, index: usize) -> Option<String> {
+        let ext = self.extension_impl()?;
+        let av = self.storage.get(index).ok()?;
+        if av.is_null() {
+            return None;
+        }
+        ext.format_value(&av, self.extension_metadata().map(|m| m.as_str()))
+    }
+
+    pub fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        if dtype == self.dtype() {
+            return Ok(self.clone().into_series());
+        }
+        if let Some(out) = self
+            .extension_impl()
+            .and_then(|ext| ext.cast_from(self, dtype, cast_options))
+        {
+            return out;
+        }
+        match dtype {
+            DataType::Extension { .. } => cast_to_extension(&self.storage, dtype, cast_options),
+            _ => self.storage.cast_with_options(dtype, cast_options),
+        }
+    }
+}
+
+/// Cast a [`Series`] that is not an extension to the extension dtype `dtype`.
+pub(crate) fn cast_to_extension(
+    s: &Series,
+    dtype: &DataType,
+    cast_options: CastOptions,
+) -> PolarsResult<Series> {
+    let DataType::Extension { name, storage, .. } = dtype else {
+        unreachable!()
+    };
+    if let Some(out) = get_extension_type(name).and_then(|ext| ext.cast_to(s, dtype, cast_options))
+    {
+        return out;
+    }
+    let storage = s.cast_with_options(storage, cast_options)?;
+    // SAFETY: we just casted to the storage dtype.
+    Ok(unsafe { ExtensionChunked::new_unchecked(storage, dtype.clone()) }.into_series())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct CaseInsensitive;
+
+    impl ExtensionTypeImpl for CaseInsensitive {
+        fn format_value(&self, value: &AnyValue<'_>, _metadata: Option<&str>) -> Option<String> {
+            value.extract_str().map(|s| format!("ci:{s}"))
+        }
+
+        fn comparison_values(&self, ca: &ExtensionChunked) -> Option<PolarsResult<Series>> {
+            let values = ca.storage().str().map(|ca| {
+                ca.apply_into_string_amortized(|s, buf| buf.push_str(&s.to_lowercase()))
+                    .into_series()
+            });
+            Some(values)
+        }
+    }
+
+    #[test]
+    fn test_extension_registry() -> PolarsResult<()> {
+        register_extension_type("test.case_insensitive", Arc::new(CaseInsensitive));
+        let ext = |values: &[&str]| {
+            let storage = Series::new("a".into(), values);
+            ExtensionChunked::new(storage, "test.case_insensitive".into(), None).into_series()
+        };
+        let s = ext(&["b", "C", "a"]);
+
+        let sorted = s.sort(Default::default())?;
+        assert_eq!(sorted.dtype(), s.dtype());
+        let values = sorted.extension()?.storage().str()?.clone();
+        assert_eq!(
+            values.into_iter().collect::<Vec<_>>(),
+            [Some("a"), Some("b"), Some("C")]
+        );
+
+        let eq = s.equal(&ext(&["B"]))?;
+        assert_eq!(
+            eq.into_iter().collect::<Vec<_>>(),
+            [Some(true), Some(false), Some(false)]
+        );
+        assert_eq!(s.extension()?.format_value(1).as_deref(), Some("ci:C"));
+
+        unregister_extension_type("test.case_insensitive");
+        assert!(s.extension()?.format_value(1).is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use super::*;
+use crate::chunked_array::ops::arity::unary_elementwise;
+use crate::prelude::*;
+
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+impl Int128Chunked {
+    /// Interpret the values as physical intervals, see [`interval_to_i128`].
+    pub fn into_interval(self) -> IntervalChunked {
+        IntervalChunked::new_logical(self, DataType::Interval)
+    }
+}
+
+impl IntervalChunked {
+    /// Create an [`IntervalChunked`] from an iterator of optional intervals.
+    pub fn from_intervals<I>(name: PlSmallStr, v: I) -> Self
+    where
+        I: IntoIterator<Item = Option<months_days_ns>>,
+    {
+        Int128Chunked::from_iter_options(name, v.into_iter().map(|v| v.map(interval_to_i128)))
+            .into_interval()
+    }
+
+    /// Get the interval at index `i`.
+    pub fn get_interval(&self, i: usize) -> Option<months_days_ns> {
+        self.phys.get(i).map(interval_from_i128)
+    }
+
+    /// Iterate over the intervals.
+    pub fn iter_intervals(&self) -> impl ExactSizeIterator<Item = Option<months_days_ns>> + '_ {
+        self.phys.iter().map(|v| v.map(interval_from_i128))
+    }
+
+    /// Negate every component of the intervals.
+    pub fn negate(&self) -> PolarsResult<Self> {
+        let out: Int128Chunked = unary_elementwise(self.physical(), |v| {
+            let v = interval_from_i128(v?);
+            Some(interval_to_i128(months_days_ns::new(
+                v.months().checked_neg()?,
+                v.days().checked_neg()?,
+                v.ns().checked_neg()?,
+            )))
+        });
+        polars_ensure!(
+            out.null_count() == self.null_count(),
+            ComputeError: "interval negation overflow"
+        );
+        Ok(out.into_interval())
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i).map(|av| av.as_interval())
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i).as_interval()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        use DataType::*;
+        match dtype {
+            Interval => Ok(self.clone().into_series()),
+            Duration(tu) => {
+                // Only intervals without months have a fixed length, we treat a day as 24 hours.
+                let ns_per_unit: i128 = match tu {
+                    TimeUnit::Nanoseconds => 1,
+                    TimeUnit::Microseconds => 1_000,
+                    TimeUnit::Milliseconds => 1_000_000,
+                };
+                let out: Int64Chunked = unary_elementwise(&self.phys, |opt_v| {
+                    let v = interval_from_i128(opt_v?);
+                    if v.months() != 0 {
+                        return None;
+                    }
+                    let ns = v.days() as i128 * 86_400_000_000_000 + v.ns() as i128;
+                    i64::try_from(ns / ns_per_unit).ok()
+                });
+                polars_ensure!(
+                    !cast_options.is_strict() || out.null_count() == self.null_count(),
+                    InvalidOperation: "cannot cast an interval with months to a duration"
+                );
+                Ok(out.into_duration(*tu).into_series())
+            },
+            String => {
+                let out = self.phys.apply_into_string_amortized(|v, buf| {
+                    crate::fmt::fmt_interval_string(buf, interval_from_i128(v)).unwrap()
+                });
+                Ok(out.into_series())
+            },
+            dt => polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
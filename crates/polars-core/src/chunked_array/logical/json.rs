@@ -0,0 +1,231 @@
+use std::borrow::Cow;
+
+use super::*;
+use crate::prelude::*;
+
+/// A [`StringChunked`] of which every non-null value is a valid JSON document.
+pub type JsonChunked = Logical<JsonType, StringType>;
+
+/// Check whether `s` is a single valid JSON document.
+pub fn is_valid_json(s: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(s).is_ok()
+}
+
+/// Write the valid JSON document `json` to `out` without whitespace outside of strings.
+pub fn write_json_compact(json: &str, out: &mut String) {
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in json.chars() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+            out.push(c);
+        } else if !c.is_ascii_whitespace() {
+            out.push(c);
+        }
+    }
+}
+
+/// Remove all whitespace outside of strings from the valid JSON document `json`.
+pub fn compact_json(json: &str) -> Cow<'_, str> {
+    if json.bytes().any(|b| b.is_ascii_whitespace()) {
+        let mut out = String::with_capacity(json.len());
+        write_json_compact(json, &mut out);
+        Cow::Owned(out)
+    } else {
+        Cow::Borrowed(json)
+    }
+}
+
+/// Write the valid JSON document `json` to `out` with every object member and array element on
+/// its own line, indented by two spaces per level. The order of object keys is preserved.
+pub fn write_json_pretty(json: &str, out: &mut String) {
+    fn newline(out: &mut String, depth: usize) {
+        out.push('\n');
+        for _ in 0..depth {
+            out.push_str("  ");
+        }
+    }
+
+    let mut compact = String::with_capacity(json.len());
+    write_json_compact(json, &mut compact);
+
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = compact.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            },
+            '{' | '[' => {
+                out.push(c);
+                if let Some(close @ ('}' | ']')) = chars.peek().copied() {
+                    out.push(close);
+                    chars.next();
+                } else {
+                    depth += 1;
+                    newline(out, depth);
+                }
+            },
+            '}' | ']' => {
+                depth -= 1;
+                newline(out, depth);
+                out.push(c);
+            },
+            ',' => {
+                out.push(c);
+                newline(out, depth);
+            },
+            ':' => out.push_str(": "),
+            c => out.push(c),
+        }
+    }
+}
+
+impl StringChunked {
+    /// Interpret the strings as JSON documents. Invalid documents raise if `strict`, otherwise
+    /// they become null.
+    ///
+    /// The documents are validated once here and stored in compact form, so that the JSON
+    /// operations can scan them without checking them again.
+    pub fn to_json(&self, strict: bool) -> PolarsResult<JsonChunked> {
+        let phys: StringChunked = self
+            .iter()
+            .map(|opt_s| match opt_s {
+                Some(s) if is_valid_json(s) => Ok(Some(compact_json(s))),
+                Some(s) if strict => Err(polars_err!(InvalidOperation: "invalid JSON: '{}'", s)),
+                _ => Ok(None),
+            })
+            .collect::<PolarsResult<_>>()?;
+        Ok(phys.with_name(self.name().clone()).into_json_unchecked())
+    }
+
+    /// Interpret the strings as JSON documents without validating them.
+    ///
+    /// Every non-null value must be a valid JSON document, the JSON operations may return
+    /// wrong results or raise otherwise.
+    pub fn into_json_unchecked(self) -> JsonChunked {
+        JsonChunked::new_logical(self, DataType::Json)
+    }
+}
+
+impl JsonChunked {
+    /// Remove all whitespace outside of strings.
+    pub fn compact(&self) -> JsonChunked {
+        self.phys
+            .apply_into_string_amortized(write_json_compact)
+            .into_json_unchecked()
+    }
+
+    /// Format the documents with one object member or array element per line.
+    pub fn pretty(&self) -> JsonChunked {
+        self.phys
+            .apply_into_string_amortized(write_json_pretty)
+            .into_json_unchecked()
+    }
+}
+
+impl LogicalType for JsonChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Json => Ok(self.clone().into_series()),
+            DataType::String => Ok(self.phys.clone().into_series()),
+            DataType::Binary => self.phys.cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation: "cannot cast {} to {}, decode the values with `json.get` instead",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json_validation() -> PolarsResult<()> {
+        let ca = StringChunked::new("a".into(), [Some(r#"{"a": 1}"#), Some("[1,"), None]);
+        assert!(ca.to_json(true).is_err());
+        let json = ca.to_json(false)?;
+        assert_eq!(json.dtype(), &DataType::Json);
+        assert_eq!(json.null_count(), 2);
+        // Valid documents are stored in compact form.
+        assert_eq!(json.physical().get(0), Some(r#"{"a":1}"#));
+
+        let s = ca.into_series();
+        assert!(s.strict_cast(&DataType::Json).is_err());
+        assert_eq!(s.cast(&DataType::Json)?.null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_formatting() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a".into(),
+            [r#" { "b" : [1, 2, {}], "a": "x , : { y" } "#, "[ ]"],
+        );
+        let json = ca.to_json(true)?;
+        assert_eq!(
+            Vec::from(json.compact().physical()),
+            &[Some(r#"{"b":[1,2,{}],"a":"x , : { y"}"#), Some("[]")]
+        );
+        assert_eq!(
+            json.pretty().physical().get(0),
+            Some("{\n  \"b\": [\n    1,\n    2,\n    {}\n  ],\n  \"a\": \"x , : { y\"\n}")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_arrow_roundtrip() -> PolarsResult<()> {
+        let ca = StringChunked::new("a".into(), [Some(r#"{"a": 1}"#), None]);
+        let s = ca.to_json(true)?.into_series();
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert!(
+            matches!(arr.dtype(), ArrowDataType::Extension(ext) if ext.name == JSON_EXTENSION_NAME)
+        );
+        let out = Series::try_from((PlSmallStr::EMPTY, arr))?;
+        assert_eq!(out.dtype(), &DataType::Json);
+        assert!(out.equals_missing(&s));
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use super::*;
+use crate::prelude::*;
+
+/// A [`ListChunked`] of `{key, value}` structs interpreted as a map per row.
+pub type MapChunked = Logical<MapType, ListType>;
+
+impl ListChunked {
+    /// Interpret a list of structs with two fields as a map. The first field is used as the key
+    /// and the second field as the value.
+    pub fn into_map(self) -> PolarsResult<MapChunked> {
+        let (key_dtype, value_dtype) = match self.inner_dtype() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                (fields[0].dtype().clone(), fields[1].dtype().clone())
+            },
+            DataType::Null => (DataType::Null, DataType::Null),
+            dt => polars_bail!(
+                InvalidOperation: "cannot interpret list of {} as a map, expected a list of structs with 2 fields",
+                dt
+            ),
+        };
+
+        let dtype = DataType::Map(Box::new(key_dtype), Box::new(value_dtype));
+        let entries_dtype = dtype.map_entries_dtype().unwrap();
+        let ca = match self.inner_dtype() {
+            DataType::Null => self.cast(&DataType::List(Box::new(entries_dtype)))?,
+            _ => self
+                .apply_to_inner(&|s| {
+                    let ca = s.struct_()?;
+                    let mut fields = ca.fields_as_series();
+                    polars_ensure!(
+                        !fields[0].has_nulls(),
+                        InvalidOperation: "map keys cannot be null"
+                    );
+                    fields[0].rename(PlSmallStr::from_static(MAP_KEY_NAME));
+                    fields[1].rename(PlSmallStr::from_static(MAP_VALUE_NAME));
+                    let out = StructChunked::from_series(s.name().clone(), s.len(), fields.iter())?
+                        .with_outer_validity(ca.rechunk_validity());
+                    Ok(out.into_series())
+                })?
+                .into_series(),
+        };
+
+        // SAFETY: the inner dtype was set to the entries of the map above.
+        Ok(unsafe { ca.list()?.clone().into_map_unchecked(dtype) })
+    }
+
+    /// # Safety
+    /// The inner dtype of the list must be equal to [`DataType::map_entries_dtype`] of `dtype`.
+    pub unsafe fn into_map_unchecked(self, dtype: DataType) -> MapChunked {
+        debug_assert_eq!(
+            self.inner_dtype(),
+            &dtype.map_entries_dtype().unwrap(),
+            "map entries dtype mismatch"
+        );
+        MapChunked::new_logical(self, dtype)
+    }
+}
+
+impl MapChunked {
+    pub fn key_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(key, _) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn value_dtype(&self) -> &DataType {
+        match &self.dtype {
+            DataType::Map(_, value) => value,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The entries of the map as a list of `{key, value}` structs.
+    pub fn entries(&self) -> &ListChunked {
+        &self.phys
+    }
+
+    fn same_type(&self, entries: ListChunked) -> MapChunked {
+        // SAFETY: the entries come from an operation that keeps the dtype.
+        unsafe { entries.into_map_unchecked(self.dtype.clone()) }
+    }
+
+    pub(crate) fn apply_entries<F: Fn(&ListChunked) -> ListChunked>(&self, f: F) -> MapChunked {
+        self.same_type(f(&self.phys))
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Map(_, _) => {
+                if dtype == self.dtype() {
+                    return Ok(self.clone().into_series());
+                }
+                let entries_dtype = dtype.map_entries_dtype().unwrap();
+                let entries = self
+                    .phys
+                    .cast_with_options(&DataType::List(Box::new(entries_dtype)), cast_options)?;
+                let entries = entries.list()?.clone();
+                // SAFETY: we just casted to the entries dtype.
+                Ok(unsafe { entries.into_map_unchecked(dtype.clone()) }.into_series())
+            },
+            DataType::List(_) => self.phys.cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation: "cannot cast {} to {}", self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entries(keys: &[&str], values: &[i32]) -> Series {
+        let keys = Series::new("k".into(), keys);
+        let values = Series::new("v".into(), values);
+        StructChunked::from_series("".into(), keys.len(), [keys, values].iter())
+            .unwrap()
+            .into_series()
+    }
+
+    fn map_series() -> Series {
+        let ca: ListChunked = [
+            Some(entries(&["a", "b"], &[1, 2])),
+            None,
+            Some(entries(&["a", "b"], &[1, 2])),
+            Some(entries(&["c"], &[3])),
+        ]
+        .into_iter()
+        .collect();
+        ca.into_map().unwrap().into_series()
+    }
+
+    #[test]
+    fn test_map_arrow_roundtrip() -> PolarsResult<()> {
+        let s = map_series();
+        let dtype = DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32));
+        assert_eq!(s.dtype(), &dtype);
+
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert!(matches!(arr.dtype(), ArrowDataType::Map(_, _)));
+
+        let out = Series::try_from((PlSmallStr::EMPTY, arr))?;
+        assert_eq!(out.dtype(), &dtype);
+        assert!(out.equals_missing(&s));
+        assert_eq!(
+            out.cast(&DataType::List(Box::new(
+                dtype.map_entries_dtype().unwrap()
+            )))?
+            .len(),
+            4
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "algorithm_group_by")]
+    fn test_map_group_by() -> PolarsResult<()> {
+        let s = map_series();
+        assert_eq!(s.n_unique()?, 3);
+        assert_eq!(s.unique()?.dtype(), s.dtype());
+        Ok(())
+    }
+}
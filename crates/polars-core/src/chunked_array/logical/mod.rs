@@ -14,6 +14,30 @@ pub use decimal::*;
 mod duration;
 #[cfg(feature = "dtype-duration")]
 pub use duration::*;
+#[cfg(feature = "dtype-extension")]
+mod extension;
+#[cfg(feature = "dtype-extension")]
+pub use extension::*;
+#[cfg(feature = "dtype-interval")]
+mod interval;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
+#[cfg(feature = "dtype-json")]
+mod json;
+#[cfg(feature = "dtype-json")]
+pub use json::*;
+#[cfg(feature = "dtype-map")]
+mod map;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
+#[cfg(feature = "dtype-union")]
+mod union;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
+#[cfg(feature = "dtype-uuid")]
+mod uuid_;
+#[cfg(feature = "dtype-uuid")]
+pub use uuid_::*;
 #[cfg(feature = "dtype-categorical")]
 pub mod categorical;
 #[cfg(feature = "dtype-categorical")]
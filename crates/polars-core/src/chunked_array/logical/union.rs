@@ -0,0 +1,231 @@
+use arrow::array::PrimitiveArray;
+
+use super::*;
+use crate::chunked_array::ops::any_value::arr_to_any_value;
+use crate::prelude::*;
+
+/// A [`StructChunked`] with an `Int8` type id and a field per variant, interpreted as a union.
+///
+/// Only the field of the active variant of a row is valid, the type id is null in null rows.
+pub type UnionChunked = Logical<UnionType, StructType>;
+
+impl StructChunked {
+    /// Interpret a struct of nullable fields as a union of those fields. Every row can have at
+    /// most one non-null field, which is the active variant of that row. Rows without a non-null
+    /// field are null.
+    pub fn into_union(self) -> PolarsResult<UnionChunked> {
+        let variants = self.struct_fields().to_vec();
+        polars_ensure!(
+            variants.len() <= i8::MAX as usize,
+            InvalidOperation: "a union can have at most {} variants, got {}", i8::MAX, variants.len()
+        );
+        polars_ensure!(
+            variants.iter().all(|f| f.name() != UNION_TYPE_ID_NAME),
+            InvalidOperation: "'{}' is reserved for the type id of a union", UNION_TYPE_ID_NAME
+        );
+
+        let mut ca = self;
+        ca.propagate_nulls_mut();
+        let fields = ca.fields_as_series();
+
+        let mut type_ids = vec![None; ca.len()];
+        for (i, s) in fields.iter().enumerate() {
+            if s.null_count() == s.len() {
+                continue;
+            }
+            for (row, valid) in s.is_not_null().into_no_null_iter().enumerate() {
+                if valid {
+                    polars_ensure!(
+                        type_ids[row].replace(i as i8).is_none(),
+                        InvalidOperation: "cannot build a union from a struct with more than one non-null field in row {}", row
+                    );
+                }
+            }
+        }
+
+        let type_id = Int8Chunked::from_iter_options(
+            PlSmallStr::from_static(UNION_TYPE_ID_NAME),
+            type_ids.into_iter(),
+        );
+        let validity = type_id.rechunk_validity();
+        let columns = std::iter::once(type_id.into_series())
+            .chain(fields)
+            .collect::<Vec<_>>();
+        let phys = StructChunked::from_series(ca.name().clone(), ca.len(), columns.iter())?
+            .rechunk()
+            .into_owned()
+            .with_outer_validity(validity);
+
+        // SAFETY: the type id and the variants were built above.
+        Ok(unsafe { phys.into_union_unchecked(DataType::Union(variants)) })
+    }
+
+    /// # Safety
+    /// The fields of the struct must be equal to [`DataType::union_struct_dtype`] of `dtype` and
+    /// the type ids must point to the only valid variant of every row.
+    pub unsafe fn into_union_unchecked(self, dtype: DataType) -> UnionChunked {
+        debug_assert_eq!(
+            self.dtype(),
+            &dtype.union_struct_dtype().unwrap(),
+            "union struct dtype mismatch"
+        );
+        UnionChunked::new_logical(self, dtype)
+    }
+}
+
+impl UnionChunked {
+    pub fn variants(&self) -> &[Field] {
+        match &self.dtype {
+            DataType::Union(variants) => variants,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The index of the active variant of every row.
+    pub fn type_ids(&self) -> Int8Chunked {
+        let mut fields = self.phys.fields_as_series();
+        let mut type_ids = fields.swap_remove(0);
+        type_ids.rename(self.name().clone());
+        type_ids.i8().unwrap().clone()
+    }
+
+    /// The values of the variant `name`, null in rows where another variant is active.
+    pub fn variant(&self, name: &str) -> PolarsResult<Series> {
+        polars_ensure!(
+            self.variants().iter().any(|f| f.name() == name),
+            StructFieldNotFound: "union has no variant '{}'", name
+        );
+        self.phys.field_by_name(name)
+    }
+
+    /// The variants of the union as a struct of nullable fields.
+    pub fn to_struct(&self) -> StructChunked {
+        let phys = self.phys.rechunk();
+        let fields = phys.fields_as_series();
+        StructChunked::from_series(self.name().clone(), self.len(), fields[1..].iter())
+            .unwrap()
+            .rechunk()
+            .into_owned()
+            .with_outer_validity(phys.rechunk_validity())
+    }
+
+    pub(crate) fn same_type(&self, phys: StructChunked) -> UnionChunked {
+        // SAFETY: the physical struct comes from an operation that keeps the dtype.
+        unsafe { phys.into_union_unchecked(self.dtype.clone()) }
+    }
+}
+
+impl LogicalType for UnionChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        // SAFETY: we just checked the bounds.
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        let (chunk_idx, idx) = self.phys.index_to_chunked_index(i);
+        let arr = self.phys.downcast_get_unchecked(chunk_idx);
+        if !arr.is_valid(idx) {
+            return AnyValue::Null;
+        }
+        let type_ids = arr.values()[0]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i8>>()
+            .unwrap();
+        let variant = type_ids.value_unchecked(idx) as usize;
+        arr_to_any_value(
+            arr.values()[variant + 1].as_ref(),
+            idx,
+            self.variants()[variant].dtype(),
+        )
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Union(_) if dtype == self.dtype() => Ok(self.clone().into_series()),
+            DataType::Union(variants) if variants.len() == self.variants().len() => {
+                // Variants are matched by position, the type ids are rebuilt in case the cast
+                // introduced nulls.
+                let ca = self.to_struct();
+                let fields = ca
+                    .fields_as_series()
+                    .iter()
+                    .zip(variants)
+                    .map(|(s, f)| {
+                        let mut s = s.cast_with_options(f.dtype(), cast_options)?;
+                        s.rename(f.name().clone());
+                        Ok(s)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let ca = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?
+                    .with_outer_validity(ca.rechunk_validity());
+                Ok(ca.into_union()?.into_series())
+            },
+            DataType::Struct(_) => self.to_struct().cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation: "cannot cast {} to {}", self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn union_series() -> Series {
+        let a = Series::new("a".into(), [Some(1i32), None, None, None]);
+        let b = Series::new("b".into(), [None, Some("x"), None, None]);
+        let mut ca = StructChunked::from_series("u".into(), 4, [a, b].iter()).unwrap();
+        ca.set_outer_validity(Some([true, true, true, false].into_iter().collect()));
+        ca.into_union().unwrap().into_series()
+    }
+
+    #[test]
+    fn test_union_from_struct() -> PolarsResult<()> {
+        let s = union_series();
+        let ca = s.union()?;
+        assert_eq!(
+            ca.type_ids().into_iter().collect::<Vec<_>>(),
+            [Some(0), Some(1), None, None]
+        );
+        assert_eq!(s.null_count(), 2);
+        assert_eq!(s.get(0)?, AnyValue::Int32(1));
+        assert_eq!(s.get(1)?, AnyValue::String("x"));
+        assert_eq!(s.get(2)?, AnyValue::Null);
+
+        let b = ca.variant("b")?;
+        assert_eq!(b.null_count(), 3);
+        assert!(ca.variant("c").is_err());
+
+        let a = Series::new("a".into(), [Some(1i32)]);
+        let b = Series::new("b".into(), [Some(2i32)]);
+        let ca = StructChunked::from_series("u".into(), 1, [a, b].iter())?;
+        assert!(ca.into_union().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_arrow_roundtrip() -> PolarsResult<()> {
+        let s = union_series();
+        let arr = s.to_arrow(0, CompatLevel::newest());
+        assert!(matches!(arr.dtype(), ArrowDataType::Union(_)));
+
+        let out = Series::try_from((PlSmallStr::EMPTY, arr))?;
+        assert_eq!(out.dtype(), s.dtype());
+        assert!(out.equals_missing(&s));
+
+        let filtered = out.filter(&BooleanChunked::new("".into(), [false, true, true, true]))?;
+        assert_eq!(filtered.dtype(), s.dtype());
+        assert_eq!(filtered.get(0)?, AnyValue::String("x"));
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use arrow::array::{FixedSizeBinaryArray, PrimitiveArray};
+use arrow::datatypes::ExtensionType;
+
+use super::*;
+use crate::chunked_array::ops::arity::unary_elementwise;
+use crate::prelude::*;
+
+/// A [`UInt128Chunked`] interpreted as UUIDs, the integers are the big-endian bytes of the UUIDs.
+pub type UuidChunked = Logical<UuidType, UInt128Type>;
+
+/// Convert the bytes of an Arrow `FixedSizeBinary(16)` array to physical UUIDs.
+pub fn uuid_array_to_physical(arr: &FixedSizeBinaryArray) -> PrimitiveArray<u128> {
+    debug_assert_eq!(arr.size(), 16);
+    let values = arr
+        .values()
+        .chunks_exact(16)
+        .map(|v| u128::from_be_bytes(v.try_into().unwrap()))
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(
+        ArrowDataType::UInt128,
+        values.into(),
+        arr.validity().cloned(),
+    )
+}
+
+/// Convert physical UUIDs to an Arrow `arrow.uuid` extension array of 16 bytes per value.
+pub fn uuid_array_from_physical(arr: &PrimitiveArray<u128>) -> FixedSizeBinaryArray {
+    let values = arr
+        .values()
+        .iter()
+        .flat_map(|v| v.to_be_bytes())
+        .collect::<Vec<_>>();
+    let dtype = ArrowDataType::Extension(Box::new(ExtensionType {
+        name: PlSmallStr::from_static(UUID_EXTENSION_NAME),
+        inner: ArrowDataType::FixedSizeBinary(16),
+        metadata: None,
+    }));
+    FixedSizeBinaryArray::new(dtype, values.into(), arr.validity().cloned())
+}
+
+/// Parse a UUID in any of the formats accepted by [`uuid::Uuid::parse_str`], e.g.
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8` or `67e5504410b1426f9247bb680e5fe0c8`.
+pub fn parse_uuid(s: &str) -> Option<u128> {
+    uuid::Uuid::parse_str(s).ok().map(|v| v.as_u128())
+}
+
+impl UInt128Chunked {
+    /// Interpret the integers as the big-endian bytes of UUIDs.
+    pub fn into_uuid(self) -> UuidChunked {
+        UuidChunked::new_logical(self, DataType::Uuid)
+    }
+}
+
+impl StringChunked {
+    /// Parse the strings as UUIDs. Invalid UUIDs raise if `strict`, otherwise they become null.
+    pub fn to_uuid(&self, strict: bool) -> PolarsResult<UuidChunked> {
+        let out: UInt128Chunked = unary_elementwise(self, |opt_s| opt_s.and_then(parse_uuid));
+        if strict && out.null_count() != self.null_count() {
+            let invalid = self
+                .iter()
+                .zip(out.iter())
+                .find_map(|(s, v)| if v.is_none() { s } else { None })
+                .unwrap();
+            polars_bail!(InvalidOperation: "invalid UUID: '{}'", invalid);
+        }
+        Ok(out.into_uuid())
+    }
+}
+
+impl UuidChunked {
+    /// Generate `len` random version 4 UUIDs.
+    #[cfg(feature = "random")]
+    pub fn new_v4(name: PlSmallStr, len: usize, seed: Option<u64>) -> Self {
+        use rand::prelude::*;
+
+        let mut rng = match seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_entropy(),
+        };
+        let values = (0..len)
+            .map(|_| {
+                uuid::Builder::from_random_bytes(rng.r#gen())
+                    .into_uuid()
+                    .as_u128()
+            })
+            .collect::<Vec<_>>();
+        UInt128Chunked::from_vec(name, values).into_uuid()
+    }
+
+    /// Get the UUID at index `i`.
+    pub fn get_uuid(&self, i: usize) -> Option<uuid::Uuid> {
+        self.phys.get(i).map(uuid::Uuid::from_u128)
+    }
+
+    /// Format the UUIDs in their hyphenated lowercase form.
+    pub fn to_string(&self) -> StringChunked {
+        self.phys.apply_into_string_amortized(|v, buf| {
+            let mut out = [0u8; uuid::fmt::Hyphenated::LENGTH];
+            buf.push_str(uuid::Uuid::from_u128(v).hyphenated().encode_lower(&mut out));
+        })
+    }
+
+    /// The 16 bytes of every UUID.
+    pub fn to_fixed_size_binary(&self) -> FixedSizeBinaryChunked {
+        let chunks = self
+            .phys
+            .downcast_iter()
+            .map(|arr| uuid_array_from_physical(arr).to(ArrowDataType::FixedSizeBinary(16)))
+            .collect::<Vec<_>>();
+        FixedSizeBinaryChunked::from_arrow_chunks(self.name().clone(), &chunks)
+    }
+}
+
+impl LogicalType for UuidChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i).map(|av| av.as_uuid())
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i).as_uuid()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Uuid => Ok(self.clone().into_series()),
+            DataType::UInt128 => Ok(self.phys.clone().into_series()),
+            DataType::String => Ok(self.to_string().into_series()),
+            DataType::FixedSizeBinary(16) => Ok(self.to_fixed_size_binary().into_series()),
+            DataType::FixedSizeBinary(_) => self
+                .to_fixed_size_binary()
+                .cast_with_options(dtype, cast_options),
+            DataType::Binary => Ok(self.to_fixed_size_binary().to_binary().into_series()),
+            dt => polars_bail!(
+                InvalidOperation: "cannot cast {} to {}", self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uuid_parse_format_and_order() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            [
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                None,
+                Some("00000000000000000000000000000001"),
+                Some("ffffffff-ffff-ffff-ffff-ffffffffffff"),
+            ],
+        );
+        let uuids = s.cast(&DataType::Uuid)?;
+        assert_eq!(uuids.dtype(), &DataType::Uuid);
+        assert_eq!(
+            uuids.get(2)?.to_string(),
+            "00000000-0000-0000-0000-000000000001"
+        );
+        let invalid = Series::new("a".into(), ["not a uuid"]);
+        assert!(invalid.strict_cast(&DataType::Uuid).is_err());
+        assert_eq!(invalid.cast(&DataType::Uuid)?.null_count(), 1);
+
+        let sorted = uuids.sort(Default::default())?;
+        let sorted = sorted.cast(&DataType::String)?;
+        assert_eq!(
+            sorted.str()?.iter().collect::<Vec<_>>(),
+            [
+                None,
+                Some("00000000-0000-0000-0000-000000000001"),
+                Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                Some("ffffffff-ffff-ffff-ffff-ffffffffffff"),
+            ]
+        );
+
+        let arr = uuids.to_arrow(0, CompatLevel::newest());
+        let out = Series::try_from((PlSmallStr::EMPTY, arr))?;
+        assert_eq!(out.dtype(), &DataType::Uuid);
+        assert!(out.equals_missing(&uuids));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "random")]
+    fn test_uuid_v4() {
+        let ca = UuidChunked::new_v4("a".into(), 8, Some(0));
+        assert_eq!(ca.n_unique().unwrap(), 8);
+        for i in 0..ca.len() {
+            assert_eq!(ca.get_uuid(i).unwrap().get_version_num(), 4);
+        }
+        assert!(
+            ca.physical()
+                .equal(UuidChunked::new_v4("a".into(), 8, Some(0)).physical())
+                .all()
+        );
+    }
+}
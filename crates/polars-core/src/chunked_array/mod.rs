@@ -29,6 +29,8 @@ mod binary;
 mod bitwise;
 #[cfg(feature = "object")]
 mod drop;
+#[cfg(feature = "dtype-fixed-size-binary")]
+pub(crate) mod fixed_size_binary;
 mod from;
 mod from_iterator;
 pub mod from_iterator_par;
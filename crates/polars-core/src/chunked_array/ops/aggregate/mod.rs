@@ -2,7 +2,7 @@
 mod quantile;
 mod var;
 
-use arrow::types::NativeType;
+use arrow::types::{NativeType, PrimitiveType};
 use num_traits::{Float, One, ToPrimitive, Zero};
 use polars_compute::float_sum;
 use polars_compute::min_max::MinMaxKernel;
@@ -61,8 +61,16 @@ where
                     std::mem::transmute::<&PrimitiveArray<T>, &PrimitiveArray<f64>>(array);
                 let sum = float_sum::sum_arr_as_f64(f64_arr);
                 std::mem::transmute_copy::<f64, T>(&sum)
+            } else if T::PRIMITIVE == PrimitiveType::Float16 {
+                // Accumulate in f32 and round once at the end.
+                let f16_arr = std::mem::transmute::<
+                    &PrimitiveArray<T>,
+                    &PrimitiveArray<arrow::types::f16>,
+                >(array);
+                let sum = arrow::types::f16::from_f32(float_sum::sum_arr_as_f32(f16_arr));
+                std::mem::transmute_copy::<arrow::types::f16, T>(&sum)
             } else {
-                unreachable!("only supported float types are f32 and f64");
+                unreachable!("only supported float types are f16, f32 and f64");
             }
         }
     } else {
@@ -310,6 +318,19 @@ impl VarAggSeries for Float32Chunked {
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl VarAggSeries for Float16Chunked {
+    fn var_reduce(&self, ddof: u8) -> Scalar {
+        let v = self.var(ddof).map(arrow::types::f16::from_f64);
+        Scalar::new(DataType::Float16, v.into())
+    }
+
+    fn std_reduce(&self, ddof: u8) -> Scalar {
+        let v = self.std(ddof).map(arrow::types::f16::from_f64);
+        Scalar::new(DataType::Float16, v.into())
+    }
+}
+
 impl VarAggSeries for Float64Chunked {
     fn var_reduce(&self, ddof: u8) -> Scalar {
         let v = self.var(ddof);
@@ -350,6 +371,19 @@ impl QuantileAggSeries for Float32Chunked {
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl QuantileAggSeries for Float16Chunked {
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        let v = self.quantile(quantile, method)?;
+        Ok(Scalar::new(DataType::Float16, v.into()))
+    }
+
+    fn median_reduce(&self) -> Scalar {
+        let v = self.median();
+        Scalar::new(DataType::Float16, v.into())
+    }
+}
+
 impl QuantileAggSeries for Float64Chunked {
     fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
         let v = self.quantile(quantile, method)?;
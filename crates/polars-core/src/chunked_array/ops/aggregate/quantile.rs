@@ -1,6 +1,8 @@
 use polars_compute::rolling::QuantileMethod;
 
 use super::*;
+#[cfg(feature = "dtype-f16")]
+use crate::chunked_array::ops::arity::unary_elementwise_values;
 
 pub trait QuantileAggSeries {
     /// Get the median of the [`ChunkedArray`] as a new [`Series`] of length 1.
@@ -265,6 +267,19 @@ impl Float32Chunked {
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl ChunkQuantile<f16> for Float16Chunked {
+    fn quantile(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Option<f16>> {
+        // f32 represents every f16 exactly.
+        let ca: Float32Chunked = unary_elementwise_values(self, |v| v.to_f32());
+        Ok(ca.quantile(quantile, method)?.map(f16::from_f32))
+    }
+
+    fn median(&self) -> Option<f16> {
+        self.quantile(0.5, QuantileMethod::Linear).unwrap() // unwrap fine since quantile in range
+    }
+}
+
 impl ChunkQuantile<String> for StringChunked {}
 impl ChunkQuantile<Series> for ListChunked {}
 #[cfg(feature = "dtype-array")]
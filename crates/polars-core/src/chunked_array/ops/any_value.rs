@@ -41,11 +41,13 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
         DataType::UInt16 => downcast_and_pack!(UInt16Array, UInt16),
         DataType::UInt32 => downcast_and_pack!(UInt32Array, UInt32),
         DataType::UInt64 => downcast_and_pack!(UInt64Array, UInt64),
+        DataType::UInt128 => downcast_and_pack!(UInt128Array, UInt128),
         DataType::Int8 => downcast_and_pack!(Int8Array, Int8),
         DataType::Int16 => downcast_and_pack!(Int16Array, Int16),
         DataType::Int32 => downcast_and_pack!(Int32Array, Int32),
         DataType::Int64 => downcast_and_pack!(Int64Array, Int64),
         DataType::Int128 => downcast_and_pack!(Int128Array, Int128),
+        DataType::Float16 => downcast_and_pack!(Float16Array, Float16),
         DataType::Float32 => downcast_and_pack!(Float32Array, Float32),
         DataType::Float64 => downcast_and_pack!(Float64Array, Float64),
         DataType::List(dt) => {
@@ -128,6 +130,25 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Decimal(v, scale.unwrap_or_else(|| unreachable!()))
         },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(interval_from_i128(v))
+        },
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => {
+            let arr = &*(arr as *const dyn Array as *const PrimitiveArray<u128>);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Uuid(v)
+        },
+        #[cfg(feature = "dtype-json")]
+        DataType::Json => downcast_and_pack!(Utf8ViewArray, String),
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        DataType::FixedSizeBinary(_) => {
+            let arr = &*(arr as *const dyn Array as *const FixedSizeBinaryArray);
+            AnyValue::Binary(arr.value_unchecked(idx))
+        },
         #[cfg(feature = "object")]
         DataType::Object(_) => {
             // We should almost never hit this. The only known exception is when we put objects in
@@ -303,6 +324,18 @@ impl ChunkAnyValue for ArrayChunked {
     }
 }
 
+#[cfg(feature = "dtype-fixed-size-binary")]
+impl ChunkAnyValue for FixedSizeBinaryChunked {
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue {
+        get_any_value_unchecked!(self, index)
+    }
+
+    fn get_any_value(&self, index: usize) -> PolarsResult<AnyValue> {
+        get_any_value!(self, index)
+    }
+}
+
 #[cfg(feature = "object")]
 impl<T: PolarsObject> ChunkAnyValue for ObjectChunked<T> {
     #[inline]
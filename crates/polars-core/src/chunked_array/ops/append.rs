@@ -176,6 +176,24 @@ where
     }
 }
 
+/// Re-encodes the categoricals nested in `ca` to the rev-maps of the merged `dtype`.
+fn remap_nested_categoricals_to<T>(
+    ca: &mut ChunkedArray<T>,
+    #[allow(unused_variables)] dtype: &DataType,
+) -> PolarsResult<()>
+where
+    T: PolarsPhysicalType,
+    ChunkedArray<T>: IntoSeries,
+{
+    #[cfg(feature = "dtype-categorical")]
+    if dtype.contains_categoricals() {
+        if let Some(s) = remap_nested_categoricals(&ca.clone().into_series(), dtype)? {
+            *ca = s.as_ref().as_ref().clone();
+        }
+    }
+    Ok(())
+}
+
 #[doc(hidden)]
 impl ListChunked {
     pub fn append(&mut self, other: &Self) -> PolarsResult<()> {
@@ -184,6 +202,8 @@ impl ListChunked {
 
     pub fn append_owned(&mut self, mut other: Self) -> PolarsResult<()> {
         let dtype = merge_dtypes(self.dtype(), other.dtype())?;
+        remap_nested_categoricals_to(self, &dtype)?;
+        remap_nested_categoricals_to(&mut other, &dtype)?;
         self.field = Arc::new(Field::new(self.name().clone(), dtype));
 
         let len = self.len();
@@ -211,6 +231,8 @@ impl ArrayChunked {
 
     pub fn append_owned(&mut self, mut other: Self) -> PolarsResult<()> {
         let dtype = merge_dtypes(self.dtype(), other.dtype())?;
+        remap_nested_categoricals_to(self, &dtype)?;
+        remap_nested_categoricals_to(&mut other, &dtype)?;
         self.field = Arc::new(Field::new(self.name().clone(), dtype));
 
         let len = self.len();
@@ -237,6 +259,8 @@ impl StructChunked {
 
     pub fn append_owned(&mut self, mut other: Self) -> PolarsResult<()> {
         let dtype = merge_dtypes(self.dtype(), other.dtype())?;
+        remap_nested_categoricals_to(self, &dtype)?;
+        remap_nested_categoricals_to(&mut other, &dtype)?;
         self.field = Arc::new(Field::new(self.name().clone(), dtype));
 
         let len = self.len();
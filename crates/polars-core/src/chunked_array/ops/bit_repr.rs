@@ -128,6 +128,16 @@ where
                 }
 
                 reinterpret_chunked_array(self)
+            } else if matches!(self.dtype(), DataType::Float16) {
+                // A value cast would merge distinct floats, so widen the raw bits instead.
+                let chunks = self.downcast_iter().map(|arr| {
+                    let values = bytemuck::cast_slice::<_, u16>(arr.values().as_slice())
+                        .iter()
+                        .map(|v| *v as u32)
+                        .collect::<Vec<_>>();
+                    PrimitiveArray::from_data_default(values.into(), arr.validity().cloned())
+                });
+                UInt32Chunked::from_chunk_iter(self.name().clone(), chunks)
             } else {
                 // SAFETY: an unchecked cast to uint32 (which has no invariants) is
                 // always sound.
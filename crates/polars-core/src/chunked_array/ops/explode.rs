@@ -166,6 +166,19 @@ impl ExplodeByOffsets for Float64Chunked {
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+impl ExplodeByOffsets for Float16Chunked {
+    fn explode_by_offsets(&self, offsets: &[i64], skip_empty: bool) -> Series {
+        // Every f16 is exactly representable as f32, so this round-trip is lossless.
+        let ca = self.cast(&DataType::Float32).unwrap();
+        ca.f32()
+            .unwrap()
+            .explode_by_offsets(offsets, skip_empty)
+            .cast(&DataType::Float16)
+            .unwrap()
+    }
+}
+
 impl ExplodeByOffsets for NullChunked {
     fn explode_by_offsets(&self, offsets: &[i64], skip_empty: bool) -> Series {
         let mut last_offset = offsets[0];
@@ -174,6 +174,16 @@ impl ArrayChunked {
     }
 }
 
+#[cfg(feature = "dtype-fixed-size-binary")]
+#[doc(hidden)]
+impl FixedSizeBinaryChunked {
+    pub fn extend(&mut self, other: &Self) -> PolarsResult<()> {
+        // TODO! properly implement mutation
+        self.set_sorted_flag(IsSorted::Not);
+        self.append(other)
+    }
+}
+
 #[cfg(feature = "dtype-struct")]
 #[doc(hidden)]
 impl StructChunked {
@@ -83,11 +83,13 @@ pub fn get_row_encoding_context(dtype: &DataType, ordered: bool) -> Option<RowEn
         | DataType::UInt16
         | DataType::UInt32
         | DataType::UInt64
+        | DataType::UInt128
         | DataType::Int8
         | DataType::Int16
         | DataType::Int32
         | DataType::Int64
         | DataType::Int128
+        | DataType::Float16
         | DataType::Float32
         | DataType::Float64
         | DataType::String
@@ -98,6 +100,14 @@ pub fn get_row_encoding_context(dtype: &DataType, ordered: bool) -> Option<RowEn
         | DataType::Date
         | DataType::Datetime(_, _)
         | DataType::Duration(_) => None,
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => None,
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        DataType::FixedSizeBinary(_) => None,
+        #[cfg(feature = "dtype-uuid")]
+        DataType::Uuid => None,
+        #[cfg(feature = "dtype-json")]
+        DataType::Json => None,
 
         DataType::Unknown(_) => panic!("Unsupported in row encoding"),
 
@@ -112,6 +122,8 @@ pub fn get_row_encoding_context(dtype: &DataType, ordered: bool) -> Option<RowEn
         #[cfg(feature = "dtype-array")]
         DataType::Array(dtype, _) => get_row_encoding_context(dtype, ordered),
         DataType::List(dtype) => get_row_encoding_context(dtype, ordered),
+        #[cfg(feature = "dtype-extension")]
+        DataType::Extension { storage, .. } => get_row_encoding_context(storage, ordered),
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(revmap, ordering) | DataType::Enum(revmap, ordering) => {
             let is_enum = dtype.is_enum();
@@ -209,6 +221,14 @@ pub fn get_row_encoding_context(dtype: &DataType, ordered: bool) -> Option<RowEn
 
             Some(RowEncodingContext::Struct(ctxts))
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(_, _) => {
+            get_row_encoding_context(&dtype.map_entries_dtype().unwrap(), ordered)
+        },
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(_) => {
+            get_row_encoding_context(&dtype.union_struct_dtype().unwrap(), ordered)
+        },
     }
 }
 
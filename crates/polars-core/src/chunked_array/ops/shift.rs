@@ -219,6 +219,31 @@ impl ChunkShift<StructType> for StructChunked {
     }
 }
 
+#[cfg(feature = "dtype-fixed-size-binary")]
+impl ChunkShift<FixedSizeBinaryType> for FixedSizeBinaryChunked {
+    fn shift(&self, periods: i64) -> ChunkedArray<FixedSizeBinaryType> {
+        // This has its own implementation because the full-null needs to know the size
+        let periods = clamp(periods, -(self.len() as i64), self.len() as i64);
+        let slice_offset = (-periods).max(0);
+        let length = self.len() - abs(periods) as usize;
+        let mut slice = self.slice(slice_offset, length);
+
+        let fill_length = abs(periods) as usize;
+        let fill = NullChunked::new(self.name().clone(), fill_length)
+            .cast(self.dtype(), Default::default())
+            .unwrap();
+        let mut fill = fill.fixed_size_binary().unwrap().clone();
+
+        if periods < 0 {
+            slice.append(&fill).unwrap();
+            slice
+        } else {
+            fill.append(&slice).unwrap();
+            fill
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
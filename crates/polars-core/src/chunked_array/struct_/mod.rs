@@ -295,6 +295,12 @@ impl StructChunked {
                 let array = builder.freeze().boxed();
                 Series::try_from((ca.name().clone(), array))
             },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(variants) => {
+                let out =
+                    self.cast_impl(&DataType::Struct(variants.clone()), cast_options, unchecked)?;
+                Ok(out.struct_()?.clone().into_union()?.into_series())
+            },
             _ => {
                 let fields = self
                     .fields_as_series()
@@ -33,6 +33,12 @@ pub fn verbose() -> bool {
     std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
 }
 
+/// Whether decimal arithmetic and reductions produce null instead of raising an error when a
+/// result does not fit in the output precision.
+pub fn decimal_overflow_to_null() -> bool {
+    std::env::var("POLARS_DECIMAL_OVERFLOW").as_deref() == Ok("null")
+}
+
 pub fn get_engine_affinity() -> String {
     std::env::var("POLARS_ENGINE_AFFINITY").unwrap_or_else(|_| "auto".to_string())
 }
@@ -98,11 +98,13 @@ enum SerializableDataType {
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Int8,
     Int16,
     Int32,
     Int64,
     Int128,
+    Float16,
     Float32,
     Float64,
     String,
@@ -116,6 +118,14 @@ enum SerializableDataType {
     Datetime(TimeUnit, Option<TimeZone>),
     // 64-bit integer representing difference between times in milli|micro|nano seconds
     Duration(TimeUnit),
+    #[cfg(feature = "dtype-interval")]
+    Interval,
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    FixedSizeBinary(usize),
+    #[cfg(feature = "dtype-uuid")]
+    Uuid,
+    #[cfg(feature = "dtype-json")]
+    Json,
     /// A 64-bit time representing elapsed time since midnight in the given TimeUnit.
     Time,
     List(Box<SerializableDataType>),
@@ -128,6 +138,16 @@ enum SerializableDataType {
     Unknown(UnknownKind),
     #[cfg(feature = "dtype-categorical")]
     Categorical(Option<Series>, CategoricalOrdering),
+    #[cfg(feature = "dtype-map")]
+    Map(Box<SerializableDataType>, Box<SerializableDataType>),
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>),
+    #[cfg(feature = "dtype-extension")]
+    Extension {
+        name: PlSmallStr,
+        metadata: Option<PlSmallStr>,
+        storage: Box<SerializableDataType>,
+    },
     #[cfg(feature = "dtype-decimal")]
     Decimal(Option<usize>, Option<usize>),
     #[cfg(feature = "dtype-categorical")]
@@ -145,11 +165,13 @@ impl From<&DataType> for SerializableDataType {
             UInt16 => Self::UInt16,
             UInt32 => Self::UInt32,
             UInt64 => Self::UInt64,
+            UInt128 => Self::UInt128,
             Int8 => Self::Int8,
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
             Int128 => Self::Int128,
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -158,6 +180,14 @@ impl From<&DataType> for SerializableDataType {
             Date => Self::Date,
             Datetime(tu, tz) => Self::Datetime(*tu, tz.clone()),
             Duration(tu) => Self::Duration(*tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(size) => Self::FixedSizeBinary(*size),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Self::Uuid,
+            #[cfg(feature = "dtype-json")]
+            Json => Self::Json,
             Time => Self::Time,
             List(dt) => Self::List(Box::new(dt.as_ref().into())),
             #[cfg(feature = "dtype-array")]
@@ -166,6 +196,23 @@ impl From<&DataType> for SerializableDataType {
             Unknown(kind) => Self::Unknown(*kind),
             #[cfg(feature = "dtype-struct")]
             Struct(flds) => Self::Struct(flds.clone()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(
+                Box::new(key.as_ref().into()),
+                Box::new(value.as_ref().into()),
+            ),
+            #[cfg(feature = "dtype-union")]
+            Union(variants) => Self::Union(variants.clone()),
+            #[cfg(feature = "dtype-extension")]
+            Extension {
+                name,
+                metadata,
+                storage,
+            } => Self::Extension {
+                name: name.clone(),
+                metadata: metadata.clone(),
+                storage: Box::new(storage.as_ref().into()),
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical(Some(rev_map), ordering) => Self::Categorical(
                 Some(
@@ -202,11 +249,13 @@ impl From<SerializableDataType> for DataType {
             UInt16 => Self::UInt16,
             UInt32 => Self::UInt32,
             UInt64 => Self::UInt64,
+            UInt128 => Self::UInt128,
             Int8 => Self::Int8,
             Int16 => Self::Int16,
             Int32 => Self::Int32,
             Int64 => Self::Int64,
             Int128 => Self::Int128,
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -215,6 +264,14 @@ impl From<SerializableDataType> for DataType {
             Date => Self::Date,
             Datetime(tu, tz) => Self::Datetime(tu, tz),
             Duration(tu) => Self::Duration(tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(size) => Self::FixedSizeBinary(size),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Self::Uuid,
+            #[cfg(feature = "dtype-json")]
+            Json => Self::Json,
             Time => Self::Time,
             List(dt) => Self::List(Box::new((*dt).into())),
             #[cfg(feature = "dtype-array")]
@@ -223,6 +280,20 @@ impl From<SerializableDataType> for DataType {
             Unknown(kind) => Self::Unknown(kind),
             #[cfg(feature = "dtype-struct")]
             Struct(flds) => Self::Struct(flds),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
+            #[cfg(feature = "dtype-union")]
+            Union(variants) => Self::Union(variants),
+            #[cfg(feature = "dtype-extension")]
+            Extension {
+                name,
+                metadata,
+                storage,
+            } => Self::Extension {
+                name,
+                metadata,
+                storage: Box::new((*storage).into()),
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical(Some(categories), ordering) => Self::Categorical(
                 Some(Arc::new(RevMapping::build_local(
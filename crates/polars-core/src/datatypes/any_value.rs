@@ -40,6 +40,8 @@ pub enum AnyValue<'a> {
     UInt32(u32),
     /// An unsigned 64-bit integer number.
     UInt64(u64),
+    /// An unsigned 128-bit integer number.
+    UInt128(u128),
     /// An 8-bit integer number.
     Int8(i8),
     /// A 16-bit integer number.
@@ -50,6 +52,8 @@ pub enum AnyValue<'a> {
     Int64(i64),
     /// A 128-bit integer number.
     Int128(i128),
+    /// A 16-bit floating point number.
+    Float16(f16),
     /// A 32-bit floating point number.
     Float32(f32),
     /// A 64-bit floating point number.
@@ -69,6 +73,12 @@ pub enum AnyValue<'a> {
     /// A 64-bit integer representing difference between date-times in [`TimeUnit`]
     #[cfg(feature = "dtype-duration")]
     Duration(i64, TimeUnit),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
+    /// A UUID, stored as its big-endian bytes.
+    #[cfg(feature = "dtype-uuid")]
+    Uuid(u128),
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     #[cfg(feature = "dtype-time")]
     Time(i64),
@@ -176,6 +186,10 @@ mod _serde {
         UInt32(u32),
         /// An unsigned 64-bit integer number.
         UInt64(u64),
+        /// An unsigned 128-bit integer number.
+        UInt128(u128),
+        /// A 16-bit floating point number.
+        Float16(f16),
         /// A 32-bit floating point number.
         Float32(f32),
         /// A 64-bit floating point number.
@@ -202,6 +216,14 @@ mod _serde {
         #[cfg(feature = "dtype-duration")]
         Duration(i64, TimeUnit),
 
+        /// A calendar interval of months, days and nanoseconds.
+        #[cfg(feature = "dtype-interval")]
+        Interval(i32, i32, i64),
+
+        /// A UUID, stored as its big-endian bytes.
+        #[cfg(feature = "dtype-uuid")]
+        Uuid(u128),
+
         /// A 64-bit time representing the elapsed time since midnight in nanoseconds
         #[cfg(feature = "dtype-time")]
         Time(i64),
@@ -229,6 +251,8 @@ mod _serde {
                 AnyValue::UInt16(v) => Self::UInt16(*v),
                 AnyValue::UInt32(v) => Self::UInt32(*v),
                 AnyValue::UInt64(v) => Self::UInt64(*v),
+                AnyValue::UInt128(v) => Self::UInt128(*v),
+                AnyValue::Float16(v) => Self::Float16(*v),
                 AnyValue::Float32(v) => Self::Float32(*v),
                 AnyValue::Float64(v) => Self::Float64(*v),
                 AnyValue::List(series) => Self::List(Cow::Borrowed(series)),
@@ -253,6 +277,12 @@ mod _serde {
                 #[cfg(feature = "dtype-duration")]
                 AnyValue::Duration(v, time_unit) => Self::Duration(*v, *time_unit),
 
+                #[cfg(feature = "dtype-interval")]
+                AnyValue::Interval(v) => Self::Interval(v.months(), v.days(), v.ns()),
+
+                #[cfg(feature = "dtype-uuid")]
+                AnyValue::Uuid(v) => Self::Uuid(*v),
+
                 #[cfg(feature = "dtype-time")]
                 AnyValue::Time(v) => Self::Time(*v),
 
@@ -299,6 +329,8 @@ mod _serde {
                 S::UInt16(v) => Self::UInt16(v),
                 S::UInt32(v) => Self::UInt32(v),
                 S::UInt64(v) => Self::UInt64(v),
+                S::UInt128(v) => Self::UInt128(v),
+                S::Float16(v) => Self::Float16(v),
                 S::Float32(v) => Self::Float32(v),
                 S::Float64(v) => Self::Float64(v),
                 S::List(v) => Self::List(v.into_owned()),
@@ -316,6 +348,12 @@ mod _serde {
                 },
                 #[cfg(feature = "dtype-duration")]
                 S::Duration(v, time_unit) => Self::Duration(v, time_unit),
+                #[cfg(feature = "dtype-interval")]
+                S::Interval(months, days, ns) => {
+                    Self::Interval(months_days_ns::new(months, days, ns))
+                },
+                #[cfg(feature = "dtype-uuid")]
+                S::Uuid(v) => Self::Uuid(v),
                 #[cfg(feature = "dtype-time")]
                 S::Time(v) => Self::Time(v),
                 #[cfg(feature = "dtype-array")]
@@ -341,6 +379,10 @@ impl AnyValue<'static> {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(unit) => AnyValue::Duration(0, *unit),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => AnyValue::Interval(months_days_ns::default()),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => AnyValue::Uuid(0),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_p, s) => {
                 AnyValue::Decimal(0, s.expect("unknown scale during execution"))
@@ -374,6 +416,8 @@ impl<'a> AnyValue<'a> {
             UInt16(_) => DataType::UInt16,
             UInt32(_) => DataType::UInt32,
             UInt64(_) => DataType::UInt64,
+            UInt128(_) => DataType::UInt128,
+            Float16(_) => DataType::Float16,
             Float32(_) => DataType::Float32,
             Float64(_) => DataType::Float64,
             String(_) | StringOwned(_) => DataType::String,
@@ -390,6 +434,10 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-duration")]
             Duration(_, tu) => DataType::Duration(*tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(_) => DataType::Uuid,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _, _) | CategoricalOwned(_, _, _) => {
                 DataType::Categorical(None, Default::default())
@@ -427,8 +475,12 @@ impl<'a> AnyValue<'a> {
             UInt16(v) => NumCast::from(*v),
             UInt32(v) => NumCast::from(*v),
             UInt64(v) => NumCast::from(*v),
+            UInt128(v) => NumCast::from(*v),
+            Float16(v) => NumCast::from(*v),
             Float32(v) => NumCast::from(*v),
             Float64(v) => NumCast::from(*v),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-date")]
             Date(v) => NumCast::from(*v),
             #[cfg(feature = "dtype-datetime")]
@@ -478,7 +530,10 @@ impl<'a> AnyValue<'a> {
     }
 
     pub fn is_float(&self) -> bool {
-        matches!(self, AnyValue::Float32(_) | AnyValue::Float64(_))
+        matches!(
+            self,
+            AnyValue::Float16(_) | AnyValue::Float32(_) | AnyValue::Float64(_)
+        )
     }
 
     pub fn is_integer(&self) -> bool {
@@ -499,12 +554,17 @@ impl<'a> AnyValue<'a> {
     pub fn is_unsigned_integer(&self) -> bool {
         matches!(
             self,
-            AnyValue::UInt8(_) | AnyValue::UInt16(_) | AnyValue::UInt32(_) | AnyValue::UInt64(_)
+            AnyValue::UInt8(_)
+                | AnyValue::UInt16(_)
+                | AnyValue::UInt32(_)
+                | AnyValue::UInt64(_)
+                | AnyValue::UInt128(_)
         )
     }
 
     pub fn is_nan(&self) -> bool {
         match self {
+            AnyValue::Float16(f) => f.is_nan(),
             AnyValue::Float32(f) => f.is_nan(),
             AnyValue::Float64(f) => f.is_nan(),
             _ => false,
@@ -536,11 +596,13 @@ impl<'a> AnyValue<'a> {
             (av, DataType::UInt16) => AnyValue::UInt16(av.extract::<u16>()?),
             (av, DataType::UInt32) => AnyValue::UInt32(av.extract::<u32>()?),
             (av, DataType::UInt64) => AnyValue::UInt64(av.extract::<u64>()?),
+            (av, DataType::UInt128) => AnyValue::UInt128(av.extract::<u128>()?),
             (av, DataType::Int8) => AnyValue::Int8(av.extract::<i8>()?),
             (av, DataType::Int16) => AnyValue::Int16(av.extract::<i16>()?),
             (av, DataType::Int32) => AnyValue::Int32(av.extract::<i32>()?),
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
             (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
+            (av, DataType::Float16) => AnyValue::Float16(av.extract::<f16>()?),
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
 
@@ -549,14 +611,28 @@ impl<'a> AnyValue<'a> {
             (AnyValue::UInt16(v), DataType::Boolean) => AnyValue::Boolean(*v != u16::default()),
             (AnyValue::UInt32(v), DataType::Boolean) => AnyValue::Boolean(*v != u32::default()),
             (AnyValue::UInt64(v), DataType::Boolean) => AnyValue::Boolean(*v != u64::default()),
+            (AnyValue::UInt128(v), DataType::Boolean) => AnyValue::Boolean(*v != u128::default()),
             (AnyValue::Int8(v), DataType::Boolean) => AnyValue::Boolean(*v != i8::default()),
             (AnyValue::Int16(v), DataType::Boolean) => AnyValue::Boolean(*v != i16::default()),
             (AnyValue::Int32(v), DataType::Boolean) => AnyValue::Boolean(*v != i32::default()),
             (AnyValue::Int64(v), DataType::Boolean) => AnyValue::Boolean(*v != i64::default()),
             (AnyValue::Int128(v), DataType::Boolean) => AnyValue::Boolean(*v != i128::default()),
+            (AnyValue::Float16(v), DataType::Boolean) => AnyValue::Boolean(*v != f16::default()),
             (AnyValue::Float32(v), DataType::Boolean) => AnyValue::Boolean(*v != f32::default()),
             (AnyValue::Float64(v), DataType::Boolean) => AnyValue::Boolean(*v != f64::default()),
 
+            // to and from uuid
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::Uuid(v), DataType::String) => {
+                AnyValue::StringOwned(uuid::Uuid::from_u128(*v).hyphenated().to_string().into())
+            },
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::String(v), DataType::Uuid) => AnyValue::Uuid(parse_uuid(v)?),
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::StringOwned(v), DataType::Uuid) => AnyValue::Uuid(parse_uuid(v)?),
+            #[cfg(feature = "dtype-uuid")]
+            (AnyValue::UInt128(v), DataType::Uuid) => AnyValue::Uuid(*v),
+
             // to string
             (AnyValue::String(v), DataType::String) => AnyValue::String(v),
             (AnyValue::StringOwned(v), DataType::String) => AnyValue::StringOwned(v.clone()),
@@ -669,6 +745,31 @@ impl<'a> AnyValue<'a> {
                 *tu_r,
             ),
 
+            // to interval
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Duration(v, tu), DataType::Interval) => {
+                let ns = match tu {
+                    TimeUnit::Nanoseconds => *v,
+                    TimeUnit::Microseconds => v.checked_mul(1_000i64)?,
+                    TimeUnit::Milliseconds => v.checked_mul(1_000_000i64)?,
+                };
+                AnyValue::Interval(months_days_ns::new(0, 0, ns))
+            },
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Interval(v), DataType::Duration(tu)) if v.months() == 0 => {
+                let ns = (v.days() as i64)
+                    .checked_mul(NS_IN_DAY)?
+                    .checked_add(v.ns())?;
+                AnyValue::Duration(
+                    match tu {
+                        TimeUnit::Nanoseconds => ns,
+                        TimeUnit::Microseconds => ns / 1_000i64,
+                        TimeUnit::Milliseconds => ns / 1_000_000i64,
+                    },
+                    *tu,
+                )
+            },
+
             // to decimal
             #[cfg(feature = "dtype-decimal")]
             (av, DataType::Decimal(prec, scale)) if av.is_integer() => {
@@ -768,11 +869,13 @@ impl<'a> AnyValue<'a> {
             | Self::UInt16(_)
             | Self::UInt32(_)
             | Self::UInt64(_)
+            | Self::UInt128(_)
             | Self::Int8(_)
             | Self::Int16(_)
             | Self::Int32(_)
             | Self::Int64(_)
             | Self::Int128(_)
+            | Self::Float16(_)
             | Self::Float32(_)
             | Self::Float64(_) => self,
 
@@ -786,6 +889,10 @@ impl<'a> AnyValue<'a> {
 
             #[cfg(feature = "dtype-duration")]
             Self::Duration(v, _) => Self::Int64(v),
+            #[cfg(feature = "dtype-interval")]
+            Self::Interval(v) => Self::Int128(interval_to_i128(v)),
+            #[cfg(feature = "dtype-uuid")]
+            Self::Uuid(v) => Self::UInt128(v),
             #[cfg(feature = "dtype-time")]
             Self::Time(v) => Self::Int64(v),
 
@@ -874,8 +981,10 @@ impl AnyValue<'_> {
             UInt16(v) => v.hash(state),
             UInt32(v) => v.hash(state),
             UInt64(v) => v.hash(state),
+            UInt128(v) => feature_gated!("dtype-u128", v.hash(state)),
             String(v) => v.hash(state),
             StringOwned(v) => v.hash(state),
+            Float16(v) => v.to_bits().hash(state),
             Float32(v) => v.to_ne_bytes().hash(state),
             Float64(v) => v.to_ne_bytes().hash(state),
             Binary(v) => v.hash(state),
@@ -912,6 +1021,10 @@ impl AnyValue<'_> {
                 v.hash(state);
                 tz.hash(state);
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(v) => v.hash(state),
             #[cfg(feature = "dtype-time")]
             Time(v) => v.hash(state),
             #[cfg(feature = "dtype-categorical")]
@@ -992,6 +1105,24 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn as_interval(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::Int128(v) => AnyValue::Interval(interval_from_i128(*v)),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create interval from other type. dtype: {dt}"),
+        }
+    }
+
+    #[cfg(feature = "dtype-uuid")]
+    pub(crate) fn as_uuid(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::UInt128(v) => AnyValue::Uuid(*v),
+            AnyValue::Null => AnyValue::Null,
+            dt => panic!("cannot create uuid from other type. dtype: {dt}"),
+        }
+    }
+
     #[cfg(feature = "dtype-time")]
     pub(crate) fn as_time(&self) -> AnyValue<'static> {
         match self {
@@ -1012,12 +1143,14 @@ impl<'a> AnyValue<'a> {
             AnyValue::Int32(v) => Some((*v).into()),
             AnyValue::Int64(v) => Some((*v).into()),
             AnyValue::Int128(v) => Some(*v),
+            AnyValue::UInt128(v) => i128::try_from(*v).ok(),
             _ => None,
         }
     }
 
     pub(crate) fn to_f64(&self) -> Option<f64> {
         match self {
+            AnyValue::Float16(v) => Some(v.to_f64()),
             AnyValue::Float32(v) => Some((*v).into()),
             AnyValue::Float64(v) => Some(*v),
             _ => None,
@@ -1091,7 +1224,9 @@ impl<'a> AnyValue<'a> {
             UInt16(v) => UInt16(v),
             UInt32(v) => UInt32(v),
             UInt64(v) => UInt64(v),
+            UInt128(v) => UInt128(v),
             Boolean(v) => Boolean(v),
+            Float16(v) => Float16(v),
             Float32(v) => Float32(v),
             Float64(v) => Float64(v),
             #[cfg(feature = "dtype-datetime")]
@@ -1102,6 +1237,10 @@ impl<'a> AnyValue<'a> {
             Date(v) => Date(v),
             #[cfg(feature = "dtype-duration")]
             Duration(v, tu) => Duration(v, tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid(v) => Uuid(v),
             #[cfg(feature = "dtype-time")]
             Time(v) => Time(v),
             List(v) => List(v),
@@ -1257,11 +1396,13 @@ impl AnyValue<'_> {
             (UInt16(l), UInt16(r)) => *l == *r,
             (UInt32(l), UInt32(r)) => *l == *r,
             (UInt64(l), UInt64(r)) => *l == *r,
+            (UInt128(l), UInt128(r)) => *l == *r,
             (Int8(l), Int8(r)) => *l == *r,
             (Int16(l), Int16(r)) => *l == *r,
             (Int32(l), Int32(r)) => *l == *r,
             (Int64(l), Int64(r)) => *l == *r,
             (Int128(l), Int128(r)) => *l == *r,
+            (Float16(l), Float16(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float32(l), Float32(r)) => l.to_total_ord() == r.to_total_ord(),
             (Float64(l), Float64(r)) => l.to_total_ord() == r.to_total_ord(),
             (String(l), String(r)) => l == r,
@@ -1299,6 +1440,10 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l == r,
+            #[cfg(feature = "dtype-uuid")]
+            (Uuid(l), Uuid(r)) => l == r,
 
             #[cfg(feature = "dtype-struct")]
             (StructOwned(l), StructOwned(r)) => struct_eq_missing(
@@ -1439,11 +1584,13 @@ impl PartialOrd for AnyValue<'_> {
             (UInt16(l), UInt16(r)) => l.partial_cmp(r),
             (UInt32(l), UInt32(r)) => l.partial_cmp(r),
             (UInt64(l), UInt64(r)) => l.partial_cmp(r),
+            (UInt128(l), UInt128(r)) => l.partial_cmp(r),
             (Int8(l), Int8(r)) => l.partial_cmp(r),
             (Int16(l), Int16(r)) => l.partial_cmp(r),
             (Int32(l), Int32(r)) => l.partial_cmp(r),
             (Int64(l), Int64(r)) => l.partial_cmp(r),
             (Int128(l), Int128(r)) => l.partial_cmp(r),
+            (Float16(l), Float16(r)) => Some(l.tot_cmp(r)),
             (Float32(l), Float32(r)) => Some(l.tot_cmp(r)),
             (Float64(l), Float64(r)) => Some(l.tot_cmp(r)),
             (String(l), String(r)) => l.partial_cmp(r),
@@ -1468,6 +1615,10 @@ impl PartialOrd for AnyValue<'_> {
 
                 lt.partial_cmp(rt)
             },
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => Some(l.tot_cmp(r)),
+            #[cfg(feature = "dtype-uuid")]
+            (Uuid(l), Uuid(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-categorical")]
@@ -1680,6 +1831,26 @@ impl GetAnyValue for ArrayRef {
                     Some(v) => AnyValue::UInt64(v),
                 }
             },
+            ArrowDataType::UInt128 => {
+                let arr = self
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<u128>>()
+                    .unwrap_unchecked();
+                match arr.get_unchecked(index) {
+                    None => AnyValue::Null,
+                    Some(v) => AnyValue::UInt128(v),
+                }
+            },
+            ArrowDataType::Float16 => {
+                let arr = self
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<f16>>()
+                    .unwrap_unchecked();
+                match arr.get_unchecked(index) {
+                    None => AnyValue::Null,
+                    Some(v) => AnyValue::Float16(v),
+                }
+            },
             ArrowDataType::Float32 => {
                 let arr = self
                     .as_any()
@@ -1738,6 +1909,12 @@ impl<K: NumericNative> From<K> for AnyValue<'static> {
                 PrimitiveType::UInt16 => AnyValue::UInt16(NumCast::from(value).unwrap_unchecked()),
                 PrimitiveType::UInt32 => AnyValue::UInt32(NumCast::from(value).unwrap_unchecked()),
                 PrimitiveType::UInt64 => AnyValue::UInt64(NumCast::from(value).unwrap_unchecked()),
+                PrimitiveType::UInt128 => {
+                    AnyValue::UInt128(NumCast::from(value).unwrap_unchecked())
+                },
+                PrimitiveType::Float16 => {
+                    AnyValue::Float16(NumCast::from(value).unwrap_unchecked())
+                },
                 PrimitiveType::Float32 => {
                     AnyValue::Float32(NumCast::from(value).unwrap_unchecked())
                 },
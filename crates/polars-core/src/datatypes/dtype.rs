@@ -1,6 +1,16 @@
 use std::collections::BTreeMap;
 
+#[cfg(any(
+    feature = "dtype-extension",
+    feature = "dtype-uuid",
+    feature = "dtype-json"
+))]
+use arrow::datatypes::ExtensionType;
+#[cfg(feature = "dtype-interval")]
+use arrow::datatypes::IntervalUnit;
 use arrow::datatypes::{DTYPE_CATEGORICAL, DTYPE_ENUM_VALUES, Metadata};
+#[cfg(feature = "dtype-union")]
+use arrow::datatypes::{UnionMode, UnionType};
 #[cfg(feature = "dtype-array")]
 use polars_utils::format_tuple;
 use polars_utils::itertools::Itertools;
@@ -17,6 +27,26 @@ use crate::utils::materialize_dyn_int;
 static MAINTAIN_PL_TYPE: &str = "maintain_type";
 static PL_KEY: &str = "pl";
 
+/// Names of the fields of the physical `List(Struct)` representation of a [`DataType::Map`].
+#[cfg(feature = "dtype-map")]
+pub const MAP_ENTRIES_NAME: &str = "entries";
+#[cfg(feature = "dtype-map")]
+pub const MAP_KEY_NAME: &str = "key";
+#[cfg(feature = "dtype-map")]
+pub const MAP_VALUE_NAME: &str = "value";
+
+/// Name of the canonical Arrow extension type a [`DataType::Uuid`] is exported as.
+#[cfg(feature = "dtype-uuid")]
+pub const UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
+/// Name of the canonical Arrow extension type a [`DataType::Json`] is exported as.
+#[cfg(feature = "dtype-json")]
+pub const JSON_EXTENSION_NAME: &str = "arrow.json";
+
+/// Name of the type id field of the physical `Struct` representation of a [`DataType::Union`].
+#[cfg(feature = "dtype-union")]
+pub const UNION_TYPE_ID_NAME: &str = "__type_id";
+
 pub trait MetaDataExt: IntoMetadata {
     fn is_enum(&self) -> bool {
         let metadata = self.into_metadata_ref();
@@ -98,11 +128,13 @@ pub enum DataType {
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Int8,
     Int16,
     Int32,
     Int64,
     Int128,
+    Float16,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -114,6 +146,18 @@ pub enum DataType {
     String,
     Binary,
     BinaryOffset,
+    /// Binary data with the same number of bytes in each row, stored contiguously without
+    /// offsets like the Arrow `FixedSizeBinary` type.
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    FixedSizeBinary(usize),
+    /// A 128-bit universally unique identifier, stored as a big-endian `u128` so that ordering
+    /// the integers orders the UUIDs by their bytes.
+    #[cfg(feature = "dtype-uuid")]
+    Uuid,
+    /// Text that is guaranteed to hold a valid JSON document in every non-null row. Casting to
+    /// this type validates the documents and stores them without whitespace outside of strings.
+    #[cfg(feature = "dtype-json")]
+    Json,
     /// A 32-bit date representing the elapsed time since UNIX epoch (1970-01-01)
     /// in days (32 bits).
     Date,
@@ -122,6 +166,12 @@ pub enum DataType {
     Datetime(TimeUnit, Option<TimeZone>),
     /// 64-bit integer representing difference between times in milliseconds or nanoseconds
     Duration(TimeUnit),
+    /// A calendar interval of months, days and nanoseconds.
+    ///
+    /// Unlike a [`DataType::Duration`] the length of an interval depends on the point in time it
+    /// is added to, e.g. `1 month` after `2024-01-31` is `2024-02-29`.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
     /// A nested list with a fixed size in each row
@@ -143,6 +193,22 @@ pub enum DataType {
     Enum(Option<Arc<RevMapping>>, CategoricalOrdering),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A map from keys to values, stored as a list of `{key, value}` structs in each row.
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
+    /// A storage type tagged with an Arrow extension name (`ARROW:extension:name`) and its
+    /// optional serialized metadata. Behavior can be customized per name with an
+    /// [`ExtensionTypeImpl`](crate::datatypes::ExtensionTypeImpl).
+    #[cfg(feature = "dtype-extension")]
+    Extension {
+        name: PlSmallStr,
+        metadata: Option<PlSmallStr>,
+        storage: Box<DataType>,
+    },
+    /// A value of one of the variant fields in each row, stored as a struct of an `Int8` type id
+    /// and a field per variant that is only valid in the rows of that variant.
+    #[cfg(feature = "dtype-union")]
+    Union(Vec<Field>),
     // some logical types we cannot know statically, e.g. Datetime
     Unknown(UnknownKind),
 }
@@ -200,6 +266,25 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-map")]
+                (Map(l_key, l_value), Map(r_key, r_value)) => l_key == r_key && l_value == r_value,
+                #[cfg(feature = "dtype-fixed-size-binary")]
+                (FixedSizeBinary(l_size), FixedSizeBinary(r_size)) => l_size == r_size,
+                #[cfg(feature = "dtype-extension")]
+                (
+                    Extension {
+                        name: l_name,
+                        metadata: l_metadata,
+                        storage: l_storage,
+                    },
+                    Extension {
+                        name: r_name,
+                        metadata: r_metadata,
+                        storage: r_storage,
+                    },
+                ) => l_name == r_name && l_metadata == r_metadata && l_storage == r_storage,
+                #[cfg(feature = "dtype-union")]
+                (Union(lhs), Union(rhs)) => lhs == rhs,
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -250,6 +335,12 @@ impl DataType {
             DataType::Array(inner, _) => inner.is_known(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { storage, .. } => storage.is_known(),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
             DataType::Unknown(_) => false,
             _ => true,
         }
@@ -289,6 +380,33 @@ impl DataType {
                     })
                     .try_collect_vec()?,
             )),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown(allow_unknown)?),
+                Box::new(value.materialize_unknown(allow_unknown)?),
+            )),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension {
+                name,
+                metadata,
+                storage,
+            } => Ok(DataType::Extension {
+                name,
+                metadata,
+                storage: Box::new(storage.materialize_unknown(allow_unknown)?),
+            }),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields) => Ok(DataType::Union(
+                fields
+                    .into_iter()
+                    .map(|f| {
+                        PolarsResult::Ok(Field::new(
+                            f.name,
+                            f.dtype.materialize_unknown(allow_unknown)?,
+                        ))
+                    })
+                    .try_collect_vec()?,
+            )),
             _ => Ok(self),
         }
     }
@@ -414,6 +532,35 @@ impl DataType {
 
                 true
             },
+            #[cfg(feature = "dtype-map")]
+            (D::Map(l_key, l_value), D::Map(r_key, r_value)) => {
+                l_key.can_cast_to(r_key)? && l_value.can_cast_to(r_value)?
+            },
+            #[cfg(feature = "dtype-map")]
+            (D::Map(_, _), D::List(inner)) | (D::List(inner), D::Map(_, _)) => {
+                inner.is_struct() || inner.is_null()
+            },
+            #[cfg(feature = "dtype-union")]
+            (D::Union(l_fields), D::Union(r_fields)) => {
+                l_fields.len() == r_fields.len()
+                    && l_fields
+                        .iter()
+                        .zip(r_fields)
+                        .all(|(l, r)| l.dtype().can_cast_to(r.dtype()) == Some(true))
+            },
+            #[cfg(feature = "dtype-union")]
+            (D::Union(_), D::Struct(_)) | (D::Struct(_), D::Union(_)) => true,
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            (D::FixedSizeBinary(_), D::Binary) | (D::Binary, D::FixedSizeBinary(_)) => true,
+            #[cfg(feature = "dtype-uuid")]
+            (D::Uuid, D::String | D::Binary | D::FixedSizeBinary(16) | D::UInt128)
+            | (D::String | D::Binary | D::FixedSizeBinary(16) | D::UInt128, D::Uuid) => true,
+            #[cfg(feature = "dtype-json")]
+            (D::Json, D::String | D::Binary) | (D::String | D::Binary, D::Json) => true,
+            #[cfg(feature = "dtype-extension")]
+            (D::Extension { storage, .. }, to) => storage.can_cast_to(to)?,
+            #[cfg(feature = "dtype-extension")]
+            (from, D::Extension { storage, .. }) => from.can_cast_to(storage)?,
 
             // @NOTE: we are being conversative
             _ => return None,
@@ -435,6 +582,12 @@ impl DataType {
             Time => Int64,
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128,
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => UInt128,
+            #[cfg(feature = "dtype-json")]
+            Json => String,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => UInt32,
             #[cfg(feature = "dtype-array")]
@@ -448,6 +601,12 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => List(Box::new(self.map_entries_dtype().unwrap().to_physical())),
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => storage.to_physical(),
+            #[cfg(feature = "dtype-union")]
+            Union(_) => self.union_struct_dtype().unwrap().to_physical(),
             _ => self.clone(),
         }
     }
@@ -505,7 +664,85 @@ impl DataType {
     }
 
     pub fn is_nested(&self) -> bool {
-        self.is_list() || self.is_struct() || self.is_array()
+        self.is_list() || self.is_struct() || self.is_array() || self.is_map() || self.is_union()
+    }
+
+    /// Check if this [`DataType`] is a union
+    pub fn is_union(&self) -> bool {
+        #[cfg(feature = "dtype-union")]
+        {
+            matches!(self, DataType::Union(_))
+        }
+        #[cfg(not(feature = "dtype-union"))]
+        {
+            false
+        }
+    }
+
+    /// Get the dtype of the physical struct of a union: the `Int8` type id followed by the
+    /// variant fields.
+    #[cfg(feature = "dtype-union")]
+    pub fn union_struct_dtype(&self) -> Option<DataType> {
+        match self {
+            DataType::Union(fields) => {
+                let type_id =
+                    Field::new(PlSmallStr::from_static(UNION_TYPE_ID_NAME), DataType::Int8);
+                Some(DataType::Struct(
+                    std::iter::once(type_id)
+                        .chain(fields.iter().cloned())
+                        .collect(),
+                ))
+            },
+            _ => None,
+        }
+    }
+
+    /// Check if this [`DataType`] is a map
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(_, _))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is an extension type
+    pub fn is_extension(&self) -> bool {
+        #[cfg(feature = "dtype-extension")]
+        {
+            matches!(self, DataType::Extension { .. })
+        }
+        #[cfg(not(feature = "dtype-extension"))]
+        {
+            false
+        }
+    }
+
+    /// Get the storage dtype of an extension type, or `self` for any other dtype.
+    pub fn extension_storage(&self) -> &DataType {
+        match self {
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { storage, .. } => storage,
+            _ => self,
+        }
+    }
+
+    /// Get the dtype of the entries of a map: a struct with a `key` and a `value` field.
+    #[cfg(feature = "dtype-map")]
+    pub fn map_entries_dtype(&self) -> Option<DataType> {
+        match self {
+            DataType::Map(key, value) => Some(DataType::Struct(vec![
+                Field::new(PlSmallStr::from_static(MAP_KEY_NAME), key.as_ref().clone()),
+                Field::new(
+                    PlSmallStr::from_static(MAP_VALUE_NAME),
+                    value.as_ref().clone(),
+                ),
+            ])),
+            _ => None,
+        }
     }
 
     /// Check if this [`DataType`] is a struct
@@ -550,6 +787,8 @@ impl DataType {
         use DataType::*;
         match self {
             Binary | String => true,
+            #[cfg(feature = "dtype-json")]
+            Json => true,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => true,
             List(inner) => inner.contains_views(),
@@ -557,6 +796,12 @@ impl DataType {
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => storage.contains_views(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields) => fields.iter().any(|field| field.dtype.contains_views()),
             _ => false,
         }
     }
@@ -573,6 +818,14 @@ impl DataType {
             Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_categoricals()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_categoricals() || value.contains_categoricals(),
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => storage.contains_categoricals(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields) => fields
+                .iter()
+                .any(|field| field.dtype.contains_categoricals()),
             _ => false,
         }
     }
@@ -587,6 +840,12 @@ impl DataType {
             Array(inner, _) => inner.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_objects() || value.contains_objects(),
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => storage.contains_objects(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
             _ => false,
         }
     }
@@ -595,12 +854,20 @@ impl DataType {
         use DataType as D;
         match self {
             D::List(_) => true,
+            #[cfg(feature = "dtype-map")]
+            D::Map(_, _) => true,
             #[cfg(feature = "dtype-array")]
             D::Array(inner, _) => inner.contains_list_recursive(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_list_recursive()),
+            #[cfg(feature = "dtype-extension")]
+            D::Extension { storage, .. } => storage.contains_list_recursive(),
+            #[cfg(feature = "dtype-union")]
+            D::Union(fields) => fields
+                .iter()
+                .any(|field| field.dtype.contains_list_recursive()),
             _ => false,
         }
     }
@@ -614,6 +881,12 @@ impl DataType {
             D::Array(inner, _) => inner.contains_unknown(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields.iter().any(|field| field.dtype.contains_unknown()),
+            #[cfg(feature = "dtype-map")]
+            D::Map(key, value) => key.contains_unknown() || value.contains_unknown(),
+            #[cfg(feature = "dtype-extension")]
+            D::Extension { storage, .. } => storage.contains_unknown(),
+            #[cfg(feature = "dtype-union")]
+            D::Union(fields) => fields.iter().any(|field| field.dtype.contains_unknown()),
             _ => false,
         }
     }
@@ -649,7 +922,10 @@ impl DataType {
     pub fn is_float(&self) -> bool {
         matches!(
             self,
-            DataType::Float32 | DataType::Float64 | DataType::Unknown(UnknownKind::Float)
+            DataType::Float16
+                | DataType::Float32
+                | DataType::Float64
+                | DataType::Unknown(UnknownKind::Float)
         )
     }
 
@@ -666,6 +942,7 @@ impl DataType {
                 | DataType::UInt16
                 | DataType::UInt32
                 | DataType::UInt64
+                | DataType::UInt128
                 | DataType::Unknown(UnknownKind::Int(_))
         )
     }
@@ -681,7 +958,11 @@ impl DataType {
     pub fn is_unsigned_integer(&self) -> bool {
         matches!(
             self,
-            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64,
+            DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::UInt128,
         )
     }
 
@@ -762,6 +1043,8 @@ impl DataType {
             UInt16 => Scalar::from(u16::MAX),
             UInt32 => Scalar::from(u32::MAX),
             UInt64 => Scalar::from(u64::MAX),
+            UInt128 => Scalar::from(u128::MAX),
+            Float16 => Scalar::from(f16::INFINITY),
             Float32 => Scalar::from(f32::INFINITY),
             Float64 => Scalar::from(f64::INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -784,6 +1067,8 @@ impl DataType {
             UInt16 => Scalar::from(u16::MIN),
             UInt32 => Scalar::from(u32::MIN),
             UInt64 => Scalar::from(u64::MIN),
+            UInt128 => Scalar::from(u128::MIN),
+            Float16 => Scalar::from(f16::NEG_INFINITY),
             Float32 => Scalar::from(f32::NEG_INFINITY),
             Float64 => Scalar::from(f64::NEG_INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -808,11 +1093,13 @@ impl DataType {
             UInt16 => Ok(ArrowDataType::UInt16),
             UInt32 => Ok(ArrowDataType::UInt32),
             UInt64 => Ok(ArrowDataType::UInt64),
+            UInt128 => Ok(ArrowDataType::UInt128),
             Int8 => Ok(ArrowDataType::Int8),
             Int16 => Ok(ArrowDataType::Int16),
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
             Int128 => Ok(ArrowDataType::Int128),
+            Float16 => Ok(ArrowDataType::Float16),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
@@ -841,12 +1128,28 @@ impl DataType {
                 };
                 Ok(dt)
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(size) => Ok(ArrowDataType::FixedSizeBinary(*size)),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Ok(ArrowDataType::Extension(Box::new(ExtensionType {
+                name: PlSmallStr::from_static(UUID_EXTENSION_NAME),
+                inner: ArrowDataType::FixedSizeBinary(16),
+                metadata: None,
+            }))),
+            #[cfg(feature = "dtype-json")]
+            Json => Ok(ArrowDataType::Extension(Box::new(ExtensionType {
+                name: PlSmallStr::from_static(JSON_EXTENSION_NAME),
+                inner: String.try_to_arrow(compat_level)?,
+                metadata: None,
+            }))),
             Date => Ok(ArrowDataType::Date32),
             Datetime(unit, tz) => Ok(ArrowDataType::Timestamp(
                 unit.to_arrow(),
                 tz.as_deref().cloned(),
             )),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(ArrowDataType::Interval(IntervalUnit::MonthDayNano)),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
             #[cfg(feature = "dtype-array")]
             Array(dt, size) => Ok(dt
@@ -879,6 +1182,53 @@ impl DataType {
                     .collect();
                 Ok(ArrowDataType::Struct(fields))
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                // Map keys are not allowed to be null.
+                let fields = vec![
+                    ArrowField::new(
+                        PlSmallStr::from_static(MAP_KEY_NAME),
+                        key.try_to_arrow(compat_level)?,
+                        false,
+                    ),
+                    ArrowField::new(
+                        PlSmallStr::from_static(MAP_VALUE_NAME),
+                        value.try_to_arrow(compat_level)?,
+                        true,
+                    ),
+                ];
+                Ok(ArrowDataType::Map(
+                    Box::new(ArrowField::new(
+                        PlSmallStr::from_static(MAP_ENTRIES_NAME),
+                        ArrowDataType::Struct(fields),
+                        false,
+                    )),
+                    false,
+                ))
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension {
+                name,
+                metadata,
+                storage,
+            } => Ok(ArrowDataType::Extension(Box::new(ExtensionType {
+                name: name.clone(),
+                inner: storage.try_to_arrow(compat_level)?,
+                metadata: metadata.clone(),
+            }))),
+            #[cfg(feature = "dtype-union")]
+            Union(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|fld| fld.to_arrow(compat_level))
+                    .collect();
+                // Unions are always written in sparse mode with the variant index as type id.
+                Ok(ArrowDataType::Union(Box::new(UnionType {
+                    fields,
+                    ids: None,
+                    mode: UnionMode::Sparse,
+                })))
+            },
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
             Unknown(kind) => {
                 let dt = match kind {
@@ -903,6 +1253,12 @@ impl DataType {
             Array(field, _) => field.is_nested_null(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().all(|fld| fld.dtype.is_nested_null()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.is_nested_null() && value.is_nested_null(),
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => storage.is_nested_null(),
+            #[cfg(feature = "dtype-union")]
+            Union(fields) => fields.iter().all(|fld| fld.dtype.is_nested_null()),
             _ => false,
         }
     }
@@ -931,6 +1287,34 @@ impl DataType {
                 }
                 Ok(must_cast)
             },
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv), DataType::Map(rk, rv)) => {
+                Ok(lk.matches_schema_type(rk)? | lv.matches_schema_type(rv)?)
+            },
+            #[cfg(feature = "dtype-extension")]
+            (
+                DataType::Extension {
+                    name: l_name,
+                    storage: l_storage,
+                    ..
+                },
+                DataType::Extension {
+                    name: r_name,
+                    storage: r_storage,
+                    ..
+                },
+            ) if l_name == r_name => l_storage.matches_schema_type(r_storage),
+            #[cfg(feature = "dtype-union")]
+            (DataType::Union(l), DataType::Union(r)) => {
+                if l.len() != r.len() {
+                    polars_bail!(SchemaMismatch: "unions have different number of variants: {} vs {}", l.len(), r.len());
+                }
+                let mut must_cast = false;
+                for (l, r) in l.iter().zip(r.iter()) {
+                    must_cast |= l.dtype.matches_schema_type(&r.dtype)?;
+                }
+                Ok(must_cast)
+            },
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(_, s1), DataType::Decimal(_, s2)) => Ok(s1 != s2),
@@ -969,11 +1353,13 @@ impl Display for DataType {
             DataType::UInt16 => "u16",
             DataType::UInt32 => "u32",
             DataType::UInt64 => "u64",
+            DataType::UInt128 => "u128",
             DataType::Int8 => "i8",
             DataType::Int16 => "i16",
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
             DataType::Int128 => "i128",
+            DataType::Float16 => "f16",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
@@ -988,6 +1374,12 @@ impl Display for DataType {
             },
             DataType::String => "str",
             DataType::Binary => "binary",
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            DataType::FixedSizeBinary(size) => return write!(f, "fixed_size_binary[{size}]"),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => "uuid",
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => "json",
             DataType::Date => "date",
             DataType::Datetime(tu, tz) => {
                 let s = match tz {
@@ -997,6 +1389,8 @@ impl Display for DataType {
                 return f.write_str(&s);
             },
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => "interval",
             DataType::Time => "time",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
@@ -1019,6 +1413,12 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { name, .. } => return write!(f, "ext[{name}]"),
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(fields) => return write!(f, "union[{}]", fields.len()),
             DataType::Unknown(kind) => match kind {
                 UnknownKind::Any => "unknown",
                 UnknownKind::Int(_) => "dyn int",
@@ -1036,17 +1436,7 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
     Ok(match (left, right) {
         #[cfg(feature = "dtype-categorical")]
         (Categorical(Some(rev_map_l), ordering), Categorical(Some(rev_map_r), _)) => {
-            match (&**rev_map_l, &**rev_map_r) {
-                (RevMapping::Global(_, _, idl), RevMapping::Global(_, _, idr)) if idl == idr => {
-                    let mut merger = GlobalRevMapMerger::new(rev_map_l.clone());
-                    merger.merge_map(rev_map_r)?;
-                    Categorical(Some(merger.finish()), *ordering)
-                },
-                (RevMapping::Local(_, idl), RevMapping::Local(_, idr)) if idl == idr => {
-                    left.clone()
-                },
-                _ => polars_bail!(string_cache_mismatch),
-            }
+            Categorical(Some(merge_rev_maps(rev_map_l, rev_map_r)?), *ordering)
         },
         #[cfg(feature = "dtype-categorical")]
         (Enum(Some(rev_map_l), _), Enum(Some(rev_map_r), _)) => {
@@ -1077,6 +1467,38 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
             let merged = merge_dtypes(inner_l, inner_r)?;
             Array(Box::new(merged), *width_l)
         },
+        #[cfg(feature = "dtype-map")]
+        (Map(key_l, value_l), Map(key_r, value_r)) => Map(
+            Box::new(merge_dtypes(key_l, key_r)?),
+            Box::new(merge_dtypes(value_l, value_r)?),
+        ),
+        #[cfg(feature = "dtype-extension")]
+        (
+            Extension {
+                name: name_l,
+                metadata: metadata_l,
+                storage: storage_l,
+            },
+            Extension {
+                name: name_r,
+                metadata: metadata_r,
+                storage: storage_r,
+            },
+        ) if name_l == name_r && metadata_l == metadata_r => Extension {
+            name: name_l.clone(),
+            metadata: metadata_l.clone(),
+            storage: Box::new(merge_dtypes(storage_l, storage_r)?),
+        },
+        #[cfg(feature = "dtype-union")]
+        (Union(inner_l), Union(inner_r)) => {
+            polars_ensure!(inner_l.len() == inner_r.len(), ComputeError: "cannot combine unions with differing amounts of variants ({} != {})", inner_l.len(), inner_r.len());
+            let fields = inner_l.iter().zip(inner_r.iter()).map(|(l, r)| {
+                polars_ensure!(l.name() == r.name(), ComputeError: "cannot combine unions with different variants ({} != {})", l.name(), r.name());
+                let merged = merge_dtypes(l.dtype(), r.dtype())?;
+                Ok(Field::new(l.name().clone(), merged))
+            }).collect::<PolarsResult<Vec<_>>>()?;
+            Union(fields)
+        },
         (left, right) if left == right => left.clone(),
         _ => polars_bail!(ComputeError: "unable to merge datatypes"),
     })
@@ -1110,6 +1532,23 @@ fn collect_nested_types(
                 collect_nested_types(field.dtype(), result, include_compound_types);
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            if include_compound_types {
+                result.insert(dtype.clone());
+            }
+            collect_nested_types(key, result, include_compound_types);
+            collect_nested_types(value, result, include_compound_types);
+        },
+        #[cfg(feature = "dtype-union")]
+        DataType::Union(fields) => {
+            if include_compound_types {
+                result.insert(dtype.clone());
+            }
+            for field in fields {
+                collect_nested_types(field.dtype(), result, include_compound_types);
+            }
+        },
         _ => {
             result.insert(dtype.clone());
         },
@@ -0,0 +1,76 @@
+//! Registry of custom behavior for Arrow extension types.
+//!
+//! Arrays tagged with `ARROW:extension:name` are loaded as [`DataType::Extension`] and behave like
+//! their storage type. A crate that owns an extension name can register an [`ExtensionTypeImpl`]
+//! to change how values of that extension are displayed, cast and compared.
+use std::sync::{Arc, LazyLock, RwLock};
+
+use polars_utils::aliases::PlHashMap;
+
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// Custom behavior for an Arrow extension type, registered by its extension name with
+/// [`register_extension_type`].
+///
+/// Every method has a default that falls back to the storage type. Hashing, grouping and joins
+/// always use the storage values.
+pub trait ExtensionTypeImpl: Send + Sync {
+    /// Format a non-null storage `value` for display.
+    ///
+    /// Returning `None` formats the storage value.
+    fn format_value(&self, _value: &AnyValue<'_>, _metadata: Option<&str>) -> Option<String> {
+        None
+    }
+
+    /// Cast an extension column to `dtype`.
+    ///
+    /// Returning `None` casts the storage instead.
+    fn cast_from(
+        &self,
+        _ca: &ExtensionChunked,
+        _dtype: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Cast `s` to `dtype`, an extension dtype with the name of this registration.
+    ///
+    /// Returning `None` casts `s` to the storage type and tags the result.
+    fn cast_to(
+        &self,
+        _s: &Series,
+        _dtype: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Values that define the order of the extension values and the result of the comparison
+    /// operators, e.g. a lowercased string to compare case-insensitively. Must have the same length
+    /// as `ca`.
+    ///
+    /// Returning `None` compares the storage values.
+    fn comparison_values(&self, _ca: &ExtensionChunked) -> Option<PolarsResult<Series>> {
+        None
+    }
+}
+
+static EXTENSION_REGISTRY: LazyLock<RwLock<PlHashMap<PlSmallStr, Arc<dyn ExtensionTypeImpl>>>> =
+    LazyLock::new(Default::default);
+
+/// Register the behavior of the extension type `name`, replacing an earlier registration.
+pub fn register_extension_type(name: impl Into<PlSmallStr>, ext: Arc<dyn ExtensionTypeImpl>) {
+    EXTENSION_REGISTRY.write().unwrap().insert(name.into(), ext);
+}
+
+/// Remove the registration of the extension type `name`.
+pub fn unregister_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeImpl>> {
+    EXTENSION_REGISTRY.write().unwrap().remove(name)
+}
+
+/// Get the registered behavior of the extension type `name`.
+pub fn get_extension_type(name: &str) -> Option<Arc<dyn ExtensionTypeImpl>> {
+    EXTENSION_REGISTRY.read().unwrap().get(name).cloned()
+}
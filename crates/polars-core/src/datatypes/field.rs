@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-interval")]
+use arrow::datatypes::IntervalUnit;
 use arrow::datatypes::{DTYPE_ENUM_VALUES, Metadata};
 use polars_utils::pl_str::PlSmallStr;
 
@@ -154,6 +156,8 @@ impl DataType {
             ArrowDataType::UInt16 => DataType::UInt16,
             ArrowDataType::UInt32 => DataType::UInt32,
             ArrowDataType::UInt64 => DataType::UInt64,
+            #[cfg(feature = "dtype-u128")]
+            ArrowDataType::UInt128 => DataType::UInt128,
             ArrowDataType::Int8 => DataType::Int8,
             ArrowDataType::Int16 => DataType::Int16,
             ArrowDataType::Int32 => DataType::Int32,
@@ -161,6 +165,9 @@ impl DataType {
             #[cfg(feature = "dtype-i128")]
             ArrowDataType::Int128 => DataType::Int128,
             ArrowDataType::Boolean => DataType::Boolean,
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => DataType::Float16,
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => DataType::Float32,
             ArrowDataType::Float32 => DataType::Float32,
             ArrowDataType::Float64 => DataType::Float64,
@@ -176,6 +183,8 @@ impl DataType {
                 DataType::Datetime(tu.into(), TimeZone::opt_try_new(tz.clone()).unwrap())
             },
             ArrowDataType::Duration(tu) => DataType::Duration(tu.into()),
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => DataType::Interval,
             ArrowDataType::Date64 => DataType::Datetime(TimeUnit::Milliseconds, None),
             ArrowDataType::Time64(_) | ArrowDataType::Time32(_) => DataType::Time,
             #[cfg(feature = "dtype-categorical")]
@@ -232,6 +241,32 @@ impl DataType {
                     panic!("activate the 'object' feature to be able to load POLARS_EXTENSION_TYPE")
                 }
             },
+            #[cfg(feature = "dtype-uuid")]
+            ArrowDataType::Extension(ext)
+                if ext.name.as_str() == UUID_EXTENSION_NAME
+                    && ext.inner == ArrowDataType::FixedSizeBinary(16) =>
+            {
+                DataType::Uuid
+            },
+            #[cfg(feature = "dtype-json")]
+            ArrowDataType::Extension(ext)
+                if ext.name.as_str() == JSON_EXTENSION_NAME
+                    && matches!(
+                        ext.inner,
+                        ArrowDataType::Utf8View | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8
+                    ) =>
+            {
+                DataType::Json
+            },
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(ext) => DataType::Extension {
+                name: ext.name.clone(),
+                metadata: ext.metadata.clone(),
+                storage: Box::new(Self::from_arrow(&ext.inner, md)),
+            },
+            // Without support for extension types we load the storage.
+            #[cfg(not(feature = "dtype-extension"))]
+            ArrowDataType::Extension(ext) => Self::from_arrow(&ext.inner, md),
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => {
                 DataType::Decimal(Some(*precision), Some(*scale))
@@ -249,10 +284,24 @@ impl DataType {
                 }
             },
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            ArrowDataType::FixedSizeBinary(size) => DataType::FixedSizeBinary(*size),
+            #[cfg(not(feature = "dtype-fixed-size-binary"))]
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(inner, _is_sorted) => match inner.dtype().to_logical_type() {
+                ArrowDataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    Box::new(Self::from_arrow_field(&fields[0])),
+                    Box::new(Self::from_arrow_field(&fields[1])),
+                ),
+                _ => DataType::List(Self::from_arrow_field(inner).boxed()),
+            },
+            #[cfg(not(feature = "dtype-map"))]
             ArrowDataType::Map(inner, _is_sorted) => {
                 DataType::List(Self::from_arrow_field(inner).boxed())
             },
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(u) => DataType::Union(u.fields.iter().map(Field::from).collect()),
             dt => panic!(
                 "Arrow datatype {dt:?} not supported by Polars. \
                 You probably need to activate that data-type feature."
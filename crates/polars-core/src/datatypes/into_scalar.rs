@@ -59,3 +59,13 @@ impl_into_scalar! {
 impl_into_scalar! {
     i128: (T::Int128), // T::Decimal
 }
+
+#[cfg(feature = "dtype-u128")]
+impl_into_scalar! {
+    u128: (T::UInt128),
+}
+
+#[cfg(feature = "dtype-f16")]
+impl_into_scalar! {
+    super::f16: (T::Float16),
+}
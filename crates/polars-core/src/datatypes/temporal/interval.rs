@@ -0,0 +1,78 @@
+//! The physical representation of [`DataType::Interval`](crate::datatypes::DataType::Interval).
+//!
+//! An interval is stored as an `i128` so that all the machinery of the 128-bit integers
+//! (gathers, hashing, sorting, ...) can be reused. The packing is chosen such that ordering
+//! the integers orders the intervals by months, then days, then nanoseconds, which is the same
+//! order Arrow uses for `MonthDayNano` intervals.
+use arrow::array::PrimitiveArray;
+use arrow::datatypes::{ArrowDataType, IntervalUnit};
+use arrow::types::months_days_ns;
+
+const DAYS_SIGN: u32 = 1 << 31;
+const NS_SIGN: u64 = 1 << 63;
+
+/// Pack an interval into its physical `i128` representation.
+#[inline]
+pub fn interval_to_i128(v: months_days_ns) -> i128 {
+    // Flipping the sign bit of the lower parts makes their unsigned order equal to their signed
+    // order.
+    let days = (v.days() as u32 ^ DAYS_SIGN) as i128;
+    let ns = (v.ns() as u64 ^ NS_SIGN) as i128;
+    ((v.months() as i128) << 96) | (days << 64) | ns
+}
+
+/// Unpack the physical `i128` representation of an interval.
+#[inline]
+pub fn interval_from_i128(v: i128) -> months_days_ns {
+    let months = (v >> 96) as i32;
+    let days = ((v >> 64) as u32 ^ DAYS_SIGN) as i32;
+    let ns = (v as u64 ^ NS_SIGN) as i64;
+    months_days_ns::new(months, days, ns)
+}
+
+/// Convert an Arrow `MonthDayNano` interval array to its physical representation.
+pub fn interval_array_to_physical(arr: &PrimitiveArray<months_days_ns>) -> PrimitiveArray<i128> {
+    let values = arr.values().iter().map(|v| interval_to_i128(*v)).collect();
+    PrimitiveArray::new(ArrowDataType::Int128, values, arr.validity().cloned())
+}
+
+/// Convert the physical representation of intervals to an Arrow `MonthDayNano` interval array.
+pub fn interval_array_from_physical(arr: &PrimitiveArray<i128>) -> PrimitiveArray<months_days_ns> {
+    let values = arr
+        .values()
+        .iter()
+        .map(|v| interval_from_i128(*v))
+        .collect();
+    PrimitiveArray::new(
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano),
+        values,
+        arr.validity().cloned(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use polars_utils::total_ord::TotalOrd;
+
+    use super::*;
+
+    #[test]
+    fn test_interval_packing_roundtrip_and_order() {
+        let values = [
+            months_days_ns::new(-1, i32::MAX, i64::MAX),
+            months_days_ns::new(0, -3, 5),
+            months_days_ns::new(0, 0, -1),
+            months_days_ns::new(0, 0, 0),
+            months_days_ns::new(0, 1, i64::MIN),
+            months_days_ns::new(2, i32::MIN, 0),
+            months_days_ns::new(i32::MAX, 0, 0),
+        ];
+        for w in values.windows(2) {
+            assert!(w[0].tot_cmp(&w[1]).is_lt());
+            assert!(interval_to_i128(w[0]) < interval_to_i128(w[1]));
+        }
+        for v in values {
+            assert_eq!(interval_from_i128(interval_to_i128(v)), v);
+        }
+    }
+}
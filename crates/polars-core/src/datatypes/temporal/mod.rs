@@ -1,3 +1,7 @@
+#[cfg(feature = "dtype-interval")]
+mod interval;
 pub mod time_unit;
 pub mod time_zone;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
 pub use time_unit::TimeUnit;
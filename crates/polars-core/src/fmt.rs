@@ -243,6 +243,28 @@ fn format_object_array(
     }
 }
 
+/// Formats the values of an extension column with its registered behavior.
+#[cfg(feature = "dtype-extension")]
+struct ExtensionValues<'a>(&'a ExtensionChunked);
+
+#[cfg(feature = "dtype-extension")]
+impl ExtensionValues<'_> {
+    fn len(&self) -> usize {
+        self.0.storage().len()
+    }
+
+    fn dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn get_any_value(&self, index: usize) -> PolarsResult<String> {
+        match self.0.format_value(index) {
+            Some(v) => Ok(v),
+            None => Ok(self.0.storage().get(index)?.to_string()),
+        }
+    }
+}
+
 impl<T> Debug for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -365,6 +387,14 @@ impl Debug for Series {
                     format_array!(f, self.i128().unwrap(), "i128", self.name(), "Series")
                 )
             },
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                format_array!(f, self.u128().unwrap(), "u128", self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                format_array!(f, self.f16().unwrap(), "f16", self.name(), "Series")
+            },
             DataType::Float32 => {
                 format_array!(f, self.f32().unwrap(), "f32", self.name(), "Series")
             },
@@ -385,11 +415,36 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.duration().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                format_array!(
+                    f,
+                    self.interval().unwrap(),
+                    "interval",
+                    self.name(),
+                    "Series"
+                )
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.decimal().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            DataType::FixedSizeBinary(_) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(
+                    f,
+                    self.fixed_size_binary().unwrap(),
+                    &dt,
+                    self.name(),
+                    "Series"
+                )
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => format_array!(f, self.uuid().unwrap(), "uuid", self.name(), "Series"),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => format_array!(f, self.json().unwrap(), "json", self.name(), "Series"),
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -399,6 +454,22 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.list().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.union().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { .. } => {
+                let dt = format!("{}", self.dtype());
+                let values = ExtensionValues(self.extension().unwrap());
+                format_array!(f, values, &dt, self.name(), "Series")
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => format_object_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
@@ -1052,6 +1123,30 @@ pub fn fmt_duration_string<W: Write>(f: &mut W, v: i64, unit: TimeUnit) -> fmt::
     Ok(())
 }
 
+#[cfg(feature = "dtype-interval")]
+pub fn fmt_interval_string<W: Write>(f: &mut W, v: months_days_ns) -> fmt::Result {
+    // e.g. "1mo 2d 3h 4m"; the nanoseconds are written like a duration.
+    let mut buffer = itoa::Buffer::new();
+    let mut parts = [(v.months() as i64, "mo"), (v.days() as i64, "d")]
+        .into_iter()
+        .filter(|(n, _)| *n != 0)
+        .peekable();
+    if parts.peek().is_none() && v.ns() == 0 {
+        return f.write_str("0d");
+    }
+    while let Some((n, unit)) = parts.next() {
+        f.write_str(buffer.format(n))?;
+        f.write_str(unit)?;
+        if parts.peek().is_some() || v.ns() != 0 {
+            f.write_char(' ')?;
+        }
+    }
+    if v.ns() != 0 {
+        fmt_duration_string(f, v.ns(), TimeUnit::Nanoseconds)?;
+    }
+    Ok(())
+}
+
 #[cfg(feature = "dtype-duration")]
 pub fn iso_duration_string(s: &mut String, mut v: i64, unit: TimeUnit) {
     if v == 0 {
@@ -1159,6 +1254,8 @@ impl Display for AnyValue<'_> {
             AnyValue::Int32(v) => fmt_integer(f, width, *v),
             AnyValue::Int64(v) => fmt_integer(f, width, *v),
             AnyValue::Int128(v) => feature_gated!("dtype-i128", fmt_integer(f, width, *v)),
+            AnyValue::UInt128(v) => feature_gated!("dtype-u128", fmt_integer(f, width, *v)),
+            AnyValue::Float16(v) => fmt_float(f, width, v.to_f32()),
             AnyValue::Float32(v) => fmt_float(f, width, *v),
             AnyValue::Float64(v) => fmt_float(f, width, *v),
             AnyValue::Boolean(v) => write!(f, "{}", *v),
@@ -1176,6 +1273,10 @@ impl Display for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             AnyValue::Duration(v, tu) => fmt_duration_string(f, *v, *tu),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval_string(f, *v),
+            #[cfg(feature = "dtype-uuid")]
+            AnyValue::Uuid(v) => write!(f, "{}", uuid::Uuid::from_u128(*v).hyphenated()),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
@@ -320,6 +320,30 @@ impl Column {
     pub fn try_array(&self) -> Option<&ArrayChunked> {
         self.as_materialized_series().try_array()
     }
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        self.as_materialized_series().try_map()
+    }
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    pub fn try_fixed_size_binary(&self) -> Option<&FixedSizeBinaryChunked> {
+        self.as_materialized_series().try_fixed_size_binary()
+    }
+    #[cfg(feature = "dtype-uuid")]
+    pub fn try_uuid(&self) -> Option<&UuidChunked> {
+        self.as_materialized_series().try_uuid()
+    }
+    #[cfg(feature = "dtype-json")]
+    pub fn try_json(&self) -> Option<&JsonChunked> {
+        self.as_materialized_series().try_json()
+    }
+    #[cfg(feature = "dtype-extension")]
+    pub fn try_extension(&self) -> Option<&ExtensionChunked> {
+        self.as_materialized_series().try_extension()
+    }
+    #[cfg(feature = "dtype-union")]
+    pub fn try_union(&self) -> Option<&UnionChunked> {
+        self.as_materialized_series().try_union()
+    }
     #[cfg(feature = "dtype-categorical")]
     pub fn try_categorical(&self) -> Option<&CategoricalChunked> {
         self.as_materialized_series().try_categorical()
@@ -365,6 +389,14 @@ impl Column {
     pub fn u64(&self) -> PolarsResult<&UInt64Chunked> {
         self.as_materialized_series().u64()
     }
+    #[cfg(feature = "dtype-u128")]
+    pub fn u128(&self) -> PolarsResult<&UInt128Chunked> {
+        self.as_materialized_series().u128()
+    }
+    #[cfg(feature = "dtype-f16")]
+    pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
+        self.as_materialized_series().f16()
+    }
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         self.as_materialized_series().f32()
     }
@@ -402,6 +434,30 @@ impl Column {
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
         self.as_materialized_series().array()
     }
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.as_materialized_series().map()
+    }
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    pub fn fixed_size_binary(&self) -> PolarsResult<&FixedSizeBinaryChunked> {
+        self.as_materialized_series().fixed_size_binary()
+    }
+    #[cfg(feature = "dtype-uuid")]
+    pub fn uuid(&self) -> PolarsResult<&UuidChunked> {
+        self.as_materialized_series().uuid()
+    }
+    #[cfg(feature = "dtype-json")]
+    pub fn json(&self) -> PolarsResult<&JsonChunked> {
+        self.as_materialized_series().json()
+    }
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        self.as_materialized_series().extension()
+    }
+    #[cfg(feature = "dtype-union")]
+    pub fn union(&self) -> PolarsResult<&UnionChunked> {
+        self.as_materialized_series().union()
+    }
     #[cfg(feature = "dtype-categorical")]
     pub fn categorical(&self) -> PolarsResult<&CategoricalChunked> {
         self.as_materialized_series().categorical()
@@ -1484,6 +1540,10 @@ impl Column {
     }
 
     pub(crate) fn str_value(&self, index: usize) -> PolarsResult<Cow<str>> {
+        #[cfg(feature = "dtype-extension")]
+        if let Some(v) = self.try_extension().and_then(|ca| ca.format_value(index)) {
+            return Ok(Cow::Owned(v));
+        }
         Ok(self.get(index)?.str_value())
     }
 
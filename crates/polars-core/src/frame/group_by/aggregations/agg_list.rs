@@ -302,6 +302,35 @@ impl AggList for StructChunked {
     }
 }
 
+#[cfg(feature = "dtype-fixed-size-binary")]
+impl AggList for FixedSizeBinaryChunked {
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        let ca = self.clone();
+        let (gather, offsets, can_fast_explode) = groups.prepare_list_agg(self.len());
+
+        let gathered = if let Some(gather) = gather {
+            let out = ca.into_series().take_unchecked(&gather);
+            out.fixed_size_binary().unwrap().clone()
+        } else {
+            ca.rechunk().into_owned()
+        };
+
+        let arr = gathered.chunks()[0].clone();
+        let dtype = LargeListArray::default_datatype(arr.dtype().clone());
+
+        let mut chunk = ListChunked::with_chunk(
+            self.name().clone(),
+            LargeListArray::new(dtype, offsets, arr, None),
+        );
+        chunk.set_dtype(DataType::List(Box::new(self.dtype().clone())));
+        if can_fast_explode {
+            chunk.set_fast_explode()
+        }
+
+        chunk.into_series()
+    }
+}
+
 unsafe fn agg_list_by_gather_and_offsets<T: PolarsDataType>(
     ca: &ChunkedArray<T>,
     groups: &GroupsType,
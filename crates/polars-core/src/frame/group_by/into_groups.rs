@@ -200,6 +200,20 @@ where
                 };
                 num_groups_proxy(ca, multithreaded, sorted)
             },
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                // convince the compiler that we are this type.
+                let ca: &UInt128Chunked = unsafe {
+                    &*(self as *const ChunkedArray<T> as *const ChunkedArray<UInt128Type>)
+                };
+                num_groups_proxy(ca, multithreaded, sorted)
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                // f32 represents every f16 exactly, so the groups are the same.
+                let s = unsafe { self.cast_unchecked(&DataType::Float32).unwrap() };
+                return s.group_tuples(multithreaded, sorted);
+            },
             #[cfg(all(feature = "performant", feature = "dtype-i8", feature = "dtype-u8"))]
             DataType::Int8 => {
                 // convince the compiler that we are this type.
@@ -1165,9 +1165,11 @@ impl DataFrame {
             .zip(other.columns.iter())
             .try_for_each::<_, PolarsResult<_>>(|(left, right)| {
                 ensure_can_extend(&*left, right)?;
-                left.append(right).map_err(|e| {
-                    e.context(format!("failed to vstack column '{}'", right.name()).into())
-                })?;
+                let reordered = match_struct_field_order(left, right)?;
+                left.append(reordered.as_ref().unwrap_or(right))
+                    .map_err(|e| {
+                        e.context(format!("failed to vstack column '{}'", right.name()).into())
+                    })?;
                 Ok(())
             })?;
         self.height += other.height;
@@ -1192,6 +1194,7 @@ impl DataFrame {
             .zip(other.columns.into_iter())
             .try_for_each::<_, PolarsResult<_>>(|(left, right)| {
                 ensure_can_extend(&*left, &right)?;
+                let right = match_struct_field_order(left, &right)?.unwrap_or(right);
                 let right_name = right.name().clone();
                 left.append_owned(right).map_err(|e| {
                     e.context(format!("failed to vstack column '{right_name}'").into())
@@ -3464,6 +3467,20 @@ fn ensure_can_extend(left: &Column, right: &Column) -> PolarsResult<()> {
     Ok(())
 }
 
+/// Casts `right` to the dtype of `left` if only the order of its (nested) struct fields differs.
+fn match_struct_field_order(
+    #[allow(unused_variables)] left: &Column,
+    #[allow(unused_variables)] right: &Column,
+) -> PolarsResult<Option<Column>> {
+    #[cfg(feature = "dtype-struct")]
+    if left.dtype() != right.dtype()
+        && crate::utils::struct_fields_reordered(left.dtype(), right.dtype())
+    {
+        return right.cast(left.dtype()).map(Some);
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -3644,6 +3661,38 @@ mod test {
         assert_eq!(df.height, 6)
     }
 
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_vstack_reordered_struct_fields() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [1i32, 2]);
+        let b = Series::new("b".into(), ["x", "y"]);
+        let s = StructChunked::from_series("s".into(), 2, [a, b].iter())?.into_series();
+        let mut df = DataFrame::new(vec![s.into()])?;
+
+        let b = Series::new("b".into(), ["z"]);
+        let a = Series::new("a".into(), [3i32]);
+        let s = StructChunked::from_series("s".into(), 1, [b, a].iter())?.into_series();
+        let other = DataFrame::new(vec![s.into()])?;
+
+        df.vstack_mut(&other)?;
+        df.vstack_mut_owned(other)?;
+        let s = df.column("s")?.struct_()?.clone();
+        assert_eq!(
+            Vec::from(s.field_by_name("a")?.i32()?),
+            [Some(1), Some(2), Some(3), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(s.field_by_name("b")?.str()?),
+            [Some("x"), Some("y"), Some("z"), Some("z")]
+        );
+
+        // Structs that differ in more than the order of their fields still don't match.
+        let c = Series::new("c".into(), [3i32]);
+        let s = StructChunked::from_series("s".into(), 1, [c].iter())?.into_series();
+        assert!(df.vstack_mut(&DataFrame::new(vec![s.into()])?).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_replace_or_add() -> PolarsResult<()> {
         let mut df = df!(
@@ -181,8 +181,12 @@ vec_hash_numeric!(UInt16Chunked);
 vec_hash_numeric!(UInt8Chunked);
 vec_hash_numeric!(Float64Chunked);
 vec_hash_numeric!(Float32Chunked);
+#[cfg(feature = "dtype-f16")]
+vec_hash_numeric!(Float16Chunked);
 #[cfg(any(feature = "dtype-decimal", feature = "dtype-i128"))]
 vec_hash_numeric!(Int128Chunked);
+#[cfg(feature = "dtype-u128")]
+vec_hash_numeric!(UInt128Chunked);
 
 impl VecHash for StringChunked {
     fn vec_hash(
@@ -292,6 +296,37 @@ impl VecHash for BinaryChunked {
     }
 }
 
+#[cfg(feature = "dtype-fixed-size-binary")]
+impl VecHash for FixedSizeBinaryChunked {
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        let null_h = get_null_hash_value(&random_state);
+        buf.clear();
+        buf.reserve(self.len());
+        buf.extend(
+            self.iter()
+                .map(|opt_v| opt_v.map_or(null_h, |v| xxh3_64_with_seed(v, null_h))),
+        );
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        let null_h = get_null_hash_value(&random_state);
+        self.iter().zip(hashes).for_each(|(opt_v, h)| {
+            let l = opt_v.map_or(null_h, |v| xxh3_64_with_seed(v, null_h));
+            *h = _boost_hash_combine(l, *h)
+        });
+        Ok(())
+    }
+}
+
 impl VecHash for BinaryOffsetChunked {
     fn vec_hash(
         &self,
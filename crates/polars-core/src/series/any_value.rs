@@ -155,11 +155,21 @@ impl Series {
             DataType::UInt16 => any_values_to_integer::<UInt16Type>(values, strict)?.into_series(),
             DataType::UInt32 => any_values_to_integer::<UInt32Type>(values, strict)?.into_series(),
             DataType::UInt64 => any_values_to_integer::<UInt64Type>(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                any_values_to_integer::<UInt128Type>(values, strict)?.into_series()
+            },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => any_values_to_f16(values, strict)?.into_series(),
             DataType::Float32 => any_values_to_f32(values, strict)?.into_series(),
             DataType::Float64 => any_values_to_f64(values, strict)?.into_series(),
             DataType::Boolean => any_values_to_bool(values, strict)?.into_series(),
             DataType::String => any_values_to_string(values, strict)?.into_series(),
             DataType::Binary => any_values_to_binary(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            DataType::FixedSizeBinary(size) => any_values_to_binary(values, strict)?
+                .to_fixed_size_binary(*size, strict)?
+                .into_series(),
             DataType::BinaryOffset => any_values_to_binary_offset(values, strict)?.into_series(),
             #[cfg(feature = "dtype-date")]
             DataType::Date => any_values_to_date(values, strict)?.into_series(),
@@ -171,6 +181,14 @@ impl Series {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(tu) => any_values_to_duration(values, *tu, strict)?.into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => any_values_to_uuid(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => any_values_to_string(values, strict)?
+                .to_json(strict)?
+                .into_series(),
             #[cfg(feature = "dtype-categorical")]
             dt @ DataType::Categorical(_, _) => any_values_to_categorical(values, dt, strict)?,
             #[cfg(feature = "dtype-categorical")]
@@ -180,6 +198,21 @@ impl Series {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                any_values_to_list(values, &dtype.map_entries_dtype().unwrap(), strict)?
+                    .into_map()?
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(variants) => any_values_to_union(values, variants, strict)?,
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { storage, .. } => {
+                let storage =
+                    Series::from_any_values_and_dtype(PlSmallStr::EMPTY, values, storage, strict)?;
+                // SAFETY: the storage was built with the storage dtype of the extension.
+                unsafe { ExtensionChunked::new_unchecked(storage, dtype.clone()) }.into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
                 .into_series()
@@ -240,6 +273,26 @@ fn any_values_to_integer<T: PolarsIntegerType>(
     }
 }
 
+#[cfg(feature = "dtype-f16")]
+fn any_values_to_f16(values: &[AnyValue], strict: bool) -> PolarsResult<Float16Chunked> {
+    fn any_values_to_f16_strict(values: &[AnyValue]) -> PolarsResult<Float16Chunked> {
+        let mut builder =
+            PrimitiveChunkedBuilder::<Float16Type>::new(PlSmallStr::EMPTY, values.len());
+        for av in values {
+            match av {
+                AnyValue::Float16(i) => builder.append_value(*i),
+                AnyValue::Null => builder.append_null(),
+                av => return Err(invalid_value_error(&DataType::Float16, av)),
+            }
+        }
+        Ok(builder.finish())
+    }
+    if strict {
+        any_values_to_f16_strict(values)
+    } else {
+        Ok(any_values_to_primitive_nonstrict::<Float16Type>(values))
+    }
+}
 fn any_values_to_f32(values: &[AnyValue], strict: bool) -> PolarsResult<Float32Chunked> {
     fn any_values_to_f32_strict(values: &[AnyValue]) -> PolarsResult<Float32Chunked> {
         let mut builder =
@@ -247,6 +300,7 @@ fn any_values_to_f32(values: &[AnyValue], strict: bool) -> PolarsResult<Float32C
         for av in values {
             match av {
                 AnyValue::Float32(i) => builder.append_value(*i),
+                AnyValue::Float16(i) => builder.append_value(i.to_f32()),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float32, av)),
             }
@@ -267,6 +321,7 @@ fn any_values_to_f64(values: &[AnyValue], strict: bool) -> PolarsResult<Float64C
             match av {
                 AnyValue::Float64(i) => builder.append_value(*i),
                 AnyValue::Float32(i) => builder.append_value(*i as f64),
+                AnyValue::Float16(i) => builder.append_value(i.to_f64()),
                 AnyValue::Null => builder.append_null(),
                 av => return Err(invalid_value_error(&DataType::Float64, av)),
             }
@@ -492,6 +547,50 @@ fn any_values_to_duration(
     Ok(builder.finish().into_duration(time_unit))
 }
 
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> PolarsResult<IntervalChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(PlSmallStr::EMPTY, values.len());
+    let target_dtype = DataType::Interval;
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => builder.append_value(interval_to_i128(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&target_dtype, av));
+                }
+                match av.cast(&target_dtype) {
+                    AnyValue::Interval(v) => builder.append_value(interval_to_i128(v)),
+                    _ => builder.append_null(),
+                }
+            },
+        }
+    }
+    Ok(builder.finish().into_interval())
+}
+
+#[cfg(feature = "dtype-uuid")]
+fn any_values_to_uuid(values: &[AnyValue], strict: bool) -> PolarsResult<UuidChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<UInt128Type>::new(PlSmallStr::EMPTY, values.len());
+    let target_dtype = DataType::Uuid;
+    for av in values {
+        match av {
+            AnyValue::Uuid(v) => builder.append_value(*v),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&target_dtype, av));
+                }
+                match av.cast(&target_dtype) {
+                    AnyValue::Uuid(v) => builder.append_value(v),
+                    _ => builder.append_null(),
+                }
+            },
+        }
+    }
+    Ok(builder.finish().into_uuid())
+}
+
 #[cfg(feature = "dtype-categorical")]
 fn any_values_to_categorical(
     values: &[AnyValue],
@@ -935,6 +1034,51 @@ fn any_values_to_struct(
     Ok(out.into_series())
 }
 
+/// Every value is assigned to the first variant of its dtype, or with `strict` disabled, to the
+/// first variant it can be cast to.
+#[cfg(feature = "dtype-union")]
+fn any_values_to_union(
+    values: &[AnyValue],
+    variants: &[Field],
+    strict: bool,
+) -> PolarsResult<Series> {
+    let mut variant_avs = vec![Vec::with_capacity(values.len()); variants.len()];
+    for av in values {
+        let variant = if av.is_null() {
+            None
+        } else {
+            let dtype = av.dtype();
+            match variants.iter().position(|f| f.dtype() == &dtype) {
+                Some(i) => Some((i, av.clone())),
+                None if strict => polars_bail!(
+                    SchemaMismatch: "value of type {} does not match any variant of {}",
+                    dtype, DataType::Union(variants.to_vec())
+                ),
+                None => variants
+                    .iter()
+                    .enumerate()
+                    .find_map(|(i, f)| Some((i, av.strict_cast(f.dtype())?))),
+            }
+        };
+        for (i, avs) in variant_avs.iter_mut().enumerate() {
+            match &variant {
+                Some((v, av)) if *v == i => avs.push(av.clone()),
+                _ => avs.push(AnyValue::Null),
+            }
+        }
+    }
+
+    let fields = variants
+        .iter()
+        .zip(variant_avs)
+        .map(|(f, avs)| {
+            Series::from_any_values_and_dtype(f.name().clone(), &avs, f.dtype(), strict)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let ca = StructChunked::from_series(PlSmallStr::EMPTY, values.len(), fields.iter())?;
+    Ok(ca.into_union()?.into_series())
+}
+
 #[cfg(feature = "object")]
 fn any_values_to_object(values: &[AnyValue]) -> PolarsResult<Series> {
     use crate::chunked_array::object::registry;
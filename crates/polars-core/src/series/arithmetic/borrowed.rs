@@ -243,6 +243,28 @@ pub mod checked {
         }
     }
 
+    #[cfg(feature = "dtype-f16")]
+    impl NumOpsDispatchCheckedInner for Float16Type {
+        fn checked_div(lhs: &Float16Chunked, rhs: &Series) -> PolarsResult<Series> {
+            // SAFETY:
+            // see check_div
+            let rhs = unsafe { lhs.unpack_series_matching_physical_type(rhs) };
+
+            let ca: Float16Chunked =
+                arity::binary_elementwise(lhs, rhs, |opt_l, opt_r| match (opt_l, opt_r) {
+                    (Some(l), Some(r)) => {
+                        if r.is_zero() {
+                            None
+                        } else {
+                            Some(l / r)
+                        }
+                    },
+                    _ => None,
+                });
+            Ok(ca.into_series())
+        }
+    }
+
     impl NumOpsDispatchChecked for Series {
         fn checked_div(&self, rhs: &Series) -> PolarsResult<Series> {
             let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
@@ -546,11 +568,18 @@ impl Mul for &Series {
             (_, Date) | (_, Datetime(_, _)) | (_, Time) => {
                 polars_bail!(opq = mul, self.dtype(), rhs.dtype())
             },
+            #[cfg(feature = "dtype-interval")]
+            (Interval, _) => self.multiply(rhs),
             (_, Duration(_)) => {
                 // swap order
                 let out = rhs.multiply(self)?;
                 Ok(out.with_name(self.name().clone()))
             },
+            #[cfg(feature = "dtype-interval")]
+            (_, Interval) => {
+                let out = rhs.multiply(self)?;
+                Ok(out.with_name(self.name().clone()))
+            },
             (DataType::List(_), _) | (_, DataType::List(_)) => {
                 list::NumericListOp::mul().execute(self, rhs)
             },
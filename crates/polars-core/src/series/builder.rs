@@ -1,3 +1,6 @@
+#[cfg(feature = "dtype-categorical")]
+use std::borrow::Cow;
+
 use arrow::array::builder::{ArrayBuilder, ShareStrategy, make_builder};
 use polars_utils::IdxSize;
 
@@ -7,19 +10,53 @@ use crate::prelude::*;
 use crate::utils::Container;
 
 #[cfg(feature = "dtype-categorical")]
-#[inline(always)]
-fn fill_rev_map(dtype: &DataType, rev_map_merger: &mut Option<Box<GlobalRevMapMerger>>) {
-    if let DataType::Categorical(Some(rev_map), _) = dtype {
-        assert!(
-            rev_map.is_active_global(),
-            "{}",
-            polars_err!(string_cache_mismatch)
-        );
-        if let Some(merger) = rev_map_merger {
-            merger.merge_map(rev_map).unwrap();
-        } else {
-            *rev_map_merger = Some(Box::new(GlobalRevMapMerger::new(rev_map.clone())));
-        }
+enum RevMapState {
+    /// Rev-maps of one string cache, these share their physicals.
+    Global(Box<GlobalRevMapMerger>),
+    /// A local rev-map, other rev-maps are re-encoded to it.
+    Local(Arc<RevMapping>),
+}
+
+/// Merges the rev-map of `other` into `state`, re-encodes `other` if its physicals can't be
+/// shared.
+#[cfg(feature = "dtype-categorical")]
+fn fill_rev_map<'a>(state: &mut Option<RevMapState>, other: &'a Series) -> Cow<'a, Series> {
+    let DataType::Categorical(Some(rev_map), ordering) = other.dtype() else {
+        return Cow::Borrowed(other);
+    };
+    match state {
+        None => {
+            *state = Some(if rev_map.is_global() {
+                RevMapState::Global(Box::new(GlobalRevMapMerger::new(rev_map.clone())))
+            } else {
+                RevMapState::Local(rev_map.clone())
+            });
+            Cow::Borrowed(other)
+        },
+        Some(RevMapState::Global(merger)) => {
+            if merger.merge_map(rev_map).is_ok() {
+                return Cow::Borrowed(other);
+            }
+            let other = other
+                .categorical()
+                .unwrap()
+                .to_local()
+                .to_global()
+                .unwrap()
+                .into_series();
+            merger
+                .merge_map(other.categorical().unwrap().get_rev_map())
+                .unwrap();
+            Cow::Owned(other)
+        },
+        Some(RevMapState::Local(merged)) => {
+            if rev_map.same_src(merged) {
+                return Cow::Borrowed(other);
+            }
+            *merged = merge_rev_maps(merged, rev_map).unwrap();
+            let dtype = DataType::Categorical(Some(merged.clone()), *ordering);
+            Cow::Owned(remap_nested_categoricals(other, &dtype).unwrap().unwrap())
+        },
     }
 }
 
@@ -28,7 +65,7 @@ pub struct SeriesBuilder {
     dtype: DataType,
     builder: Box<dyn ArrayBuilder>,
     #[cfg(feature = "dtype-categorical")]
-    rev_map_merger: Option<Box<GlobalRevMapMerger>>,
+    rev_map_state: Option<RevMapState>,
 }
 
 impl SeriesBuilder {
@@ -41,7 +78,7 @@ impl SeriesBuilder {
                 dtype,
                 builder,
                 #[cfg(feature = "dtype-categorical")]
-                rev_map_merger: None,
+                rev_map_state: None,
             };
         }
 
@@ -50,7 +87,7 @@ impl SeriesBuilder {
             dtype,
             builder,
             #[cfg(feature = "dtype-categorical")]
-            rev_map_merger: None,
+            rev_map_state: None,
         }
     }
 
@@ -61,11 +98,15 @@ impl SeriesBuilder {
 
     fn freeze_dtype(&mut self) -> DataType {
         #[cfg(feature = "dtype-categorical")]
-        if let Some(rev_map_merger) = self.rev_map_merger.take() {
+        if let Some(rev_map_state) = self.rev_map_state.take() {
             let DataType::Categorical(_, order) = self.dtype else {
                 unreachable!()
             };
-            return DataType::Categorical(Some(rev_map_merger.finish()), order);
+            let rev_map = match rev_map_state {
+                RevMapState::Global(merger) => merger.finish(),
+                RevMapState::Local(rev_map) => rev_map,
+            };
+            return DataType::Categorical(Some(rev_map), order);
         }
 
         self.dtype.clone()
@@ -100,11 +141,6 @@ impl SeriesBuilder {
     /// other does not match the dtype of this builder.
     #[inline(always)]
     pub fn extend(&mut self, other: &Series, share: ShareStrategy) {
-        #[cfg(feature = "dtype-categorical")]
-        {
-            fill_rev_map(other.dtype(), &mut self.rev_map_merger);
-        }
-
         self.subslice_extend(other, 0, other.len(), share);
     }
 
@@ -118,9 +154,9 @@ impl SeriesBuilder {
         share: ShareStrategy,
     ) {
         #[cfg(feature = "dtype-categorical")]
-        {
-            fill_rev_map(other.dtype(), &mut self.rev_map_merger);
-        }
+        let other = fill_rev_map(&mut self.rev_map_state, other);
+        #[cfg(feature = "dtype-categorical")]
+        let other: &Series = &other;
 
         if length == 0 || other.is_empty() {
             return;
@@ -152,9 +188,9 @@ impl SeriesBuilder {
         share: ShareStrategy,
     ) {
         #[cfg(feature = "dtype-categorical")]
-        {
-            fill_rev_map(other.dtype(), &mut self.rev_map_merger);
-        }
+        let other = fill_rev_map(&mut self.rev_map_state, other);
+        #[cfg(feature = "dtype-categorical")]
+        let other: &Series = &other;
 
         if length == 0 || other.is_empty() {
             return;
@@ -180,9 +216,9 @@ impl SeriesBuilder {
         share: ShareStrategy,
     ) {
         #[cfg(feature = "dtype-categorical")]
-        {
-            fill_rev_map(other.dtype(), &mut self.rev_map_merger);
-        }
+        let other = fill_rev_map(&mut self.rev_map_state, other);
+        #[cfg(feature = "dtype-categorical")]
+        let other: &Series = &other;
 
         if length == 0 || repeats == 0 || other.is_empty() {
             return;
@@ -219,9 +255,9 @@ impl SeriesBuilder {
     /// The indices must be in-bounds.
     pub unsafe fn gather_extend(&mut self, other: &Series, idxs: &[IdxSize], share: ShareStrategy) {
         #[cfg(feature = "dtype-categorical")]
-        {
-            fill_rev_map(other.dtype(), &mut self.rev_map_merger);
-        }
+        let other = fill_rev_map(&mut self.rev_map_state, other);
+        #[cfg(feature = "dtype-categorical")]
+        let other: &Series = &other;
 
         let chunks = other.chunks();
         assert!(chunks.len() == 1);
@@ -230,9 +266,9 @@ impl SeriesBuilder {
 
     pub fn opt_gather_extend(&mut self, other: &Series, idxs: &[IdxSize], share: ShareStrategy) {
         #[cfg(feature = "dtype-categorical")]
-        {
-            fill_rev_map(other.dtype(), &mut self.rev_map_merger);
-        }
+        let other = fill_rev_map(&mut self.rev_map_state, other);
+        #[cfg(feature = "dtype-categorical")]
+        let other: &Series = &other;
 
         let chunks = other.chunks();
         assert!(chunks.len() == 1);
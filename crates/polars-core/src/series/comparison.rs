@@ -12,6 +12,12 @@ macro_rules! impl_eq_compare {
         let (lhs, rhs) = ($self, $rhs);
         validate_types(lhs.dtype(), rhs.dtype())?;
 
+        #[cfg(feature = "dtype-extension")]
+        if lhs.dtype().is_extension() || rhs.dtype().is_extension() {
+            let (l, r) = (extension_comparison_values(lhs)?, extension_comparison_values(rhs)?);
+            return Ok(l.as_ref().$method(r.as_ref())?.with_name(lhs.name().clone()));
+        }
+
         polars_ensure!(
             lhs.len() == rhs.len() ||
 
@@ -65,11 +71,13 @@ macro_rules! impl_eq_compare {
             UInt16 => feature_gated!("dtype-u16", lhs.u16().unwrap().$method(rhs.u16().unwrap())),
             UInt32 => lhs.u32().unwrap().$method(rhs.u32().unwrap()),
             UInt64 => lhs.u64().unwrap().$method(rhs.u64().unwrap()),
+            UInt128 => feature_gated!("dtype-u128", lhs.u128().unwrap().$method(rhs.u128().unwrap())),
             Int8 => feature_gated!("dtype-i8", lhs.i8().unwrap().$method(rhs.i8().unwrap())),
             Int16 => feature_gated!("dtype-i16", lhs.i16().unwrap().$method(rhs.i16().unwrap())),
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
             Int64 => lhs.i64().unwrap().$method(rhs.i64().unwrap()),
             Int128 => feature_gated!("dtype-i128", lhs.i128().unwrap().$method(rhs.i128().unwrap())),
+            Float16 => feature_gated!("dtype-f16", lhs.f16().unwrap().$method(rhs.f16().unwrap())),
             Float32 => lhs.f32().unwrap().$method(rhs.f32().unwrap()),
             Float64 => lhs.f64().unwrap().$method(rhs.f64().unwrap()),
             List(_) => lhs.list().unwrap().$method(rhs.list().unwrap()),
@@ -77,6 +85,12 @@ macro_rules! impl_eq_compare {
             Array(_, _) => lhs.array().unwrap().$method(rhs.array().unwrap()),
             #[cfg(feature = "dtype-struct")]
             Struct(_) => lhs.struct_().unwrap().$method(rhs.struct_().unwrap()),
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(_) => lhs
+                .fixed_size_binary()
+                .unwrap()
+                .to_array()
+                .$method(&rhs.fixed_size_binary().unwrap().to_array()),
 
             dt => polars_bail!(InvalidOperation: "could not apply comparison on series of dtype '{}; operand names: '{}', '{}'", dt, lhs.name(), rhs.name()),
         };
@@ -102,6 +116,12 @@ macro_rules! impl_ineq_compare {
         let (lhs, rhs) = ($self, $rhs);
         validate_types(lhs.dtype(), rhs.dtype())?;
 
+        #[cfg(feature = "dtype-extension")]
+        if lhs.dtype().is_extension() || rhs.dtype().is_extension() {
+            let (l, r) = (extension_comparison_values(lhs)?, extension_comparison_values(rhs)?);
+            return Ok(l.as_ref().$method(r.as_ref())?.with_name(lhs.name().clone()));
+        }
+
         polars_ensure!(
             lhs.len() == rhs.len() ||
 
@@ -160,11 +180,13 @@ macro_rules! impl_ineq_compare {
             UInt16 => feature_gated!("dtype-u16", lhs.u16().unwrap().$method(rhs.u16().unwrap())),
             UInt32 => lhs.u32().unwrap().$method(rhs.u32().unwrap()),
             UInt64 => lhs.u64().unwrap().$method(rhs.u64().unwrap()),
+            UInt128 => feature_gated!("dtype-u128", lhs.u128().unwrap().$method(rhs.u128().unwrap())),
             Int8 => feature_gated!("dtype-i8", lhs.i8().unwrap().$method(rhs.i8().unwrap())),
             Int16 => feature_gated!("dtype-i16", lhs.i16().unwrap().$method(rhs.i16().unwrap())),
             Int32 => lhs.i32().unwrap().$method(rhs.i32().unwrap()),
             Int64 => lhs.i64().unwrap().$method(rhs.i64().unwrap()),
             Int128 => feature_gated!("dtype-i128", lhs.i128().unwrap().$method(rhs.i128().unwrap())),
+            Float16 => feature_gated!("dtype-f16", lhs.f16().unwrap().$method(rhs.f16().unwrap())),
             Float32 => lhs.f32().unwrap().$method(rhs.f32().unwrap()),
             Float64 => lhs.f64().unwrap().$method(rhs.f64().unwrap()),
             List(_) => bail_invalid_ineq!(lhs, rhs, $op),
@@ -172,6 +194,8 @@ macro_rules! impl_ineq_compare {
             Array(_, _) => bail_invalid_ineq!(lhs, rhs, $op),
             #[cfg(feature = "dtype-struct")]
             Struct(_) => bail_invalid_ineq!(lhs, rhs, $op),
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(_) => bail_invalid_ineq!(lhs, rhs, $op),
 
             dt => polars_bail!(InvalidOperation: "could not apply comparison on series of dtype '{}; operand names: '{}', '{}'", dt, lhs.name(), rhs.name()),
         };
@@ -198,6 +222,15 @@ fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     Ok(())
 }
 
+/// The values an extension column is compared by, see [`ExtensionChunked::comparison_values`].
+#[cfg(feature = "dtype-extension")]
+fn extension_comparison_values(s: &Series) -> PolarsResult<std::borrow::Cow<'_, Series>> {
+    match s.try_extension() {
+        Some(ca) => ca.comparison_values(),
+        None => Ok(std::borrow::Cow::Borrowed(s)),
+    }
+}
+
 impl ChunkCompareEq<&Series> for Series {
     type Item = PolarsResult<BooleanChunked>;
 
@@ -1,5 +1,7 @@
 #[cfg(feature = "dtype-categorical")]
 use arrow::compute::concatenate::concatenate_unchecked;
+#[cfg(feature = "dtype-interval")]
+use arrow::datatypes::IntervalUnit;
 use arrow::datatypes::Metadata;
 #[cfg(any(
     feature = "dtype-date",
@@ -65,6 +67,8 @@ impl Series {
             UInt64 => UInt64Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-i128")]
             Int128 => Int128Chunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-u128")]
+            UInt128 => UInt128Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-date")]
             Date => Int32Chunked::from_chunks(name, chunks)
                 .into_date()
@@ -81,6 +85,23 @@ impl Series {
             Datetime(tu, tz) => Int64Chunked::from_chunks(name, chunks)
                 .into_datetime(*tu, tz.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => UInt128Chunked::from_chunks(name, chunks)
+                .into_uuid()
+                .into_series(),
+            #[cfg(feature = "dtype-json")]
+            Json => StringChunked::from_chunks(name, chunks)
+                .into_json_unchecked()
+                .into_series(),
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            FixedSizeBinary(_) => {
+                FixedSizeBinaryChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
+                    .into_series()
+            },
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Int128Chunked::from_chunks(name, chunks)
                 .into_decimal_unchecked(
@@ -95,6 +116,26 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => {
+                let entries_dtype = DataType::List(Box::new(dtype.map_entries_dtype().unwrap()));
+                ListChunked::from_chunks_and_dtype_unchecked(name, chunks, entries_dtype)
+                    .into_map_unchecked(dtype.clone())
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-extension")]
+            Extension { storage, .. } => {
+                let storage = Series::from_chunks_and_dtype_unchecked(name, chunks, storage);
+                ExtensionChunked::new_unchecked(storage, dtype.clone()).into_series()
+            },
+            #[cfg(feature = "dtype-union")]
+            Union(_) => StructChunked::from_chunks_and_dtype_unchecked(
+                name,
+                chunks,
+                dtype.union_struct_dtype().unwrap(),
+            )
+            .into_union_unchecked(dtype.clone())
+            .into_series(),
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -114,6 +155,8 @@ impl Series {
                 ca.into_series()
             },
             Boolean => BooleanChunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Float16Chunked::from_chunks(name, chunks).into_series(),
             Float32 => Float32Chunked::from_chunks(name, chunks).into_series(),
             Float64 => Float64Chunked::from_chunks(name, chunks).into_series(),
             BinaryOffset => BinaryOffsetChunked::from_chunks(name, chunks).into_series(),
@@ -220,6 +263,10 @@ impl Series {
             ArrowDataType::UInt16 => Ok(UInt16Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::UInt32 => Ok(UInt32Chunked::from_chunks(name, chunks).into_series()),
             ArrowDataType::UInt64 => Ok(UInt64Chunked::from_chunks(name, chunks).into_series()),
+            ArrowDataType::UInt128 => feature_gated!(
+                "dtype-u128",
+                Ok(UInt128Chunked::from_chunks(name, chunks).into_series())
+            ),
             #[cfg(feature = "dtype-i8")]
             ArrowDataType::Int8 => Ok(Int8Chunked::from_chunks(name, chunks).into_series()),
             #[cfg(feature = "dtype-i16")]
@@ -230,6 +277,9 @@ impl Series {
                 "dtype-i128",
                 Ok(Int128Chunked::from_chunks(name, chunks).into_series())
             ),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => Ok(Float16Chunked::from_chunks(name, chunks).into_series()),
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -281,6 +331,22 @@ impl Series {
                     ArrowTimeUnit::Nanosecond => s,
                 })
             },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr
+                            .as_any()
+                            .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                            .unwrap();
+                        interval_array_to_physical(arr).to_boxed()
+                    })
+                    .collect::<Vec<_>>();
+                Ok(Int128Chunked::from_chunks(name, chunks)
+                    .into_interval()
+                    .into_series())
+            },
             #[cfg(feature = "dtype-time")]
             ArrowDataType::Time64(tu) | ArrowDataType::Time32(tu) => {
                 let mut chunks = chunks;
@@ -526,6 +592,56 @@ impl Series {
                 };
                 Ok(s)
             },
+            #[cfg(feature = "dtype-uuid")]
+            ArrowDataType::Extension(ext)
+                if ext.name == UUID_EXTENSION_NAME
+                    && ext.inner == ArrowDataType::FixedSizeBinary(16) =>
+            {
+                let chunks = chunks.iter().map(|arr| {
+                    let arr = arr.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+                    uuid_array_to_physical(arr)
+                });
+                Ok(UInt128Chunked::from_chunk_iter(name, chunks)
+                    .into_uuid()
+                    .into_series())
+            },
+            #[cfg(feature = "dtype-json")]
+            ArrowDataType::Extension(ext)
+                if ext.name == JSON_EXTENSION_NAME
+                    && matches!(
+                        ext.inner,
+                        ArrowDataType::Utf8View | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8
+                    ) =>
+            {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| arrow::array::with_dtype(arr.as_ref(), ext.inner.clone()))
+                    .collect();
+                let s = Series::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)?;
+                Ok(s.str()?.clone().into_json_unchecked().into_series())
+            },
+            #[cfg(feature = "dtype-extension")]
+            ArrowDataType::Extension(ext) if ext.name != EXTENSION_NAME => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| arrow::array::with_dtype(arr.as_ref(), ext.inner.clone()))
+                    .collect();
+                let storage =
+                    Series::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)?;
+                Ok(
+                    ExtensionChunked::new(storage, ext.name.clone(), ext.metadata.clone())
+                        .into_series(),
+                )
+            },
+            // Without support for extension types we load the storage.
+            #[cfg(not(feature = "dtype-extension"))]
+            ArrowDataType::Extension(ext) if ext.name != EXTENSION_NAME => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| arrow::array::with_dtype(arr.as_ref(), ext.inner.clone()))
+                    .collect();
+                Series::_try_from_arrow_unchecked_with_md(name, chunks, &ext.inner, md)
+            },
             #[cfg(feature = "dtype-struct")]
             ArrowDataType::Struct(_) => {
                 let (chunks, dtype) = to_physical_and_dtype(chunks, md);
@@ -537,11 +653,27 @@ impl Series {
                     Ok(ca.into_series())
                 }
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            ArrowDataType::FixedSizeBinary(_) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        arr.as_any()
+                            .downcast_ref::<FixedSizeBinaryArray>()
+                            .unwrap()
+                            .clone()
+                    })
+                    .collect::<Vec<_>>();
+                Ok(FixedSizeBinaryChunked::from_arrow_chunks(name, &chunks).into_series())
+            },
+            #[cfg(not(feature = "dtype-fixed-size-binary"))]
             ArrowDataType::FixedSizeBinary(_) => {
                 let chunks = cast_chunks(&chunks, &DataType::Binary, CastOptions::NonStrict)?;
                 Ok(BinaryChunked::from_chunks(name, chunks).into_series())
             },
             ArrowDataType::Map(_, _) => map_arrays_to_series(name, chunks),
+            #[cfg(feature = "dtype-union")]
+            ArrowDataType::Union(_) => union_arrays_to_series(name, chunks),
             dt => polars_bail!(ComputeError: "cannot create series from {:?}", dt),
         }
     }
@@ -551,9 +683,11 @@ fn map_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult
     let chunks = chunks
         .iter()
         .map(|arr| {
-            // we convert the map to the logical type: List<struct<key, value>>
+            // we convert the map to the physical type: List<struct<key, value>>
             let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
             let inner = arr.field().clone();
+            #[cfg(feature = "dtype-map")]
+            let inner = rename_map_entries(inner);
 
             // map has i32 offsets
             let dtype = ListArray::<i32>::default_datatype(inner.dtype().clone());
@@ -565,7 +699,99 @@ fn map_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult
             )) as ArrayRef
         })
         .collect::<Vec<_>>();
-    Series::try_from((name, chunks))
+    let s = Series::try_from((name, chunks))?;
+
+    #[cfg(feature = "dtype-map")]
+    if let DataType::List(inner) = s.dtype() {
+        if let DataType::Struct(fields) = inner.as_ref() {
+            if fields.len() == 2 {
+                let dtype = DataType::Map(
+                    Box::new(fields[0].dtype().clone()),
+                    Box::new(fields[1].dtype().clone()),
+                );
+                // SAFETY: the entries were renamed to the key and value fields of the map.
+                return Ok(unsafe { s.list()?.clone().into_map_unchecked(dtype) }.into_series());
+            }
+        }
+    }
+    Ok(s)
+}
+
+/// Convert arrow unions, sparse or dense, to the struct of variants of [`DataType::Union`].
+#[cfg(feature = "dtype-union")]
+unsafe fn union_arrays_to_series(name: PlSmallStr, chunks: Vec<ArrayRef>) -> PolarsResult<Series> {
+    let mut out: Option<Series> = None;
+    for arr in &chunks {
+        let arr = arr.as_any().downcast_ref::<UnionArray>().unwrap();
+        let fields = UnionArray::get_fields(arr.dtype());
+
+        let mut slots = vec![Vec::with_capacity(arr.len()); fields.len()];
+        for row in 0..arr.len() {
+            let (variant, slot) = arr.index_unchecked(row);
+            for (i, slots) in slots.iter_mut().enumerate() {
+                slots.push((i == variant).then_some(slot as IdxSize));
+            }
+        }
+
+        let variants = fields
+            .iter()
+            .zip(arr.fields())
+            .zip(slots)
+            .map(|((field, child), slots)| {
+                let child = Series::_try_from_arrow_unchecked_with_md(
+                    field.name.clone(),
+                    vec![child.clone()],
+                    child.dtype(),
+                    field.metadata.as_deref(),
+                )?;
+                Ok(child.take_unchecked(&IdxCa::from_iter_options(
+                    PlSmallStr::EMPTY,
+                    slots.into_iter(),
+                )))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        // Arrow unions have no validity of their own, a row is null if the value of its variant
+        // is null.
+        let ca =
+            StructChunked::from_series(name.clone(), arr.len(), variants.iter())?.into_union()?;
+        match &mut out {
+            None => out = Some(ca.into_series()),
+            Some(out) => {
+                out.append_owned(ca.into_series())?;
+            },
+        }
+    }
+    Ok(out.unwrap_or_else(|| Series::new_empty(name, &DataType::Union(vec![]))))
+}
+
+/// Rename the fields of the entries of an arrow map to the field names of [`DataType::Map`].
+#[cfg(feature = "dtype-map")]
+fn rename_map_entries(entries: ArrayRef) -> ArrayRef {
+    let arr = entries.as_any().downcast_ref::<StructArray>().unwrap();
+    if arr.values().len() != 2 {
+        return entries;
+    }
+    let ArrowDataType::Struct(fields) = arr.dtype() else {
+        unreachable!()
+    };
+    let fields = fields
+        .iter()
+        .zip([MAP_KEY_NAME, MAP_VALUE_NAME])
+        .map(|(field, name)| {
+            let mut field = field.clone();
+            field.name = PlSmallStr::from_static(name);
+            field.is_nullable = true;
+            field
+        })
+        .collect();
+    StructArray::new(
+        ArrowDataType::Struct(fields),
+        arr.len(),
+        arr.values().to_vec(),
+        arr.validity().cloned(),
+    )
+    .boxed()
 }
 
 fn convert<F: Fn(&dyn Array) -> ArrayRef>(arr: &[ArrayRef], f: F) -> Vec<ArrayRef> {
@@ -583,7 +809,19 @@ unsafe fn to_physical_and_dtype(
             let chunks = cast_chunks(&arrays, &DataType::String, CastOptions::NonStrict).unwrap();
             (chunks, DataType::String)
         },
-        ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::FixedSizeBinary(_) => {
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        dt @ ArrowDataType::FixedSizeBinary(_) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        ArrowDataType::Binary | ArrowDataType::LargeBinary => {
+            let chunks = cast_chunks(&arrays, &DataType::Binary, CastOptions::NonStrict).unwrap();
+            (chunks, DataType::Binary)
+        },
+        #[cfg(not(feature = "dtype-fixed-size-binary"))]
+        ArrowDataType::FixedSizeBinary(_) => {
             let chunks = cast_chunks(&arrays, &DataType::Binary, CastOptions::NonStrict).unwrap();
             (chunks, DataType::Binary)
         },
@@ -727,6 +965,33 @@ unsafe fn to_physical_and_dtype(
             let dtype = s.dtype().clone();
             (std::mem::take(s.chunks_mut()), dtype)
         },
+        #[cfg(feature = "dtype-interval")]
+        dt @ ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        dt @ ArrowDataType::Extension(ext) if ext.name != EXTENSION_NAME => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-union")]
+        dt @ ArrowDataType::Union(_) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
+        #[cfg(feature = "dtype-map")]
+        dt @ ArrowDataType::Map(_, _) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
         dt => {
             let dtype = DataType::from_arrow(dt, md);
             (arrays, dtype)
@@ -866,6 +1131,27 @@ impl From<DurationChunked> for Series {
     }
 }
 
+#[cfg(feature = "dtype-interval")]
+impl From<IntervalChunked> for Series {
+    fn from(a: IntervalChunked) -> Self {
+        a.into_series()
+    }
+}
+
+#[cfg(feature = "dtype-uuid")]
+impl From<UuidChunked> for Series {
+    fn from(a: UuidChunked) -> Self {
+        a.into_series()
+    }
+}
+
+#[cfg(feature = "dtype-json")]
+impl From<JsonChunked> for Series {
+    fn from(a: JsonChunked) -> Self {
+        a.into_series()
+    }
+}
+
 #[cfg(feature = "dtype-time")]
 impl From<TimeChunked> for Series {
     fn from(a: TimeChunked) -> Self {
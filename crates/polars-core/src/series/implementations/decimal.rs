@@ -1,6 +1,10 @@
 use polars_compute::rolling::QuantileMethod;
 
 use super::*;
+use crate::chunked_array::arithmetic::{
+    _decimal_mean, _get_decimal_precision_mean_var_std, _get_decimal_precision_sum,
+    _get_decimal_scale_mean_var_std, DecimalVarState,
+};
 use crate::prelude::*;
 
 unsafe impl IntoSeries for DecimalChunked {
@@ -9,6 +13,50 @@ unsafe impl IntoSeries for DecimalChunked {
     }
 }
 
+/// The exclusive upper bound of the physical values of a decimal with the given precision.
+fn decimal_upper_bound(precision: Option<usize>) -> i128 {
+    10_i128.pow(precision.unwrap_or(38).min(38) as u32)
+}
+
+/// Sums the physical values of a decimal, returning `None` if the result doesn't fit in the
+/// precision described by `upper`.
+fn checked_decimal_sum(mut values: impl Iterator<Item = i128>, upper: i128) -> Option<i128> {
+    values
+        .try_fold(0_i128, |acc, v| acc.checked_add(v))
+        .filter(|v| *v > -upper && *v < upper)
+}
+
+/// The mean of the physical values of a decimal at `scale`, `None` if there are no values or the
+/// result overflowed.
+fn checked_decimal_mean(values: impl Iterator<Item = i128>, scale: usize) -> Option<i128> {
+    let mut count = 0;
+    let sum = values
+        .inspect(|_| count += 1)
+        .try_fold(0_i128, |acc, v| acc.checked_add(v))?;
+    (count > 0).then(|| _decimal_mean(sum, count, scale))?
+}
+
+/// The variance, or standard deviation if `is_std`, of the physical values of a decimal at
+/// `scale`. The outer `None` means the result overflowed.
+fn checked_decimal_var_std(
+    values: impl Iterator<Item = i128>,
+    ddof: u8,
+    scale: usize,
+    is_std: bool,
+) -> Option<Option<i128>> {
+    let mut state = DecimalVarState::default();
+    values.for_each(|x| state.insert_one(x));
+    state.finalize(ddof, scale, is_std)
+}
+
+/// The dtype of the mean, variance and standard deviation of a decimal with the given scale.
+fn mean_var_std_dtype(scale: usize) -> DataType {
+    DataType::Decimal(
+        _get_decimal_precision_mean_var_std(),
+        Some(_get_decimal_scale_mean_var_std(scale)),
+    )
+}
+
 impl private::PrivateSeriesNumeric for SeriesWrap<DecimalChunked> {
     fn bit_repr(&self) -> Option<BitRepr> {
         None
@@ -26,6 +74,83 @@ impl SeriesWrap<DecimalChunked> {
         f(&self.0)
     }
 
+    /// Aggregates the physical values of every group with `f` into a decimal of `dtype`.
+    #[cfg(feature = "algorithm_group_by")]
+    fn agg_physical_values<F>(&self, groups: &GroupsType, dtype: &DataType, f: F) -> Series
+    where
+        F: Fn(&mut dyn Iterator<Item = i128>) -> Option<i128> + Send + Sync,
+    {
+        use crate::frame::group_by::aggregations::{_agg_helper_idx, _agg_helper_slice};
+
+        let DataType::Decimal(precision, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let ca = self.0.physical().rechunk();
+        let arr = ca.downcast_iter().next().unwrap();
+        let out = match groups {
+            GroupsType::Idx(groups) => _agg_helper_idx::<Int128Type, _>(groups, |(_, idx)| {
+                f(&mut idx.iter().filter_map(|i| arr.get(*i as usize)))
+            }),
+            GroupsType::Slice { groups, .. } => {
+                _agg_helper_slice::<Int128Type, _>(groups, |[first, len]| {
+                    let (first, len) = (first as usize, len as usize);
+                    f(&mut (first..first + len).filter_map(|i| arr.get(i)))
+                })
+            },
+        };
+        out.i128()
+            .unwrap()
+            .clone()
+            .into_decimal_unchecked(*precision, *scale)
+            .into_series()
+    }
+
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    #[cfg(feature = "algorithm_group_by")]
+    pub(crate) unsafe fn agg_decimal_mean(&self, groups: &GroupsType) -> Series {
+        // We can't raise here, so groups whose mean overflows become null.
+        let scale = self.0.scale();
+        self.agg_physical_values(groups, &mean_var_std_dtype(scale), |values| {
+            checked_decimal_mean(values, scale)
+        })
+    }
+
+    pub(crate) fn decimal_mean_reduce(&self) -> Scalar {
+        let scale = self.0.scale();
+        let values = self
+            .0
+            .physical()
+            .downcast_iter()
+            .flat_map(|arr| arr.non_null_values_iter());
+        let av = match checked_decimal_mean(values, scale) {
+            Some(mean) => AnyValue::Decimal(mean, _get_decimal_scale_mean_var_std(scale)),
+            None => AnyValue::Null,
+        };
+        Scalar::new(mean_var_std_dtype(scale), av)
+    }
+
+    fn var_std_reduce(&self, ddof: u8, is_std: bool) -> PolarsResult<Scalar> {
+        let scale = self.0.scale();
+        let dtype = mean_var_std_dtype(scale);
+        let values = self
+            .0
+            .physical()
+            .downcast_iter()
+            .flat_map(|arr| arr.non_null_values_iter());
+        let av = match checked_decimal_var_std(values, ddof, scale, is_std) {
+            Some(Some(v)) => AnyValue::Decimal(v, _get_decimal_scale_mean_var_std(scale)),
+            Some(None) => AnyValue::Null,
+            None if crate::config::decimal_overflow_to_null() => AnyValue::Null,
+            None => polars_bail!(
+                ComputeError: "decimal {} overflow: result does not fit in {}",
+                if is_std { "std" } else { "var" }, dtype
+            ),
+        };
+        Ok(Scalar::new(dtype, av))
+    }
+
     fn scale_factor(&self) -> u128 {
         10u128.pow(self.0.scale() as u32)
     }
@@ -151,7 +276,27 @@ impl private::PrivateSeries for SeriesWrap<DecimalChunked> {
 
     #[cfg(feature = "algorithm_group_by")]
     unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
-        self.agg_helper(|ca| ca.agg_sum(groups))
+        // We can't raise here, so groups whose sum overflows become null instead of wrapping.
+        let precision = _get_decimal_precision_sum(self.0.precision());
+        let upper = decimal_upper_bound(precision);
+        let dtype = DataType::Decimal(precision, Some(self.0.scale()));
+        self.agg_physical_values(groups, &dtype, |values| checked_decimal_sum(values, upper))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        let scale = self.0.scale();
+        self.agg_physical_values(groups, &mean_var_std_dtype(scale), |values| {
+            checked_decimal_var_std(values, ddof, scale, true).flatten()
+        })
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        let scale = self.0.scale();
+        self.agg_physical_values(groups, &mean_var_std_dtype(scale), |values| {
+            checked_decimal_var_std(values, ddof, scale, false).flatten()
+        })
     }
 
     #[cfg(feature = "algorithm_group_by")]
@@ -382,14 +527,22 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
     }
 
     fn sum_reduce(&self) -> PolarsResult<Scalar> {
-        Ok(self.apply_physical(|ca| {
-            let sum = ca.sum();
-            let DataType::Decimal(_, Some(scale)) = self.dtype() else {
-                unreachable!()
-            };
-            let av = AnyValue::Decimal(sum.unwrap(), *scale);
-            Scalar::new(self.dtype().clone(), av)
-        }))
+        let precision = _get_decimal_precision_sum(self.0.precision());
+        let scale = self.0.scale();
+        let dtype = DataType::Decimal(precision, Some(scale));
+        let values = self
+            .0
+            .physical()
+            .downcast_iter()
+            .flat_map(|arr| arr.non_null_values_iter());
+        let av = match checked_decimal_sum(values, decimal_upper_bound(precision)) {
+            Some(sum) => AnyValue::Decimal(sum, scale),
+            None if crate::config::decimal_overflow_to_null() => AnyValue::Null,
+            None => {
+                polars_bail!(ComputeError: "decimal sum overflow: result does not fit in {}", dtype)
+            },
+        };
+        Ok(Scalar::new(dtype, av))
     }
     fn min_reduce(&self) -> PolarsResult<Scalar> {
         Ok(self.apply_physical(|ca| {
@@ -439,7 +592,10 @@ impl SeriesTrait for SeriesWrap<DecimalChunked> {
         self.0.std(ddof).map(|v| v / self.scale_factor() as f64)
     }
     fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
-        Ok(self.apply_scale(self.0.std_reduce(ddof)))
+        self.var_std_reduce(ddof, true)
+    }
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.var_std_reduce(ddof, false)
     }
 
     fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
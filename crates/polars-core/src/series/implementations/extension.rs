@@ -0,0 +1,303 @@
+use super::*;
+use crate::chunked_array::cast::CastOptions;
+use crate::chunked_array::flags::StatisticsFlags;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for ExtensionChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<ExtensionChunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        self.0.storage().bit_repr()
+    }
+}
+
+impl SeriesWrap<ExtensionChunked> {
+    fn apply_storage<F: Fn(&Series) -> Series>(&self, f: F) -> Series {
+        self.0.with_storage(f(self.0.storage())).into_series()
+    }
+
+    fn try_apply_storage<F: Fn(&Series) -> PolarsResult<Series>>(
+        &self,
+        f: F,
+    ) -> PolarsResult<Series> {
+        Ok(self.0.with_storage(f(self.0.storage())?).into_series())
+    }
+}
+
+/// The storage of `s` if it is an extension, otherwise `s` itself.
+fn storage_of(s: &Series) -> &Series {
+    match s.try_extension() {
+        Some(ca) => ca.storage(),
+        None => s,
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
+    fn compute_len(&mut self) {
+        self.0.storage_mut().compute_len()
+    }
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(Field::new(self.name().clone(), self.0.dtype().clone()))
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        // The order of the storage is not necessarily the order of the extension.
+        let mut flags = self.0.storage().get_flags();
+        flags.remove(StatisticsFlags::IS_SORTED_ANY);
+        flags
+    }
+    fn _set_flags(&mut self, mut flags: StatisticsFlags) {
+        flags.remove(StatisticsFlags::IS_SORTED_ANY);
+        self.0.storage_mut().set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0
+            .storage()
+            .equal_element(idx_self, idx_other, storage_of(other))
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.extension()?.storage();
+        self.try_apply_storage(|s| s.zip_with_same_type(mask, other))
+    }
+
+    fn vec_hash(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.storage().vec_hash(build_hasher, buf)
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.storage().vec_hash_combine(build_hasher, hashes)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        let out = self.0.storage().agg_list(groups);
+        let mut out = out.list().unwrap().clone();
+        out.set_inner_dtype(self.dtype().clone());
+        out.into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.0.storage().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.comparison_values()?.arg_sort_multiple(by, options)
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.storage().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.storage().into_total_ord_inner()
+    }
+}
+
+impl SeriesTrait for SeriesWrap<ExtensionChunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.storage_mut().rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.storage().chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.storage().name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.storage().chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.storage_mut().chunks_mut()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.storage_mut().shrink_to_fit()
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0
+            .comparison_values()
+            .expect("comparison values of an extension type")
+            .arg_sort(options)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        let idx = self.0.comparison_values()?.arg_sort(options);
+        // SAFETY: arg_sort returns in-bounds indices.
+        Ok(unsafe { self.take_unchecked(&idx) })
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.apply_storage(|s| s.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.storage().split_at(offset);
+        (
+            self.0.with_storage(a).into_series(),
+            self.0.with_storage(b).into_series(),
+        )
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.extension()?.storage();
+        self.0.storage_mut().append(other)?;
+        Ok(())
+    }
+    fn append_owned(&mut self, other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.extension()?.storage().clone();
+        self.0.storage_mut().append_owned(other)?;
+        Ok(())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.extension()?.storage();
+        self.0.storage_mut().extend(other)?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.try_apply_storage(|s| s.filter(filter))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        self.try_apply_storage(|s| s.take(indices))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.apply_storage(|s| s.take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        self.try_apply_storage(|s| s.take_slice(indices))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.apply_storage(|s| s.take_slice_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.storage().len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.apply_storage(|s| s.rechunk())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.apply_storage(|s| s.new_from_index(index, length))
+    }
+
+    fn trim_lists_to_normalized_offsets(&self) -> Option<Series> {
+        self.0
+            .storage()
+            .trim_lists_to_normalized_offsets()
+            .map(|s| self.0.with_storage(s).into_series())
+    }
+
+    fn propagate_nulls(&self) -> Option<Series> {
+        self.0
+            .storage()
+            .propagate_nulls()
+            .map(|s| self.0.with_storage(s).into_series())
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.storage().get_unchecked(index)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.storage().null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.storage().has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.try_apply_storage(|s| s.unique())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.storage().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.storage().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.storage().is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.storage().is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_storage(|s| s.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_storage(|s| s.shift(periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0
+            .storage()
+            .find_validity_mismatch(storage_of(other), idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.storage().as_phys_any()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
@@ -0,0 +1,290 @@
+use super::*;
+use crate::prelude::*;
+use crate::series::private::PrivateSeries;
+
+unsafe impl IntoSeries for FixedSizeBinaryChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<FixedSizeBinaryChunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl SeriesWrap<FixedSizeBinaryChunked> {
+    /// The values viewed as an array of `u8`, which the nested kernels operate on.
+    fn phys_series(&self) -> Series {
+        self.0.to_array().into_series()
+    }
+
+    fn wrap_phys(&self, s: Series) -> Series {
+        FixedSizeBinaryChunked::from_array_view(s.array().unwrap()).into_series()
+    }
+
+    fn apply_phys_to_s<F: Fn(&Series) -> Series>(&self, f: F) -> Series {
+        self.wrap_phys(f(&self.phys_series()))
+    }
+
+    fn try_apply_phys_to_s<F: Fn(&Series) -> PolarsResult<Series>>(
+        &self,
+        f: F,
+    ) -> PolarsResult<Series> {
+        Ok(self.wrap_phys(f(&self.phys_series())?))
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<FixedSizeBinaryChunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+    fn _field(&self) -> Cow<Field> {
+        Cow::Borrowed(self.0.ref_field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.fixed_size_binary().unwrap();
+        self.0.get_unchecked(idx_self) == other.get_unchecked(idx_other)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.fixed_size_binary()?.to_array().into_series();
+        self.try_apply_phys_to_s(|s| s.zip_with_same_type(mask, &other))
+    }
+
+    fn vec_hash(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.vec_hash(build_hasher, buf)
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.vec_hash_combine(build_hasher, hashes)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        self.0.agg_list(groups)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.phys_series().group_tuples(multithreaded, sorted)
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        (&self.0).into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        (&self.0).into_total_ord_inner()
+    }
+}
+
+impl SeriesTrait for SeriesWrap<FixedSizeBinaryChunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.phys_series().arg_sort(options)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        self.try_apply_phys_to_s(|s| s.sort_with(options))
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append(other.fixed_size_binary()?)
+    }
+    fn append_owned(&mut self, other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append_owned(other.fixed_size_binary()?.clone())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        self.0.extend(other.fixed_size_binary()?)
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.try_apply_phys_to_s(|s| s.filter(filter))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        self.try_apply_phys_to_s(|s| s.take(indices))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.apply_phys_to_s(|s| s.take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        self.try_apply_phys_to_s(|s| s.take_slice(indices))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.apply_phys_to_s(|s| s.take_slice_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_owned().into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.apply_phys_to_s(|s| s.new_from_index(index, length))
+    }
+
+    fn trim_lists_to_normalized_offsets(&self) -> Option<Series> {
+        self.phys_series()
+            .trim_lists_to_normalized_offsets()
+            .map(|s| self.wrap_phys(s))
+    }
+
+    fn propagate_nulls(&self) -> Option<Series> {
+        self.phys_series()
+            .propagate_nulls()
+            .map(|s| self.wrap_phys(s))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        if self.len() < 2 {
+            return Ok(self.0.clone().into_series());
+        }
+        let main_thread = POOL.current_thread_index().is_none();
+        let groups = self.group_tuples(main_thread, false);
+        // SAFETY:
+        // groups are in bounds
+        Ok(unsafe { self.0.clone().into_series().agg_first(&groups?) })
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        match self.len() {
+            0 => Ok(0),
+            1 => Ok(1),
+            _ => {
+                let main_thread = POOL.current_thread_index().is_none();
+                let groups = self.group_tuples(main_thread, false)?;
+                Ok(groups.len())
+            },
+        }
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        if self.len() == 1 {
+            return Ok(IdxCa::new_vec(self.name().clone(), vec![0 as IdxSize]));
+        }
+        let main_thread = POOL.current_thread_index().is_none();
+        let groups = self.group_tuples(main_thread, true)?;
+        let first = groups.take_group_firsts();
+        Ok(IdxCa::from_vec(self.name().clone(), first))
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_phys_to_s(|s| s.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.0.shift(periods).into_series()
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
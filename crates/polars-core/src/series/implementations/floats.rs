@@ -1,3 +1,4 @@
+use num_traits::AsPrimitive;
 use polars_compute::rolling::QuantileMethod;
 
 use super::*;
@@ -209,7 +210,7 @@ macro_rules! impl_dyn_series {
             }
 
             fn median(&self) -> Option<f64> {
-                self.0.median().map(|v| v as f64)
+                self.0.median().map(AsPrimitive::<f64>::as_)
             }
 
             fn std(&self, ddof: u8) -> Option<f64> {
@@ -392,5 +393,7 @@ macro_rules! impl_dyn_series {
     };
 }
 
+#[cfg(feature = "dtype-f16")]
+impl_dyn_series!(Float16Chunked, Float16Type);
 impl_dyn_series!(Float32Chunked, Float32Type);
 impl_dyn_series!(Float64Chunked, Float64Type);
@@ -0,0 +1,383 @@
+use std::ops::DerefMut;
+
+use super::*;
+use crate::chunked_array::comparison::*;
+use crate::chunked_array::ops::arity::broadcast_try_binary_elementwise;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for IntervalChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<IntervalChunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl SeriesWrap<IntervalChunked> {
+    /// Combine the components of two intervals, raising if any of them overflows.
+    fn interval_arithmetic(
+        &self,
+        rhs: &Series,
+        op_name: &str,
+        op: fn(i64, i64) -> Option<i64>,
+    ) -> PolarsResult<Series> {
+        let rhs = rhs.interval()?;
+        let out: Int128Chunked =
+            broadcast_try_binary_elementwise(self.0.physical(), rhs.physical(), |l, r| {
+                let (Some(l), Some(r)) = (l, r) else {
+                    return Ok(None);
+                };
+                let (l, r) = (interval_from_i128(l), interval_from_i128(r));
+                let months =
+                    op(l.months() as i64, r.months() as i64).and_then(|v| i32::try_from(v).ok());
+                let days = op(l.days() as i64, r.days() as i64).and_then(|v| i32::try_from(v).ok());
+                let ns = op(l.ns(), r.ns());
+                match (months, days, ns) {
+                    (Some(months), Some(days), Some(ns)) => Ok(Some(interval_to_i128(
+                        months_days_ns::new(months, days, ns),
+                    ))),
+                    _ => polars_bail!(ComputeError: "interval {} overflow", op_name),
+                }
+            })?;
+        Ok(out.into_interval().into_series())
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<IntervalChunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(self.0.field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.deref_mut().set_flags(flags)
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.deref().get_flags()
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        self.0.equal_element(idx_self, idx_other, other)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.to_physical_repr().into_owned();
+        self.0
+            .zip_with(mask, other.as_ref().as_ref())
+            .map(|ca| ca.into_interval().into_series())
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.physical().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.physical().into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        self.0
+            .agg_min(groups)
+            .i128()
+            .unwrap()
+            .clone()
+            .into_interval()
+            .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        self.0
+            .agg_max(groups)
+            .i128()
+            .unwrap()
+            .clone()
+            .into_interval()
+            .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        // we cannot cast and dispatch as the inner type of the list would be incorrect
+        self.0
+            .agg_list(groups)
+            .cast(&DataType::List(Box::new(self.dtype().clone())))
+            .unwrap()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        match rhs.dtype() {
+            DataType::Interval => self.interval_arithmetic(rhs, "subtraction", i64::checked_sub),
+            dtr => polars_bail!(opq = sub, self.dtype(), dtr),
+        }
+    }
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        match rhs.dtype() {
+            DataType::Interval => self.interval_arithmetic(rhs, "addition", i64::checked_add),
+            _ => polars_bail!(opq = add, self.dtype(), rhs.dtype()),
+        }
+    }
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        match rhs.dtype() {
+            dt if dt.is_integer() => {
+                let rhs = rhs.cast(&DataType::Int64)?;
+                let rhs = rhs.i64().unwrap();
+                let lhs = self.0.physical();
+                let out: Int128Chunked = broadcast_try_binary_elementwise(lhs, rhs, |l, r| {
+                    let (Some(l), Some(r)) = (l, r) else {
+                        return Ok(None);
+                    };
+                    let l = interval_from_i128(l);
+                    let months = (l.months() as i64)
+                        .checked_mul(r)
+                        .and_then(|v| i32::try_from(v).ok());
+                    let days = (l.days() as i64)
+                        .checked_mul(r)
+                        .and_then(|v| i32::try_from(v).ok());
+                    match (months, days, l.ns().checked_mul(r)) {
+                        (Some(months), Some(days), Some(ns)) => Ok(Some(interval_to_i128(
+                            months_days_ns::new(months, days, ns),
+                        ))),
+                        _ => polars_bail!(ComputeError: "interval multiplication overflow"),
+                    }
+                })?;
+                Ok(out.into_interval().into_series())
+            },
+            _ => polars_bail!(opq = mul, self.dtype(), rhs.dtype()),
+        }
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.0.group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.deref().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<IntervalChunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_interval().into_series()
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (
+            a.into_interval().into_series(),
+            b.into_interval().into_series(),
+        )
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let mut other = other.to_physical_repr().into_owned();
+        self.0
+            .append_owned(std::mem::take(other._get_inner_mut().as_mut()))
+    }
+    fn append_owned(&mut self, mut other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<IntervalChunked>()
+                .unwrap()
+                .phys,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.to_physical_repr();
+        self.0.extend(other.as_ref().as_ref().as_ref())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.0
+            .filter(filter)
+            .map(|ca| ca.into_interval().into_series())
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_interval().into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0.take_unchecked(indices).into_interval().into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_interval().into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0.take_unchecked(indices).into_interval().into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_owned().into_interval().into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.0
+            .new_from_index(index, length)
+            .into_interval()
+            .into_series()
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        Ok(self.0.sort_with(options).into_interval().into_series())
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.0.unique().map(|ca| ca.into_interval().into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.0.reverse().into_interval().into_series()
+    }
+
+    fn as_single_ptr(&mut self) -> PolarsResult<usize> {
+        self.0.as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.0.shift(periods).into_interval().into_series()
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        let sc = self.0.max_reduce();
+        Ok(Scalar::new(self.dtype().clone(), sc.value().as_interval()))
+    }
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        let sc = self.0.min_reduce();
+        Ok(Scalar::new(self.dtype().clone(), sc.value().as_interval()))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
@@ -14,8 +14,18 @@ mod datetime;
 mod decimal;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-extension")]
+mod extension;
+#[cfg(feature = "dtype-fixed-size-binary")]
+mod fixed_size_binary;
 mod floats;
+#[cfg(feature = "dtype-interval")]
+mod interval;
+#[cfg(feature = "dtype-json")]
+mod json;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 pub(crate) mod null;
 #[cfg(feature = "object")]
 mod object;
@@ -24,6 +34,10 @@ mod string;
 mod struct_;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "dtype-union")]
+mod union;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -475,6 +489,8 @@ impl_dyn_series!(Int32Chunked, Int32Type);
 impl_dyn_series!(Int64Chunked, Int64Type);
 #[cfg(feature = "dtype-i128")]
 impl_dyn_series!(Int128Chunked, Int128Type);
+#[cfg(feature = "dtype-u128")]
+impl_dyn_series!(UInt128Chunked, UInt128Type);
 
 impl<T: PolarsNumericType> private::PrivateSeriesNumeric for SeriesWrap<ChunkedArray<T>> {
     fn bit_repr(&self) -> Option<BitRepr> {
@@ -0,0 +1,275 @@
+use super::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for UnionChunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<UnionChunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl SeriesWrap<UnionChunked> {
+    /// The physical struct of type ids and variants as a [`Series`].
+    fn phys_series(&self) -> Series {
+        self.0.physical().clone().into_series()
+    }
+
+    fn wrap_phys(&self, s: Series) -> Series {
+        let phys = s.struct_().unwrap().clone();
+        self.0.same_type(phys).into_series()
+    }
+
+    fn apply_phys_to_s<F: Fn(&Series) -> Series>(&self, f: F) -> Series {
+        self.wrap_phys(f(&self.phys_series()))
+    }
+
+    fn try_apply_phys_to_s<F: Fn(&Series) -> PolarsResult<Series>>(
+        &self,
+        f: F,
+    ) -> PolarsResult<Series> {
+        Ok(self.wrap_phys(f(&self.phys_series())?))
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<UnionChunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+    fn _field(&self) -> Cow<Field> {
+        Cow::Owned(self.0.field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.to_physical_repr();
+        self.phys_series()
+            .equal_element(idx_self, idx_other, &other)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.union()?.physical().clone().into_series();
+        self.try_apply_phys_to_s(|s| s.zip_with_same_type(mask, &other))
+    }
+
+    fn vec_hash(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.phys_series().vec_hash(build_hasher, buf)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        let out = self.0.physical().agg_list(groups);
+        let mut out = out.list().unwrap().clone();
+        out.set_inner_dtype(self.dtype().clone());
+        out.into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.phys_series().group_tuples(multithreaded, sorted)
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        invalid_operation_panic!(into_total_eq_inner, self)
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        invalid_operation_panic!(into_total_ord_inner, self)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<UnionChunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter {
+        self.0.chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.physical().arg_sort(options)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        self.try_apply_phys_to_s(|s| s.sort_with(options))
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0
+            .same_type(self.0.physical().slice(offset, length))
+            .into_series()
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.physical().split_at(offset);
+        (
+            self.0.same_type(a).into_series(),
+            self.0.same_type(b).into_series(),
+        )
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.union()?;
+        self.0.append(other.physical())
+    }
+    fn append_owned(&mut self, other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.union()?.physical().clone();
+        self.0.append_owned(other)
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.union()?;
+        self.0.extend(other.physical())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        self.try_apply_phys_to_s(|s| s.filter(filter))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        self.try_apply_phys_to_s(|s| s.take(indices))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.apply_phys_to_s(|s| s.take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        self.try_apply_phys_to_s(|s| s.take_slice(indices))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.apply_phys_to_s(|s| s.take_slice_unchecked(indices))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0
+            .same_type(self.0.rechunk().into_owned())
+            .into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.apply_phys_to_s(|s| s.new_from_index(index, length))
+    }
+
+    fn trim_lists_to_normalized_offsets(&self) -> Option<Series> {
+        self.phys_series()
+            .trim_lists_to_normalized_offsets()
+            .map(|s| self.wrap_phys(s))
+    }
+
+    fn propagate_nulls(&self) -> Option<Series> {
+        self.phys_series()
+            .propagate_nulls()
+            .map(|s| self.wrap_phys(s))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        self.try_apply_phys_to_s(|s| s.unique())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.phys_series().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.phys_series().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.phys_series().is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.phys_series().is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_phys_to_s(|s| s.reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_phys_to_s(|s| s.shift(periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
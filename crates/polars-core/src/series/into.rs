@@ -85,6 +85,91 @@ impl Series {
                 );
                 Box::new(arr)
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let entries = self.map().unwrap().entries().clone().into_series();
+                let arr = entries.to_arrow(chunk_idx, compat_level);
+                let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+                let values = arr.values().as_any().downcast_ref::<StructArray>().unwrap();
+
+                // Map keys are not allowed to be null.
+                let ArrowDataType::Struct(fields) = values.dtype() else {
+                    unreachable!()
+                };
+                let mut fields = fields.clone();
+                fields[0].is_nullable = false;
+                let entries_dtype = ArrowDataType::Struct(fields);
+                let values = StructArray::new(
+                    entries_dtype.clone(),
+                    values.len(),
+                    values.values().to_vec(),
+                    values.validity().cloned(),
+                );
+
+                let dtype = ArrowDataType::Map(
+                    Box::new(ArrowField::new(
+                        PlSmallStr::from_static(MAP_ENTRIES_NAME),
+                        entries_dtype,
+                        false,
+                    )),
+                    false,
+                );
+                let offsets = arr
+                    .offsets()
+                    .try_into()
+                    .expect("map has too many entries for arrow map offsets");
+                Box::new(MapArray::new(
+                    dtype,
+                    offsets,
+                    values.boxed(),
+                    arr.validity().cloned(),
+                ))
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_) => {
+                let phys = self.union().unwrap().physical().clone().into_series();
+                let arr = phys.to_arrow(chunk_idx, compat_level);
+                let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+                let ArrowDataType::Struct(fields) = arr.dtype() else {
+                    unreachable!()
+                };
+
+                // Null rows have no active variant, all variants are null in those rows.
+                let type_ids = arr.values()[0]
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i8>>()
+                    .unwrap();
+                let types = type_ids
+                    .iter()
+                    .map(|id| id.copied().unwrap_or(0))
+                    .collect::<Vec<_>>();
+
+                let dtype = ArrowDataType::Union(Box::new(arrow::datatypes::UnionType {
+                    fields: fields[1..].to_vec(),
+                    ids: None,
+                    mode: arrow::datatypes::UnionMode::Sparse,
+                }));
+                Box::new(UnionArray::new(
+                    dtype,
+                    types.into(),
+                    arr.values()[1..].to_vec(),
+                    None,
+                ))
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { name, metadata, .. } => {
+                let arr = self
+                    .extension()
+                    .unwrap()
+                    .storage()
+                    .to_arrow(chunk_idx, compat_level);
+                let dtype = ArrowDataType::Extension(Box::new(arrow::datatypes::ExtensionType {
+                    name: name.clone(),
+                    inner: arr.dtype().clone(),
+                    metadata: metadata.clone(),
+                }));
+                arrow::array::with_dtype(arr.as_ref(), dtype)
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, width) => {
                 let ca = self.array().unwrap();
@@ -150,6 +235,33 @@ impl Series {
                 &self.dtype().to_arrow(compat_level),
             )
             .unwrap(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => interval_array_from_physical(
+                self.chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<i128>>()
+                    .unwrap(),
+            )
+            .to_boxed(),
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => uuid_array_from_physical(
+                self.chunks()[chunk_idx]
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<u128>>()
+                    .unwrap(),
+            )
+            .to_boxed(),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => {
+                let arr = self
+                    .json()
+                    .unwrap()
+                    .physical()
+                    .clone()
+                    .into_series()
+                    .to_arrow(chunk_idx, compat_level);
+                arrow::array::with_dtype(arr.as_ref(), self.dtype().to_arrow(compat_level))
+            },
             #[cfg(feature = "dtype-time")]
             DataType::Time => cast(
                 &*self.chunks()[chunk_idx],
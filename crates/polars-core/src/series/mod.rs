@@ -466,6 +466,13 @@ impl Series {
             opt => opt,
         };
 
+        #[cfg(feature = "dtype-extension")]
+        let out = if dtype.is_extension() && !slf.dtype().is_extension() {
+            crate::chunked_array::logical::cast_to_extension(&slf, dtype, new_options)?
+        } else {
+            slf.0.cast(dtype, new_options)?
+        };
+        #[cfg(not(feature = "dtype-extension"))]
         let out = slf.0.cast(dtype, new_options)?;
         if options.is_strict() {
             handle_casting_failures(slf.as_ref(), &out)?;
@@ -554,6 +561,19 @@ impl Series {
                 feature_gated!("dtype-duration", Ok(self.clone().into_duration(*tu)))
             },
             (D::Int64, D::Time) => feature_gated!("dtype-time", Ok(self.clone().into_time())),
+            #[cfg(feature = "dtype-interval")]
+            (D::Int128, D::Interval) => {
+                Ok(self.i128().unwrap().clone().into_interval().into_series())
+            },
+            #[cfg(feature = "dtype-uuid")]
+            (D::UInt128, D::Uuid) => Ok(self.u128().unwrap().clone().into_uuid().into_series()),
+            #[cfg(feature = "dtype-json")]
+            (D::String, D::Json) => Ok(self
+                .str()
+                .unwrap()
+                .clone()
+                .into_json_unchecked()
+                .into_series()),
 
             (D::List(_), D::List(to)) => unsafe {
                 self.list()
@@ -575,6 +595,27 @@ impl Series {
                     .from_physical_unchecked(to.as_slice())
                     .map(|ca| ca.into_series())
             },
+            #[cfg(feature = "dtype-extension")]
+            (_, D::Extension { storage, .. }) => unsafe {
+                let storage = self.from_physical_unchecked(storage)?;
+                Ok(ExtensionChunked::new_unchecked(storage, dtype.clone()).into_series())
+            },
+            #[cfg(feature = "dtype-union")]
+            (D::Struct(_), D::Union(_)) => unsafe {
+                let D::Struct(fields) = dtype.union_struct_dtype().unwrap() else {
+                    unreachable!()
+                };
+                let phys = self.struct_().unwrap().from_physical_unchecked(&fields)?;
+                Ok(phys.into_union_unchecked(dtype.clone()).into_series())
+            },
+            #[cfg(feature = "dtype-map")]
+            (D::List(_), D::Map(_, _)) => unsafe {
+                let entries = self
+                    .list()
+                    .unwrap()
+                    .from_physical_unchecked(dtype.map_entries_dtype().unwrap())?;
+                Ok(entries.into_map_unchecked(dtype.clone()).into_series())
+            },
 
             _ => panic!("invalid from_physical({dtype:?}) for {:?}", self.dtype()),
         }
@@ -710,11 +751,15 @@ impl Series {
     /// * Datetime -> Int64
     /// * Duration -> Int64
     /// * Decimal -> Int128
+    /// * Uuid -> UInt128
+    /// * Json -> String
     /// * Time -> Int64
     /// * Categorical -> UInt32
     /// * List(inner) -> List(physical of inner)
     /// * Array(inner) -> Array(physical of inner)
     /// * Struct -> Struct with physical repr of each struct column
+    /// * Map(key, value) -> List(Struct{key, value}) with physical repr of the key and value
+    /// * Extension -> physical repr of the storage
     pub fn to_physical_repr(&self) -> Cow<Series> {
         use DataType::*;
         match self.dtype() {
@@ -728,6 +773,12 @@ impl Series {
             Duration(_) => Cow::Owned(self.duration().unwrap().phys.clone().into_series()),
             #[cfg(feature = "dtype-time")]
             Time => Cow::Owned(self.time().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(self.interval().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-uuid")]
+            Uuid => Cow::Owned(self.uuid().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-json")]
+            Json => Cow::Owned(self.json().unwrap().phys.clone().into_series()),
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | Enum(_, _) => {
                 let ca = self.categorical().unwrap();
@@ -749,6 +800,32 @@ impl Series {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => Cow::Owned(
+                self.map()
+                    .unwrap()
+                    .entries()
+                    .to_physical_repr()
+                    .into_owned()
+                    .into_series(),
+            ),
+            #[cfg(feature = "dtype-union")]
+            Union(_) => Cow::Owned(
+                self.union()
+                    .unwrap()
+                    .physical()
+                    .to_physical_repr()
+                    .into_owned()
+                    .into_series(),
+            ),
+            #[cfg(feature = "dtype-extension")]
+            Extension { .. } => Cow::Owned(
+                self.extension()
+                    .unwrap()
+                    .storage()
+                    .to_physical_repr()
+                    .into_owned(),
+            ),
             _ => Cow::Borrowed(self),
         }
     }
@@ -799,6 +876,10 @@ impl Series {
                 UInt64 => Ok(self.u64().unwrap().prod_reduce()),
                 #[cfg(feature = "dtype-i128")]
                 Int128 => Ok(self.i128().unwrap().prod_reduce()),
+                #[cfg(feature = "dtype-u128")]
+                UInt128 => Ok(self.u128().unwrap().prod_reduce()),
+                #[cfg(feature = "dtype-f16")]
+                Float16 => Ok(self.f16().unwrap().prod_reduce()),
                 Float32 => Ok(self.f32().unwrap().prod_reduce()),
                 Float64 => Ok(self.f64().unwrap().prod_reduce()),
                 dt => {
@@ -927,6 +1008,10 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<str>> {
+        #[cfg(feature = "dtype-extension")]
+        if let Some(v) = self.try_extension().and_then(|ca| ca.format_value(index)) {
+            return Ok(Cow::Owned(v));
+        }
         Ok(self.0.get(index)?.str_value())
     }
     /// Get the head of the Series.
@@ -943,6 +1028,10 @@ impl Series {
     }
 
     pub fn mean_reduce(&self) -> Scalar {
+        #[cfg(feature = "dtype-decimal")]
+        if let Ok(ca) = self.decimal() {
+            return implementations::SeriesWrap(ca.clone()).decimal_mean_reduce();
+        }
         crate::scalar::reduce::mean_reduce(self.mean(), self.dtype().clone())
     }
 
@@ -1104,6 +1193,41 @@ mod test {
         assert!(s2.f32().is_ok());
     }
 
+    #[test]
+    #[cfg(all(feature = "dtype-u128", feature = "dtype-f16"))]
+    fn u128_and_f16() -> PolarsResult<()> {
+        let big = u128::MAX - 1;
+        let s = Series::new("a".into(), &[Some(1u128), None, Some(big)]);
+        assert_eq!(s.dtype(), &DataType::UInt128);
+        assert_eq!(s.max::<u128>()?, Some(big));
+        assert_eq!(s.sum::<u128>()?, big + 1);
+        assert_eq!(s.get(2)?, AnyValue::UInt128(big));
+        assert_eq!((&s + &s)?.u128()?.get(0), Some(2));
+
+        let s = Series::new("b".into(), &[f16::from_f32(1.5), f16::from_f32(-2.0)]);
+        assert_eq!(s.dtype(), &DataType::Float16);
+        assert_eq!(s.min::<f32>()?, Some(-2.0));
+        assert_eq!(s.sum::<f32>()?, -0.5);
+        let s = s.cast(&DataType::Float64)?;
+        assert_eq!(s.f64()?.get(0), Some(1.5));
+        let s = s.cast(&DataType::Float16)?;
+        assert_eq!(s.f16()?.get(1), Some(f16::from_f32(-2.0)));
+
+        assert_eq!(
+            crate::utils::try_get_supertype(&DataType::UInt128, &DataType::UInt8)?,
+            DataType::UInt128
+        );
+        assert_eq!(
+            crate::utils::try_get_supertype(&DataType::Float16, &DataType::Int8)?,
+            DataType::Float16
+        );
+        assert_eq!(
+            crate::utils::try_get_supertype(&DataType::Float16, &DataType::Int32)?,
+            DataType::Float64
+        );
+        Ok(())
+    }
+
     #[test]
     fn new_series() {
         let _ = Series::new("boolean series".into(), &vec![true, false, true]);
@@ -68,6 +68,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::Int128 => Int128Chunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_f16(&self) -> Option<&Float16Chunked> {
+        try_unpack_chunked!(self, DataType::Float16 => Float16Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn try_f32(&self) -> Option<&Float32Chunked> {
         try_unpack_chunked!(self, DataType::Float32 => Float32Chunked)
@@ -98,6 +104,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::UInt64 => UInt64Chunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::UInt128`]
+    #[cfg(feature = "dtype-u128")]
+    pub fn try_u128(&self) -> Option<&UInt128Chunked> {
+        try_unpack_chunked!(self, DataType::UInt128 => UInt128Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Boolean`]
     pub fn try_bool(&self) -> Option<&BooleanChunked> {
         try_unpack_chunked!(self, DataType::Boolean => BooleanChunked)
@@ -142,6 +154,30 @@ impl Series {
         try_unpack_chunked!(self, DataType::Duration(_) => DurationChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        try_unpack_chunked!(self, DataType::Interval => IntervalChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::FixedSizeBinary`]
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    pub fn try_fixed_size_binary(&self) -> Option<&FixedSizeBinaryChunked> {
+        try_unpack_chunked!(self, DataType::FixedSizeBinary(_) => FixedSizeBinaryChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Uuid`]
+    #[cfg(feature = "dtype-uuid")]
+    pub fn try_uuid(&self) -> Option<&UuidChunked> {
+        try_unpack_chunked!(self, DataType::Uuid => UuidChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Json`]
+    #[cfg(feature = "dtype-json")]
+    pub fn try_json(&self) -> Option<&JsonChunked> {
+        try_unpack_chunked!(self, DataType::Json => JsonChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn try_decimal(&self) -> Option<&DecimalChunked> {
@@ -153,6 +189,24 @@ impl Series {
         try_unpack_chunked!(self, DataType::List(_) => ListChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        try_unpack_chunked!(self, DataType::Map(_, _) => MapChunked)
+    }
+
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn try_extension(&self) -> Option<&ExtensionChunked> {
+        try_unpack_chunked!(self, DataType::Extension { .. } => ExtensionChunked)
+    }
+
+    /// Unpack to [`UnionChunked`] of dtype [`DataType::Union`]
+    #[cfg(feature = "dtype-union")]
+    pub fn try_union(&self) -> Option<&UnionChunked> {
+        try_unpack_chunked!(self, DataType::Union(_) => UnionChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Array`]
     #[cfg(feature = "dtype-array")]
     pub fn try_array(&self) -> Option<&ArrayChunked> {
@@ -227,6 +281,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Int128"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
+        self.try_f16()
+            .ok_or_else(|| unpack_chunked_err!(self => "Float16"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn f32(&self) -> PolarsResult<&Float32Chunked> {
         self.try_f32()
@@ -263,6 +324,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "UInt64"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::UInt128`]
+    #[cfg(feature = "dtype-u128")]
+    pub fn u128(&self) -> PolarsResult<&UInt128Chunked> {
+        self.try_u128()
+            .ok_or_else(|| unpack_chunked_err!(self => "UInt128"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Boolean`]
     pub fn bool(&self) -> PolarsResult<&BooleanChunked> {
         self.try_bool()
@@ -315,6 +383,34 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Duration"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.try_interval()
+            .ok_or_else(|| unpack_chunked_err!(self => "Interval"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::FixedSizeBinary`]
+    #[cfg(feature = "dtype-fixed-size-binary")]
+    pub fn fixed_size_binary(&self) -> PolarsResult<&FixedSizeBinaryChunked> {
+        self.try_fixed_size_binary()
+            .ok_or_else(|| unpack_chunked_err!(self => "FixedSizeBinary"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Uuid`]
+    #[cfg(feature = "dtype-uuid")]
+    pub fn uuid(&self) -> PolarsResult<&UuidChunked> {
+        self.try_uuid()
+            .ok_or_else(|| unpack_chunked_err!(self => "Uuid"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Json`]
+    #[cfg(feature = "dtype-json")]
+    pub fn json(&self) -> PolarsResult<&JsonChunked> {
+        self.try_json()
+            .ok_or_else(|| unpack_chunked_err!(self => "Json"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
@@ -328,6 +424,27 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "List"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.try_map()
+            .ok_or_else(|| unpack_chunked_err!(self => "Map"))
+    }
+
+    /// Unpack to [`ExtensionChunked`] of dtype [`DataType::Extension`]
+    #[cfg(feature = "dtype-extension")]
+    pub fn extension(&self) -> PolarsResult<&ExtensionChunked> {
+        self.try_extension()
+            .ok_or_else(|| unpack_chunked_err!(self => "Extension"))
+    }
+
+    /// Unpack to [`UnionChunked`] of dtype [`DataType::Union`]
+    #[cfg(feature = "dtype-union")]
+    pub fn union(&self) -> PolarsResult<&UnionChunked> {
+        self.try_union()
+            .ok_or_else(|| unpack_chunked_err!(self => "Union"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Array`]
     #[cfg(feature = "dtype-array")]
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
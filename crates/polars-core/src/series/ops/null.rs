@@ -13,6 +13,31 @@ impl Series {
             DataType::List(inner_dtype) => {
                 ListChunked::full_null_with_dtype(name, size, inner_dtype).into_series()
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let entries_dtype = dtype.map_entries_dtype().unwrap();
+                let ca = ListChunked::full_null_with_dtype(name, size, &entries_dtype);
+                // SAFETY: the inner dtype is the entries dtype of the map.
+                unsafe { ca.into_map_unchecked(dtype.clone()) }.into_series()
+            },
+            #[cfg(feature = "dtype-union")]
+            DataType::Union(_) => {
+                let phys = Series::full_null(name, size, &dtype.union_struct_dtype().unwrap());
+                // SAFETY: all rows are null, the physical struct has the fields of the union.
+                unsafe {
+                    phys.struct_()
+                        .unwrap()
+                        .clone()
+                        .into_union_unchecked(dtype.clone())
+                }
+                .into_series()
+            },
+            #[cfg(feature = "dtype-extension")]
+            DataType::Extension { storage, .. } => {
+                let storage = Series::full_null(name, size, storage);
+                // SAFETY: the storage has the storage dtype of the extension.
+                unsafe { ExtensionChunked::new_unchecked(storage, dtype.clone()) }.into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner_dtype, width) => {
                 ArrayChunked::full_null_with_dtype(name, size, inner_dtype, *width).into_series()
@@ -43,6 +68,24 @@ impl Series {
             DataType::Duration(tu) => Int64Chunked::full_null(name, size)
                 .into_duration(*tu)
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Int128Chunked::full_null(name, size)
+                .into_interval()
+                .into_series(),
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            DataType::FixedSizeBinary(_) => {
+                let arr =
+                    FixedSizeBinaryArray::new_null(dtype.to_arrow(CompatLevel::newest()), size);
+                FixedSizeBinaryChunked::from_arrow_chunks(name, &[arr]).into_series()
+            },
+            #[cfg(feature = "dtype-uuid")]
+            DataType::Uuid => UInt128Chunked::full_null(name, size)
+                .into_uuid()
+                .into_series(),
+            #[cfg(feature = "dtype-json")]
+            DataType::Json => StringChunked::full_null(name, size)
+                .into_json_unchecked()
+                .into_series(),
             #[cfg(feature = "dtype-time")]
             DataType::Time => Int64Chunked::full_null(name, size)
                 .into_time()
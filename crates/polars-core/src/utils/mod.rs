@@ -369,6 +369,8 @@ macro_rules! match_dtype_to_physical_apply_macro {
             DataType::UInt16 => $macro!(u16 $(, $opt_args)*),
             DataType::UInt32 => $macro!(u32 $(, $opt_args)*),
             DataType::UInt64 => $macro!(u64 $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!(u128 $(, $opt_args)*),
             #[cfg(feature = "dtype-i8")]
             DataType::Int8 => $macro!(i8 $(, $opt_args)*),
             #[cfg(feature = "dtype-i16")]
@@ -377,6 +379,8 @@ macro_rules! match_dtype_to_physical_apply_macro {
             DataType::Int64 => $macro!(i64 $(, $opt_args)*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $macro!(i128 $(, $opt_args)*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $macro!(f16 $(, $opt_args)*),
             DataType::Float32 => $macro!(f32 $(, $opt_args)*),
             DataType::Float64 => $macro!(f64 $(, $opt_args)*),
             dt => panic!("not implemented for dtype {:?}", dt),
@@ -398,6 +402,8 @@ macro_rules! match_dtype_to_logical_apply_macro {
             DataType::UInt16 => $macro!(UInt16Type $(, $opt_args)*),
             DataType::UInt32 => $macro!(UInt32Type $(, $opt_args)*),
             DataType::UInt64 => $macro!(UInt64Type $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!(UInt128Type $(, $opt_args)*),
             #[cfg(feature = "dtype-i8")]
             DataType::Int8 => $macro!(Int8Type $(, $opt_args)*),
             #[cfg(feature = "dtype-i16")]
@@ -406,6 +412,8 @@ macro_rules! match_dtype_to_logical_apply_macro {
             DataType::Int64 => $macro!(Int64Type $(, $opt_args)*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $macro!(Int128Type $(, $opt_args)*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $macro!(Float16Type $(, $opt_args)*),
             DataType::Float32 => $macro!(Float32Type $(, $opt_args)*),
             DataType::Float64 => $macro!(Float64Type $(, $opt_args)*),
             dt => panic!("not implemented for dtype {:?}", dt),
@@ -426,6 +434,8 @@ macro_rules! match_arrow_dtype_apply_macro_ca {
             DataType::UInt16 => $macro!($self.u16().unwrap() $(, $opt_args)*),
             DataType::UInt32 => $macro!($self.u32().unwrap() $(, $opt_args)*),
             DataType::UInt64 => $macro!($self.u64().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!($self.u128().unwrap() $(, $opt_args)*),
             #[cfg(feature = "dtype-i8")]
             DataType::Int8 => $macro!($self.i8().unwrap() $(, $opt_args)*),
             #[cfg(feature = "dtype-i16")]
@@ -434,6 +444,8 @@ macro_rules! match_arrow_dtype_apply_macro_ca {
             DataType::Int64 => $macro!($self.i64().unwrap() $(, $opt_args)*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $macro!($self.i128().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $macro!($self.f16().unwrap() $(, $opt_args)*),
             DataType::Float32 => $macro!($self.f32().unwrap() $(, $opt_args)*),
             DataType::Float64 => $macro!($self.f64().unwrap() $(, $opt_args)*),
             dt => panic!("not implemented for dtype {:?}", dt),
@@ -462,6 +474,10 @@ macro_rules! with_match_physical_numeric_type {(
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { u128 },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -489,6 +505,8 @@ macro_rules! with_match_physical_integer_type {(
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
         UInt64 => __with_ty__! { u64 },
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { u128 },
         dt => panic!("not implemented for dtype {:?}", dt),
     }
 })}
@@ -500,6 +518,8 @@ macro_rules! with_match_physical_float_type {(
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use $crate::datatypes::DataType::*;
     match $dtype {
+        #[cfg(feature = "dtype-f16")]
+        Float16 => __with_ty__! { f16 },
         Float32 => __with_ty__! { f32 },
         Float64 => __with_ty__! { f64 },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -513,6 +533,8 @@ macro_rules! with_match_physical_float_polars_type {(
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use $crate::datatypes::DataType::*;
     match $key_type {
+        #[cfg(feature = "dtype-f16")]
+        Float16 => __with_ty__! { Float16Type },
         Float32 => __with_ty__! { Float32Type },
         Float64 => __with_ty__! { Float64Type },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -540,6 +562,10 @@ macro_rules! with_match_physical_numeric_polars_type {(
         UInt16 => __with_ty__! { UInt16Type },
         UInt32 => __with_ty__! { UInt32Type },
         UInt64 => __with_ty__! { UInt64Type },
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { UInt128Type },
+        #[cfg(feature = "dtype-f16")]
+        Float16 => __with_ty__! { Float16Type },
         Float32 => __with_ty__! { Float32Type },
         Float64 => __with_ty__! { Float64Type },
         dt => panic!("not implemented for dtype {:?}", dt),
@@ -568,6 +594,8 @@ macro_rules! with_match_physical_integer_polars_type {(
         UInt16 => __with_ty__! { UInt16Type },
         UInt32 => __with_ty__! { UInt32Type },
         UInt64 => __with_ty__! { UInt64Type },
+        #[cfg(feature = "dtype-u128")]
+        UInt128 => __with_ty__! { UInt128Type },
         dt => panic!("not implemented for dtype {:?}", dt),
     }
 })}
@@ -584,6 +612,8 @@ macro_rules! downcast_as_macro_arg_physical {
             DataType::UInt16 => $macro!($self.u16().unwrap() $(, $opt_args)*),
             DataType::UInt32 => $macro!($self.u32().unwrap() $(, $opt_args)*),
             DataType::UInt64 => $macro!($self.u64().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $macro!($self.u128().unwrap() $(, $opt_args)*),
             #[cfg(feature = "dtype-i8")]
             DataType::Int8 => $macro!($self.i8().unwrap() $(, $opt_args)*),
             #[cfg(feature = "dtype-i16")]
@@ -592,6 +622,8 @@ macro_rules! downcast_as_macro_arg_physical {
             DataType::Int64 => $macro!($self.i64().unwrap() $(, $opt_args)*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $macro!($self.i128().unwrap() $(, $opt_args)*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $macro!($self.f16().unwrap() $(, $opt_args)*),
             DataType::Float32 => $macro!($self.f32().unwrap() $(, $opt_args)*),
             DataType::Float64 => $macro!($self.f64().unwrap() $(, $opt_args)*),
             dt => panic!("not implemented for {:?}", dt),
@@ -624,6 +656,11 @@ macro_rules! downcast_as_macro_arg_physical_mut {
                 let ca: &mut UInt64Chunked = $self.as_mut();
                 $macro!(UInt64Type, ca $(, $opt_args)*)
             },
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => {
+                let ca: &mut UInt128Chunked = $self.as_mut();
+                $macro!(UInt128Type, ca $(, $opt_args)*)
+            },
             #[cfg(feature = "dtype-i8")]
             DataType::Int8 => {
                 let ca: &mut Int8Chunked = $self.as_mut();
@@ -647,6 +684,11 @@ macro_rules! downcast_as_macro_arg_physical_mut {
                 let ca: &mut Int128Chunked = $self.as_mut();
                 $macro!(Int128Type, ca $(, $opt_args)*)
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let ca: &mut Float16Chunked = $self.as_mut();
+                $macro!(Float16Type, ca $(, $opt_args)*)
+            },
             DataType::Float32 => {
                 let ca: &mut Float32Chunked = $self.as_mut();
                 $macro!(Float32Type, ca $(, $opt_args)*)
@@ -672,6 +714,8 @@ macro_rules! apply_method_all_arrow_series {
             DataType::UInt16 => $self.u16().unwrap().$method($($args),*),
             DataType::UInt32 => $self.u32().unwrap().$method($($args),*),
             DataType::UInt64 => $self.u64().unwrap().$method($($args),*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $self.u128().unwrap().$method($($args),*),
             #[cfg(feature = "dtype-i8")]
             DataType::Int8 => $self.i8().unwrap().$method($($args),*),
             #[cfg(feature = "dtype-i16")]
@@ -680,6 +724,8 @@ macro_rules! apply_method_all_arrow_series {
             DataType::Int64 => $self.i64().unwrap().$method($($args),*),
             #[cfg(feature = "dtype-i128")]
             DataType::Int128 => $self.i128().unwrap().$method($($args),*),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $self.f16().unwrap().$method($($args),*),
             DataType::Float32 => $self.f32().unwrap().$method($($args),*),
             DataType::Float64 => $self.f64().unwrap().$method($($args),*),
             DataType::Time => $self.time().unwrap().$method($($args),*),
@@ -702,6 +748,8 @@ macro_rules! apply_method_physical_integer {
             DataType::UInt16 => $self.u16().unwrap().$method($($args),*),
             DataType::UInt32 => $self.u32().unwrap().$method($($args),*),
             DataType::UInt64 => $self.u64().unwrap().$method($($args),*),
+            #[cfg(feature = "dtype-u128")]
+            DataType::UInt128 => $self.u128().unwrap().$method($($args),*),
             #[cfg(feature = "dtype-i8")]
             DataType::Int8 => $self.i8().unwrap().$method($($args),*),
             #[cfg(feature = "dtype-i16")]
@@ -720,6 +768,8 @@ macro_rules! apply_method_physical_integer {
 macro_rules! apply_method_physical_numeric {
     ($self:expr, $method:ident, $($args:expr),*) => {
         match $self.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => $self.f16().unwrap().$method($($args),*),
             DataType::Float32 => $self.f32().unwrap().$method($($args),*),
             DataType::Float64 => $self.f64().unwrap().$method($($args),*),
             _ => apply_method_physical_integer!($self, $method, $($args),*),
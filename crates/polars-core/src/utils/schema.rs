@@ -17,3 +17,33 @@ where
         .map(|(i, dtype)| Field::new(format_pl_smallstr!("column_{i}"), dtype))
         .collect()
 }
+
+/// Whether `incoming` holds the same (nested) struct fields as `target`, but in a different order.
+///
+/// Casting matches struct fields by name, so `incoming` can be cast to `target`.
+#[cfg(feature = "dtype-struct")]
+pub fn struct_fields_reordered(target: &DataType, incoming: &DataType) -> bool {
+    use DataType::*;
+
+    let matches = |target: &DataType, incoming: &DataType| {
+        target == incoming || struct_fields_reordered(target, incoming)
+    };
+
+    match (target, incoming) {
+        (Struct(target_fields), Struct(incoming_fields)) => {
+            target_fields.len() == incoming_fields.len()
+                && target_fields.iter().all(|target_fld| {
+                    incoming_fields
+                        .iter()
+                        .find(|fld| fld.name() == target_fld.name())
+                        .is_some_and(|fld| matches(target_fld.dtype(), fld.dtype()))
+                })
+        },
+        (List(target_inner), List(incoming_inner)) => matches(target_inner, incoming_inner),
+        #[cfg(feature = "dtype-array")]
+        (Array(target_inner, target_width), Array(incoming_inner, incoming_width)) => {
+            target_width == incoming_width && matches(target_inner, incoming_inner)
+        },
+        _ => false,
+    }
+}
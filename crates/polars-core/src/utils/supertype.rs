@@ -31,6 +31,7 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
     } else if l.is_float() && r.is_float() {
         match (l, r) {
             (Float64, _) | (_, Float64) => Some(Float64),
+            (Float32, _) | (_, Float32) => Some(Float32),
             v => {
                 // Did we add a new float type?
                 if cfg!(debug_assertions) {
@@ -57,6 +58,7 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
         }
     } else if l.is_unsigned_integer() && r.is_unsigned_integer() {
         match (l, r) {
+            (UInt128, _) | (_, UInt128) => Some(UInt128),
             (UInt64, _) | (_, UInt64) => Some(UInt64),
             (UInt32, _) | (_, UInt32) => Some(UInt32),
             (UInt16, _) | (_, UInt16) => Some(UInt16),
@@ -73,6 +75,8 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
         // One side is signed, the other is unsigned. We just need to upcast the
         // unsigned side to a signed integer with the next-largest bit width.
         match (l, r) {
+            // No signed integer can hold every UInt128.
+            (UInt128, _) | (_, UInt128) => None,
             (UInt64, _) | (_, UInt64) | (Int128, _) | (_, Int128) => Some(Int128),
             (UInt32, _) | (_, UInt32) | (Int64, _) | (_, Int64) => Some(Int64),
             (UInt16, _) | (_, UInt16) | (Int32, _) | (_, Int32) => Some(Int32),
@@ -194,6 +198,22 @@ pub fn get_supertype_with_options(
             (Int16, Float64) => Some(Float64),
 
 
+            #[cfg(feature = "dtype-u128")]
+            (a, UInt128) if a.is_unsigned_integer() | a.is_bool() => Some(UInt128),
+            #[cfg(feature = "dtype-u128")]
+            (a, UInt128) if a.is_signed_integer() => Some(Int128),
+            #[cfg(feature = "dtype-u128")]
+            (a, UInt128) if a.is_float() => Some(Float64),
+
+            #[cfg(feature = "dtype-f16")]
+            (Boolean | Int8 | UInt8, Float16) => Some(Float16),
+            #[cfg(feature = "dtype-f16")]
+            (Int16 | UInt16, Float16) => Some(Float32), // Follow numpy
+            #[cfg(feature = "dtype-f16")]
+            (a, Float16) if a.is_integer() => Some(Float64), // Follow numpy
+            #[cfg(feature = "dtype-f16")]
+            (a @ (Float32 | Float64), Float16) => Some(a.clone()),
+
             #[cfg(feature = "dtype-i128")]
             (a, Int128) if a.is_integer() | a.is_bool() => Some(Int128),
             #[cfg(feature = "dtype-i128")]
@@ -332,6 +352,15 @@ pub fn get_supertype_with_options(
             #[cfg(feature = "dtype-time")]
             (Time, Float64) => Some(Float64),
 
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            (FixedSizeBinary(_), Binary) => Some(Binary),
+            // Strings are parsed, so that uuids can be compared with string literals.
+            #[cfg(feature = "dtype-uuid")]
+            (Uuid, String) => Some(Uuid),
+            // Documents are compared by their text.
+            #[cfg(feature = "dtype-json")]
+            (Json, String) => Some(String),
+
             // Every known type can be cast to a string except binary
             (dt, String) if !matches!(dt, Unknown(UnknownKind::Any)) && dt != &Binary && options.allow_primitive_to_string() || !dt.to_physical().is_primitive() => Some(String),
             (String, Binary) => Some(Binary),
@@ -353,6 +382,8 @@ pub fn get_supertype_with_options(
             (Duration(_), Date) | (Date, Duration(_)) => Some(Date),
             #[cfg(feature = "dtype-duration")]
             (Duration(lu), Duration(ru)) => Some(Duration(get_time_units(lu, ru))),
+            #[cfg(feature = "dtype-interval")]
+            (Duration(_), Interval) | (Interval, Duration(_)) => Some(Interval),
 
             // both None or both Some("<tz>") timezones
             // we cast from more precision to higher precision as that always fits with occasional loss of precision
@@ -405,7 +436,7 @@ pub fn get_supertype_with_options(
                         }
                     },
                     // numeric vs float|str -> always float|str|decimal
-                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
+                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() => Some(dt.clone()),
                     UnknownKind::Float if dt.is_integer() => Some(Unknown(UnknownKind::Float)),
                     // Materialize float to float or decimal
                     UnknownKind::Float if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
@@ -444,7 +475,19 @@ pub fn get_supertype_with_options(
                             }
                         }
                     }
-                    UnknownKind::Int(_) if dt.is_decimal() => Some(dt.clone()),
+                    // Widen the precision if the literal doesn't fit.
+                    #[cfg(feature = "dtype-decimal")]
+                    UnknownKind::Int(v) if dt.is_decimal() => {
+                        let Decimal(p, s) = dt else { unreachable!() };
+                        let precision = match (p, s) {
+                            (Some(p), Some(s)) => {
+                                let digits = v.unsigned_abs().checked_ilog10().map_or(1, |d| d as usize + 1);
+                                Some((p.saturating_sub(*s).max(digits) + s).min(38))
+                            },
+                            _ => *p,
+                        };
+                        Some(Decimal(precision, *s))
+                    },
                     _ => Some(Unknown(UnknownKind::Any))
                 }
             },
@@ -463,12 +506,31 @@ pub fn get_supertype_with_options(
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(p1, s1), Decimal(p2, s2)) => {
-                Some(Decimal((*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)), (*s1).max(*s2)))
+                let scale = (*s1).max(*s2);
+                // Keep enough integer digits for both sides, so the cast to the supertype
+                // cannot overflow.
+                let precision = match (p1, s1, p2, s2) {
+                    (Some(p1), Some(s1), Some(p2), Some(s2)) => {
+                        let integer_digits = p1.saturating_sub(*s1).max(p2.saturating_sub(*s2));
+                        Some((integer_digits + scale.unwrap_or(0)).min(38))
+                    },
+                    _ => (*p1).zip(*p2).map(|(p1, p2)| p1.max(p2)),
+                };
+                Some(Decimal(precision, scale))
             }
             #[cfg(feature = "dtype-decimal")]
             (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
             #[cfg(feature = "dtype-decimal")]
-            (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
+            (Decimal(p, s), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => {
+                let precision = match (p, s) {
+                    (Some(p), Some(s)) => {
+                        let integer_digits = p.saturating_sub(*s).max(integer_decimal_digits(dt));
+                        Some((integer_digits + s).min(38))
+                    },
+                    _ => *p,
+                };
+                Some(Decimal(precision, *s))
+            },
             _ => None,
         }
     }
@@ -536,6 +598,20 @@ fn super_type_structs(fields_a: &[Field], fields_b: &[Field]) -> Option<DataType
     }
 }
 
+/// The number of decimal digits needed to represent any value of the integer type `dtype`.
+#[cfg(feature = "dtype-decimal")]
+fn integer_decimal_digits(dtype: &DataType) -> usize {
+    use DataType::*;
+    match dtype {
+        Int8 | UInt8 => 3,
+        Int16 | UInt16 => 5,
+        Int32 | UInt32 => 10,
+        Int64 => 19,
+        UInt64 => 20,
+        _ => 38,
+    }
+}
+
 pub fn materialize_dyn_int(v: i128) -> AnyValue<'static> {
     // Try to get the "smallest" fitting value.
     // TODO! next breaking go to true smallest.
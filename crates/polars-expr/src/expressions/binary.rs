@@ -45,8 +45,37 @@ impl BinaryExpr {
     }
 }
 
+/// `Date`/`Datetime` +/- `Interval` needs the calendar-aware offset logic of polars-time, so it
+/// cannot be dispatched to the arithmetic of polars-core.
+#[cfg(feature = "dtype-interval")]
+fn apply_interval_offset(
+    left: &Column,
+    right: &Column,
+    op: Operator,
+) -> Option<PolarsResult<Column>> {
+    use DataType::*;
+    let (ts, interval) = match (left.dtype(), right.dtype(), op) {
+        (Date | Datetime(_, _), Interval, Operator::Plus | Operator::Minus) => (left, right),
+        (Interval, Date | Datetime(_, _), Operator::Plus) => (right, left),
+        _ => return None,
+    };
+    let out = (|| {
+        let mut interval = interval.as_materialized_series().clone();
+        if op == Operator::Minus {
+            interval = interval.interval()?.negate()?.into_series();
+        }
+        let out = polars_time::impl_offset_by(ts.as_materialized_series(), &interval)?;
+        Ok(out.with_name(left.name().clone()).into_column())
+    })();
+    Some(out)
+}
+
 /// Can partially do operations in place.
 fn apply_operator_owned(left: Column, right: Column, op: Operator) -> PolarsResult<Column> {
+    #[cfg(feature = "dtype-interval")]
+    if let Some(out) = apply_interval_offset(&left, &right, op) {
+        return out;
+    }
     match op {
         Operator::Plus => left.try_add_owned(right),
         Operator::Minus => left.try_sub_owned(right),
@@ -61,6 +90,10 @@ fn apply_operator_owned(left: Column, right: Column, op: Operator) -> PolarsResu
 
 pub fn apply_operator(left: &Column, right: &Column, op: Operator) -> PolarsResult<Column> {
     use DataType::*;
+    #[cfg(feature = "dtype-interval")]
+    if let Some(out) = apply_interval_offset(left, right, op) {
+        return out;
+    }
     match op {
         Operator::Gt => ChunkCompareIneq::gt(left, right).map(|ca| ca.into_column()),
         Operator::GtEq => ChunkCompareIneq::gt_eq(left, right).map(|ca| ca.into_column()),
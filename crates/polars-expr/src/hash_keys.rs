@@ -4,10 +4,13 @@ use std::hash::BuildHasher;
 use arrow::array::{Array, BinaryArray, BinaryViewArray, PrimitiveArray, StaticArray, UInt64Array};
 use arrow::bitmap::Bitmap;
 use arrow::compute::utils::combine_validities_and_many;
-use polars_core::error::polars_err;
+#[cfg(feature = "dtype-categorical")]
+use polars_core::error::{PolarsResult, polars_bail, polars_err};
 use polars_core::frame::DataFrame;
 use polars_core::prelude::row_encode::_get_rows_encoded_unordered;
 use polars_core::prelude::{ChunkedArray, DataType, PlRandomState, PolarsDataType};
+#[cfg(feature = "dtype-categorical")]
+use polars_core::prelude::{IntoColumn, Schema, remap_nested_categoricals};
 use polars_core::series::Series;
 use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
@@ -85,6 +88,44 @@ macro_rules! downcast_single_key_ca {
 }
 pub(crate) use downcast_single_key_ca;
 
+/// Re-encodes the categorical keys to the mappings in `key_schema`, so that equal categories
+/// get equal physicals regardless of the mapping they were built with.
+///
+/// Keys without a mapping in `key_schema` must use the global string cache.
+#[cfg(feature = "dtype-categorical")]
+pub fn remap_categorical_keys(keys: DataFrame, key_schema: &Schema) -> PolarsResult<DataFrame> {
+    if !key_schema
+        .iter_values()
+        .any(|dt| dt.contains_categoricals())
+    {
+        return Ok(keys);
+    }
+
+    let height = keys.height();
+    let columns = keys
+        .take_columns()
+        .into_iter()
+        .zip(key_schema.iter_values())
+        .map(|(key, dtype)| {
+            if let (DataType::Categorical(None, _), DataType::Categorical(Some(rev_map), _)) =
+                (dtype, key.dtype())
+            {
+                if !rev_map.is_active_global() {
+                    polars_bail!(string_cache_mismatch);
+                }
+            }
+            Ok(
+                match remap_nested_categoricals(key.as_materialized_series(), dtype)? {
+                    Some(s) => s.into_column(),
+                    None => key,
+                },
+            )
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    // SAFETY: the remapped keys keep their names and height.
+    Ok(unsafe { DataFrame::new_no_checks(height, columns) })
+}
+
 /// Represents a DataFrame plus a hash per row, intended for keys in grouping
 /// or joining. The hashes may or may not actually be physically pre-computed,
 /// this depends per type.
@@ -108,16 +149,6 @@ impl HashKeys {
             || first_col_variant == HashKeysVariant::RowEncoded;
         if use_row_encoding {
             let keys = df.get_columns();
-            #[cfg(feature = "dtype-categorical")]
-            for key in keys {
-                if let DataType::Categorical(Some(rev_map), _) = key.dtype() {
-                    assert!(
-                        rev_map.is_active_global(),
-                        "{}",
-                        polars_err!(string_cache_mismatch)
-                    );
-                }
-            }
             let mut keys_encoded = _get_rows_encoded_unordered(keys).unwrap().into_array();
 
             if !null_is_valid {
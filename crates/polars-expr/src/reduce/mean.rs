@@ -1,6 +1,10 @@
 use std::marker::PhantomData;
 
 use num_traits::{AsPrimitive, Zero};
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{
+    _decimal_mean, _get_decimal_precision_mean_var_std, _get_decimal_scale_mean_var_std,
+};
 use polars_core::with_match_physical_numeric_polars_type;
 
 use super::*;
@@ -16,7 +20,7 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumMeanReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalMeanReducer)),
 
         // For compatibility with the current engine, should probably be an error.
         String | Binary => Box::new(super::NullGroupedReduction::new(dtype)),
@@ -41,15 +45,6 @@ fn finish_output(values: Vec<(f64, usize)>, dtype: &DataType) -> Series {
                 .collect_ca(PlSmallStr::EMPTY);
             ca.into_series()
         },
-        #[cfg(feature = "dtype-decimal")]
-        DataType::Decimal(_prec, scale) => {
-            let inv_scale_factor = 1.0 / 10u128.pow(scale.unwrap() as u32) as f64;
-            let ca: Float64Chunked = values
-                .into_iter()
-                .map(|(s, c)| (c != 0).then(|| s / c as f64 * inv_scale_factor))
-                .collect_ca(PlSmallStr::EMPTY);
-            ca.into_series()
-        },
         #[cfg(feature = "dtype-datetime")]
         DataType::Date => {
             const MS_IN_DAY: i64 = 86_400_000;
@@ -122,6 +117,83 @@ where
     }
 }
 
+/// Computes the exact mean of decimals from their exact sum.
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalMeanReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalMeanReducer {
+    type Dtype = Int128Type;
+    // The sum, None if it overflowed, and the count.
+    type Value = (Option<i128>, usize);
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        (Some(0), 0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.0 = a.0.zip(b.0).and_then(|(a, b)| a.checked_add(b));
+        a.1 += b.1;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        if let Some(b) = b {
+            a.0 = a.0.and_then(|a| a.checked_add(b));
+            a.1 += 1;
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            if let Some(acc) = v.0 {
+                v.0 = arr
+                    .non_null_values_iter()
+                    .try_fold(acc, |acc, x| acc.checked_add(x));
+            }
+        }
+        v.1 += ca.len() - ca.null_count();
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(_, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let means = v
+            .into_iter()
+            .map(|(sum, count)| {
+                let Some(sum) = super::sum::check_decimal_overflow(sum, None, "mean")? else {
+                    return Ok(None);
+                };
+                if count == 0 {
+                    return Ok(None);
+                }
+                super::sum::check_decimal_overflow(_decimal_mean(sum, count, *scale), None, "mean")
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let ca = Int128Chunked::from_iter_options(PlSmallStr::EMPTY, means.into_iter());
+        Ok(ca
+            .into_decimal_unchecked(
+                _get_decimal_precision_mean_var_std(),
+                _get_decimal_scale_mean_var_std(*scale),
+            )
+            .into_series())
+    }
+}
+
 #[derive(Clone)]
 struct BoolMeanReducer;
 
@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use arrow::array::PrimitiveArray;
 use num_traits::Zero;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::_get_decimal_precision_sum;
 use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::float::IsFloat;
 
@@ -30,6 +32,10 @@ impl_sum_cast!(
 impl_sum_cast!(u32, u64, i32, i64, f32, f64);
 #[cfg(feature = "dtype-i128")]
 impl_sum_cast!(i128);
+#[cfg(feature = "dtype-u128")]
+impl_sum_cast!(u128);
+#[cfg(feature = "dtype-f16")]
+impl_sum_cast!(f16);
 
 fn out_dtype(in_dtype: &DataType) -> DataType {
     use DataType::*;
@@ -51,7 +57,7 @@ pub fn new_sum_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(dtype, NumSumReducer::<Int128Type>(PhantomData))),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalSumReducer)),
         Duration(_) => Box::new(VGR::new(dtype, NumSumReducer::<Int64Type>(PhantomData))),
         // For compatibility with the current engine, should probably be an error.
         String | Binary => Box::new(super::NullGroupedReduction::new(dtype)),
@@ -126,6 +132,88 @@ where
     }
 }
 
+/// Sums the physical values of decimals, tracking overflow instead of wrapping around.
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalSumReducer;
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalSumReducer {
+    type Dtype = Int128Type;
+    // None if the sum overflowed.
+    type Value = Option<i128>;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        Some(0)
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        *a = a.zip(*b).and_then(|(a, b)| a.checked_add(b));
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        if let Some(b) = b {
+            *a = a.and_then(|a| a.checked_add(b));
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            let Some(acc) = *v else { return };
+            *v = arr
+                .non_null_values_iter()
+                .try_fold(acc, |acc, x| acc.checked_add(x));
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(precision, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let precision = _get_decimal_precision_sum(*precision);
+        let sums = v
+            .into_iter()
+            .map(|sum| check_decimal_overflow(sum, precision, "sum"))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let ca = Int128Chunked::from_iter_options(PlSmallStr::EMPTY, sums.into_iter());
+        Ok(ca.into_decimal_unchecked(precision, *scale).into_series())
+    }
+}
+
+/// Validates a decimal aggregate against `precision`, where `None` means the computation
+/// overflowed.
+///
+/// Depending on the configured overflow behavior, this either raises or returns null.
+#[cfg(feature = "dtype-decimal")]
+pub(super) fn check_decimal_overflow(
+    value: Option<i128>,
+    precision: Option<usize>,
+    op_name: &str,
+) -> PolarsResult<Option<i128>> {
+    let upper = 10_i128.pow(precision.unwrap_or(38) as u32);
+    match value {
+        Some(v) if v > -upper && v < upper => Ok(Some(v)),
+        _ if polars_core::config::decimal_overflow_to_null() => Ok(None),
+        _ => polars_bail!(
+            ComputeError: "decimal {} overflow: result does not fit in a decimal of precision {}",
+            op_name, precision.unwrap_or(38)
+        ),
+    }
+}
+
 #[derive(Clone)]
 struct BoolSumReducer;
 
@@ -2,6 +2,10 @@ use std::marker::PhantomData;
 
 use num_traits::AsPrimitive;
 use polars_compute::moment::VarState;
+#[cfg(feature = "dtype-decimal")]
+use polars_core::chunked_array::arithmetic::{
+    _get_decimal_precision_mean_var_std, _get_decimal_scale_mean_var_std, DecimalVarState,
+};
 use polars_core::with_match_physical_numeric_polars_type;
 
 use super::*;
@@ -16,21 +20,12 @@ pub fn new_var_std_reduction(dtype: DataType, is_std: bool, ddof: u8) -> Box<dyn
                 Box::new(VGR::new(dtype, VarStdReducer::<$T> {
                     is_std,
                     ddof,
-                    needs_cast: false,
                     _phantom: PhantomData,
                 }))
             })
         },
         #[cfg(feature = "dtype-decimal")]
-        Decimal(_, _) => Box::new(VGR::new(
-            dtype,
-            VarStdReducer::<Float64Type> {
-                is_std,
-                ddof,
-                needs_cast: true,
-                _phantom: PhantomData,
-            },
-        )),
+        Decimal(_, _) => Box::new(VGR::new(dtype, DecimalVarStdReducer { is_std, ddof })),
         Duration(..) => todo!(),
         _ => unimplemented!(),
     }
@@ -39,7 +34,6 @@ pub fn new_var_std_reduction(dtype: DataType, is_std: bool, ddof: u8) -> Box<dyn
 struct VarStdReducer<T> {
     is_std: bool,
     ddof: u8,
-    needs_cast: bool,
     _phantom: PhantomData<T>,
 }
 
@@ -48,7 +42,6 @@ impl<T> Clone for VarStdReducer<T> {
         Self {
             is_std: self.is_std,
             ddof: self.ddof,
-            needs_cast: self.needs_cast,
             _phantom: PhantomData,
         }
     }
@@ -62,14 +55,6 @@ impl<T: PolarsNumericType> Reducer for VarStdReducer<T> {
         VarState::default()
     }
 
-    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
-        if self.needs_cast {
-            Cow::Owned(s.cast(&DataType::Float64).unwrap())
-        } else {
-            Cow::Borrowed(s)
-        }
-    }
-
     fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
         a.combine(b)
     }
@@ -105,6 +90,77 @@ impl<T: PolarsNumericType> Reducer for VarStdReducer<T> {
     }
 }
 
+#[cfg(feature = "dtype-decimal")]
+#[derive(Clone)]
+struct DecimalVarStdReducer {
+    is_std: bool,
+    ddof: u8,
+}
+
+#[cfg(feature = "dtype-decimal")]
+impl Reducer for DecimalVarStdReducer {
+    type Dtype = Int128Type;
+    type Value = DecimalVarState;
+
+    fn init(&self) -> Self::Value {
+        DecimalVarState::default()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b)
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<i128>, _seq_id: u64) {
+        if let Some(x) = b {
+            a.insert_one(x);
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for arr in ca.downcast_iter() {
+            for x in arr.non_null_values_iter() {
+                v.insert_one(x);
+            }
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let DataType::Decimal(_, Some(scale)) = dtype else {
+            unreachable!()
+        };
+        let to_null = polars_core::config::decimal_overflow_to_null();
+        let out = v
+            .into_iter()
+            .map(|s| match s.finalize(self.ddof, *scale, self.is_std) {
+                Some(out) => Ok(out),
+                None if to_null => Ok(None),
+                None => polars_bail!(
+                    ComputeError: "decimal {} overflow",
+                    if self.is_std { "std" } else { "var" }
+                ),
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let ca = Int128Chunked::from_iter_options(PlSmallStr::EMPTY, out.into_iter());
+        Ok(ca
+            .into_decimal_unchecked(
+                _get_decimal_precision_mean_var_std(),
+                _get_decimal_scale_mean_var_std(*scale),
+            )
+            .into_series())
+    }
+}
+
 #[derive(Clone)]
 struct BoolVarStdReducer {
     is_std: bool,
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
-use futures::TryStreamExt;
+use futures::{StreamExt, TryStreamExt};
+use object_store::ObjectMeta;
 use object_store::path::Path;
 use polars_core::error::to_compute_err;
 use polars_error::{PolarsResult, polars_bail};
@@ -9,7 +10,8 @@ use polars_utils::pl_str::PlSmallStr;
 use regex::Regex;
 use url::Url;
 
-use super::{CloudOptions, parse_url};
+use super::{CloudOptions, PolarsObjectStore, parse_url};
+use crate::path_utils::hive_filter::HivePathFilter;
 
 const DELIMITER: char = '/';
 
@@ -199,8 +201,94 @@ impl Matcher {
     }
 }
 
+/// List all non-empty objects under `prefix` one directory level at a time, skipping hive
+/// partition directories rejected by `hive_filter`.
+pub(crate) async fn list_hive_pruned(
+    store: &PolarsObjectStore,
+    prefix: &Path,
+    hive_filter: &HivePathFilter,
+) -> PolarsResult<Vec<ObjectMeta>> {
+    let concurrency = crate::pl_async::get_concurrency_limit() as usize;
+
+    store
+        .try_exec_rebuild_on_err(|store| {
+            let st = store.clone();
+
+            async move {
+                let store = &st;
+                let mut out = vec![];
+                let mut level = vec![prefix.clone()];
+
+                while !level.is_empty() {
+                    let mut results = futures::stream::iter(std::mem::take(&mut level))
+                        .map(|dir| async move { store.list_with_delimiter(Some(&dir)).await })
+                        .buffer_unordered(concurrency);
+
+                    while let Some(result) = results.next().await {
+                        let result = result.map_err(to_compute_err)?;
+
+                        out.extend(result.objects.into_iter().filter(|x| x.size > 0));
+                        level.extend(
+                            result
+                                .common_prefixes
+                                .into_iter()
+                                .filter(|x| x.filename().is_none_or(|x| hive_filter.keep_dir(x))),
+                        );
+                    }
+                }
+
+                Ok(out)
+            }
+        })
+        .await
+}
+
+/// Finds the first non-empty object under `prefix` by descending into one directory at a time,
+/// so that only the directories on the way to that object are listed.
+pub(crate) async fn first_object(
+    store: &PolarsObjectStore,
+    prefix: &Path,
+) -> PolarsResult<Option<ObjectMeta>> {
+    store
+        .try_exec_rebuild_on_err(|store| {
+            let st = store.clone();
+
+            async move {
+                let store = &st;
+                let mut stack = vec![prefix.clone()];
+
+                while let Some(dir) = stack.pop() {
+                    let mut result = store
+                        .list_with_delimiter(Some(&dir))
+                        .await
+                        .map_err(to_compute_err)?;
+
+                    result
+                        .objects
+                        .sort_unstable_by(|l, r| l.location.cmp(&r.location));
+                    if let Some(object) = result.objects.into_iter().find(|x| x.size > 0) {
+                        return Ok(Some(object));
+                    }
+
+                    // Pushed in reverse so that the directories are visited in sorted order.
+                    result.common_prefixes.sort_unstable_by(|l, r| r.cmp(l));
+                    stack.extend(result.common_prefixes);
+                }
+
+                Ok(None)
+            }
+        })
+        .await
+}
+
 /// List files with a prefix derived from the pattern.
-pub async fn glob(url: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<Vec<String>> {
+///
+/// If a `hive_filter` is given, hive partition directories that cannot match it are skipped.
+pub async fn glob(
+    url: &str,
+    cloud_options: Option<&CloudOptions>,
+    hive_filter: Option<&HivePathFilter>,
+) -> PolarsResult<Vec<String>> {
     // Find the fixed prefix, up to the first '*'.
 
     let (
@@ -222,28 +310,37 @@ pub async fn glob(url: &str, cloud_options: Option<&CloudOptions>) -> PolarsResu
         expansion.as_deref(),
     )?;
 
-    let path = Path::from(prefix.as_str());
-    let path = Some(&path);
+    let prefix_path = Path::from(prefix.as_str());
+    let path = Some(&prefix_path);
 
-    let mut locations = store
-        .try_exec_rebuild_on_err(|store| {
-            let st = store.clone();
-
-            async {
-                let store = st;
-                store
-                    .list(path)
-                    .try_filter_map(|x| async move {
-                        let out = (x.size > 0 && matcher.is_matching(x.location.as_ref()))
-                            .then_some(x.location);
-                        Ok(out)
-                    })
-                    .try_collect::<Vec<_>>()
-                    .await
-                    .map_err(to_compute_err)
-            }
-        })
-        .await?;
+    let mut locations = if let Some(hive_filter) = hive_filter {
+        list_hive_pruned(&store, &prefix_path, hive_filter)
+            .await?
+            .into_iter()
+            .filter(|x| matcher.is_matching(x.location.as_ref()))
+            .map(|x| x.location)
+            .collect()
+    } else {
+        store
+            .try_exec_rebuild_on_err(|store| {
+                let st = store.clone();
+
+                async {
+                    let store = st;
+                    store
+                        .list(path)
+                        .try_filter_map(|x| async move {
+                            let out = (x.size > 0 && matcher.is_matching(x.location.as_ref()))
+                                .then_some(x.location);
+                            Ok(out)
+                        })
+                        .try_collect::<Vec<_>>()
+                        .await
+                        .map_err(to_compute_err)
+                }
+            })
+            .await?
+    };
 
     locations.sort_unstable();
     Ok(locations
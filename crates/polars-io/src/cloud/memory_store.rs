@@ -0,0 +1,295 @@
+//! In-process object store for `memory://` urls.
+//!
+//! Objects are kept per bucket for the lifetime of the process, so data written to
+//! `memory://bucket/...` can be read back by later queries. Latency and transient failures can be
+//! injected through the storage options, which allows exercising cloud code paths (globbing,
+//! range requests, retries and store rebuilds) without a real service.
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+
+use futures::stream::BoxStream;
+use futures::{StreamExt, TryStreamExt};
+use object_store::memory::InMemory;
+use object_store::path::Path;
+use object_store::{
+    GetOptions, GetResult, ListResult, MultipartUpload, ObjectMeta, ObjectStore, PutMultipartOpts,
+    PutOptions, PutPayload, PutResult,
+};
+use polars_error::{PolarsResult, polars_bail, to_compute_err};
+use polars_utils::aliases::PlHashMap;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fault injection settings for a `memory://` bucket.
+///
+/// Request counting restarts whenever a bucket is used with different settings.
+#[derive(Clone, Debug, Default, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct MemoryStoreConfig {
+    /// Delay added to every request, in milliseconds.
+    pub latency_ms: u64,
+    /// Number of requests that fail before requests start succeeding.
+    pub fail_first_n: usize,
+    /// Fail every n-th request. `0` disables this.
+    pub fail_every_n: usize,
+}
+
+impl MemoryStoreConfig {
+    pub(crate) fn from_untyped_config<
+        I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>,
+    >(
+        config: I,
+    ) -> PolarsResult<Self> {
+        let mut out = Self::default();
+
+        for (k, v) in config {
+            let (k, v): (&str, String) = (k.as_ref(), v.into());
+
+            match k.to_ascii_lowercase().as_str() {
+                "latency_ms" => out.latency_ms = v.parse().map_err(to_compute_err)?,
+                "fail_first_n" => out.fail_first_n = v.parse().map_err(to_compute_err)?,
+                "fail_every_n" => out.fail_every_n = v.parse().map_err(to_compute_err)?,
+                _ => polars_bail!(
+                    ComputeError:
+                    "unknown configuration key for memory://: {}, expected one of \
+                    'latency_ms', 'fail_first_n', 'fail_every_n'", k
+                ),
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+struct MemoryBucket {
+    store: Arc<InMemory>,
+    config: MemoryStoreConfig,
+    requests: Arc<AtomicUsize>,
+}
+
+static MEMORY_BUCKETS: LazyLock<Mutex<PlHashMap<PlSmallStr, MemoryBucket>>> =
+    LazyLock::new(Default::default);
+
+/// Get the store of a `memory://` bucket, creating the bucket if it does not exist.
+pub(crate) fn build_memory_store(
+    bucket: &str,
+    config: Option<&MemoryStoreConfig>,
+) -> Arc<dyn ObjectStore> {
+    let default_config = MemoryStoreConfig::default();
+    let config = config.unwrap_or(&default_config);
+
+    let mut buckets = MEMORY_BUCKETS.lock().unwrap();
+    let bucket_state = buckets
+        .entry(PlSmallStr::from_str(bucket))
+        .or_insert_with(|| MemoryBucket {
+            store: Arc::new(InMemory::new()),
+            config: config.clone(),
+            requests: Default::default(),
+        });
+
+    if &bucket_state.config != config {
+        bucket_state.config = config.clone();
+        bucket_state.requests = Default::default();
+    }
+
+    if config.is_default() {
+        return bucket_state.store.clone();
+    }
+
+    Arc::new(FaultInjectingStore {
+        inner: bucket_state.store.clone(),
+        bucket: PlSmallStr::from_str(bucket),
+        config: config.clone(),
+        requests: bucket_state.requests.clone(),
+    })
+}
+
+/// Remove a `memory://` bucket and all objects in it.
+pub fn clear_memory_bucket(bucket: &str) {
+    MEMORY_BUCKETS.lock().unwrap().remove(bucket);
+}
+
+/// Wraps the store of a bucket to add latency and fail requests according to a
+/// [`MemoryStoreConfig`].
+#[derive(Debug, Clone)]
+struct FaultInjectingStore {
+    inner: Arc<InMemory>,
+    bucket: PlSmallStr,
+    config: MemoryStoreConfig,
+    /// Shared between all stores built for the bucket with the same config, so that a rebuilt
+    /// store continues counting.
+    requests: Arc<AtomicUsize>,
+}
+
+impl Display for FaultInjectingStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FaultInjectingStore(memory://{})", self.bucket)
+    }
+}
+
+impl FaultInjectingStore {
+    async fn before_request(&self) -> object_store::Result<()> {
+        if self.config.latency_ms > 0 {
+            tokio::time::sleep(Duration::from_millis(self.config.latency_ms)).await;
+        }
+
+        let request_idx = self.requests.fetch_add(1, Ordering::Relaxed);
+        let fail = request_idx < self.config.fail_first_n
+            || (self.config.fail_every_n > 0 && (request_idx + 1) % self.config.fail_every_n == 0);
+
+        if fail {
+            return Err(object_store::Error::Generic {
+                store: "memory",
+                source: format!(
+                    "injected transient error for request {} to memory://{}",
+                    request_idx + 1,
+                    self.bucket
+                )
+                .into(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ObjectStore for FaultInjectingStore {
+    async fn put_opts(
+        &self,
+        location: &Path,
+        payload: PutPayload,
+        opts: PutOptions,
+    ) -> object_store::Result<PutResult> {
+        self.before_request().await?;
+        self.inner.put_opts(location, payload, opts).await
+    }
+
+    async fn put_multipart_opts(
+        &self,
+        location: &Path,
+        opts: PutMultipartOpts,
+    ) -> object_store::Result<Box<dyn MultipartUpload>> {
+        self.before_request().await?;
+        self.inner.put_multipart_opts(location, opts).await
+    }
+
+    async fn get_opts(
+        &self,
+        location: &Path,
+        options: GetOptions,
+    ) -> object_store::Result<GetResult> {
+        self.before_request().await?;
+        self.inner.get_opts(location, options).await
+    }
+
+    async fn delete(&self, location: &Path) -> object_store::Result<()> {
+        self.before_request().await?;
+        self.inner.delete(location).await
+    }
+
+    fn list(&self, prefix: Option<&Path>) -> BoxStream<'static, object_store::Result<ObjectMeta>> {
+        let this = self.clone();
+        let prefix = prefix.cloned();
+
+        futures::stream::once(async move {
+            this.before_request()
+                .await
+                .map(|_| this.inner.list(prefix.as_ref()))
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    async fn list_with_delimiter(&self, prefix: Option<&Path>) -> object_store::Result<ListResult> {
+        self.before_request().await?;
+        self.inner.list_with_delimiter(prefix).await
+    }
+
+    async fn copy(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.before_request().await?;
+        self.inner.copy(from, to).await
+    }
+
+    async fn copy_if_not_exists(&self, from: &Path, to: &Path) -> object_store::Result<()> {
+        self.before_request().await?;
+        self.inner.copy_if_not_exists(from, to).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cloud::{CloudOptions, build_object_store};
+    use crate::pl_async::get_runtime;
+
+    #[test]
+    fn test_memory_store_shared_between_builds() {
+        get_runtime().block_on(async {
+            let (_, store) = build_object_store("memory://test-shared/a.bin", None, false)
+                .await
+                .unwrap();
+            let path = Path::from("a.bin");
+            store
+                .to_dyn_object_store()
+                .await
+                .put(&path, b"abc".to_vec().into())
+                .await
+                .unwrap();
+
+            let (_, store) = build_object_store("memory://test-shared/a.bin", None, false)
+                .await
+                .unwrap();
+            let bytes = store.get_range(&path, 1..3).await.unwrap();
+            assert_eq!(bytes.as_ref(), b"bc");
+        });
+
+        clear_memory_bucket("test-shared");
+    }
+
+    #[test]
+    fn test_memory_store_injected_failures() {
+        let options = CloudOptions::from_untyped_config(
+            "memory://test-faults",
+            [("fail_first_n", "1"), ("latency_ms", "1")],
+        )
+        .unwrap();
+
+        get_runtime().block_on(async {
+            let (_, store) = build_object_store("memory://test-faults/", Some(&options), false)
+                .await
+                .unwrap();
+            let path = Path::from("a.bin");
+
+            // The first request fails; the store is rebuilt and the retry succeeds.
+            store
+                .try_exec_rebuild_on_err(|store| {
+                    let store = store.clone();
+                    let path = path.clone();
+                    async move {
+                        store
+                            .put(&path, b"abc".to_vec().into())
+                            .await
+                            .map_err(to_compute_err)
+                    }
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(store.head(&path).await.unwrap().size, 3);
+        });
+
+        clear_memory_bucket("test-faults");
+
+        let err = CloudOptions::from_untyped_config("memory://x", [("region", "1")]).unwrap_err();
+        assert!(err.to_string().contains("unknown configuration key"));
+    }
+}
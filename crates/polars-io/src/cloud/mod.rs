@@ -5,6 +5,8 @@ mod adaptors;
 #[cfg(feature = "cloud")]
 mod glob;
 #[cfg(feature = "cloud")]
+mod memory_store;
+#[cfg(feature = "cloud")]
 mod object_store_setup;
 pub mod options;
 #[cfg(feature = "cloud")]
@@ -15,6 +17,8 @@ pub use adaptors::*;
 #[cfg(feature = "cloud")]
 pub use glob::*;
 #[cfg(feature = "cloud")]
+pub use memory_store::*;
+#[cfg(feature = "cloud")]
 pub use object_store_setup::*;
 pub use options::*;
 #[cfg(feature = "cloud")]
@@ -10,7 +10,9 @@ use polars_utils::{format_pl_smallstr, pl_serialize};
 use tokio::sync::RwLock;
 use url::Url;
 
-use super::{CloudLocation, CloudOptions, CloudType, PolarsObjectStore, parse_url};
+use super::{
+    CloudLocation, CloudOptions, CloudType, PolarsObjectStore, build_memory_store, parse_url,
+};
 use crate::cloud::CloudConfig;
 
 /// Object stores must be cached. Every object-store will do DNS lookups and
@@ -153,6 +155,14 @@ impl PolarsObjectStoreBuilder {
                 return err_missing_feature("http", &cloud_location.scheme);
             },
             CloudType::Hf => panic!("impl error: unresolved hf:// path"),
+            CloudType::Memory => {
+                let config = match &options.config {
+                    Some(CloudConfig::Memory(config)) => Some(config),
+                    _ => None,
+                };
+                let bucket = self.parsed_url.host_str().unwrap_or_default();
+                Ok(build_memory_store(bucket, config))
+            },
         }?;
 
         Ok(store)
@@ -165,7 +175,7 @@ impl PolarsObjectStoreBuilder {
                 &self.parsed_url,
                 self.options.as_ref(),
             )),
-            CloudType::File | CloudType::Http | CloudType::Hf => None,
+            CloudType::File | CloudType::Http | CloudType::Hf | CloudType::Memory => None,
         };
 
         let opt_cache_write_guard = if let Some(cache_key) = opt_cache_key.as_deref() {
@@ -209,6 +219,15 @@ impl PolarsObjectStoreBuilder {
         Ok(store)
     }
 
+    /// Scheme, host and port of the URL this store was built for, e.g. `s3://bucket`.
+    pub(crate) fn url_base(&self) -> &str {
+        &self.parsed_url[url::Position::BeforeScheme..url::Position::AfterPort]
+    }
+
+    pub(crate) fn is_local(&self) -> bool {
+        matches!(&self.cloud_type, CloudType::File)
+    }
+
     pub(crate) fn is_azure(&self) -> bool {
         matches!(&self.cloud_type, CloudType::Azure)
     }
@@ -31,6 +31,8 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "cloud")]
 use url::Url;
 
+#[cfg(feature = "cloud")]
+use super::MemoryStoreConfig;
 #[cfg(feature = "cloud")]
 use super::credential_provider::PlCredentialProvider;
 #[cfg(feature = "file_cache")]
@@ -73,6 +75,8 @@ pub(crate) enum CloudConfig {
     ),
     #[cfg(feature = "http")]
     Http { headers: Vec<(String, String)> },
+    #[cfg(feature = "cloud")]
+    Memory(MemoryStoreConfig),
 }
 
 #[derive(Clone, Debug, PartialEq, Hash, Eq)]
@@ -156,6 +160,7 @@ pub enum CloudType {
     Gcp,
     Http,
     Hf,
+    Memory,
 }
 
 impl CloudType {
@@ -168,6 +173,7 @@ impl CloudType {
             "file" => Self::File,
             "http" | "https" => Self::Http,
             "hf" => Self::Hf,
+            "memory" => Self::Memory,
             _ => polars_bail!(ComputeError: "unknown url scheme"),
         })
     }
@@ -562,6 +568,13 @@ impl CloudOptions {
         Ok(out)
     }
 
+    /// Set the latency and failure injection for `memory://` urls.
+    #[cfg(feature = "cloud")]
+    pub fn with_memory(mut self, config: MemoryStoreConfig) -> Self {
+        self.config = Some(CloudConfig::Memory(config));
+        self
+    }
+
     /// Parse a configuration from a Hashmap. This is the interface from Python.
     #[allow(unused_variables)]
     pub fn from_untyped_config<I: IntoIterator<Item = (impl AsRef<str>, impl Into<String>)>>(
@@ -593,6 +606,17 @@ impl CloudOptions {
             },
             CloudType::File => Ok(Self::default()),
             CloudType::Http => Ok(Self::default()),
+            CloudType::Memory => {
+                #[cfg(feature = "cloud")]
+                {
+                    MemoryStoreConfig::from_untyped_config(config)
+                        .map(|memory| Self::default().with_memory(memory))
+                }
+                #[cfg(not(feature = "cloud"))]
+                {
+                    polars_bail!(ComputeError: "'cloud' feature is not enabled");
+                }
+            },
             CloudType::Gcp => {
                 #[cfg(feature = "gcp")]
                 {
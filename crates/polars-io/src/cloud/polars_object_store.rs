@@ -1,4 +1,5 @@
 use std::ops::Range;
+use std::sync::Arc;
 
 use bytes::Bytes;
 use futures::{StreamExt, TryStreamExt};
@@ -10,6 +11,7 @@ use polars_error::{PolarsError, PolarsResult};
 use polars_utils::mmap::MemSlice;
 use tokio::io::{AsyncSeekExt, AsyncWriteExt};
 
+use crate::file_cache::{BlockCache, BlockKey, block_cache};
 use crate::pl_async::{
     self, MAX_BUDGET_PER_REQUEST, get_concurrency_limit, get_download_chunk_size,
     tune_with_concurrency_budget, with_concurrency_budget,
@@ -97,6 +99,15 @@ mod inner {
             Ok((*current_store).clone())
         }
 
+        /// Scheme, host and port of the URL this store was built for, e.g. `s3://bucket`.
+        pub fn url_base(&self) -> &str {
+            self.inner.builder.url_base()
+        }
+
+        pub fn is_local(&self) -> bool {
+            self.inner.builder.is_local()
+        }
+
         pub async fn try_exec_rebuild_on_err<Fn, Fut, O>(&self, mut func: Fn) -> PolarsResult<O>
         where
             Fn: FnMut(&Arc<dyn ObjectStore>) -> Fut,
@@ -168,7 +179,47 @@ impl PolarsObjectStore {
         .buffered(get_concurrency_limit() as usize)
     }
 
+    /// Returns the block cache along with the URI and remote version of `path`, if the block
+    /// cache is enabled.
+    async fn get_block_cache(
+        &self,
+        path: &Path,
+    ) -> PolarsResult<Option<(&'static BlockCache, Arc<str>, Arc<str>)>> {
+        let Some(block_cache) = block_cache()? else {
+            return Ok(None);
+        };
+
+        if self.is_local() {
+            return Ok(None);
+        }
+
+        let uri = Arc::<str>::from(format!("{}/{}", self.url_base(), path));
+        // Errors are not raised here - the request will be sent uncached and raise instead.
+        let Ok(version) = block_cache.get_version(&uri, || self.head(path)).await else {
+            return Ok(None);
+        };
+
+        Ok(Some((block_cache, uri, version)))
+    }
+
     pub async fn get_range(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
+        let Some((block_cache, uri, version)) = self.get_block_cache(path).await? else {
+            return self.get_range_uncached(path, range).await;
+        };
+
+        let key = BlockKey::new(&uri, &version, &range);
+
+        if let Some(bytes) = block_cache.get(&key, range.len()) {
+            return Ok(bytes);
+        }
+
+        let bytes = self.get_range_uncached(path, range).await?;
+        block_cache.insert(&key, &bytes);
+
+        Ok(bytes)
+    }
+
+    async fn get_range_uncached(&self, path: &Path, range: Range<usize>) -> PolarsResult<Bytes> {
         self.try_exec_rebuild_on_err(move |store| {
             let range = range.clone();
             let st = store.clone();
@@ -225,6 +276,47 @@ impl PolarsObjectStore {
             return Ok(Default::default());
         }
 
+        let Some((block_cache, uri, version)) = self.get_block_cache(path).await? else {
+            return self.get_ranges_sort_uncached(path, ranges).await;
+        };
+
+        ranges.sort_unstable_by_key(|x| x.start);
+
+        let mut out = PlHashMap::with_capacity(ranges.len());
+        let mut missing = vec![];
+
+        for range in ranges.iter() {
+            match block_cache.get(&BlockKey::new(&uri, &version, range), range.len()) {
+                Some(bytes) => insert_longest(&mut out, range.start, MemSlice::from_bytes(bytes)),
+                None => missing.push(range.clone()),
+            }
+        }
+
+        if !missing.is_empty() {
+            let mut fetched = self.get_ranges_sort_uncached(path, &mut missing).await?;
+
+            for range in missing.iter() {
+                let mem_slice = fetched.get(&range.start).unwrap().slice(0..range.len());
+                block_cache.insert(&BlockKey::new(&uri, &version, range), &mem_slice);
+            }
+
+            for (start, mem_slice) in fetched.drain() {
+                insert_longest(&mut out, start, mem_slice);
+            }
+        }
+
+        Ok(out)
+    }
+
+    async fn get_ranges_sort_uncached(
+        &self,
+        path: &Path,
+        ranges: &mut [Range<usize>],
+    ) -> PolarsResult<PlHashMap<usize, MemSlice>> {
+        if ranges.is_empty() {
+            return Ok(Default::default());
+        }
+
         ranges.sort_unstable_by_key(|x| x.start);
 
         let ranges_len = ranges.len();
@@ -286,16 +378,7 @@ impl PolarsObjectStore {
                                     range.start - full_range.start..range.end - full_range.start,
                                 );
 
-                                match out.raw_entry_mut().from_key(&range.start) {
-                                    RawEntryMut::Vacant(slot) => {
-                                        slot.insert(range.start, mem_slice);
-                                    },
-                                    RawEntryMut::Occupied(mut slot) => {
-                                        if slot.get_mut().len() < mem_slice.len() {
-                                            *slot.get_mut() = mem_slice;
-                                        }
-                                    },
-                                }
+                                insert_longest(&mut out, range.start, mem_slice);
                             }
 
                             current_offset = end;
@@ -414,6 +497,30 @@ impl PolarsObjectStore {
     }
 }
 
+/// Identifies the remote version of an object, for validating cached data. This uses the ETag if
+/// the store provides one, otherwise the last modified time.
+pub fn object_meta_version(meta: &ObjectMeta) -> Arc<str> {
+    match &meta.e_tag {
+        Some(e_tag) => format!("e{}-{}", e_tag, meta.size),
+        None => format!("t{}-{}", meta.last_modified.timestamp_millis(), meta.size),
+    }
+    .into()
+}
+
+/// Inserts `mem_slice` at `start`, keeping the longer slice if `start` is already present.
+fn insert_longest(out: &mut PlHashMap<usize, MemSlice>, start: usize, mem_slice: MemSlice) {
+    match out.raw_entry_mut().from_key(&start) {
+        RawEntryMut::Vacant(slot) => {
+            slot.insert(start, mem_slice);
+        },
+        RawEntryMut::Occupied(mut slot) => {
+            if slot.get_mut().len() < mem_slice.len() {
+                *slot.get_mut() = mem_slice;
+            }
+        },
+    }
+}
+
 /// Splits a single range into multiple smaller ranges, which can be downloaded concurrently for
 /// much higher throughput.
 fn split_range(range: Range<usize>) -> impl ExactSizeIterator<Item = Range<usize>> {
@@ -0,0 +1,540 @@
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+use bytes::Bytes;
+use object_store::ObjectMeta;
+use polars_core::config;
+use polars_error::{PolarsResult, polars_err};
+use polars_utils::aliases::PlHashMap;
+
+use super::cache_lock::GLOBAL_FILE_CACHE_LOCK;
+use super::utils::FILE_CACHE_PREFIX;
+use crate::cloud::object_meta_version;
+use crate::path_utils::ensure_directory_init;
+
+pub(super) const BLOCK_PREFIX: u8 = b'b';
+
+/// Process-wide byte-range block cache, see [`block_cache`].
+static BLOCK_CACHE: LazyLock<PolarsResult<Option<BlockCache>>> = LazyLock::new(|| {
+    let max_size = get_env_block_cache_max_size()?;
+
+    if max_size == 0 {
+        return Ok(None);
+    }
+
+    let data_dir = FILE_CACHE_PREFIX
+        .join(std::str::from_utf8(&[BLOCK_PREFIX]).unwrap())
+        .into_boxed_path();
+
+    Ok(init_block_cache(data_dir, max_size))
+});
+
+/// Returns the process-wide byte-range block cache. This is `None` unless enabled by setting
+/// `POLARS_BLOCK_CACHE_MAX_SIZE` to a non-zero number of bytes, or if the cache directory cannot
+/// be created.
+pub fn block_cache() -> PolarsResult<Option<&'static BlockCache>> {
+    match &*BLOCK_CACHE {
+        Ok(block_cache) => Ok(block_cache.as_ref()),
+        Err(err) => Err(err.wrap_msg(|msg| msg.to_string())),
+    }
+}
+
+/// Creates the block cache in `data_dir`, or returns `None` if the directory cannot be created.
+fn init_block_cache(data_dir: Box<Path>, max_size: u64) -> Option<BlockCache> {
+    let verbose = config::verbose();
+
+    if let Err(err) = ensure_directory_init(&data_dir) {
+        if verbose {
+            eprintln!(
+                "[block_cache] warning: disabled, failed to create data directory: path = {}, err = {}",
+                data_dir.to_str().unwrap(),
+                err
+            );
+        }
+        return None;
+    }
+
+    if verbose {
+        eprintln!(
+            "[block_cache] enabled: path = {}, max_size = {}",
+            data_dir.to_str().unwrap(),
+            max_size
+        );
+    }
+
+    Some(BlockCache::new(data_dir, max_size))
+}
+
+pub fn get_env_block_cache_max_size() -> PolarsResult<u64> {
+    let Ok(v) = std::env::var("POLARS_BLOCK_CACHE_MAX_SIZE") else {
+        return Ok(0);
+    };
+
+    v.parse::<u64>().map_err(|_| {
+        polars_err!(
+            InvalidOperation:
+            "POLARS_BLOCK_CACHE_MAX_SIZE must be a non-negative number of bytes, got '{}'", v
+        )
+    })
+}
+
+/// Interval (in seconds) after which the remote version of an object is re-checked.
+pub fn get_env_block_cache_validate_interval() -> u64 {
+    std::env::var("POLARS_BLOCK_CACHE_VALIDATE_INTERVAL")
+        .map(|x| x.parse::<u64>().expect("integer"))
+        .unwrap_or(60)
+}
+
+/// Identifies a cached byte range of a specific version of a remote object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockKey {
+    hash: Arc<str>,
+}
+
+impl BlockKey {
+    pub fn new(uri: &str, version: &str, range: &Range<usize>) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(uri.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(version.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(&(range.start as u64).to_le_bytes());
+        hasher.update(&(range.end as u64).to_le_bytes());
+
+        Self {
+            hash: hasher.finalize().to_hex()[..32].into(),
+        }
+    }
+}
+
+struct CheckedVersion {
+    version: Arc<str>,
+    checked_at: Instant,
+}
+
+struct LruEntry {
+    size: u64,
+    last_accessed: u64,
+}
+
+#[derive(Default)]
+struct LruState {
+    entries: PlHashMap<Arc<str>, LruEntry>,
+    /// Access counter -> hash, ordered from least to most recently used.
+    access_order: BTreeMap<u64, Arc<str>>,
+    total_size: u64,
+    counter: u64,
+}
+
+impl LruState {
+    fn touch(&mut self, hash: &Arc<str>, size: u64) {
+        self.counter += 1;
+        let counter = self.counter;
+
+        if let Some(entry) = self.entries.get_mut(hash) {
+            self.access_order.remove(&entry.last_accessed);
+            self.total_size -= entry.size;
+            entry.last_accessed = counter;
+            entry.size = size;
+        } else {
+            self.entries.insert(
+                hash.clone(),
+                LruEntry {
+                    size,
+                    last_accessed: counter,
+                },
+            );
+        }
+
+        self.total_size += size;
+        self.access_order.insert(counter, hash.clone());
+    }
+
+    fn remove(&mut self, hash: &str) {
+        if let Some(entry) = self.entries.remove(hash) {
+            self.access_order.remove(&entry.last_accessed);
+            self.total_size -= entry.size;
+        }
+    }
+
+    /// Pops least recently used entries until the total size is within `max_size`.
+    fn pop_lru_until(&mut self, max_size: u64) -> Vec<Arc<str>> {
+        let mut out = vec![];
+
+        while self.total_size > max_size {
+            let Some((_, hash)) = self.access_order.pop_first() else {
+                break;
+            };
+            let entry = self.entries.remove(&hash).unwrap();
+            self.total_size -= entry.size;
+            out.push(hash);
+        }
+
+        out
+    }
+}
+
+/// On-disk cache of byte ranges of remote objects, keyed by (uri, version, range) and evicted in
+/// least-recently-used order once `max_size` is exceeded.
+///
+/// Block files are shared with other processes using the same cache directory. Blocks written by
+/// other processes are picked up on lookup, and blocks that were evicted by other processes are
+/// treated as cache misses. Blocks are only removed while holding the exclusive cache lock, so
+/// eviction is postponed to a later insert while other readers or writers are active.
+pub struct BlockCache {
+    data_dir: Box<Path>,
+    max_size: u64,
+    lru: Mutex<LruState>,
+    versions: Mutex<PlHashMap<Arc<str>, CheckedVersion>>,
+    validate_interval: Duration,
+    tmp_counter: AtomicU64,
+    /// Whether temporary files left behind by interrupted writes have been removed.
+    tmp_files_swept: AtomicBool,
+}
+
+impl BlockCache {
+    /// Creates a cache backed by `data_dir`, which must already exist. Files already present in
+    /// `data_dir` are tracked in order of their last access time.
+    pub(super) fn new(data_dir: Box<Path>, max_size: u64) -> Self {
+        let mut existing = std::fs::read_dir(data_dir.as_ref())
+            .map(|iter| {
+                iter.filter_map(|file| {
+                    let file = file.ok()?;
+                    let metadata = file.metadata().ok()?;
+                    let hash = file.file_name().to_str()?.to_string();
+
+                    if hash.len() != 32 || !metadata.is_file() {
+                        return None;
+                    }
+
+                    let accessed = metadata
+                        .accessed()
+                        .or_else(|_| metadata.modified())
+                        .unwrap_or(SystemTime::UNIX_EPOCH);
+
+                    Some((accessed, Arc::<str>::from(hash), metadata.len()))
+                })
+                .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        existing.sort_unstable_by_key(|x| x.0);
+
+        let mut lru = LruState::default();
+
+        for (_, hash, size) in existing {
+            lru.touch(&hash, size);
+        }
+
+        let out = Self {
+            data_dir,
+            max_size,
+            lru: Mutex::new(lru),
+            versions: Default::default(),
+            validate_interval: Duration::from_secs(get_env_block_cache_validate_interval()),
+            tmp_counter: AtomicU64::new(0),
+            tmp_files_swept: AtomicBool::new(false),
+        };
+
+        out.evict_to_max_size();
+        out
+    }
+
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+    }
+
+    /// Total size in bytes of the blocks tracked by this process.
+    pub fn total_size(&self) -> u64 {
+        self.lru.lock().unwrap().total_size
+    }
+
+    fn block_path(&self, hash: &str) -> PathBuf {
+        self.data_dir.join(hash)
+    }
+
+    /// Returns the remote version for `uri`. The version is memoized and re-fetched using
+    /// `fetch_meta` once it is older than the validation interval.
+    pub async fn get_version<F, Fut>(&self, uri: &Arc<str>, fetch_meta: F) -> PolarsResult<Arc<str>>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = PolarsResult<ObjectMeta>>,
+    {
+        if let Some(checked) = self.versions.lock().unwrap().get(uri) {
+            if checked.checked_at.elapsed() < self.validate_interval {
+                return Ok(checked.version.clone());
+            }
+        }
+
+        let version = object_meta_version(&fetch_meta().await?);
+
+        self.versions.lock().unwrap().insert(
+            uri.clone(),
+            CheckedVersion {
+                version: version.clone(),
+                checked_at: Instant::now(),
+            },
+        );
+
+        Ok(version)
+    }
+
+    /// Drops the memoized version of `uri`, forcing it to be re-checked on the next access.
+    pub fn invalidate_version(&self, uri: &str) {
+        self.versions.lock().unwrap().remove(uri);
+    }
+
+    pub fn get(&self, key: &BlockKey, expected_len: usize) -> Option<Bytes> {
+        let _cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_shared();
+        let path = self.block_path(&key.hash);
+
+        match std::fs::read(&path) {
+            Ok(data) if data.len() == expected_len => {
+                if let Ok(file) = std::fs::File::open(&path) {
+                    super::utils::update_last_accessed(&file);
+                }
+                self.lru.lock().unwrap().touch(&key.hash, data.len() as u64);
+                Some(Bytes::from(data))
+            },
+            Ok(_) => {
+                // Truncated or otherwise corrupted block.
+                let _ = std::fs::remove_file(&path);
+                self.lru.lock().unwrap().remove(&key.hash);
+                None
+            },
+            Err(_) => {
+                self.lru.lock().unwrap().remove(&key.hash);
+                None
+            },
+        }
+    }
+
+    /// Stores `data` for `key`. Errors are not propagated as the cache is only an optimization.
+    pub fn insert(&self, key: &BlockKey, data: &[u8]) {
+        let size = data.len() as u64;
+
+        if size > self.max_size {
+            return;
+        }
+
+        if self.write_block(key, data) {
+            self.lru.lock().unwrap().touch(&key.hash, size);
+            self.evict_to_max_size();
+        }
+    }
+
+    /// Writes the block file for `key`, returning whether it succeeded.
+    fn write_block(&self, key: &BlockKey, data: &[u8]) -> bool {
+        let verbose = config::verbose();
+        let _cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_shared();
+        let path = self.block_path(&key.hash);
+
+        // Write to a temporary file first so that other readers never observe a partial block.
+        let tmp_path = self.data_dir.join(format!(
+            "{}.tmp.{}.{}",
+            key.hash,
+            std::process::id(),
+            self.tmp_counter
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+        ));
+
+        if let Err(err) =
+            std::fs::write(&tmp_path, data).and_then(|_| std::fs::rename(&tmp_path, &path))
+        {
+            let _ = std::fs::remove_file(&tmp_path);
+
+            if verbose {
+                eprintln!(
+                    "[block_cache] insert: failed to write block at {}: {}",
+                    path.to_str().unwrap(),
+                    err
+                );
+            }
+            return false;
+        }
+
+        true
+    }
+
+    /// Removes least recently used blocks until the cache fits in `max_size`, and on the first
+    /// call also the temporary files of interrupted writes. This needs the exclusive cache lock,
+    /// so that no other process is reading a block while it is removed. If the lock is
+    /// unavailable, eviction is retried on the next insert.
+    fn evict_to_max_size(&self) {
+        let verbose = config::verbose();
+        let sweep_tmp_files = !self
+            .tmp_files_swept
+            .load(std::sync::atomic::Ordering::Relaxed);
+
+        if !sweep_tmp_files && self.lru.lock().unwrap().total_size <= self.max_size {
+            return;
+        }
+
+        let Some(_cache_guard) = GLOBAL_FILE_CACHE_LOCK.try_lock_eviction() else {
+            if verbose {
+                eprintln!("[block_cache] evict: cache is in use, postponing eviction");
+            }
+            return;
+        };
+
+        if sweep_tmp_files {
+            self.remove_tmp_files();
+            self.tmp_files_swept
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+
+        let to_remove = self.lru.lock().unwrap().pop_lru_until(self.max_size);
+
+        for hash in to_remove {
+            let path = self.block_path(&hash);
+
+            if let Err(err) = std::fs::remove_file(&path) {
+                if config::verbose() && err.kind() != std::io::ErrorKind::NotFound {
+                    eprintln!(
+                        "[block_cache] evict: error removing file: {} ({})",
+                        path.to_str().unwrap(),
+                        err
+                    );
+                }
+            }
+        }
+    }
+
+    /// Removes the temporary files of writes that never completed. Must be called while
+    /// holding the exclusive cache lock, as writers hold the shared lock until their temporary
+    /// file is renamed.
+    fn remove_tmp_files(&self) {
+        let Ok(iter) = std::fs::read_dir(self.data_dir.as_ref()) else {
+            return;
+        };
+
+        for file in iter.flatten() {
+            if file
+                .file_name()
+                .to_str()
+                .is_some_and(|x| x.contains(".tmp."))
+            {
+                let _ = std::fs::remove_file(file.path());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::{BlockCache, BlockKey, init_block_cache};
+
+    /// Eviction needs the process-wide exclusive cache lock, which is unavailable while another
+    /// test holds the shared lock.
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    fn n_files(dir: &std::path::Path) -> usize {
+        std::fs::read_dir(dir).unwrap().count()
+    }
+
+    #[test]
+    fn test_block_cache_lru_eviction() {
+        let _serial = SERIAL.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let cache = BlockCache::new(dir.path().into(), 10);
+
+        let k0 = BlockKey::new("s3://bucket/a", "v0", &(0..4));
+        let k1 = BlockKey::new("s3://bucket/a", "v0", &(4..8));
+        let k2 = BlockKey::new("s3://bucket/a", "v0", &(8..12));
+
+        cache.insert(&k0, b"0123");
+        cache.insert(&k1, b"4567");
+        assert_eq!(cache.total_size(), 8);
+
+        // Access k0 so that k1 becomes the least recently used.
+        assert_eq!(cache.get(&k0, 4).as_deref(), Some(b"0123".as_slice()));
+
+        cache.insert(&k2, b"89ab");
+        assert_eq!(cache.total_size(), 8);
+
+        assert!(cache.get(&k1, 4).is_none());
+        assert_eq!(cache.get(&k0, 4).as_deref(), Some(b"0123".as_slice()));
+        assert_eq!(cache.get(&k2, 4).as_deref(), Some(b"89ab".as_slice()));
+
+        // Blocks larger than the cache are not stored.
+        let k3 = BlockKey::new("s3://bucket/a", "v0", &(0..11));
+        cache.insert(&k3, b"0123456789a");
+        assert!(cache.get(&k3, 11).is_none());
+
+        // A different version is a different key.
+        let k0_v1 = BlockKey::new("s3://bucket/a", "v1", &(0..4));
+        assert!(cache.get(&k0_v1, 4).is_none());
+
+        // Blocks on disk are picked up by a new instance.
+        drop(cache);
+        let cache = BlockCache::new(dir.path().into(), 10);
+        assert_eq!(cache.total_size(), 8);
+        assert_eq!(cache.get(&k2, 4).as_deref(), Some(b"89ab".as_slice()));
+    }
+
+    #[test]
+    fn test_block_cache_max_size() {
+        let _serial = SERIAL.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let cache = BlockCache::new(dir.path().into(), 10);
+
+        let keys = (0..8)
+            .map(|i| BlockKey::new("s3://bucket/a", "v0", &(i * 3..i * 3 + 3)))
+            .collect::<Vec<_>>();
+
+        for key in &keys {
+            cache.insert(key, b"abc");
+            assert!(cache.total_size() <= 10);
+            assert_eq!(n_files(dir.path()) as u64 * 3, cache.total_size());
+        }
+
+        // Only the three most recently inserted blocks remain, in insertion order.
+        let hits = keys
+            .iter()
+            .map(|key| cache.get(key, 3).is_some())
+            .collect::<Vec<_>>();
+        assert_eq!(hits, [false, false, false, false, false, true, true, true]);
+
+        // A smaller cache opened on the same directory evicts the oldest blocks first.
+        drop(cache);
+        let cache = BlockCache::new(dir.path().into(), 6);
+        assert_eq!(cache.total_size(), 6);
+        assert_eq!(n_files(dir.path()), 2);
+    }
+
+    #[test]
+    fn test_block_cache_removes_tmp_files() {
+        let _serial = SERIAL.lock().unwrap();
+        let dir = tempfile::tempdir().unwrap();
+
+        let key = BlockKey::new("s3://bucket/a", "v0", &(0..4));
+        // Left behind by a write that was interrupted before the rename.
+        let tmp_path = dir.path().join(format!("{}.tmp.1.0", key.hash));
+        std::fs::write(&tmp_path, b"01").unwrap();
+
+        let cache = BlockCache::new(dir.path().into(), 10);
+        assert!(!tmp_path.exists());
+        assert_eq!(cache.total_size(), 0);
+
+        cache.insert(&key, b"0123");
+        assert_eq!(cache.get(&key, 4).as_deref(), Some(b"0123".as_slice()));
+        assert_eq!(n_files(dir.path()), 1);
+    }
+
+    #[test]
+    fn test_block_cache_disabled_without_data_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        // The data directory cannot be created below a file.
+        let file = dir.path().join("file");
+        std::fs::write(&file, b"").unwrap();
+
+        assert!(init_block_cache(file.join("b").into(), 10).is_none());
+        assert!(init_block_cache(dir.path().join("b").into(), 10).is_some());
+    }
+}
@@ -1,3 +1,4 @@
+mod block_cache;
 mod cache;
 mod cache_lock;
 mod entry;
@@ -6,6 +7,10 @@ mod file_fetcher;
 mod file_lock;
 mod metadata;
 mod utils;
+pub use block_cache::{
+    BlockCache, BlockKey, block_cache, get_env_block_cache_max_size,
+    get_env_block_cache_validate_interval,
+};
 pub use cache::{FILE_CACHE, get_env_file_cache_ttl};
 pub use entry::FileCacheEntry;
 pub use utils::{FILE_CACHE_PREFIX, init_entries_from_uri_list};
@@ -6,3 +6,213 @@ pub use polars_parquet::parquet::metadata::FileMetadata;
 pub use polars_parquet::read::statistics::{Statistics as ParquetStatistics, deserialize};
 
 pub type FileMetadataRef = Arc<FileMetadata>;
+
+#[cfg(feature = "cloud")]
+pub use cache::{FILE_METADATA_CACHE, FileMetadataCache, get_env_metadata_cache_max_size};
+
+#[cfg(feature = "cloud")]
+mod cache {
+    use std::collections::BTreeMap;
+    use std::sync::{Arc, LazyLock, Mutex};
+
+    use polars_core::config;
+    use polars_utils::aliases::PlHashMap;
+
+    use super::FileMetadataRef;
+
+    /// Process-wide cache of decoded parquet footers of remote files. Its size is bounded by
+    /// `POLARS_PARQUET_METADATA_CACHE_MAX_SIZE` bytes (64 MiB by default) of encoded footers, set
+    /// it to `0` to disable the cache.
+    pub static FILE_METADATA_CACHE: LazyLock<FileMetadataCache> =
+        LazyLock::new(|| FileMetadataCache::new(get_env_metadata_cache_max_size()));
+
+    /// Maximum total size in bytes of the footers held by the cache. This counts the encoded size
+    /// of the footers as stored in the files, the decoded metadata takes more memory.
+    pub fn get_env_metadata_cache_max_size() -> usize {
+        std::env::var("POLARS_PARQUET_METADATA_CACHE_MAX_SIZE")
+            .map(|x| x.parse::<usize>().expect("integer"))
+            .unwrap_or(64 * 1024 * 1024)
+    }
+
+    struct Entry {
+        version: Arc<str>,
+        metadata: FileMetadataRef,
+        size: usize,
+        last_accessed: u64,
+    }
+
+    #[derive(Default)]
+    struct Inner {
+        entries: PlHashMap<Arc<str>, Entry>,
+        /// Access counter -> uri, ordered from least to most recently used.
+        access_order: BTreeMap<u64, Arc<str>>,
+        total_size: usize,
+        counter: u64,
+    }
+
+    impl Inner {
+        fn remove(&mut self, uri: &str) -> Option<Entry> {
+            let entry = self.entries.remove(uri)?;
+            self.access_order.remove(&entry.last_accessed);
+            self.total_size -= entry.size;
+            Some(entry)
+        }
+    }
+
+    /// Size-bounded cache of [`FileMetadata`](super::FileMetadata), keyed by URI and validated
+    /// against the remote version (ETag / last modified) of the object. Entries are evicted in
+    /// least-recently-used order.
+    pub struct FileMetadataCache {
+        inner: Mutex<Inner>,
+        max_size: usize,
+    }
+
+    impl FileMetadataCache {
+        pub fn new(max_size: usize) -> Self {
+            Self {
+                inner: Default::default(),
+                max_size,
+            }
+        }
+
+        pub fn is_enabled(&self) -> bool {
+            self.max_size > 0
+        }
+
+        /// Returns the cached metadata for `uri` if it was stored for the same `version`. Entries
+        /// of a different version are removed.
+        pub fn get(&self, uri: &str, version: &str) -> Option<FileMetadataRef> {
+            let mut inner = self.inner.lock().unwrap();
+            let inner = &mut *inner;
+
+            let entry = inner.entries.get_mut(uri)?;
+
+            if entry.version.as_ref() != version {
+                if config::verbose() {
+                    eprintln!(
+                        "[FileMetadataCache]: removing outdated entry for uri = {uri} \
+                        (cached version = {}, remote version = {version})",
+                        entry.version
+                    );
+                }
+                inner.remove(uri);
+                return None;
+            }
+
+            inner.counter += 1;
+            let uri = inner.access_order.remove(&entry.last_accessed).unwrap();
+            entry.last_accessed = inner.counter;
+            let metadata = entry.metadata.clone();
+            inner.access_order.insert(inner.counter, uri);
+
+            Some(metadata)
+        }
+
+        /// `size` is the encoded size of the footer and is used to bound the size of the cache.
+        pub fn insert(
+            &self,
+            uri: Arc<str>,
+            version: Arc<str>,
+            metadata: FileMetadataRef,
+            size: usize,
+        ) {
+            if size > self.max_size {
+                return;
+            }
+
+            let mut inner = self.inner.lock().unwrap();
+
+            inner.remove(&uri);
+            inner.counter += 1;
+            let last_accessed = inner.counter;
+
+            inner.access_order.insert(last_accessed, uri.clone());
+            inner.total_size += size;
+            inner.entries.insert(
+                uri,
+                Entry {
+                    version,
+                    metadata,
+                    size,
+                    last_accessed,
+                },
+            );
+
+            while inner.total_size > self.max_size {
+                let (_, uri) = inner.access_order.pop_first().unwrap();
+                let entry = inner.entries.remove(&uri).unwrap();
+                inner.total_size -= entry.size;
+            }
+        }
+
+        /// Removes the cached metadata for `uri`.
+        pub fn invalidate(&self, uri: &str) {
+            self.inner.lock().unwrap().remove(uri);
+        }
+
+        /// Removes all cached metadata.
+        pub fn clear(&self) {
+            *self.inner.lock().unwrap() = Default::default();
+        }
+
+        pub fn len(&self) -> usize {
+            self.inner.lock().unwrap().entries.len()
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::sync::Arc;
+
+        use super::FileMetadataCache;
+        use crate::parquet::metadata::FileMetadata;
+
+        fn metadata(num_rows: usize) -> Arc<FileMetadata> {
+            Arc::new(FileMetadata {
+                version: 1,
+                num_rows,
+                max_row_group_height: num_rows,
+                created_by: None,
+                row_groups: vec![],
+                key_value_metadata: None,
+                schema_descr: polars_parquet::parquet::metadata::SchemaDescriptor::new(
+                    "schema".into(),
+                    vec![],
+                ),
+                column_orders: None,
+            })
+        }
+
+        #[test]
+        fn test_file_metadata_cache() {
+            let cache = FileMetadataCache::new(10);
+
+            cache.insert("s3://a".into(), "v0".into(), metadata(1), 4);
+            cache.insert("s3://b".into(), "v0".into(), metadata(2), 4);
+
+            assert_eq!(cache.get("s3://a", "v0").unwrap().num_rows, 1);
+            // Version mismatch removes the entry.
+            assert!(cache.get("s3://b", "v1").is_none());
+            assert!(cache.get("s3://b", "v0").is_none());
+
+            cache.insert("s3://b".into(), "v1".into(), metadata(3), 4);
+            // Evicts "s3://a" as the least recently used.
+            cache.insert("s3://c".into(), "v0".into(), metadata(4), 4);
+
+            assert!(cache.get("s3://a", "v0").is_none());
+            assert_eq!(cache.get("s3://b", "v1").unwrap().num_rows, 3);
+            assert_eq!(cache.get("s3://c", "v0").unwrap().num_rows, 4);
+
+            cache.invalidate("s3://b");
+            assert!(cache.get("s3://b", "v1").is_none());
+            assert_eq!(cache.len(), 1);
+
+            cache.clear();
+            assert!(cache.is_empty());
+        }
+    }
+}
@@ -6,14 +6,17 @@ use polars_core::prelude::*;
 use polars_parquet::write::FileMetadata;
 
 use crate::cloud::{
-    CloudLocation, CloudOptions, PolarsObjectStore, build_object_store, object_path_from_str,
+    CloudLocation, CloudOptions, PolarsObjectStore, build_object_store, object_meta_version,
+    object_path_from_str,
 };
-use crate::parquet::metadata::FileMetadataRef;
+use crate::parquet::metadata::{FILE_METADATA_CACHE, FileMetadataRef};
 
 pub struct ParquetObjectStore {
+    uri: Arc<str>,
     store: PolarsObjectStore,
     path: ObjectPath,
     length: Option<usize>,
+    version: Option<Arc<str>>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
 }
@@ -28,18 +31,23 @@ impl ParquetObjectStore {
         let path = object_path_from_str(&prefix)?;
 
         Ok(ParquetObjectStore {
+            uri: uri.into(),
             store,
             path,
             length: None,
+            version: None,
             metadata,
             schema: None,
         })
     }
 
-    /// Initialize the length property of the object, unless it has already been fetched.
+    /// Initialize the length and version properties of the object, unless they have already been
+    /// fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
-            self.length = Some(self.store.head(&self.path).await?.size as usize);
+            let object_meta = self.store.head(&self.path).await?;
+            self.length = Some(object_meta.size as usize);
+            self.version = Some(object_meta_version(&object_meta));
         }
         Ok(self.length.unwrap())
     }
@@ -50,16 +58,33 @@ impl ParquetObjectStore {
         Ok(metadata.num_rows)
     }
 
-    /// Fetch the metadata of the parquet file, do not memoize it.
-    async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
-        let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
-    }
-
-    /// Fetch and memoize the metadata of the parquet file.
+    /// Fetch and memoize the metadata of the parquet file. This goes through the process-wide
+    /// [`FILE_METADATA_CACHE`].
     pub async fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(self.fetch_metadata().await?));
+            let length = self.length().await?;
+            let version = self.version.clone().unwrap();
+
+            let metadata = if let Some(metadata) = FILE_METADATA_CACHE.get(&self.uri, &version) {
+                metadata
+            } else {
+                let (metadata, footer_size) =
+                    fetch_metadata(&self.store, &self.path, length).await?;
+                let metadata = Arc::new(metadata);
+
+                if FILE_METADATA_CACHE.is_enabled() {
+                    FILE_METADATA_CACHE.insert(
+                        self.uri.clone(),
+                        version,
+                        metadata.clone(),
+                        footer_size,
+                    );
+                }
+
+                metadata
+            };
+
+            self.metadata = Some(metadata);
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -92,12 +117,13 @@ fn read_i32le(reader: &mut &[u8]) -> Option<i32> {
     read_n(reader).map(i32::from_le_bytes)
 }
 
-/// Asynchronously reads the files' metadata
+/// Asynchronously reads the files' metadata. Returns the metadata along with the size of the
+/// encoded footer.
 pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
-) -> PolarsResult<FileMetadata> {
+) -> PolarsResult<(FileMetadata, usize)> {
     let footer_header_bytes = store
         .get_range(
             path,
@@ -142,11 +168,13 @@ pub async fn fetch_metadata(
         )
         .await?;
 
-    Ok(polars_parquet::parquet::read::deserialize_metadata(
+    let metadata = polars_parquet::parquet::read::deserialize_metadata(
         std::io::Cursor::new(footer_bytes.as_ref()),
         // TODO: Describe why this makes sense. Taken from the previous
         // implementation which said "a highly nested but sparse struct could
         // result in many allocations".
         footer_bytes.as_ref().len() * 2 + 1024,
-    )?)
+    )?;
+
+    Ok((metadata, footer_bytes.len()))
 }
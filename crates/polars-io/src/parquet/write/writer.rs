@@ -253,7 +253,23 @@ fn to_column_write_options_rec(
             }
         },
 
-        Map | Union => unreachable!(),
+        Map => {
+            let child_overwrites = overwrites.and_then(|o| match &o.children {
+                ChildFieldOverwrites::None => None,
+                ChildFieldOverwrites::ListLike(child_overwrites) => Some(child_overwrites.as_ref()),
+                _ => unreachable!(),
+            });
+
+            let ArrowDataType::Map(inner, _) = field.dtype().to_logical_type() else {
+                unreachable!()
+            };
+            let child = to_column_write_options_rec(inner, child_overwrites);
+
+            column_options.children =
+                ChildWriteOptions::ListLike(Box::new(ListLikeFieldWriteOptions { child }));
+        },
+        // Parquet has no union type, `to_parquet_schema` raises for it.
+        Union => {},
     }
 
     column_options
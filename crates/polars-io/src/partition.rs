@@ -1,6 +1,7 @@
 //! Functionality for writing a DataFrame partitioned into multiple files.
 
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use polars_core::POOL;
 use polars_core::prelude::*;
@@ -11,8 +12,11 @@ use crate::cloud::CloudOptions;
 use crate::parquet::write::ParquetWriteOptions;
 #[cfg(feature = "ipc")]
 use crate::prelude::IpcWriterOptions;
-use crate::prelude::URL_ENCODE_CHAR_SET;
 use crate::utils::file::try_get_writeable;
+use crate::utils::partition_path::{
+    PartitionOverwriteMode, PartitionPathIndices, PartitionPathOptions, PartitionStaging,
+    default_partition_dir,
+};
 use crate::{SerWriter, WriteDataFrameToFile, is_cloud_url};
 
 impl WriteDataFrameToFile for ParquetWriteOptions {
@@ -43,6 +47,9 @@ impl WriteDataFrameToFile for IpcWriterOptions {
 }
 
 /// Write a partitioned parquet dataset. This functionality is unstable.
+///
+/// Files are written to `key=value/...` directories unless `path_options` specifies a
+/// [`PartitionPathTemplate`](crate::utils::partition_path::PartitionPathTemplate).
 pub fn write_partitioned_dataset(
     df: &mut DataFrame,
     path: &Path,
@@ -50,14 +57,17 @@ pub fn write_partitioned_dataset(
     file_write_options: &(dyn WriteDataFrameToFile + Send + Sync),
     cloud_options: Option<&CloudOptions>,
     chunk_size: usize,
+    path_options: &PartitionPathOptions,
 ) -> PolarsResult<()> {
+    path_options.validate(&partition_by, false)?;
+
     // Ensure we have a single chunk as the gather will otherwise rechunk per group.
     df.as_single_chunk_par();
 
     // Note: When adding support for formats other than Parquet, avoid writing the partitioned
     // columns into the file. We write them for parquet because they are encoded efficiently with
     // RLE and also gives us a way to get the hive schema from the parquet file for free.
-    let get_hive_path_part = {
+    let get_keys = {
         let schema = &df.schema();
 
         let partition_by_col_idx = partition_by
@@ -75,24 +85,8 @@ pub fn write_partitioned_dataset(
 
             partition_by_col_idx
                 .iter()
-                .map(|&i| {
-                    let s = &cols[i].slice(0, 1).cast(&DataType::String).unwrap();
-
-                    format!(
-                        "{}={}",
-                        s.name(),
-                        percent_encoding::percent_encode(
-                            s.str()
-                                .unwrap()
-                                .get(0)
-                                .unwrap_or("__HIVE_DEFAULT_PARTITION__")
-                                .as_bytes(),
-                            URL_ENCODE_CHAR_SET
-                        )
-                    )
-                })
+                .map(|&i| cols[i].slice(0, 1))
                 .collect::<Vec<_>>()
-                .join("/")
         }
     };
 
@@ -100,22 +94,34 @@ pub fn write_partitioned_dataset(
     let is_cloud = is_cloud_url(base_path);
     let groups = df.group_by(partition_by)?.take_groups();
 
-    let init_part_base_dir = |part_df: &DataFrame| {
-        let path_part = get_hive_path_part(part_df);
-        let dir = base_path.join(path_part);
-
-        if !is_cloud {
-            std::fs::create_dir_all(&dir)?;
+    let file_idx = AtomicUsize::new(0);
+
+    // Dynamic overwrites write to a staging directory first and only replace the existing
+    // partitions once everything has been written.
+    let staging = (path_options.overwrite_mode == PartitionOverwriteMode::Dynamic)
+        .then(|| PartitionStaging::new(base_path, cloud_options));
+
+    let get_path = |keys: &[Column], part_idx: usize, in_part_idx: usize| {
+        let indices = PartitionPathIndices {
+            file_idx: file_idx.fetch_add(1, Ordering::Relaxed),
+            part_idx,
+            in_part_idx,
+        };
+
+        let file_path = match &path_options.template {
+            Some(template) => template.render(keys, indices, "parquet")?,
+            // Use a fixed-width file name so that it sorts properly.
+            None => default_partition_dir(keys)?.join(format!("{in_part_idx:08x}.parquet")),
+        };
+
+        match &staging {
+            Some(staging) => {
+                PolarsResult::Ok(staging.stage(path_options.partition_dir(keys)?, &file_path))
+            },
+            None => Ok(base_path.join(file_path)),
         }
-
-        PolarsResult::Ok(dir)
     };
 
-    fn get_path_for_index(i: usize) -> String {
-        // Use a fixed-width file name so that it sorts properly.
-        format!("{i:08x}.parquet")
-    }
-
     let get_n_files_and_rows_per_file = |part_df: &DataFrame| {
         let n_files = (part_df.estimated_size() / chunk_size).clamp(1, 0xffff_ffff);
         let rows_per_file = (df.height() / n_files).saturating_add(1);
@@ -123,6 +129,9 @@ pub fn write_partitioned_dataset(
     };
 
     let write_part = |mut df: DataFrame, path: &Path| {
+        if !is_cloud {
+            std::fs::create_dir_all(path.parent().unwrap())?;
+        }
         file_write_options.write_df_to_file(&mut df, path.to_str().unwrap(), cloud_options)?;
         PolarsResult::Ok(())
     };
@@ -131,12 +140,12 @@ pub fn write_partitioned_dataset(
     // proxy level and within every group.
     const MAX_OPEN_FILES: usize = 8;
 
-    let finish_part_df = |df: DataFrame| {
-        let dir_path = init_part_base_dir(&df)?;
+    let finish_part_df = |part_idx: usize, df: DataFrame| {
+        let keys = get_keys(&df);
         let (n_files, rows_per_file) = get_n_files_and_rows_per_file(&df);
 
         if n_files == 1 {
-            write_part(df.clone(), &dir_path.join(get_path_for_index(0)))
+            write_part(df.clone(), &get_path(&keys, part_idx, 0)?)
         } else {
             (0..df.height())
                 .step_by(rows_per_file)
@@ -148,7 +157,7 @@ pub fn write_partitioned_dataset(
                         .into_par_iter()
                         .map(|&(idx, slice_start)| {
                             let df = df.slice(slice_start as i64, rows_per_file);
-                            write_part(df.clone(), &dir_path.join(get_path_for_index(idx)))
+                            write_part(df.clone(), &get_path(&keys, part_idx, idx)?)
                         })
                         .reduce(
                             || PolarsResult::Ok(()),
@@ -164,14 +173,16 @@ pub fn write_partitioned_dataset(
         GroupsType::Idx(idx) => idx
             .all()
             .chunks(MAX_OPEN_FILES)
-            .map(|chunk| {
+            .enumerate()
+            .map(|(chunk_idx, chunk)| {
                 chunk
                     .par_iter()
-                    .map(|group| {
+                    .enumerate()
+                    .map(|(i, group)| {
                         let df = unsafe {
                             df._take_unchecked_slice_sorted(group, true, IsSorted::Ascending)
                         };
-                        finish_part_df(df)
+                        finish_part_df(chunk_idx * MAX_OPEN_FILES + i, df)
                     })
                     .reduce(
                         || PolarsResult::Ok(()),
@@ -181,12 +192,14 @@ pub fn write_partitioned_dataset(
             .collect::<PolarsResult<Vec<()>>>(),
         GroupsType::Slice { groups, .. } => groups
             .chunks(MAX_OPEN_FILES)
-            .map(|chunk| {
+            .enumerate()
+            .map(|(chunk_idx, chunk)| {
                 chunk
                     .into_par_iter()
-                    .map(|&[offset, len]| {
+                    .enumerate()
+                    .map(|(i, &[offset, len])| {
                         let df = df.slice(offset as i64, len as usize);
-                        finish_part_df(df)
+                        finish_part_df(chunk_idx * MAX_OPEN_FILES + i, df)
                     })
                     .reduce(
                         || PolarsResult::Ok(()),
@@ -196,5 +209,14 @@ pub fn write_partitioned_dataset(
             .collect::<PolarsResult<Vec<()>>>(),
     })?;
 
+    if let Some(staging) = &staging {
+        if is_cloud {
+            #[cfg(feature = "cloud")]
+            crate::pl_async::get_runtime().block_in_place_on(staging.commit())?;
+        } else {
+            staging.commit_local()?;
+        }
+    }
+
     Ok(())
 }
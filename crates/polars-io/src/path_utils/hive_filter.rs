@@ -0,0 +1,326 @@
+//! Predicates on Hive partition values that can be evaluated on directory names during listing.
+//!
+//! The filter is only used to skip directories that cannot contain matching rows. It is
+//! conservative: any value it cannot interpret is kept, and the original predicate is still
+//! applied to the scanned rows afterwards.
+use std::cmp::Ordering;
+
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::utils::partition_path::HIVE_DEFAULT_PARTITION;
+
+/// A literal that a Hive partition value is compared against.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HiveLiteral {
+    Null,
+    Boolean(bool),
+    Int(i128),
+    Float(f64),
+    String(PlSmallStr),
+    /// ISO-8601 date (`YYYY-MM-DD`).
+    Date(PlSmallStr),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HiveCompareOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl HiveCompareOp {
+    /// The operator to use when the operands are swapped (`lit < col` => `col > lit`).
+    pub fn swap_operands(self) -> Self {
+        use HiveCompareOp as O;
+        match self {
+            O::Eq => O::Eq,
+            O::NotEq => O::NotEq,
+            O::Lt => O::Gt,
+            O::LtEq => O::GtEq,
+            O::Gt => O::Lt,
+            O::GtEq => O::LtEq,
+        }
+    }
+
+    fn holds(self, ordering: Ordering) -> bool {
+        use HiveCompareOp as O;
+        match self {
+            O::Eq => ordering.is_eq(),
+            O::NotEq => ordering.is_ne(),
+            O::Lt => ordering.is_lt(),
+            O::LtEq => ordering.is_le(),
+            O::Gt => ordering.is_gt(),
+            O::GtEq => ordering.is_ge(),
+        }
+    }
+}
+
+/// A predicate on the value of a single Hive partition key.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HiveValuePredicate {
+    Compare(HiveCompareOp, HiveLiteral),
+    IsIn(Vec<HiveLiteral>),
+    /// `true` for `is_null`, `false` for `is_not_null`.
+    IsNull(bool),
+    And(Vec<HiveValuePredicate>),
+    Or(Vec<HiveValuePredicate>),
+    Not(Box<HiveValuePredicate>),
+}
+
+/// Three-valued logic extended with `Unknown` for values we cannot interpret.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Truth {
+    True,
+    False,
+    Null,
+    Unknown,
+}
+
+impl Truth {
+    fn from_bool(v: bool) -> Self {
+        if v { Self::True } else { Self::False }
+    }
+
+    fn not(self) -> Self {
+        match self {
+            Self::True => Self::False,
+            Self::False => Self::True,
+            v => v,
+        }
+    }
+
+    fn and(self, other: Self) -> Self {
+        use Truth as T;
+        match (self, other) {
+            (T::False, _) | (_, T::False) => T::False,
+            (T::Unknown, _) | (_, T::Unknown) => T::Unknown,
+            (T::Null, _) | (_, T::Null) => T::Null,
+            (T::True, T::True) => T::True,
+        }
+    }
+
+    fn or(self, other: Self) -> Self {
+        use Truth as T;
+        match (self, other) {
+            (T::True, _) | (_, T::True) => T::True,
+            (T::Unknown, _) | (_, T::Unknown) => T::Unknown,
+            (T::Null, _) | (_, T::Null) => T::Null,
+            (T::False, T::False) => T::False,
+        }
+    }
+}
+
+fn is_iso_date(value: &str) -> bool {
+    let b = value.as_bytes();
+    b.len() == 10
+        && b[4] == b'-'
+        && b[7] == b'-'
+        && b.iter()
+            .enumerate()
+            .all(|(i, c)| i == 4 || i == 7 || c.is_ascii_digit())
+}
+
+/// Compares a raw (decoded, non-null) partition value against a literal, interpreting the raw
+/// value as the type of the literal. Returns `None` if the value cannot be interpreted.
+fn compare_value(value: &str, lit: &HiveLiteral) -> Option<Ordering> {
+    match lit {
+        HiveLiteral::Null => None,
+        HiveLiteral::Boolean(l) => {
+            let v = if value.eq_ignore_ascii_case("true") {
+                true
+            } else if value.eq_ignore_ascii_case("false") {
+                false
+            } else {
+                return None;
+            };
+            Some(v.cmp(l))
+        },
+        HiveLiteral::Int(l) => match value.parse::<i128>() {
+            Ok(v) => Some(v.cmp(l)),
+            Err(_) => value.parse::<f64>().ok()?.partial_cmp(&(*l as f64)),
+        },
+        HiveLiteral::Float(l) => value.parse::<f64>().ok()?.partial_cmp(l),
+        HiveLiteral::String(l) => Some(value.cmp(l.as_str())),
+        HiveLiteral::Date(l) => is_iso_date(value).then(|| value.cmp(l.as_str())),
+    }
+}
+
+impl HiveValuePredicate {
+    /// `value` is `None` for null partition values.
+    fn evaluate(&self, value: Option<&str>) -> Truth {
+        match self {
+            Self::Compare(op, lit) => match (value, lit) {
+                (None, _) | (_, HiveLiteral::Null) => Truth::Null,
+                (Some(v), lit) => compare_value(v, lit)
+                    .map_or(Truth::Unknown, |ord| Truth::from_bool(op.holds(ord))),
+            },
+            Self::IsIn(lits) => {
+                let Some(v) = value else {
+                    return Truth::Null;
+                };
+                lits.iter()
+                    .filter(|lit| !matches!(lit, HiveLiteral::Null))
+                    .fold(Truth::False, |acc, lit| {
+                        acc.or(compare_value(v, lit)
+                            .map_or(Truth::Unknown, |ord| Truth::from_bool(ord.is_eq())))
+                    })
+            },
+            Self::IsNull(is_null) => Truth::from_bool(value.is_none() == *is_null),
+            Self::And(preds) => preds
+                .iter()
+                .fold(Truth::True, |acc, p| acc.and(p.evaluate(value))),
+            Self::Or(preds) => preds
+                .iter()
+                .fold(Truth::False, |acc, p| acc.or(p.evaluate(value))),
+            Self::Not(p) => p.evaluate(value).not(),
+        }
+    }
+}
+
+/// Predicates on Hive partition keys, used to prune directories while listing.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HivePathFilter {
+    predicates: Vec<(PlSmallStr, HiveValuePredicate)>,
+}
+
+impl HivePathFilter {
+    pub fn push(&mut self, key: PlSmallStr, predicate: HiveValuePredicate) {
+        self.predicates.push((key, predicate))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.predicates.is_empty()
+    }
+
+    /// Returns `false` if `dir_name` is a Hive partition directory (`key=value`) whose value
+    /// cannot satisfy the predicates. Other directory names are always kept.
+    pub fn keep_dir(&self, dir_name: &str) -> bool {
+        let mut it = dir_name.split('=');
+        let (Some(key), Some(value), None) = (it.next(), it.next(), it.next()) else {
+            return true;
+        };
+
+        let Ok(key) = percent_encoding::percent_decode_str(key).decode_utf8() else {
+            return true;
+        };
+        let Ok(value) = percent_encoding::percent_decode_str(value).decode_utf8() else {
+            return true;
+        };
+        let value = (!value.is_empty() && value != HIVE_DEFAULT_PARTITION).then_some(&*value);
+
+        self.predicates
+            .iter()
+            .filter(|(k, _)| k.as_str() == key)
+            .all(|(_, p)| matches!(p.evaluate(value), Truth::True | Truth::Unknown))
+    }
+
+    /// Applies [`Self::keep_dir`] to every directory component of `path`.
+    pub fn keep_path(&self, path: &str) -> bool {
+        let mut parts = path.split(['/', std::path::MAIN_SEPARATOR]);
+        // The last component is the file name.
+        parts.next_back();
+        parts.all(|part| self.keep_dir(part))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(
+        preds: impl IntoIterator<Item = (&'static str, HiveValuePredicate)>,
+    ) -> HivePathFilter {
+        let mut filter = HivePathFilter::default();
+        for (k, p) in preds {
+            filter.push(k.into(), p);
+        }
+        filter
+    }
+
+    #[test]
+    fn test_hive_filter_compare_and_is_in() {
+        use {HiveCompareOp as O, HiveValuePredicate as P};
+
+        let f = filter([
+            ("year", P::Compare(O::Eq, HiveLiteral::Int(2024))),
+            (
+                "month",
+                P::IsIn(vec![HiveLiteral::Int(1), HiveLiteral::Int(2)]),
+            ),
+        ]);
+
+        assert!(f.keep_dir("year=2024"));
+        assert!(!f.keep_dir("year=2023"));
+        assert!(f.keep_dir("month=01"));
+        assert!(!f.keep_dir("month=3"));
+        // Nulls never compare equal.
+        assert!(!f.keep_dir("month=__HIVE_DEFAULT_PARTITION__"));
+        assert!(!f.keep_dir("month="));
+        // Values we cannot interpret, unrelated keys and non-hive names are kept.
+        assert!(f.keep_dir("year=abc"));
+        assert!(f.keep_dir("day=1"));
+        assert!(f.keep_dir("data"));
+
+        assert!(f.keep_path("s3://bucket/t/year=2024/month=2/0.parquet"));
+        assert!(!f.keep_path("s3://bucket/t/year=2024/month=4/0.parquet"));
+        // The file name is not checked.
+        assert!(f.keep_path("s3://bucket/t/year=2024/month=1/year=1.parquet"));
+    }
+
+    #[test]
+    fn test_hive_filter_literal_types() {
+        use {HiveCompareOp as O, HiveValuePredicate as P};
+
+        let f = filter([
+            ("b", P::Compare(O::Eq, HiveLiteral::Boolean(true))),
+            ("s", P::Compare(O::Eq, HiveLiteral::String("a b/c".into()))),
+            (
+                "d",
+                P::Compare(O::GtEq, HiveLiteral::Date("2024-02-01".into())),
+            ),
+            ("f", P::Compare(O::Lt, HiveLiteral::Float(1.5))),
+        ]);
+
+        assert!(f.keep_dir("b=TRUE"));
+        assert!(!f.keep_dir("b=false"));
+        assert!(f.keep_dir("s=a%20b%2Fc"));
+        assert!(!f.keep_dir("s=a b"));
+        assert!(f.keep_dir("d=2024-03-01"));
+        assert!(!f.keep_dir("d=2024-01-31"));
+        assert!(f.keep_dir("d=2024-01-31 10:00:00"));
+        assert!(f.keep_dir("f=1"));
+        assert!(!f.keep_dir("f=2.5"));
+    }
+
+    #[test]
+    fn test_hive_filter_boolean_logic() {
+        use {HiveCompareOp as O, HiveValuePredicate as P};
+
+        let f = filter([(
+            "k",
+            P::Or(vec![
+                P::IsNull(true),
+                P::Not(Box::new(P::Compare(O::LtEq, HiveLiteral::Int(5)))),
+            ]),
+        )]);
+
+        assert!(f.keep_dir("k="));
+        assert!(f.keep_dir("k=6"));
+        assert!(!f.keep_dir("k=5"));
+
+        let f = filter([(
+            "k",
+            P::And(vec![
+                P::Compare(O::Gt, HiveLiteral::Int(1)),
+                P::Compare(O::Lt, HiveLiteral::Int(4)),
+            ]),
+        )]);
+
+        assert!(f.keep_dir("k=2"));
+        assert!(!f.keep_dir("k=4"));
+        assert!(!f.keep_dir("k=__HIVE_DEFAULT_PARTITION__"));
+    }
+}
@@ -6,9 +6,11 @@ use polars_core::config;
 use polars_core::error::{PolarsError, PolarsResult, polars_bail, to_compute_err};
 use polars_utils::pl_str::PlSmallStr;
 
+pub mod hive_filter;
 #[cfg(feature = "cloud")]
 mod hugging_face;
 
+use self::hive_filter::HivePathFilter;
 use crate::cloud::CloudOptions;
 
 pub static POLARS_TEMP_DIR_BASE_PATH: LazyLock<Box<Path>> = LazyLock::new(|| {
@@ -135,7 +137,7 @@ pub fn resolve_homedir(path: &dyn AsRef<Path>) -> PathBuf {
 }
 
 polars_utils::regex_cache::cached_regex! {
-    static CLOUD_URL = r"^(s3a?|gs|gcs|file|abfss?|azure|az|adl|https?|hf)://";
+    static CLOUD_URL = r"^(s3a?|gs|gcs|file|abfss?|azure|az|adl|https?|hf|memory)://";
 }
 
 /// Check if the path is a cloud url.
@@ -178,7 +180,7 @@ pub fn expand_paths(
     glob: bool,
     #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
 ) -> PolarsResult<Arc<[PathBuf]>> {
-    expand_paths_hive(paths, glob, cloud_options, false).map(|x| x.0)
+    expand_paths_hive(paths, glob, cloud_options, false, None).map(|x| x.0)
 }
 
 struct HiveIdxTracker<'a> {
@@ -214,11 +216,137 @@ impl HiveIdxTracker<'_> {
 /// Recursively traverses directories and expands globs if `glob` is `true`.
 /// Returns the expanded paths and the index at which to start parsing hive
 /// partitions from the path.
+///
+/// If a `hive_filter` is given, hive partition directories whose values cannot
+/// match it are not listed. The result is empty if every partition was pruned.
 pub fn expand_paths_hive(
+    paths: &[PathBuf],
+    glob: bool,
+    cloud_options: Option<&CloudOptions>,
+    check_directory_level: bool,
+    hive_filter: Option<&HivePathFilter>,
+) -> PolarsResult<(Arc<[PathBuf]>, usize)> {
+    if let Some(hive_filter) = hive_filter.filter(|f| !f.is_empty()) {
+        let out = expand_paths_hive_impl(
+            paths,
+            glob,
+            cloud_options,
+            check_directory_level,
+            Some(hive_filter),
+        )?;
+
+        if config::verbose() {
+            eprintln!(
+                "[expand_paths_hive]: listed {} paths after hive partition pruning",
+                out.0.len()
+            );
+        }
+
+        return Ok(out);
+    }
+
+    expand_paths_hive_impl(paths, glob, cloud_options, check_directory_level, None)
+}
+
+/// Finds a single non-empty file under `paths` without listing all of them. This is used to
+/// resolve the schema when hive partition pruning leaves no files to scan.
+pub fn first_path_hive(
+    paths: &[PathBuf],
+    glob: bool,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Option<PathBuf>> {
+    for path in paths {
+        let is_cloud = is_cloud_url(path);
+        let is_glob = glob && get_glob_start_idx(path.to_str().unwrap().as_bytes()).is_some();
+
+        let is_async = cfg!(not(target_family = "windows")) && config::force_async();
+
+        let first = if !is_cloud && !is_glob && !is_async && path.is_dir() {
+            first_path_local(path)?
+        } else if is_cloud
+            && !is_glob
+            && path.to_str().unwrap().ends_with('/')
+            && !path.starts_with("hf://")
+            && !path.to_str().unwrap().starts_with("http")
+        {
+            first_path_cloud(path.to_str().unwrap(), cloud_options)?
+        } else {
+            expand_paths_hive_impl(std::slice::from_ref(path), glob, cloud_options, false, None)?
+                .0
+                .first()
+                .cloned()
+        };
+
+        if first.is_some() {
+            return Ok(first);
+        }
+    }
+
+    Ok(None)
+}
+
+fn first_path_local(dir: &Path) -> PolarsResult<Option<PathBuf>> {
+    let mut stack = vec![dir.to_path_buf()];
+
+    while let Some(dir) = stack.pop() {
+        let mut paths = std::fs::read_dir(dir)
+            .map_err(PolarsError::from)?
+            .map(|x| x.map(|x| x.path()))
+            .collect::<std::io::Result<Vec<_>>>()
+            .map_err(PolarsError::from)?;
+        paths.sort_unstable();
+
+        let mut dirs = vec![];
+        for path in paths {
+            if path.is_dir() {
+                dirs.push(path);
+            } else if path.metadata()?.len() > 0 {
+                return Ok(Some(path));
+            }
+        }
+
+        // Pushed in reverse so that the directories are visited in sorted order.
+        stack.extend(dirs.into_iter().rev());
+    }
+
+    Ok(None)
+}
+
+#[cfg(feature = "cloud")]
+fn first_path_cloud(
+    path: &str,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Option<PathBuf>> {
+    use crate::cloud::object_path_from_str;
+
+    crate::pl_async::get_runtime().block_in_place_on(async {
+        let (cloud_location, store) =
+            crate::cloud::build_object_store(path, cloud_options, false).await?;
+        let prefix = object_path_from_str(&cloud_location.prefix)?;
+
+        Ok(crate::cloud::first_object(&store, &prefix).await?.map(|x| {
+            PathBuf::from(format!(
+                "{}://{}/{}",
+                cloud_location.scheme, cloud_location.bucket, x.location
+            ))
+        }))
+    })
+}
+
+#[cfg(not(feature = "cloud"))]
+fn first_path_cloud(
+    _path: &str,
+    _cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Option<PathBuf>> {
+    panic!("Feature `cloud` must be enabled to use globbing patterns with cloud urls.")
+}
+
+fn expand_paths_hive_impl(
     paths: &[PathBuf],
     glob: bool,
     #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
     check_directory_level: bool,
+    hive_filter: Option<&HivePathFilter>,
 ) -> PolarsResult<(Arc<[PathBuf]>, usize)> {
     let Some(first_path) = paths.first() else {
         return Ok((vec![].into(), 0));
@@ -359,33 +487,47 @@ pub fn expand_paths_hive(
 
                         let cloud_location = &cloud_location;
 
-                        let mut paths = store
-                            .try_exec_rebuild_on_err(|store| {
-                                let st = store.clone();
-
-                                async {
-                                    let store = st;
-                                    let out = store
-                                        .list(Some(&prefix))
-                                        .try_filter_map(|x| async move {
-                                            let out = (x.size > 0).then(|| {
-                                                PathBuf::from({
-                                                    format_path(
-                                                        &cloud_location.scheme,
-                                                        &cloud_location.bucket,
-                                                        x.location.as_ref(),
-                                                    )
-                                                })
-                                            });
-                                            Ok(out)
-                                        })
-                                        .try_collect::<Vec<_>>()
-                                        .await?;
-
-                                    Ok(out)
-                                }
-                            })
-                            .await?;
+                        let mut paths = if let Some(hive_filter) = hive_filter {
+                            crate::cloud::list_hive_pruned(&store, &prefix, hive_filter)
+                                .await?
+                                .into_iter()
+                                .map(|x| {
+                                    PathBuf::from(format_path(
+                                        &cloud_location.scheme,
+                                        &cloud_location.bucket,
+                                        x.location.as_ref(),
+                                    ))
+                                })
+                                .collect::<Vec<_>>()
+                        } else {
+                            store
+                                .try_exec_rebuild_on_err(|store| {
+                                    let st = store.clone();
+
+                                    async {
+                                        let store = st;
+                                        let out = store
+                                            .list(Some(&prefix))
+                                            .try_filter_map(|x| async move {
+                                                let out = (x.size > 0).then(|| {
+                                                    PathBuf::from({
+                                                        format_path(
+                                                            &cloud_location.scheme,
+                                                            &cloud_location.bucket,
+                                                            x.location.as_ref(),
+                                                        )
+                                                    })
+                                                });
+                                                Ok(out)
+                                            })
+                                            .try_collect::<Vec<_>>()
+                                            .await?;
+
+                                        Ok(out)
+                                    }
+                                })
+                                .await?
+                        };
 
                         paths.sort_unstable();
                         (
@@ -424,8 +566,11 @@ pub fn expand_paths_hive(
 
                 hive_idx_tracker.update(0, path_idx)?;
 
-                let iter = crate::pl_async::get_runtime()
-                    .block_in_place_on(crate::async_glob(path.to_str().unwrap(), cloud_options))?;
+                let iter = crate::pl_async::get_runtime().block_in_place_on(crate::async_glob(
+                    path.to_str().unwrap(),
+                    cloud_options,
+                    hive_filter,
+                ))?;
 
                 if is_cloud {
                     out_paths.extend(iter.into_iter().map(PathBuf::from));
@@ -461,6 +606,15 @@ pub fn expand_paths_hive(
 
                     for path in paths {
                         if path.is_dir() {
+                            if let Some(hive_filter) = hive_filter {
+                                if !path
+                                    .file_name()
+                                    .and_then(|x| x.to_str())
+                                    .is_none_or(|x| hive_filter.keep_dir(x))
+                                {
+                                    continue;
+                                }
+                            }
                             stack.push_back(path);
                         } else if path.metadata()?.len() > 0 {
                             out_paths.push(path);
@@ -482,7 +636,10 @@ pub fn expand_paths_hive(
 
                 for path in paths {
                     let path = path.map_err(to_compute_err)?;
-                    if !path.is_dir() && path.metadata()?.len() > 0 {
+                    if !path.is_dir()
+                        && path.metadata()?.len() > 0
+                        && hive_filter.is_none_or(|f| f.keep_path(path.to_str().unwrap()))
+                    {
                         out_paths.push(path);
                     }
                 }
@@ -574,4 +731,50 @@ mod tests {
         let out = expand_paths(paths, true, None).unwrap();
         assert_eq!(out.as_ref(), paths);
     }
+
+    #[test]
+    fn test_expand_paths_hive_pruned_to_nothing() {
+        use super::hive_filter::{HiveCompareOp, HiveLiteral, HivePathFilter, HiveValuePredicate};
+        use super::{expand_paths_hive, first_path_hive};
+
+        let dir = tempfile::tempdir().unwrap();
+        for (part, file) in [
+            ("a=1", "0.parquet"),
+            ("a=2", "0.parquet"),
+            ("a=2", "1.parquet"),
+        ] {
+            std::fs::create_dir_all(dir.path().join(part)).unwrap();
+            std::fs::write(dir.path().join(part).join(file), b"x").unwrap();
+        }
+        let paths = &[dir.path().to_path_buf()];
+
+        let mut filter = HivePathFilter::default();
+        filter.push(
+            "a".into(),
+            HiveValuePredicate::Compare(HiveCompareOp::GtEq, HiveLiteral::Int(2)),
+        );
+        let (out, _) = expand_paths_hive(paths, false, None, true, Some(&filter)).unwrap();
+        assert_eq!(
+            out.as_ref(),
+            [
+                dir.path().join("a=2/0.parquet"),
+                dir.path().join("a=2/1.parquet")
+            ]
+        );
+
+        let mut filter = HivePathFilter::default();
+        filter.push(
+            "a".into(),
+            HiveValuePredicate::Compare(HiveCompareOp::Eq, HiveLiteral::Int(3)),
+        );
+        let (out, hive_start_idx) =
+            expand_paths_hive(paths, false, None, true, Some(&filter)).unwrap();
+        assert!(out.is_empty());
+        assert_eq!(hive_start_idx, dir.path().to_str().unwrap().len());
+
+        assert_eq!(
+            first_path_hive(paths, false, None).unwrap(),
+            Some(dir.path().join("a=1/0.parquet"))
+        );
+    }
 }
@@ -8,7 +8,7 @@ use polars_utils::mmap::MemSlice;
 
 use crate::cloud::{
     CloudLocation, CloudOptions, ObjectStorePath, PolarsObjectStore, build_object_store,
-    object_path_from_str,
+    object_meta_version, object_path_from_str,
 };
 
 #[allow(async_fn_in_trait)]
@@ -66,8 +66,11 @@ impl ByteSource for MemSliceByteSource {
 }
 
 pub struct ObjectStoreByteSource {
+    uri: Arc<str>,
     store: PolarsObjectStore,
     path: ObjectStorePath,
+    /// Memoized result of the HEAD request.
+    object_meta: tokio::sync::OnceCell<object_store::ObjectMeta>,
 }
 
 impl ObjectStoreByteSource {
@@ -75,17 +78,38 @@ impl ObjectStoreByteSource {
         path: &str,
         cloud_options: Option<&CloudOptions>,
     ) -> PolarsResult<Self> {
+        let uri = Arc::<str>::from(path);
         let (CloudLocation { prefix, .. }, store) =
             build_object_store(path, cloud_options, false).await?;
         let path = object_path_from_str(&prefix)?;
 
-        Ok(Self { store, path })
+        Ok(Self {
+            uri,
+            store,
+            path,
+            object_meta: Default::default(),
+        })
+    }
+
+    async fn head(&self) -> PolarsResult<&object_store::ObjectMeta> {
+        self.object_meta
+            .get_or_try_init(|| self.store.head(&self.path))
+            .await
+    }
+
+    pub fn uri(&self) -> &Arc<str> {
+        &self.uri
+    }
+
+    /// Remote version of the object, used to validate cached data.
+    pub async fn get_version(&self) -> PolarsResult<Arc<str>> {
+        Ok(object_meta_version(self.head().await?))
     }
 }
 
 impl ByteSource for ObjectStoreByteSource {
     async fn get_size(&self) -> PolarsResult<usize> {
-        Ok(self.store.head(&self.path).await?.size as usize)
+        Ok(self.head().await?.size as usize)
     }
 
     async fn get_range(&self, range: Range<usize>) -> PolarsResult<MemSlice> {
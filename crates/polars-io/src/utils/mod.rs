@@ -6,6 +6,7 @@ pub use other::*;
 pub mod byte_source;
 pub mod file;
 pub mod mkdir;
+pub mod partition_path;
 pub mod slice;
 pub mod sync_on_close;
 
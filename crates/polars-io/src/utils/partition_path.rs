@@ -0,0 +1,880 @@
+//! Output paths of hive-partitioned datasets.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+
+use crate::cloud::CloudOptions;
+
+/// Directory value used for null (and empty) partition values, as used by Hive and Spark.
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+fn needs_hive_escape(c: char) -> bool {
+    matches!(
+        c,
+        '\u{00}'
+            ..='\u{1F}'
+                | '\u{7F}'
+                | '"'
+                | '#'
+                | '%'
+                | '\''
+                | '*'
+                | '/'
+                | ':'
+                | '='
+                | '?'
+                | '\\'
+                | '{'
+                | '['
+                | ']'
+                | '^'
+    )
+}
+
+/// Escapes a partition value the same way as Hive / Spark (`escapePathName`). Special ASCII
+/// characters are percent-encoded, all other characters are kept as-is.
+pub fn hive_escape_partition_value(value: &str, out: &mut String) {
+    if value.is_empty() {
+        out.push_str(HIVE_DEFAULT_PARTITION);
+        return;
+    }
+
+    for c in value.chars() {
+        if needs_hive_escape(c) {
+            use std::fmt::Write;
+            write!(out, "%{:02X}", c as u32).unwrap();
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum TemplatePart {
+    Literal(String),
+    Placeholder {
+        name: PlSmallStr,
+        /// Zero-pad integers to this width.
+        width: Option<usize>,
+    },
+}
+
+const BUILTIN_PLACEHOLDERS: [&str; 5] = ["uuid", "file_idx", "part_idx", "in_part_idx", "ext"];
+
+/// User-defined file path of a partitioned sink relative to its base path, e.g.
+/// `year={year}/month={month:02}/part-{uuid}.{ext}`.
+///
+/// Placeholders refer to partition key columns or to one of the builtins:
+/// * `{uuid}`: random UUID (v4), different for every file.
+/// * `{file_idx}`, `{part_idx}`, `{in_part_idx}`: the index of the file, of the partition and of
+///   the file within the partition.
+/// * `{ext}`: extension of the file type.
+///
+/// Key columns take precedence over builtins with the same name. Integer placeholders can be
+/// zero-padded using `{name:0N}`. Key values are escaped the same way as Hive / Spark. Use `{{`
+/// and `}}` for literal braces.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct PartitionPathTemplate {
+    template: Arc<str>,
+    parts: Arc<[TemplatePart]>,
+}
+
+impl fmt::Debug for PartitionPathTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.template, f)
+    }
+}
+
+impl fmt::Display for PartitionPathTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.template)
+    }
+}
+
+/// Indices available to a [`PartitionPathTemplate`].
+#[derive(Debug, Clone, Copy)]
+pub struct PartitionPathIndices {
+    pub file_idx: usize,
+    pub part_idx: usize,
+    pub in_part_idx: usize,
+}
+
+impl PartitionPathTemplate {
+    pub fn new(template: &str) -> PolarsResult<Self> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                },
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                },
+                '}' => {
+                    polars_bail!(InvalidOperation: "unmatched '}}' in partition path template '{template}'")
+                },
+                '{' => {
+                    let mut placeholder = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) => placeholder.push(c),
+                            None => polars_bail!(
+                                InvalidOperation: "unclosed '{{' in partition path template '{template}'"
+                            ),
+                        }
+                    }
+
+                    let (name, width) = match placeholder.split_once(':') {
+                        None => (placeholder.as_str(), None),
+                        Some((name, spec)) => {
+                            let width = spec
+                                .strip_prefix('0')
+                                .and_then(|w| w.parse::<usize>().ok())
+                                .filter(|_| spec.len() > 1);
+                            let Some(width) = width else {
+                                polars_bail!(
+                                    InvalidOperation:
+                                    "invalid format spec '{spec}' for placeholder '{name}' in partition path template '{template}', expected a zero-padding width like '02'"
+                                )
+                            };
+                            (name, Some(width))
+                        },
+                    };
+
+                    polars_ensure!(
+                        !name.is_empty(),
+                        InvalidOperation: "empty placeholder in partition path template '{template}'"
+                    );
+
+                    if !literal.is_empty() {
+                        parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(TemplatePart::Placeholder {
+                        name: name.into(),
+                        width,
+                    });
+                },
+                c => literal.push(c),
+            }
+        }
+
+        if !literal.is_empty() {
+            parts.push(TemplatePart::Literal(literal));
+        }
+
+        polars_ensure!(
+            !template.starts_with('/') && !template.ends_with('/'),
+            InvalidOperation: "partition path template '{template}' must be a relative file path"
+        );
+
+        Ok(Self {
+            template: template.into(),
+            parts: parts.into(),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    fn placeholders(&self) -> impl Iterator<Item = &PlSmallStr> {
+        self.parts.iter().filter_map(|p| match p {
+            TemplatePart::Placeholder { name, .. } => Some(name),
+            TemplatePart::Literal(_) => None,
+        })
+    }
+
+    /// Checks that all placeholders refer to either a key column or a builtin.
+    pub fn validate(&self, key_names: &[PlSmallStr]) -> PolarsResult<()> {
+        for name in self.placeholders() {
+            polars_ensure!(
+                key_names.contains(name) || BUILTIN_PLACEHOLDERS.contains(&name.as_str()),
+                InvalidOperation:
+                "unknown placeholder '{{{name}}}' in partition path template '{}', expected a partition key or one of {:?}",
+                self.template, BUILTIN_PLACEHOLDERS
+            );
+            #[cfg(not(feature = "partition"))]
+            polars_ensure!(
+                name != "uuid" || key_names.contains(name),
+                InvalidOperation:
+                "placeholder '{{uuid}}' in partition path template '{}' requires the 'partition' feature",
+                self.template
+            );
+        }
+        Ok(())
+    }
+
+    /// Number of leading parts that make up the directory of the rendered path.
+    fn dir_parts_len(&self) -> usize {
+        let mut len = 0;
+        for (i, part) in self.parts.iter().enumerate() {
+            if matches!(part, TemplatePart::Literal(s) if s.contains('/')) {
+                len = i + 1;
+            }
+        }
+        len
+    }
+
+    /// Checks whether the directory of the rendered path only depends on (and fully identifies)
+    /// the partition keys. This is required for dynamic partition overwrites.
+    fn validate_for_dynamic_overwrite(&self, key_names: &[PlSmallStr]) -> PolarsResult<()> {
+        let dir_parts = &self.parts[..self.dir_parts_len()];
+
+        for part in dir_parts {
+            if let TemplatePart::Placeholder { name, .. } = part {
+                polars_ensure!(
+                    key_names.contains(name),
+                    InvalidOperation:
+                    "dynamic partition overwrite requires the directories of partition path template '{}' to only contain partition keys, found '{{{name}}}'",
+                    self.template
+                );
+            }
+        }
+
+        for key in key_names {
+            polars_ensure!(
+                dir_parts.iter().any(|p| matches!(p, TemplatePart::Placeholder { name, .. } if name == key)),
+                InvalidOperation:
+                "dynamic partition overwrite requires the directories of partition path template '{}' to contain all partition keys, missing '{{{key}}}'",
+                self.template
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Renders the file path for a file of the partition with `keys`. Every key column must
+    /// contain the partition value as its first element.
+    pub fn render(
+        &self,
+        keys: &[Column],
+        indices: PartitionPathIndices,
+        ext: &str,
+    ) -> PolarsResult<PathBuf> {
+        let mut out = String::new();
+        for part in self.parts.iter() {
+            self.render_part(part, keys, indices, ext, &mut out)?;
+        }
+        Ok(PathBuf::from(out))
+    }
+
+    /// Renders only the directory of the path, which identifies the partition.
+    fn render_dir(&self, keys: &[Column], indices: PartitionPathIndices) -> PolarsResult<PathBuf> {
+        let mut out = String::new();
+        for part in self.parts[..self.dir_parts_len()].iter() {
+            self.render_part(part, keys, indices, "", &mut out)?;
+        }
+        // Strip the file name prefix following the last separator.
+        out.truncate(out.rfind('/').map_or(0, |i| i));
+        Ok(PathBuf::from(out))
+    }
+
+    fn render_part(
+        &self,
+        part: &TemplatePart,
+        keys: &[Column],
+        indices: PartitionPathIndices,
+        ext: &str,
+        out: &mut String,
+    ) -> PolarsResult<()> {
+        let (name, width) = match part {
+            TemplatePart::Literal(s) => {
+                out.push_str(s);
+                return Ok(());
+            },
+            TemplatePart::Placeholder { name, width } => (name, *width),
+        };
+
+        let pad_int = |out: &mut String, v: i128| match width {
+            Some(width) => out.push_str(&format!("{v:0width$}")),
+            None => out.push_str(&v.to_string()),
+        };
+
+        if let Some(key) = keys.iter().find(|k| k.name() == name) {
+            let value = key.get(0)?;
+
+            if value.is_null() {
+                out.push_str(HIVE_DEFAULT_PARTITION);
+            } else if width.is_some() {
+                polars_ensure!(
+                    key.dtype().is_integer(),
+                    InvalidOperation:
+                    "zero-padding in partition path template '{}' is only supported for integer keys, '{name}' has dtype {}",
+                    self.template, key.dtype()
+                );
+                pad_int(out, value.extract::<i128>().unwrap());
+            } else {
+                let value = key.head(Some(1)).strict_cast(&DataType::String)?;
+                hive_escape_partition_value(value.str().unwrap().get(0).unwrap(), out);
+            }
+
+            return Ok(());
+        }
+
+        match name.as_str() {
+            "file_idx" => pad_int(out, indices.file_idx as i128),
+            "part_idx" => pad_int(out, indices.part_idx as i128),
+            "in_part_idx" => pad_int(out, indices.in_part_idx as i128),
+            "uuid" | "ext" if width.is_some() => polars_bail!(
+                InvalidOperation:
+                "zero-padding in partition path template '{}' is not supported for '{{{name}}}'",
+                self.template
+            ),
+            #[cfg(feature = "partition")]
+            "uuid" => out.push_str(&uuid::Uuid::new_v4().to_string()),
+            #[cfg(not(feature = "partition"))]
+            "uuid" => polars_bail!(
+                InvalidOperation:
+                "placeholder '{{uuid}}' in partition path template '{}' requires the 'partition' feature",
+                self.template
+            ),
+            "ext" => out.push_str(ext),
+            _ => polars_bail!(
+                InvalidOperation:
+                "unknown placeholder '{{{name}}}' in partition path template '{}'",
+                self.template
+            ),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PartitionPathTemplate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.template.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PartitionPathTemplate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        let template = String::deserialize(deserializer)?;
+        Self::new(&template).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for PartitionPathTemplate {
+    fn schema_name() -> String {
+        "PartitionPathTemplate".to_owned()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "PartitionPathTemplate"))
+    }
+
+    fn json_schema(generator: &mut schemars::r#gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(generator)
+    }
+}
+
+/// What to do with existing data in the output directory of a partitioned write.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum PartitionOverwriteMode {
+    /// Keep existing files. Files with the same path are overwritten.
+    #[default]
+    Append,
+    /// Replace the existing data of every partition that is written to once all files have been
+    /// written successfully. Other partitions are left untouched.
+    Dynamic,
+}
+
+/// Options for the file paths of a partitioned write.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct PartitionPathOptions {
+    /// File path template relative to the base path. Uses the `key=value/...` layout if `None`.
+    pub template: Option<PartitionPathTemplate>,
+    pub overwrite_mode: PartitionOverwriteMode,
+}
+
+impl PartitionPathOptions {
+    /// Validates the options for partitioning by `key_names`. `has_file_path_cb` indicates
+    /// whether the paths are additionally modified by a user callback.
+    pub fn validate(&self, key_names: &[PlSmallStr], has_file_path_cb: bool) -> PolarsResult<()> {
+        if let Some(template) = &self.template {
+            template.validate(key_names)?;
+        }
+
+        if self.overwrite_mode == PartitionOverwriteMode::Dynamic {
+            polars_ensure!(
+                !key_names.is_empty(),
+                InvalidOperation: "dynamic partition overwrite requires partitioning by key"
+            );
+            polars_ensure!(
+                !has_file_path_cb,
+                InvalidOperation: "dynamic partition overwrite cannot be combined with a file path callback"
+            );
+            if let Some(template) = &self.template {
+                template.validate_for_dynamic_overwrite(key_names)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the directory that holds all files of the partition with `keys`, relative to the
+    /// base path.
+    pub fn partition_dir(&self, keys: &[Column]) -> PolarsResult<PathBuf> {
+        let indices = PartitionPathIndices {
+            file_idx: 0,
+            part_idx: 0,
+            in_part_idx: 0,
+        };
+
+        match &self.template {
+            Some(template) => template.render_dir(keys, indices),
+            None => default_partition_dir(keys),
+        }
+    }
+}
+
+/// Returns the default `key=value/...` directory for the partition with `keys`.
+pub fn default_partition_dir(keys: &[Column]) -> PolarsResult<PathBuf> {
+    let mut path = PathBuf::new();
+    let mut part = String::new();
+    for c in keys {
+        part.clear();
+        part.push_str(c.name());
+        part.push('=');
+        let value = c.head(Some(1)).strict_cast(&DataType::String)?;
+        match value.str().unwrap().get(0) {
+            Some(value) => hive_escape_partition_value(value, &mut part),
+            None => part.push_str(HIVE_DEFAULT_PARTITION),
+        }
+        path.push(&part);
+    }
+    Ok(path)
+}
+
+/// Removes all existing files of a local partition directory. A missing directory is not an
+/// error.
+pub fn remove_local_partition_dir(path: &Path) -> PolarsResult<()> {
+    match std::fs::remove_dir_all(path) {
+        Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+        _ => Ok(()),
+    }
+}
+
+/// Removes all existing files of a local or cloud partition directory. A missing directory is not
+/// an error.
+#[cfg(feature = "cloud")]
+pub async fn remove_partition_dir(
+    path: &Path,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<()> {
+    use futures::{StreamExt, TryStreamExt};
+    use polars_error::to_compute_err;
+
+    let path_str = path.to_str().unwrap();
+
+    if !crate::is_cloud_url(path_str) {
+        return remove_local_partition_dir(path);
+    }
+
+    let (location, store) =
+        crate::cloud::build_object_store(path_str, cloud_options, false).await?;
+    let prefix = object_store::path::Path::from(location.prefix.as_str());
+
+    store
+        .try_exec_rebuild_on_err(|store| {
+            let store = store.clone();
+            let prefix = prefix.clone();
+
+            async move {
+                let locations = store.list(Some(&prefix)).map_ok(|x| x.location).boxed();
+                store
+                    .delete_stream(locations)
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .map_err(to_compute_err)?;
+                Ok(())
+            }
+        })
+        .await
+}
+
+/// Staging area of a dynamic partition overwrite.
+///
+/// The files of every partition that is written to are first written below a hidden directory in
+/// the base path. The existing partitions are only replaced on [`PartitionStaging::commit`], so a
+/// failed write leaves them untouched. The staging directory is removed if the staging is dropped
+/// without being committed.
+pub struct PartitionStaging {
+    base_path: PathBuf,
+    staging_name: String,
+    is_cloud: bool,
+    cloud_options: Option<CloudOptions>,
+    /// Partition directories relative to the base path that have files in the staging area.
+    partition_dirs: Mutex<PlIndexSet<PathBuf>>,
+    committed: AtomicBool,
+}
+
+impl PartitionStaging {
+    pub fn new(base_path: &Path, cloud_options: Option<&CloudOptions>) -> Self {
+        static STAGING_IDX: AtomicUsize = AtomicUsize::new(0);
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        let staging_name = format!(
+            ".polars-staging-{}-{timestamp:x}-{}",
+            std::process::id(),
+            STAGING_IDX.fetch_add(1, Ordering::Relaxed)
+        );
+
+        Self {
+            base_path: base_path.to_path_buf(),
+            staging_name,
+            is_cloud: crate::is_cloud_url(base_path),
+            cloud_options: cloud_options.cloned(),
+            partition_dirs: Mutex::new(PlIndexSet::default()),
+            committed: AtomicBool::new(false),
+        }
+    }
+
+    fn staging_path(&self) -> PathBuf {
+        self.base_path.join(&self.staging_name)
+    }
+
+    /// Returns the path that `file_path` of the partition in `partition_dir` is written to. Both
+    /// paths are relative to the base path.
+    pub fn stage(&self, partition_dir: PathBuf, file_path: &Path) -> PathBuf {
+        self.partition_dirs.lock().unwrap().insert(partition_dir);
+        self.staging_path().join(file_path)
+    }
+
+    /// Replaces the existing local partitions with the staged ones.
+    pub fn commit_local(&self) -> PolarsResult<()> {
+        assert!(!self.is_cloud);
+
+        let staging_path = self.staging_path();
+        let old_path = staging_path.join(".old");
+
+        for (i, dir) in self.partition_dirs.lock().unwrap().iter().enumerate() {
+            let staged = staging_path.join(dir);
+            let target = self.base_path.join(dir);
+            let old = old_path.join(i.to_string());
+
+            let has_old = target.exists();
+            if has_old {
+                std::fs::create_dir_all(&old_path)?;
+                std::fs::rename(&target, &old)?;
+            }
+
+            let result = std::fs::create_dir_all(target.parent().unwrap())
+                .and_then(|_| std::fs::rename(&staged, &target));
+            if let Err(err) = result {
+                if has_old {
+                    _ = std::fs::rename(&old, &target);
+                }
+                return Err(err.into());
+            }
+        }
+
+        self.committed.store(true, Ordering::Relaxed);
+        remove_local_partition_dir(&staging_path)
+    }
+
+    /// Replaces the existing local or cloud partitions with the staged ones.
+    #[cfg(feature = "cloud")]
+    pub async fn commit(&self) -> PolarsResult<()> {
+        use futures::{StreamExt, TryStreamExt};
+        use object_store::path::Path as ObjectPath;
+        use polars_error::to_compute_err;
+
+        if !self.is_cloud {
+            return self.commit_local();
+        }
+
+        let (location, store) = crate::cloud::build_object_store(
+            self.base_path.to_str().unwrap(),
+            self.cloud_options.as_ref(),
+            false,
+        )
+        .await?;
+        let store = store.to_dyn_object_store().await;
+
+        let join = |prefix: &str, path: &str| match prefix {
+            "" => ObjectPath::from(path),
+            _ => ObjectPath::from(format!("{prefix}/{path}")),
+        };
+        let staging_prefix = join(&location.prefix, &self.staging_name);
+
+        let partition_dirs = std::mem::take(&mut *self.partition_dirs.lock().unwrap());
+        for dir in partition_dirs.iter() {
+            let dir = dir.to_str().unwrap();
+            let target = join(&location.prefix, dir);
+            let staged = join(staging_prefix.as_ref(), dir);
+
+            let existing = store
+                .list(Some(&target))
+                .map_ok(|x| x.location)
+                .try_collect::<Vec<_>>()
+                .await
+                .map_err(to_compute_err)?;
+            let staged_files = store
+                .list(Some(&staged))
+                .map_ok(|x| x.location)
+                .try_collect::<Vec<_>>()
+                .await
+                .map_err(to_compute_err)?;
+
+            // Move the new files into place before deleting the old ones, so that the partition
+            // is never missing.
+            let mut written = PlHashSet::with_capacity(staged_files.len());
+            for from in staged_files {
+                let to = ObjectPath::from_iter(
+                    target.parts().chain(from.prefix_match(&staged).unwrap()),
+                );
+                store.rename(&from, &to).await.map_err(to_compute_err)?;
+                written.insert(to);
+            }
+
+            let stale = existing
+                .into_iter()
+                .filter(|p| !written.contains(p))
+                .map(Ok);
+            store
+                .delete_stream(futures::stream::iter(stale).boxed())
+                .try_collect::<Vec<_>>()
+                .await
+                .map_err(to_compute_err)?;
+        }
+
+        self.committed.store(true, Ordering::Relaxed);
+        remove_partition_dir(&self.staging_path(), self.cloud_options.as_ref()).await
+    }
+}
+
+impl Drop for PartitionStaging {
+    fn drop(&mut self) {
+        if *self.committed.get_mut() {
+            return;
+        }
+
+        if !self.is_cloud {
+            _ = remove_local_partition_dir(&self.staging_path());
+            return;
+        }
+
+        #[cfg(feature = "cloud")]
+        {
+            let staging_path = self.staging_path();
+            let cloud_options = self.cloud_options.take();
+            crate::pl_async::get_runtime().spawn(async move {
+                _ = remove_partition_dir(&staging_path, cloud_options.as_ref()).await;
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    fn keys() -> Vec<Column> {
+        vec![
+            Column::new("year".into(), [2024i32]),
+            Column::new("month".into(), [3u32]),
+            Column::new("city".into(), ["a/b:c=d é"]),
+            Column::full_null("null".into(), 1, &DataType::Int32),
+        ]
+    }
+
+    #[test]
+    fn test_hive_escape_partition_value() {
+        let mut out = String::new();
+        hive_escape_partition_value("a/b%c d\u{1}é", &mut out);
+        assert_eq!(out, "a%2Fb%25c d%01é");
+
+        out.clear();
+        hive_escape_partition_value("", &mut out);
+        assert_eq!(out, HIVE_DEFAULT_PARTITION);
+    }
+
+    #[test]
+    fn test_partition_path_template_render() {
+        let key_names = keys().iter().map(|c| c.name().clone()).collect::<Vec<_>>();
+        let indices = PartitionPathIndices {
+            file_idx: 7,
+            part_idx: 3,
+            in_part_idx: 1,
+        };
+
+        let template = PartitionPathTemplate::new(
+            "year={year}/month={month:02}/city={city}/n={null}/{part_idx:04}-{{{in_part_idx}}}.{ext}",
+        )
+        .unwrap();
+        template.validate(&key_names).unwrap();
+        assert_eq!(
+            template.render(&keys(), indices, "parquet").unwrap(),
+            PathBuf::from(
+                "year=2024/month=03/city=a%2Fb%3Ac%3Dd é/n=__HIVE_DEFAULT_PARTITION__/0003-{1}.parquet"
+            )
+        );
+        assert_eq!(
+            template.render_dir(&keys(), indices).unwrap(),
+            PathBuf::from("year=2024/month=03/city=a%2Fb%3Ac%3Dd é/n=__HIVE_DEFAULT_PARTITION__")
+        );
+
+        let template = PartitionPathTemplate::new("{year}/part-{uuid}.{ext}").unwrap();
+        #[cfg(feature = "partition")]
+        {
+            let path = template.render(&keys(), indices, "csv").unwrap();
+            let path = path.to_str().unwrap();
+            assert!(path.starts_with("2024/part-") && path.ends_with(".csv"));
+            assert_eq!(path.len(), "2024/part-.csv".len() + 36);
+        }
+        #[cfg(not(feature = "partition"))]
+        assert!(template.validate(&key_names).is_err());
+
+        assert!(PartitionPathTemplate::new("{year").is_err());
+        assert!(PartitionPathTemplate::new("year}").is_err());
+        assert!(PartitionPathTemplate::new("{year:2}").is_err());
+        assert!(PartitionPathTemplate::new("/{year}").is_err());
+        assert!(
+            PartitionPathTemplate::new("{day}.parquet")
+                .unwrap()
+                .validate(&key_names)
+                .is_err()
+        );
+        assert!(
+            PartitionPathTemplate::new("{city:02}.parquet")
+                .unwrap()
+                .render(&keys(), indices, "parquet")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_partition_dir_escaping() {
+        let keys = [
+            Column::new("city".into(), ["a/b=c d%e"]),
+            Column::full_null("null".into(), 1, &DataType::String),
+        ];
+        let expected = PathBuf::from("city=a%2Fb%3Dc d%25e/null=__HIVE_DEFAULT_PARTITION__");
+
+        assert_eq!(default_partition_dir(&keys).unwrap(), expected);
+
+        let options = PartitionPathOptions {
+            template: Some(
+                PartitionPathTemplate::new("city={city}/null={null}/{part_idx}.{ext}").unwrap(),
+            ),
+            overwrite_mode: PartitionOverwriteMode::default(),
+        };
+        assert_eq!(options.partition_dir(&keys).unwrap(), expected);
+        assert_eq!(
+            PartitionPathOptions::default()
+                .partition_dir(&keys)
+                .unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_partition_path_options_validate() {
+        let key_names = ["year".into(), "month".into()];
+        let options = |template: &str| PartitionPathOptions {
+            template: Some(PartitionPathTemplate::new(template).unwrap()),
+            overwrite_mode: PartitionOverwriteMode::Dynamic,
+        };
+
+        options("y={year}/m={month}/{uuid}.{ext}")
+            .validate(&key_names, false)
+            .unwrap();
+        assert!(
+            options("y={year}/m={month}/{uuid}.{ext}")
+                .validate(&key_names, true)
+                .is_err()
+        );
+        assert!(
+            options("y={year}/{month}-{uuid}.{ext}")
+                .validate(&key_names, false)
+                .is_err()
+        );
+        assert!(
+            options("y={year}/m={month}/{file_idx}/{uuid}.{ext}")
+                .validate(&key_names, false)
+                .is_err()
+        );
+        assert!(
+            PartitionPathOptions {
+                template: None,
+                overwrite_mode: PartitionOverwriteMode::Dynamic,
+            }
+            .validate(&[], false)
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_partition_staging_local() {
+        let base = tempfile::tempdir().unwrap();
+        let base = base.path();
+        let write = |path: &Path, contents: &str| {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, contents).unwrap();
+        };
+        let read_dir = |path: &Path| {
+            let mut names = std::fs::read_dir(path)
+                .unwrap()
+                .map(|e| e.unwrap().file_name().into_string().unwrap())
+                .collect::<Vec<_>>();
+            names.sort();
+            names
+        };
+
+        write(&base.join("a=1/old.parquet"), "old");
+        write(&base.join("a=2/old.parquet"), "old");
+
+        // Existing partitions are kept if the staging is not committed.
+        let staging = PartitionStaging::new(base, None);
+        write(
+            &staging.stage("a=1".into(), Path::new("a=1/new.parquet")),
+            "new",
+        );
+        drop(staging);
+        assert_eq!(read_dir(base), ["a=1", "a=2"]);
+        assert_eq!(read_dir(&base.join("a=1")), ["old.parquet"]);
+
+        let staging = PartitionStaging::new(base, None);
+        for (dir, file) in [("a=1", "a=1/new.parquet"), ("a=3", "a=3/new.parquet")] {
+            write(&staging.stage(dir.into(), Path::new(file)), "new");
+        }
+        assert_eq!(read_dir(&base.join("a=1")), ["old.parquet"]);
+        staging.commit_local().unwrap();
+        drop(staging);
+
+        assert_eq!(read_dir(base), ["a=1", "a=2", "a=3"]);
+        assert_eq!(read_dir(&base.join("a=1")), ["new.parquet"]);
+        assert_eq!(read_dir(&base.join("a=2")), ["old.parquet"]);
+        assert_eq!(read_dir(&base.join("a=3")), ["new.parquet"]);
+    }
+}
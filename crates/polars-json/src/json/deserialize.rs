@@ -432,6 +432,15 @@ pub(crate) fn _deserialize<'a, A: Borrow<BorrowedValue<'a>>>(
     }
 }
 
+/// Deserialize already parsed JSON values, one per row, into an array of `dtype`.
+pub fn deserialize_rows<'a, A: Borrow<BorrowedValue<'a>>>(
+    rows: &[A],
+    dtype: ArrowDataType,
+    allow_extra_fields_in_struct: bool,
+) -> PolarsResult<Box<dyn Array>> {
+    _deserialize(rows, dtype, allow_extra_fields_in_struct)
+}
+
 pub fn deserialize(
     json: &BorrowedValue,
     dtype: ArrowDataType,
@@ -132,4 +132,131 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(all(feature = "diagonal_concat", feature = "dtype-struct"))]
+    fn test_diag_concat_lf_relaxed_nested() -> PolarsResult<()> {
+        let a = df![
+            "a" => [1i32, 2],
+        ]?
+        .lazy()
+        .select([as_struct(vec![col("a")]).alias("s")]);
+
+        let b = df![
+            "b" => ["x"],
+            "a" => [3i64],
+            "c" => [1]
+        ]?
+        .lazy()
+        .select([as_struct(vec![col("b"), col("a")]).alias("s"), col("c")]);
+
+        let out = concat_lf_diagonal(
+            &[a, b],
+            UnionArgs {
+                to_supertypes: true,
+                ..Default::default()
+            },
+        )?
+        .collect()?;
+
+        let s = out.column("s")?.struct_()?.clone();
+        let fields = s.fields_as_series();
+        assert_eq!(fields.len(), 2);
+        let a = s.field_by_name("a")?;
+        assert_eq!(a.dtype(), &DataType::Int64);
+        assert_eq!(
+            a.i64()?.into_iter().collect::<Vec<_>>(),
+            [Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(s.field_by_name("b")?.null_count(), 2);
+        assert_eq!(out.column("c")?.null_count(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-struct")]
+    fn test_concat_lf_nested_struct_fields() -> PolarsResult<()> {
+        let base = df![
+            "a" => [1i32, 2],
+            "b" => ["x", "y"],
+        ]?
+        .lazy();
+        let other = df![
+            "a" => [3i64],
+            "b" => ["z"],
+            "c" => [1.5],
+        ]?
+        .lazy();
+        let to_struct =
+            |lf: LazyFrame, fields: Vec<Expr>| lf.select([as_struct(fields).alias("s")]);
+
+        // Reordered fields are matched by name.
+        let reordered = to_struct(
+            other.clone(),
+            vec![col("b"), col("a").cast(DataType::Int32)],
+        );
+        let out = concat(
+            [to_struct(base.clone(), vec![col("a"), col("b")]), reordered],
+            UnionArgs::default(),
+        )?
+        .collect()?;
+        let s = out.column("s")?.struct_()?.clone();
+        assert_eq!(
+            Vec::from(s.field_by_name("a")?.i32()?),
+            [Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            Vec::from(s.field_by_name("b")?.str()?),
+            [Some("x"), Some("y"), Some("z")]
+        );
+
+        // Added and widened fields need a relaxed concat.
+        let added = || to_struct(other.clone(), vec![col("b"), col("a"), col("c")]);
+        let inputs = [to_struct(base.clone(), vec![col("a"), col("b")]), added()];
+        assert!(
+            concat(inputs.clone(), UnionArgs::default())?
+                .collect()
+                .is_err()
+        );
+
+        let relaxed = UnionArgs {
+            to_supertypes: true,
+            ..Default::default()
+        };
+        let out = concat(inputs, relaxed)?.collect()?;
+        let expected = DataType::Struct(vec![
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+            Field::new("c".into(), DataType::Float64),
+        ]);
+        assert_eq!(out.column("s")?.dtype(), &expected);
+        let s = out.column("s")?.struct_()?.clone();
+        assert_eq!(
+            Vec::from(s.field_by_name("c")?.f64()?),
+            [None, None, Some(1.5)]
+        );
+
+        // The same holds for structs nested in lists.
+        let list = |lf: LazyFrame| lf.select([col("s").implode().alias("l")]);
+        let out = concat(
+            [
+                list(to_struct(base, vec![col("a"), col("b")])),
+                list(added()),
+            ],
+            relaxed,
+        )?
+        .collect()?;
+        assert_eq!(
+            out.column("l")?.dtype(),
+            &DataType::List(Box::new(expected))
+        );
+        let s = out.column("l")?.explode(false)?;
+        let s = s.struct_()?;
+        assert_eq!(
+            Vec::from(s.field_by_name("a")?.i64()?),
+            [Some(1), Some(2), Some(3)]
+        );
+        Ok(())
+    }
 }
@@ -1021,6 +1021,7 @@ impl LazyFrame {
         self,
         base_path: Arc<PathBuf>,
         file_path_cb: Option<PartitionTargetCallback>,
+        path_options: PartitionPathOptions,
         variant: PartitionVariant,
         options: ParquetWriteOptions,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
@@ -1037,6 +1038,7 @@ impl LazyFrame {
             cloud_options,
             per_partition_sort_by,
             finish_callback,
+            path_options,
         }))
     }
 
@@ -1049,6 +1051,7 @@ impl LazyFrame {
         self,
         base_path: Arc<PathBuf>,
         file_path_cb: Option<PartitionTargetCallback>,
+        path_options: PartitionPathOptions,
         variant: PartitionVariant,
         options: IpcWriterOptions,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
@@ -1065,6 +1068,7 @@ impl LazyFrame {
             cloud_options,
             per_partition_sort_by,
             finish_callback,
+            path_options,
         }))
     }
 
@@ -1077,6 +1081,7 @@ impl LazyFrame {
         self,
         base_path: Arc<PathBuf>,
         file_path_cb: Option<PartitionTargetCallback>,
+        path_options: PartitionPathOptions,
         variant: PartitionVariant,
         options: CsvWriterOptions,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
@@ -1093,6 +1098,7 @@ impl LazyFrame {
             cloud_options,
             per_partition_sort_by,
             finish_callback,
+            path_options,
         }))
     }
 
@@ -1105,6 +1111,7 @@ impl LazyFrame {
         self,
         base_path: Arc<PathBuf>,
         file_path_cb: Option<PartitionTargetCallback>,
+        path_options: PartitionPathOptions,
         variant: PartitionVariant,
         options: JsonWriterOptions,
         cloud_options: Option<polars_io::cloud::CloudOptions>,
@@ -1121,6 +1128,7 @@ impl LazyFrame {
             cloud_options,
             per_partition_sort_by,
             finish_callback,
+            path_options,
         }))
     }
 
@@ -2029,6 +2037,63 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Compute the correlation between every pair of `columns` in a single pass.
+    ///
+    /// See [`CorrMatrixLayout`] for the shape of the result. To compute the correlations per
+    /// group, use [`corr_matrix`] in an aggregation instead.
+    #[cfg(feature = "cov")]
+    pub fn corr_matrix(
+        self,
+        columns: &[PlSmallStr],
+        method: CorrelationMethod,
+        layout: CorrMatrixLayout,
+    ) -> PolarsResult<LazyFrame> {
+        let n = columns.len();
+        polars_ensure!(
+            n >= 2,
+            InvalidOperation: "`corr_matrix` needs at least two columns, got {}", n
+        );
+        // Both halves of the matrix use the same expression, so it is only computed once.
+        let corr_of = |i: usize, j: usize| {
+            let (a, b) = (&columns[i.min(j)], &columns[i.max(j)]);
+            corr(col(a.clone()), col(b.clone()), method).cast(DataType::Float64)
+        };
+        let names = |idxs: &[usize]| {
+            let names = idxs
+                .iter()
+                .map(|i| columns[*i].as_str())
+                .collect::<Vec<_>>();
+            lit(Series::new(PlSmallStr::EMPTY, names))
+        };
+
+        let exprs = match layout {
+            CorrMatrixLayout::Square => {
+                let mut exprs = Vec::with_capacity(n + 1);
+                exprs.push(names(&(0..n).collect::<Vec<_>>()).alias("column"));
+                for (j, name) in columns.iter().enumerate() {
+                    let values = (0..n).map(|i| corr_of(i, j)).collect::<Vec<_>>();
+                    exprs.push(concat_list(values)?.explode().alias(name.clone()));
+                }
+                exprs
+            },
+            CorrMatrixLayout::Tidy => {
+                let (left, right): (Vec<_>, Vec<_>) =
+                    (0..n).flat_map(|i| (i + 1..n).map(move |j| (i, j))).unzip();
+                let values = left
+                    .iter()
+                    .zip(&right)
+                    .map(|(i, j)| corr_of(*i, *j))
+                    .collect::<Vec<_>>();
+                vec![
+                    names(&left).alias("column_1"),
+                    names(&right).alias("column_2"),
+                    concat_list(values)?.explode().alias("correlation"),
+                ]
+            },
+        };
+        Ok(self.select(exprs))
+    }
+
     /// Limit the DataFrame to the first `n` rows.
     ///
     /// Note if you don't want the rows to be scanned, use [`fetch`](LazyFrame::fetch).
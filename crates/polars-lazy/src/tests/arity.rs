@@ -36,6 +36,87 @@ fn test_pearson_corr() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "cov")]
+fn test_kendall_tau_corr() -> PolarsResult<()> {
+    let df = df! {
+        "a" => [Some(1), Some(2), Some(2), Some(3), Some(4), None],
+        "b" => [1, 3, 2, 3, 1, 7]
+    }?;
+
+    let out = df
+        .lazy()
+        .select([kendall_tau_corr(col("a"), col("b"))])
+        .collect()?;
+    let tau = out.column("a")?.f64()?.get(0).unwrap();
+    assert!((tau - 0.117851).abs() < 0.000001);
+
+    // Like the other correlations, fewer than two rows give a null.
+    let df = df! {
+        "a" => [Some(1), None],
+        "b" => [1, 2]
+    }?;
+    let out = df
+        .lazy()
+        .select([kendall_tau_corr(col("a"), col("b"))])
+        .collect()?;
+    assert_eq!(out.column("a")?.f64()?.get(0), None);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "cov", feature = "dtype-struct"))]
+fn test_corr_matrix() -> PolarsResult<()> {
+    let df = df! {
+        "uid" => [0, 0, 0, 1, 1, 1],
+        "x" => [1, 2, 3, 4, 5, 6],
+        "y" => [3, 1, 2, 5, 4, 6],
+        "z" => [6, 5, 4, 3, 2, 1]
+    }?;
+    let columns = ["x".into(), "y".into(), "z".into()];
+    let method = CorrelationMethod::KendallTau;
+
+    let out = df
+        .clone()
+        .lazy()
+        .corr_matrix(&columns, method, CorrMatrixLayout::Square)?
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["column", "x", "y", "z"]);
+    let x = out.column("x")?.f64()?;
+    let y = out.column("y")?.f64()?;
+    assert_eq!(y.get(0), Some(0.6));
+    assert_eq!(x.get(1), Some(0.6));
+    assert_eq!(y.get(1), Some(1.0));
+    assert_eq!(out.column("z")?.f64()?.get(0), Some(-1.0));
+
+    let out = df
+        .clone()
+        .lazy()
+        .corr_matrix(&columns, method, CorrMatrixLayout::Tidy)?
+        .collect()?;
+    let expected = df! {
+        "column_1" => ["x", "x", "y"],
+        "column_2" => ["y", "z", "z"],
+        "correlation" => [0.6, -1.0, -0.6]
+    }?;
+    assert!(out.equals(&expected));
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("uid")])
+        .agg([corr_matrix(&columns, method)?])
+        .explode(["corr_matrix"])
+        .unnest(["corr_matrix"])
+        .collect()?;
+    assert_eq!(out.height(), 6);
+    let corr = out.column("correlation")?.f64()?;
+    assert_eq!(
+        corr.into_no_null_iter().collect::<Vec<_>>(),
+        &[-1.0 / 3.0, -1.0, 1.0 / 3.0, 1.0 / 3.0, -1.0, -1.0 / 3.0]
+    );
+    Ok(())
+}
+
 // TODO! fix this we must get a token that prevents resetting the string cache until the plan has
 // finished running. We cannot store a mutexguard in the executionstate because they don't implement
 // send.
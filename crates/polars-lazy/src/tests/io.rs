@@ -795,3 +795,176 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn scan_parquet_nested_schema_evolution() -> PolarsResult<()> {
+    fn write(df: &mut DataFrame) -> PolarsResult<polars_utils::mmap::MemSlice> {
+        let mut buf = vec![];
+        ParquetWriter::new(&mut buf).finish(df)?;
+        Ok(polars_utils::mmap::MemSlice::from_vec(buf))
+    }
+
+    // The second file reorders the struct fields, widens `a` and adds `b`.
+    let mut df1 = df!["a" => [1i32, 2]]?
+        .lazy()
+        .select([as_struct(vec![col("a")]).alias("s")])
+        .collect()?;
+    let mut df2 = df!["b" => ["x"], "a" => [3i64]]?
+        .lazy()
+        .select([as_struct(vec![col("b"), col("a")]).alias("s")])
+        .collect()?;
+
+    let schema = Schema::from_iter([Field::new(
+        "s".into(),
+        DataType::Struct(vec![
+            Field::new("a".into(), DataType::Int64),
+            Field::new("b".into(), DataType::String),
+        ]),
+    )]);
+
+    let sources = ScanSources::Buffers([write(&mut df1)?, write(&mut df2)?].into());
+    let scan = |cast_columns_policy| -> PolarsResult<DataFrame> {
+        let lf: LazyFrame = DslBuilder::scan_parquet(
+            sources.clone(),
+            ParquetOptions {
+                schema: Some(Arc::new(schema.clone())),
+                ..Default::default()
+            },
+            UnifiedScanArgs {
+                hive_options: polars_io::HiveOptions::new_disabled(),
+                cast_columns_policy,
+                ..Default::default()
+            },
+        )?
+        .build()
+        .into();
+        lf.collect()
+    };
+
+    assert!(scan(CastColumnsPolicy::ERROR_ON_MISMATCH).is_err());
+
+    let out = scan(CastColumnsPolicy {
+        integer_upcast: true,
+        missing_struct_fields: MissingColumnsPolicy::Insert,
+        ..CastColumnsPolicy::ERROR_ON_MISMATCH
+    })?;
+    assert_eq!(out.schema().as_ref(), &schema);
+
+    let s = out.column("s")?.struct_()?.clone();
+    assert_eq!(
+        s.field_by_name("a")?.i64()?.into_iter().collect::<Vec<_>>(),
+        [Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        s.field_by_name("b")?.str()?.into_iter().collect::<Vec<_>>(),
+        [None, None, Some("x")]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn scan_parquet_list_of_struct_schema_evolution() -> PolarsResult<()> {
+    fn write(df: &mut DataFrame) -> PolarsResult<polars_utils::mmap::MemSlice> {
+        let mut buf = vec![];
+        ParquetWriter::new(&mut buf).finish(df)?;
+        Ok(polars_utils::mmap::MemSlice::from_vec(buf))
+    }
+
+    // The first file determines the schema. The second file reorders the struct fields, holds a
+    // narrower `a`, misses `b` and adds `c`.
+    let mut df1 = df!["a" => [1i64, 2], "b" => ["x", "y"]]?
+        .lazy()
+        .select([as_struct(vec![col("a"), col("b")]).implode().alias("l")])
+        .collect()?;
+    let mut df2 = df!["c" => [1.5], "a" => [3i32]]?
+        .lazy()
+        .select([as_struct(vec![col("c"), col("a")]).implode().alias("l")])
+        .collect()?;
+
+    let sources = ScanSources::Buffers([write(&mut df1)?, write(&mut df2)?].into());
+    let scan = |cast_columns_policy| -> PolarsResult<DataFrame> {
+        let lf: LazyFrame = DslBuilder::scan_parquet(
+            sources.clone(),
+            ParquetOptions::default(),
+            UnifiedScanArgs {
+                hive_options: polars_io::HiveOptions::new_disabled(),
+                cast_columns_policy,
+                ..Default::default()
+            },
+        )?
+        .build()
+        .into();
+        lf.collect()
+    };
+
+    assert!(scan(CastColumnsPolicy::ERROR_ON_MISMATCH).is_err());
+
+    let out = scan(CastColumnsPolicy {
+        integer_upcast: true,
+        missing_struct_fields: MissingColumnsPolicy::Insert,
+        extra_struct_fields: ExtraColumnsPolicy::Ignore,
+        ..CastColumnsPolicy::ERROR_ON_MISMATCH
+    })?;
+    assert_eq!(out.schema().as_ref(), df1.schema().as_ref());
+
+    let s = out.column("l")?.explode(false)?;
+    let s = s.struct_()?;
+    assert_eq!(
+        s.field_by_name("a")?.i64()?.into_iter().collect::<Vec<_>>(),
+        [Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        s.field_by_name("b")?.str()?.into_iter().collect::<Vec<_>>(),
+        [Some("x"), Some("y"), None]
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-struct")]
+fn match_to_schema_nested_struct_fields() -> PolarsResult<()> {
+    let target = DataType::Struct(vec![
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]);
+    let schema = Arc::new(Schema::from_iter([Field::new("s".into(), target)]));
+    let per_column = |missing_struct_fields, integer_cast| -> Arc<[MatchToSchemaPerColumn]> {
+        Arc::new([MatchToSchemaPerColumn {
+            missing_columns: MissingColumnsPolicyOrExpr::Raise,
+            missing_struct_fields,
+            extra_struct_fields: ExtraColumnsPolicy::Raise,
+            integer_cast,
+            float_cast: UpcastOrForbid::Forbid,
+        }])
+    };
+
+    // `b` is missing and `a` is narrower.
+    let lf = df!["a" => [1i32, 2]]?
+        .lazy()
+        .select([as_struct(vec![col("a")]).alias("s")]);
+
+    let strict = per_column(MissingColumnsPolicy::Raise, UpcastOrForbid::Upcast);
+    let out = lf
+        .clone()
+        .match_to_schema(schema.clone(), strict, ExtraColumnsPolicy::Raise)
+        .collect();
+    assert!(out.is_err());
+
+    let relaxed = per_column(MissingColumnsPolicy::Insert, UpcastOrForbid::Upcast);
+    let out = lf
+        .match_to_schema(schema.clone(), relaxed, ExtraColumnsPolicy::Raise)
+        .collect()?;
+    assert_eq!(out.schema().as_ref(), schema.as_ref());
+    let s = out.column("s")?.struct_()?.clone();
+    assert_eq!(
+        s.field_by_name("a")?.i64()?.into_iter().collect::<Vec<_>>(),
+        [Some(1), Some(2)]
+    );
+    assert_eq!(s.field_by_name("b")?.null_count(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use md5::{Digest, Md5};
+use sha1::Sha1;
+use sha2::Sha256;
+use xxhash_rust::xxh3::{xxh3_64_with_seed, xxh3_128_with_seed};
+
+pub(super) fn md5(value: &[u8]) -> Vec<u8> {
+    Md5::digest(value).to_vec()
+}
+
+pub(super) fn sha1(value: &[u8]) -> Vec<u8> {
+    Sha1::digest(value).to_vec()
+}
+
+pub(super) fn sha256(value: &[u8]) -> Vec<u8> {
+    Sha256::digest(value).to_vec()
+}
+
+pub(super) fn xxh3_64(value: &[u8], seed: u64) -> u64 {
+    xxh3_64_with_seed(value, seed)
+}
+
+/// The 128-bit hash in its canonical, big-endian byte order.
+pub(super) fn xxh3_128(value: &[u8], seed: u64) -> Vec<u8> {
+    xxh3_128_with_seed(value, seed).to_be_bytes().to_vec()
+}
+
+fn murmur3_mix_k(k: u32) -> u32 {
+    k.wrapping_mul(0xcc9e_2d51)
+        .rotate_left(15)
+        .wrapping_mul(0x1b87_3593)
+}
+
+fn murmur3_mix_h(h: u32, k: u32) -> u32 {
+    (h ^ murmur3_mix_k(k))
+        .rotate_left(13)
+        .wrapping_mul(5)
+        .wrapping_add(0xe654_6b64)
+}
+
+/// MurmurHash3 x86 32-bit, the hash behind the Iceberg bucket transform.
+///
+/// If `spark`, the trailing bytes are hashed like Spark's `hash` does: every byte is mixed in
+/// on its own as a sign-extended integer, instead of all together as one block.
+pub(super) fn murmur3_32(value: &[u8], seed: u32, spark: bool) -> u32 {
+    let mut h = seed;
+    let mut blocks = value.chunks_exact(4);
+    for block in &mut blocks {
+        h = murmur3_mix_h(h, u32::from_le_bytes(block.try_into().unwrap()));
+    }
+
+    let tail = blocks.remainder();
+    if spark {
+        for &byte in tail {
+            h = murmur3_mix_h(h, byte as i8 as u32);
+        }
+    } else if !tail.is_empty() {
+        let k = tail
+            .iter()
+            .rev()
+            .fold(0u32, |k, &byte| (k << 8) | byte as u32);
+        h ^= murmur3_mix_k(k);
+    }
+
+    h ^= value.len() as u32;
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^ (h >> 16)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_digests() {
+        assert_eq!(hex::encode(md5(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(hex::encode(md5(b"abc")), "900150983cd24fb0d6963f7d28e17f72");
+        assert_eq!(
+            hex::encode(sha1(b"abc")),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            hex::encode(sha256(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(xxh3_64(b"", 0), 0x2d06_8005_38d3_94c2);
+    }
+
+    #[test]
+    fn test_murmur3_32() {
+        // Test vectors from the Iceberg specification.
+        assert_eq!(murmur3_32(b"iceberg", 0, false) as i32, 1210000089);
+        assert_eq!(
+            murmur3_32(&34i64.to_le_bytes(), 0, false) as i32,
+            2017239379
+        );
+        assert_eq!(murmur3_32(&[0, 1, 2, 3], 0, false) as i32, -188683207);
+        assert_eq!(murmur3_32(b"", 0, false), 0);
+        assert_eq!(murmur3_32(b"", 1, false), 0x514e_28b7);
+
+        // Spark: `SELECT hash('Spark')` and `SELECT hash('')`.
+        assert_eq!(murmur3_32(b"Spark", 42, true) as i32, 228093765);
+        assert_eq!(murmur3_32(b"", 42, true) as i32, 142593372);
+        // Both variants only differ in the trailing bytes.
+        assert_eq!(
+            murmur3_32(b"abcd", 42, true),
+            murmur3_32(b"abcd", 42, false)
+        );
+        assert_ne!(murmur3_32(b"abc", 42, true), murmur3_32(b"abc", 42, false));
+    }
+}
@@ -1,4 +1,6 @@
 mod cast_binary_to_numerical;
+#[cfg(feature = "stable_hash")]
+mod hash;
 mod namespace;
 
 pub use namespace::*;
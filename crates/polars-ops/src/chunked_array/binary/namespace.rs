@@ -183,6 +183,51 @@ pub trait BinaryNameSpaceImpl: AsBinary {
             ),
         }
     }
+
+    /// Compute the MD5 digest of every value.
+    #[cfg(feature = "stable_hash")]
+    fn md5(&self) -> BinaryChunked {
+        let ca = self.as_binary();
+        ca.apply_values(|s| hash::md5(s).into())
+    }
+
+    /// Compute the SHA-1 digest of every value.
+    #[cfg(feature = "stable_hash")]
+    fn sha1(&self) -> BinaryChunked {
+        let ca = self.as_binary();
+        ca.apply_values(|s| hash::sha1(s).into())
+    }
+
+    /// Compute the SHA-256 digest of every value.
+    #[cfg(feature = "stable_hash")]
+    fn sha256(&self) -> BinaryChunked {
+        let ca = self.as_binary();
+        ca.apply_values(|s| hash::sha256(s).into())
+    }
+
+    /// Compute the 64-bit XXH3 hash of every value.
+    #[cfg(feature = "stable_hash")]
+    fn xxh3_64(&self, seed: u64) -> UInt64Chunked {
+        let ca = self.as_binary();
+        unary_elementwise_values(ca, |s| hash::xxh3_64(s, seed))
+    }
+
+    /// Compute the 128-bit XXH3 hash of every value as 16 big-endian bytes.
+    #[cfg(feature = "stable_hash")]
+    fn xxh3_128(&self, seed: u64) -> BinaryChunked {
+        let ca = self.as_binary();
+        ca.apply_values(|s| hash::xxh3_128(s, seed).into())
+    }
+
+    /// Compute the 32-bit MurmurHash3 (x86 variant) of every value.
+    ///
+    /// With a seed of 0 this matches the hash of the Iceberg bucket transform. With `spark` and
+    /// a seed of 42 it matches Spark's `hash` of a single string or binary column.
+    #[cfg(feature = "stable_hash")]
+    fn murmur3_32(&self, seed: u32, spark: bool) -> Int32Chunked {
+        let ca = self.as_binary();
+        unary_elementwise_values(ca, |s| hash::murmur3_32(s, seed, spark) as i32)
+    }
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
@@ -2,6 +2,7 @@ use num_traits::AsPrimitive;
 use polars_compute::moment::{CovState, PearsonState};
 use polars_core::prelude::*;
 use polars_core::utils::align_chunks_binary;
+use polars_utils::total_ord::{TotalEq, TotalOrd};
 
 /// Compute the covariance between two columns.
 pub fn cov<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>, ddof: u8) -> Option<f64>
@@ -32,3 +33,76 @@ where
     }
     Some(out.finalize())
 }
+
+/// Compute Kendall's tau-b rank correlation between two columns.
+///
+/// Rows where either value is missing are excluded. Returns `None` if fewer than two rows
+/// remain. The discordant pairs are counted with a merge sort, so this runs in `O(n log n)`.
+pub fn kendall_tau_b<T>(a: &ChunkedArray<T>, b: &ChunkedArray<T>) -> Option<f64>
+where
+    T: PolarsNumericType,
+    T::Native: TotalOrd,
+{
+    let mut pairs = a
+        .iter()
+        .zip(b.iter())
+        .filter_map(|(a, b)| Some((a?, b?)))
+        .collect::<Vec<_>>();
+    if pairs.len() < 2 {
+        return None;
+    }
+    pairs.sort_unstable_by(|l, r| l.0.tot_cmp(&r.0).then_with(|| l.1.tot_cmp(&r.1)));
+
+    let n = pairs.len() as u64;
+    let n_pairs = n * n.saturating_sub(1) / 2;
+    let x_ties = tied_pairs(&pairs, |l, r| l.0.tot_eq(&r.0));
+    let xy_ties = tied_pairs(&pairs, |l, r| l.0.tot_eq(&r.0) && l.1.tot_eq(&r.1));
+
+    // The pairs are sorted by x (and y within ties in x), so every inversion left in y is a
+    // discordant pair.
+    let mut ys = pairs.into_iter().map(|(_, y)| y).collect::<Vec<_>>();
+    let mut scratch = ys.clone();
+    let discordant = merge_sort_count_inversions(&mut ys, &mut scratch);
+    let y_ties = tied_pairs(&ys, |l, r| l.tot_eq(r));
+
+    let numerator =
+        n_pairs as f64 - x_ties as f64 - y_ties as f64 + xy_ties as f64 - 2.0 * discordant as f64;
+    let denominator = ((n_pairs - x_ties) as f64 * (n_pairs - y_ties) as f64).sqrt();
+    Some(numerator / denominator)
+}
+
+/// Count the pairs of equal values in sorted `values`.
+fn tied_pairs<T>(values: &[T], eq: impl FnMut(&T, &T) -> bool) -> u64 {
+    values
+        .chunk_by(eq)
+        .map(|group| {
+            let len = group.len() as u64;
+            len * (len - 1) / 2
+        })
+        .sum()
+}
+
+/// Sort `values`, returning the number of pairs that were out of order.
+fn merge_sort_count_inversions<T: Copy + TotalOrd>(values: &mut [T], scratch: &mut [T]) -> u64 {
+    let len = values.len();
+    if len <= 1 {
+        return 0;
+    }
+    let mid = len / 2;
+    let mut inversions = merge_sort_count_inversions(&mut values[..mid], &mut scratch[..mid])
+        + merge_sort_count_inversions(&mut values[mid..], &mut scratch[mid..]);
+
+    let (mut i, mut j) = (0, mid);
+    for out in scratch[..len].iter_mut() {
+        if j == len || (i < mid && values[i].tot_le(&values[j])) {
+            *out = values[i];
+            i += 1;
+        } else {
+            *out = values[j];
+            inversions += (mid - i) as u64;
+            j += 1;
+        }
+    }
+    values.copy_from_slice(&scratch[..len]);
+    inversions
+}
@@ -0,0 +1,15 @@
+mod namespace;
+mod select;
+
+pub use namespace::*;
+use polars_core::prelude::*;
+
+pub trait AsJson {
+    fn as_json(&self) -> &JsonChunked;
+}
+
+impl AsJson for JsonChunked {
+    fn as_json(&self) -> &JsonChunked {
+        self
+    }
+}
@@ -0,0 +1,227 @@
+use jsonpath_lib::PathCompiled;
+use polars_core::prelude::*;
+use serde_json::Value;
+use simd_json::{BorrowedValue, StaticNode};
+
+use super::AsJson;
+use super::select::{PathSegment, parse_simple_path, select};
+
+/// Convert a parsed document (or a part of it) without copying its strings.
+fn to_borrowed_value(value: &Value) -> BorrowedValue<'_> {
+    match value {
+        Value::Null => BorrowedValue::Static(StaticNode::Null),
+        Value::Bool(b) => BorrowedValue::Static(StaticNode::Bool(*b)),
+        Value::Number(n) => BorrowedValue::Static(match (n.as_i64(), n.as_u64()) {
+            (Some(n), _) => StaticNode::I64(n),
+            (_, Some(n)) => StaticNode::U64(n),
+            _ => StaticNode::F64(n.as_f64().unwrap_or(f64::NAN)),
+        }),
+        Value::String(s) => BorrowedValue::String(s.as_str().into()),
+        Value::Array(values) => values.iter().map(to_borrowed_value).collect(),
+        Value::Object(fields) => fields
+            .iter()
+            .map(|(k, v)| (k.as_str(), to_borrowed_value(v)))
+            .collect(),
+    }
+}
+
+fn invalid_json(doc: &str) -> PolarsError {
+    polars_err!(ComputeError: "invalid JSON document: '{}'", doc)
+}
+
+/// Decode the selected value of every row as `dtype`.
+fn decode_rows(rows: &[BorrowedValue], dtype: &DataType) -> PolarsResult<Series> {
+    let array = polars_json::json::deserialize::deserialize_rows(
+        rows,
+        dtype.to_arrow(CompatLevel::newest()),
+        true,
+    )?;
+    Series::try_from((PlSmallStr::EMPTY, array))
+}
+
+/// [`JsonNameSpace::json_get`] for paths that only access object members and array elements.
+/// Only the text of the selected values is parsed.
+fn get_simple_path(
+    ca: &JsonChunked,
+    path: &[PathSegment],
+    dtype: &DataType,
+) -> PolarsResult<Series> {
+    let selected = ca
+        .physical()
+        .iter()
+        .map(|opt_doc| opt_doc.map_or(Ok(None), |doc| select(doc, path)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    match dtype {
+        DataType::Json => {
+            let ca: StringChunked = selected.iter().map(|v| v.map(compact_json)).collect();
+            Ok(ca.into_json_unchecked().into_series())
+        },
+        dt => {
+            let values = selected
+                .iter()
+                .map(|v| {
+                    v.map(|v| serde_json::from_str::<Value>(v).map_err(|_| invalid_json(v)))
+                        .transpose()
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let rows = values
+                .iter()
+                .map(|v| {
+                    v.as_ref()
+                        .map_or(BorrowedValue::Static(StaticNode::Null), to_borrowed_value)
+                })
+                .collect::<Vec<_>>();
+            decode_rows(&rows, dt)
+        },
+    }
+}
+
+/// [`JsonNameSpace::json_get`] for any JSONPath. Every document is parsed once and the selected
+/// values are decoded from the parsed document directly.
+fn get_jsonpath(ca: &JsonChunked, path: &str, dtype: &DataType) -> PolarsResult<Series> {
+    let pat = PathCompiled::compile(path)
+        .map_err(|e| polars_err!(ComputeError: "error compiling JSONpath expression: {}", e))?;
+
+    let documents = ca
+        .physical()
+        .iter()
+        .map(|opt_s| {
+            opt_s
+                .map(|s| serde_json::from_str::<Value>(s).map_err(|_| invalid_json(s)))
+                .transpose()
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let selected = documents
+        .iter()
+        .map(|doc| {
+            let Some(doc) = doc else {
+                return Ok(None);
+            };
+            let matches = pat
+                .select(doc)
+                .map_err(|e| polars_err!(ComputeError: "error evaluating JSONpath: {}", e))?;
+            Ok((!matches.is_empty()).then_some(matches))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    match dtype {
+        DataType::Json => {
+            let ca: StringChunked = selected
+                .iter()
+                .map(|matches| match matches.as_deref()? {
+                    [value] => serde_json::to_string(value).ok(),
+                    values => serde_json::to_string(values).ok(),
+                })
+                .collect();
+            Ok(ca.into_json_unchecked().into_series())
+        },
+        dt => {
+            let rows = selected
+                .iter()
+                .map(|matches| match matches.as_deref() {
+                    None => BorrowedValue::Static(StaticNode::Null),
+                    Some([value]) => to_borrowed_value(value),
+                    Some(values) => values.iter().map(|v| to_borrowed_value(v)).collect(),
+                })
+                .collect::<Vec<_>>();
+            decode_rows(&rows, dt)
+        },
+    }
+}
+
+pub trait JsonNameSpace: AsJson {
+    /// Extract the value at the JSONPath `path` of every document as `dtype`. Only the selected
+    /// values are decoded, rows where nothing matches become null. Raises if a document is not
+    /// valid JSON.
+    ///
+    /// Paths that only access object members and array elements, such as `$.a.b[0]`, are
+    /// resolved on the stored text without parsing the rest of the documents.
+    ///
+    /// Selecting [`DataType::Json`] keeps the values as JSON documents.
+    fn json_get(&self, path: &str, dtype: &DataType) -> PolarsResult<Series> {
+        let ca = self.as_json();
+        let mut out = match parse_simple_path(path) {
+            Some(segments) => get_simple_path(ca, &segments, dtype)?,
+            None => get_jsonpath(ca, path, dtype)?,
+        };
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    /// Remove all whitespace outside of strings.
+    fn json_compact(&self) -> JsonChunked {
+        self.as_json().compact()
+    }
+
+    /// Format the documents with one object member or array element per line.
+    fn json_pretty(&self) -> JsonChunked {
+        self.as_json().pretty()
+    }
+}
+
+impl JsonNameSpace for JsonChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_json_get() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a".into(),
+            [
+                Some(r#"{"a": {"b": 1, "c": "x"}}"#),
+                Some(r#"{"a": {"b": 2}}"#),
+                Some(r#"{"d": 3}"#),
+                None,
+            ],
+        )
+        .to_json(true)?;
+
+        let b = ca.json_get("$.a.b", &DataType::Int64)?;
+        assert_eq!(b.name().as_str(), "a");
+        assert_eq!(Vec::from(b.i64()?), &[Some(1), Some(2), None, None]);
+
+        let c = ca.json_get("$.a.c", &DataType::String)?;
+        assert_eq!(Vec::from(c.str()?), &[Some("x"), None, None, None]);
+
+        let a = ca.json_get("$.a", &DataType::Json)?;
+        assert_eq!(a.dtype(), &DataType::Json);
+        assert_eq!(a.get(1)?, AnyValue::String(r#"{"b":2}"#));
+
+        // Several matches are combined into an array.
+        let all = ca.json_get("$.a.*", &DataType::Json)?;
+        assert_eq!(all.get(0)?, AnyValue::String(r#"[1,"x"]"#));
+        assert_eq!(all.get(1)?, AnyValue::String("2"));
+        assert_eq!(all.null_count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_get_simple_path() -> PolarsResult<()> {
+        // Documents that were not normalized, e.g. read from a file.
+        let ca = StringChunked::new(
+            "a".into(),
+            [
+                r#" { "x": "}]", "a b" : [ 1, {"c": [true, null]} ] } "#,
+                r#"{"\u0061 b": [2]}"#,
+                r#"{"a b": {"0": 3}}"#,
+            ],
+        )
+        .into_json_unchecked();
+
+        let c = ca.json_get("$['a b'][1].c", &DataType::Json)?;
+        assert_eq!(c.get(0)?, AnyValue::String("[true,null]"));
+        assert_eq!(c.null_count(), 2);
+        let c = ca.json_get("$['a b'][1].c[0]", &DataType::Boolean)?;
+        assert_eq!(Vec::from(c.bool()?), &[Some(true), None, None]);
+        let first = ca.json_get("$[\"a b\"][0]", &DataType::Int64)?;
+        assert_eq!(Vec::from(first.i64()?), &[Some(1), Some(2), None]);
+
+        let invalid = StringChunked::new("a".into(), [r#"{"a": [1 2]}"#]).into_json_unchecked();
+        assert!(invalid.json_get("$.a[1]", &DataType::Int64).is_err());
+        assert!(invalid.json_get("$.a.*", &DataType::Int64).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+//! Selection of values by JSONPaths that only access object members and array elements. These
+//! are resolved on the text of the documents: the values that are not on the path are skipped
+//! without being parsed.
+
+use polars_core::prelude::*;
+
+pub(super) enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// Parse a JSONPath like `$.a[0]['b c']` that only consists of member and element accesses.
+/// Returns `None` if the path uses any other JSONPath feature.
+pub(super) fn parse_simple_path(path: &str) -> Option<Vec<PathSegment>> {
+    let mut rest = path.strip_prefix('$')?;
+    let mut segments = vec![];
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix('.') {
+            let end = r.find(['.', '[']).unwrap_or(r.len());
+            let key = &r[..end];
+            if key.is_empty()
+                || !key
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+            {
+                return None;
+            }
+            segments.push(PathSegment::Key(key.to_string()));
+            rest = &r[end..];
+        } else {
+            let r = rest.strip_prefix('[')?;
+            let end = r.find(']')?;
+            let inner = &r[..end];
+            let segment = match inner.as_bytes().first()? {
+                quote @ (b'\'' | b'"') => {
+                    let key = inner.strip_prefix(*quote as char)?;
+                    let key = key.strip_suffix(*quote as char)?;
+                    if key.contains(['\\', '\'', '"']) {
+                        return None;
+                    }
+                    PathSegment::Key(key.to_string())
+                },
+                b'0'..=b'9' => PathSegment::Index(inner.parse().ok()?),
+                _ => return None,
+            };
+            segments.push(segment);
+            rest = &r[end + 1..];
+        }
+    }
+    Some(segments)
+}
+
+/// The text of a document is not valid JSON.
+struct InvalidJson;
+
+/// Select the value at `path` in the document `doc` and return its text, or `None` if the
+/// document does not contain it. If a key occurs more than once in an object, the first member
+/// is selected.
+pub(super) fn select<'a>(doc: &'a str, path: &[PathSegment]) -> PolarsResult<Option<&'a str>> {
+    let find = || -> Result<Option<&'a str>, InvalidJson> {
+        let bytes = doc.as_bytes();
+        let mut pos = skip_whitespace(bytes, 0);
+        for segment in path {
+            let next = match segment {
+                PathSegment::Key(key) => find_member(doc, pos, key)?,
+                PathSegment::Index(idx) => find_element(bytes, pos, *idx)?,
+            };
+            match next {
+                Some(next) => pos = next,
+                None => return Ok(None),
+            }
+        }
+        let end = skip_value(bytes, pos)?;
+        Ok(Some(&doc[pos..end]))
+    };
+    find().map_err(|_| polars_err!(ComputeError: "invalid JSON document: '{}'", doc))
+}
+
+fn skip_whitespace(bytes: &[u8], pos: usize) -> usize {
+    bytes[pos.min(bytes.len())..]
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .map_or(bytes.len(), |n| pos + n)
+}
+
+/// Return the position after the string starting at `pos`.
+fn skip_string(bytes: &[u8], pos: usize) -> Result<usize, InvalidJson> {
+    let mut i = pos + 1;
+    while let Some(b) = bytes.get(i) {
+        match b {
+            b'\\' => i += 2,
+            b'"' => return Ok(i + 1),
+            _ => i += 1,
+        }
+    }
+    Err(InvalidJson)
+}
+
+/// Return the position after the value starting at `pos`.
+fn skip_value(bytes: &[u8], pos: usize) -> Result<usize, InvalidJson> {
+    match bytes.get(pos).ok_or(InvalidJson)? {
+        b'"' => skip_string(bytes, pos),
+        b'{' | b'[' => {
+            let mut depth = 0usize;
+            let mut i = pos;
+            while let Some(b) = bytes.get(i) {
+                match b {
+                    b'"' => {
+                        i = skip_string(bytes, i)?;
+                        continue;
+                    },
+                    b'{' | b'[' => depth += 1,
+                    b'}' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Ok(i + 1);
+                        }
+                    },
+                    _ => {},
+                }
+                i += 1;
+            }
+            Err(InvalidJson)
+        },
+        // A number or a literal.
+        _ => match bytes[pos..]
+            .iter()
+            .position(|b| matches!(b, b',' | b'}' | b']') || b.is_ascii_whitespace())
+        {
+            Some(0) => Err(InvalidJson),
+            n => Ok(n.map_or(bytes.len(), |n| pos + n)),
+        },
+    }
+}
+
+/// Return the position of the value of the member `key` of the object starting at `pos`.
+fn find_member(doc: &str, pos: usize, key: &str) -> Result<Option<usize>, InvalidJson> {
+    let bytes = doc.as_bytes();
+    if bytes.get(pos) != Some(&b'{') {
+        return Ok(None);
+    }
+    let mut i = skip_whitespace(bytes, pos + 1);
+    if bytes.get(i) == Some(&b'}') {
+        return Ok(None);
+    }
+    loop {
+        if bytes.get(i) != Some(&b'"') {
+            return Err(InvalidJson);
+        }
+        let key_end = skip_string(bytes, i)?;
+        let raw_key = &doc[i..key_end];
+        let is_match = if raw_key.contains('\\') {
+            serde_json::from_str::<String>(raw_key).is_ok_and(|k| k == key)
+        } else {
+            &raw_key[1..raw_key.len() - 1] == key
+        };
+        i = skip_whitespace(bytes, key_end);
+        if bytes.get(i) != Some(&b':') {
+            return Err(InvalidJson);
+        }
+        i = skip_whitespace(bytes, i + 1);
+        if is_match {
+            return Ok(Some(i));
+        }
+        i = skip_whitespace(bytes, skip_value(bytes, i)?);
+        match bytes.get(i) {
+            Some(b',') => i = skip_whitespace(bytes, i + 1),
+            Some(b'}') => return Ok(None),
+            _ => return Err(InvalidJson),
+        }
+    }
+}
+
+/// Return the position of element `idx` of the array starting at `pos`.
+fn find_element(bytes: &[u8], pos: usize, idx: usize) -> Result<Option<usize>, InvalidJson> {
+    if bytes.get(pos) != Some(&b'[') {
+        return Ok(None);
+    }
+    let mut i = skip_whitespace(bytes, pos + 1);
+    if bytes.get(i) == Some(&b']') {
+        return Ok(None);
+    }
+    for _ in 0..idx {
+        i = skip_whitespace(bytes, skip_value(bytes, i)?);
+        match bytes.get(i) {
+            Some(b',') => i = skip_whitespace(bytes, i + 1),
+            Some(b']') => return Ok(None),
+            _ => return Err(InvalidJson),
+        }
+    }
+    Ok(Some(i))
+}
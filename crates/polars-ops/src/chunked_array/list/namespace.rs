@@ -1,7 +1,13 @@
 use std::borrow::Cow;
 use std::fmt::Write;
 
+#[cfg(feature = "list_zip")]
+use arrow::array::Array;
 use arrow::array::ValueSize;
+#[cfg(feature = "list_zip")]
+use arrow::bitmap::{Bitmap, MutableBitmap};
+#[cfg(feature = "list_zip")]
+use arrow::offset::{Offsets, OffsetsBuffer};
 #[cfg(feature = "list_gather")]
 use num_traits::ToPrimitive;
 #[cfg(feature = "list_gather")]
@@ -20,7 +26,11 @@ use crate::chunked_array::list::sum_mean::sum_with_nulls;
 #[cfg(feature = "diff")]
 use crate::prelude::diff;
 use crate::prelude::list::sum_mean::{mean_list_numerical, sum_list_numerical};
+#[cfg(feature = "top_k")]
+use crate::prelude::top_k;
 use crate::series::ArgAgg;
+#[cfg(feature = "search_sorted")]
+use crate::series::{SearchSortedSide, search_sorted};
 
 pub(super) fn has_inner_nulls(ca: &ListChunked) -> bool {
     for arr in ca.downcast_iter() {
@@ -31,6 +41,47 @@ pub(super) fn has_inner_nulls(ca: &ListChunked) -> bool {
     false
 }
 
+fn broadcast_list(ca: &ListChunked, len: usize) -> Cow<'_, ListChunked> {
+    if ca.len() == len {
+        Cow::Borrowed(ca)
+    } else {
+        Cow::Owned(ca.new_from_index(0, len))
+    }
+}
+
+/// Cast `out` to a list of `dtype`, which it might not be if it has no valid sublists.
+fn with_inner_dtype(out: ListChunked, dtype: &DataType) -> ListChunked {
+    if out.inner_dtype() != dtype {
+        out.cast(&DataType::List(Box::new(dtype.clone())))
+            .unwrap()
+            .list()
+            .unwrap()
+            .clone()
+    } else {
+        out
+    }
+}
+
+/// Build a list column from the flat `values` and the offsets and validity of a list array.
+#[cfg(feature = "list_zip")]
+fn list_from_parts(
+    name: PlSmallStr,
+    offsets: OffsetsBuffer<i64>,
+    values: Series,
+    validity: Option<Bitmap>,
+) -> ListChunked {
+    let dtype = DataType::List(Box::new(values.dtype().clone()));
+    let values = values.rechunk().chunks()[0].clone();
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values.dtype().clone()),
+        offsets,
+        values,
+        validity,
+    );
+    // SAFETY: the inner dtype is the dtype of `values`.
+    unsafe { ListChunked::from_chunks_and_dtype(name, vec![Box::new(arr)], dtype) }
+}
+
 fn cast_rhs(
     other: &mut [Column],
     inner_type: &DataType,
@@ -250,6 +301,127 @@ pub trait ListNameSpaceImpl: AsList {
         Ok(self.same_type(out))
     }
 
+    /// Sort every sublist by the values of the sublist in the same row of `by`, which must have
+    /// the same length.
+    fn lst_sort_by(&self, by: &ListChunked, options: SortOptions) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        polars_ensure!(
+            ca.len() == by.len() || ca.len() == 1 || by.len() == 1,
+            length_mismatch = "list.sort_by",
+            ca.len(),
+            by.len()
+        );
+        let len = ca.len().max(by.len());
+        let ca = broadcast_list(ca, len);
+        let by = broadcast_list(by, len);
+
+        let mut out: ListChunked = ca
+            .amortized_iter()
+            .zip(by.amortized_iter())
+            .map(|(opt_s, opt_by)| match (opt_s, opt_by) {
+                (Some(s), Some(by)) => {
+                    let (s, by) = (s.as_ref(), by.as_ref());
+                    polars_ensure!(
+                        s.len() == by.len(),
+                        ShapeMismatch: "list.sort_by: sublists of length {} and {} cannot be sorted by each other",
+                        s.len(), by.len()
+                    );
+                    let idx = by.arg_sort(options);
+                    // SAFETY: the sort indices are in bounds.
+                    Ok(Some(unsafe { s.take_unchecked(&idx) }))
+                },
+                _ => Ok(None),
+            })
+            .collect::<PolarsResult<_>>()?;
+        out.rename(ca.name().clone());
+        Ok(self.same_type(out))
+    }
+
+    /// Get the indices that sort every sublist.
+    fn lst_arg_sort(&self, options: SortOptions) -> ListChunked {
+        let ca = self.as_list();
+        let out = ca.apply_amortized(|s| s.as_ref().arg_sort(options).into_series());
+        with_inner_dtype(out, &IDX_DTYPE)
+    }
+
+    /// Find the index at which every value of `values` would have to be inserted into the sorted
+    /// sublist in the same row to keep it sorted. The result is null where the sublist or the value
+    /// is null.
+    #[cfg(feature = "search_sorted")]
+    fn lst_search_sorted(
+        &self,
+        values: &Series,
+        side: SearchSortedSide,
+        descending: bool,
+    ) -> PolarsResult<IdxCa> {
+        let ca = self.as_list();
+        polars_ensure!(
+            ca.len() == values.len() || ca.len() == 1 || values.len() == 1,
+            length_mismatch = "list.search_sorted",
+            ca.len(),
+            values.len()
+        );
+        let len = ca.len().max(values.len());
+        let ca = broadcast_list(ca, len);
+        let values = values.strict_cast(ca.inner_dtype())?;
+
+        let mut out: IdxCa = ca
+            .amortized_iter()
+            .enumerate()
+            .map(|(i, opt_s)| {
+                let i = if values.len() == 1 { 0 } else { i };
+                let value = values.slice(i as i64, 1);
+                match opt_s {
+                    Some(s) if value.null_count() == 0 => {
+                        let idx = search_sorted(s.as_ref(), &value, side, descending)?;
+                        Ok(idx.get(0))
+                    },
+                    _ => Ok(None),
+                }
+            })
+            .collect::<PolarsResult<_>>()?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    /// Get the `k` largest values of every sublist, sorted in descending order. `k` is either a
+    /// single value or one value per row.
+    #[cfg(feature = "top_k")]
+    fn lst_top_k(&self, k: &Column) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        let k = k.strict_cast(&IDX_DTYPE)?;
+        let k = k.idx()?;
+        polars_ensure!(
+            ca.len() == k.len() || ca.len() == 1 || k.len() == 1,
+            length_mismatch = "list.top_k",
+            ca.len(),
+            k.len()
+        );
+        let len = ca.len().max(k.len());
+        let ca = broadcast_list(ca, len);
+        let k = if k.len() == len {
+            Cow::Borrowed(k)
+        } else {
+            Cow::Owned(k.new_from_index(0, len))
+        };
+
+        let sort_options = SortOptions::default()
+            .with_order_descending(true)
+            .with_nulls_last(true);
+        let out = ca.try_zip_and_apply_amortized(k.as_ref(), |opt_s, opt_k| {
+            let (Some(s), Some(k)) = (opt_s, opt_k) else {
+                return Ok(None);
+            };
+            let k = IdxCa::from_slice(PlSmallStr::EMPTY, &[k]).into_column();
+            let s = s.as_ref().clone().into_column();
+            let top = top_k(&[s, k], false)?;
+            top.as_materialized_series()
+                .sort_with(sort_options)
+                .map(Some)
+        })?;
+        Ok(self.same_type(out))
+    }
+
     #[must_use]
     fn lst_reverse(&self) -> ListChunked {
         let ca = self.as_list();
@@ -840,6 +1012,144 @@ pub trait ListNameSpaceImpl: AsList {
         };
         Ok(out)
     }
+
+    /// Zip this list with the lists in `others` into a list of structs. The fields of the structs
+    /// are named after the columns.
+    ///
+    /// Sublists in the same row must have the same length and a row is null if any of its
+    /// sublists is null. Columns of length 1 are broadcast.
+    #[cfg(feature = "list_zip")]
+    fn lst_zip(&self, others: &[Column]) -> PolarsResult<ListChunked> {
+        let ca = self.as_list();
+        let mut lists = Vec::with_capacity(others.len() + 1);
+        lists.push(ca.clone());
+        for c in others {
+            lists.push(c.list()?.clone());
+        }
+        let len = lists
+            .iter()
+            .map(|l| l.len())
+            .find(|&len| len != 1)
+            .unwrap_or(1);
+        for l in &lists {
+            polars_ensure!(
+                l.len() == len || l.len() == 1,
+                length_mismatch = "list.zip",
+                len,
+                l.len()
+            );
+        }
+        let lists = lists
+            .iter()
+            .map(|l| broadcast_list(l, len).rechunk().into_owned())
+            .collect::<Vec<_>>();
+        let arrs = lists
+            .iter()
+            .map(|l| l.downcast_as_array())
+            .collect::<Vec<_>>();
+
+        let mut offsets = Vec::with_capacity(len + 1);
+        offsets.push(0i64);
+        let mut validity = MutableBitmap::with_capacity(len);
+        let mut indices = vec![Vec::<IdxSize>::new(); arrs.len()];
+        for i in 0..len {
+            let valid = arrs.iter().all(|arr| arr.is_valid(i));
+            let mut length = 0;
+            if valid {
+                let (start, end) = arrs[0].offsets().start_end(i);
+                length = end - start;
+                for (arr, idx) in arrs.iter().zip(indices.iter_mut()) {
+                    let (start, end) = arr.offsets().start_end(i);
+                    polars_ensure!(
+                        end - start == length,
+                        ShapeMismatch: "list.zip: sublists of length {} and {} cannot be zipped",
+                        length, end - start
+                    );
+                    idx.extend(start as IdxSize..end as IdxSize);
+                }
+            }
+            offsets.push(offsets[i] + length as i64);
+            validity.push(valid);
+        }
+
+        let values_len = offsets[len] as usize;
+        let fields = lists
+            .iter()
+            .zip(indices)
+            .map(|(l, idx)| {
+                let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx);
+                // SAFETY: the indices are within the offsets of the sublists.
+                let field = unsafe { l.get_inner().take_unchecked(&idx) };
+                field.with_name(l.name().clone())
+            })
+            .collect::<Vec<_>>();
+        let values = StructChunked::from_series(PlSmallStr::EMPTY, values_len, fields.iter())?;
+        // SAFETY: the offsets are monotonically increasing.
+        let offsets = unsafe { Offsets::new_unchecked(offsets) };
+        Ok(list_from_parts(
+            ca.name().clone(),
+            offsets.into(),
+            values.into_series(),
+            validity.into(),
+        ))
+    }
+
+    /// Unzip a list of structs into a struct of lists, with a list for every field of the inner
+    /// struct.
+    #[cfg(feature = "list_zip")]
+    fn lst_unzip(&self) -> PolarsResult<StructChunked> {
+        let ca = self.as_list();
+        polars_ensure!(
+            matches!(ca.inner_dtype(), DataType::Struct(_)),
+            InvalidOperation: "list.unzip expected a list of structs, got {}", ca.dtype()
+        );
+        let ca = ca.rechunk();
+        let arr = ca.downcast_as_array();
+        let inner = ca.get_inner();
+        let fields = inner
+            .struct_()?
+            .fields_as_series()
+            .into_iter()
+            .map(|field| {
+                list_from_parts(
+                    field.name().clone(),
+                    arr.offsets().clone(),
+                    field,
+                    arr.validity().cloned(),
+                )
+                .into_series()
+            })
+            .collect::<Vec<_>>();
+        let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+        Ok(out.with_outer_validity(arr.validity().cloned()))
+    }
+
+    /// Pair every element with its index in the sublist, giving a list of structs with the fields
+    /// `index` and `value`.
+    #[cfg(feature = "list_zip")]
+    fn lst_enumerate(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_list().rechunk();
+        let arr = ca.downcast_as_array();
+        let mut index = vec![0 as IdxSize; arr.values().len()];
+        for w in arr.offsets().as_slice().windows(2) {
+            let (start, end) = (w[0] as usize, w[1] as usize);
+            for (i, v) in index[start..end].iter_mut().enumerate() {
+                *v = i as IdxSize;
+            }
+        }
+        let fields = [
+            IdxCa::from_vec(PlSmallStr::from_static("index"), index).into_series(),
+            ca.get_inner().with_name(PlSmallStr::from_static("value")),
+        ];
+        let values =
+            StructChunked::from_series(PlSmallStr::EMPTY, arr.values().len(), fields.iter())?;
+        Ok(list_from_parts(
+            ca.name().clone(),
+            arr.offsets().clone(),
+            values.into_series(),
+            arr.validity().cloned(),
+        ))
+    }
 }
 
 impl ListNameSpaceImpl for ListChunked {}
@@ -0,0 +1,285 @@
+use arrow::array::{Array, ListArray};
+use arrow::bitmap::MutableBitmap;
+use arrow::offset::OffsetsBuffer;
+use polars_core::datatypes::{MAP_KEY_NAME, MAP_VALUE_NAME};
+use polars_core::prelude::*;
+use polars_core::utils::align_chunks_binary;
+
+use super::AsMap;
+
+/// The entries of a map flattened into keys and values, restricted to the elements that are
+/// referenced by the offsets. The offsets are rebased to start at zero.
+struct FlatEntries {
+    offsets: Vec<i64>,
+    validity: Option<arrow::bitmap::Bitmap>,
+    keys: Series,
+    values: Series,
+}
+
+fn flat_entries(ca: &MapChunked) -> PolarsResult<FlatEntries> {
+    let entries = ca.entries().rechunk();
+    let arr = entries.downcast_as_array();
+    let start = *arr.offsets().first();
+    let end = *arr.offsets().last();
+    let offsets = arr.offsets().iter().map(|o| *o - start).collect();
+
+    let inner = entries.get_inner();
+    let inner = inner.struct_()?;
+    let keys = inner
+        .field_by_name(MAP_KEY_NAME)?
+        .slice(start, (end - start) as usize);
+    let values = inner
+        .field_by_name(MAP_VALUE_NAME)?
+        .slice(start, (end - start) as usize);
+
+    Ok(FlatEntries {
+        offsets,
+        validity: arr.validity().cloned(),
+        keys,
+        values,
+    })
+}
+
+impl FlatEntries {
+    fn rows(&self) -> impl Iterator<Item = (usize, std::ops::Range<usize>)> + '_ {
+        self.offsets
+            .windows(2)
+            .enumerate()
+            .filter(|(i, _)| self.validity.as_ref().is_none_or(|v| v.get_bit(*i)))
+            .map(|(i, w)| (i, w[0] as usize..w[1] as usize))
+    }
+
+    /// For every entry, whether its key equals the lookup key of its row.
+    fn key_mask(&self, key: &Series, key_dtype: &DataType) -> PolarsResult<BooleanChunked> {
+        let n_rows = self.offsets.len() - 1;
+        polars_ensure!(
+            key.len() == 1 || key.len() == n_rows,
+            length_mismatch = "map.get",
+            n_rows,
+            key.len()
+        );
+
+        let key = key.cast(key_dtype)?;
+        let key = if key.len() == 1 {
+            key
+        } else {
+            // Repeat the key of every row for each of its entries.
+            let idx = self
+                .offsets
+                .windows(2)
+                .enumerate()
+                .flat_map(|(i, w)| std::iter::repeat_n(i as IdxSize, (w[1] - w[0]) as usize))
+                .collect::<Vec<_>>();
+            key.take_slice(&idx)?
+        };
+
+        let mask = self.keys.equal(&key)?;
+        Ok(mask.fill_null_with_values(false)?.rechunk().into_owned())
+    }
+
+    /// The position of the first entry in each row whose key matches.
+    fn first_match(&self, mask: &BooleanChunked) -> Vec<Option<IdxSize>> {
+        let mask = mask.downcast_as_array().values();
+        let mut out = vec![None; self.offsets.len() - 1];
+        for (i, range) in self.rows() {
+            out[i] = range
+                .into_iter()
+                .find(|j| mask.get_bit(*j))
+                .map(|j| j as IdxSize);
+        }
+        out
+    }
+}
+
+pub trait MapNameSpace: AsMap {
+    /// Get the keys of every map as a list.
+    fn map_keys(&self) -> PolarsResult<ListChunked> {
+        map_field(self.as_map(), MAP_KEY_NAME)
+    }
+
+    /// Get the values of every map as a list.
+    fn map_values(&self) -> PolarsResult<ListChunked> {
+        map_field(self.as_map(), MAP_VALUE_NAME)
+    }
+
+    /// Get the value of `key` in every map. `key` is either a single key or one key per row.
+    ///
+    /// Returns null if the key is not in the map. If a key occurs more than once, the value of
+    /// the first occurrence is returned.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        let entries = flat_entries(ca)?;
+        let mask = entries.key_mask(key, ca.key_dtype())?;
+        let idx =
+            IdxCa::from_iter_options(PlSmallStr::EMPTY, entries.first_match(&mask).into_iter());
+        let mut out = entries.values.take(&idx)?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    /// Check whether every map contains `key`. `key` is either a single key or one key per row.
+    fn map_contains_key(&self, key: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_map();
+        let entries = flat_entries(ca)?;
+        let mask = entries.key_mask(key, ca.key_dtype())?;
+        let found = entries.first_match(&mask);
+
+        let out: BooleanChunked = found
+            .iter()
+            .enumerate()
+            .map(|(i, idx)| {
+                let valid = entries.validity.as_ref().is_none_or(|v| v.get_bit(i));
+                valid.then_some(idx.is_some())
+            })
+            .collect_ca(ca.name().clone());
+        Ok(out)
+    }
+}
+
+impl MapNameSpace for MapChunked {}
+
+fn map_field(ca: &MapChunked, name: &str) -> PolarsResult<ListChunked> {
+    let mut out = ca
+        .entries()
+        .apply_to_inner(&|s| s.struct_()?.field_by_name(name))?;
+    out.rename(ca.name().clone());
+    Ok(out)
+}
+
+/// Create a map from a list of keys and a list of values of the same length in every row.
+pub fn map_from_lists(keys: &ListChunked, values: &ListChunked) -> PolarsResult<MapChunked> {
+    let broadcast = |ca: &ListChunked, len: usize| {
+        if ca.len() == 1 && len != 1 {
+            ca.new_from_index(0, len)
+        } else {
+            ca.clone()
+        }
+    };
+    let len = keys.len().max(values.len());
+    let (keys, values) = (broadcast(keys, len), broadcast(values, len));
+    polars_ensure!(
+        keys.len() == values.len(),
+        length_mismatch = "map.from_lists",
+        keys.len(),
+        values.len()
+    );
+
+    let (keys, values) = align_chunks_binary(&keys, &values);
+    let keys = keys.rechunk();
+    let values = values.rechunk();
+    let key_arr = keys.downcast_as_array();
+    let value_arr = values.downcast_as_array();
+
+    let mut offsets = Vec::with_capacity(len + 1);
+    offsets.push(0i64);
+    let mut validity = MutableBitmap::with_capacity(len);
+    let mut key_idx = Vec::new();
+    let mut value_idx = Vec::new();
+
+    for i in 0..len {
+        let valid = key_arr.is_valid(i) && value_arr.is_valid(i);
+        validity.push(valid);
+        if valid {
+            let (k_start, k_end) = key_arr.offsets().start_end(i);
+            let (v_start, v_end) = value_arr.offsets().start_end(i);
+            polars_ensure!(
+                k_end - k_start == v_end - v_start,
+                ShapeMismatch: "keys and values of a map must have the same length, got {} keys and {} values in row {}",
+                k_end - k_start,
+                v_end - v_start,
+                i
+            );
+            key_idx.extend((k_start..k_end).map(|j| j as IdxSize));
+            value_idx.extend((v_start..v_end).map(|j| j as IdxSize));
+        }
+        offsets.push(key_idx.len() as i64);
+    }
+
+    let mut key_s = keys.get_inner().take_slice(&key_idx)?;
+    key_s.rename(PlSmallStr::from_static(MAP_KEY_NAME));
+    let mut value_s = values.get_inner().take_slice(&value_idx)?;
+    value_s.rename(PlSmallStr::from_static(MAP_VALUE_NAME));
+    let entries =
+        StructChunked::from_series(PlSmallStr::EMPTY, key_s.len(), [key_s, value_s].iter())?
+            .into_series()
+            .rechunk();
+
+    let values_arr = entries.chunks()[0].clone();
+    let arr = ListArray::<i64>::new(
+        ListArray::<i64>::default_datatype(values_arr.dtype().clone()),
+        // SAFETY: the offsets are monotonically increasing and start at zero.
+        unsafe { OffsetsBuffer::new_unchecked(offsets.into()) },
+        values_arr,
+        validity.into(),
+    );
+
+    // SAFETY: the arrow dtype of the list matches the physical type of the entries.
+    let list = unsafe {
+        Series::from_chunks_and_dtype_unchecked(
+            keys.name().clone(),
+            vec![arr.boxed()],
+            &DataType::List(Box::new(entries.dtype().clone())),
+        )
+    };
+    list.list()?.clone().into_map()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn keys_and_values() -> (ListChunked, ListChunked) {
+        let keys: ListChunked = [
+            Some(Series::new("".into(), ["a", "b"])),
+            None,
+            Some(Series::new("".into(), ["c"])),
+        ]
+        .into_iter()
+        .collect();
+        let values: ListChunked = [
+            Some(Series::new("".into(), [1, 2])),
+            Some(Series::new("".into(), [3])),
+            Some(Series::new("".into(), [4])),
+        ]
+        .into_iter()
+        .collect();
+        (keys, values)
+    }
+
+    #[test]
+    fn test_map_get_and_contains_key() -> PolarsResult<()> {
+        let (keys, values) = keys_and_values();
+        let map = map_from_lists(&keys, &values)?;
+        assert_eq!(
+            map.dtype(),
+            &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32))
+        );
+
+        let out = map.map_get(&Series::new("".into(), ["b"]))?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(2), None, None]);
+
+        let out = map.map_get(&Series::new("".into(), ["a", "a", "c"]))?;
+        assert_eq!(Vec::from(out.i32()?), &[Some(1), None, Some(4)]);
+
+        let out = map.map_contains_key(&Series::new("".into(), ["c"]))?;
+        assert_eq!(Vec::from(&out), &[Some(false), None, Some(true)]);
+
+        let out = map.map_keys()?;
+        assert_eq!(out.inner_dtype(), &DataType::String);
+        assert_eq!(out.get_as_series(2).unwrap().str()?.get(0), Some("c"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_from_lists_length_mismatch() {
+        let (_, values) = keys_and_values();
+        let keys: ListChunked = [
+            Some(Series::new("".into(), ["a"])),
+            None,
+            Some(Series::new("".into(), ["c"])),
+        ]
+        .into_iter()
+        .collect();
+        assert!(map_from_lists(&keys, &values).is_err());
+    }
+}
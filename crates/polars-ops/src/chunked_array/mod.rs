@@ -3,7 +3,11 @@ pub mod array;
 mod binary;
 #[cfg(feature = "timezones")]
 pub mod datetime;
+#[cfg(feature = "dtype-json")]
+pub mod json;
 pub mod list;
+#[cfg(feature = "dtype-map")]
+pub mod map;
 #[cfg(feature = "propagate_nans")]
 pub mod nan_propagating_aggregate;
 #[cfg(feature = "peaks")]
@@ -13,6 +17,8 @@ pub mod strings;
 mod sum;
 #[cfg(feature = "top_k")]
 mod top_k;
+#[cfg(feature = "dtype-union")]
+pub mod union;
 
 #[cfg(feature = "mode")]
 pub mod mode;
@@ -18,14 +18,20 @@ mod normalize;
 mod pad;
 #[cfg(feature = "string_reverse")]
 mod reverse;
+#[cfg(feature = "string_similarity")]
+mod similarity;
 #[cfg(feature = "strings")]
 mod split;
 #[cfg(feature = "strings")]
 mod strip;
 #[cfg(feature = "strings")]
 mod substring;
+#[cfg(feature = "string_tokenize")]
+mod tokenize;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
+#[cfg(feature = "net")]
+mod url;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
@@ -46,6 +52,10 @@ pub use split::*;
 pub use strip::*;
 #[cfg(feature = "strings")]
 pub use substring::{substring_ternary_offsets_value, update_view};
+#[cfg(feature = "string_tokenize")]
+pub use tokenize::*;
+#[cfg(feature = "net")]
+pub use url::*;
 
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
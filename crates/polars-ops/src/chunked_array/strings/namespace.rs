@@ -632,6 +632,50 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Levenshtein distance in characters to the strings in `other`. Distances larger than
+    /// `max_distance` are reported as `max_distance + 1`, which lets the kernel stop early.
+    #[cfg(feature = "string_similarity")]
+    fn str_levenshtein(&self, other: &StringChunked, max_distance: Option<u32>) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::levenshtein(ca, other, max_distance)
+    }
+
+    /// Damerau-Levenshtein distance in characters to the strings in `other`, where swapping two
+    /// characters counts as a single edit. Distances larger than `max_distance` are reported as
+    /// `max_distance + 1`.
+    #[cfg(feature = "string_similarity")]
+    fn str_damerau_levenshtein(
+        &self,
+        other: &StringChunked,
+        max_distance: Option<u32>,
+    ) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::damerau_levenshtein(ca, other, max_distance)
+    }
+
+    /// Number of positions at which the characters differ from the strings in `other`. Strings of
+    /// different lengths result in null. Distances larger than `max_distance` are reported as
+    /// `max_distance + 1`.
+    #[cfg(feature = "string_similarity")]
+    fn str_hamming(&self, other: &StringChunked, max_distance: Option<u32>) -> UInt32Chunked {
+        let ca = self.as_string();
+        similarity::hamming(ca, other, max_distance)
+    }
+
+    /// Jaro-Winkler similarity to the strings in `other`, between 0 and 1.
+    #[cfg(feature = "string_similarity")]
+    fn str_jaro_winkler(&self, other: &StringChunked) -> Float64Chunked {
+        let ca = self.as_string();
+        similarity::jaro_winkler(ca, other)
+    }
+
+    /// Jaccard similarity between the sets of character `n`-grams of the strings and `other`.
+    #[cfg(feature = "string_similarity")]
+    fn str_jaccard_ngram(&self, other: &StringChunked, n: usize) -> PolarsResult<Float64Chunked> {
+        let ca = self.as_string();
+        similarity::jaccard_ngram(ca, other, n)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
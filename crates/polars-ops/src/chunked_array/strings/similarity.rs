@@ -0,0 +1,363 @@
+use polars_core::prelude::arity::broadcast_binary_elementwise;
+use polars_core::prelude::*;
+use polars_utils::aliases::{PlHashMap, PlHashSet};
+
+/// Decode `s` into the reusable `buf`, so that the kernels don't allocate per row.
+fn load<'a>(buf: &'a mut Vec<char>, s: &str) -> &'a [char] {
+    buf.clear();
+    buf.extend(s.chars());
+    buf
+}
+
+/// Remove the common prefix and suffix, they don't contribute to the edit distance.
+fn strip_common_affixes<'a>(mut a: &'a [char], mut b: &'a [char]) -> (&'a [char], &'a [char]) {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    a = &a[prefix..];
+    b = &b[prefix..];
+    let suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    (&a[..a.len() - suffix], &b[..b.len() - suffix])
+}
+
+/// Levenshtein distance, or `max + 1` as soon as the distance is known to exceed `max`.
+fn levenshtein_dist(a: &[char], b: &[char], max: usize, row: &mut Vec<usize>) -> usize {
+    let cutoff = max.saturating_add(1);
+    let (a, b) = strip_common_affixes(a, b);
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if a.len() - b.len() > max {
+        return cutoff;
+    }
+    if b.is_empty() {
+        return a.len();
+    }
+
+    row.clear();
+    row.extend(0..=b.len());
+    for (i, ca) in a.iter().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        let mut row_min = row[0];
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            let v = (diag + (ca != cb) as usize).min(above + 1).min(row[j] + 1);
+            diag = above;
+            row[j + 1] = v;
+            row_min = row_min.min(v);
+        }
+        // Row minima never decrease, so the distance can only grow from here.
+        if row_min > max {
+            return cutoff;
+        }
+    }
+    row[b.len()].min(cutoff)
+}
+
+/// Unrestricted Damerau-Levenshtein distance (Lowrance-Wagner), or `max + 1` as soon as the
+/// distance is known to exceed `max`.
+fn damerau_levenshtein_dist(
+    a: &[char],
+    b: &[char],
+    max: usize,
+    matrix: &mut Vec<usize>,
+    last_row: &mut PlHashMap<char, usize>,
+) -> usize {
+    let cutoff = max.saturating_add(1);
+    let (a, b) = strip_common_affixes(a, b);
+    if a.len().abs_diff(b.len()) > max {
+        return cutoff;
+    }
+    if a.is_empty() || b.is_empty() {
+        return a.len().max(b.len());
+    }
+
+    // The matrix has an extra leading row and column holding an upper bound of the distance.
+    let inf = a.len() + b.len();
+    let width = b.len() + 2;
+    matrix.clear();
+    matrix.resize((a.len() + 2) * width, inf);
+    for i in 0..=a.len() {
+        matrix[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=b.len() {
+        matrix[width + j + 1] = j;
+    }
+    last_row.clear();
+
+    for i in 1..=a.len() {
+        let mut last_match_col = 0;
+        let mut row_min = i;
+        for j in 1..=b.len() {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_match_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_col = j;
+                0
+            } else {
+                1
+            };
+            let v = (matrix[i * width + j] + cost)
+                .min(matrix[(i + 1) * width + j] + 1)
+                .min(matrix[i * width + j + 1] + 1)
+                .min(matrix[k * width + l] + (i - k - 1) + 1 + (j - l - 1));
+            matrix[(i + 1) * width + j + 1] = v;
+            row_min = row_min.min(v);
+        }
+        if row_min > max {
+            return cutoff;
+        }
+        last_row.insert(a[i - 1], i);
+    }
+    matrix[(a.len() + 1) * width + b.len() + 1].min(cutoff)
+}
+
+/// Hamming distance, or `max + 1` as soon as the distance is known to exceed `max`. Strings of
+/// different lengths have no Hamming distance.
+fn hamming_dist(a: &str, b: &str, max: usize) -> Option<usize> {
+    let mut a = a.chars();
+    let mut b = b.chars();
+    let mut dist = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (Some(x), Some(y)) => {
+                dist += (x != y) as usize;
+                if dist > max {
+                    // The lengths still have to match for the result to be defined.
+                    return (a.count() == b.count()).then_some(max.saturating_add(1));
+                }
+            },
+            (None, None) => return Some(dist),
+            _ => return None,
+        }
+    }
+}
+
+fn jaro_similarity(
+    a: &[char],
+    b: &[char],
+    a_flags: &mut Vec<bool>,
+    b_flags: &mut Vec<bool>,
+) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+
+    let window = (a.len().max(b.len()) / 2).saturating_sub(1);
+    a_flags.clear();
+    a_flags.resize(a.len(), false);
+    b_flags.clear();
+    b_flags.resize(b.len(), false);
+
+    let mut matches = 0;
+    for (i, ca) in a.iter().enumerate() {
+        let lo = i.saturating_sub(window);
+        let hi = (i + window + 1).min(b.len());
+        for j in lo..hi {
+            if !b_flags[j] && *ca == b[j] {
+                a_flags[i] = true;
+                b_flags[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let mut transpositions = 0;
+    let mut j = 0;
+    for (ca, _) in a.iter().zip(a_flags.iter()).filter(|(_, m)| **m) {
+        while !b_flags[j] {
+            j += 1;
+        }
+        transpositions += (*ca != b[j]) as usize;
+        j += 1;
+    }
+
+    let m = matches as f64;
+    let t = (transpositions / 2) as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - t) / m) / 3.0
+}
+
+/// Jaro-Winkler similarity with the usual prefix scale of 0.1 over at most 4 characters. The
+/// prefix boost is only applied when the Jaro similarity exceeds 0.7.
+fn jaro_winkler_similarity(
+    a: &[char],
+    b: &[char],
+    a_flags: &mut Vec<bool>,
+    b_flags: &mut Vec<bool>,
+) -> f64 {
+    let jaro = jaro_similarity(a, b, a_flags, b_flags);
+    if jaro <= 0.7 {
+        return jaro;
+    }
+    let prefix = a.iter().zip(b).take(4).take_while(|(x, y)| x == y).count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+fn ngrams(s: &[char], n: usize) -> PlHashSet<&[char]> {
+    if s.is_empty() {
+        PlHashSet::new()
+    } else if s.len() < n {
+        PlHashSet::from_iter([s])
+    } else {
+        s.windows(n).collect()
+    }
+}
+
+/// Jaccard similarity of the sets of character `n`-grams. Strings shorter than `n` form a single
+/// n-gram, two empty strings are identical.
+fn jaccard_ngram_similarity(a: &[char], b: &[char], n: usize) -> f64 {
+    let a = ngrams(a, n);
+    let b = ngrams(b, n);
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let intersection = a.intersection(&b).count();
+    intersection as f64 / (a.len() + b.len() - intersection) as f64
+}
+
+fn max_or_unbounded(max_distance: Option<u32>) -> usize {
+    max_distance.map_or(usize::MAX, |max| max as usize)
+}
+
+pub(super) fn levenshtein(
+    ca: &StringChunked,
+    other: &StringChunked,
+    max_distance: Option<u32>,
+) -> UInt32Chunked {
+    let max = max_or_unbounded(max_distance);
+    let (mut a_buf, mut b_buf, mut row) = (Vec::new(), Vec::new(), Vec::new());
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = (load(&mut a_buf, a?), load(&mut b_buf, b?));
+        Some(levenshtein_dist(a, b, max, &mut row) as u32)
+    })
+}
+
+pub(super) fn damerau_levenshtein(
+    ca: &StringChunked,
+    other: &StringChunked,
+    max_distance: Option<u32>,
+) -> UInt32Chunked {
+    let max = max_or_unbounded(max_distance);
+    let (mut a_buf, mut b_buf, mut matrix) = (Vec::new(), Vec::new(), Vec::new());
+    let mut last_row = PlHashMap::new();
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = (load(&mut a_buf, a?), load(&mut b_buf, b?));
+        Some(damerau_levenshtein_dist(a, b, max, &mut matrix, &mut last_row) as u32)
+    })
+}
+
+pub(super) fn hamming(
+    ca: &StringChunked,
+    other: &StringChunked,
+    max_distance: Option<u32>,
+) -> UInt32Chunked {
+    let max = max_or_unbounded(max_distance);
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        hamming_dist(a?, b?, max).map(|d| d as u32)
+    })
+}
+
+pub(super) fn jaro_winkler(ca: &StringChunked, other: &StringChunked) -> Float64Chunked {
+    let (mut a_buf, mut b_buf) = (Vec::new(), Vec::new());
+    let (mut a_flags, mut b_flags) = (Vec::new(), Vec::new());
+    broadcast_binary_elementwise(ca, other, |a: Option<&str>, b: Option<&str>| {
+        let (a, b) = (load(&mut a_buf, a?), load(&mut b_buf, b?));
+        Some(jaro_winkler_similarity(a, b, &mut a_flags, &mut b_flags))
+    })
+}
+
+pub(super) fn jaccard_ngram(
+    ca: &StringChunked,
+    other: &StringChunked,
+    n: usize,
+) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(n > 0, InvalidOperation: "n-gram size must be positive");
+    let (mut a_buf, mut b_buf) = (Vec::new(), Vec::new());
+    Ok(broadcast_binary_elementwise(
+        ca,
+        other,
+        |a: Option<&str>, b: Option<&str>| {
+            let (a, b) = (load(&mut a_buf, a?), load(&mut b_buf, b?));
+            Some(jaccard_ngram_similarity(a, b, n))
+        },
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dist(
+        f: fn(&StringChunked, &StringChunked, Option<u32>) -> UInt32Chunked,
+        a: &str,
+        b: &str,
+        max: Option<u32>,
+    ) -> Option<u32> {
+        let a = StringChunked::new("a".into(), [a]);
+        let b = StringChunked::new("b".into(), [b]);
+        f(&a, &b, max).get(0)
+    }
+
+    #[test]
+    fn test_edit_distances() {
+        assert_eq!(dist(levenshtein, "kitten", "sitting", None), Some(3));
+        assert_eq!(dist(levenshtein, "", "abc", None), Some(3));
+        assert_eq!(dist(levenshtein, "café", "cafe", None), Some(1));
+        assert_eq!(dist(levenshtein, "kitten", "sitting", Some(2)), Some(3));
+        assert_eq!(dist(levenshtein, "kitten", "sitting", Some(3)), Some(3));
+        assert_eq!(dist(levenshtein, "a", "abcdef", Some(1)), Some(2));
+
+        assert_eq!(dist(damerau_levenshtein, "ca", "abc", None), Some(2));
+        assert_eq!(dist(damerau_levenshtein, "abcdef", "badcfe", None), Some(3));
+        assert_eq!(
+            dist(damerau_levenshtein, "kitten", "sitting", None),
+            Some(3)
+        );
+        assert_eq!(
+            dist(damerau_levenshtein, "abcdef", "badcfe", Some(1)),
+            Some(2)
+        );
+
+        assert_eq!(dist(hamming, "karolin", "kathrin", None), Some(3));
+        assert_eq!(dist(hamming, "karolin", "kathrin", Some(1)), Some(2));
+        assert_eq!(dist(hamming, "abc", "ab", None), None);
+        assert_eq!(dist(hamming, "abc", "xyzw", Some(0)), None);
+    }
+
+    #[test]
+    fn test_similarities() -> PolarsResult<()> {
+        let a = StringChunked::new("a".into(), [Some("martha"), Some("dixon"), Some(""), None]);
+        let b = StringChunked::new(
+            "b".into(),
+            [Some("marhta"), Some("dicksonx"), Some(""), Some("x")],
+        );
+
+        let jw = jaro_winkler(&a, &b);
+        assert!((jw.get(0).unwrap() - 0.9611).abs() < 1e-4);
+        assert!((jw.get(1).unwrap() - 0.8133).abs() < 1e-4);
+        assert_eq!(jw.get(2), Some(1.0));
+        assert_eq!(jw.get(3), None);
+
+        let jaccard = jaccard_ngram(&a, &b, 2)?;
+        // {ma, ar, rt, th, ha} vs {ma, ar, rh, ht, ta}
+        assert_eq!(jaccard.get(0), Some(2.0 / 8.0));
+        assert_eq!(jaccard.get(2), Some(1.0));
+        assert!(jaccard_ngram(&a, &b, 0).is_err());
+
+        // Broadcasting a literal.
+        let lit = StringChunked::new("b".into(), ["martha"]);
+        let out = levenshtein(&a, &lit, None);
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(&out), &[Some(0), Some(6), Some(6), None]);
+        Ok(())
+    }
+}
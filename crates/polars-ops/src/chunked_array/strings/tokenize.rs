@@ -0,0 +1,180 @@
+use std::borrow::Cow;
+
+use arrow::array::ValueSize;
+use polars_core::chunked_array::ops::arity::binary_elementwise_for_each;
+use polars_core::prelude::arity::unary_elementwise_values;
+use polars_utils::aliases::PlHashSet;
+use polars_utils::regex_cache::with_regex_cache;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::*;
+
+fn list_builder(ca: &StringChunked) -> ListStringChunkedBuilder {
+    ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size())
+}
+
+fn for_each_list<F>(ca: &StringChunked, mut f: F) -> ListChunked
+where
+    F: FnMut(&str, &mut ListStringChunkedBuilder),
+{
+    let mut builder = list_builder(ca);
+    ca.for_each(|opt_s| match opt_s {
+        Some(s) => f(s, &mut builder),
+        None => builder.append_null(),
+    });
+    builder.finish()
+}
+
+/// Split the strings on every match of the regular expressions in `pat`.
+pub fn split_regex(ca: &StringChunked, pat: &StringChunked) -> PolarsResult<ListChunked> {
+    let (ca, pat) = match (ca.len(), pat.len()) {
+        (a, b) if a == b => (Cow::Borrowed(ca), Cow::Borrowed(pat)),
+        (_, 1) => {
+            let Some(pat) = pat.get(0) else {
+                return Ok(ListChunked::full_null_with_dtype(
+                    ca.name().clone(),
+                    ca.len(),
+                    &DataType::String,
+                ));
+            };
+            let reg = polars_utils::regex_cache::compile_regex(pat)?;
+            return Ok(for_each_list(ca, |s, builder| {
+                builder.append_values_iter(reg.split(s))
+            }));
+        },
+        (1, len) => (Cow::Owned(ca.new_from_index(0, len)), Cow::Borrowed(pat)),
+        (a, b) => polars_bail!(length_mismatch = "str.split_regex", a, b),
+    };
+
+    let mut builder = list_builder(&ca);
+    with_regex_cache(|cache| {
+        let mut result = Ok(());
+        binary_elementwise_for_each(&ca, &pat, |opt_s, opt_pat| match (opt_s, opt_pat) {
+            (Some(s), Some(pat)) if result.is_ok() => match cache.compile(pat) {
+                Ok(reg) => builder.append_values_iter(reg.split(s)),
+                Err(err) => result = Err(err),
+            },
+            _ => builder.append_null(),
+        });
+        result
+    })?;
+    Ok(builder.finish())
+}
+
+/// Split the strings into words at the Unicode word boundaries (UAX #29). Whitespace and
+/// punctuation between the words is dropped.
+pub fn tokenize(ca: &StringChunked) -> ListChunked {
+    for_each_list(ca, |s, builder| {
+        builder.append_values_iter(s.unicode_words())
+    })
+}
+
+/// Count the words as found by [`tokenize`].
+pub fn count_tokens(ca: &StringChunked) -> UInt32Chunked {
+    unary_elementwise_values(ca, |s| s.unicode_words().count() as u32)
+}
+
+/// All overlapping substrings of `n` characters. Strings shorter than `n` have no n-grams.
+pub fn char_ngrams(ca: &StringChunked, n: usize) -> PolarsResult<ListChunked> {
+    polars_ensure!(n > 0, InvalidOperation: "n-gram size must be positive");
+    let mut boundaries = Vec::new();
+    Ok(for_each_list(ca, |s, builder| {
+        boundaries.clear();
+        boundaries.extend(s.char_indices().map(|(i, _)| i));
+        boundaries.push(s.len());
+        builder.append_values_iter(boundaries.windows(n + 1).map(|w| &s[w[0]..w[n]]))
+    }))
+}
+
+/// All overlapping sequences of `n` words as found by [`tokenize`], joined by a single space.
+/// Strings with fewer than `n` words have no n-grams.
+pub fn word_ngrams(ca: &StringChunked, n: usize) -> PolarsResult<ListChunked> {
+    polars_ensure!(n > 0, InvalidOperation: "n-gram size must be positive");
+    Ok(for_each_list(ca, |s, builder| {
+        let words = s.unicode_words().collect::<Vec<_>>();
+        let grams = words.windows(n).map(|w| w.join(" ")).collect::<Vec<_>>();
+        builder.append_values_iter(grams.iter().map(String::as_str))
+    }))
+}
+
+/// Split the strings into words as [`tokenize`] does, leaving out the words in `stop_words`.
+/// Words are compared exactly, so lowercase the strings first to ignore case.
+pub fn remove_stop_words(ca: &StringChunked, stop_words: &[PlSmallStr]) -> ListChunked {
+    let stop_words: PlHashSet<&str> = stop_words.iter().map(|w| w.as_str()).collect();
+    for_each_list(ca, |s, builder| {
+        builder.append_values_iter(s.unicode_words().filter(|w| !stop_words.contains(w)))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn values(ca: &ListChunked, i: usize) -> Option<Vec<String>> {
+        ca.get_as_series(i).map(|s| {
+            s.str()
+                .unwrap()
+                .into_no_null_iter()
+                .map(|s| s.to_string())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn test_split_regex() -> PolarsResult<()> {
+        let ca = StringChunked::new("a".into(), [Some("a1b22c"), Some("x"), None]);
+        let out = split_regex(&ca, &StringChunked::new("p".into(), [r"\d+"]))?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(values(&out, 0).unwrap(), ["a", "b", "c"]);
+        assert_eq!(values(&out, 1).unwrap(), ["x"]);
+        assert_eq!(values(&out, 2), None);
+
+        let pat = StringChunked::new("p".into(), [Some("1"), None, Some("y")]);
+        let out = split_regex(&ca, &pat)?;
+        assert_eq!(values(&out, 0).unwrap(), ["a", "b22c"]);
+        assert_eq!(values(&out, 1), None);
+
+        let invalid = StringChunked::new("p".into(), ["("]);
+        assert!(split_regex(&ca, &invalid).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_tokenize() -> PolarsResult<()> {
+        let ca = StringChunked::new(
+            "a".into(),
+            [
+                Some("The quick (\"brown\") fox can't jump 32.3 feet, right?"),
+                None,
+            ],
+        );
+        assert_eq!(
+            values(&tokenize(&ca), 0).unwrap(),
+            [
+                "The", "quick", "brown", "fox", "can't", "jump", "32.3", "feet", "right"
+            ]
+        );
+        assert_eq!(Vec::from(&count_tokens(&ca)), &[Some(9), None]);
+
+        let stop_words = ["The".into(), "can't".into()];
+        assert_eq!(
+            values(&remove_stop_words(&ca, &stop_words), 0).unwrap(),
+            ["quick", "brown", "fox", "jump", "32.3", "feet", "right"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ngrams() -> PolarsResult<()> {
+        let ca = StringChunked::new("a".into(), ["héllo", "hi", "one two three"]);
+        let chars = char_ngrams(&ca, 3)?;
+        assert_eq!(values(&chars, 0).unwrap(), ["hél", "éll", "llo"]);
+        assert!(values(&chars, 1).unwrap().is_empty());
+
+        let words = word_ngrams(&ca, 2)?;
+        assert!(values(&words, 0).unwrap().is_empty());
+        assert_eq!(values(&words, 2).unwrap(), ["one two", "two three"]);
+        assert!(char_ngrams(&ca, 0).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+use std::borrow::Cow;
+
+use percent_encoding::percent_decode_str;
+
+use super::*;
+
+/// The components of a URI reference as split by RFC 3986, appendix B. Components that are
+/// absent are `None`, which differs from being present but empty (`http://host?` has an empty
+/// query).
+#[derive(Debug, Default, PartialEq)]
+struct UrlParts<'a> {
+    scheme: Option<&'a str>,
+    host: Option<&'a str>,
+    port: Option<u16>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+fn is_scheme(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+fn split_host_port(authority: &str) -> (Option<&str>, Option<u16>) {
+    // Drop the user information.
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, hp)| hp);
+    let (host, port) = if let Some(rest) = host_port.strip_prefix('[') {
+        // An IPv6 literal, the brackets are not part of the host.
+        match rest.split_once(']') {
+            Some((host, rest)) => (host, rest.strip_prefix(':')),
+            None => (host_port, None),
+        }
+    } else {
+        match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        }
+    };
+    let host = (!host.is_empty()).then_some(host);
+    (host, port.and_then(|p| p.parse().ok()))
+}
+
+fn split_url(s: &str) -> UrlParts<'_> {
+    let (rest, fragment) = match s.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (s, None),
+    };
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+    let (scheme, rest) = match rest.split_once(':') {
+        Some((scheme, rest)) if is_scheme(scheme) => (Some(scheme), rest),
+        _ => (None, rest),
+    };
+    let (host, port, path) = match rest.strip_prefix("//") {
+        Some(rest) => {
+            let (authority, path) = rest.find('/').map_or((rest, ""), |i| rest.split_at(i));
+            let (host, port) = split_host_port(authority);
+            (host, port, path)
+        },
+        None => (None, None, rest),
+    };
+    UrlParts {
+        scheme,
+        host,
+        port,
+        path,
+        query,
+        fragment,
+    }
+}
+
+/// Parse URLs, absolute or relative, into a struct with the fields `scheme`, `host`, `port`,
+/// `path`, `query` and `fragment`.
+///
+/// Components are split as described in RFC 3986 and are not decoded or normalized. Components
+/// that are missing from a URL are null, except for the path, which is empty. Ports that are not
+/// a valid `u16` are null.
+pub fn url_parse(ca: &StringChunked) -> PolarsResult<StructChunked> {
+    let len = ca.len();
+    let mut scheme = StringChunkedBuilder::new("scheme".into(), len);
+    let mut host = StringChunkedBuilder::new("host".into(), len);
+    let mut port = PrimitiveChunkedBuilder::<UInt16Type>::new("port".into(), len);
+    let mut path = StringChunkedBuilder::new("path".into(), len);
+    let mut query = StringChunkedBuilder::new("query".into(), len);
+    let mut fragment = StringChunkedBuilder::new("fragment".into(), len);
+
+    ca.for_each(|opt_s| {
+        let parts = opt_s.map(split_url).unwrap_or_default();
+        scheme.append_option(parts.scheme);
+        host.append_option(parts.host);
+        port.append_option(parts.port);
+        path.append_option(opt_s.map(|_| parts.path));
+        query.append_option(parts.query);
+        fragment.append_option(parts.fragment);
+    });
+
+    let fields = [
+        scheme.finish().into_series(),
+        host.finish().into_series(),
+        port.finish().into_series(),
+        path.finish().into_series(),
+        query.finish().into_series(),
+        fragment.finish().into_series(),
+    ];
+    let validity = ca.rechunk_validity();
+    Ok(
+        StructChunked::from_series(ca.name().clone(), len, fields.iter())?
+            .with_outer_validity(validity),
+    )
+}
+
+fn decode_component(s: &str) -> Cow<'_, str> {
+    if s.contains('+') {
+        Cow::Owned(
+            percent_decode_str(&s.replace('+', " "))
+                .decode_utf8_lossy()
+                .into_owned(),
+        )
+    } else {
+        percent_decode_str(s).decode_utf8_lossy()
+    }
+}
+
+/// Get the percent-decoded value of the first query parameter called `name` in every URL.
+///
+/// Parameters are separated by `&` and a `+` decodes to a space, as in HTML forms. URLs without
+/// the parameter give null, a parameter without a value (`?flag`) gives an empty string.
+pub fn url_query_param(ca: &StringChunked, name: &str) -> StringChunked {
+    let mut builder = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    let mut value = String::new();
+    ca.for_each(|opt_s| {
+        let query = opt_s.and_then(|s| split_url(s).query);
+        let found = query.and_then(|query| {
+            query
+                .split('&')
+                .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+                .find(|(key, _)| decode_component(key) == name)
+        });
+        match found {
+            Some((_, v)) => {
+                value.clear();
+                value.push_str(&decode_component(v));
+                builder.append_value(&value);
+            },
+            None => builder.append_null(),
+        }
+    });
+    builder.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_url() {
+        assert_eq!(
+            split_url("https://user:pw@Example.com:8080/a/b.html?x=1&y=2#top"),
+            UrlParts {
+                scheme: Some("https"),
+                host: Some("Example.com"),
+                port: Some(8080),
+                path: "/a/b.html",
+                query: Some("x=1&y=2"),
+                fragment: Some("top"),
+            }
+        );
+        assert_eq!(
+            split_url("http://[::1]:80"),
+            UrlParts {
+                scheme: Some("http"),
+                host: Some("::1"),
+                port: Some(80),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            split_url("/search?q=polars"),
+            UrlParts {
+                path: "/search",
+                query: Some("q=polars"),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            split_url("mailto:someone@example.com"),
+            UrlParts {
+                scheme: Some("mailto"),
+                path: "someone@example.com",
+                ..Default::default()
+            }
+        );
+        assert_eq!(split_url("http://host:99999/").port, None);
+        assert_eq!(split_url("http://host?").query, Some(""));
+    }
+
+    #[test]
+    fn test_url_parse() -> PolarsResult<()> {
+        let ca = StringChunked::new("u".into(), [Some("http://a.com/x?q=1"), None]);
+        let out = url_parse(&ca)?;
+        assert_eq!(out.null_count(), 1);
+        let fields = out.fields_as_series();
+        assert_eq!(fields[1].str()?.get(0), Some("a.com"));
+        assert_eq!(fields[2].u16()?.get(0), None);
+        assert_eq!(fields[3].str()?.get(0), Some("/x"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_query_param() {
+        let ca = StringChunked::new(
+            "u".into(),
+            [
+                Some("/s?q=hello+world%21&page=2&q=again"),
+                Some("/s?flag&q"),
+                Some("/s?page=1"),
+                Some("/s#q=1"),
+                None,
+            ],
+        );
+        let out = url_query_param(&ca, "q");
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("hello world!"), Some(""), None, None, None]
+        );
+    }
+}
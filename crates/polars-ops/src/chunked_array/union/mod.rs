@@ -0,0 +1,14 @@
+mod namespace;
+
+pub use namespace::*;
+use polars_core::prelude::*;
+
+pub trait AsUnion {
+    fn as_union(&self) -> &UnionChunked;
+}
+
+impl AsUnion for UnionChunked {
+    fn as_union(&self) -> &UnionChunked {
+        self
+    }
+}
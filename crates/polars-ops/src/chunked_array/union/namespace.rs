@@ -0,0 +1,57 @@
+use polars_core::prelude::*;
+
+use super::AsUnion;
+
+pub trait UnionNameSpace: AsUnion {
+    /// Get the index of the active variant of every row, null for null rows.
+    fn union_type_id(&self) -> Int8Chunked {
+        self.as_union().type_ids()
+    }
+
+    /// Get the values of the variant `name`, null in rows where another variant is active.
+    fn union_field(&self, name: &str) -> PolarsResult<Series> {
+        self.as_union().variant(name)
+    }
+}
+
+impl UnionNameSpace for UnionChunked {}
+
+/// Build a union from a struct of nullable fields. Every field is a variant and at most one
+/// field can be non-null per row.
+pub fn union_from_struct(ca: &StructChunked) -> PolarsResult<UnionChunked> {
+    ca.clone().into_union()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_union_namespace() -> PolarsResult<()> {
+        let a = Series::new("a".into(), [Some(1i64), None, None]);
+        let b = Series::new("b".into(), [None, Some(2.5f64), None]);
+        let ca = StructChunked::from_series("u".into(), 3, [a, b].iter())?;
+        let union = union_from_struct(&ca)?;
+        assert_eq!(
+            union.dtype(),
+            &DataType::Union(vec![
+                Field::new("a".into(), DataType::Int64),
+                Field::new("b".into(), DataType::Float64),
+            ])
+        );
+
+        assert_eq!(Vec::from(&union.union_type_id()), &[Some(0), Some(1), None]);
+        let b = union.union_field("b")?;
+        assert_eq!(Vec::from(b.f64()?), &[None, Some(2.5), None]);
+        assert!(union.union_field("c").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_from_struct_overlapping_fields() {
+        let a = Series::new("a".into(), [Some(1i64)]);
+        let b = Series::new("b".into(), [Some(2i64)]);
+        let ca = StructChunked::from_series("u".into(), 1, [a, b].iter()).unwrap();
+        assert!(union_from_struct(&ca).is_err());
+    }
+}
@@ -527,7 +527,13 @@ pub trait AsofJoinBy: IntoDf {
                 .zip(right_by.get_columns_mut().iter_mut())
             {
                 #[cfg(feature = "dtype-categorical")]
-                _check_categorical_src(l.dtype(), r.dtype())?;
+                if l.dtype().is_categorical() || l.dtype().is_enum() {
+                    let mut l_s = l.as_materialized_series().clone();
+                    let mut r_s = r.as_materialized_series().clone();
+                    _make_categoricals_compatible(&mut l_s, &mut r_s)?;
+                    *l = l_s.into();
+                    *r = r_s.into();
+                }
                 *l = l.to_physical_repr();
                 *r = r.to_physical_repr();
             }
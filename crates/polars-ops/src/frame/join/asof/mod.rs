@@ -11,7 +11,7 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "dtype-categorical")]
-use super::_check_categorical_src;
+use super::{_check_categorical_src, _make_categoricals_compatible};
 use super::{_finish_join, build_tables};
 use crate::frame::IntoDf;
 use crate::series::SeriesMethods;
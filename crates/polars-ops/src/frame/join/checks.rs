@@ -16,3 +16,15 @@ pub(crate) fn _check_categorical_src(l: &DataType, r: &DataType) -> PolarsResult
     };
     Ok(())
 }
+
+/// Categoricals that were created with different mappings get their physicals remapped onto a
+/// shared mapping, so that they can be joined on their physicals.
+pub(crate) fn _make_categoricals_compatible(l: &mut Series, r: &mut Series) -> PolarsResult<()> {
+    if _check_categorical_src(l.dtype(), r.dtype()).is_err() {
+        let (ca_left, ca_right) =
+            make_rhs_categoricals_compatible(l.categorical()?, r.categorical()?)?;
+        *l = ca_left.into_series().with_name(l.name().clone());
+        *r = ca_right.into_series().with_name(r.name().clone());
+    }
+    Ok(())
+}
@@ -76,12 +76,24 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_left(rhs, validate, nulls_equal)
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            T::FixedSizeBinary(_) => {
+                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
+                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                lhs.hash_join_left(rhs, validate, nulls_equal)
+            },
             #[cfg(feature = "dtype-struct")]
             T::Struct(_) => {
                 let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_left(rhs, validate, nulls_equal)
             },
+            #[cfg(feature = "dtype-u128")]
+            T::UInt128 => {
+                let lhs = lhs.u128().unwrap();
+                let rhs = rhs.u128().unwrap();
+                num_group_join_left(lhs, rhs, validate, nulls_equal)
+            },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
@@ -171,12 +183,24 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_semi_anti(rhs, anti, nulls_equal)?
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            T::FixedSizeBinary(_) => {
+                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
+                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                lhs.hash_join_semi_anti(rhs, anti, nulls_equal)?
+            },
             #[cfg(feature = "dtype-struct")]
             T::Struct(_) => {
                 let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_semi_anti(rhs, anti, nulls_equal)?
             },
+            #[cfg(feature = "dtype-u128")]
+            T::UInt128 => {
+                let lhs = lhs.u128().unwrap();
+                let rhs = rhs.u128().unwrap();
+                num_group_join_anti_semi(lhs, rhs, anti, nulls_equal)
+            },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
@@ -289,12 +313,24 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_inner(rhs, validate, nulls_equal)
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            T::FixedSizeBinary(_) => {
+                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
+                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                lhs.hash_join_inner(rhs, validate, nulls_equal)
+            },
             #[cfg(feature = "dtype-struct")]
             T::Struct(_) => {
                 let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_inner(rhs, validate, nulls_equal)
             },
+            #[cfg(feature = "dtype-u128")]
+            T::UInt128 => {
+                let lhs = lhs.u128().unwrap();
+                let rhs = rhs.u128().unwrap();
+                group_join_inner::<UInt128Type>(lhs, rhs, validate, nulls_equal)
+            },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
@@ -376,12 +412,24 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_outer(rhs, validate, nulls_equal)
             },
+            #[cfg(feature = "dtype-fixed-size-binary")]
+            T::FixedSizeBinary(_) => {
+                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
+                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                lhs.hash_join_outer(rhs, validate, nulls_equal)
+            },
             #[cfg(feature = "dtype-struct")]
             T::Struct(_) => {
                 let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
                 let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
                 lhs.hash_join_outer(rhs, validate, nulls_equal)
             },
+            #[cfg(feature = "dtype-u128")]
+            T::UInt128 => {
+                let lhs = lhs.u128().unwrap();
+                let rhs = rhs.u128().unwrap();
+                hash_join_outer(lhs, rhs, validate, nulls_equal)
+            },
             x if x.is_float() => {
                 with_match_physical_float_polars_type!(lhs.dtype(), |$T| {
                     let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref().as_ref();
@@ -225,15 +225,7 @@ pub trait DataFrameJoinOps: IntoDf {
 
         #[cfg(feature = "dtype-categorical")]
         for (l, r) in selected_left.iter_mut().zip(selected_right.iter_mut()) {
-            match _check_categorical_src(l.dtype(), r.dtype()) {
-                Ok(_) => {},
-                Err(_) => {
-                    let (ca_left, ca_right) =
-                        make_rhs_categoricals_compatible(l.categorical()?, r.categorical()?)?;
-                    *l = ca_left.into_series().with_name(l.name().clone());
-                    *r = ca_right.into_series().with_name(r.name().clone());
-                },
-            }
+            _make_categoricals_compatible(l, r)?;
         }
 
         #[cfg(feature = "iejoin")]
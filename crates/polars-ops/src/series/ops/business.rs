@@ -1,3 +1,5 @@
+use std::cell::Cell;
+
 #[cfg(feature = "dtype-date")]
 use chrono::DateTime;
 use polars_core::prelude::arity::{binary_elementwise_values, try_binary_elementwise};
@@ -8,6 +10,7 @@ use polars_utils::binary_search::{find_first_ge_index, find_first_gt_index};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::HolidayCalendar;
 #[cfg(feature = "timezones")]
 use crate::prelude::replace_time_zone;
 
@@ -28,20 +31,23 @@ pub enum Roll {
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
+/// - `calendar`: a calendar whose holidays are excluded in addition to `holidays`.
 pub fn business_day_count(
     start: &Series,
     end: &Series,
     week_mask: [bool; 7],
     holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
 ) -> PolarsResult<Series> {
     if !week_mask.iter().any(|&x| x) {
         polars_bail!(ComputeError:"`week_mask` must have at least one business day");
     }
 
-    // Sort now so we can use `binary_search` in the hot for-loop.
-    let holidays = normalise_holidays(holidays, &week_mask);
     let start_dates = start.date()?;
     let end_dates = end.date()?;
+    let holidays = with_calendar_holidays(holidays, calendar, &[start_dates, end_dates]);
+    // Sort now so we can use `binary_search` in the hot for-loop.
+    let holidays = normalise_holidays(holidays, &week_mask);
     let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
 
     let out = match (start_dates.len(), end_dates.len()) {
@@ -139,6 +145,7 @@ fn business_day_count_impl(
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
+/// - `calendar`: a calendar whose holidays are skipped in addition to `holidays`.
 /// - `roll`: what to do when the start date doesn't land on a business day:
 ///   - `Roll::Forward`: roll forward to the next business day.
 ///   - `Roll::Backward`: roll backward to the previous business day.
@@ -148,6 +155,7 @@ pub fn add_business_days(
     n: &Series,
     week_mask: [bool; 7],
     holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
     roll: Roll,
 ) -> PolarsResult<Series> {
     if !week_mask.iter().any(|&x| x) {
@@ -158,8 +166,14 @@ pub fn add_business_days(
         DataType::Date => {},
         #[cfg(feature = "dtype-datetime")]
         DataType::Datetime(time_unit, None) => {
-            let result_date =
-                add_business_days(&start.cast(&DataType::Date)?, n, week_mask, holidays, roll)?;
+            let result_date = add_business_days(
+                &start.cast(&DataType::Date)?,
+                n,
+                week_mask,
+                holidays,
+                calendar,
+                roll,
+            )?;
             let start_time = start
                 .cast(&DataType::Time)?
                 .cast(&DataType::Duration(*time_unit))?;
@@ -181,6 +195,7 @@ pub fn add_business_days(
                 n,
                 week_mask,
                 holidays,
+                calendar,
                 roll,
             )?;
             let start_time = start_naive
@@ -201,8 +216,6 @@ pub fn add_business_days(
         _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", start.dtype()),
     }
 
-    // Sort now so we can use `binary_search` in the hot for-loop.
-    let holidays = normalise_holidays(holidays, &week_mask);
     let start_dates = start.date()?;
     let n = match &n.dtype() {
         DataType::Int64 | DataType::UInt64 | DataType::UInt32 => n.cast(&DataType::Int32)?,
@@ -213,21 +226,98 @@ pub fn add_business_days(
     };
     let n = n.i32()?;
     let n_business_days_in_week_mask = week_mask.iter().filter(|&x| *x).count() as i32;
+    let (Some(calendar), Some(lower), Some(upper)) =
+        (calendar, start_dates.phys.min(), start_dates.phys.max())
+    else {
+        // Sort now so we can use `binary_search` in the hot for-loop.
+        let holidays = normalise_holidays(holidays.to_vec(), &week_mask);
+        return add_business_days_with_holidays(
+            start_dates,
+            n,
+            &week_mask,
+            n_business_days_in_week_mask,
+            &holidays,
+            roll,
+        )
+        .map(|(out, _)| out.into_date().into_series());
+    };
 
+    // The calendar's holidays are only materialised around the start dates. Start with the
+    // span needed without holidays (plus some slack for rolling), and widen it until every
+    // date visited during the walk falls inside the materialised window.
+    let max_n = n
+        .min()
+        .into_iter()
+        .chain(n.max())
+        .map(|n| n.saturating_abs())
+        .max()
+        .unwrap_or(0);
+    let mut margin = (max_n / n_business_days_in_week_mask)
+        .saturating_add(2)
+        .saturating_mul(7)
+        .saturating_add(31);
+    loop {
+        let window = (lower.saturating_sub(margin), upper.saturating_add(margin));
+        let mut holidays = holidays.to_vec();
+        holidays.extend(calendar.holidays_between(window.0, window.1));
+        let holidays = normalise_holidays(holidays, &week_mask);
+        let (out, visited) = add_business_days_with_holidays(
+            start_dates,
+            n,
+            &week_mask,
+            n_business_days_in_week_mask,
+            &holidays,
+            roll,
+        )?;
+        if visited.is_none_or(|(min, max)| window.0 < min && max < window.1) {
+            return Ok(out.into_date().into_series());
+        }
+        polars_ensure!(
+            margin < MAX_CALENDAR_MARGIN,
+            ComputeError: "holiday calendar '{}' leaves too few business days to add {} business days",
+            calendar.name, max_n
+        );
+        margin = margin.saturating_mul(2).min(MAX_CALENDAR_MARGIN);
+    }
+}
+
+/// The widest margin, in days, around the start dates for which the holidays of a calendar
+/// are generated. This is well over the range of years that dates can represent.
+const MAX_CALENDAR_MARGIN: i32 = 1 << 26;
+
+/// Add business days, skipping the given (sorted) holidays. Also returns the earliest and
+/// latest date that was visited, either while rolling or as a result.
+fn add_business_days_with_holidays(
+    start_dates: &DateChunked,
+    n: &Int32Chunked,
+    week_mask: &[bool; 7],
+    n_business_days_in_week_mask: i32,
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<(Int32Chunked, Option<(i32, i32)>)> {
+    let visited: Cell<Option<(i32, i32)>> = Cell::new(None);
+    let visit = |date: i32| {
+        let bounds = visited
+            .get()
+            .map_or((date, date), |(min, max)| (min.min(date), max.max(date)));
+        visited.set(Some(bounds));
+        date
+    };
     let out: Int32Chunked = match (start_dates.len(), n.len()) {
         (_, 1) => {
             if let Some(n) = n.get(0) {
                 start_dates.try_apply_nonnull_values_generic(|start_date| {
                     let (start_date, day_of_week) =
-                        roll_start_date(start_date, roll, &week_mask, &holidays)?;
-                    Ok::<i32, PolarsError>(add_business_days_impl(
+                        roll_start_date(start_date, roll, week_mask, holidays)?;
+                    visit(start_date);
+                    Ok::<i32, PolarsError>(visit(add_business_days_impl(
                         start_date,
                         day_of_week,
                         n,
-                        &week_mask,
+                        week_mask,
                         n_business_days_in_week_mask,
-                        &holidays,
-                    ))
+                        holidays,
+                    )))
                 })?
             } else {
                 Int32Chunked::full_null(start_dates.name().clone(), start_dates.len())
@@ -236,16 +326,17 @@ pub fn add_business_days(
         (1, _) => {
             if let Some(start_date) = start_dates.get(0) {
                 let (start_date, day_of_week) =
-                    roll_start_date(start_date, roll, &week_mask, &holidays)?;
+                    roll_start_date(start_date, roll, week_mask, holidays)?;
+                visit(start_date);
                 n.apply_values(|n| {
-                    add_business_days_impl(
+                    visit(add_business_days_impl(
                         start_date,
                         day_of_week,
                         n,
-                        &week_mask,
+                        week_mask,
                         n_business_days_in_week_mask,
-                        &holidays,
-                    )
+                        holidays,
+                    ))
                 })
             } else {
                 Int32Chunked::full_null(start_dates.name().clone(), n.len())
@@ -262,22 +353,24 @@ pub fn add_business_days(
                 match (opt_start_date, opt_n) {
                     (Some(start_date), Some(n)) => {
                         let (start_date, day_of_week) =
-                            roll_start_date(start_date, roll, &week_mask, &holidays)?;
-                        Ok::<Option<i32>, PolarsError>(Some(add_business_days_impl(
+                            roll_start_date(start_date, roll, week_mask, holidays)?;
+                        visit(start_date);
+                        Ok::<Option<i32>, PolarsError>(Some(visit(add_business_days_impl(
                             start_date,
                             day_of_week,
                             n,
-                            &week_mask,
+                            week_mask,
                             n_business_days_in_week_mask,
-                            &holidays,
-                        )))
+                            holidays,
+                        ))))
                     },
                     _ => Ok(None),
                 }
             })?
         },
     };
-    Ok(out.into_date().into_series())
+
+    Ok((out, visited.get()))
 }
 
 /// Ported from:
@@ -337,10 +430,12 @@ fn add_business_days_impl(
 /// - `week_mask`: A boolean array of length 7, where `true` indicates that the day is a business day.
 /// - `holidays`: timestamps that are holidays. Must be provided as i32, i.e. the number of
 ///   days since the UNIX epoch.
+/// - `calendar`: a calendar whose holidays are not business days, in addition to `holidays`.
 pub fn is_business_day(
     dates: &Series,
     week_mask: [bool; 7],
     holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
 ) -> PolarsResult<Series> {
     if !week_mask.iter().any(|&x| x) {
         polars_bail!(ComputeError:"`week_mask` must have at least one business day");
@@ -350,7 +445,7 @@ pub fn is_business_day(
         DataType::Date => {},
         #[cfg(feature = "dtype-datetime")]
         DataType::Datetime(_, None) => {
-            return is_business_day(&dates.cast(&DataType::Date)?, week_mask, holidays);
+            return is_business_day(&dates.cast(&DataType::Date)?, week_mask, holidays, calendar);
         },
         #[cfg(feature = "timezones")]
         DataType::Datetime(_, Some(_)) => {
@@ -360,14 +455,20 @@ pub fn is_business_day(
                 &StringChunked::from_iter(std::iter::once("raise")),
                 NonExistent::Raise,
             )?;
-            return is_business_day(&dates_local.cast(&DataType::Date)?, week_mask, holidays);
+            return is_business_day(
+                &dates_local.cast(&DataType::Date)?,
+                week_mask,
+                holidays,
+                calendar,
+            );
         },
         _ => polars_bail!(InvalidOperation: "expected date or datetime, got {}", dates.dtype()),
     }
 
+    let dates = dates.date()?;
+    let holidays = with_calendar_holidays(holidays, calendar, &[dates]);
     // Sort now so we can use `binary_search` in the hot for-loop.
     let holidays = normalise_holidays(holidays, &week_mask);
-    let dates = dates.date()?;
     let out: BooleanChunked = dates.apply_nonnull_values_generic(DataType::Boolean, |date| {
         let day_of_week = get_day_of_week(date);
         // SAFETY: week_mask is length 7, day_of_week is between 0 and 6
@@ -419,9 +520,26 @@ fn roll_start_date(
     Ok((date, day_of_week))
 }
 
+/// Add the holidays of `calendar` between the earliest and latest of `dates` to `holidays`.
+fn with_calendar_holidays(
+    holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
+    dates: &[&DateChunked],
+) -> Vec<i32> {
+    let mut holidays = holidays.to_vec();
+    let Some(calendar) = calendar else {
+        return holidays;
+    };
+    let start = dates.iter().filter_map(|d| d.phys.min()).min();
+    let end = dates.iter().filter_map(|d| d.phys.max()).max();
+    if let (Some(start), Some(end)) = (start, end) {
+        holidays.extend(calendar.holidays_between(start, end));
+    }
+    holidays
+}
+
 /// Sort and deduplicate holidays and remove holidays that are not business days.
-fn normalise_holidays(holidays: &[i32], week_mask: &[bool; 7]) -> Vec<i32> {
-    let mut holidays: Vec<i32> = holidays.to_vec();
+fn normalise_holidays(mut holidays: Vec<i32>, week_mask: &[bool; 7]) -> Vec<i32> {
     holidays.sort_unstable();
     let mut previous_holiday: Option<i32> = None;
     holidays.retain(|&x| {
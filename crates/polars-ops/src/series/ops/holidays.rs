@@ -0,0 +1,317 @@
+use arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+use chrono::{Datelike, NaiveDate};
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// How the date of a holiday is determined in a given year.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum HolidayRule {
+    /// The same month and day every year, e.g. 25 December.
+    Fixed { month: u8, day: u8 },
+    /// The `n`-th `weekday` (0 is Monday) of `month`. A negative `n` counts from the end of
+    /// the month, so `-1` is the last such weekday.
+    NthWeekday { month: u8, weekday: u8, n: i8 },
+    /// A number of days relative to (Western) Easter Sunday, e.g. `-2` for Good Friday.
+    Easter { offset: i16 },
+}
+
+/// How a holiday falling on a weekend is moved to the day on which it is observed.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum Observance {
+    /// Observe the holiday on its actual date.
+    #[default]
+    None,
+    /// Saturday moves to the preceding Friday and Sunday to the following Monday.
+    NearestWeekday,
+    /// Saturday and Sunday both move to the following Monday.
+    NextMonday,
+    /// Only Sunday moves to the following Monday.
+    SundayToMonday,
+}
+
+/// A named, rule-based holiday.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct Holiday {
+    pub name: PlSmallStr,
+    pub rule: HolidayRule,
+    pub observance: Observance,
+    /// First year (inclusive) in which the holiday applies.
+    pub start_year: Option<i32>,
+    /// Last year (inclusive) in which the holiday applies.
+    pub end_year: Option<i32>,
+}
+
+impl Holiday {
+    pub fn new(name: PlSmallStr, rule: HolidayRule) -> PolarsResult<Self> {
+        match rule {
+            HolidayRule::Fixed { month, day } => {
+                polars_ensure!(
+                    NaiveDate::from_ymd_opt(2000, month as u32, day as u32).is_some(),
+                    InvalidOperation: "invalid date for holiday '{}': month {}, day {}", name, month, day
+                );
+            },
+            HolidayRule::NthWeekday { month, weekday, n } => {
+                polars_ensure!(
+                    (1..=12).contains(&month) && weekday < 7 && n != 0 && (-5..=5).contains(&n),
+                    InvalidOperation: "invalid rule for holiday '{}': month {}, weekday {}, n {}", name, month, weekday, n
+                );
+            },
+            HolidayRule::Easter { .. } => {},
+        }
+        Ok(Self {
+            name,
+            rule,
+            observance: Observance::None,
+            start_year: None,
+            end_year: None,
+        })
+    }
+
+    pub fn with_observance(mut self, observance: Observance) -> Self {
+        self.observance = observance;
+        self
+    }
+
+    /// Restrict the holiday to the years `start_year..=end_year`.
+    pub fn with_years(mut self, start_year: Option<i32>, end_year: Option<i32>) -> Self {
+        self.start_year = start_year;
+        self.end_year = end_year;
+        self
+    }
+
+    /// The date on which the holiday of `year` is observed, as the number of days since the
+    /// UNIX epoch. The observed date may fall in an adjacent year.
+    pub fn observed_in(&self, year: i32) -> Option<i32> {
+        if self.start_year.is_some_and(|start| year < start)
+            || self.end_year.is_some_and(|end| year > end)
+        {
+            return None;
+        }
+        let date = match self.rule {
+            HolidayRule::Fixed { month, day } => {
+                NaiveDate::from_ymd_opt(year, month as u32, day as u32)?
+            },
+            HolidayRule::NthWeekday { month, weekday, n } => {
+                nth_weekday_of_month(year, month as u32, weekday as u32, n)?
+            },
+            HolidayRule::Easter { offset } => {
+                easter_sunday(year)? + chrono::Duration::days(offset as i64)
+            },
+        };
+        let date = date.num_days_from_ce() - EPOCH_DAYS_FROM_CE;
+        // 0 is Monday, 6 is Sunday. 1970-01-01 was a Thursday.
+        let weekday = (date + 3).rem_euclid(7);
+        let shift = match (self.observance, weekday) {
+            (Observance::NearestWeekday, 5) => -1,
+            (Observance::NearestWeekday | Observance::SundayToMonday, 6) => 1,
+            (Observance::NextMonday, 5) => 2,
+            (Observance::NextMonday, 6) => 1,
+            _ => 0,
+        };
+        Some(date + shift)
+    }
+}
+
+/// A named set of holidays, such as an exchange or a country calendar.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct HolidayCalendar {
+    pub name: PlSmallStr,
+    pub holidays: Vec<Holiday>,
+}
+
+impl HolidayCalendar {
+    pub fn new(name: PlSmallStr, holidays: Vec<Holiday>) -> Self {
+        Self { name, holidays }
+    }
+
+    /// The observed holidays between `start` and `end` (both inclusive), given and returned as
+    /// the number of days since the UNIX epoch. The output is sorted and free of duplicates.
+    pub fn holidays_between(&self, start: i32, end: i32) -> Vec<i32> {
+        if start > end {
+            return Vec::new();
+        }
+        let year = |date: i32| {
+            NaiveDate::from_num_days_from_ce_opt(date.saturating_add(EPOCH_DAYS_FROM_CE))
+                .unwrap_or(if date < 0 {
+                    NaiveDate::MIN
+                } else {
+                    NaiveDate::MAX
+                })
+                .year()
+        };
+        // Observance can move a holiday into an adjacent year.
+        let first_year = year(start).saturating_sub(1);
+        let last_year = year(end).saturating_add(1);
+        let mut out: Vec<i32> = (first_year..=last_year)
+            .flat_map(|year| {
+                self.holidays
+                    .iter()
+                    .filter_map(move |h| h.observed_in(year))
+            })
+            .filter(|date| (start..=end).contains(date))
+            .collect();
+        out.sort_unstable();
+        out.dedup();
+        out
+    }
+}
+
+fn nth_weekday_of_month(year: i32, month: u32, weekday: u32, n: i8) -> Option<NaiveDate> {
+    if n > 0 {
+        let first = NaiveDate::from_ymd_opt(year, month, 1)?;
+        let offset = (weekday + 7 - first.weekday().num_days_from_monday()) % 7;
+        let day = 1 + offset + 7 * (n as u32 - 1);
+        NaiveDate::from_ymd_opt(year, month, day)
+    } else {
+        let (next_year, next_month) = if month == 12 {
+            (year + 1, 1)
+        } else {
+            (year, month + 1)
+        };
+        let last = NaiveDate::from_ymd_opt(next_year, next_month, 1)?.pred_opt()?;
+        let offset = (last.weekday().num_days_from_monday() + 7 - weekday) % 7;
+        let back = offset + 7 * (n.unsigned_abs() as u32 - 1);
+        (back < last.day()).then(|| last - chrono::Duration::days(back as i64))
+    }
+}
+
+/// Western Easter Sunday, computed with the anonymous Gregorian algorithm.
+fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b.div_euclid(4);
+    let e = b.rem_euclid(4);
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn day(year: i32, month: u32, day: u32) -> i32 {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .unwrap()
+            .num_days_from_ce()
+            - EPOCH_DAYS_FROM_CE
+    }
+
+    #[test]
+    fn test_holiday_rules() {
+        let easter =
+            Holiday::new("Easter Monday".into(), HolidayRule::Easter { offset: 1 }).unwrap();
+        assert_eq!(easter.observed_in(2024), Some(day(2024, 4, 1)));
+        assert_eq!(easter.observed_in(2025), Some(day(2025, 4, 21)));
+
+        let thanksgiving = Holiday::new(
+            "Thanksgiving".into(),
+            HolidayRule::NthWeekday {
+                month: 11,
+                weekday: 3,
+                n: 4,
+            },
+        )
+        .unwrap();
+        assert_eq!(thanksgiving.observed_in(2024), Some(day(2024, 11, 28)));
+
+        let memorial_day = Holiday::new(
+            "Memorial Day".into(),
+            HolidayRule::NthWeekday {
+                month: 5,
+                weekday: 0,
+                n: -1,
+            },
+        )
+        .unwrap();
+        assert_eq!(memorial_day.observed_in(2024), Some(day(2024, 5, 27)));
+
+        // 2022-01-01 was a Saturday.
+        let new_year = Holiday::new("New Year".into(), HolidayRule::Fixed { month: 1, day: 1 })
+            .unwrap()
+            .with_observance(Observance::NearestWeekday);
+        assert_eq!(new_year.observed_in(2022), Some(day(2021, 12, 31)));
+        let new_year = new_year.with_observance(Observance::NextMonday);
+        assert_eq!(new_year.observed_in(2022), Some(day(2022, 1, 3)));
+        let new_year = new_year.with_years(Some(2023), None);
+        assert_eq!(new_year.observed_in(2022), None);
+
+        assert!(Holiday::new("Invalid".into(), HolidayRule::Fixed { month: 2, day: 30 }).is_err());
+    }
+
+    #[test]
+    fn test_holidays_between() {
+        let calendar = HolidayCalendar::new(
+            "test".into(),
+            vec![
+                Holiday::new("New Year".into(), HolidayRule::Fixed { month: 1, day: 1 })
+                    .unwrap()
+                    .with_observance(Observance::NearestWeekday),
+                Holiday::new(
+                    "Christmas".into(),
+                    HolidayRule::Fixed { month: 12, day: 25 },
+                )
+                .unwrap(),
+            ],
+        );
+        assert_eq!(
+            calendar.holidays_between(day(2021, 12, 1), day(2022, 12, 31)),
+            vec![day(2021, 12, 25), day(2021, 12, 31), day(2022, 12, 25)]
+        );
+        assert!(
+            calendar
+                .holidays_between(day(2022, 1, 2), day(2022, 1, 1))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_add_business_days_dense_calendar() {
+        // Only Mondays are business days, and most of them are holidays, so the result
+        // lies far beyond the span needed without holidays.
+        let holidays = (1..=11)
+            .flat_map(|month| (1..=28).map(move |day| (month, day)))
+            .map(|(month, day)| {
+                Holiday::new("Closed".into(), HolidayRule::Fixed { month, day }).unwrap()
+            })
+            .collect();
+        let calendar = HolidayCalendar::new("sparse".into(), holidays);
+        let week_mask = [true, false, false, false, false, false, false];
+
+        let start = day(2024, 1, 1);
+        let is_open = |date: i32| {
+            (date + 3).rem_euclid(7) == 0 && calendar.holidays_between(date, date).is_empty()
+        };
+        let expected: Vec<i32> = (start..).filter(|&date| is_open(date)).take(11).collect();
+
+        let out = crate::series::add_business_days(
+            &Int32Chunked::from_slice("start".into(), &[start])
+                .into_date()
+                .into_series(),
+            &Series::new("n".into(), [10i32]),
+            week_mask,
+            &[],
+            Some(&calendar),
+            crate::series::Roll::Forward,
+        )
+        .unwrap();
+        assert_eq!(out.date().unwrap().phys.get(0), Some(expected[10]));
+    }
+}
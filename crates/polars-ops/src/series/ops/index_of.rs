@@ -1,3 +1,5 @@
+#[cfg(feature = "dtype-fixed-size-binary")]
+use arrow::array::FixedSizeBinaryArray;
 use arrow::array::{BinaryArray, BinaryViewArray, PrimitiveArray};
 use polars_core::downcast_as_macro_arg_physical;
 use polars_core::prelude::*;
@@ -68,6 +70,12 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         needle.dtype(),
     );
 
+    #[cfg(feature = "dtype-extension")]
+    if let Some(ca) = series.try_extension() {
+        let needle = Scalar::new(ca.storage().dtype().clone(), needle.into_value());
+        return index_of(ca.storage(), needle);
+    }
+
     if series.is_empty() {
         return Ok(None);
     }
@@ -123,6 +131,11 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
             series.binary_offset()?,
             needle.value().extract_bytes().unwrap(),
         )),
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        DT::FixedSizeBinary(_) => Ok(index_of_value::<_, FixedSizeBinaryArray>(
+            series.fixed_size_binary()?,
+            needle.value().extract_bytes().unwrap(),
+        )),
         DT::Array(_, _) | DT::List(_) | DT::Struct(_) => {
             // For non-numeric dtypes, we convert to row-encoding, which essentially has
             // us searching the physical representation of the data as a series of
@@ -145,6 +158,8 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         | DT::Int32
         | DT::Int64
         | DT::Int128
+        | DT::UInt128
+        | DT::Float16
         | DT::Float32
         | DT::Float64 => unreachable!("primitive numeric"),
 
@@ -153,6 +168,18 @@ pub fn index_of(series: &Series, needle: Scalar) -> PolarsResult<Option<usize>>
         DT::Decimal(..) => unreachable!(),
         #[cfg(feature = "dtype-categorical")]
         DT::Categorical(..) | DT::Enum(..) => unreachable!(),
+        #[cfg(feature = "dtype-map")]
+        DT::Map(..) => unreachable!(),
+        #[cfg(feature = "dtype-union")]
+        DT::Union(..) => unreachable!(),
+        #[cfg(feature = "dtype-interval")]
+        DT::Interval => unreachable!(),
+        #[cfg(feature = "dtype-uuid")]
+        DT::Uuid => unreachable!(),
+        #[cfg(feature = "dtype-json")]
+        DT::Json => unreachable!(),
+        #[cfg(feature = "dtype-extension")]
+        DT::Extension { .. } => unreachable!(),
         DT::Date | DT::Datetime(..) | DT::Duration(..) | DT::Time => unreachable!(),
 
         DT::Object(_) | DT::Unknown(_) => polars_bail!(op = "index_of", series.dtype()),
@@ -0,0 +1,214 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use polars_core::prelude::*;
+
+fn ip_from_bytes(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes.len() {
+        4 => Some(IpAddr::from(<[u8; 4]>::try_from(bytes).unwrap())),
+        16 => Some(IpAddr::from(<[u8; 16]>::try_from(bytes).unwrap())),
+        _ => None,
+    }
+}
+
+/// Iterate over the IP addresses in `s`, which are read from:
+/// - strings in their textual form,
+/// - binary values of 4 (IPv4) or 16 (IPv6) bytes in network byte order,
+/// - `UInt32` (IPv4) and `UInt128` (IPv6) integers.
+///
+/// Values that are not a valid address yield `None`. IPv4-mapped IPv6 addresses
+/// (`::ffff:a.b.c.d`) yield their IPv4 address.
+fn ip_iter(s: &Series) -> PolarsResult<Box<dyn Iterator<Item = Option<IpAddr>> + '_>> {
+    let iter: Box<dyn Iterator<Item = Option<IpAddr>>> = match s.dtype() {
+        DataType::String => Box::new(
+            s.str()?
+                .iter()
+                .map(|opt_v| opt_v.and_then(|v| v.parse().ok())),
+        ),
+        DataType::Binary => Box::new(
+            s.binary()?
+                .iter()
+                .map(|opt_v| opt_v.and_then(ip_from_bytes)),
+        ),
+        DataType::UInt32 => Box::new(
+            s.u32()?
+                .iter()
+                .map(|opt_v| opt_v.map(|v| IpAddr::from(Ipv4Addr::from(v)))),
+        ),
+        DataType::UInt128 => Box::new(
+            s.u128()?
+                .iter()
+                .map(|opt_v| opt_v.map(|v| IpAddr::from(Ipv6Addr::from(v)))),
+        ),
+        dt => polars_bail!(
+            InvalidOperation: "expected String, Binary, UInt32 or UInt128 IP addresses, got {}", dt
+        ),
+    };
+    Ok(Box::new(
+        iter.map(|opt_ip| opt_ip.map(|ip| ip.to_canonical())),
+    ))
+}
+
+/// Convert IP addresses to integers.
+///
+/// With `ipv6` unset the output is `UInt32` and IPv6 addresses are null, otherwise it is
+/// `UInt128` and IPv4 addresses are mapped into IPv6 (`::ffff:a.b.c.d`).
+pub fn ip_to_int(s: &Series, ipv6: bool) -> PolarsResult<Series> {
+    let iter = ip_iter(s)?;
+    let out = if ipv6 {
+        let ca: UInt128Chunked = iter
+            .map(|opt_ip| {
+                opt_ip.map(|ip| match ip {
+                    IpAddr::V4(ip) => ip.to_ipv6_mapped().to_bits(),
+                    IpAddr::V6(ip) => ip.to_bits(),
+                })
+            })
+            .collect();
+        ca.into_series()
+    } else {
+        let ca: UInt32Chunked = iter
+            .map(|opt_ip| match opt_ip {
+                Some(IpAddr::V4(ip)) => Some(ip.to_bits()),
+                _ => None,
+            })
+            .collect();
+        ca.into_series()
+    };
+    Ok(out.with_name(s.name().clone()))
+}
+
+/// Convert IP addresses to their 4 (IPv4) or 16 (IPv6) bytes in network byte order.
+pub fn ip_to_binary(s: &Series) -> PolarsResult<BinaryChunked> {
+    let ca: BinaryChunked = ip_iter(s)?
+        .map(|opt_ip| {
+            opt_ip.map(|ip| match ip {
+                IpAddr::V4(ip) => ip.octets().to_vec(),
+                IpAddr::V6(ip) => ip.octets().to_vec(),
+            })
+        })
+        .collect();
+    Ok(ca.with_name(s.name().clone()))
+}
+
+/// Format IP addresses in their canonical textual form.
+pub fn ip_to_string(s: &Series) -> PolarsResult<StringChunked> {
+    let ca: StringChunked = ip_iter(s)?
+        .map(|opt_ip| opt_ip.map(|ip| ip.to_string()))
+        .collect();
+    Ok(ca.with_name(s.name().clone()))
+}
+
+fn parse_network(cidr: &str) -> PolarsResult<(IpAddr, u32)> {
+    let (addr, prefix) = match cidr.split_once('/') {
+        Some((addr, prefix)) => (addr, Some(prefix)),
+        None => (cidr, None),
+    };
+    let network = addr.parse::<IpAddr>().ok().map(|ip| ip.to_canonical());
+    let max_prefix = match network {
+        Some(IpAddr::V4(_)) => 32,
+        _ => 128,
+    };
+    let prefix = match prefix {
+        Some(prefix) => prefix.parse::<u32>().ok(),
+        None => Some(max_prefix),
+    };
+    match (network, prefix) {
+        (Some(network), Some(prefix)) if prefix <= max_prefix => Ok((network, prefix)),
+        _ => polars_bail!(ComputeError: "invalid CIDR network '{}'", cidr),
+    }
+}
+
+/// Check whether IP addresses lie in the network given in CIDR notation, e.g. `10.0.0.0/8`.
+///
+/// Host bits set in the network address are ignored and a network without a prefix length is a
+/// single address. IPv4 networks only contain IPv4 addresses and IPv6 networks only IPv6
+/// addresses; addresses that are null or invalid give null.
+pub fn ip_in_network(s: &Series, cidr: &str) -> PolarsResult<BooleanChunked> {
+    let (network, prefix) = parse_network(cidr)?;
+    let ca: BooleanChunked = ip_iter(s)?
+        .map(|opt_ip| {
+            opt_ip.map(|ip| match (ip, network) {
+                (IpAddr::V4(ip), IpAddr::V4(network)) => {
+                    let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+                    (ip.to_bits() ^ network.to_bits()) & mask == 0
+                },
+                (IpAddr::V6(ip), IpAddr::V6(network)) => {
+                    let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+                    (ip.to_bits() ^ network.to_bits()) & mask == 0
+                },
+                _ => false,
+            })
+        })
+        .collect();
+    Ok(ca.with_name(s.name().clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ip_round_trip() -> PolarsResult<()> {
+        let s = Series::new(
+            "ip".into(),
+            [
+                Some("192.168.0.1"),
+                Some("2001:db8::1"),
+                Some("::ffff:10.0.0.1"),
+                Some("not an ip"),
+                None,
+            ],
+        );
+        let v4 = ip_to_int(&s, false)?;
+        assert_eq!(
+            Vec::from(v4.u32()?),
+            &[Some(0xc0a8_0001), None, Some(0x0a00_0001), None, None]
+        );
+        let v6 = ip_to_int(&s, true)?;
+        assert_eq!(v6.u128()?.get(1), Some(0x2001_0db8 << 96 | 1));
+
+        let expected = [
+            Some("192.168.0.1"),
+            Some("2001:db8::1"),
+            Some("10.0.0.1"),
+            None,
+            None,
+        ];
+        assert_eq!(Vec::from(&ip_to_string(&v6)?), &expected);
+        let bin = ip_to_binary(&s)?;
+        assert_eq!(bin.get(0), Some(&[192, 168, 0, 1][..]));
+        assert_eq!(Vec::from(&ip_to_string(&bin.into_series())?), &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ip_in_network() -> PolarsResult<()> {
+        let s = Series::new(
+            "ip".into(),
+            [
+                Some("10.1.2.3"),
+                Some("11.0.0.1"),
+                Some("::ffff:10.0.0.1"),
+                Some("fd00::1"),
+                None,
+            ],
+        );
+        let out = ip_in_network(&s, "10.0.0.0/8")?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(true), Some(false), Some(true), Some(false), None]
+        );
+        let out = ip_in_network(&s, "fc00::/7")?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), Some(false), Some(false), Some(true), None]
+        );
+        let out = ip_in_network(&s, "0.0.0.0/0")?;
+        assert_eq!(out.sum(), Some(3));
+        let out = ip_in_network(&s, "10.1.2.3")?;
+        assert_eq!(out.sum(), Some(1));
+
+        assert!(ip_in_network(&s, "10.0.0.0/33").is_err());
+        assert!(ip_in_network(&s, "10.0.0/8").is_err());
+        Ok(())
+    }
+}
@@ -412,11 +412,16 @@ fn is_in_cat_and_enum(
     other: &Series,
     nulls_equal: bool,
 ) -> PolarsResult<BooleanChunked> {
-    use std::borrow::Cow;
-
-    use arrow::array::{Array, FixedSizeListArray, IntoBoxedArray, ListArray};
-
-    let mut needs_remap = false;
+    // Categoricals with different mappings are compared by remapping the physicals of `other`
+    // onto those of `ca_in`. Categories that `ca_in` doesn't have can never match.
+    let remap = match (ca_in.dtype(), other.dtype().inner_dtype().unwrap()) {
+        (DataType::Categorical(Some(revmap), _), DataType::Categorical(Some(other_revmap), _))
+            if !revmap.same_src(other_revmap) =>
+        {
+            Some(other_revmap.physical_map_to(revmap))
+        },
+        _ => None,
+    };
     let to_categories = match (ca_in.dtype(), other.dtype().inner_dtype().unwrap()) {
         (DataType::Enum(revmap, ordering), DataType::String) => {
             let categories = revmap.as_deref().unwrap().get_categories();
@@ -449,10 +454,15 @@ fn is_in_cat_and_enum(
             let (Some(revmap), Some(other_revmap)) = (revmap, other_revmap) else {
                 polars_bail!(ComputeError: "expected revmap to be set at this point");
             };
-            needs_remap = !revmap.same_src(other_revmap);
+            debug_assert_eq!(remap.is_none(), revmap.same_src(other_revmap));
             (&|s: Series| {
                 let ca = s.categorical()?;
-                let ca = ca.physical().clone();
+                let ca = match &remap {
+                    Some(remap) => ca
+                        .physical()
+                        .apply_values(|v| remap.get(&v).copied().unwrap_or(u32::MAX)),
+                    None => ca.physical().clone(),
+                };
                 Ok(ca.into_series())
             }) as _
         },
@@ -475,76 +485,10 @@ fn is_in_cat_and_enum(
         _ => polars_bail!(opq = is_in, ca_in.dtype(), other.dtype()),
     };
 
-    let mut ca_in = Cow::Borrowed(ca_in);
     let other = match other.dtype() {
-        DataType::List(_) => {
-            let mut other = Cow::Borrowed(other.list()?);
-            if needs_remap {
-                let other_rechunked = other.rechunk();
-                let other_arr = other_rechunked.downcast_as_array();
-
-                let other_inner = other.get_inner();
-                let other_offsets = other_arr.offsets().clone();
-                let other_inner = other_inner.categorical()?;
-                let (ca_in_remapped, other_inner) =
-                    make_rhs_categoricals_compatible(&ca_in, other_inner)?;
-
-                let other_inner_phys = other_inner.physical().rechunk();
-                let other_inner_phys = other_inner_phys.downcast_as_array();
-
-                let other_phys = ListArray::try_new(
-                    other_arr.dtype().clone(),
-                    other_offsets,
-                    other_inner_phys.clone().into_boxed(),
-                    other_arr.validity().cloned(),
-                )?;
-
-                other = Cow::Owned(unsafe {
-                    ListChunked::from_chunks_and_dtype(
-                        other.name().clone(),
-                        vec![other_phys.into_boxed()],
-                        DataType::List(Box::new(other_inner.dtype().clone())),
-                    )
-                });
-                ca_in = Cow::Owned(ca_in_remapped);
-            }
-            let other = other.apply_to_inner(to_categories)?;
-            other.into_series()
-        },
+        DataType::List(_) => other.list()?.apply_to_inner(to_categories)?.into_series(),
         #[cfg(feature = "dtype-array")]
-        DataType::Array(_, _) => {
-            let mut other = Cow::Borrowed(other.array()?);
-            if needs_remap {
-                let other_rechunked = other.rechunk();
-                let other_arr = other_rechunked.downcast_as_array();
-
-                let other_inner = other.get_inner();
-                let other_inner = other_inner.categorical()?;
-                let (ca_in_remapped, other_inner) =
-                    make_rhs_categoricals_compatible(&ca_in, other_inner)?;
-
-                let other_inner_phys = other_inner.physical().rechunk();
-                let other_inner_phys = other_inner_phys.downcast_as_array();
-
-                let other_phys = FixedSizeListArray::try_new(
-                    other_arr.dtype().clone(),
-                    other.len(),
-                    other_inner_phys.clone().into_boxed(),
-                    other_arr.validity().cloned(),
-                )?;
-
-                other = Cow::Owned(unsafe {
-                    ArrayChunked::from_chunks_and_dtype(
-                        other.name().clone(),
-                        vec![other_phys.into_boxed()],
-                        DataType::Array(Box::new(other_inner.dtype().clone()), other.width()),
-                    )
-                });
-                ca_in = Cow::Owned(ca_in_remapped);
-            }
-            let other = other.apply_to_inner(to_categories)?;
-            other.into_series()
-        },
+        DataType::Array(_, _) => other.array()?.apply_to_inner(to_categories)?.into_series(),
         _ => polars_bail!(opq = is_in, ca_in.dtype(), other.dtype()),
     };
 
@@ -748,6 +692,8 @@ pub fn is_in(s: &Series, other: &Series, nulls_equal: bool) -> PolarsResult<Bool
             let ca_in = s.decimal()?;
             is_in_decimal(ca_in, other, nulls_equal)
         },
+        #[cfg(feature = "dtype-fixed-size-binary")]
+        DataType::FixedSizeBinary(_) => is_in_row_encoded(s, other, nulls_equal),
         dt if dt.is_nested() => is_in_row_encoded(s, other, nulls_equal),
         dt if dt.to_physical().is_primitive_numeric() => {
             let s = s.to_physical_repr();
@@ -19,6 +19,8 @@ mod ewm_by;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "business")]
+mod holidays;
 mod horizontal;
 mod index;
 #[cfg(feature = "index_of")]
@@ -26,6 +28,8 @@ mod index_of;
 mod int_range;
 #[cfg(any(feature = "interpolate_by", feature = "interpolate"))]
 mod interpolation;
+#[cfg(feature = "net")]
+mod ip;
 #[cfg(feature = "is_between")]
 mod is_between;
 #[cfg(feature = "is_first_distinct")]
@@ -58,6 +62,8 @@ mod rolling;
 pub mod round;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+#[cfg(feature = "stable_hash")]
+mod stable_hash;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
@@ -85,6 +91,8 @@ pub use ewm_by::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(feature = "business")]
+pub use holidays::*;
 pub use horizontal::*;
 pub use index::*;
 #[cfg(feature = "index_of")]
@@ -96,6 +104,8 @@ pub use interpolation::interpolate::*;
 pub use interpolation::interpolate_by::*;
 #[cfg(any(feature = "interpolate", feature = "interpolate_by"))]
 pub use interpolation::*;
+#[cfg(feature = "net")]
+pub use ip::*;
 #[cfg(feature = "is_between")]
 pub use is_between::*;
 #[cfg(feature = "is_first_distinct")]
@@ -130,6 +140,8 @@ pub use rolling::*;
 pub use round::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+#[cfg(feature = "stable_hash")]
+pub use stable_hash::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
@@ -1,3 +1,4 @@
+use num_traits::AsPrimitive;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
 #[cfg(feature = "serde")]
@@ -15,6 +16,8 @@ pub enum RoundMode {
     #[default]
     HalfToEven,
     HalfAwayFromZero,
+    /// Drop the digits beyond the requested precision, i.e. round towards zero.
+    Truncate,
 }
 
 pub trait RoundSeries: SeriesSealed {
@@ -76,6 +79,29 @@ pub trait RoundSeries: SeriesSealed {
                         Ok(s)
                     };
                 },
+                RoundMode::Truncate => {
+                    return if decimals == 0 {
+                        let s = ca.apply_values(|val| val.trunc()).into_series();
+                        Ok(s)
+                    } else if decimals >= 326 {
+                        // More precise than smallest denormal.
+                        Ok(s.clone())
+                    } else {
+                        let multiplier = 10.0_f64.powi(decimals as i32);
+                        let s = ca
+                            .apply_values(|val| {
+                                let ret = ((val as f64 * multiplier).trunc() / multiplier) as f32;
+                                if ret.is_finite() {
+                                    ret
+                                } else {
+                                    // We return the original value which is correct both for overflows and non-finite inputs.
+                                    val
+                                }
+                            })
+                            .into_series();
+                        Ok(s)
+                    };
+                },
             }
         }
         if let Ok(ca) = s.f64() {
@@ -162,6 +188,47 @@ pub trait RoundSeries: SeriesSealed {
                         Ok(s)
                     };
                 },
+                RoundMode::Truncate => {
+                    return if decimals == 0 {
+                        let s = ca.apply_values(|val| val.trunc()).into_series();
+                        Ok(s)
+                    } else if decimals >= 326 {
+                        // More precise than smallest denormal.
+                        Ok(s.clone())
+                    } else if decimals >= 300 {
+                        // We're getting into unrepresentable territory for the multiplier
+                        // here, split up the 10^n multiplier into 2^n and 5^n.
+                        let mul2 = libm::scalbn(1.0, decimals as i32);
+                        let invmul2 = 1.0 / mul2; // Still exact for any valid value of decimals.
+                        let mul5 = 5.0_f64.powi(decimals as i32);
+                        let s = ca
+                            .apply_values(|val| {
+                                let ret = (val * mul2 * mul5).trunc() / mul5 * invmul2;
+                                if ret.is_finite() {
+                                    ret
+                                } else {
+                                    // We return the original value which is correct both for overflows and non-finite inputs.
+                                    val
+                                }
+                            })
+                            .into_series();
+                        Ok(s)
+                    } else {
+                        let multiplier = 10.0_f64.powi(decimals as i32);
+                        let s = ca
+                            .apply_values(|val| {
+                                let ret = (val * multiplier).trunc() / multiplier;
+                                if ret.is_finite() {
+                                    ret
+                                } else {
+                                    // We return the original value which is correct both for overflows and non-finite inputs.
+                                    val
+                                }
+                            })
+                            .into_series();
+                        Ok(s)
+                    };
+                },
             }
         }
         #[cfg(feature = "dtype-decimal")]
@@ -205,6 +272,7 @@ pub trait RoundSeries: SeriesSealed {
                     };
                     v - rem + round_offset
                 }),
+                RoundMode::Truncate => ca.apply_values(|v| v - v % multiplier),
             };
             return Ok(res
                 .into_decimal_unchecked(ca.precision(), scale as usize)
@@ -263,9 +331,9 @@ pub trait RoundSeries: SeriesSealed {
         with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
             let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
             let s = ca.apply_values(|value| {
-                let value = value as f64;
+                let value: f64 = value.as_();
                 if value == 0.0 {
-                    return value as <$T as PolarsNumericType>::Native;
+                    return value.as_();
                 }
                 // To deal with very large/small numbers we split up 10^n in 5^n and 2^n.
                 // The scaling by 2^n is almost always lossless.
@@ -274,9 +342,9 @@ pub trait RoundSeries: SeriesSealed {
                 let scaled = libm::scalbn(value, exp) * pow5;
                 let descaled = libm::scalbn(scaled.round() / pow5, -exp);
                 if descaled.is_finite() {
-                    descaled as <$T as PolarsNumericType>::Native
+                    descaled.as_()
                 } else {
-                    value as <$T as PolarsNumericType>::Native
+                    value.as_()
                 }
             }).into_series();
             return Ok(s);
@@ -375,4 +443,20 @@ mod test {
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
     }
+
+    #[test]
+    #[cfg(feature = "dtype-decimal")]
+    fn test_round_decimal_modes() {
+        let s = Int128Chunked::from_slice(PlSmallStr::from_static("a"), &[125, 135, -125, 129])
+            .into_decimal_unchecked(Some(5), 2)
+            .into_series();
+        let round = |mode| {
+            let out = s.round(1, mode).unwrap();
+            let ca = out.decimal().unwrap();
+            ca.physical().into_no_null_iter().collect::<Vec<_>>()
+        };
+        assert_eq!(round(RoundMode::HalfToEven), [120, 140, -120, 130]);
+        assert_eq!(round(RoundMode::HalfAwayFromZero), [130, 140, -130, 130]);
+        assert_eq!(round(RoundMode::Truncate), [120, 130, -120, 120]);
+    }
 }
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_integer_polars_type;
+use polars_utils::total_ord::{canonical_f32, canonical_f64};
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+const NULL_TAG: u8 = 0;
+const VALUE_TAG: u8 = 1;
+
+fn fold_column<T>(
+    hashes: &mut [u64],
+    values: impl Iterator<Item = Option<T>>,
+    mut write: impl FnMut(&mut Vec<u8>, T),
+) {
+    let mut buf = Vec::new();
+    for (h, opt_v) in hashes.iter_mut().zip(values) {
+        buf.clear();
+        match opt_v {
+            None => buf.push(NULL_TAG),
+            Some(v) => {
+                buf.push(VALUE_TAG);
+                write(&mut buf, v);
+            },
+        }
+        *h = xxh3_64_with_seed(&buf, *h);
+    }
+}
+
+fn write_le<T: NativeType>(buf: &mut Vec<u8>, v: T) {
+    buf.extend_from_slice(v.to_le_bytes().as_ref())
+}
+
+/// Hash the rows of `columns` into a `UInt64` that is stable across runs, platforms and
+/// versions.
+///
+/// Every row starts from `seed`; the columns are then folded in order, each value hashed
+/// with XXH3 (64-bit) seeded by the hash so far. A value is encoded as a `0` byte when
+/// null, otherwise as a `1` byte followed by:
+/// - booleans: a single `0` or `1` byte,
+/// - numbers: the little-endian bytes of the value, with `-0.0` and NaNs canonicalized,
+/// - strings and binary: the raw bytes,
+/// - categoricals and enums: the bytes of the string value,
+/// - other logical types: the encoding of their physical representation.
+///
+/// Columns of length 1 are broadcast. Nested types are not supported.
+pub fn stable_row_hashes(columns: &[Column], seed: u64) -> PolarsResult<UInt64Chunked> {
+    polars_ensure!(!columns.is_empty(), ComputeError: "cannot hash rows of zero columns");
+    let len = columns.iter().map(|c| c.len()).max().unwrap();
+    polars_ensure!(
+        columns.iter().all(|c| c.len() == len || c.len() == 1),
+        ShapeMismatch: "all columns must have the same length to hash their rows"
+    );
+
+    let mut hashes = vec![seed; len];
+    for c in columns {
+        let c = if c.len() == len {
+            Cow::Borrowed(c)
+        } else {
+            Cow::Owned(c.new_from_index(0, len))
+        };
+        let s = c.as_materialized_series();
+        let s = if s.dtype().is_categorical() || s.dtype().is_enum() {
+            s.cast(&DataType::String)?
+        } else {
+            s.to_physical_repr().into_owned()
+        };
+
+        match s.dtype() {
+            DataType::Null => {
+                fold_column::<()>(&mut hashes, std::iter::repeat_n(None, len), |_, _| {})
+            },
+            DataType::Boolean => {
+                fold_column(&mut hashes, s.bool()?.iter(), |buf, v| buf.push(v as u8))
+            },
+            DataType::String => fold_column(&mut hashes, s.str()?.iter(), |buf, v| {
+                buf.extend_from_slice(v.as_bytes())
+            }),
+            DataType::Binary => fold_column(&mut hashes, s.binary()?.iter(), |buf, v| {
+                buf.extend_from_slice(v)
+            }),
+            DataType::Float32 => fold_column(&mut hashes, s.f32()?.iter(), |buf, v| {
+                write_le(buf, canonical_f32(v))
+            }),
+            DataType::Float64 => fold_column(&mut hashes, s.f64()?.iter(), |buf, v| {
+                write_le(buf, canonical_f64(v))
+            }),
+            dt if dt.is_integer() => with_match_physical_integer_polars_type!(dt, |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                fold_column(&mut hashes, ca.iter(), write_le)
+            }),
+            _ => polars_bail!(
+                InvalidOperation: "cannot compute a stable hash of column '{}' with dtype {}",
+                c.name(), c.dtype()
+            ),
+        }
+    }
+    Ok(UInt64Chunked::from_vec(columns[0].name().clone(), hashes))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stable_row_hashes() -> PolarsResult<()> {
+        let a = Column::new("a".into(), [Some(1i64), None, Some(1)]);
+        let b = Column::new("b".into(), [Some("x"), Some("x"), Some("")]);
+        let out = stable_row_hashes(&[a.clone(), b.clone()], 0)?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(out.null_count(), 0);
+
+        let expected = {
+            let h = xxh3_64_with_seed(&[VALUE_TAG, 1, 0, 0, 0, 0, 0, 0, 0], 0);
+            xxh3_64_with_seed(&[VALUE_TAG, b'x'], h)
+        };
+        assert_eq!(out.get(0), Some(expected));
+
+        // Nulls and empty strings do not collide.
+        let c = Column::new("c".into(), [None::<&str>, None, None]);
+        let out_null = stable_row_hashes(&[a.clone(), c], 0)?;
+        let empty = Column::new("c".into(), ["", "", ""]);
+        let out_empty = stable_row_hashes(&[a.clone(), empty], 0)?;
+        assert_ne!(out_null.get(0), out_empty.get(0));
+
+        // The seed and the column order matter.
+        assert_ne!(
+            stable_row_hashes(&[a.clone(), b.clone()], 1)?.get(0),
+            Some(expected)
+        );
+        assert_ne!(stable_row_hashes(&[b, a], 0)?.get(0), Some(expected));
+
+        let f = Column::new("f".into(), [0.0f64, -0.0, f64::NAN, -f64::NAN]);
+        let out = stable_row_hashes(&[f], 0)?;
+        assert_eq!(out.get(0), out.get(1));
+        assert_eq!(out.get(2), out.get(3));
+        Ok(())
+    }
+}
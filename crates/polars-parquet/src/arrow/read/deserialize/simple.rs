@@ -3,7 +3,7 @@ use arrow::bitmap::Bitmap;
 use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL, DTYPE_ENUM_VALUES, Field, IntegerType, IntervalUnit, TimeUnit,
 };
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, i256, months_days_ns};
 use ethnum::I256;
 use polars_compute::cast::CastOptionsImpl;
 
@@ -338,6 +338,81 @@ pub fn page_iter_to_array(
         )?
         .collect_boxed(filter)?,
 
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
+            let (nested, mut fsb_array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(2),
+                fixed_size_binary::BinaryDecoder { size: 2 },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let validity = fsb_array.take_validity();
+            let values = fsb_array
+                .values()
+                .as_slice()
+                .chunks_exact(2)
+                .map(|v| arrow::types::f16::from_le_bytes([v[0], v[1]]))
+                .collect();
+
+            (
+                nested,
+                PrimitiveArray::<arrow::types::f16>::new(dtype, values, validity).to_boxed(),
+                ptm,
+            )
+        },
+        (PhysicalType::FixedLenByteArray(16), Interval(IntervalUnit::MonthDayNano)) => {
+            let (nested, mut fsb_array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(16),
+                fixed_size_binary::BinaryDecoder { size: 16 },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let validity = fsb_array.take_validity();
+            let values = fsb_array
+                .values()
+                .as_slice()
+                .chunks_exact(16)
+                // SAFETY: We know that every chunk is of size 16.
+                .map(|v| months_days_ns::from_le_bytes(unsafe { v.try_into().unwrap_unchecked() }))
+                .collect();
+
+            (
+                nested,
+                PrimitiveArray::<months_days_ns>::new(dtype, values, validity).to_boxed(),
+                ptm,
+            )
+        },
+        (PhysicalType::FixedLenByteArray(16), UInt128) => {
+            let (nested, mut fsb_array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(16),
+                fixed_size_binary::BinaryDecoder { size: 16 },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let validity = fsb_array.take_validity();
+            let values = fsb_array
+                .values()
+                .as_slice()
+                .chunks_exact(16)
+                // SAFETY: We know that every chunk is of size 16.
+                .map(|v| u128::from_be_bytes(unsafe { v.try_into().unwrap_unchecked() }))
+                .collect();
+
+            (
+                nested,
+                PrimitiveArray::<u128>::new(dtype, values, validity).to_boxed(),
+                ptm,
+            )
+        },
+
         // Float16
         (PhysicalType::FixedLenByteArray(2), Float32) => {
             // @NOTE: To reduce code bloat, we just use the FixedSizeBinary decoder.
@@ -1,5 +1,5 @@
 //! This module has entry points, [`parquet_to_arrow_schema`] and the more configurable [`parquet_to_arrow_schema_with_options`].
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, TimeUnit};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::arrow::read::schema::SchemaInferenceOptions;
@@ -147,16 +147,25 @@ fn from_int64(
     }
 }
 
+/// JSON is exported as the canonical `arrow.json` extension type over UTF-8 text.
+fn json_extension_type() -> ArrowDataType {
+    ArrowDataType::Extension(Box::new(ExtensionType {
+        name: "arrow.json".into(),
+        inner: ArrowDataType::Utf8View,
+        metadata: None,
+    }))
+}
+
 fn from_byte_array(
     logical_type: &Option<PrimitiveLogicalType>,
     converted_type: &Option<PrimitiveConvertedType>,
 ) -> ArrowDataType {
     match (logical_type, converted_type) {
         (Some(PrimitiveLogicalType::String), _) => ArrowDataType::Utf8View,
-        (Some(PrimitiveLogicalType::Json), _) => ArrowDataType::BinaryView,
+        (Some(PrimitiveLogicalType::Json), _) => json_extension_type(),
         (Some(PrimitiveLogicalType::Bson), _) => ArrowDataType::BinaryView,
         (Some(PrimitiveLogicalType::Enum), _) => ArrowDataType::BinaryView,
-        (_, Some(PrimitiveConvertedType::Json)) => ArrowDataType::BinaryView,
+        (_, Some(PrimitiveConvertedType::Json)) => json_extension_type(),
         (_, Some(PrimitiveConvertedType::Bson)) => ArrowDataType::BinaryView,
         (_, Some(PrimitiveConvertedType::Enum)) => ArrowDataType::BinaryView,
         (_, Some(PrimitiveConvertedType::Utf8)) => ArrowDataType::Utf8View,
@@ -176,6 +185,20 @@ fn from_fixed_len_byte_array(
         (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) => {
             ArrowDataType::Decimal(precision, scale)
         },
+        (Some(PrimitiveLogicalType::Float16), _) => {
+            if cfg!(feature = "dtype-f16") {
+                ArrowDataType::Float16
+            } else {
+                ArrowDataType::Float32
+            }
+        },
+        (Some(PrimitiveLogicalType::Uuid), _) if length == 16 => {
+            ArrowDataType::Extension(Box::new(ExtensionType {
+                name: "arrow.uuid".into(),
+                inner: ArrowDataType::FixedSizeBinary(length),
+                metadata: None,
+            }))
+        },
         (None, Some(PrimitiveConvertedType::Interval)) => {
             // There is currently no reliable way of determining which IntervalUnit
             // to return. Thus without the original Arrow schema, the results
@@ -244,7 +267,7 @@ fn non_repeated_group(
     match (logical_type, converted_type) {
         (Some(GroupLogicalType::List), _) => to_list(fields, parent_name, options),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name, options),
-        (Some(GroupLogicalType::Map), _) => to_list(fields, parent_name, options),
+        (Some(GroupLogicalType::Map), _) => to_list(fields, parent_name, options).map(list_to_map),
         (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
             to_map(fields, options)
         },
@@ -266,6 +289,17 @@ fn to_struct(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option
     }
 }
 
+/// Converts the list that a parquet `MAP` is read as to an arrow [`ArrowDataType::Map`] if the
+/// entries have both a key and a value.
+fn list_to_map(dtype: ArrowDataType) -> ArrowDataType {
+    match dtype {
+        ArrowDataType::LargeList(inner) if matches!(inner.dtype(), ArrowDataType::Struct(fields) if fields.len() == 2) => {
+            ArrowDataType::Map(inner, false)
+        },
+        dtype => dtype,
+    }
+}
+
 /// Converts a parquet group type to an arrow [`ArrowDataType::Struct`].
 /// Returns [`None`] if all its fields are empty
 fn to_map(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option<ArrowDataType> {
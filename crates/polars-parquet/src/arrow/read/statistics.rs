@@ -5,7 +5,7 @@ use arrow::array::{
     PrimitiveArray, Utf8ViewArray,
 };
 use arrow::datatypes::{ArrowDataType, Field, IntegerType, IntervalUnit, TimeUnit};
-use arrow::types::{NativeType, days_ms, f16, i256};
+use arrow::types::{NativeType, days_ms, f16, i256, months_days_ns};
 use ethnum::I256;
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
@@ -205,6 +205,16 @@ impl ColumnStatistics {
             // some implementations of parquet write arrow's u32 into i64.
             (D::UInt32, PPT::Int64) => rmap!(expect_int64, @prim i64 as u32),
             (D::UInt64, _) => rmap!(expect_int64, @prim i64 as u64),
+            (D::Interval(IntervalUnit::MonthDayNano), PPT::FixedLenByteArray(16)) => rmap!(
+                expect_fixedlen,
+                @prim Vec<u8>,
+                |x| months_days_ns::from_le_bytes(x.try_into().unwrap())
+            ),
+            (D::UInt128, PPT::FixedLenByteArray(16)) => rmap!(
+                expect_fixedlen,
+                @prim Vec<u8>,
+                |x| u128::from_be_bytes(x.try_into().unwrap())
+            ),
 
             (D::Timestamp(time_unit, _), PPT::Int96) => {
                 rmap!(expect_int96, @prim [u32; 3], |x| {
@@ -217,7 +227,10 @@ impl ColumnStatistics {
                 })
             },
 
-            // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+            (D::Float16, PPT::FixedLenByteArray(2)) => {
+                rmap!(expect_fixedlen, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+            },
+            // Read Float16 to a Float32 if the f16 type is not requested.
             (_, PPT::FixedLenByteArray(2))
                 if matches!(
                     self.logical_type.as_ref(),
@@ -456,6 +469,18 @@ pub fn deserialize_all(
                 (D::UInt64, _) => {
                     rmap!(expect_int64, MutablePrimitiveArray::<u64>, @prim i64 as u64)
                 },
+                (D::Interval(IntervalUnit::MonthDayNano), PPT::FixedLenByteArray(16)) => rmap!(
+                    expect_fixedlen,
+                    MutablePrimitiveArray::<months_days_ns>,
+                    @prim Vec<u8>,
+                    |x| months_days_ns::from_le_bytes(x.try_into().unwrap())
+                ),
+                (D::UInt128, PPT::FixedLenByteArray(16)) => rmap!(
+                    expect_fixedlen,
+                    MutablePrimitiveArray::<u128>,
+                    @prim Vec<u8>,
+                    |x| u128::from_be_bytes(x.try_into().unwrap())
+                ),
 
                 (D::Timestamp(time_unit, _), PPT::Int96) => {
                     rmap!(expect_int96, MutablePrimitiveArray::<i64>, @prim [u32; 3], |x| {
@@ -468,7 +493,10 @@ pub fn deserialize_all(
                     })
                 },
 
-                // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
+                (D::Float16, PPT::FixedLenByteArray(2)) => {
+                    rmap!(expect_fixedlen, MutablePrimitiveArray::<f16>, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+                },
+                // Read Float16 to a Float32 if the f16 type is not requested.
                 (_, PPT::FixedLenByteArray(2))
                     if matches!(logical_type.as_ref(), Some(PrimitiveLogicalType::Float16)) =>
                 {
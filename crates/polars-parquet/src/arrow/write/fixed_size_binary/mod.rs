@@ -2,7 +2,8 @@ mod basic;
 mod nested;
 
 use arrow::array::{Array, FixedSizeBinaryArray, PrimitiveArray};
-use arrow::types::i256;
+use arrow::datatypes::ArrowDataType;
+use arrow::types::{NativeType, f16, i256, months_days_ns};
 pub use basic::array_to_page;
 pub use nested::array_to_page as nested_array_to_page;
 
@@ -60,6 +61,100 @@ pub(super) fn build_statistics(
     }
 }
 
+/// Stores every value as 16 big-endian bytes, such that the byte-wise order of the
+/// values matches their numeric order.
+pub(super) fn u128_to_fixed_size_binary(array: &PrimitiveArray<u128>) -> FixedSizeBinaryArray {
+    let mut values = Vec::<u8>::with_capacity(16 * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(&x.to_be_bytes()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(16),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// Stores every value as 16 little-endian bytes: the months, the days and the nanoseconds.
+pub(super) fn months_days_ns_to_fixed_size_binary(
+    array: &PrimitiveArray<months_days_ns>,
+) -> FixedSizeBinaryArray {
+    let mut values = Vec::<u8>::with_capacity(16 * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(&x.to_le_bytes()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(16),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// Intervals have no meaningful byte-wise order, so only the null count is written.
+pub(super) fn build_statistics_months_days_ns(
+    array: &FixedSizeBinaryArray,
+    primitive_type: PrimitiveType,
+    options: &StatisticsOptions,
+) -> FixedLenStatistics {
+    FixedLenStatistics {
+        primitive_type,
+        null_count: options.null_count.then_some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: None,
+        min_value: None,
+    }
+}
+
+/// Stores every value as 2 little-endian bytes, as required by the `FLOAT16` logical type.
+pub(super) fn f16_to_fixed_size_binary(array: &PrimitiveArray<f16>) -> FixedSizeBinaryArray {
+    let mut values = Vec::<u8>::with_capacity(2 * array.len());
+    array
+        .values()
+        .iter()
+        .for_each(|x| values.extend_from_slice(&x.to_le_bytes()));
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(2),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// The byte-wise order of `FLOAT16` values is meaningless, so the statistics are computed on the
+/// floats. NaNs are ignored and zeros are widened to `-0.0` and `+0.0` for the bounds, as the
+/// parquet specification asks for.
+pub(super) fn build_statistics_f16(
+    array: &PrimitiveArray<f16>,
+    primitive_type: PrimitiveType,
+    options: &StatisticsOptions,
+) -> FixedLenStatistics {
+    let non_nan = || array.iter().flatten().copied().filter(|x| !x.is_nan());
+    FixedLenStatistics {
+        primitive_type,
+        null_count: options.null_count.then_some(array.null_count() as i64),
+        distinct_count: None,
+        max_value: options
+            .max_value
+            .then(|| {
+                non_nan()
+                    .reduce(|a, b| if b > a { b } else { a })
+                    .map(|x| if x == f16::ZERO { f16::ZERO } else { x })
+                    .map(|x| x.to_le_bytes().to_vec())
+            })
+            .flatten(),
+        min_value: options
+            .min_value
+            .then(|| {
+                non_nan()
+                    .reduce(|a, b| if b < a { b } else { a })
+                    .map(|x| if x == f16::ZERO { f16::NEG_ZERO } else { x })
+                    .map(|x| x.to_le_bytes().to_vec())
+            })
+            .flatten(),
+    }
+}
+
 pub(super) fn build_statistics_decimal(
     array: &PrimitiveArray<i128>,
     primitive_type: PrimitiveType,
@@ -569,15 +569,12 @@ pub fn array_to_page_simple(
             );
         },
         ArrowDataType::Utf8View => {
-            let array =
-                polars_compute::cast::cast(array, &ArrowDataType::BinaryView, Default::default())
-                    .unwrap();
-            return binview::array_to_page(
-                array.as_any().downcast_ref().unwrap(),
-                options,
-                type_,
-                encoding,
-            );
+            let array = array
+                .as_any()
+                .downcast_ref::<Utf8ViewArray>()
+                .unwrap()
+                .to_binview();
+            return binview::array_to_page(&array, options, type_, encoding);
         },
         ArrowDataType::Null => {
             let array = Int32Array::new_null(ArrowDataType::Int32, array.len());
@@ -637,6 +634,20 @@ pub fn array_to_page_simple(
             };
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let array = fixed_size_binary::months_days_ns_to_fixed_size_binary(array);
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_months_days_ns(
+                    &array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -651,6 +662,36 @@ pub fn array_to_page_simple(
 
             fixed_size_binary::array_to_page(array, options, type_, statistics)
         },
+        ArrowDataType::UInt128 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let array = fixed_size_binary::u128_to_fixed_size_binary(array);
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics(
+                    &array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
+        ArrowDataType::Float16 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_f16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let array = fixed_size_binary::f16_to_fixed_size_binary(array);
+
+            fixed_size_binary::array_to_page(&array, options, type_, statistics)
+        },
         ArrowDataType::Decimal256(precision, _) => {
             let precision = *precision;
             let array = array
@@ -898,6 +939,48 @@ fn array_to_page_nested(
             let array = array.as_any().downcast_ref().unwrap();
             primitive::nested_array_to_page::<f64, f64>(array, options, type_, nested)
         },
+        Interval(IntervalUnit::MonthDayNano) => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let array = fixed_size_binary::months_days_ns_to_fixed_size_binary(array);
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_months_days_ns(
+                    &array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
+        UInt128 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let array = fixed_size_binary::u128_to_fixed_size_binary(array);
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics(
+                    &array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
+        Float16 => {
+            let array = array.as_any().downcast_ref().unwrap();
+            let statistics = if options.has_statistics() {
+                Some(fixed_size_binary::build_statistics_f16(
+                    array,
+                    type_.clone(),
+                    &options.statistics,
+                ))
+            } else {
+                None
+            };
+            let array = fixed_size_binary::f16_to_fixed_size_binary(array);
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
+        },
         Decimal(precision, _) => {
             let precision = *precision;
             let array = array
@@ -4,7 +4,7 @@ use arrow::array::{Array, FixedSizeListArray, ListArray, MapArray, StructArray};
 use arrow::bitmap::{Bitmap, MutableBitmap};
 use arrow::datatypes::PhysicalType;
 use arrow::offset::{Offset, OffsetsBuffer};
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 
 use super::{ColumnWriteOptions, WriteOptions, array_to_pages};
 use crate::arrow::read::schema::is_nullable;
@@ -239,9 +239,11 @@ fn to_nested_recursive(
         },
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let type_ = if let ParquetType::GroupType { fields, .. } = type_ {
+            // The repeated `key_value` group holds the key and value columns directly, so the
+            // entries struct does not add a level of its own.
+            let fields = if let ParquetType::GroupType { fields, .. } = type_ {
                 if let ParquetType::GroupType { fields, .. } = &fields[0] {
-                    &fields[0]
+                    fields
                 } else {
                     polars_bail!(InvalidOperation:
                         "Parquet type must be a group for a map array",
@@ -252,13 +254,29 @@ fn to_nested_recursive(
                     "Parquet type must be a group for a map array",
                 )
             };
+            let entries = array
+                .field()
+                .as_any()
+                .downcast_ref::<StructArray>()
+                .unwrap();
+            polars_ensure!(
+                entries.null_count() == 0,
+                InvalidOperation: "writing a missing map entry to parquet"
+            );
 
             parents.push(Nested::List(ListNested::new(
                 array.offsets().clone(),
                 array.validity().cloned(),
                 is_optional,
             )));
-            to_nested_recursive(array.field().as_ref(), type_, nested, parents)?;
+            parents.push(Nested::Struct(StructNested {
+                is_optional: false,
+                validity: None,
+                length: entries.len(),
+            }));
+            for (type_, array) in fields.iter().zip(entries.values()) {
+                to_nested_recursive(array.as_ref(), type_, nested, parents.clone())?;
+            }
         },
         _ => {
             parents.push(Nested::Primitive(PrimitiveNested {
@@ -273,24 +291,26 @@ fn to_nested_recursive(
 }
 
 fn expand_list_validity<'a, O: Offset>(
-    array: &'a ListArray<O>,
+    offsets: &OffsetsBuffer<O>,
+    values: &'a dyn Array,
     validity: BitmapState,
     array_stack: &mut Vec<(&'a dyn Array, BitmapState)>,
 ) {
     let BitmapState::SomeSet(list_validity) = validity else {
         array_stack.push((
-            array.values().as_ref(),
+            values,
             match validity {
                 BitmapState::AllSet => BitmapState::AllSet,
                 BitmapState::SomeSet(_) => unreachable!(),
-                BitmapState::AllUnset(_) => BitmapState::AllUnset(array.values().len()),
+                BitmapState::AllUnset(_) => BitmapState::AllUnset(values.len()),
             },
         ));
         return;
     };
 
-    let offsets = array.offsets().buffer();
-    let mut validity = MutableBitmap::with_capacity(array.values().len());
+    let len = offsets.len_proxy();
+    let offsets = offsets.buffer();
+    let mut validity = MutableBitmap::with_capacity(values.len());
     let mut list_validity_iter = list_validity.iter();
 
     // @NOTE: We need to take into account here that the list might only point to a slice of the
@@ -312,13 +332,13 @@ fn expand_list_validity<'a, O: Offset>(
 
         idx += num_zeros;
     }
-    validity.extend_constant(array.values().len() - validity.len(), false);
+    validity.extend_constant(values.len() - validity.len(), false);
 
-    debug_assert_eq!(idx, array.len());
+    debug_assert_eq!(idx, len);
     let validity = validity.freeze();
 
-    debug_assert_eq!(validity.len(), array.values().len());
-    array_stack.push((array.values().as_ref(), BitmapState::SomeSet(validity)));
+    debug_assert_eq!(validity.len(), values.len());
+    array_stack.push((values, BitmapState::SomeSet(validity)));
 }
 
 #[derive(Clone)]
@@ -409,11 +429,21 @@ pub fn to_leaves(array: &dyn Array, leaves: &mut Vec<Box<dyn Array>>) {
             },
             P::List => {
                 let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
-                expand_list_validity(array, validity, &mut array_stack);
+                expand_list_validity(
+                    array.offsets(),
+                    array.values().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::LargeList => {
                 let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
-                expand_list_validity(array, validity, &mut array_stack);
+                expand_list_validity(
+                    array.offsets(),
+                    array.values().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::FixedSizeList => {
                 let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
@@ -459,7 +489,12 @@ pub fn to_leaves(array: &dyn Array, leaves: &mut Vec<Box<dyn Array>>) {
             },
             P::Map => {
                 let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-                array_stack.push((array.field().as_ref(), validity));
+                expand_list_validity(
+                    array.offsets(),
+                    array.field().as_ref(),
+                    validity,
+                    &mut array_stack,
+                );
             },
             P::Null
             | P::Boolean
@@ -892,37 +927,28 @@ mod tests {
 
         let array = MapArray::try_new(map_type, offsets, kv_array, None).unwrap();
 
-        let type_ = ParquetType::GroupType {
-            field_info: FieldInfo {
-                name: "kv".into(),
-                repetition: Repetition::Optional,
-                id: None,
-            },
-            logical_type: None,
-            converted_type: None,
-            fields: vec![
-                ParquetType::PrimitiveType(ParquetPrimitiveType {
-                    field_info: FieldInfo {
-                        name: "k".into(),
-                        repetition: Repetition::Required,
-                        id: None,
-                    },
-                    logical_type: Some(PrimitiveLogicalType::String),
-                    converted_type: Some(PrimitiveConvertedType::Utf8),
-                    physical_type: ParquetPhysicalType::ByteArray,
-                }),
-                ParquetType::PrimitiveType(ParquetPrimitiveType {
-                    field_info: FieldInfo {
-                        name: "v".into(),
-                        repetition: Repetition::Required,
-                        id: None,
-                    },
-                    logical_type: None,
-                    converted_type: None,
-                    physical_type: ParquetPhysicalType::Int32,
-                }),
-            ],
-        };
+        let fields = vec![
+            ParquetType::PrimitiveType(ParquetPrimitiveType {
+                field_info: FieldInfo {
+                    name: "k".into(),
+                    repetition: Repetition::Required,
+                    id: None,
+                },
+                logical_type: Some(PrimitiveLogicalType::String),
+                converted_type: Some(PrimitiveConvertedType::Utf8),
+                physical_type: ParquetPhysicalType::ByteArray,
+            }),
+            ParquetType::PrimitiveType(ParquetPrimitiveType {
+                field_info: FieldInfo {
+                    name: "v".into(),
+                    repetition: Repetition::Required,
+                    id: None,
+                },
+                logical_type: None,
+                converted_type: None,
+                physical_type: ParquetPhysicalType::Int32,
+            }),
+        ];
 
         let type_ = ParquetType::GroupType {
             field_info: FieldInfo {
@@ -934,13 +960,13 @@ mod tests {
             converted_type: None,
             fields: vec![ParquetType::GroupType {
                 field_info: FieldInfo {
-                    name: "map".into(),
+                    name: "key_value".into(),
                     repetition: Repetition::Repeated,
                     id: None,
                 },
                 logical_type: None,
                 converted_type: None,
-                fields: vec![type_],
+                fields,
             }],
         };
 
@@ -955,7 +981,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
                 vec![
@@ -964,7 +990,7 @@ mod tests {
                         offsets: vec![0, 2, 3, 4, 6].try_into().unwrap(),
                         validity: None,
                     }),
-                    Nested::structure(None, true, 6),
+                    Nested::structure(None, false, 6),
                     Nested::primitive(None, false, 6),
                 ],
             ]
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::sync::Arc;
 
-use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, TimeUnit};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit, TimeUnit};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::Engine as _;
 use base64::engine::general_purpose;
@@ -194,6 +194,8 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
     };
 
     let field_id = options.field_id;
+    let is_json =
+        matches!(field.dtype(), ArrowDataType::Extension(ext) if ext.name == "arrow.json");
 
     // create type from field
     let (physical_type, primitive_converted_type, primitive_logical_type) = match field
@@ -214,9 +216,23 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
         ArrowDataType::Date64 => (PhysicalType::Int64, None, None),
         ArrowDataType::Float32 => (PhysicalType::Float, None, None),
         ArrowDataType::Float64 => (PhysicalType::Double, None, None),
+        ArrowDataType::Float16 => (
+            PhysicalType::FixedLenByteArray(2),
+            None,
+            Some(PrimitiveLogicalType::Float16),
+        ),
+        // no natural representation in parquet; leave it as is.
+        // arrow consumers MAY use the arrow schema in the metadata to parse them.
+        ArrowDataType::UInt128 => (PhysicalType::FixedLenByteArray(16), None, None),
         ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::BinaryView => {
             (PhysicalType::ByteArray, None, None)
         },
+        // The canonical JSON extension type maps to the parquet JSON logical type.
+        ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8View if is_json => (
+            PhysicalType::ByteArray,
+            Some(PrimitiveConvertedType::Json),
+            Some(PrimitiveLogicalType::Json),
+        ),
         ArrowDataType::Utf8 | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8View => (
             PhysicalType::ByteArray,
             Some(PrimitiveConvertedType::Utf8),
@@ -329,7 +345,14 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
             return to_parquet_type(&dict_field, options);
         },
         ArrowDataType::FixedSizeBinary(size) => {
-            (PhysicalType::FixedLenByteArray(*size), None, None)
+            // The canonical UUID extension type maps to the parquet UUID logical type.
+            let is_uuid = *size == 16
+                && matches!(field.dtype(), ArrowDataType::Extension(ext) if ext.name == "arrow.uuid");
+            (
+                PhysicalType::FixedLenByteArray(*size),
+                None,
+                is_uuid.then_some(PrimitiveLogicalType::Uuid),
+            )
         },
         ArrowDataType::Decimal(precision, scale) => {
             let precision = *precision;
@@ -378,6 +401,11 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
                 (PhysicalType::FixedLenByteArray(32), None, None)
             }
         },
+        // The parquet INTERVAL type only has millisecond precision, so MonthDayNano is stored
+        // as is. Arrow consumers MAY use the arrow schema in the metadata to parse them.
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            (PhysicalType::FixedLenByteArray(16), None, None)
+        },
         ArrowDataType::Interval(_) => (
             PhysicalType::FixedLenByteArray(12),
             Some(PrimitiveConvertedType::Interval),
@@ -409,6 +437,43 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
                 field_id,
             ));
         },
+        ArrowDataType::Map(f, _) => {
+            let ArrowDataType::Struct(fields) = f.dtype() else {
+                polars_bail!(InvalidOperation: "map entries must be a struct, got {:?}", f.dtype());
+            };
+
+            let ChildWriteOptions::ListLike(map_write_options) = &options.children else {
+                unreachable!();
+            };
+            let ChildWriteOptions::Struct(entries_write_options) =
+                &map_write_options.child.children
+            else {
+                unreachable!();
+            };
+
+            assert_eq!(fields.len(), entries_write_options.children.len());
+
+            let fields = fields
+                .iter()
+                .zip(entries_write_options.children.as_slice())
+                .map(|(f, c)| to_parquet_type(f, c))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            return Ok(ParquetType::from_group(
+                name,
+                repetition,
+                Some(GroupConvertedType::Map),
+                Some(GroupLogicalType::Map),
+                vec![ParquetType::from_group(
+                    PlSmallStr::from_static("key_value"),
+                    Repetition::Repeated,
+                    None,
+                    None,
+                    fields,
+                    None,
+                )],
+                field_id,
+            ));
+        },
         other => polars_bail!(nyi = "Writing the data type {other:?} is not yet implemented"),
     };
 
@@ -61,4 +61,53 @@ impl BinaryNameSpace {
                 is_little_endian,
             )))
     }
+
+    /// Compute the MD5 digest of every value, as raw bytes or as a lowercase hex string.
+    #[cfg(feature = "stable_hash")]
+    pub fn md5(self, hex: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Md5(hex)))
+    }
+
+    /// Compute the SHA-1 digest of every value, as raw bytes or as a lowercase hex string.
+    #[cfg(feature = "stable_hash")]
+    pub fn sha1(self, hex: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Sha1(hex)))
+    }
+
+    /// Compute the SHA-256 digest of every value, as raw bytes or as a lowercase hex string.
+    #[cfg(feature = "stable_hash")]
+    pub fn sha256(self, hex: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Sha256(hex)))
+    }
+
+    /// Compute the 64-bit XXH3 hash of every value.
+    #[cfg(feature = "stable_hash")]
+    pub fn xxh3_64(self, seed: u64) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Xxh3_64(seed)))
+    }
+
+    /// Compute the 128-bit XXH3 hash of every value, as 16 big-endian bytes or as a
+    /// lowercase hex string.
+    #[cfg(feature = "stable_hash")]
+    pub fn xxh3_128(self, seed: u64, hex: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Xxh3_128(
+                seed, hex,
+            )))
+    }
+
+    /// Compute the 32-bit MurmurHash3 (x86 variant) of every value. With a seed of 0 this
+    /// is the hash of the Iceberg bucket transform, with `spark` and a seed of 42 it is the
+    /// hash of Spark's `hash` function.
+    #[cfg(feature = "stable_hash")]
+    pub fn murmur3_32(self, seed: u32, spark: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::BinaryExpr(BinaryFunction::Murmur3_32(
+                seed, spark,
+            )))
+    }
 }
@@ -11,12 +11,14 @@ impl DateLikeNameSpace {
         n: Expr,
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
         roll: Roll,
     ) -> Expr {
         self.0.map_binary(
             FunctionExpr::Business(BusinessFunction::AddBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
                 roll,
             }),
             n,
@@ -85,11 +87,17 @@ impl DateLikeNameSpace {
 
     /// Determine whether days are business days.
     #[cfg(feature = "business")]
-    pub fn is_business_day(self, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+    pub fn is_business_day(
+        self,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
+    ) -> Expr {
         self.0
             .map_unary(FunctionExpr::Business(BusinessFunction::IsBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
             }))
     }
 
@@ -218,6 +226,53 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Get the fiscal year of a Date/Datetime according to `calendar`.
+    #[cfg(feature = "dtype-date")]
+    pub fn fiscal_year(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalYear(
+                calendar,
+            )))
+    }
+
+    /// Get the fiscal quarter of a Date/Datetime according to `calendar`.
+    /// Quarters range from 1 to 4.
+    #[cfg(feature = "dtype-date")]
+    pub fn fiscal_quarter(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalQuarter(
+                calendar,
+            )))
+    }
+
+    /// Get the fiscal period (month) of a Date/Datetime according to `calendar`.
+    /// Periods range from 1 to 12.
+    #[cfg(feature = "dtype-date")]
+    pub fn fiscal_period(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalPeriod(
+                calendar,
+            )))
+    }
+
+    /// Get the week of the fiscal year of a Date/Datetime according to `calendar`.
+    /// Weeks are counted from the start of the fiscal year and range from 1 to 53.
+    #[cfg(feature = "dtype-date")]
+    pub fn fiscal_week(self, calendar: FiscalCalendar) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::TemporalExpr(TemporalFunction::FiscalWeek(
+                calendar,
+            )))
+    }
+
+    /// Truncate a Date/Datetime to the start of its fiscal year, quarter, period or week.
+    #[cfg(feature = "dtype-date")]
+    pub fn fiscal_truncate(self, calendar: FiscalCalendar, unit: FiscalUnit) -> Expr {
+        self.0.map_unary(FunctionExpr::TemporalExpr(
+            TemporalFunction::FiscalTruncate(calendar, unit),
+        ))
+    }
+
     /// Roll backward to the first day of the month.
     #[cfg(feature = "month_start")]
     pub fn month_start(self) -> Expr {
@@ -368,6 +368,12 @@ impl CastColumnsPolicy {
             )
         };
 
+        // Fields that are entirely null can be written with the `Null` dtype, e.g. a struct field
+        // that was added in a later file. These can always be cast to the target.
+        if incoming_dtype.is_null() {
+            return Ok(!target_dtype.is_null());
+        }
+
         // We intercept the nested types first to prevent an expensive recursive eq - recursion
         // is instead done manually through this function.
 
@@ -474,7 +480,40 @@ impl CastColumnsPolicy {
             return self.should_cast_column(column_name, target_inner, incoming_inner);
         }
 
-        // Eq here should be cheap as we have intercepted all nested types above.
+        #[cfg(feature = "dtype-map")]
+        if let DataType::Map(target_key, target_value) = target_dtype {
+            let DataType::Map(incoming_key, incoming_value) = incoming_dtype else {
+                return mismatch_err("");
+            };
+
+            return Ok(
+                self.should_cast_column(column_name, target_key, incoming_key)?
+                    | self.should_cast_column(column_name, target_value, incoming_value)?,
+            );
+        }
+
+        #[cfg(feature = "dtype-union")]
+        if let DataType::Union(target_variants) = target_dtype {
+            let DataType::Union(incoming_variants) = incoming_dtype else {
+                return mismatch_err("");
+            };
+
+            if target_variants.len() != incoming_variants.len()
+                || target_variants
+                    .iter()
+                    .zip(incoming_variants)
+                    .any(|(t, i)| t.name() != i.name())
+            {
+                return mismatch_err("");
+            }
+
+            let mut should_cast = false;
+            for (target, incoming) in target_variants.iter().zip(incoming_variants) {
+                should_cast |=
+                    self.should_cast_column(column_name, target.dtype(), incoming.dtype())?;
+            }
+            return Ok(should_cast);
+        }
 
         debug_assert!(!target_dtype.is_nested());
 
@@ -588,4 +627,123 @@ impl CastColumnsPolicy {
 
         mismatch_err("")
     }
+
+    /// Reconciles two dtypes into one that both can be cast to with this policy, e.g. for inputs
+    /// that were written with an evolving schema.
+    ///
+    /// Struct fields are matched by name and keep the order of `target`. Fields that exist on
+    /// one side only are kept if `missing_struct_fields` allows inserting them as nulls,
+    /// otherwise fields of `incoming` are dropped if `extra_struct_fields` allows ignoring them.
+    pub fn reconcile_dtypes(
+        &self,
+        column_name: &str,
+        target_dtype: &DataType,
+        incoming_dtype: &DataType,
+    ) -> PolarsResult<DataType> {
+        use DataType::*;
+
+        let mismatch_err = |hint: &str| {
+            let hint_spacing = if hint.is_empty() { "" } else { ", " };
+
+            polars_err!(
+                SchemaMismatch:
+                "data type mismatch for column {}: incoming: {:?} != target: {:?}{}{}",
+                column_name,
+                incoming_dtype,
+                target_dtype,
+                hint_spacing,
+                hint,
+            )
+        };
+
+        if target_dtype == incoming_dtype {
+            return Ok(target_dtype.clone());
+        }
+
+        Ok(match (target_dtype, incoming_dtype) {
+            (Null, dtype) | (dtype, Null) => dtype.clone(),
+            #[cfg(feature = "dtype-struct")]
+            (Struct(target_fields), Struct(incoming_fields)) => {
+                let incoming_fields_schema = PlHashMap::from_iter(
+                    incoming_fields
+                        .iter()
+                        .map(|fld| (fld.name.as_str(), &fld.dtype)),
+                );
+
+                let mut fields = Vec::with_capacity(target_fields.len());
+                for fld in target_fields {
+                    match incoming_fields_schema.get(fld.name.as_str()) {
+                        Some(dtype) => fields.push(Field::new(
+                            fld.name.clone(),
+                            self.reconcile_dtypes(column_name, &fld.dtype, dtype)?,
+                        )),
+                        None if self.missing_struct_fields == MissingColumnsPolicy::Insert => {
+                            fields.push(fld.clone())
+                        },
+                        None if self.extra_struct_fields == ExtraColumnsPolicy::Ignore => {},
+                        None => {
+                            return Err(mismatch_err(&format!(
+                                "encountered missing struct field: {}",
+                                fld.name
+                            )));
+                        },
+                    }
+                }
+
+                let target_fields_schema =
+                    PlHashSet::from_iter(target_fields.iter().map(|fld| fld.name.as_str()));
+                for fld in incoming_fields {
+                    if target_fields_schema.contains(fld.name.as_str()) {
+                        continue;
+                    }
+                    match (self.missing_struct_fields, self.extra_struct_fields) {
+                        (MissingColumnsPolicy::Insert, _) => fields.push(fld.clone()),
+                        (_, ExtraColumnsPolicy::Ignore) => {},
+                        _ => {
+                            return Err(mismatch_err(&format!(
+                                "encountered extra struct field: {}",
+                                fld.name
+                            )));
+                        },
+                    }
+                }
+
+                Struct(fields)
+            },
+            (List(target_inner), List(incoming_inner)) => List(Box::new(self.reconcile_dtypes(
+                column_name,
+                target_inner,
+                incoming_inner,
+            )?)),
+            #[cfg(feature = "dtype-array")]
+            (Array(target_inner, target_width), Array(incoming_inner, incoming_width))
+                if target_width == incoming_width =>
+            {
+                Array(
+                    Box::new(self.reconcile_dtypes(column_name, target_inner, incoming_inner)?),
+                    *target_width,
+                )
+            },
+            (l, r) if l.is_integer() && r.is_integer() => {
+                if !self.integer_upcast {
+                    return Err(mismatch_err(
+                        "hint: pass cast_options=pl.ScanCastOptions(integer_cast='upcast')",
+                    ));
+                }
+                get_numeric_upcast_supertype_lossless(l, r).ok_or_else(|| {
+                    mismatch_err("incoming dtype cannot safely cast to target dtype")
+                })?
+            },
+            (l, r) if l.is_float() && r.is_float() => {
+                if !self.float_upcast {
+                    return Err(mismatch_err(
+                        "hint: pass cast_options=pl.ScanCastOptions(float_cast='upcast')",
+                    ));
+                }
+                get_numeric_upcast_supertype_lossless(l, r).ok_or_else(|| mismatch_err(""))?
+            },
+            // E.g. categoricals with different rev-maps.
+            _ => merge_dtypes(target_dtype, incoming_dtype).map_err(|_| mismatch_err(""))?,
+        })
+    }
 }
@@ -21,6 +21,18 @@ pub enum BinaryFunction {
     Size,
     #[cfg(feature = "binary_encoding")]
     FromBuffer(DataTypeExpr, bool),
+    #[cfg(feature = "stable_hash")]
+    Md5(bool),
+    #[cfg(feature = "stable_hash")]
+    Sha1(bool),
+    #[cfg(feature = "stable_hash")]
+    Sha256(bool),
+    #[cfg(feature = "stable_hash")]
+    Xxh3_64(u64),
+    #[cfg(feature = "stable_hash")]
+    Xxh3_128(u64, bool),
+    #[cfg(feature = "stable_hash")]
+    Murmur3_32(u32, bool),
 }
 
 impl Display for BinaryFunction {
@@ -41,6 +53,18 @@ impl Display for BinaryFunction {
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             FromBuffer(_, _) => "from_buffer",
+            #[cfg(feature = "stable_hash")]
+            Md5(_) => "md5",
+            #[cfg(feature = "stable_hash")]
+            Sha1(_) => "sha1",
+            #[cfg(feature = "stable_hash")]
+            Sha256(_) => "sha256",
+            #[cfg(feature = "stable_hash")]
+            Xxh3_64(_) => "xxh3_64",
+            #[cfg(feature = "stable_hash")]
+            Xxh3_128(_, _) => "xxh3_128",
+            #[cfg(feature = "stable_hash")]
+            Murmur3_32(_, _) => "murmur3_32",
         };
         write!(f, "bin.{s}")
     }
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use polars_ops::prelude::Roll;
+use polars_ops::prelude::{HolidayCalendar, Roll};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -11,15 +11,18 @@ pub enum BusinessFunction {
     BusinessDayCount {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
     },
     AddBusinessDay {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
         roll: Roll,
     },
     IsBusinessDay {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
     },
 }
 
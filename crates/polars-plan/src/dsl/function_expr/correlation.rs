@@ -10,6 +10,7 @@ pub enum CorrelationMethod {
     Pearson,
     #[cfg(all(feature = "rank", feature = "propagate_nans"))]
     SpearmanRank(bool),
+    KendallTau,
     Covariance(u8),
 }
 
@@ -20,6 +21,7 @@ impl Display for CorrelationMethod {
             Pearson => "pearson",
             #[cfg(all(feature = "rank", feature = "propagate_nans"))]
             SpearmanRank(_) => "spearman_rank",
+            KendallTau => "kendall_tau",
             Covariance(_) => return write!(f, "covariance"),
         };
         write!(f, "{s}_correlation")
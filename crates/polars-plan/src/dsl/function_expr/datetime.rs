@@ -53,6 +53,16 @@ pub enum TemporalFunction {
     MonthStart,
     #[cfg(feature = "month_end")]
     MonthEnd,
+    #[cfg(feature = "dtype-date")]
+    FiscalYear(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalQuarter(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalPeriod(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalWeek(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalTruncate(FiscalCalendar, FiscalUnit),
     #[cfg(feature = "timezones")]
     BaseUtcOffset,
     #[cfg(feature = "timezones")]
@@ -121,6 +131,16 @@ impl Display for TemporalFunction {
             MonthStart => "month_start",
             #[cfg(feature = "month_end")]
             MonthEnd => "month_end",
+            #[cfg(feature = "dtype-date")]
+            FiscalYear(_) => "fiscal_year",
+            #[cfg(feature = "dtype-date")]
+            FiscalQuarter(_) => "fiscal_quarter",
+            #[cfg(feature = "dtype-date")]
+            FiscalPeriod(_) => "fiscal_period",
+            #[cfg(feature = "dtype-date")]
+            FiscalWeek(_) => "fiscal_week",
+            #[cfg(feature = "dtype-date")]
+            FiscalTruncate(_, _) => "fiscal_truncate",
             #[cfg(feature = "timezones")]
             BaseUtcOffset => "base_utc_offset",
             #[cfg(feature = "timezones")]
@@ -0,0 +1,24 @@
+use super::*;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum IpFunction {
+    ToInt { ipv6: bool },
+    ToBinary,
+    ToString,
+    InNetwork(PlSmallStr),
+}
+
+impl Display for IpFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IpFunction::*;
+        let name = match self {
+            ToInt { .. } => "to_int",
+            ToBinary => "to_binary",
+            ToString => "to_string",
+            InNetwork(_) => "in_network",
+        };
+        write!(f, "ip.{name}")
+    }
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+#[derive(Clone, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum JsonFunction {
+    Get {
+        path: PlSmallStr,
+        dtype: DataTypeExpr,
+    },
+    Compact,
+    Pretty,
+}
+
+impl Display for JsonFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use JsonFunction::*;
+        let name = match self {
+            Get { .. } => "get",
+            Compact => "compact",
+            Pretty => "pretty",
+        };
+        write!(f, "json.{name}")
+    }
+}
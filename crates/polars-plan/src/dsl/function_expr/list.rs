@@ -43,6 +43,15 @@ pub enum ListFunction {
         null_behavior: NullBehavior,
     },
     Sort(SortOptions),
+    SortBy(SortOptions),
+    ArgSort(SortOptions),
+    #[cfg(feature = "search_sorted")]
+    SearchSorted {
+        side: SearchSortedSide,
+        descending: bool,
+    },
+    #[cfg(feature = "top_k")]
+    TopK,
     Reverse,
     Unique(bool),
     NUnique,
@@ -57,6 +66,12 @@ pub enum ListFunction {
     ToArray(usize),
     #[cfg(feature = "list_to_struct")]
     ToStruct(ListToStructArgs),
+    #[cfg(feature = "list_zip")]
+    Zip,
+    #[cfg(feature = "list_zip")]
+    Unzip,
+    #[cfg(feature = "list_zip")]
+    Enumerate,
 }
 
 impl Display for ListFunction {
@@ -99,6 +114,12 @@ impl Display for ListFunction {
             Diff { .. } => "diff",
             Length => "length",
             Sort(_) => "sort",
+            SortBy(_) => "sort_by",
+            ArgSort(_) => "arg_sort",
+            #[cfg(feature = "search_sorted")]
+            SearchSorted { .. } => "search_sorted",
+            #[cfg(feature = "top_k")]
+            TopK => "top_k",
             Reverse => "reverse",
             Unique(is_stable) => {
                 if *is_stable {
@@ -119,6 +140,12 @@ impl Display for ListFunction {
             ToArray(_) => "to_array",
             #[cfg(feature = "list_to_struct")]
             ToStruct(_) => "to_struct",
+            #[cfg(feature = "list_zip")]
+            Zip => "zip",
+            #[cfg(feature = "list_zip")]
+            Unzip => "unzip",
+            #[cfg(feature = "list_zip")]
+            Enumerate => "enumerate",
         };
         write!(f, "list.{name}")
     }
@@ -12,7 +12,13 @@ mod cat;
 mod correlation;
 #[cfg(feature = "temporal")]
 mod datetime;
+#[cfg(feature = "net")]
+mod ip;
+#[cfg(feature = "dtype-json")]
+mod json;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod pow;
 #[cfg(feature = "random")]
 mod random;
@@ -28,6 +34,10 @@ mod strings;
 mod struct_;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-union")]
+mod union;
+#[cfg(feature = "net")]
+mod url;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -37,6 +47,8 @@ pub use array::ArrayFunction;
 #[cfg(feature = "cov")]
 pub use correlation::CorrelationMethod;
 pub use list::ListFunction;
+#[cfg(feature = "dtype-map")]
+pub use map::MapFunction;
 pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 #[cfg(feature = "random")]
@@ -54,6 +66,10 @@ pub use self::business::BusinessFunction;
 pub use self::cat::CategoricalFunction;
 #[cfg(feature = "temporal")]
 pub use self::datetime::TemporalFunction;
+#[cfg(feature = "net")]
+pub use self::ip::IpFunction;
+#[cfg(feature = "dtype-json")]
+pub use self::json::JsonFunction;
 pub use self::pow::PowFunction;
 #[cfg(feature = "range")]
 pub use self::range::RangeFunction;
@@ -67,6 +83,10 @@ pub use self::strings::StringFunction;
 pub use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::TrigonometricFunction;
+#[cfg(feature = "dtype-union")]
+pub use self::union::UnionFunction;
+#[cfg(feature = "net")]
+pub use self::url::UrlFunction;
 use super::*;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -80,6 +100,16 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(CategoricalFunction),
     ListExpr(ListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(MapFunction),
+    #[cfg(feature = "dtype-union")]
+    UnionExpr(UnionFunction),
+    #[cfg(feature = "dtype-json")]
+    JsonExpr(JsonFunction),
+    #[cfg(feature = "net")]
+    UrlExpr(UrlFunction),
+    #[cfg(feature = "net")]
+    IpExpr(IpFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -106,6 +136,10 @@ pub enum FunctionExpr {
     Pow(PowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "stable_hash")]
+    StableHashRows {
+        seed: u64,
+    },
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -334,6 +368,16 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(f) => f.hash(state),
+            #[cfg(feature = "net")]
+            UrlExpr(f) => f.hash(state),
+            #[cfg(feature = "net")]
+            IpExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -406,6 +450,8 @@ impl Hash for FunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "stable_hash")]
+            StableHashRows { seed } => seed.hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr(f) => {
@@ -577,6 +623,16 @@ impl Display for FunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "net")]
+            UrlExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "net")]
+            IpExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -597,6 +653,8 @@ impl Display for FunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "stable_hash")]
+            StableHashRows { .. } => "stable_hash_rows",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
@@ -11,6 +11,14 @@ pub enum RollingFunction {
     Quantile(RollingOptionsFixedWindow),
     Var(RollingOptionsFixedWindow),
     Std(RollingOptionsFixedWindow),
+    NUnique(RollingOptionsFixedWindow),
+    Mode(RollingOptionsFixedWindow),
+    Rank(RollingOptionsFixedWindow),
+    ArgMin(RollingOptionsFixedWindow),
+    ArgMax(RollingOptionsFixedWindow),
+    First(RollingOptionsFixedWindow),
+    Last(RollingOptionsFixedWindow),
+    Product(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
     Skew(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
@@ -36,6 +44,14 @@ impl Display for RollingFunction {
             Quantile(_) => "quantile",
             Var(_) => "var",
             Std(_) => "std",
+            NUnique(_) => "n_unique",
+            Mode(_) => "mode",
+            Rank(_) => "rank",
+            ArgMin(_) => "arg_min",
+            ArgMax(_) => "arg_max",
+            First(_) => "first",
+            Last(_) => "last",
+            Product(_) => "product",
             #[cfg(feature = "moment")]
             Skew(..) => "skew",
             #[cfg(feature = "moment")]
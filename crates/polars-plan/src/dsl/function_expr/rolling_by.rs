@@ -11,6 +11,14 @@ pub enum RollingFunctionBy {
     QuantileBy(RollingOptionsDynamicWindow),
     VarBy(RollingOptionsDynamicWindow),
     StdBy(RollingOptionsDynamicWindow),
+    NUniqueBy(RollingOptionsDynamicWindow),
+    ModeBy(RollingOptionsDynamicWindow),
+    RankBy(RollingOptionsDynamicWindow),
+    ArgMinBy(RollingOptionsDynamicWindow),
+    ArgMaxBy(RollingOptionsDynamicWindow),
+    FirstBy(RollingOptionsDynamicWindow),
+    LastBy(RollingOptionsDynamicWindow),
+    ProductBy(RollingOptionsDynamicWindow),
 }
 
 impl Display for RollingFunctionBy {
@@ -25,6 +33,14 @@ impl Display for RollingFunctionBy {
             QuantileBy(_) => "rolling_quantile_by",
             VarBy(_) => "rolling_var_by",
             StdBy(_) => "rolling_std_by",
+            NUniqueBy(_) => "rolling_n_unique_by",
+            ModeBy(_) => "rolling_mode_by",
+            RankBy(_) => "rolling_rank_by",
+            ArgMinBy(_) => "rolling_arg_min_by",
+            ArgMaxBy(_) => "rolling_arg_max_by",
+            FirstBy(_) => "rolling_first_by",
+            LastBy(_) => "rolling_last_by",
+            ProductBy(_) => "rolling_product_by",
         };
 
         write!(f, "{name}")
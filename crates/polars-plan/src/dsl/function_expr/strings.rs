@@ -123,6 +123,34 @@ pub enum StringFunction {
     },
     #[cfg(feature = "regex")]
     EscapeRegex,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshtein {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    Hamming {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_similarity")]
+    JaccardNgram(usize),
+    #[cfg(feature = "string_tokenize")]
+    SplitRegex,
+    #[cfg(feature = "string_tokenize")]
+    Tokenize,
+    #[cfg(feature = "string_tokenize")]
+    CountTokens,
+    #[cfg(feature = "string_tokenize")]
+    CharNgrams(usize),
+    #[cfg(feature = "string_tokenize")]
+    WordNgrams(usize),
+    #[cfg(feature = "string_tokenize")]
+    RemoveStopWords(Vec<PlSmallStr>),
 }
 
 impl Display for StringFunction {
@@ -215,6 +243,28 @@ impl Display for StringFunction {
             FindMany { .. } => "extract_many",
             #[cfg(feature = "regex")]
             EscapeRegex => "escape_regex",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { .. } => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein { .. } => "damerau_levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Hamming { .. } => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_similarity")]
+            JaccardNgram(_) => "jaccard_ngram",
+            #[cfg(feature = "string_tokenize")]
+            SplitRegex => "split_regex",
+            #[cfg(feature = "string_tokenize")]
+            Tokenize => "tokenize",
+            #[cfg(feature = "string_tokenize")]
+            CountTokens => "count_tokens",
+            #[cfg(feature = "string_tokenize")]
+            CharNgrams(_) => "char_ngrams",
+            #[cfg(feature = "string_tokenize")]
+            WordNgrams(_) => "word_ngrams",
+            #[cfg(feature = "string_tokenize")]
+            RemoveStopWords(_) => "remove_stop_words",
         };
         write!(f, "str.{s}")
     }
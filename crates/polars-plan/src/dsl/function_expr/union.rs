@@ -0,0 +1,22 @@
+use super::*;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum UnionFunction {
+    TypeId,
+    Field(PlSmallStr),
+    FromStruct,
+}
+
+impl Display for UnionFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use UnionFunction::*;
+        let name = match self {
+            TypeId => "type_id",
+            Field(_) => "field",
+            FromStruct => "from_struct",
+        };
+        write!(f, "union.{name}")
+    }
+}
@@ -0,0 +1,20 @@
+use super::*;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum UrlFunction {
+    Parse,
+    QueryParam(PlSmallStr),
+}
+
+impl Display for UrlFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use UrlFunction::*;
+        let name = match self {
+            Parse => "parse",
+            QueryParam(_) => "query_param",
+        };
+        write!(f, "url.{name}")
+    }
+}
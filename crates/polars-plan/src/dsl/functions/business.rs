@@ -6,6 +6,7 @@ pub fn business_day_count(
     end: Expr,
     week_mask: [bool; 7],
     holidays: Vec<i32>,
+    calendar: Option<HolidayCalendar>,
 ) -> Expr {
     let input = vec![start, end];
 
@@ -14,6 +15,7 @@ pub fn business_day_count(
         function: FunctionExpr::Business(BusinessFunction::BusinessDayCount {
             week_mask,
             holidays,
+            calendar,
         }),
     }
 }
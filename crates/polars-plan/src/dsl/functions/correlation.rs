@@ -31,6 +31,48 @@ pub fn spearman_rank_corr(a: Expr, b: Expr, propagate_nans: bool) -> Expr {
     a.map_binary(function, b)
 }
 
+/// Compute Kendall's tau-b rank correlation between two columns.
+/// Missing data will be excluded from the computation.
+pub fn kendall_tau_corr(a: Expr, b: Expr) -> Expr {
+    let function = FunctionExpr::Correlation {
+        method: CorrelationMethod::KendallTau,
+    };
+    a.map_binary(function, b)
+}
+
+/// Compute the correlation between two columns with the given method.
+pub fn corr(a: Expr, b: Expr, method: CorrelationMethod) -> Expr {
+    a.map_binary(FunctionExpr::Correlation { method }, b)
+}
+
+/// Compute the correlation between every pair of distinct `columns`.
+///
+/// The result is a single `List` named `corr_matrix` of structs with the fields `column_1`,
+/// `column_2` and `correlation`, holding one element per pair. As it is an aggregation, it can
+/// be used in `group_by` contexts; explode and unnest it to get a tidy frame.
+#[cfg(feature = "dtype-struct")]
+pub fn corr_matrix(columns: &[PlSmallStr], method: CorrelationMethod) -> PolarsResult<Expr> {
+    polars_ensure!(
+        columns.len() >= 2,
+        InvalidOperation: "`corr_matrix` needs at least two columns, got {}", columns.len()
+    );
+    let pairs = columns
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| columns[i + 1..].iter().map(move |b| (a, b)))
+        .map(|(a, b)| {
+            as_struct(vec![
+                lit(a.clone()).alias("column_1"),
+                lit(b.clone()).alias("column_2"),
+                corr(col(a.clone()), col(b.clone()), method)
+                    .cast(DataType::Float64)
+                    .alias("correlation"),
+            ])
+        })
+        .collect::<Vec<_>>();
+    Ok(concat_list(pairs)?.alias("corr_matrix"))
+}
+
 #[cfg(all(feature = "rolling_window", feature = "cov"))]
 fn dispatch_corr_cov(x: Expr, y: Expr, options: RollingCovOptions, is_corr: bool) -> Expr {
     // see: https://github.com/pandas-dev/pandas/blob/v1.5.1/pandas/core/window/rolling.py#L1780-L1804
@@ -259,6 +259,17 @@ pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Polars
     ))
 }
 
+/// Hash every row of the given expressions into a `UInt64` that is stable across runs,
+/// platforms and versions.
+///
+/// See [`polars_ops::series::stable_row_hashes`] for the exact definition.
+#[cfg(feature = "stable_hash")]
+pub fn stable_hash_rows<E: AsRef<[Expr]>>(exprs: E, seed: u64) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot hash rows of zero columns");
+    Ok(Expr::n_ary(FunctionExpr::StableHashRows { seed }, exprs))
+}
+
 /// Folds the expressions from left to right keeping the first non-null values.
 ///
 /// It is an error to provide an empty `exprs`.
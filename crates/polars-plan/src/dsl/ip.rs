@@ -0,0 +1,36 @@
+use crate::dsl::function_expr::IpFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for IP addresses stored as strings, as 4 or 16 bytes of
+/// [`DataType::Binary`], or as [`DataType::UInt32`] and [`DataType::UInt128`] integers.
+///
+/// Invalid addresses are null.
+pub struct IpNameSpace(pub Expr);
+
+impl IpNameSpace {
+    /// Convert to [`DataType::UInt32`], null for IPv6 addresses, or with `ipv6` set to
+    /// [`DataType::UInt128`], mapping IPv4 addresses into IPv6.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_int(self, ipv6: bool) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::IpExpr(IpFunction::ToInt { ipv6 }))
+    }
+
+    /// Convert to the 4 or 16 bytes of the address in network byte order.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_binary(self) -> Expr {
+        self.0.map_unary(FunctionExpr::IpExpr(IpFunction::ToBinary))
+    }
+
+    /// Format the addresses in their canonical textual form.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_string(self) -> Expr {
+        self.0.map_unary(FunctionExpr::IpExpr(IpFunction::ToString))
+    }
+
+    /// Check whether the addresses lie in the network `cidr`, e.g. `10.0.0.0/8`.
+    pub fn in_network(self, cidr: &str) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::IpExpr(IpFunction::InNetwork(cidr.into())))
+    }
+}
@@ -0,0 +1,28 @@
+use crate::dsl::function_expr::JsonFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Json`].
+pub struct JsonNameSpace(pub Expr);
+
+impl JsonNameSpace {
+    /// Extract the value at the JSONPath `path` of every document as `dtype`, null in rows where
+    /// nothing matches. Only the selected values are decoded.
+    pub fn get(self, path: &str, dtype: impl Into<DataTypeExpr>) -> Expr {
+        self.0.map_unary(FunctionExpr::JsonExpr(JsonFunction::Get {
+            path: path.into(),
+            dtype: dtype.into(),
+        }))
+    }
+
+    /// Remove all whitespace outside of strings.
+    pub fn compact(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::JsonExpr(JsonFunction::Compact))
+    }
+
+    /// Format the documents with one object member or array element per line.
+    pub fn pretty(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::JsonExpr(JsonFunction::Pretty))
+    }
+}
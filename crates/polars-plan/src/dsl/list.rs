@@ -112,6 +112,40 @@ impl ListNameSpace {
             .map_unary(FunctionExpr::ListExpr(ListFunction::Sort(options)))
     }
 
+    /// Sort every sublist by the sublist in the same row of `by`, which must have the same length.
+    pub fn sort_by(self, by: Expr, options: SortOptions) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ListExpr(ListFunction::SortBy(options)), by)
+    }
+
+    /// Get the indices that sort every sublist.
+    pub fn arg_sort(self, options: SortOptions) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::ArgSort(options)))
+    }
+
+    /// Find the indices where `element` should be inserted into every sorted sublist to keep it
+    /// sorted.
+    #[cfg(feature = "search_sorted")]
+    pub fn search_sorted<E: Into<Expr>>(
+        self,
+        element: E,
+        side: SearchSortedSide,
+        descending: bool,
+    ) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::ListExpr(ListFunction::SearchSorted { side, descending }),
+            element.into(),
+        )
+    }
+
+    /// Get the `k` largest values of every sublist, in descending order.
+    #[cfg(feature = "top_k")]
+    pub fn top_k(self, k: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ListExpr(ListFunction::TopK), k)
+    }
+
     /// Reverse every sublist
     pub fn reverse(self) -> Expr {
         self.0
@@ -248,6 +282,33 @@ impl ListNameSpace {
             .map_unary(FunctionExpr::ListExpr(ListFunction::ToStruct(args)))
     }
 
+    #[cfg(feature = "list_zip")]
+    /// Zip every sublist with the sublists in the same row of `others` into a list of structs.
+    /// The struct fields are named after the input columns.
+    ///
+    /// # Error
+    /// This errors if sublists in the same row have different lengths.
+    pub fn zip<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, others: E) -> Expr {
+        let others = others.as_ref().iter().map(|e| e.clone().into());
+        self.0
+            .map_n_ary(FunctionExpr::ListExpr(ListFunction::Zip), others)
+    }
+
+    #[cfg(feature = "list_zip")]
+    /// Unzip a list of structs into a struct with a list for every field.
+    pub fn unzip(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::Unzip))
+    }
+
+    #[cfg(feature = "list_zip")]
+    /// Pair every element of a sublist with its index, giving a list of structs with the fields
+    /// `index` and `value`.
+    pub fn enumerate(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::ListExpr(ListFunction::Enumerate))
+    }
+
     #[cfg(feature = "is_in")]
     /// Check if the list array contain an element
     pub fn contains<E: Into<Expr>>(self, other: E, nulls_equal: bool) -> Expr {
@@ -1,4 +1,4 @@
-use super::{Expr, ExtraColumnsPolicy, MissingColumnsPolicy};
+use super::{CastColumnsPolicy, Expr, ExtraColumnsPolicy, MissingColumnsPolicy};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -29,3 +29,16 @@ pub struct MatchToSchemaPerColumn {
     pub integer_cast: UpcastOrForbid,
     pub float_cast: UpcastOrForbid,
 }
+
+impl MatchToSchemaPerColumn {
+    /// The policy used to cast, or reconcile, the (nested) dtype of this column.
+    pub fn cast_columns_policy(&self) -> CastColumnsPolicy {
+        CastColumnsPolicy {
+            integer_upcast: self.integer_cast == UpcastOrForbid::Upcast,
+            float_upcast: self.float_cast == UpcastOrForbid::Upcast,
+            missing_struct_fields: self.missing_struct_fields,
+            extra_struct_fields: self.extra_struct_fields,
+            ..CastColumnsPolicy::ERROR_ON_MISMATCH
+        }
+    }
+}
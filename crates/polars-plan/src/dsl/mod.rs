@@ -25,7 +25,13 @@ mod format;
 mod from;
 pub mod function_expr;
 pub mod functions;
+#[cfg(feature = "net")]
+mod ip;
+#[cfg(feature = "dtype-json")]
+mod json;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod match_to_schema;
 #[cfg(feature = "meta")]
 mod meta;
@@ -43,6 +49,10 @@ pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
 pub mod udf;
+#[cfg(feature = "dtype-union")]
+mod union;
+#[cfg(feature = "net")]
+mod url;
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -56,6 +66,8 @@ pub use expr::*;
 pub use function_expr::*;
 pub use functions::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 pub use match_to_schema::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
@@ -70,10 +82,14 @@ use polars_core::series::IsSorted;
 #[cfg(feature = "diff")]
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::try_get_supertype;
+#[cfg(feature = "dtype-uuid")]
+pub use random::uuid4;
 pub use selector::Selector;
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 pub use udf::UserDefinedFunction;
+#[cfg(feature = "dtype-union")]
+pub use union::*;
 mod file_scan;
 pub use file_scan::*;
 pub use scan_sources::{ScanSource, ScanSourceIter, ScanSourceRef, ScanSources};
@@ -1207,6 +1223,60 @@ impl Expr {
         self.rolling_quantile_by(by, QuantileMethod::Linear, 0.5, options)
     }
 
+    /// Apply a rolling count of the unique values based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_n_unique_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::NUniqueBy)
+    }
+
+    /// Apply a rolling mode based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_mode_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ModeBy)
+    }
+
+    /// Apply a rolling rank based on another column, ranking every value within its window.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_rank_by(
+        self,
+        by: Expr,
+        method: RollingRankMethod,
+        mut options: RollingOptionsDynamicWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Rank { method });
+        self.finish_rolling_by(by, options, RollingFunctionBy::RankBy)
+    }
+
+    /// Apply a rolling arg-min, the position of the minimum within the window based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_arg_min_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ArgMinBy)
+    }
+
+    /// Apply a rolling arg-max, the position of the maximum within the window based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_arg_max_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ArgMaxBy)
+    }
+
+    /// Apply a rolling first based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_first_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::FirstBy)
+    }
+
+    /// Apply a rolling last based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_last_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::LastBy)
+    }
+
+    /// Apply a rolling product based on another column.
+    #[cfg(feature = "rolling_window_by")]
+    pub fn rolling_product_by(self, by: Expr, options: RollingOptionsDynamicWindow) -> Expr {
+        self.finish_rolling_by(by, options, RollingFunctionBy::ProductBy)
+    }
+
     /// Apply a rolling minimum.
     ///
     /// See: [`RollingAgg::rolling_min`]
@@ -1293,6 +1363,59 @@ impl Expr {
         self.finish_rolling(options, RollingFunction::Kurtosis)
     }
 
+    /// Apply a rolling count of the unique values.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_n_unique(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::NUnique)
+    }
+
+    /// Apply a rolling mode.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_mode(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::Mode)
+    }
+
+    /// Apply a rolling rank, ranking every value within its window.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_rank(
+        self,
+        method: RollingRankMethod,
+        mut options: RollingOptionsFixedWindow,
+    ) -> Expr {
+        options.fn_params = Some(RollingFnParams::Rank { method });
+        self.finish_rolling(options, RollingFunction::Rank)
+    }
+
+    /// Apply a rolling arg-min, the position of the minimum within the window.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_arg_min(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::ArgMin)
+    }
+
+    /// Apply a rolling arg-max, the position of the maximum within the window.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_arg_max(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::ArgMax)
+    }
+
+    /// Apply a rolling first.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_first(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::First)
+    }
+
+    /// Apply a rolling last.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_last(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::Last)
+    }
+
+    /// Apply a rolling product.
+    #[cfg(feature = "rolling_window")]
+    pub fn rolling_product(self, options: RollingOptionsFixedWindow) -> Expr {
+        self.finish_rolling(options, RollingFunction::Product)
+    }
+
     #[cfg(feature = "rolling_window")]
     /// Apply a custom function over a rolling/ moving window of the array.
     /// This has quite some dynamic dispatch, so prefer rolling_min, max, mean, sum over this.
@@ -1699,6 +1822,36 @@ impl Expr {
         cat::CategoricalNameSpace(self)
     }
 
+    /// Get the [`map::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map::MapNameSpace {
+        map::MapNameSpace(self)
+    }
+
+    /// Get the [`json::JsonNameSpace`].
+    #[cfg(feature = "dtype-json")]
+    pub fn json(self) -> json::JsonNameSpace {
+        json::JsonNameSpace(self)
+    }
+
+    /// Get the [`url::UrlNameSpace`].
+    #[cfg(feature = "net")]
+    pub fn url(self) -> url::UrlNameSpace {
+        url::UrlNameSpace(self)
+    }
+
+    /// Get the [`ip::IpNameSpace`].
+    #[cfg(feature = "net")]
+    pub fn ip(self) -> ip::IpNameSpace {
+        ip::IpNameSpace(self)
+    }
+
+    /// Get the [`union::UnionNameSpace`].
+    #[cfg(feature = "dtype-union")]
+    pub fn union_(self) -> union::UnionNameSpace {
+        union::UnionNameSpace(self)
+    }
+
     /// Get the [`struct_::StructNameSpace`].
     #[cfg(feature = "dtype-struct")]
     pub fn struct_(self) -> struct_::StructNameSpace {
@@ -43,6 +43,20 @@ pub struct RollingCovOptions {
     pub ddof: u8,
 }
 
+/// Shape of the frame returned by a correlation matrix.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum CorrMatrixLayout {
+    /// A `column` label column followed by one column per input column.
+    #[default]
+    Square,
+    /// The columns `column_1`, `column_2` and `correlation`, with one row per pair of distinct
+    /// input columns.
+    Tidy,
+}
+
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -9,6 +9,9 @@ use polars_core::prelude::DataType;
 use polars_core::scalar::Scalar;
 use polars_io::cloud::CloudOptions;
 use polars_io::utils::file::{DynWriteable, Writeable};
+pub use polars_io::utils::partition_path::{
+    PartitionOverwriteMode, PartitionPathOptions, PartitionPathTemplate,
+};
 use polars_io::utils::sync_on_close::SyncOnCloseType;
 use polars_utils::IdxSize;
 use polars_utils::arena::Arena;
@@ -464,6 +467,7 @@ pub struct PartitionSinkType {
     pub cloud_options: Option<polars_io::cloud::CloudOptions>,
     pub per_partition_sort_by: Option<Vec<SortColumn>>,
     pub finish_callback: Option<SinkFinishCallback>,
+    pub path_options: PartitionPathOptions,
 }
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -477,6 +481,7 @@ pub struct PartitionSinkTypeIR {
     pub cloud_options: Option<polars_io::cloud::CloudOptions>,
     pub per_partition_sort_by: Option<Vec<SortColumnIR>>,
     pub finish_callback: Option<SinkFinishCallback>,
+    pub path_options: PartitionPathOptions,
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -529,6 +534,22 @@ impl SinkTypeIR {
     }
 }
 
+impl PartitionSinkTypeIR {
+    /// Checks that the path options are valid for the partitioning variant.
+    pub fn validate_path_options(&self) -> PolarsResult<()> {
+        let key_names = match &self.variant {
+            PartitionVariantIR::MaxSize(_) => Vec::new(),
+            PartitionVariantIR::Parted { key_exprs, .. }
+            | PartitionVariantIR::ByKey { key_exprs, .. } => {
+                key_exprs.iter().map(|e| e.output_name().clone()).collect()
+            },
+        };
+
+        self.path_options
+            .validate(&key_names, self.file_path_cb.is_some())
+    }
+}
+
 #[cfg(feature = "cse")]
 impl PartitionSinkTypeIR {
     pub(crate) fn traverse_and_hash<H: Hasher>(&self, expr_arena: &Arena<AExpr>, state: &mut H) {
@@ -536,6 +557,7 @@ impl PartitionSinkTypeIR {
         self.sink_options.hash(state);
         self.variant.traverse_and_hash(expr_arena, state);
         self.cloud_options.hash(state);
+        self.path_options.hash(state);
         std::mem::discriminant(&self.per_partition_sort_by).hash(state);
         if let Some(v) = &self.per_partition_sort_by {
             v.len().hash(state);
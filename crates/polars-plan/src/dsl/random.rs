@@ -1,5 +1,16 @@
 use super::*;
 
+/// Generate random version 4 UUIDs, one for every row of the context.
+#[cfg(feature = "dtype-uuid")]
+pub fn uuid4(seed: Option<u64>) -> Expr {
+    len()
+        .map_unary(FunctionExpr::Random {
+            method: RandomMethod::Uuid4,
+            seed,
+        })
+        .alias(PlSmallStr::from_static("uuid"))
+}
+
 impl Expr {
     pub fn shuffle(self, seed: Option<u64>) -> Self {
         self.map_unary(FunctionExpr::Random {
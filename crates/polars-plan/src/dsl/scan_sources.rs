@@ -7,9 +7,13 @@ use polars_core::error::{PolarsResult, feature_gated};
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "cloud")]
 use polars_io::file_cache::FileCacheEntry;
+use polars_io::path_utils::hive_filter::HivePathFilter;
 #[cfg(feature = "cloud")]
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder};
-use polars_io::{expand_paths, expand_paths_hive, expanded_from_single_directory};
+use polars_io::{
+    expand_paths, expand_paths_hive, expanded_from_single_directory, first_path_hive,
+    get_glob_start_idx,
+};
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -175,19 +179,32 @@ impl ScanSources {
 
     /// This will update `scan_args.hive_options.enabled` to `true` if the existing value is `None`
     /// and the paths are expanded from a single directory. Otherwise the existing value is maintained.
+    ///
+    /// `hive_filter` is used to skip listing hive partitions that cannot match. It is ignored
+    /// if hive partitioning is (or will be inferred as) disabled.
     #[cfg(any(feature = "ipc", feature = "parquet"))]
     pub fn expand_paths_with_hive_update(
         &self,
         scan_args: &mut UnifiedScanArgs,
         #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
+        hive_filter: Option<&HivePathFilter>,
     ) -> PolarsResult<Self> {
         match self {
             Self::Paths(paths) => {
+                let hive_may_be_enabled = match scan_args.hive_options.enabled {
+                    Some(enabled) => enabled,
+                    None => {
+                        paths.len() == 1
+                            && get_glob_start_idx(paths[0].to_str().unwrap().as_bytes()).is_none()
+                    },
+                };
+
                 let (expanded_paths, hive_start_idx) = expand_paths_hive(
                     paths,
                     scan_args.glob,
                     cloud_options,
                     scan_args.hive_options.enabled.unwrap_or(false),
+                    hive_filter.filter(|_| hive_may_be_enabled),
                 )?;
 
                 if scan_args.hive_options.enabled.is_none()
@@ -203,6 +220,24 @@ impl ScanSources {
         }
     }
 
+    /// Returns a single file from these sources, found without listing all of them. This is
+    /// used to resolve the schema when hive partition pruning leaves no files to scan.
+    #[cfg(any(feature = "ipc", feature = "parquet"))]
+    pub fn sample_path(
+        &self,
+        scan_args: &UnifiedScanArgs,
+        #[allow(unused_variables)] cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        match self {
+            Self::Paths(paths) => Ok(Self::Paths(
+                first_path_hive(paths, scan_args.glob, cloud_options)?
+                    .into_iter()
+                    .collect(),
+            )),
+            v => Ok(v.clone()),
+        }
+    }
+
     pub fn iter(&self) -> ScanSourceIter {
         ScanSourceIter {
             sources: self,
@@ -495,4 +495,86 @@ impl StringNameSpace {
     pub fn escape_regex(self) -> Expr {
         self.0.map_unary(StringFunction::EscapeRegex)
     }
+
+    /// Compute the Levenshtein distance in characters to the strings in `other`.
+    ///
+    /// Distances larger than `max_distance` are reported as `max_distance + 1`, which allows
+    /// the computation to stop early.
+    #[cfg(feature = "string_similarity")]
+    pub fn levenshtein(self, other: Expr, max_distance: Option<u32>) -> Expr {
+        self.0
+            .map_binary(StringFunction::Levenshtein { max_distance }, other)
+    }
+
+    /// Compute the Damerau-Levenshtein distance in characters to the strings in `other`, where
+    /// swapping two characters counts as a single edit.
+    ///
+    /// Distances larger than `max_distance` are reported as `max_distance + 1`.
+    #[cfg(feature = "string_similarity")]
+    pub fn damerau_levenshtein(self, other: Expr, max_distance: Option<u32>) -> Expr {
+        self.0
+            .map_binary(StringFunction::DamerauLevenshtein { max_distance }, other)
+    }
+
+    /// Compute the number of positions at which the characters differ from the strings in
+    /// `other`. Strings of different lengths result in null.
+    ///
+    /// Distances larger than `max_distance` are reported as `max_distance + 1`.
+    #[cfg(feature = "string_similarity")]
+    pub fn hamming(self, other: Expr, max_distance: Option<u32>) -> Expr {
+        self.0
+            .map_binary(StringFunction::Hamming { max_distance }, other)
+    }
+
+    /// Compute the Jaro-Winkler similarity to the strings in `other`, between 0 and 1.
+    #[cfg(feature = "string_similarity")]
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0.map_binary(StringFunction::JaroWinkler, other)
+    }
+
+    /// Compute the Jaccard similarity between the sets of character `n`-grams of the strings
+    /// and the strings in `other`, between 0 and 1.
+    #[cfg(feature = "string_similarity")]
+    pub fn jaccard_ngram(self, other: Expr, n: usize) -> Expr {
+        self.0.map_binary(StringFunction::JaccardNgram(n), other)
+    }
+
+    /// Split the string on every match of the regular expression `pat`.
+    #[cfg(feature = "string_tokenize")]
+    pub fn split_regex(self, pat: Expr) -> Expr {
+        self.0.map_binary(StringFunction::SplitRegex, pat)
+    }
+
+    /// Split the string into words at the Unicode word boundaries, dropping whitespace and
+    /// punctuation.
+    #[cfg(feature = "string_tokenize")]
+    pub fn tokenize(self) -> Expr {
+        self.0.map_unary(StringFunction::Tokenize)
+    }
+
+    /// Count the words as found by [`tokenize`](Self::tokenize).
+    #[cfg(feature = "string_tokenize")]
+    pub fn count_tokens(self) -> Expr {
+        self.0.map_unary(StringFunction::CountTokens)
+    }
+
+    /// Get all overlapping substrings of `n` characters.
+    #[cfg(feature = "string_tokenize")]
+    pub fn char_ngrams(self, n: usize) -> Expr {
+        self.0.map_unary(StringFunction::CharNgrams(n))
+    }
+
+    /// Get all overlapping sequences of `n` words, joined by a single space.
+    #[cfg(feature = "string_tokenize")]
+    pub fn word_ngrams(self, n: usize) -> Expr {
+        self.0.map_unary(StringFunction::WordNgrams(n))
+    }
+
+    /// Split the string into words as [`tokenize`](Self::tokenize) does, leaving out the
+    /// `stop_words`.
+    #[cfg(feature = "string_tokenize")]
+    pub fn remove_stop_words(self, stop_words: Vec<PlSmallStr>) -> Expr {
+        self.0
+            .map_unary(StringFunction::RemoveStopWords(stop_words))
+    }
 }
@@ -0,0 +1,24 @@
+use crate::dsl::function_expr::UnionFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Union`].
+pub struct UnionNameSpace(pub Expr);
+
+impl UnionNameSpace {
+    /// Get the index of the active variant of every row.
+    pub fn type_id(self) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::UnionExpr(UnionFunction::TypeId))
+    }
+
+    /// Get the values of the variant `name`, null in rows where another variant is active.
+    pub fn field(self, name: &str) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::UnionExpr(UnionFunction::Field(name.into())))
+    }
+}
+
+/// Create a union from a struct of nullable fields, at most one field can be non-null per row.
+pub fn union_from_struct(expr: Expr) -> Expr {
+    expr.map_unary(FunctionExpr::UnionExpr(UnionFunction::FromStruct))
+}
@@ -0,0 +1,20 @@
+use crate::dsl::function_expr::UrlFunction;
+use crate::prelude::*;
+
+/// Specialized expressions for URLs stored as [`DataType::String`].
+pub struct UrlNameSpace(pub Expr);
+
+impl UrlNameSpace {
+    /// Split URLs into a struct with the fields `scheme`, `host`, `port`, `path`, `query` and
+    /// `fragment`. Missing components are null, except for the path, which is empty.
+    pub fn parse(self) -> Expr {
+        self.0.map_unary(FunctionExpr::UrlExpr(UrlFunction::Parse))
+    }
+
+    /// Extract the percent-decoded value of the first query parameter called `name`, null in rows
+    /// where the parameter is absent.
+    pub fn query_param(self, name: &str) -> Expr {
+        self.0
+            .map_unary(FunctionExpr::UrlExpr(UrlFunction::QueryParam(name.into())))
+    }
+}
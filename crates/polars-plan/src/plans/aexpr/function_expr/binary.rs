@@ -18,6 +18,18 @@ pub enum IRBinaryFunction {
     Size,
     #[cfg(feature = "binary_encoding")]
     FromBuffer(DataType, bool),
+    #[cfg(feature = "stable_hash")]
+    Md5(bool),
+    #[cfg(feature = "stable_hash")]
+    Sha1(bool),
+    #[cfg(feature = "stable_hash")]
+    Sha256(bool),
+    #[cfg(feature = "stable_hash")]
+    Xxh3_64(u64),
+    #[cfg(feature = "stable_hash")]
+    Xxh3_128(u64, bool),
+    #[cfg(feature = "stable_hash")]
+    Murmur3_32(u32, bool),
 }
 
 impl IRBinaryFunction {
@@ -33,9 +45,38 @@ impl IRBinaryFunction {
             Size => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, _) => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "stable_hash")]
+            Md5(hex) | Sha1(hex) | Sha256(hex) | Xxh3_128(_, hex) => {
+                self.ensure_hashable(&mapper)?;
+                mapper.with_dtype(if *hex {
+                    DataType::String
+                } else {
+                    DataType::Binary
+                })
+            },
+            #[cfg(feature = "stable_hash")]
+            Xxh3_64(_) => {
+                self.ensure_hashable(&mapper)?;
+                mapper.with_dtype(DataType::UInt64)
+            },
+            #[cfg(feature = "stable_hash")]
+            Murmur3_32(_, _) => {
+                self.ensure_hashable(&mapper)?;
+                mapper.with_dtype(DataType::Int32)
+            },
         }
     }
 
+    #[cfg(feature = "stable_hash")]
+    fn ensure_hashable(&self, mapper: &FieldsMapper) -> PolarsResult<()> {
+        let dtype = mapper.args()[0].dtype();
+        polars_ensure!(
+            matches!(dtype, DataType::String | DataType::Binary),
+            InvalidOperation: "`{}` expects a String or Binary input, got {}", self, dtype
+        );
+        Ok(())
+    }
+
     pub fn function_options(&self) -> FunctionOptions {
         use IRBinaryFunction as B;
         match self {
@@ -49,6 +90,13 @@ impl IRBinaryFunction {
             | B::Base64Decode(_)
             | B::Base64Encode
             | B::FromBuffer(_, _) => FunctionOptions::elementwise(),
+            #[cfg(feature = "stable_hash")]
+            B::Md5(_)
+            | B::Sha1(_)
+            | B::Sha256(_)
+            | B::Xxh3_64(_)
+            | B::Xxh3_128(_, _)
+            | B::Murmur3_32(_, _) => FunctionOptions::elementwise(),
         }
     }
 }
@@ -71,6 +119,18 @@ impl Display for IRBinaryFunction {
             Size => "size_bytes",
             #[cfg(feature = "binary_encoding")]
             FromBuffer(_, _) => "from_buffer",
+            #[cfg(feature = "stable_hash")]
+            Md5(_) => "md5",
+            #[cfg(feature = "stable_hash")]
+            Sha1(_) => "sha1",
+            #[cfg(feature = "stable_hash")]
+            Sha256(_) => "sha256",
+            #[cfg(feature = "stable_hash")]
+            Xxh3_64(_) => "xxh3_64",
+            #[cfg(feature = "stable_hash")]
+            Xxh3_128(_, _) => "xxh3_128",
+            #[cfg(feature = "stable_hash")]
+            Murmur3_32(_, _) => "murmur3_32",
         };
         write!(f, "bin.{s}")
     }
@@ -100,6 +160,18 @@ impl From<IRBinaryFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Size => map!(size_bytes),
             #[cfg(feature = "binary_encoding")]
             FromBuffer(dtype, is_little_endian) => map!(from_buffer, &dtype, is_little_endian),
+            #[cfg(feature = "stable_hash")]
+            Md5(hex) => map!(digest, |ca: &BinaryChunked| ca.md5(), hex),
+            #[cfg(feature = "stable_hash")]
+            Sha1(hex) => map!(digest, |ca: &BinaryChunked| ca.sha1(), hex),
+            #[cfg(feature = "stable_hash")]
+            Sha256(hex) => map!(digest, |ca: &BinaryChunked| ca.sha256(), hex),
+            #[cfg(feature = "stable_hash")]
+            Xxh3_64(seed) => map!(xxh3_64, seed),
+            #[cfg(feature = "stable_hash")]
+            Xxh3_128(seed, hex) => map!(digest, |ca: &BinaryChunked| ca.xxh3_128(seed), hex),
+            #[cfg(feature = "stable_hash")]
+            Murmur3_32(seed, spark) => map!(murmur3_32, seed, spark),
         }
     }
 }
@@ -173,6 +245,38 @@ pub(super) fn from_buffer(
         .map(|val| val.into())
 }
 
+#[cfg(feature = "stable_hash")]
+fn hash_input(s: &Column) -> PolarsResult<BinaryChunked> {
+    match s.dtype() {
+        DataType::String => Ok(s.str()?.as_binary()),
+        _ => s.binary().cloned(),
+    }
+}
+
+#[cfg(feature = "stable_hash")]
+pub(super) fn digest(
+    s: &Column,
+    f: impl Fn(&BinaryChunked) -> BinaryChunked,
+    hex: bool,
+) -> PolarsResult<Column> {
+    let out = f(&hash_input(s)?);
+    Ok(if hex {
+        out.hex_encode().into()
+    } else {
+        out.into_column()
+    })
+}
+
+#[cfg(feature = "stable_hash")]
+pub(super) fn xxh3_64(s: &Column, seed: u64) -> PolarsResult<Column> {
+    Ok(hash_input(s)?.xxh3_64(seed).into_column())
+}
+
+#[cfg(feature = "stable_hash")]
+pub(super) fn murmur3_32(s: &Column, seed: u32, spark: bool) -> PolarsResult<Column> {
+    Ok(hash_input(s)?.murmur3_32(seed, spark).into_column())
+}
+
 impl From<IRBinaryFunction> for IRFunctionExpr {
     fn from(b: IRBinaryFunction) -> Self {
         IRFunctionExpr::BinaryExpr(b)
@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter};
 
 use polars_core::prelude::*;
-use polars_ops::prelude::Roll;
+use polars_ops::prelude::{HolidayCalendar, Roll};
 
 use super::FunctionOptions;
 use crate::dsl::SpecialEq;
@@ -15,15 +15,18 @@ pub enum IRBusinessFunction {
     BusinessDayCount {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
     },
     AddBusinessDay {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
         roll: Roll,
     },
     IsBusinessDay {
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<HolidayCalendar>,
     },
 }
 
@@ -64,21 +67,30 @@ impl From<IRBusinessFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             BusinessDayCount {
                 week_mask,
                 holidays,
+                calendar,
             } => {
-                map_as_slice!(business_day_count, week_mask, &holidays)
+                map_as_slice!(business_day_count, week_mask, &holidays, calendar.as_ref())
             },
             AddBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
                 roll,
             } => {
-                map_as_slice!(add_business_days, week_mask, &holidays, roll)
+                map_as_slice!(
+                    add_business_days,
+                    week_mask,
+                    &holidays,
+                    calendar.as_ref(),
+                    roll
+                )
             },
             IsBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
             } => {
-                map_as_slice!(is_business_day, week_mask, &holidays)
+                map_as_slice!(is_business_day, week_mask, &holidays, calendar.as_ref())
             },
         }
     }
@@ -88,6 +100,7 @@ pub(super) fn business_day_count(
     s: &[Column],
     week_mask: [bool; 7],
     holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
 ) -> PolarsResult<Column> {
     let start = &s[0];
     let end = &s[1];
@@ -96,6 +109,7 @@ pub(super) fn business_day_count(
         end.as_materialized_series(),
         week_mask,
         holidays,
+        calendar,
     )
     .map(Column::from)
 }
@@ -103,6 +117,7 @@ pub(super) fn add_business_days(
     s: &[Column],
     week_mask: [bool; 7],
     holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
     roll: Roll,
 ) -> PolarsResult<Column> {
     let start = &s[0];
@@ -112,6 +127,7 @@ pub(super) fn add_business_days(
         n.as_materialized_series(),
         week_mask,
         holidays,
+        calendar,
         roll,
     )
     .map(Column::from)
@@ -121,8 +137,14 @@ pub(super) fn is_business_day(
     s: &[Column],
     week_mask: [bool; 7],
     holidays: &[i32],
+    calendar: Option<&HolidayCalendar>,
 ) -> PolarsResult<Column> {
     let dates = &s[0];
-    polars_ops::prelude::is_business_day(dates.as_materialized_series(), week_mask, holidays)
-        .map(Column::from)
+    polars_ops::prelude::is_business_day(
+        dates.as_materialized_series(),
+        week_mask,
+        holidays,
+        calendar,
+    )
+    .map(Column::from)
 }
@@ -6,6 +6,7 @@ pub enum IRCorrelationMethod {
     Pearson,
     #[cfg(all(feature = "rank", feature = "propagate_nans"))]
     SpearmanRank(bool),
+    KendallTau,
     Covariance(u8),
 }
 
@@ -16,6 +17,7 @@ impl Display for IRCorrelationMethod {
             Pearson => "pearson",
             #[cfg(all(feature = "rank", feature = "propagate_nans"))]
             SpearmanRank(_) => "spearman_rank",
+            KendallTau => "kendall_tau",
             Covariance(_) => return write!(f, "covariance"),
         };
         write!(f, "{s}_correlation")
@@ -34,6 +36,7 @@ pub(super) fn corr(s: &[Column], method: IRCorrelationMethod) -> PolarsResult<Co
         IRCorrelationMethod::Pearson => pearson_corr(s),
         #[cfg(all(feature = "rank", feature = "propagate_nans"))]
         IRCorrelationMethod::SpearmanRank(propagate_nans) => spearman_rank_corr(s, propagate_nans),
+        IRCorrelationMethod::KendallTau => kendall_tau_corr(s),
         IRCorrelationMethod::Covariance(ddof) => covariance(s, ddof),
     }
 }
@@ -87,6 +90,31 @@ fn pearson_corr(s: &[Column]) -> PolarsResult<Column> {
     Ok(Column::new(name, &[ret]))
 }
 
+fn kendall_tau_corr(s: &[Column]) -> PolarsResult<Column> {
+    let a = &s[0];
+    let b = &s[1];
+    let name = PlSmallStr::from_static("kendall_tau_correlation");
+
+    use polars_ops::chunked_array::cov::kendall_tau_b;
+    let ret = match a.dtype() {
+        DataType::Float32 => {
+            let ret = kendall_tau_b(a.f32().unwrap(), b.f32().unwrap()).map(|v| v as f32);
+            return Ok(Column::new(name, &[ret]));
+        },
+        DataType::Float64 => kendall_tau_b(a.f64().unwrap(), b.f64().unwrap()),
+        DataType::Int32 => kendall_tau_b(a.i32().unwrap(), b.i32().unwrap()),
+        DataType::Int64 => kendall_tau_b(a.i64().unwrap(), b.i64().unwrap()),
+        DataType::UInt32 => kendall_tau_b(a.u32().unwrap(), b.u32().unwrap()),
+        DataType::UInt64 => kendall_tau_b(a.u64().unwrap(), b.u64().unwrap()),
+        _ => {
+            let a = a.cast(&DataType::Float64)?;
+            let b = b.cast(&DataType::Float64)?;
+            kendall_tau_b(a.f64().unwrap(), b.f64().unwrap())
+        },
+    };
+    Ok(Column::new(name, &[ret]))
+}
+
 #[cfg(all(feature = "rank", feature = "propagate_nans"))]
 fn spearman_rank_corr(s: &[Column], propagate_nans: bool) -> PolarsResult<Column> {
     use polars_core::utils::coalesce_nulls_columns;
@@ -63,6 +63,16 @@ pub enum IRTemporalFunction {
     MonthStart,
     #[cfg(feature = "month_end")]
     MonthEnd,
+    #[cfg(feature = "dtype-date")]
+    FiscalYear(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalQuarter(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalPeriod(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalWeek(FiscalCalendar),
+    #[cfg(feature = "dtype-date")]
+    FiscalTruncate(FiscalCalendar, FiscalUnit),
     #[cfg(feature = "timezones")]
     BaseUtcOffset,
     #[cfg(feature = "timezones")]
@@ -121,6 +131,12 @@ impl IRTemporalFunction {
             MonthStart => mapper.with_same_dtype(),
             #[cfg(feature = "month_end")]
             MonthEnd => mapper.with_same_dtype(),
+            #[cfg(feature = "dtype-date")]
+            FiscalYear(_) => mapper.with_dtype(DataType::Int32),
+            #[cfg(feature = "dtype-date")]
+            FiscalQuarter(_) | FiscalPeriod(_) | FiscalWeek(_) => mapper.with_dtype(DataType::Int8),
+            #[cfg(feature = "dtype-date")]
+            FiscalTruncate(_, _) => mapper.with_same_dtype(),
             #[cfg(feature = "timezones")]
             BaseUtcOffset => mapper.with_dtype(DataType::Duration(TimeUnit::Milliseconds)),
             #[cfg(feature = "timezones")]
@@ -187,6 +203,12 @@ impl IRTemporalFunction {
             T::MonthStart => FunctionOptions::elementwise(),
             #[cfg(feature = "month_end")]
             T::MonthEnd => FunctionOptions::elementwise(),
+            #[cfg(feature = "dtype-date")]
+            T::FiscalYear(_)
+            | T::FiscalQuarter(_)
+            | T::FiscalPeriod(_)
+            | T::FiscalWeek(_)
+            | T::FiscalTruncate(_, _) => FunctionOptions::elementwise(),
             #[cfg(feature = "timezones")]
             T::BaseUtcOffset | T::DSTOffset => FunctionOptions::elementwise(),
             T::Truncate => FunctionOptions::elementwise(),
@@ -259,6 +281,16 @@ impl Display for IRTemporalFunction {
             MonthStart => "month_start",
             #[cfg(feature = "month_end")]
             MonthEnd => "month_end",
+            #[cfg(feature = "dtype-date")]
+            FiscalYear(_) => "fiscal_year",
+            #[cfg(feature = "dtype-date")]
+            FiscalQuarter(_) => "fiscal_quarter",
+            #[cfg(feature = "dtype-date")]
+            FiscalPeriod(_) => "fiscal_period",
+            #[cfg(feature = "dtype-date")]
+            FiscalWeek(_) => "fiscal_week",
+            #[cfg(feature = "dtype-date")]
+            FiscalTruncate(_, _) => "fiscal_truncate",
             #[cfg(feature = "timezones")]
             BaseUtcOffset => "base_utc_offset",
             #[cfg(feature = "timezones")]
@@ -572,6 +604,73 @@ pub(super) fn month_end(s: &Column) -> PolarsResult<Column> {
     })
 }
 
+#[cfg(feature = "dtype-date")]
+fn fiscal_field<T: PolarsNumericType>(
+    s: &Column,
+    f: impl Fn(
+        &dyn PolarsFiscal,
+        Option<&arrow::legacy::time_zone::Tz>,
+    ) -> PolarsResult<ChunkedArray<T>>,
+) -> PolarsResult<Column> {
+    Ok(match s.dtype() {
+        DataType::Datetime(_, tz) => match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => f(s.datetime().unwrap(), tz.parse::<Tz>().ok().as_ref())?.into_column(),
+            _ => f(s.datetime().unwrap(), None)?.into_column(),
+        },
+        DataType::Date => f(s.date().unwrap(), None)?.into_column(),
+        dt => polars_bail!(opq = fiscal_calendar, got = dt, expected = "date/datetime"),
+    })
+}
+
+#[cfg(feature = "dtype-date")]
+pub(super) fn fiscal_year(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    fiscal_field(s, |ca, tz| ca.fiscal_year(calendar, tz))
+}
+
+#[cfg(feature = "dtype-date")]
+pub(super) fn fiscal_quarter(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    fiscal_field(s, |ca, tz| ca.fiscal_quarter(calendar, tz))
+}
+
+#[cfg(feature = "dtype-date")]
+pub(super) fn fiscal_period(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    fiscal_field(s, |ca, tz| ca.fiscal_period(calendar, tz))
+}
+
+#[cfg(feature = "dtype-date")]
+pub(super) fn fiscal_week(s: &Column, calendar: &FiscalCalendar) -> PolarsResult<Column> {
+    fiscal_field(s, |ca, tz| ca.fiscal_week(calendar, tz))
+}
+
+#[cfg(feature = "dtype-date")]
+pub(super) fn fiscal_truncate(
+    s: &Column,
+    calendar: &FiscalCalendar,
+    unit: FiscalUnit,
+) -> PolarsResult<Column> {
+    let mut out = match s.dtype() {
+        DataType::Datetime(_, tz) => match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => s
+                .datetime()?
+                .fiscal_truncate(calendar, unit, tz.parse::<Tz>().ok().as_ref())?
+                .into_column(),
+            _ => s
+                .datetime()?
+                .fiscal_truncate(calendar, unit, None)?
+                .into_column(),
+        },
+        DataType::Date => s
+            .date()?
+            .fiscal_truncate(calendar, unit, None)?
+            .into_column(),
+        dt => polars_bail!(opq = fiscal_truncate, got = dt, expected = "date/datetime"),
+    };
+    out.set_sorted_flag(s.is_sorted_flag());
+    Ok(out)
+}
+
 #[cfg(feature = "timezones")]
 pub(super) fn base_utc_offset(s: &Column) -> PolarsResult<Column> {
     match s.dtype() {
@@ -101,6 +101,11 @@ pub(super) fn repeat_by(s: &[Column]) -> PolarsResult<Column> {
         .map(|ok| ok.into_column())
 }
 
+#[cfg(feature = "stable_hash")]
+pub(super) fn stable_hash_rows(s: &[Column], seed: u64) -> PolarsResult<Column> {
+    polars_ops::prelude::stable_row_hashes(s, seed).map(|ca| ca.into_column())
+}
+
 pub(super) fn max_horizontal(s: &mut [Column]) -> PolarsResult<Option<Column>> {
     polars_ops::prelude::max_horizontal(s)
 }
@@ -0,0 +1,80 @@
+use polars_ops::series::{ip_in_network, ip_to_binary, ip_to_int, ip_to_string};
+
+use super::*;
+use crate::map;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRIpFunction {
+    ToInt { ipv6: bool },
+    ToBinary,
+    ToString,
+    InNetwork(PlSmallStr),
+}
+
+impl IRIpFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRIpFunction::*;
+        let dtype = mapper.args()[0].dtype();
+        polars_ensure!(
+            matches!(
+                dtype,
+                DataType::String | DataType::Binary | DataType::UInt32 | DataType::UInt128
+            ),
+            InvalidOperation: "`{}` expects String, Binary, UInt32 or UInt128 IP addresses, got {}",
+            self, dtype
+        );
+        match self {
+            ToInt { ipv6: false } => mapper.with_dtype(DataType::UInt32),
+            ToInt { ipv6: true } => mapper.with_dtype(DataType::UInt128),
+            ToBinary => mapper.with_dtype(DataType::Binary),
+            ToString => mapper.with_dtype(DataType::String),
+            InNetwork(_) => mapper.with_dtype(DataType::Boolean),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IRIpFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRIpFunction::*;
+        let name = match self {
+            ToInt { .. } => "to_int",
+            ToBinary => "to_binary",
+            ToString => "to_string",
+            InNetwork(_) => "in_network",
+        };
+        write!(f, "ip.{name}")
+    }
+}
+
+impl From<IRIpFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRIpFunction) -> Self {
+        use IRIpFunction::*;
+        match func {
+            ToInt { ipv6 } => map!(to_int, ipv6),
+            ToBinary => map!(to_binary),
+            ToString => map!(to_string),
+            InNetwork(cidr) => map!(in_network, &cidr),
+        }
+    }
+}
+
+pub(super) fn to_int(s: &Column, ipv6: bool) -> PolarsResult<Column> {
+    ip_to_int(s.as_materialized_series(), ipv6).map(Column::from)
+}
+
+pub(super) fn to_binary(s: &Column) -> PolarsResult<Column> {
+    Ok(ip_to_binary(s.as_materialized_series())?.into_column())
+}
+
+pub(super) fn to_string(s: &Column) -> PolarsResult<Column> {
+    Ok(ip_to_string(s.as_materialized_series())?.into_column())
+}
+
+pub(super) fn in_network(s: &Column, cidr: &str) -> PolarsResult<Column> {
+    Ok(ip_in_network(s.as_materialized_series(), cidr)?.into_column())
+}
@@ -0,0 +1,61 @@
+use polars_ops::chunked_array::json::JsonNameSpace;
+
+use super::*;
+use crate::map;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRJsonFunction {
+    Get { path: PlSmallStr, dtype: DataType },
+    Compact,
+    Pretty,
+}
+
+impl IRJsonFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRJsonFunction::*;
+        match self {
+            Get { dtype, .. } => mapper.with_dtype(dtype.clone()),
+            Compact | Pretty => mapper.with_dtype(DataType::Json),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IRJsonFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRJsonFunction::*;
+        let name = match self {
+            Get { .. } => "get",
+            Compact => "compact",
+            Pretty => "pretty",
+        };
+        write!(f, "json.{name}")
+    }
+}
+
+impl From<IRJsonFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRJsonFunction) -> Self {
+        use IRJsonFunction::*;
+        match func {
+            Get { path, dtype } => map!(get, &path, &dtype),
+            Compact => map!(compact),
+            Pretty => map!(pretty),
+        }
+    }
+}
+
+pub(super) fn get(s: &Column, path: &str, dtype: &DataType) -> PolarsResult<Column> {
+    s.json()?.json_get(path, dtype).map(Column::from)
+}
+
+pub(super) fn compact(s: &Column) -> PolarsResult<Column> {
+    Ok(s.json()?.json_compact().into_column())
+}
+
+pub(super) fn pretty(s: &Column) -> PolarsResult<Column> {
+    Ok(s.json()?.json_pretty().into_column())
+}
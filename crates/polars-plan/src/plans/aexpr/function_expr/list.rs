@@ -47,6 +47,15 @@ pub enum IRListFunction {
         null_behavior: NullBehavior,
     },
     Sort(SortOptions),
+    SortBy(SortOptions),
+    ArgSort(SortOptions),
+    #[cfg(feature = "search_sorted")]
+    SearchSorted {
+        side: SearchSortedSide,
+        descending: bool,
+    },
+    #[cfg(feature = "top_k")]
+    TopK,
     Reverse,
     Unique(bool),
     NUnique,
@@ -61,6 +70,12 @@ pub enum IRListFunction {
     ToArray(usize),
     #[cfg(feature = "list_to_struct")]
     ToStruct(ListToStructArgs),
+    #[cfg(feature = "list_zip")]
+    Zip,
+    #[cfg(feature = "list_zip")]
+    Unzip,
+    #[cfg(feature = "list_zip")]
+    Enumerate,
 }
 
 impl IRListFunction {
@@ -114,6 +129,12 @@ impl IRListFunction {
                 Ok(DataType::List(Box::new(inner_dt)))
             }),
             Sort(_) => mapper.with_same_dtype(),
+            SortBy(_) => mapper.with_same_dtype(),
+            ArgSort(_) => mapper.with_dtype(DataType::List(Box::new(IDX_DTYPE))),
+            #[cfg(feature = "search_sorted")]
+            SearchSorted { .. } => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "top_k")]
+            TopK => mapper.with_same_dtype(),
             Reverse => mapper.with_same_dtype(),
             Unique(_) => mapper.with_same_dtype(),
             Length => mapper.with_dtype(IDX_DTYPE),
@@ -129,6 +150,23 @@ impl IRListFunction {
             NUnique => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "list_to_struct")]
             ToStruct(args) => mapper.try_map_dtype(|x| args.get_output_dtype(x)),
+            #[cfg(feature = "list_zip")]
+            Zip => {
+                let dtype = zip_dtype(mapper.args())?;
+                mapper.with_dtype(dtype)
+            },
+            #[cfg(feature = "list_zip")]
+            Unzip => mapper.try_map_dtype(unzip_dtype),
+            #[cfg(feature = "list_zip")]
+            Enumerate => mapper.try_map_dtype(|dt| {
+                let DataType::List(inner) = dt else {
+                    polars_bail!(op = "list.enumerate", dt);
+                };
+                Ok(DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new(PlSmallStr::from_static("index"), IDX_DTYPE),
+                    Field::new(PlSmallStr::from_static("value"), inner.as_ref().clone()),
+                ]))))
+            }),
         }
     }
 
@@ -171,10 +209,16 @@ impl IRListFunction {
             | L::ArgMin
             | L::ArgMax
             | L::Sort(_)
+            | L::SortBy(_)
+            | L::ArgSort(_)
             | L::Reverse
             | L::Unique(_)
             | L::Join(_)
             | L::NUnique => FunctionOptions::elementwise(),
+            #[cfg(feature = "search_sorted")]
+            L::SearchSorted { .. } => FunctionOptions::elementwise(),
+            #[cfg(feature = "top_k")]
+            L::TopK => FunctionOptions::elementwise(),
             #[cfg(feature = "list_any_all")]
             L::Any | L::All => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-array")]
@@ -183,10 +227,49 @@ impl IRListFunction {
             L::ToStruct(ListToStructArgs::FixedWidth(_)) => FunctionOptions::elementwise(),
             #[cfg(feature = "list_to_struct")]
             L::ToStruct(ListToStructArgs::InferWidth { .. }) => FunctionOptions::groupwise(),
+            #[cfg(feature = "list_zip")]
+            L::Zip | L::Unzip | L::Enumerate => FunctionOptions::elementwise(),
         }
     }
 }
 
+#[cfg(feature = "list_zip")]
+fn zip_dtype(fields: &[Field]) -> PolarsResult<DataType> {
+    let mut names = PlHashSet::with_capacity(fields.len());
+    let struct_fields = fields
+        .iter()
+        .map(|f| {
+            let DataType::List(inner) = f.dtype() else {
+                polars_bail!(op = "list.zip", f.dtype());
+            };
+            polars_ensure!(names.insert(f.name().as_str()), duplicate_field = f.name());
+            Ok(Field::new(f.name().clone(), inner.as_ref().clone()))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(DataType::List(Box::new(DataType::Struct(struct_fields))))
+}
+
+#[cfg(feature = "list_zip")]
+fn unzip_dtype(dt: &DataType) -> PolarsResult<DataType> {
+    let DataType::List(inner) = dt else {
+        polars_bail!(op = "list.unzip", dt);
+    };
+    let DataType::Struct(fields) = inner.as_ref() else {
+        polars_bail!(InvalidOperation: "list.unzip expected a list of structs, got {}", dt);
+    };
+    Ok(DataType::Struct(
+        fields
+            .iter()
+            .map(|f| {
+                Field::new(
+                    f.name().clone(),
+                    DataType::List(Box::new(f.dtype().clone())),
+                )
+            })
+            .collect(),
+    ))
+}
+
 #[cfg(feature = "dtype-array")]
 fn map_list_dtype_to_array_dtype(datatype: &DataType, width: usize) -> PolarsResult<DataType> {
     if let DataType::List(inner) = datatype {
@@ -236,6 +319,12 @@ impl Display for IRListFunction {
             Diff { .. } => "diff",
             Length => "length",
             Sort(_) => "sort",
+            SortBy(_) => "sort_by",
+            ArgSort(_) => "arg_sort",
+            #[cfg(feature = "search_sorted")]
+            SearchSorted { .. } => "search_sorted",
+            #[cfg(feature = "top_k")]
+            TopK => "top_k",
             Reverse => "reverse",
             Unique(is_stable) => {
                 if *is_stable {
@@ -256,6 +345,12 @@ impl Display for IRListFunction {
             ToArray(_) => "to_array",
             #[cfg(feature = "list_to_struct")]
             ToStruct(_) => "to_struct",
+            #[cfg(feature = "list_zip")]
+            Zip => "zip",
+            #[cfg(feature = "list_zip")]
+            Unzip => "unzip",
+            #[cfg(feature = "list_zip")]
+            Enumerate => "enumerate",
         };
         write!(f, "list.{name}")
     }
@@ -305,6 +400,12 @@ impl From<IRListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "diff")]
             Diff { n, null_behavior } => map!(diff, n, null_behavior),
             Sort(options) => map!(sort, options),
+            SortBy(options) => map_as_slice!(sort_by, options),
+            ArgSort(options) => map!(arg_sort, options),
+            #[cfg(feature = "search_sorted")]
+            SearchSorted { side, descending } => map_as_slice!(search_sorted, side, descending),
+            #[cfg(feature = "top_k")]
+            TopK => map_as_slice!(top_k),
             Reverse => map!(reverse),
             Unique(is_stable) => map!(unique, is_stable),
             #[cfg(feature = "list_sets")]
@@ -319,6 +420,12 @@ impl From<IRListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             NUnique => map!(n_unique),
             #[cfg(feature = "list_to_struct")]
             ToStruct(args) => map!(to_struct, &args),
+            #[cfg(feature = "list_zip")]
+            Zip => map_as_slice!(zip),
+            #[cfg(feature = "list_zip")]
+            Unzip => map!(unzip),
+            #[cfg(feature = "list_zip")]
+            Enumerate => map!(enumerate),
         }
     }
 }
@@ -716,6 +823,34 @@ pub(super) fn sort(s: &Column, options: SortOptions) -> PolarsResult<Column> {
     Ok(s.list()?.lst_sort(options)?.into_column())
 }
 
+pub(super) fn sort_by(args: &[Column], options: SortOptions) -> PolarsResult<Column> {
+    let ca = args[0].list()?;
+    let by = args[1].list()?;
+    Ok(ca.lst_sort_by(by, options)?.into_column())
+}
+
+pub(super) fn arg_sort(s: &Column, options: SortOptions) -> PolarsResult<Column> {
+    Ok(s.list()?.lst_arg_sort(options).into_column())
+}
+
+#[cfg(feature = "search_sorted")]
+pub(super) fn search_sorted(
+    args: &[Column],
+    side: SearchSortedSide,
+    descending: bool,
+) -> PolarsResult<Column> {
+    let ca = args[0].list()?;
+    let values = args[1].as_materialized_series();
+    Ok(ca
+        .lst_search_sorted(values, side, descending)?
+        .into_column())
+}
+
+#[cfg(feature = "top_k")]
+pub(super) fn top_k(args: &[Column]) -> PolarsResult<Column> {
+    Ok(args[0].list()?.lst_top_k(&args[1])?.into_column())
+}
+
 pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(s.list()?.lst_reverse().into_column())
 }
@@ -783,6 +918,21 @@ pub(super) fn to_struct(s: &Column, args: &ListToStructArgs) -> PolarsResult<Col
     Ok(s.list()?.to_struct(args)?.into_series().into())
 }
 
+#[cfg(feature = "list_zip")]
+pub(super) fn zip(args: &[Column]) -> PolarsResult<Column> {
+    Ok(args[0].list()?.lst_zip(&args[1..])?.into_column())
+}
+
+#[cfg(feature = "list_zip")]
+pub(super) fn unzip(s: &Column) -> PolarsResult<Column> {
+    Ok(s.list()?.lst_unzip()?.into_column())
+}
+
+#[cfg(feature = "list_zip")]
+pub(super) fn enumerate(s: &Column) -> PolarsResult<Column> {
+    Ok(s.list()?.lst_enumerate()?.into_column())
+}
+
 pub(super) fn n_unique(s: &Column) -> PolarsResult<Column> {
     Ok(s.list()?.lst_n_unique()?.into_column())
 }
@@ -0,0 +1,101 @@
+use polars_ops::chunked_array::map::MapNameSpace;
+
+use super::*;
+use crate::{map, map_as_slice};
+
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRMapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    FromLists,
+}
+
+impl IRMapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRMapFunction::*;
+        match self {
+            Get => mapper.try_map_dtype(|dt| match dt {
+                DataType::Map(_, value) => Ok(value.as_ref().clone()),
+                dt => polars_bail!(op = "map.get", got = dt, expected = "Map"),
+            }),
+            ContainsKey => mapper.with_dtype(DataType::Boolean),
+            Keys => mapper.try_map_dtype(|dt| match dt {
+                DataType::Map(key, _) => Ok(DataType::List(key.clone())),
+                dt => polars_bail!(op = "map.keys", got = dt, expected = "Map"),
+            }),
+            Values => mapper.try_map_dtype(|dt| match dt {
+                DataType::Map(_, value) => Ok(DataType::List(value.clone())),
+                dt => polars_bail!(op = "map.values", got = dt, expected = "Map"),
+            }),
+            FromLists => mapper.try_map_dtypes(|dtypes| match dtypes {
+                [DataType::List(key), DataType::List(value)] => {
+                    Ok(DataType::Map(key.clone(), value.clone()))
+                },
+                [DataType::List(_), dt] | [dt, _] => {
+                    polars_bail!(op = "map.from_lists", got = dt, expected = "List")
+                },
+                _ => unreachable!(),
+            }),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IRMapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRMapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            FromLists => "from_lists",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<IRMapFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRMapFunction) -> Self {
+        use IRMapFunction::*;
+        match func {
+            Get => map_as_slice!(get),
+            ContainsKey => map_as_slice!(contains_key),
+            Keys => map!(keys),
+            Values => map!(values),
+            FromLists => map_as_slice!(from_lists),
+        }
+    }
+}
+
+pub(super) fn get(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_get(key).map(Column::from)
+}
+
+pub(super) fn contains_key(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    let key = s[1].as_materialized_series();
+    ca.map_contains_key(key).map(|ca| ca.into_column())
+}
+
+pub(super) fn keys(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_keys().map(|ca| ca.into_column())
+}
+
+pub(super) fn values(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_values().map(|ca| ca.into_column())
+}
+
+pub(super) fn from_lists(s: &[Column]) -> PolarsResult<Column> {
+    let keys = s[0].list()?;
+    let values = s[1].list()?;
+    polars_ops::chunked_array::map::map_from_lists(keys, values).map(|ca| ca.into_column())
+}
@@ -36,9 +36,15 @@ mod fill_null;
 mod fused;
 #[cfg(feature = "index_of")]
 mod index_of;
+#[cfg(feature = "net")]
+mod ip;
+#[cfg(feature = "dtype-json")]
+mod json;
 mod list;
 #[cfg(feature = "log")]
 mod log;
+#[cfg(feature = "dtype-map")]
+mod map;
 mod nan;
 #[cfg(feature = "peaks")]
 mod peaks;
@@ -73,7 +79,11 @@ mod struct_;
 mod temporal;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-union")]
+mod union;
 mod unique;
+#[cfg(feature = "net")]
+mod url;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -85,6 +95,8 @@ pub use correlation::IRCorrelationMethod;
 #[cfg(feature = "fused")]
 pub use fused::FusedOperator;
 pub use list::IRListFunction;
+#[cfg(feature = "dtype-map")]
+pub use map::IRMapFunction;
 pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -104,6 +116,10 @@ pub use self::business::IRBusinessFunction;
 pub use self::cat::IRCategoricalFunction;
 #[cfg(feature = "temporal")]
 pub use self::datetime::IRTemporalFunction;
+#[cfg(feature = "net")]
+pub use self::ip::IRIpFunction;
+#[cfg(feature = "dtype-json")]
+pub use self::json::IRJsonFunction;
 pub use self::pow::IRPowFunction;
 #[cfg(feature = "range")]
 pub use self::range::IRRangeFunction;
@@ -117,6 +133,10 @@ pub use self::strings::IRStringFunction;
 pub use self::struct_::IRStructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::IRTrigonometricFunction;
+#[cfg(feature = "dtype-union")]
+pub use self::union::IRUnionFunction;
+#[cfg(feature = "net")]
+pub use self::url::IRUrlFunction;
 use super::*;
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -129,6 +149,16 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "dtype-categorical")]
     Categorical(IRCategoricalFunction),
     ListExpr(IRListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(IRMapFunction),
+    #[cfg(feature = "dtype-union")]
+    UnionExpr(IRUnionFunction),
+    #[cfg(feature = "dtype-json")]
+    JsonExpr(IRJsonFunction),
+    #[cfg(feature = "net")]
+    UrlExpr(IRUrlFunction),
+    #[cfg(feature = "net")]
+    IpExpr(IRIpFunction),
     #[cfg(feature = "strings")]
     StringExpr(IRStringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -155,6 +185,10 @@ pub enum IRFunctionExpr {
     Pow(IRPowFunction),
     #[cfg(feature = "row_hash")]
     Hash(u64, u64, u64, u64),
+    #[cfg(feature = "stable_hash")]
+    StableHashRows {
+        seed: u64,
+    },
     #[cfg(feature = "arg_where")]
     ArgWhere,
     #[cfg(feature = "index_of")]
@@ -385,6 +419,16 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(f) => f.hash(state),
+            #[cfg(feature = "net")]
+            UrlExpr(f) => f.hash(state),
+            #[cfg(feature = "net")]
+            IpExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -459,6 +503,8 @@ impl Hash for IRFunctionExpr {
             Sign => {},
             #[cfg(feature = "row_hash")]
             Hash(a, b, c, d) => (a, b, c, d).hash(state),
+            #[cfg(feature = "stable_hash")]
+            StableHashRows { seed } => seed.hash(state),
             FillNull => {},
             #[cfg(feature = "rolling_window")]
             RollingExpr(f) => {
@@ -630,6 +676,16 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "net")]
+            UrlExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "net")]
+            IpExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -650,6 +706,8 @@ impl Display for IRFunctionExpr {
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
             Hash(_, _, _, _) => "hash",
+            #[cfg(feature = "stable_hash")]
+            StableHashRows { .. } => "stable_hash_rows",
             #[cfg(feature = "arg_where")]
             ArgWhere => "arg_where",
             #[cfg(feature = "index_of")]
@@ -902,6 +960,16 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.into(),
             ListExpr(func) => func.into(),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.into(),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => func.into(),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(func) => func.into(),
+            #[cfg(feature = "net")]
+            UrlExpr(func) => func.into(),
+            #[cfg(feature = "net")]
+            IpExpr(func) => func.into(),
             #[cfg(feature = "strings")]
             StringExpr(func) => func.into(),
             #[cfg(feature = "dtype-struct")]
@@ -937,6 +1005,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Hash(k0, k1, k2, k3) => {
                 map!(row_hash::row_hash, k0, k1, k2, k3)
             },
+            #[cfg(feature = "stable_hash")]
+            StableHashRows { seed } => map_as_slice!(dispatch::stable_hash_rows, seed),
             #[cfg(feature = "arg_where")]
             ArgWhere => {
                 wrap!(arg_where::arg_where)
@@ -979,6 +1049,14 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     Quantile(options) => map!(rolling::rolling_quantile, options.clone()),
                     Var(options) => map!(rolling::rolling_var, options.clone()),
                     Std(options) => map!(rolling::rolling_std, options.clone()),
+                    NUnique(options) => map!(rolling::rolling_n_unique, options.clone()),
+                    Mode(options) => map!(rolling::rolling_mode, options.clone()),
+                    Rank(options) => map!(rolling::rolling_rank, options.clone()),
+                    ArgMin(options) => map!(rolling::rolling_arg_min, options.clone()),
+                    ArgMax(options) => map!(rolling::rolling_arg_max, options.clone()),
+                    First(options) => map!(rolling::rolling_first, options.clone()),
+                    Last(options) => map!(rolling::rolling_last, options.clone()),
+                    Product(options) => map!(rolling::rolling_product, options.clone()),
                     #[cfg(feature = "moment")]
                     Skew(options) => map!(rolling::rolling_skew, options.clone()),
                     #[cfg(feature = "moment")]
@@ -1011,6 +1089,24 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     },
                     VarBy(options) => map_as_slice!(rolling_by::rolling_var_by, options.clone()),
                     StdBy(options) => map_as_slice!(rolling_by::rolling_std_by, options.clone()),
+                    NUniqueBy(options) => {
+                        map_as_slice!(rolling_by::rolling_n_unique_by, options.clone())
+                    },
+                    ModeBy(options) => map_as_slice!(rolling_by::rolling_mode_by, options.clone()),
+                    RankBy(options) => map_as_slice!(rolling_by::rolling_rank_by, options.clone()),
+                    ArgMinBy(options) => {
+                        map_as_slice!(rolling_by::rolling_arg_min_by, options.clone())
+                    },
+                    ArgMaxBy(options) => {
+                        map_as_slice!(rolling_by::rolling_arg_max_by, options.clone())
+                    },
+                    FirstBy(options) => {
+                        map_as_slice!(rolling_by::rolling_first_by, options.clone())
+                    },
+                    LastBy(options) => map_as_slice!(rolling_by::rolling_last_by, options.clone()),
+                    ProductBy(options) => {
+                        map_as_slice!(rolling_by::rolling_product_by, options.clone())
+                    },
                 }
             },
             #[cfg(feature = "hist")]
@@ -1179,6 +1275,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                             map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
                         }
                     },
+                    #[cfg(feature = "dtype-uuid")]
+                    Uuid4 => map!(random::uuid4, seed),
                 }
             },
             SetSortedFlag(sorted) => map!(dispatch::set_sorted_flag, sorted),
@@ -1236,6 +1334,16 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             F::Categorical(e) => e.function_options(),
             F::ListExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-map")]
+            F::MapExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-union")]
+            F::UnionExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-json")]
+            F::JsonExpr(e) => e.function_options(),
+            #[cfg(feature = "net")]
+            F::UrlExpr(e) => e.function_options(),
+            #[cfg(feature = "net")]
+            F::IpExpr(e) => e.function_options(),
             #[cfg(feature = "strings")]
             F::StringExpr(e) => e.function_options(),
             #[cfg(feature = "dtype-struct")]
@@ -1377,6 +1485,11 @@ impl IRFunctionExpr {
                 method: IRRandomMethod::Sample { .. },
                 ..
             } => FunctionOptions::groupwise(),
+            #[cfg(feature = "dtype-uuid")]
+            F::Random {
+                method: IRRandomMethod::Uuid4,
+                ..
+            } => FunctionOptions::groupwise(),
             #[cfg(feature = "random")]
             F::Random {
                 method: IRRandomMethod::Shuffle,
@@ -1390,6 +1503,9 @@ impl IRFunctionExpr {
             }),
             F::MeanHorizontal { .. } | F::SumHorizontal { .. } => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
+            #[cfg(feature = "stable_hash")]
+            F::StableHashRows { .. } => FunctionOptions::elementwise()
+                .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
             #[cfg(feature = "ewma")]
             F::EwmMean { .. } | F::EwmStd { .. } | F::EwmVar { .. } => {
                 FunctionOptions::length_preserving()
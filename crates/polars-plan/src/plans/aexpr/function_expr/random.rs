@@ -13,6 +13,8 @@ pub enum IRRandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    #[cfg(feature = "dtype-uuid")]
+    Uuid4,
 }
 
 impl Hash for IRRandomMethod {
@@ -25,6 +27,18 @@ pub(super) fn shuffle(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
     Ok(s.shuffle(seed))
 }
 
+#[cfg(feature = "dtype-uuid")]
+pub(super) fn uuid4(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
+    polars_ensure!(
+        s.len() == 1,
+        ComputeError: "uuid4 expects a single length, got {}", s.len()
+    );
+    let n = s.cast(&IDX_DTYPE)?.idx()?.get(0).unwrap_or(0);
+    Ok(UuidChunked::new_v4(s.name().clone(), n as usize, seed)
+        .into_series()
+        .into())
+}
+
 pub(super) fn sample_frac(
     s: &[Column],
     with_replacement: bool,
@@ -18,6 +18,14 @@ pub enum IRRollingFunction {
     Quantile(RollingOptionsFixedWindow),
     Var(RollingOptionsFixedWindow),
     Std(RollingOptionsFixedWindow),
+    NUnique(RollingOptionsFixedWindow),
+    Mode(RollingOptionsFixedWindow),
+    Rank(RollingOptionsFixedWindow),
+    ArgMin(RollingOptionsFixedWindow),
+    ArgMax(RollingOptionsFixedWindow),
+    First(RollingOptionsFixedWindow),
+    Last(RollingOptionsFixedWindow),
+    Product(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
     Skew(RollingOptionsFixedWindow),
     #[cfg(feature = "moment")]
@@ -43,6 +51,14 @@ impl Display for IRRollingFunction {
             Quantile(_) => "quantile",
             Var(_) => "var",
             Std(_) => "std",
+            NUnique(_) => "n_unique",
+            Mode(_) => "mode",
+            Rank(_) => "rank",
+            ArgMin(_) => "arg_min",
+            ArgMax(_) => "arg_max",
+            First(_) => "first",
+            Last(_) => "last",
+            Product(_) => "product",
             #[cfg(feature = "moment")]
             Skew(..) => "skew",
             #[cfg(feature = "moment")]
@@ -113,6 +129,77 @@ pub(super) fn rolling_std(s: &Column, options: RollingOptionsFixedWindow) -> Pol
         .map(Column::from)
 }
 
+pub(super) fn rolling_n_unique(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_n_unique(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_mode(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_mode(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_rank(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_rank(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_min(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_arg_min(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_max(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_arg_max(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_first(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_first(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_last(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_last(options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_product(
+    s: &Column,
+    options: RollingOptionsFixedWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s.as_materialized_series()
+        .rolling_product(options)
+        .map(Column::from)
+}
+
 #[cfg(feature = "moment")]
 pub(super) fn rolling_skew(s: &Column, options: RollingOptionsFixedWindow) -> PolarsResult<Column> {
     // @scalar-opt
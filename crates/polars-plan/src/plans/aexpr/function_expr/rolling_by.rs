@@ -12,6 +12,14 @@ pub enum IRRollingFunctionBy {
     QuantileBy(RollingOptionsDynamicWindow),
     VarBy(RollingOptionsDynamicWindow),
     StdBy(RollingOptionsDynamicWindow),
+    NUniqueBy(RollingOptionsDynamicWindow),
+    ModeBy(RollingOptionsDynamicWindow),
+    RankBy(RollingOptionsDynamicWindow),
+    ArgMinBy(RollingOptionsDynamicWindow),
+    ArgMaxBy(RollingOptionsDynamicWindow),
+    FirstBy(RollingOptionsDynamicWindow),
+    LastBy(RollingOptionsDynamicWindow),
+    ProductBy(RollingOptionsDynamicWindow),
 }
 
 impl Display for IRRollingFunctionBy {
@@ -26,6 +34,14 @@ impl Display for IRRollingFunctionBy {
             QuantileBy(_) => "rolling_quantile_by",
             VarBy(_) => "rolling_var_by",
             StdBy(_) => "rolling_std_by",
+            NUniqueBy(_) => "rolling_n_unique_by",
+            ModeBy(_) => "rolling_mode_by",
+            RankBy(_) => "rolling_rank_by",
+            ArgMinBy(_) => "rolling_arg_min_by",
+            ArgMaxBy(_) => "rolling_arg_max_by",
+            FirstBy(_) => "rolling_first_by",
+            LastBy(_) => "rolling_last_by",
+            ProductBy(_) => "rolling_product_by",
         };
 
         write!(f, "{name}")
@@ -107,3 +123,83 @@ pub(super) fn rolling_std_by(
         .rolling_std_by(s[1].as_materialized_series(), options)
         .map(Column::from)
 }
+
+pub(super) fn rolling_n_unique_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_n_unique_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_mode_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_mode_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_rank_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_rank_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_min_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_arg_min_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_arg_max_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_arg_max_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_first_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_first_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_last_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_last_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
+
+pub(super) fn rolling_product_by(
+    s: &[Column],
+    options: RollingOptionsDynamicWindow,
+) -> PolarsResult<Column> {
+    // @scalar-opt
+    s[0].as_materialized_series()
+        .rolling_product_by(s[1].as_materialized_series(), options)
+        .map(Column::from)
+}
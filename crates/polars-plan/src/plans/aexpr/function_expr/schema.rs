@@ -20,6 +20,16 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-categorical")]
             Categorical(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-union")]
+            UnionExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-json")]
+            JsonExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "net")]
+            UrlExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "net")]
+            IpExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -44,6 +54,8 @@ impl IRFunctionExpr {
             Coalesce => mapper.map_to_supertype(),
             #[cfg(feature = "row_hash")]
             Hash(..) => mapper.with_dtype(DataType::UInt64),
+            #[cfg(feature = "stable_hash")]
+            StableHashRows { .. } => mapper.with_dtype(DataType::UInt64),
             #[cfg(feature = "arg_where")]
             ArgWhere => mapper.with_dtype(IDX_DTYPE),
             #[cfg(feature = "index_of")]
@@ -66,6 +78,10 @@ impl IRFunctionExpr {
                     Min(_) | Max(_) => mapper.with_same_dtype(),
                     Mean(_) | Quantile(_) | Var(_) | Std(_) => mapper.map_to_float_dtype(),
                     Sum(_) => mapper.sum_dtype(),
+                    NUnique(_) | ArgMin(_) | ArgMax(_) => mapper.with_dtype(IDX_DTYPE),
+                    Mode(_) | First(_) | Last(_) => mapper.with_same_dtype(),
+                    Rank(options) => mapper.with_dtype(rolling_rank_dtype(options.fn_params)),
+                    Product(_) => mapper.map_dtype(rolling_product_dtype),
                     #[cfg(feature = "cov")]
                     CorrCov {..} => mapper.map_to_float_dtype(),
                     #[cfg(feature = "moment")]
@@ -79,6 +95,10 @@ impl IRFunctionExpr {
                     MinBy(_) | MaxBy(_) => mapper.with_same_dtype(),
                     MeanBy(_) | QuantileBy(_) | VarBy(_) | StdBy(_) => mapper.map_to_float_dtype(),
                     SumBy(_) => mapper.sum_dtype(),
+                    NUniqueBy(_) | ArgMinBy(_) | ArgMaxBy(_) => mapper.with_dtype(IDX_DTYPE),
+                    ModeBy(_) | FirstBy(_) | LastBy(_) => mapper.with_same_dtype(),
+                    RankBy(options) => mapper.with_dtype(rolling_rank_dtype(options.fn_params)),
+                    ProductBy(_) => mapper.map_dtype(rolling_product_dtype),
                 }
             },
             ShiftAndFill => mapper.with_same_dtype(),
@@ -324,6 +344,11 @@ impl IRFunctionExpr {
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(IDX_DTYPE),
             ToPhysical => mapper.to_physical_type(),
+            #[cfg(feature = "dtype-uuid")]
+            Random {
+                method: IRRandomMethod::Uuid4,
+                ..
+            } => mapper.with_dtype(DataType::Uuid),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
             SetSortedFlag(_) => mapper.with_same_dtype(),
@@ -386,6 +411,10 @@ impl IRFunctionExpr {
             IRFunctionExpr::StructExpr(IRStructFunction::FieldByName(name)) => {
                 Some(OutputName::Field(name.clone()))
             },
+            #[cfg(feature = "dtype-union")]
+            IRFunctionExpr::UnionExpr(IRUnionFunction::Field(name)) => {
+                Some(OutputName::Field(name.clone()))
+            },
             _ => None,
         }
     }
@@ -671,3 +700,23 @@ pub(crate) fn args_to_supertype<D: AsRef<DataType>>(dtypes: &[D]) -> PolarsResul
 
     Ok(st)
 }
+
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn rolling_rank_dtype(fn_params: Option<RollingFnParams>) -> DataType {
+    match fn_params {
+        Some(RollingFnParams::Rank {
+            method: RollingRankMethod::Average,
+        })
+        | None => DataType::Float64,
+        _ => IDX_DTYPE,
+    }
+}
+
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn rolling_product_dtype(dtype: &DataType) -> DataType {
+    use DataType as T;
+    match dtype {
+        T::Boolean | T::Int8 | T::UInt8 | T::Int16 | T::UInt16 | T::Int32 | T::UInt32 => T::Int64,
+        dt => dt.clone(),
+    }
+}
@@ -135,6 +135,34 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "regex")]
     EscapeRegex,
+    #[cfg(feature = "string_similarity")]
+    Levenshtein {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    DamerauLevenshtein {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    Hamming {
+        max_distance: Option<u32>,
+    },
+    #[cfg(feature = "string_similarity")]
+    JaroWinkler,
+    #[cfg(feature = "string_similarity")]
+    JaccardNgram(usize),
+    #[cfg(feature = "string_tokenize")]
+    SplitRegex,
+    #[cfg(feature = "string_tokenize")]
+    Tokenize,
+    #[cfg(feature = "string_tokenize")]
+    CountTokens,
+    #[cfg(feature = "string_tokenize")]
+    CharNgrams(usize),
+    #[cfg(feature = "string_tokenize")]
+    WordNgrams(usize),
+    #[cfg(feature = "string_tokenize")]
+    RemoveStopWords(Vec<PlSmallStr>),
 }
 
 impl IRStringFunction {
@@ -208,6 +236,18 @@ impl IRStringFunction {
             FindMany { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::UInt32))),
             #[cfg(feature = "regex")]
             EscapeRegex => mapper.with_same_dtype(),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { .. } | DamerauLevenshtein { .. } | Hamming { .. } => {
+                mapper.with_dtype(DataType::UInt32)
+            },
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler | JaccardNgram(_) => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "string_tokenize")]
+            SplitRegex | Tokenize | CharNgrams(_) | WordNgrams(_) | RemoveStopWords(_) => {
+                mapper.with_dtype(DataType::List(Box::new(DataType::String)))
+            },
+            #[cfg(feature = "string_tokenize")]
+            CountTokens => mapper.with_dtype(DataType::UInt32),
         }
     }
 
@@ -287,6 +327,19 @@ impl IRStringFunction {
             S::FindMany { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "regex")]
             S::EscapeRegex => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_similarity")]
+            S::Levenshtein { .. }
+            | S::DamerauLevenshtein { .. }
+            | S::Hamming { .. }
+            | S::JaroWinkler
+            | S::JaccardNgram(_) => FunctionOptions::elementwise(),
+            #[cfg(feature = "string_tokenize")]
+            S::SplitRegex
+            | S::Tokenize
+            | S::CountTokens
+            | S::CharNgrams(_)
+            | S::WordNgrams(_)
+            | S::RemoveStopWords(_) => FunctionOptions::elementwise(),
         }
     }
 }
@@ -381,6 +434,28 @@ impl Display for IRStringFunction {
             FindMany { .. } => "extract_many",
             #[cfg(feature = "regex")]
             EscapeRegex => "escape_regex",
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { .. } => "levenshtein",
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein { .. } => "damerau_levenshtein",
+            #[cfg(feature = "string_similarity")]
+            Hamming { .. } => "hamming",
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_similarity")]
+            JaccardNgram(_) => "jaccard_ngram",
+            #[cfg(feature = "string_tokenize")]
+            SplitRegex => "split_regex",
+            #[cfg(feature = "string_tokenize")]
+            Tokenize => "tokenize",
+            #[cfg(feature = "string_tokenize")]
+            CountTokens => "count_tokens",
+            #[cfg(feature = "string_tokenize")]
+            CharNgrams(_) => "char_ngrams",
+            #[cfg(feature = "string_tokenize")]
+            WordNgrams(_) => "word_ngrams",
+            #[cfg(feature = "string_tokenize")]
+            RemoveStopWords(_) => "remove_stop_words",
         };
         write!(f, "str.{s}")
     }
@@ -507,6 +582,30 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             },
             #[cfg(feature = "regex")]
             EscapeRegex => map!(escape_regex),
+            #[cfg(feature = "string_similarity")]
+            Levenshtein { max_distance } => map_as_slice!(strings::levenshtein, max_distance),
+            #[cfg(feature = "string_similarity")]
+            DamerauLevenshtein { max_distance } => {
+                map_as_slice!(strings::damerau_levenshtein, max_distance)
+            },
+            #[cfg(feature = "string_similarity")]
+            Hamming { max_distance } => map_as_slice!(strings::hamming, max_distance),
+            #[cfg(feature = "string_similarity")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
+            #[cfg(feature = "string_similarity")]
+            JaccardNgram(n) => map_as_slice!(strings::jaccard_ngram, n),
+            #[cfg(feature = "string_tokenize")]
+            SplitRegex => map_as_slice!(strings::split_regex),
+            #[cfg(feature = "string_tokenize")]
+            Tokenize => map!(strings::tokenize),
+            #[cfg(feature = "string_tokenize")]
+            CountTokens => map!(strings::count_tokens),
+            #[cfg(feature = "string_tokenize")]
+            CharNgrams(n) => map!(strings::char_ngrams, n),
+            #[cfg(feature = "string_tokenize")]
+            WordNgrams(n) => map!(strings::word_ngrams, n),
+            #[cfg(feature = "string_tokenize")]
+            RemoveStopWords(stop_words) => map!(strings::remove_stop_words, &stop_words),
         }
     }
 }
@@ -1135,6 +1234,85 @@ fn _check_same_length(s: &[Column], fn_name: &str) -> Result<(), PolarsError> {
     Ok(())
 }
 
+#[cfg(feature = "string_similarity")]
+pub(super) fn levenshtein(s: &[Column], max_distance: Option<u32>) -> PolarsResult<Column> {
+    _check_same_length(s, "levenshtein")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_levenshtein(other, max_distance).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn damerau_levenshtein(s: &[Column], max_distance: Option<u32>) -> PolarsResult<Column> {
+    _check_same_length(s, "damerau_levenshtein")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca
+        .str_damerau_levenshtein(other, max_distance)
+        .into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn hamming(s: &[Column], max_distance: Option<u32>) -> PolarsResult<Column> {
+    _check_same_length(s, "hamming")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_hamming(other, max_distance).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaro_winkler(s: &[Column]) -> PolarsResult<Column> {
+    _check_same_length(s, "jaro_winkler")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_jaro_winkler(other).into_column())
+}
+
+#[cfg(feature = "string_similarity")]
+pub(super) fn jaccard_ngram(s: &[Column], n: usize) -> PolarsResult<Column> {
+    _check_same_length(s, "jaccard_ngram")?;
+    let ca = s[0].str()?;
+    let other = s[1].str()?;
+    Ok(ca.str_jaccard_ngram(other, n)?.into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn split_regex(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let pat = s[1].str()?;
+    Ok(polars_ops::chunked_array::strings::split_regex(ca, pat)?.into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn tokenize(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::tokenize(ca).into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn count_tokens(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::count_tokens(ca).into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn char_ngrams(s: &Column, n: usize) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::char_ngrams(ca, n)?.into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn word_ngrams(s: &Column, n: usize) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::word_ngrams(ca, n)?.into_column())
+}
+
+#[cfg(feature = "string_tokenize")]
+pub(super) fn remove_stop_words(s: &Column, stop_words: &[PlSmallStr]) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::remove_stop_words(ca, stop_words).into_column())
+}
+
 pub(super) fn str_slice(s: &[Column]) -> PolarsResult<Column> {
     _check_same_length(s, "slice")?;
     let ca = s[0].str()?;
@@ -58,6 +58,16 @@ impl From<IRTemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             MonthStart => map!(datetime::month_start),
             #[cfg(feature = "month_end")]
             MonthEnd => map!(datetime::month_end),
+            #[cfg(feature = "dtype-date")]
+            FiscalYear(calendar) => map!(datetime::fiscal_year, &calendar),
+            #[cfg(feature = "dtype-date")]
+            FiscalQuarter(calendar) => map!(datetime::fiscal_quarter, &calendar),
+            #[cfg(feature = "dtype-date")]
+            FiscalPeriod(calendar) => map!(datetime::fiscal_period, &calendar),
+            #[cfg(feature = "dtype-date")]
+            FiscalWeek(calendar) => map!(datetime::fiscal_week, &calendar),
+            #[cfg(feature = "dtype-date")]
+            FiscalTruncate(calendar, unit) => map!(datetime::fiscal_truncate, &calendar, unit),
             #[cfg(feature = "timezones")]
             BaseUtcOffset => map!(datetime::base_utc_offset),
             #[cfg(feature = "timezones")]
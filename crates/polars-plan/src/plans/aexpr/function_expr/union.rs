@@ -0,0 +1,80 @@
+use polars_ops::chunked_array::union::UnionNameSpace;
+
+use super::*;
+use crate::map;
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRUnionFunction {
+    TypeId,
+    Field(PlSmallStr),
+    FromStruct,
+}
+
+impl IRUnionFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRUnionFunction::*;
+        match self {
+            TypeId => mapper.with_dtype(DataType::Int8),
+            Field(name) => mapper.try_map_field(|field| match field.dtype() {
+                DataType::Union(variants) => variants
+                    .iter()
+                    .find(|f| f.name() == name)
+                    .cloned()
+                    .ok_or_else(
+                        || polars_err!(StructFieldNotFound: "union has no variant '{}'", name),
+                    ),
+                dt => polars_bail!(op = "union.field", got = dt, expected = "Union"),
+            }),
+            FromStruct => mapper.try_map_dtype(|dt| match dt {
+                DataType::Struct(fields) => Ok(DataType::Union(fields.clone())),
+                dt => polars_bail!(op = "union.from_struct", got = dt, expected = "Struct"),
+            }),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRUnionFunction::*;
+        match self {
+            Field(_) => {
+                FunctionOptions::elementwise().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
+            },
+            TypeId | FromStruct => FunctionOptions::elementwise(),
+        }
+    }
+}
+
+impl Display for IRUnionFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRUnionFunction::*;
+        let name = match self {
+            TypeId => "type_id",
+            Field(_) => "field",
+            FromStruct => "from_struct",
+        };
+        write!(f, "union.{name}")
+    }
+}
+
+impl From<IRUnionFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRUnionFunction) -> Self {
+        use IRUnionFunction::*;
+        match func {
+            TypeId => map!(type_id),
+            Field(name) => map!(field, &name),
+            FromStruct => map!(from_struct),
+        }
+    }
+}
+
+pub(super) fn type_id(s: &Column) -> PolarsResult<Column> {
+    Ok(s.union()?.union_type_id().into_column())
+}
+
+pub(super) fn field(s: &Column, name: &str) -> PolarsResult<Column> {
+    s.union()?.union_field(name).map(Column::from)
+}
+
+pub(super) fn from_struct(s: &Column) -> PolarsResult<Column> {
+    polars_ops::chunked_array::union::union_from_struct(s.struct_()?).map(|ca| ca.into_column())
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::map;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IRUrlFunction {
+    Parse,
+    QueryParam(PlSmallStr),
+}
+
+impl IRUrlFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRUrlFunction::*;
+        match self {
+            Parse => mapper.with_dtype(DataType::Struct(vec![
+                Field::new("scheme".into(), DataType::String),
+                Field::new("host".into(), DataType::String),
+                Field::new("port".into(), DataType::UInt16),
+                Field::new("path".into(), DataType::String),
+                Field::new("query".into(), DataType::String),
+                Field::new("fragment".into(), DataType::String),
+            ])),
+            QueryParam(_) => mapper.with_dtype(DataType::String),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IRUrlFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRUrlFunction::*;
+        let name = match self {
+            Parse => "parse",
+            QueryParam(_) => "query_param",
+        };
+        write!(f, "url.{name}")
+    }
+}
+
+impl From<IRUrlFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRUrlFunction) -> Self {
+        use IRUrlFunction::*;
+        match func {
+            Parse => map!(parse),
+            QueryParam(name) => map!(query_param, &name),
+        }
+    }
+}
+
+pub(super) fn parse(s: &Column) -> PolarsResult<Column> {
+    polars_ops::chunked_array::strings::url_parse(s.str()?).map(|ca| ca.into_column())
+}
+
+pub(super) fn query_param(s: &Column, name: &str) -> PolarsResult<Column> {
+    Ok(polars_ops::chunked_array::strings::url_query_param(s.str()?, name).into_column())
+}
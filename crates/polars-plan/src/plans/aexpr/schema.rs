@@ -1,6 +1,9 @@
 #[cfg(feature = "dtype-decimal")]
 use polars_core::chunked_array::arithmetic::{
-    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul,
+    _get_decimal_precision_add_sub, _get_decimal_precision_div,
+    _get_decimal_precision_mean_var_std, _get_decimal_precision_mul, _get_decimal_precision_sum,
+    _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mean_var_std,
+    _get_decimal_scale_mul,
 };
 use polars_utils::format_pl_smallstr;
 use recursive::recursive;
@@ -20,6 +23,19 @@ fn float_type(field: &mut Field) {
     }
 }
 
+/// The output type of the mean, variance and standard deviation. Decimals stay exact.
+fn mean_var_std_type(field: &mut Field) {
+    #[cfg(feature = "dtype-decimal")]
+    if let DataType::Decimal(_, Some(scale)) = field.dtype {
+        field.coerce(DataType::Decimal(
+            _get_decimal_precision_mean_var_std(),
+            Some(_get_decimal_scale_mean_var_std(scale)),
+        ));
+        return;
+    }
+    float_type(field)
+}
+
 fn validate_expr(node: Node, arena: &Arena<AExpr>, schema: &Schema) -> PolarsResult<()> {
     let mut ctx = ToFieldContext {
         schema,
@@ -239,6 +255,10 @@ impl AExpr {
                         let dt = match field.dtype() {
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-decimal")]
+                            Decimal(precision, scale) => {
+                                Some(Decimal(_get_decimal_precision_sum(*precision), *scale))
+                            },
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -260,7 +280,7 @@ impl AExpr {
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
                         match field.dtype {
                             Date => field.coerce(Datetime(TimeUnit::Milliseconds, None)),
-                            _ => float_type(&mut field),
+                            _ => mean_var_std_type(&mut field),
                         }
                         Ok(field)
                     },
@@ -273,13 +293,13 @@ impl AExpr {
                     Std(expr, _) => {
                         *agg_list = false;
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
-                        float_type(&mut field);
+                        mean_var_std_type(&mut field);
                         Ok(field)
                     },
                     Var(expr, _) => {
                         *agg_list = false;
                         let mut field = ctx.arena.get(*expr).to_field_impl(ctx, &mut false)?;
-                        float_type(&mut field);
+                        mean_var_std_type(&mut field);
                         Ok(field)
                     },
                     NUnique(expr) => {
@@ -540,6 +560,14 @@ fn get_arithmetic_field(
                 (Struct(_), Struct(_)) => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => left_field.dtype.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Interval | Duration(_)) | (Duration(_), Interval) => Interval,
+                #[cfg(feature = "dtype-interval")]
+                (_, Interval) | (Interval, _) => {
+                    polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -594,9 +622,18 @@ fn get_arithmetic_field(
                     )?)
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
+                (
+                    Decimal(precision_left, Some(scale_left)),
+                    Decimal(precision_right, Some(scale_right)),
+                ) => {
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
-                    Decimal(None, Some(scale))
+                    let precision = _get_decimal_precision_add_sub(
+                        *precision_left,
+                        *scale_left,
+                        *precision_right,
+                        *scale_right,
+                    );
+                    Decimal(precision, Some(scale))
                 },
                 (left, right) => try_get_supertype(left, right)?,
             }
@@ -604,6 +641,16 @@ fn get_arithmetic_field(
         Operator::Plus => {
             let right_type = right_ae.to_field_impl(ctx, agg_list)?.dtype;
             match (&left_field.dtype, &right_type) {
+                #[cfg(feature = "dtype-interval")]
+                (Datetime(_, _) | Date, Interval) => left_field.dtype.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Datetime(_, _) | Date) => right_type.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Interval | Duration(_)) | (Duration(_), Interval) => Interval,
+                #[cfg(feature = "dtype-interval")]
+                (_, Interval) | (Interval, _) => {
+                    polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -648,9 +695,18 @@ fn get_arithmetic_field(
                     )?)
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
+                (
+                    Decimal(precision_left, Some(scale_left)),
+                    Decimal(precision_right, Some(scale_right)),
+                ) => {
                     let scale = _get_decimal_scale_add_sub(*scale_left, *scale_right);
-                    Decimal(None, Some(scale))
+                    let precision = _get_decimal_precision_add_sub(
+                        *precision_left,
+                        *scale_left,
+                        *precision_right,
+                        *scale_right,
+                    );
+                    Decimal(precision, Some(scale))
                 },
                 (left, right) => try_get_supertype(left, right)?,
             }
@@ -671,6 +727,19 @@ fn get_arithmetic_field(
                 | (_, Date) => {
                     polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
                 },
+                #[cfg(feature = "dtype-interval")]
+                (l, Interval) if l.is_integer() && op == Operator::Multiply => {
+                    left_field.coerce(right_type);
+                    return Ok(left_field);
+                },
+                #[cfg(feature = "dtype-interval")]
+                (Interval, r) if r.is_integer() && op == Operator::Multiply => {
+                    return Ok(left_field);
+                },
+                #[cfg(feature = "dtype-interval")]
+                (_, Interval) | (Interval, _) => {
+                    polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
+                },
                 (Duration(_), Duration(_)) => {
                     // True divide handled somewhere else
                     polars_bail!(InvalidOperation: "{} not allowed on {} and {}", op, left_field.dtype, right_type)
@@ -693,18 +762,35 @@ fn get_arithmetic_field(
                     },
                 },
                 #[cfg(feature = "dtype-decimal")]
-                (Decimal(_, Some(scale_left)), Decimal(_, Some(scale_right))) => {
-                    let scale = match op {
-                        Operator::Multiply => _get_decimal_scale_mul(*scale_left, *scale_right),
-                        Operator::Divide | Operator::TrueDivide => {
-                            _get_decimal_scale_div(*scale_left)
-                        },
+                (
+                    Decimal(precision_left, Some(scale_left)),
+                    Decimal(precision_right, Some(scale_right)),
+                ) => {
+                    let (precision, scale) = match op {
+                        Operator::Multiply => (
+                            _get_decimal_precision_mul(*precision_left, *precision_right),
+                            _get_decimal_scale_mul(*scale_left, *scale_right),
+                        ),
+                        Operator::Divide | Operator::TrueDivide => (
+                            _get_decimal_precision_div(
+                                *precision_left,
+                                *scale_left,
+                                *precision_right,
+                                *scale_right,
+                            ),
+                            _get_decimal_scale_div(
+                                *precision_left,
+                                *scale_left,
+                                *precision_right,
+                                *scale_right,
+                            ),
+                        ),
                         _ => {
                             debug_assert!(false);
-                            *scale_left
+                            (None, *scale_left)
                         },
                     };
-                    let dtype = Decimal(None, Some(scale));
+                    let dtype = Decimal(precision, Some(scale));
                     left_field.coerce(dtype);
                     return Ok(left_field);
                 },
@@ -811,9 +897,18 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
         },
         (Float32, _) => Float32,
         #[cfg(feature = "dtype-decimal")]
-        (Decimal(_, Some(scale_left)), Decimal(_, _)) => {
-            let scale = _get_decimal_scale_div(*scale_left);
-            Decimal(None, Some(scale))
+        (Decimal(precision_left, Some(scale_left)), Decimal(precision_right, scale_right)) => {
+            // An unknown right scale is resolved to the left scale by the supertype cast.
+            let scale_right = scale_right.unwrap_or(*scale_left);
+            let scale =
+                _get_decimal_scale_div(*precision_left, *scale_left, *precision_right, scale_right);
+            let precision = _get_decimal_precision_div(
+                *precision_left,
+                *scale_left,
+                *precision_right,
+                scale_right,
+            );
+            Decimal(precision, Some(scale))
         },
         (dt, _) if dt.is_primitive_numeric() => Float64,
         #[cfg(feature = "dtype-duration")]
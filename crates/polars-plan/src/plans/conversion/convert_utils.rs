@@ -1,5 +1,16 @@
+use polars_core::utils::try_get_supertype;
+
 use super::*;
 
+/// Reconciles nested dtypes in relaxed concatenations: struct fields are matched by name, missing
+/// fields are inserted as nulls and leaf types are upcast.
+const RELAXED_CAST_POLICY: CastColumnsPolicy = CastColumnsPolicy {
+    integer_upcast: true,
+    float_upcast: true,
+    missing_struct_fields: MissingColumnsPolicy::Insert,
+    ..CastColumnsPolicy::ERROR_ON_MISMATCH
+};
+
 pub(super) fn convert_st_union(
     inputs: &mut [Node],
     lp_arena: &mut Arena<IR>,
@@ -10,35 +21,83 @@ pub(super) fn convert_st_union(
     let mut changed = false;
     for input in inputs[1..].iter() {
         let schema_other = lp_arena.get(*input).schema(lp_arena);
-        changed |= schema.to_supertype(schema_other.as_ref())?;
+        polars_ensure!(schema.len() == schema_other.len(), ComputeError: "schema lengths differ");
+
+        for ((name, dtype), (other_name, other_dtype)) in schema.iter_mut().zip(schema_other.iter())
+        {
+            polars_ensure!(name == other_name, ComputeError: "schema names differ: got {}, expected {}", name, other_name);
+
+            let st = if dtype.is_nested() && other_dtype.is_nested() {
+                RELAXED_CAST_POLICY
+                    .reconcile_dtypes(name, dtype, other_dtype)
+                    .or_else(|_| try_get_supertype(dtype, other_dtype))?
+            } else {
+                try_get_supertype(dtype, other_dtype)?
+            };
+            changed |= (&st != dtype) || (&st != other_dtype);
+            *dtype = st
+        }
     }
 
     if changed {
         for input in inputs {
-            let mut exprs = vec![];
-            let input_schema = lp_arena.get(*input).schema(lp_arena);
-
-            let to_cast = input_schema.iter().zip(schema.iter_values()).flat_map(
-                |((left_name, left_type), st)| {
-                    if left_type != st {
-                        Some(col(left_name.clone()).cast(st.clone()))
-                    } else {
-                        None
-                    }
-                },
-            );
-            exprs.extend(to_cast);
+            let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+
+            let to_cast = input_schema
+                .iter()
+                .zip(schema.iter_values())
+                .filter(|((_, left_type), st)| left_type != st)
+                .map(|((left_name, _), st)| col(left_name.clone()).cast(st.clone()))
+                .collect();
+            add_casts(input, to_cast, lp_arena, expr_arena)?;
+        }
+    }
+    Ok(())
+}
 
-            if !exprs.is_empty() {
-                let expr = to_expr_irs(exprs, expr_arena, &input_schema)?;
-                let lp = IRBuilder::new(*input, expr_arena, lp_arena)
-                    .with_columns(expr, Default::default())
-                    .build();
+/// Casts inputs of a strict concatenation whose (nested) struct fields are ordered differently
+/// than those of the first input.
+pub(super) fn convert_struct_field_order(
+    inputs: &mut [Node],
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    let schema = lp_arena.get(inputs[0]).schema(lp_arena).into_owned();
+
+    for input in inputs[1..].iter_mut() {
+        let input_schema = lp_arena.get(*input).schema(lp_arena).into_owned();
+
+        // Other mismatches are left to the schema check of the union.
+        let to_cast = input_schema
+            .iter()
+            .zip(schema.iter())
+            .filter(|((left_name, left_type), (name, dtype))| {
+                left_name == name
+                    && CastColumnsPolicy::ERROR_ON_MISMATCH
+                        .should_cast_column(name, dtype, left_type)
+                        .unwrap_or(false)
+            })
+            .map(|((left_name, _), (_, dtype))| col(left_name.clone()).cast(dtype.clone()))
+            .collect();
+        add_casts(input, to_cast, lp_arena, expr_arena)?;
+    }
+    Ok(())
+}
 
-                let node = lp_arena.add(lp);
-                *input = node
-            }
-        }
+fn add_casts(
+    input: &mut Node,
+    exprs: Vec<Expr>,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    if !exprs.is_empty() {
+        let input_schema = lp_arena.get(*input).schema(lp_arena);
+        let expr = to_expr_irs(exprs, expr_arena, &input_schema)?;
+        let lp = IRBuilder::new(*input, expr_arena, lp_arena)
+            .with_columns(expr, Default::default())
+            .build();
+
+        *input = lp_arena.add(lp);
     }
     Ok(())
 }
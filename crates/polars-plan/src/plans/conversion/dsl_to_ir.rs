@@ -1,7 +1,7 @@
 use arrow::datatypes::ArrowSchemaRef;
 use either::Either;
 use expr_expansion::{is_regex_projection, rewrite_projections};
-use hive::hive_partitions_from_paths;
+use hive::{hive_partitions_from_paths, hive_path_filter_from_predicates};
 use polars_core::chunked_array::cast::CastOptions;
 use polars_utils::unique_id::UniqueId;
 
@@ -81,6 +81,7 @@ pub fn to_alp(
         opt_flags,
         nodes_scratch: &mut unitvec![],
         pushdown_maintain_errors: optimizer::pushdown_maintain_errors(),
+        hive_listing_predicates: vec![],
     };
 
     match to_alp_impl(lp, &mut ctxt) {
@@ -121,6 +122,9 @@ pub(super) struct DslConversionContext<'a> {
     pub(super) opt_flags: &'a mut OptFlags,
     pub(super) nodes_scratch: &'a mut UnitVec<Node>,
     pub(super) pushdown_maintain_errors: bool,
+    /// Filters directly above the scan that is currently being converted. Used to skip listing
+    /// Hive partitions that cannot match.
+    pub(super) hive_listing_predicates: Vec<Expr>,
 }
 
 pub(super) fn run_conversion(
@@ -155,7 +159,17 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             // filtered from predicate pushdown.
             let mut cached_ir = cached_ir.lock().unwrap();
 
+            let hive_listing_predicates = std::mem::take(&mut ctxt.hive_listing_predicates);
+            // The listing depends on the filters, so a pruned listing must not be cached.
+            let mut uncached_ir = None;
+
             if cached_ir.is_none() {
+                // Row indices, slices and deletion files depend on the full file list.
+                let hive_filter = (unified_scan_args_box.row_index.is_none()
+                    && unified_scan_args_box.pre_slice.is_none()
+                    && unified_scan_args_box.deletion_files.is_none())
+                .then(|| hive_path_filter_from_predicates(&hive_listing_predicates))
+                .filter(|f| !f.is_empty());
                 let cloud_options = unified_scan_args_box.cloud_options.clone();
                 let cloud_options = cloud_options.as_ref();
 
@@ -177,30 +191,48 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     }
                 }
 
-                let sources =
-                    match &*scan_type {
-                        #[cfg(feature = "parquet")]
-                        FileScan::Parquet { .. } => sources
-                            .expand_paths_with_hive_update(unified_scan_args, cloud_options)?,
-                        #[cfg(feature = "ipc")]
-                        FileScan::Ipc { .. } => sources
-                            .expand_paths_with_hive_update(unified_scan_args, cloud_options)?,
-                        #[cfg(feature = "csv")]
-                        FileScan::Csv { .. } => {
-                            sources.expand_paths(unified_scan_args, cloud_options)?
-                        },
-                        #[cfg(feature = "json")]
-                        FileScan::NDJson { .. } => {
-                            sources.expand_paths(unified_scan_args, cloud_options)?
-                        },
-                        #[cfg(feature = "python")]
-                        FileScan::PythonDataset { .. } => {
-                            // There are a lot of places that short-circuit if the paths is empty,
-                            // so we just give a dummy path here.
-                            ScanSources::Paths(Arc::from(["dummy".into()]))
-                        },
-                        FileScan::Anonymous { .. } => sources,
-                    };
+                #[cfg(any(feature = "ipc", feature = "parquet"))]
+                let unexpanded_sources = sources.clone();
+                let sources = match &*scan_type {
+                    #[cfg(feature = "parquet")]
+                    FileScan::Parquet { .. } => sources.expand_paths_with_hive_update(
+                        unified_scan_args,
+                        cloud_options,
+                        hive_filter.as_ref(),
+                    )?,
+                    #[cfg(feature = "ipc")]
+                    FileScan::Ipc { .. } => sources.expand_paths_with_hive_update(
+                        unified_scan_args,
+                        cloud_options,
+                        hive_filter.as_ref(),
+                    )?,
+                    #[cfg(feature = "csv")]
+                    FileScan::Csv { .. } => {
+                        sources.expand_paths(unified_scan_args, cloud_options)?
+                    },
+                    #[cfg(feature = "json")]
+                    FileScan::NDJson { .. } => {
+                        sources.expand_paths(unified_scan_args, cloud_options)?
+                    },
+                    #[cfg(feature = "python")]
+                    FileScan::PythonDataset { .. } => {
+                        // There are a lot of places that short-circuit if the paths is empty,
+                        // so we just give a dummy path here.
+                        ScanSources::Paths(Arc::from(["dummy".into()]))
+                    },
+                    FileScan::Anonymous { .. } => sources,
+                };
+
+                // Pruning can leave no files to scan. The schema is then resolved from a single
+                // file that is found without listing every partition.
+                #[cfg(any(feature = "ipc", feature = "parquet"))]
+                let schema_sources = if hive_filter.is_some() && sources.is_empty() {
+                    unexpanded_sources.sample_path(unified_scan_args, cloud_options)?
+                } else {
+                    sources.clone()
+                };
+                #[cfg(not(any(feature = "ipc", feature = "parquet")))]
+                let schema_sources = sources.clone();
 
                 let mut file_info = match &mut *scan_type {
                     #[cfg(feature = "parquet")]
@@ -217,7 +249,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                             }
                         } else {
                             let (file_info, md) = scans::parquet_file_info(
-                                &sources,
+                                &schema_sources,
                                 unified_scan_args.row_index.as_ref(),
                                 cloud_options,
                             )
@@ -230,7 +262,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     #[cfg(feature = "ipc")]
                     FileScan::Ipc { metadata, .. } => {
                         let (file_info, md) = scans::ipc_file_info(
-                            &sources,
+                            &schema_sources,
                             unified_scan_args.row_index.as_ref(),
                             cloud_options,
                         )
@@ -299,7 +331,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 let hive_parts = if unified_scan_args.hive_options.enabled.unwrap()
                     && file_info.reader_schema.is_some()
                 {
-                    let paths = sources.as_paths().ok_or_else(|| {
+                    let paths = schema_sources.as_paths().ok_or_else(|| {
                         polars_err!(nyi = "Hive-partitioning of in-memory buffers")
                     })?;
 
@@ -388,10 +420,14 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     }
                 };
 
-                cached_ir.replace(ir);
+                if hive_filter.is_some() {
+                    uncached_ir = Some(ir);
+                } else {
+                    cached_ir.replace(ir);
+                }
             }
 
-            cached_ir.clone().unwrap()
+            uncached_ir.unwrap_or_else(|| cached_ir.clone().unwrap())
         },
         #[cfg(feature = "python")]
         DslPlan::PythonScan { mut options } => {
@@ -425,6 +461,12 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             if args.to_supertypes {
                 convert_utils::convert_st_union(&mut inputs, ctxt.lp_arena, ctxt.expr_arena)
                     .map_err(|e| e.context(failed_here!(vertical concat)))?;
+            } else if !inputs.is_empty() {
+                convert_utils::convert_struct_field_order(
+                    &mut inputs,
+                    ctxt.lp_arena,
+                    ctxt.expr_arena,
+                )?;
             }
 
             let first = *inputs.first().ok_or_else(
@@ -458,6 +500,14 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             }
         },
         DslPlan::Filter { input, predicate } => {
+            if ctxt.opt_flags.predicate_pushdown()
+                && matches!(&*input, DslPlan::Scan { .. } | DslPlan::Filter { .. })
+            {
+                ctxt.hive_listing_predicates.push(predicate.clone());
+            } else {
+                ctxt.hive_listing_predicates.clear();
+            }
+
             let mut input =
                 to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(filter)))?;
             let schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
@@ -792,19 +842,9 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         let from_dtype = input_dtype;
                         let to_dtype = dtype;
 
-                        let policy = CastColumnsPolicy {
-                            integer_upcast: per_column.integer_cast == UpcastOrForbid::Upcast,
-                            float_upcast: per_column.float_cast == UpcastOrForbid::Upcast,
-                            float_downcast: false,
-                            datetime_nanoseconds_downcast: false,
-                            datetime_microseconds_downcast: false,
-                            datetime_convert_timezone: false,
-                            missing_struct_fields: per_column.missing_struct_fields,
-                            extra_struct_fields: per_column.extra_struct_fields,
-                        };
-
-                        let should_cast =
-                            policy.should_cast_column(column, to_dtype, from_dtype)?;
+                        let should_cast = per_column
+                            .cast_columns_policy()
+                            .should_cast_column(column, to_dtype, from_dtype)?;
 
                         let mut expr = Expr::Column(PlSmallStr::from_str(column));
                         if should_cast {
@@ -1214,9 +1254,14 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         ),
                     },
                     finish_callback: f.finish_callback,
+                    path_options: f.path_options,
                 }),
             };
 
+            if let SinkTypeIR::Partition(f) = &payload {
+                f.validate_path_options()?;
+            }
+
             let lp = IR::Sink { input, payload };
             return run_conversion(lp, ctxt, "sink");
         },
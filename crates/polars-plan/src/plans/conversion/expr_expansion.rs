@@ -568,6 +568,10 @@ fn expand_function_inputs(
                 input_wildcard_expansion |= matches!(function, F::AsStruct);
                 input_wildcard_expansion |= matches!( function, F::StructExpr(StructFunction::WithFields));
             }
+            #[cfg(feature = "stable_hash")]
+            {
+                input_wildcard_expansion |= matches!(function, F::StableHashRows { .. });
+            }
             #[cfg(feature = "ffi_plugin")]
             {
                 input_wildcard_expansion |= matches!(function, F::FfiPlugin { flags, .. } if flags.flags.contains(FunctionFlags::INPUT_WILDCARD_EXPANSION));
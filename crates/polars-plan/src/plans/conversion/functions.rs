@@ -70,6 +70,18 @@ pub(super) fn convert_functions(
                 B::Size => IB::Size,
                 #[cfg(feature = "binary_encoding")]
                 B::FromBuffer(data_type, v) => IB::FromBuffer(data_type.into_datatype(schema)?, v),
+                #[cfg(feature = "stable_hash")]
+                B::Md5(v) => IB::Md5(v),
+                #[cfg(feature = "stable_hash")]
+                B::Sha1(v) => IB::Sha1(v),
+                #[cfg(feature = "stable_hash")]
+                B::Sha256(v) => IB::Sha256(v),
+                #[cfg(feature = "stable_hash")]
+                B::Xxh3_64(seed) => IB::Xxh3_64(seed),
+                #[cfg(feature = "stable_hash")]
+                B::Xxh3_128(seed, v) => IB::Xxh3_128(seed, v),
+                #[cfg(feature = "stable_hash")]
+                B::Murmur3_32(seed, spark) => IB::Murmur3_32(seed, spark),
             })
         },
         #[cfg(feature = "dtype-categorical")]
@@ -131,6 +143,12 @@ pub(super) fn convert_functions(
                 #[cfg(feature = "diff")]
                 L::Diff { n, null_behavior } => IL::Diff { n, null_behavior },
                 L::Sort(sort_options) => IL::Sort(sort_options),
+                L::SortBy(sort_options) => IL::SortBy(sort_options),
+                L::ArgSort(sort_options) => IL::ArgSort(sort_options),
+                #[cfg(feature = "search_sorted")]
+                L::SearchSorted { side, descending } => IL::SearchSorted { side, descending },
+                #[cfg(feature = "top_k")]
+                L::TopK => IL::TopK,
                 L::Reverse => IL::Reverse,
                 L::Unique(v) => IL::Unique(v),
                 L::NUnique => IL::NUnique,
@@ -145,6 +163,62 @@ pub(super) fn convert_functions(
                 L::ToArray(v) => IL::ToArray(v),
                 #[cfg(feature = "list_to_struct")]
                 L::ToStruct(list_to_struct_args) => IL::ToStruct(list_to_struct_args),
+                #[cfg(feature = "list_zip")]
+                L::Zip => IL::Zip,
+                #[cfg(feature = "list_zip")]
+                L::Unzip => IL::Unzip,
+                #[cfg(feature = "list_zip")]
+                L::Enumerate => IL::Enumerate,
+            })
+        },
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(map_function) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            I::MapExpr(match map_function {
+                M::Get => IM::Get,
+                M::ContainsKey => IM::ContainsKey,
+                M::Keys => IM::Keys,
+                M::Values => IM::Values,
+                M::FromLists => IM::FromLists,
+            })
+        },
+        #[cfg(feature = "dtype-union")]
+        F::UnionExpr(union_function) => {
+            use {IRUnionFunction as IU, UnionFunction as U};
+            I::UnionExpr(match union_function {
+                U::TypeId => IU::TypeId,
+                U::Field(name) => IU::Field(name),
+                U::FromStruct => IU::FromStruct,
+            })
+        },
+        #[cfg(feature = "dtype-json")]
+        F::JsonExpr(json_function) => {
+            use {IRJsonFunction as IJ, JsonFunction as J};
+            I::JsonExpr(match json_function {
+                J::Get { path, dtype } => IJ::Get {
+                    path,
+                    dtype: dtype.into_datatype(schema)?,
+                },
+                J::Compact => IJ::Compact,
+                J::Pretty => IJ::Pretty,
+            })
+        },
+        #[cfg(feature = "net")]
+        F::UrlExpr(url_function) => {
+            use {IRUrlFunction as IU, UrlFunction as U};
+            I::UrlExpr(match url_function {
+                U::Parse => IU::Parse,
+                U::QueryParam(name) => IU::QueryParam(name),
+            })
+        },
+        #[cfg(feature = "net")]
+        F::IpExpr(ip_function) => {
+            use {IRIpFunction as II, IpFunction as IP};
+            I::IpExpr(match ip_function {
+                IP::ToInt { ipv6 } => II::ToInt { ipv6 },
+                IP::ToBinary => II::ToBinary,
+                IP::ToString => II::ToString,
+                IP::InNetwork(cidr) => II::InNetwork(cidr),
             })
         },
         #[cfg(feature = "strings")]
@@ -279,6 +353,28 @@ pub(super) fn convert_functions(
                 },
                 #[cfg(feature = "regex")]
                 S::EscapeRegex => IS::EscapeRegex,
+                #[cfg(feature = "string_similarity")]
+                S::Levenshtein { max_distance } => IS::Levenshtein { max_distance },
+                #[cfg(feature = "string_similarity")]
+                S::DamerauLevenshtein { max_distance } => IS::DamerauLevenshtein { max_distance },
+                #[cfg(feature = "string_similarity")]
+                S::Hamming { max_distance } => IS::Hamming { max_distance },
+                #[cfg(feature = "string_similarity")]
+                S::JaroWinkler => IS::JaroWinkler,
+                #[cfg(feature = "string_similarity")]
+                S::JaccardNgram(n) => IS::JaccardNgram(n),
+                #[cfg(feature = "string_tokenize")]
+                S::SplitRegex => IS::SplitRegex,
+                #[cfg(feature = "string_tokenize")]
+                S::Tokenize => IS::Tokenize,
+                #[cfg(feature = "string_tokenize")]
+                S::CountTokens => IS::CountTokens,
+                #[cfg(feature = "string_tokenize")]
+                S::CharNgrams(n) => IS::CharNgrams(n),
+                #[cfg(feature = "string_tokenize")]
+                S::WordNgrams(n) => IS::WordNgrams(n),
+                #[cfg(feature = "string_tokenize")]
+                S::RemoveStopWords(stop_words) => IS::RemoveStopWords(stop_words),
             })
         },
         #[cfg(feature = "dtype-struct")]
@@ -351,6 +447,16 @@ pub(super) fn convert_functions(
                 T::MonthStart => IT::MonthStart,
                 #[cfg(feature = "month_end")]
                 T::MonthEnd => IT::MonthEnd,
+                #[cfg(feature = "dtype-date")]
+                T::FiscalYear(calendar) => IT::FiscalYear(calendar),
+                #[cfg(feature = "dtype-date")]
+                T::FiscalQuarter(calendar) => IT::FiscalQuarter(calendar),
+                #[cfg(feature = "dtype-date")]
+                T::FiscalPeriod(calendar) => IT::FiscalPeriod(calendar),
+                #[cfg(feature = "dtype-date")]
+                T::FiscalWeek(calendar) => IT::FiscalWeek(calendar),
+                #[cfg(feature = "dtype-date")]
+                T::FiscalTruncate(calendar, unit) => IT::FiscalTruncate(calendar, unit),
                 #[cfg(feature = "timezones")]
                 T::BaseUtcOffset => IT::BaseUtcOffset,
                 #[cfg(feature = "timezones")]
@@ -473,25 +579,31 @@ pub(super) fn convert_functions(
             BusinessFunction::BusinessDayCount {
                 week_mask,
                 holidays,
+                calendar,
             } => IRBusinessFunction::BusinessDayCount {
                 week_mask,
                 holidays,
+                calendar,
             },
             BusinessFunction::AddBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
                 roll,
             } => IRBusinessFunction::AddBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
                 roll,
             },
             BusinessFunction::IsBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
             } => IRBusinessFunction::IsBusinessDay {
                 week_mask,
                 holidays,
+                calendar,
             },
         }),
         #[cfg(feature = "abs")]
@@ -515,6 +627,8 @@ pub(super) fn convert_functions(
         }),
         #[cfg(feature = "row_hash")]
         F::Hash(s0, s1, s2, s3) => I::Hash(s0, s1, s2, s3),
+        #[cfg(feature = "stable_hash")]
+        F::StableHashRows { seed } => I::StableHashRows { seed },
         #[cfg(feature = "arg_where")]
         F::ArgWhere => I::ArgWhere,
         #[cfg(feature = "index_of")]
@@ -622,6 +736,14 @@ pub(super) fn convert_functions(
                 R::Quantile(r) => IR::Quantile(r),
                 R::Var(r) => IR::Var(r),
                 R::Std(r) => IR::Std(r),
+                R::NUnique(r) => IR::NUnique(r),
+                R::Mode(r) => IR::Mode(r),
+                R::Rank(r) => IR::Rank(r),
+                R::ArgMin(r) => IR::ArgMin(r),
+                R::ArgMax(r) => IR::ArgMax(r),
+                R::First(r) => IR::First(r),
+                R::Last(r) => IR::Last(r),
+                R::Product(r) => IR::Product(r),
                 #[cfg(feature = "moment")]
                 R::Skew(r) => IR::Skew(r),
                 #[cfg(feature = "moment")]
@@ -650,6 +772,14 @@ pub(super) fn convert_functions(
                 R::QuantileBy(r) => IR::QuantileBy(r),
                 R::VarBy(r) => IR::VarBy(r),
                 R::StdBy(r) => IR::StdBy(r),
+                R::NUniqueBy(r) => IR::NUniqueBy(r),
+                R::ModeBy(r) => IR::ModeBy(r),
+                R::RankBy(r) => IR::RankBy(r),
+                R::ArgMinBy(r) => IR::ArgMinBy(r),
+                R::ArgMaxBy(r) => IR::ArgMaxBy(r),
+                R::FirstBy(r) => IR::FirstBy(r),
+                R::LastBy(r) => IR::LastBy(r),
+                R::ProductBy(r) => IR::ProductBy(r),
             })
         },
         F::ShiftAndFill => {
@@ -754,6 +884,7 @@ pub(super) fn convert_functions(
                     C::Pearson => IC::Pearson,
                     #[cfg(all(feature = "rank", feature = "propagate_nans"))]
                     C::SpearmanRank(v) => IC::SpearmanRank(v),
+                    C::KendallTau => IC::KendallTau,
                     C::Covariance(v) => IC::Covariance(v),
                 },
             }
@@ -808,6 +939,8 @@ pub(super) fn convert_functions(
                         with_replacement,
                         shuffle,
                     },
+                    #[cfg(feature = "dtype-uuid")]
+                    R::Uuid4 => IR::Uuid4,
                 },
                 seed,
             }
@@ -323,6 +323,18 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Size => B::Size,
                 #[cfg(feature = "binary_encoding")]
                 IB::FromBuffer(data_type, v) => B::FromBuffer(data_type.into(), v),
+                #[cfg(feature = "stable_hash")]
+                IB::Md5(v) => B::Md5(v),
+                #[cfg(feature = "stable_hash")]
+                IB::Sha1(v) => B::Sha1(v),
+                #[cfg(feature = "stable_hash")]
+                IB::Sha256(v) => B::Sha256(v),
+                #[cfg(feature = "stable_hash")]
+                IB::Xxh3_64(seed) => B::Xxh3_64(seed),
+                #[cfg(feature = "stable_hash")]
+                IB::Xxh3_128(seed, v) => B::Xxh3_128(seed, v),
+                #[cfg(feature = "stable_hash")]
+                IB::Murmur3_32(seed, spark) => B::Murmur3_32(seed, spark),
             })
         },
         #[cfg(feature = "dtype-categorical")]
@@ -384,6 +396,12 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 #[cfg(feature = "diff")]
                 IL::Diff { n, null_behavior } => L::Diff { n, null_behavior },
                 IL::Sort(sort_options) => L::Sort(sort_options),
+                IL::SortBy(sort_options) => L::SortBy(sort_options),
+                IL::ArgSort(sort_options) => L::ArgSort(sort_options),
+                #[cfg(feature = "search_sorted")]
+                IL::SearchSorted { side, descending } => L::SearchSorted { side, descending },
+                #[cfg(feature = "top_k")]
+                IL::TopK => L::TopK,
                 IL::Reverse => L::Reverse,
                 IL::Unique(v) => L::Unique(v),
                 IL::NUnique => L::NUnique,
@@ -398,6 +416,62 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::ToArray(v) => L::ToArray(v),
                 #[cfg(feature = "list_to_struct")]
                 IL::ToStruct(list_to_struct_args) => L::ToStruct(list_to_struct_args),
+                #[cfg(feature = "list_zip")]
+                IL::Zip => L::Zip,
+                #[cfg(feature = "list_zip")]
+                IL::Unzip => L::Unzip,
+                #[cfg(feature = "list_zip")]
+                IL::Enumerate => L::Enumerate,
+            })
+        },
+        #[cfg(feature = "dtype-map")]
+        IF::MapExpr(f) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            F::MapExpr(match f {
+                IM::Get => M::Get,
+                IM::ContainsKey => M::ContainsKey,
+                IM::Keys => M::Keys,
+                IM::Values => M::Values,
+                IM::FromLists => M::FromLists,
+            })
+        },
+        #[cfg(feature = "dtype-union")]
+        IF::UnionExpr(f) => {
+            use {IRUnionFunction as IU, UnionFunction as U};
+            F::UnionExpr(match f {
+                IU::TypeId => U::TypeId,
+                IU::Field(name) => U::Field(name),
+                IU::FromStruct => U::FromStruct,
+            })
+        },
+        #[cfg(feature = "dtype-json")]
+        IF::JsonExpr(f) => {
+            use {IRJsonFunction as IJ, JsonFunction as J};
+            F::JsonExpr(match f {
+                IJ::Get { path, dtype } => J::Get {
+                    path,
+                    dtype: dtype.into(),
+                },
+                IJ::Compact => J::Compact,
+                IJ::Pretty => J::Pretty,
+            })
+        },
+        #[cfg(feature = "net")]
+        IF::UrlExpr(f) => {
+            use {IRUrlFunction as IU, UrlFunction as U};
+            F::UrlExpr(match f {
+                IU::Parse => U::Parse,
+                IU::QueryParam(name) => U::QueryParam(name),
+            })
+        },
+        #[cfg(feature = "net")]
+        IF::IpExpr(f) => {
+            use {IRIpFunction as II, IpFunction as IP};
+            F::IpExpr(match f {
+                II::ToInt { ipv6 } => IP::ToInt { ipv6 },
+                II::ToBinary => IP::ToBinary,
+                II::ToString => IP::ToString,
+                II::InNetwork(cidr) => IP::InNetwork(cidr),
             })
         },
         #[cfg(feature = "strings")]
@@ -518,6 +592,28 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 },
                 #[cfg(feature = "regex")]
                 IB::EscapeRegex => B::EscapeRegex,
+                #[cfg(feature = "string_similarity")]
+                IB::Levenshtein { max_distance } => B::Levenshtein { max_distance },
+                #[cfg(feature = "string_similarity")]
+                IB::DamerauLevenshtein { max_distance } => B::DamerauLevenshtein { max_distance },
+                #[cfg(feature = "string_similarity")]
+                IB::Hamming { max_distance } => B::Hamming { max_distance },
+                #[cfg(feature = "string_similarity")]
+                IB::JaroWinkler => B::JaroWinkler,
+                #[cfg(feature = "string_similarity")]
+                IB::JaccardNgram(n) => B::JaccardNgram(n),
+                #[cfg(feature = "string_tokenize")]
+                IB::SplitRegex => B::SplitRegex,
+                #[cfg(feature = "string_tokenize")]
+                IB::Tokenize => B::Tokenize,
+                #[cfg(feature = "string_tokenize")]
+                IB::CountTokens => B::CountTokens,
+                #[cfg(feature = "string_tokenize")]
+                IB::CharNgrams(n) => B::CharNgrams(n),
+                #[cfg(feature = "string_tokenize")]
+                IB::WordNgrams(n) => B::WordNgrams(n),
+                #[cfg(feature = "string_tokenize")]
+                IB::RemoveStopWords(stop_words) => B::RemoveStopWords(stop_words),
             })
         },
         #[cfg(feature = "dtype-struct")]
@@ -590,6 +686,16 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::MonthStart => B::MonthStart,
                 #[cfg(feature = "month_end")]
                 IB::MonthEnd => B::MonthEnd,
+                #[cfg(feature = "dtype-date")]
+                IB::FiscalYear(calendar) => B::FiscalYear(calendar),
+                #[cfg(feature = "dtype-date")]
+                IB::FiscalQuarter(calendar) => B::FiscalQuarter(calendar),
+                #[cfg(feature = "dtype-date")]
+                IB::FiscalPeriod(calendar) => B::FiscalPeriod(calendar),
+                #[cfg(feature = "dtype-date")]
+                IB::FiscalWeek(calendar) => B::FiscalWeek(calendar),
+                #[cfg(feature = "dtype-date")]
+                IB::FiscalTruncate(calendar, unit) => B::FiscalTruncate(calendar, unit),
                 #[cfg(feature = "timezones")]
                 IB::BaseUtcOffset => B::BaseUtcOffset,
                 #[cfg(feature = "timezones")]
@@ -660,25 +766,31 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::BusinessDayCount {
                     week_mask,
                     holidays,
+                    calendar,
                 } => B::BusinessDayCount {
                     week_mask,
                     holidays,
+                    calendar,
                 },
                 IB::AddBusinessDay {
                     week_mask,
                     holidays,
+                    calendar,
                     roll,
                 } => B::AddBusinessDay {
                     week_mask,
                     holidays,
+                    calendar,
                     roll,
                 },
                 IB::IsBusinessDay {
                     week_mask,
                     holidays,
+                    calendar,
                 } => B::IsBusinessDay {
                     week_mask,
                     holidays,
+                    calendar,
                 },
             })
         },
@@ -706,6 +818,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         },
         #[cfg(feature = "row_hash")]
         IF::Hash(s0, s1, s2, s3) => F::Hash(s0, s1, s2, s3),
+        #[cfg(feature = "stable_hash")]
+        IF::StableHashRows { seed } => F::StableHashRows { seed },
         #[cfg(feature = "arg_where")]
         IF::ArgWhere => F::ArgWhere,
         #[cfg(feature = "index_of")]
@@ -805,6 +919,18 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 },
                 IR::Var(rolling_options_fixed_window) => R::Var(rolling_options_fixed_window),
                 IR::Std(rolling_options_fixed_window) => R::Std(rolling_options_fixed_window),
+                IR::NUnique(rolling_options_fixed_window) => {
+                    R::NUnique(rolling_options_fixed_window)
+                },
+                IR::Mode(rolling_options_fixed_window) => R::Mode(rolling_options_fixed_window),
+                IR::Rank(rolling_options_fixed_window) => R::Rank(rolling_options_fixed_window),
+                IR::ArgMin(rolling_options_fixed_window) => R::ArgMin(rolling_options_fixed_window),
+                IR::ArgMax(rolling_options_fixed_window) => R::ArgMax(rolling_options_fixed_window),
+                IR::First(rolling_options_fixed_window) => R::First(rolling_options_fixed_window),
+                IR::Last(rolling_options_fixed_window) => R::Last(rolling_options_fixed_window),
+                IR::Product(rolling_options_fixed_window) => {
+                    R::Product(rolling_options_fixed_window)
+                },
                 #[cfg(feature = "moment")]
                 IR::Skew(rolling_options_fixed_window) => R::Skew(rolling_options_fixed_window),
                 #[cfg(feature = "moment")]
@@ -848,6 +974,30 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IR::StdBy(rolling_options_dynamic_window) => {
                     R::StdBy(rolling_options_dynamic_window)
                 },
+                IR::NUniqueBy(rolling_options_dynamic_window) => {
+                    R::NUniqueBy(rolling_options_dynamic_window)
+                },
+                IR::ModeBy(rolling_options_dynamic_window) => {
+                    R::ModeBy(rolling_options_dynamic_window)
+                },
+                IR::RankBy(rolling_options_dynamic_window) => {
+                    R::RankBy(rolling_options_dynamic_window)
+                },
+                IR::ArgMinBy(rolling_options_dynamic_window) => {
+                    R::ArgMinBy(rolling_options_dynamic_window)
+                },
+                IR::ArgMaxBy(rolling_options_dynamic_window) => {
+                    R::ArgMaxBy(rolling_options_dynamic_window)
+                },
+                IR::FirstBy(rolling_options_dynamic_window) => {
+                    R::FirstBy(rolling_options_dynamic_window)
+                },
+                IR::LastBy(rolling_options_dynamic_window) => {
+                    R::LastBy(rolling_options_dynamic_window)
+                },
+                IR::ProductBy(rolling_options_dynamic_window) => {
+                    R::ProductBy(rolling_options_dynamic_window)
+                },
             })
         },
         IF::ShiftAndFill => F::ShiftAndFill,
@@ -954,6 +1104,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                     IC::Pearson => C::Pearson,
                     #[cfg(all(feature = "rank", feature = "propagate_nans"))]
                     IC::SpearmanRank(v) => C::SpearmanRank(v),
+                    IC::KendallTau => C::KendallTau,
                     IC::Covariance(v) => C::Covariance(v),
                 },
             }
@@ -1008,6 +1159,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                         with_replacement,
                         shuffle,
                     },
+                    #[cfg(feature = "dtype-uuid")]
+                    IR::Uuid4 => R::Uuid4,
                 },
                 seed,
             }
@@ -316,6 +316,7 @@ impl IR {
                                 .collect()
                         }),
                         finish_callback: f.finish_callback,
+                        path_options: f.path_options,
                     }),
                 };
                 DslPlan::Sink { input, payload }
@@ -150,6 +150,10 @@ impl ExprIR {
                         IRFunctionExpr::StructExpr(IRStructFunction::FieldByName(name)) => {
                             out.output_name = OutputName::Field(name.clone());
                         },
+                        #[cfg(feature = "dtype-union")]
+                        IRFunctionExpr::UnionExpr(IRUnionFunction::Field(name)) => {
+                            out.output_name = OutputName::Field(name.clone());
+                        },
                         _ => {
                             if input.is_empty() {
                                 out.output_name =
@@ -1,10 +1,15 @@
 use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
+use polars_io::path_utils::hive_filter::{
+    HiveCompareOp, HiveLiteral, HivePathFilter, HiveValuePredicate,
+};
 use polars_io::prelude::schema_inference::{finish_infer_field_schema, infer_field_schema};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Debug, Clone)]
 pub struct HivePartitionsDf(DataFrame);
@@ -81,8 +86,14 @@ pub fn hive_partitions_from_paths(
     let sep = separator(path);
     let path_string = path.to_str().unwrap();
 
-    fn parse_hive_string_and_decode(part: &'_ str) -> Option<(&'_ str, std::borrow::Cow<'_, str>)> {
+    fn parse_hive_string_and_decode(
+        part: &'_ str,
+    ) -> Option<(std::borrow::Cow<'_, str>, std::borrow::Cow<'_, str>)> {
         let (k, v) = parse_hive_string(part)?;
+        // Writers escape special characters in both the key and the value.
+        let k = percent_encoding::percent_decode(k.as_bytes())
+            .decode_utf8()
+            .ok()?;
         let v = percent_encoding::percent_decode(v.as_bytes())
             .decode_utf8()
             .ok()?;
@@ -107,7 +118,7 @@ pub fn hive_partitions_from_paths(
 
     let hive_schema = if let Some(ref schema) = schema {
         Arc::new(get_hive_parts_iter!(path_string).map(|(name, _)| {
-                let Some(dtype) = schema.get(name) else {
+                let Some(dtype) = schema.get(&name) else {
                     polars_bail!(
                         SchemaFieldNotFound:
                         "path contains column not present in the given Hive schema: {:?}, path = {:?}",
@@ -122,27 +133,28 @@ pub fn hive_partitions_from_paths(
                     dtype.clone()
                 };
 
-                Ok(Field::new(PlSmallStr::from_str(name), dtype))
+                Ok(Field::new(PlSmallStr::from_str(&name), dtype))
             }).collect::<PolarsResult<Schema>>()?)
     } else {
         let mut hive_schema = Schema::with_capacity(16);
-        let mut schema_inference_map: PlHashMap<&str, PlHashSet<DataType>> =
+        let mut schema_inference_map: PlHashMap<PlSmallStr, PlHashSet<DataType>> =
             PlHashMap::with_capacity(16);
 
         for (name, _) in get_hive_parts_iter!(path_string) {
             // If the column is also in the file we can use the dtype stored there.
-            if let Some(dtype) = reader_schema.get(name) {
+            if let Some(dtype) = reader_schema.get(&name) {
                 let dtype = if !try_parse_dates && dtype.is_temporal() {
                     DataType::String
                 } else {
                     dtype.clone()
                 };
 
-                hive_schema.insert_at_index(hive_schema.len(), name.into(), dtype.clone())?;
+                hive_schema.insert_at_index(hive_schema.len(), (&*name).into(), dtype.clone())?;
                 continue;
             }
 
-            hive_schema.insert_at_index(hive_schema.len(), name.into(), DataType::String)?;
+            let name = PlSmallStr::from_str(&name);
+            hive_schema.insert_at_index(hive_schema.len(), name.clone(), DataType::String)?;
             schema_inference_map.insert(name, PlHashSet::with_capacity(4));
         }
 
@@ -153,7 +165,7 @@ pub fn hive_partitions_from_paths(
         if !schema_inference_map.is_empty() {
             for path in paths {
                 for (name, value) in get_hive_parts_iter!(path.to_str().unwrap()) {
-                    let Some(entry) = schema_inference_map.get_mut(name) else {
+                    let Some(entry) = schema_inference_map.get_mut(&*name) else {
                         continue;
                     };
 
@@ -161,13 +173,13 @@ pub fn hive_partitions_from_paths(
                         continue;
                     }
 
-                    entry.insert(infer_field_schema(value.as_ref(), try_parse_dates, false));
+                    entry.insert(infer_hive_value_dtype(value.as_ref(), try_parse_dates));
                 }
             }
 
             for (name, ref possibilities) in schema_inference_map.drain() {
                 let dtype = finish_infer_field_schema(possibilities);
-                *hive_schema.try_get_mut(name).unwrap() = dtype;
+                *hive_schema.try_get_mut(&name).unwrap() = dtype;
             }
         }
         Arc::new(hive_schema)
@@ -186,7 +198,7 @@ pub fn hive_partitions_from_paths(
         let path = path.to_str().unwrap();
 
         for (name, value) in get_hive_parts_iter!(path) {
-            let Some(index) = hive_schema.index_of(name) else {
+            let Some(index) = hive_schema.index_of(&name) else {
                 polars_bail!(
                     SchemaFieldNotFound:
                     "path contains column not present in the given Hive schema: {:?}, path = {:?}",
@@ -217,6 +229,17 @@ pub fn hive_partitions_from_paths(
     )?)))
 }
 
+/// Infer the dtype of a single Hive partition value.
+///
+/// This follows CSV inference, except that integers which do not fit in an `Int64` are kept as
+/// strings (e.g. long numeric identifiers) instead of failing to parse.
+fn infer_hive_value_dtype(value: &str, try_parse_dates: bool) -> DataType {
+    match infer_field_schema(value, try_parse_dates, false) {
+        DataType::Int64 if value.parse::<i64>().is_err() => DataType::String,
+        dtype => dtype,
+    }
+}
+
 /// Determine the path separator for identifying Hive partitions.
 fn separator(url: &Path) -> &[char] {
     if cfg!(target_family = "windows") {
@@ -250,3 +273,221 @@ fn parse_hive_string(part: &'_ str) -> Option<(&'_ str, &'_ str)> {
 
     Some((name, value))
 }
+
+/// Collects the parts of `predicates` that only reference a single column and can be evaluated on
+/// Hive partition directory names, so that non-matching partitions are not listed.
+pub(crate) fn hive_path_filter_from_predicates(predicates: &[Expr]) -> HivePathFilter {
+    fn split_conjunction<'a>(expr: &'a Expr, out: &mut Vec<&'a Expr>) {
+        match expr {
+            Expr::BinaryExpr {
+                left,
+                op: Operator::And | Operator::LogicalAnd,
+                right,
+            } => {
+                split_conjunction(left, out);
+                split_conjunction(right, out);
+            },
+            Expr::Alias(e, _) => split_conjunction(e, out),
+            e => out.push(e),
+        }
+    }
+
+    let mut conjuncts = vec![];
+    for predicate in predicates {
+        split_conjunction(predicate, &mut conjuncts);
+    }
+
+    let mut filter = HivePathFilter::default();
+    for expr in conjuncts {
+        if let Some((name, predicate)) = to_hive_value_predicate(expr) {
+            filter.push(name, predicate);
+        }
+    }
+    filter
+}
+
+fn to_hive_value_predicate(expr: &Expr) -> Option<(PlSmallStr, HiveValuePredicate)> {
+    fn column_name(expr: &Expr) -> Option<&PlSmallStr> {
+        match expr {
+            Expr::Column(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    fn compare_op(op: Operator) -> Option<HiveCompareOp> {
+        Some(match op {
+            Operator::Eq => HiveCompareOp::Eq,
+            Operator::NotEq => HiveCompareOp::NotEq,
+            Operator::Lt => HiveCompareOp::Lt,
+            Operator::LtEq => HiveCompareOp::LtEq,
+            Operator::Gt => HiveCompareOp::Gt,
+            Operator::GtEq => HiveCompareOp::GtEq,
+            _ => return None,
+        })
+    }
+
+    match expr {
+        Expr::Alias(e, _) => to_hive_value_predicate(e),
+        Expr::BinaryExpr { left, op, right } => {
+            if let Some(op) = compare_op(*op) {
+                return match (left.as_ref(), right.as_ref()) {
+                    (Expr::Column(name), Expr::Literal(lv)) => Some((
+                        name.clone(),
+                        HiveValuePredicate::Compare(op, hive_literal_scalar(lv)?),
+                    )),
+                    (Expr::Literal(lv), Expr::Column(name)) => Some((
+                        name.clone(),
+                        HiveValuePredicate::Compare(op.swap_operands(), hive_literal_scalar(lv)?),
+                    )),
+                    _ => None,
+                };
+            }
+
+            let (l_name, l) = to_hive_value_predicate(left)?;
+            let (r_name, r) = to_hive_value_predicate(right)?;
+            if l_name != r_name {
+                return None;
+            }
+
+            let predicate = match op {
+                Operator::And | Operator::LogicalAnd => HiveValuePredicate::And(vec![l, r]),
+                Operator::Or | Operator::LogicalOr => HiveValuePredicate::Or(vec![l, r]),
+                _ => return None,
+            };
+            Some((l_name, predicate))
+        },
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(function),
+        } => match (function, input.as_slice()) {
+            (BooleanFunction::IsNull, [e]) => {
+                Some((column_name(e)?.clone(), HiveValuePredicate::IsNull(true)))
+            },
+            (BooleanFunction::IsNotNull, [e]) => {
+                Some((column_name(e)?.clone(), HiveValuePredicate::IsNull(false)))
+            },
+            (BooleanFunction::Not, [e]) => {
+                let (name, predicate) = to_hive_value_predicate(e)?;
+                Some((name, HiveValuePredicate::Not(Box::new(predicate))))
+            },
+            #[cfg(feature = "is_in")]
+            (BooleanFunction::IsIn { nulls_equal }, [e, other]) => {
+                let name = column_name(e)?.clone();
+                let other = match other {
+                    Expr::Agg(AggExpr::Implode(e)) => e.as_ref(),
+                    e => e,
+                };
+                let Expr::Literal(lv) = other else {
+                    return None;
+                };
+
+                let values = hive_literal_list(lv)?;
+                let predicate = if *nulls_equal && values.contains(&HiveLiteral::Null) {
+                    HiveValuePredicate::Or(vec![
+                        HiveValuePredicate::IsIn(values),
+                        HiveValuePredicate::IsNull(true),
+                    ])
+                } else {
+                    HiveValuePredicate::IsIn(values)
+                };
+                Some((name, predicate))
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn hive_literal_any_value(av: &AnyValue) -> Option<HiveLiteral> {
+    Some(match av {
+        AnyValue::Null => HiveLiteral::Null,
+        AnyValue::Boolean(v) => HiveLiteral::Boolean(*v),
+        av if av.is_integer() => HiveLiteral::Int(av.extract::<i128>()?),
+        av if av.is_float() => HiveLiteral::Float(av.extract::<f64>()?),
+        AnyValue::String(v) => HiveLiteral::String(PlSmallStr::from_str(v)),
+        AnyValue::StringOwned(v) => HiveLiteral::String(v.clone()),
+        #[cfg(feature = "dtype-date")]
+        AnyValue::Date(v) => HiveLiteral::Date(
+            arrow::temporal_conversions::date32_to_date_opt(*v)?
+                .format("%Y-%m-%d")
+                .to_string()
+                .into(),
+        ),
+        _ => return None,
+    })
+}
+
+fn hive_literal_scalar(lv: &LiteralValue) -> Option<HiveLiteral> {
+    match lv {
+        LiteralValue::Dyn(DynLiteralValue::Int(v)) => Some(HiveLiteral::Int(*v)),
+        LiteralValue::Dyn(DynLiteralValue::Float(v)) => Some(HiveLiteral::Float(*v)),
+        LiteralValue::Dyn(DynLiteralValue::Str(v)) => Some(HiveLiteral::String(v.clone())),
+        LiteralValue::Scalar(sc) => hive_literal_any_value(sc.value()),
+        _ => None,
+    }
+}
+
+fn hive_literal_list(lv: &LiteralValue) -> Option<Vec<HiveLiteral>> {
+    fn from_series(s: &Series) -> Option<Vec<HiveLiteral>> {
+        s.iter().map(|av| hive_literal_any_value(&av)).collect()
+    }
+
+    match lv {
+        LiteralValue::Series(s) => from_series(s),
+        LiteralValue::Scalar(sc) => match sc.value() {
+            AnyValue::List(s) => from_series(s),
+            _ => None,
+        },
+        LiteralValue::Dyn(DynLiteralValue::List(list)) => match list {
+            DynListLiteralValue::Int(vs) => Some(
+                vs.iter()
+                    .map(|v| v.map_or(HiveLiteral::Null, HiveLiteral::Int))
+                    .collect(),
+            ),
+            DynListLiteralValue::Float(vs) => Some(
+                vs.iter()
+                    .map(|v| v.map_or(HiveLiteral::Null, HiveLiteral::Float))
+                    .collect(),
+            ),
+            DynListLiteralValue::Str(vs) => Some(
+                vs.iter()
+                    .map(|v| v.clone().map_or(HiveLiteral::Null, HiveLiteral::String))
+                    .collect(),
+            ),
+            DynListLiteralValue::List(_) => None,
+        },
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hive_path_filter_from_predicates() {
+        let predicates = [
+            col("year")
+                .eq(lit(2024))
+                .and(lit(3).gt(col("month")))
+                .and(col("a").eq(col("b"))),
+            col("city")
+                .eq(lit("a b"))
+                .or(col("city").is_null())
+                .alias("x"),
+            col("year").eq(lit(1)).or(col("month").eq(lit(1))),
+        ];
+
+        let filter = hive_path_filter_from_predicates(&predicates);
+
+        assert!(filter.keep_dir("year=2024"));
+        assert!(!filter.keep_dir("year=2023"));
+        assert!(filter.keep_dir("month=2"));
+        assert!(!filter.keep_dir("month=3"));
+        assert!(filter.keep_dir("city=a%20b"));
+        assert!(filter.keep_dir("city=__HIVE_DEFAULT_PARTITION__"));
+        assert!(!filter.keep_dir("city=c"));
+        // Predicates over multiple columns are not used.
+        assert!(filter.keep_dir("a=1"));
+    }
+}
@@ -58,6 +58,16 @@ impl<'py> FromPyObject<'py> for Wrap<AnyValue<'static>> {
     }
 }
 
+static UUID_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+pub(crate) fn uuid_into_py_object(v: u128, py: Python<'_>) -> PyResult<Bound<'_, PyAny>> {
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(intern!(py, "int"), v)?;
+    UUID_TYPE
+        .import(py, "uuid", "UUID")?
+        .call((), Some(&kwargs))
+}
+
 pub(crate) fn any_value_into_py_object<'py>(
     av: AnyValue<'_>,
     py: Python<'py>,
@@ -73,6 +83,8 @@ pub(crate) fn any_value_into_py_object<'py>(
         AnyValue::Int32(v) => v.into_bound_py_any(py),
         AnyValue::Int64(v) => v.into_bound_py_any(py),
         AnyValue::Int128(v) => v.into_bound_py_any(py),
+        AnyValue::UInt128(v) => v.into_bound_py_any(py),
+        AnyValue::Float16(v) => v.to_f32().into_bound_py_any(py),
         AnyValue::Float32(v) => v.into_bound_py_any(py),
         AnyValue::Float64(v) => v.into_bound_py_any(py),
         AnyValue::Null => py.None().into_bound_py_any(py),
@@ -110,6 +122,8 @@ pub(crate) fn any_value_into_py_object<'py>(
             time_delta.into_bound_py_any(py)
         },
         AnyValue::Time(v) => nanos_since_midnight_to_naivetime(v).into_bound_py_any(py),
+        AnyValue::Interval(v) => (v.months(), v.days(), v.ns()).into_bound_py_any(py),
+        AnyValue::Uuid(v) => uuid_into_py_object(v, py),
         AnyValue::Array(v, _) | AnyValue::List(v) => PySeries::new(v).to_list(py),
         ref av @ AnyValue::Struct(_, _, flds) => {
             Ok(struct_dict(py, av._iter_struct_av(), flds)?.into_any())
@@ -331,6 +345,11 @@ pub(crate) fn py_object_to_any_value(
         ))
     }
 
+    fn get_uuid(ob: &Bound<'_, PyAny>, _strict: bool) -> PyResult<AnyValue<'static>> {
+        let v = ob.getattr(intern!(ob.py(), "int"))?.extract::<u128>()?;
+        Ok(AnyValue::Uuid(v))
+    }
+
     fn get_decimal(ob: &Bound<'_, PyAny>, _strict: bool) -> PyResult<AnyValue<'static>> {
         fn abs_decimal_from_digits(
             digits: impl IntoIterator<Item = u8>,
@@ -536,6 +555,9 @@ pub(crate) fn py_object_to_any_value(
             if ob.is_instance(DECIMAL_TYPE.import(py, "decimal", "Decimal")?)? {
                 return Ok(get_decimal as InitFn);
             }
+            if ob.is_instance(UUID_TYPE.import(py, "uuid", "UUID")?)? {
+                return Ok(get_uuid as InitFn);
+            }
 
             // Support NumPy scalars.
             if ob.extract::<i64>().is_ok() || ob.extract::<u64>().is_ok() {
@@ -229,6 +229,14 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Int128"))?;
                 class.call0()
             },
+            DataType::UInt128 => {
+                let class = pl.getattr(intern!(py, "UInt128"))?;
+                class.call0()
+            },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16"))?;
+                class.call0()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32"))?;
                 class.call0()
@@ -254,6 +262,18 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Binary"))?;
                 class.call0()
             },
+            DataType::FixedSizeBinary(size) => {
+                let class = pl.getattr(intern!(py, "FixedSizeBinary"))?;
+                class.call1((*size,))
+            },
+            DataType::Uuid => {
+                let class = pl.getattr(intern!(py, "Uuid"))?;
+                class.call0()
+            },
+            DataType::Json => {
+                let class = pl.getattr(intern!(py, "Json"))?;
+                class.call0()
+            },
             DataType::Array(inner, size) => {
                 let class = pl.getattr(intern!(py, "Array"))?;
                 let inner = Wrap(*inner.clone());
@@ -265,6 +285,25 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let inner = Wrap(*inner.clone());
                 class.call1((&inner,))
             },
+            DataType::Map(key, value) => {
+                let class = pl.getattr(intern!(py, "Map"))?;
+                let key = Wrap(*key.clone());
+                let value = Wrap(*value.clone());
+                class.call1((&key, &value))
+            },
+            // Python has no extension dtype, so extensions are exposed as their storage.
+            DataType::Extension { storage, .. } => Wrap(*storage.clone()).into_pyobject(py),
+            DataType::Union(variants) => {
+                let field_class = pl.getattr(intern!(py, "Field"))?;
+                let iter = variants.iter().map(|fld| {
+                    let name = fld.name().as_str();
+                    let dtype = Wrap(fld.dtype().clone());
+                    field_class.call1((name, &dtype)).unwrap()
+                });
+                let variants = PyList::new(py, iter)?;
+                let union_class = pl.getattr(intern!(py, "Union"))?;
+                union_class.call1((variants,))
+            },
             DataType::Date => {
                 let class = pl.getattr(intern!(py, "Date"))?;
                 class.call0()
@@ -277,6 +316,10 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let duration_class = pl.getattr(intern!(py, "Duration"))?;
                 duration_class.call1((tu.to_ascii(),))
             },
+            DataType::Interval => {
+                let class = pl.getattr(intern!(py, "Interval"))?;
+                class.call0()
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => {
                 let class = pl.getattr(intern!(py, "Object"))?;
@@ -362,6 +405,8 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "UInt128" => DataType::UInt128,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
@@ -373,10 +418,15 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "Time" => DataType::Time,
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Duration" => DataType::Duration(TimeUnit::Microseconds),
+                    "Interval" => DataType::Interval,
+                    "Uuid" => DataType::Uuid,
+                    "Json" => DataType::Json,
                     "Decimal" => DataType::Decimal(None, None), // "none" scale => "infer"
                     "List" => DataType::List(Box::new(DataType::Null)),
+                    "Map" => DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null)),
                     "Array" => DataType::Array(Box::new(DataType::Null), 0),
                     "Struct" => DataType::Struct(vec![]),
+                    "Union" => DataType::Union(vec![]),
                     "Null" => DataType::Null,
                     #[cfg(feature = "object")]
                     "Object" => DataType::Object(OBJECT_NAME),
@@ -397,11 +447,19 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "UInt128" => DataType::UInt128,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
             "String" => DataType::String,
             "Binary" => DataType::Binary,
+            "FixedSizeBinary" => {
+                let size = ob.getattr(intern!(py, "size"))?.extract()?;
+                DataType::FixedSizeBinary(size)
+            },
+            "Uuid" => DataType::Uuid,
+            "Json" => DataType::Json,
             "Categorical" => {
                 let ordering = ob.getattr(intern!(py, "ordering")).unwrap();
                 let ordering = ordering.extract::<Wrap<CategoricalOrdering>>()?.0;
@@ -431,6 +489,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let time_unit = time_unit.extract::<Wrap<TimeUnit>>()?.0;
                 DataType::Duration(time_unit)
             },
+            "Interval" => DataType::Interval,
             "Decimal" => {
                 let precision = ob.getattr(intern!(py, "precision"))?.extract()?;
                 let scale = ob.getattr(intern!(py, "scale"))?.extract()?;
@@ -441,6 +500,13 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let inner = inner.extract::<Wrap<DataType>>()?;
                 DataType::List(Box::new(inner.0))
             },
+            "Map" => {
+                let key = ob.getattr(intern!(py, "key")).unwrap();
+                let value = ob.getattr(intern!(py, "value")).unwrap();
+                let key = key.extract::<Wrap<DataType>>()?;
+                let value = value.extract::<Wrap<DataType>>()?;
+                DataType::Map(Box::new(key.0), Box::new(value.0))
+            },
             "Array" => {
                 let inner = ob.getattr(intern!(py, "inner")).unwrap();
                 let size = ob.getattr(intern!(py, "size")).unwrap();
@@ -457,6 +523,15 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     .collect::<Vec<Field>>();
                 DataType::Struct(fields)
             },
+            "Union" => {
+                let variants = ob.getattr(intern!(py, "variants"))?;
+                let variants = variants
+                    .extract::<Vec<Wrap<Field>>>()?
+                    .into_iter()
+                    .map(|f| f.0)
+                    .collect::<Vec<Field>>();
+                DataType::Union(variants)
+            },
             "Null" => DataType::Null,
             #[cfg(feature = "object")]
             "Object" => DataType::Object(OBJECT_NAME),
@@ -848,9 +923,10 @@ impl<'py> FromPyObject<'py> for Wrap<RoundMode> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "half_to_even" => RoundMode::HalfToEven,
             "half_away_from_zero" => RoundMode::HalfAwayFromZero,
+            "truncate" => RoundMode::Truncate,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "`mode` must be one of {{'half_to_even', 'half_away_from_zero'}}, got {v}",
+                    "`mode` must be one of {{'half_to_even', 'half_away_from_zero', 'truncate'}}, got {v}",
                 )));
             },
         };
@@ -1041,6 +1117,54 @@ impl<'py> FromPyObject<'py> for Wrap<QuantileMethod> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<CorrelationMethod> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "pearson" => CorrelationMethod::Pearson,
+            "spearman" => CorrelationMethod::SpearmanRank(false),
+            "kendall" => CorrelationMethod::KendallTau,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`method` must be one of {{'pearson', 'spearman', 'kendall'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<CorrMatrixLayout> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "square" => CorrMatrixLayout::Square,
+            "tidy" => CorrMatrixLayout::Tidy,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`layout` must be one of {{'square', 'tidy'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<RollingRankMethod> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "average" => RollingRankMethod::Average,
+            "min" => RollingRankMethod::Min,
+            "max" => RollingRankMethod::Max,
+            "dense" => RollingRankMethod::Dense,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "rolling rank `method` must be one of {{'average', 'min', 'max', 'dense'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<RankMethod> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1076,6 +1200,149 @@ impl<'py> FromPyObject<'py> for Wrap<Roll> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<Observance> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "none" => Observance::None,
+            "nearest_weekday" => Observance::NearestWeekday,
+            "next_monday" => Observance::NextMonday,
+            "sunday_to_monday" => Observance::SundayToMonday,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`observance` must be one of {{'none', 'nearest_weekday', 'next_monday', 'sunday_to_monday'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<Holiday> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = ob.py();
+        let name = ob.getattr(intern!(py, "name"))?.extract::<PyBackedStr>()?;
+        let month = ob.getattr(intern!(py, "month"))?.extract::<Option<u8>>()?;
+        let day = ob.getattr(intern!(py, "day"))?.extract::<Option<u8>>()?;
+        let weekday = ob
+            .getattr(intern!(py, "weekday"))?
+            .extract::<Option<u8>>()?;
+        let n = ob.getattr(intern!(py, "n"))?.extract::<i8>()?;
+        let easter_offset = ob
+            .getattr(intern!(py, "easter_offset"))?
+            .extract::<Option<i16>>()?;
+        let observance = ob
+            .getattr(intern!(py, "observance"))?
+            .extract::<Wrap<Observance>>()?;
+        let start_year = ob.getattr(intern!(py, "start_year"))?.extract()?;
+        let end_year = ob.getattr(intern!(py, "end_year"))?.extract()?;
+
+        let rule = match (easter_offset, month, day, weekday) {
+            (Some(offset), ..) => HolidayRule::Easter { offset },
+            // Python numbers weekdays from 1 (Monday) to 7 (Sunday).
+            (None, Some(month), None, Some(weekday @ 1..=7)) => HolidayRule::NthWeekday {
+                month,
+                weekday: weekday - 1,
+                n,
+            },
+            (None, Some(month), Some(day), None) => HolidayRule::Fixed { month, day },
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "holiday '{}' must specify either `month` and `day`, `month` and `weekday` (1-7), or `easter_offset`",
+                    &*name
+                )));
+            },
+        };
+        let holiday = Holiday::new(PlSmallStr::from_str(&name), rule)
+            .map_err(PyPolarsErr::from)?
+            .with_observance(observance.0)
+            .with_years(start_year, end_year);
+        Ok(Wrap(holiday))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<HolidayCalendar> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = ob.py();
+        let name = ob.getattr(intern!(py, "name"))?.extract::<PyBackedStr>()?;
+        let holidays = ob
+            .getattr(intern!(py, "holidays"))?
+            .extract::<Vec<Wrap<Holiday>>>()?;
+        Ok(Wrap(HolidayCalendar::new(
+            PlSmallStr::from_str(&name),
+            holidays.into_iter().map(|h| h.0).collect(),
+        )))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<FiscalUnit> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "year" => FiscalUnit::Year,
+            "quarter" => FiscalUnit::Quarter,
+            "period" => FiscalUnit::Period,
+            "week" => FiscalUnit::Week,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`unit` must be one of {{'year', 'quarter', 'period', 'week'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+impl<'py> FromPyObject<'py> for Wrap<FiscalCalendar> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = ob.py();
+        let start_month = ob.getattr(intern!(py, "start_month"))?.extract::<u8>()?;
+        let week_pattern = ob
+            .getattr(intern!(py, "week_pattern"))?
+            .extract::<Option<PyBackedStr>>()?;
+        let end_weekday = ob.getattr(intern!(py, "end_weekday"))?.extract::<u8>()?;
+        let year_end = match &*ob
+            .getattr(intern!(py, "year_end"))?
+            .extract::<PyBackedStr>()?
+        {
+            "nearest" => FiscalYearEnd::Nearest,
+            "last" => FiscalYearEnd::Last,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`year_end` must be one of {{'nearest', 'last'}}, got {v}",
+                )));
+            },
+        };
+        let label = match &*ob.getattr(intern!(py, "label"))?.extract::<PyBackedStr>()? {
+            "end" => FiscalYearLabel::End,
+            "start" => FiscalYearLabel::Start,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`label` must be one of {{'end', 'start'}}, got {v}",
+                )));
+            },
+        };
+
+        let calendar = match week_pattern.as_deref() {
+            None => FiscalCalendar::new(start_month),
+            Some(pattern) => {
+                let pattern = match pattern {
+                    "445" => FiscalWeekPattern::P445,
+                    "454" => FiscalWeekPattern::P454,
+                    "544" => FiscalWeekPattern::P544,
+                    v => {
+                        return Err(PyValueError::new_err(format!(
+                            "`week_pattern` must be one of {{'445', '454', '544'}}, got {v}",
+                        )));
+                    },
+                };
+                // Python numbers weekdays from 1 (Monday) to 7 (Sunday).
+                FiscalCalendar::retail(start_month, pattern, end_weekday.wrapping_sub(1), year_end)
+            },
+        }
+        .map_err(PyPolarsErr::from)?;
+        Ok(Wrap(calendar.with_label(label)))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<TimeUnit> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
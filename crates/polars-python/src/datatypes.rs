@@ -38,6 +38,12 @@ pub(crate) enum PyDataType {
     Array(usize),
     Enum(Utf8ViewArray),
     Int128,
+    UInt128,
+    Float16,
+    Interval,
+    FixedSizeBinary(usize),
+    Uuid,
+    Json,
 }
 
 impl From<&DataType> for PyDataType {
@@ -53,23 +59,30 @@ impl From<&DataType> for PyDataType {
             DataType::UInt16 => UInt16,
             DataType::UInt32 => UInt32,
             DataType::UInt64 => UInt64,
+            DataType::UInt128 => UInt128,
+            DataType::Float16 => Float16,
             DataType::Float32 => Float32,
             DataType::Float64 => Float64,
             DataType::Decimal(p, s) => Decimal(*p, s.expect("unexpected null decimal scale")),
             DataType::Boolean => Bool,
             DataType::String => String,
             DataType::Binary => Binary,
+            DataType::FixedSizeBinary(size) => FixedSizeBinary(*size),
+            DataType::Uuid => Uuid,
+            DataType::Json => Json,
             DataType::Array(_, width) => Array(*width),
-            DataType::List(_) => List,
+            DataType::List(_) | DataType::Map(_, _) => List,
+            DataType::Extension { storage, .. } => Self::from(storage.as_ref()),
             DataType::Date => Date,
             DataType::Datetime(tu, tz) => Datetime(*tu, tz.clone()),
             DataType::Duration(tu) => Duration(*tu),
+            DataType::Interval => Interval,
             DataType::Time => Time,
             #[cfg(feature = "object")]
             DataType::Object(_) => Object,
             DataType::Categorical(_, _) => Categorical,
             DataType::Enum(rev_map, _) => Enum(rev_map.as_ref().unwrap().get_categories().clone()),
-            DataType::Struct(_) => Struct,
+            DataType::Struct(_) | DataType::Union(_) => Struct,
             DataType::Null | DataType::Unknown(_) | DataType::BinaryOffset => {
                 panic!("null or unknown not expected here")
             },
@@ -113,6 +126,12 @@ impl From<PyDataType> for DataType {
             PyDataType::Decimal(p, s) => Decimal(p, Some(s)),
             PyDataType::Array(width) => Array(DataType::Null.into(), width),
             PyDataType::Int128 => Int128,
+            PyDataType::UInt128 => UInt128,
+            PyDataType::Float16 => Float16,
+            PyDataType::Interval => Interval,
+            PyDataType::FixedSizeBinary(size) => FixedSizeBinary(size),
+            PyDataType::Uuid => Uuid,
+            PyDataType::Json => Json,
         }
     }
 }
@@ -66,4 +66,28 @@ impl PyExpr {
     fn bin_size_bytes(&self) -> Self {
         self.inner.clone().binary().size_bytes().into()
     }
+
+    fn bin_md5(&self, hex: bool) -> Self {
+        self.inner.clone().binary().md5(hex).into()
+    }
+
+    fn bin_sha1(&self, hex: bool) -> Self {
+        self.inner.clone().binary().sha1(hex).into()
+    }
+
+    fn bin_sha256(&self, hex: bool) -> Self {
+        self.inner.clone().binary().sha256(hex).into()
+    }
+
+    fn bin_xxh3_64(&self, seed: u64) -> Self {
+        self.inner.clone().binary().xxh3_64(seed).into()
+    }
+
+    fn bin_xxh3_128(&self, seed: u64, hex: bool) -> Self {
+        self.inner.clone().binary().xxh3_128(seed, hex).into()
+    }
+
+    fn bin_murmur3_32(&self, seed: u32, spark: bool) -> Self {
+        self.inner.clone().binary().murmur3_32(seed, spark).into()
+    }
 }
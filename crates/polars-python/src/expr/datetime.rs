@@ -11,12 +11,13 @@ impl PyExpr {
         n: PyExpr,
         week_mask: [bool; 7],
         holidays: Vec<i32>,
+        calendar: Option<Wrap<HolidayCalendar>>,
         roll: Wrap<Roll>,
     ) -> Self {
         self.inner
             .clone()
             .dt()
-            .add_business_days(n.inner, week_mask, holidays, roll.0)
+            .add_business_days(n.inner, week_mask, holidays, calendar.map(|c| c.0), roll.0)
             .into()
     }
 
@@ -92,6 +93,30 @@ impl PyExpr {
         self.inner.clone().dt().truncate(every.inner).into()
     }
 
+    fn dt_fiscal_year(&self, calendar: Wrap<FiscalCalendar>) -> Self {
+        self.inner.clone().dt().fiscal_year(calendar.0).into()
+    }
+
+    fn dt_fiscal_quarter(&self, calendar: Wrap<FiscalCalendar>) -> Self {
+        self.inner.clone().dt().fiscal_quarter(calendar.0).into()
+    }
+
+    fn dt_fiscal_period(&self, calendar: Wrap<FiscalCalendar>) -> Self {
+        self.inner.clone().dt().fiscal_period(calendar.0).into()
+    }
+
+    fn dt_fiscal_week(&self, calendar: Wrap<FiscalCalendar>) -> Self {
+        self.inner.clone().dt().fiscal_week(calendar.0).into()
+    }
+
+    fn dt_fiscal_truncate(&self, calendar: Wrap<FiscalCalendar>, unit: Wrap<FiscalUnit>) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .fiscal_truncate(calendar.0, unit.0)
+            .into()
+    }
+
     fn dt_month_start(&self) -> Self {
         self.inner.clone().dt().month_start().into()
     }
@@ -156,11 +181,16 @@ impl PyExpr {
     fn dt_year(&self) -> Self {
         self.inner.clone().dt().year().into()
     }
-    fn dt_is_business_day(&self, week_mask: [bool; 7], holidays: Vec<i32>) -> Self {
+    fn dt_is_business_day(
+        &self,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        calendar: Option<Wrap<HolidayCalendar>>,
+    ) -> Self {
         self.inner
             .clone()
             .dt()
-            .is_business_day(week_mask, holidays)
+            .is_business_day(week_mask, holidays, calendar.map(|c| c.0))
             .into()
     }
     fn dt_is_leap_year(&self) -> Self {
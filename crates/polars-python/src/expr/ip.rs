@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn ip_to_int(&self, ipv6: bool) -> Self {
+        self.inner.clone().ip().to_int(ipv6).into()
+    }
+
+    fn ip_to_binary(&self) -> Self {
+        self.inner.clone().ip().to_binary().into()
+    }
+
+    fn ip_to_string(&self) -> Self {
+        self.inner.clone().ip().to_string().into()
+    }
+
+    fn ip_in_network(&self, cidr: &str) -> Self {
+        self.inner.clone().ip().in_network(cidr).into()
+    }
+}
@@ -0,0 +1,19 @@
+use pyo3::prelude::*;
+
+use super::datatype::PyDataTypeExpr;
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn json_get(&self, path: &str, dtype: PyDataTypeExpr) -> Self {
+        self.inner.clone().json().get(path, dtype.inner).into()
+    }
+
+    fn json_compact(&self) -> Self {
+        self.inner.clone().json().compact().into()
+    }
+
+    fn json_pretty(&self) -> Self {
+        self.inner.clone().json().pretty().into()
+    }
+}
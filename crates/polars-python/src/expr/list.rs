@@ -8,6 +8,7 @@ use pyo3::types::PySequence;
 
 use crate::PyExpr;
 use crate::conversion::Wrap;
+use crate::expr::ToExprs;
 
 #[pymethods]
 impl PyExpr {
@@ -137,6 +138,58 @@ impl PyExpr {
             .into()
     }
 
+    fn list_sort_by(
+        &self,
+        by: PyExpr,
+        descending: bool,
+        nulls_last: bool,
+        maintain_order: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .sort_by(
+                by.inner,
+                SortOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nulls_last)
+                    .with_maintain_order(maintain_order),
+            )
+            .into()
+    }
+
+    fn list_arg_sort(&self, descending: bool, nulls_last: bool, maintain_order: bool) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .arg_sort(
+                SortOptions::default()
+                    .with_order_descending(descending)
+                    .with_nulls_last(nulls_last)
+                    .with_maintain_order(maintain_order),
+            )
+            .into()
+    }
+
+    #[cfg(feature = "search_sorted")]
+    fn list_search_sorted(
+        &self,
+        element: PyExpr,
+        side: Wrap<SearchSortedSide>,
+        descending: bool,
+    ) -> Self {
+        self.inner
+            .clone()
+            .list()
+            .search_sorted(element.inner, side.0, descending)
+            .into()
+    }
+
+    #[cfg(feature = "top_k")]
+    fn list_top_k(&self, k: PyExpr) -> Self {
+        self.inner.clone().list().top_k(k.inner).into()
+    }
+
     fn list_sum(&self) -> Self {
         self.inner.clone().list().sum().into()
     }
@@ -244,6 +297,18 @@ impl PyExpr {
             .into())
     }
 
+    fn list_zip(&self, others: Vec<PyExpr>) -> Self {
+        self.inner.clone().list().zip(others.to_exprs()).into()
+    }
+
+    fn list_unzip(&self) -> Self {
+        self.inner.clone().list().unzip().into()
+    }
+
+    fn list_enumerate(&self) -> Self {
+        self.inner.clone().list().enumerate().into()
+    }
+
     fn list_n_unique(&self) -> Self {
         self.inner.clone().list().n_unique().into()
     }
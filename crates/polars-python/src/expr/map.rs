@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn map_get(&self, key: PyExpr) -> Self {
+        self.inner.clone().map_().get(key.inner).into()
+    }
+
+    fn map_contains_key(&self, key: PyExpr) -> Self {
+        self.inner.clone().map_().contains_key(key.inner).into()
+    }
+
+    fn map_keys(&self) -> Self {
+        self.inner.clone().map_().keys().into()
+    }
+
+    fn map_values(&self) -> Self {
+        self.inner.clone().map_().values().into()
+    }
+}
@@ -12,7 +12,13 @@ mod datetime;
 #[cfg(feature = "pymethods")]
 mod general;
 #[cfg(feature = "pymethods")]
+mod ip;
+#[cfg(feature = "pymethods")]
+mod json;
+#[cfg(feature = "pymethods")]
 mod list;
+#[cfg(feature = "pymethods")]
+mod map;
 #[cfg(all(feature = "meta", feature = "pymethods"))]
 mod meta;
 #[cfg(feature = "pymethods")]
@@ -25,6 +31,10 @@ mod serde;
 mod string;
 #[cfg(feature = "pymethods")]
 mod r#struct;
+#[cfg(feature = "pymethods")]
+mod union;
+#[cfg(feature = "pymethods")]
+mod url;
 
 use std::mem::ManuallyDrop;
 
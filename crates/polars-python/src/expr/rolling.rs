@@ -365,6 +365,289 @@ impl PyExpr {
         self.inner.clone().rolling_kurtosis(options).into()
     }
 
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_n_unique(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_n_unique(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_n_unique_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_n_unique_by(by.inner, options)
+            .into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_mode(&self, window_size: usize, min_periods: Option<usize>, center: bool) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_mode(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_mode_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self.inner.clone().rolling_mode_by(by.inner, options).into())
+    }
+
+    #[pyo3(signature = (window_size, method, min_periods, center))]
+    fn rolling_rank(
+        &self,
+        window_size: usize,
+        method: Wrap<RollingRankMethod>,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_rank(method.0, options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, method, min_periods, closed))]
+    fn rolling_rank_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        method: Wrap<RollingRankMethod>,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_rank_by(by.inner, method.0, options)
+            .into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_arg_min(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_arg_min(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_arg_min_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_arg_min_by(by.inner, options)
+            .into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_arg_max(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_arg_max(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_arg_max_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_arg_max_by(by.inner, options)
+            .into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_first(&self, window_size: usize, min_periods: Option<usize>, center: bool) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_first(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_first_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_first_by(by.inner, options)
+            .into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_last(&self, window_size: usize, min_periods: Option<usize>, center: bool) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_last(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_last_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self.inner.clone().rolling_last_by(by.inner, options).into())
+    }
+
+    #[pyo3(signature = (window_size, min_periods, center))]
+    fn rolling_product(
+        &self,
+        window_size: usize,
+        min_periods: Option<usize>,
+        center: bool,
+    ) -> Self {
+        let min_periods = min_periods.unwrap_or(window_size);
+        let options = RollingOptionsFixedWindow {
+            window_size,
+            min_periods,
+            center,
+            ..Default::default()
+        };
+        self.inner.clone().rolling_product(options).into()
+    }
+
+    #[pyo3(signature = (by, window_size, min_periods, closed))]
+    fn rolling_product_by(
+        &self,
+        by: PyExpr,
+        window_size: &str,
+        min_periods: usize,
+        closed: Wrap<ClosedWindow>,
+    ) -> PyResult<Self> {
+        let options = RollingOptionsDynamicWindow {
+            window_size: Duration::try_parse(window_size).map_err(PyPolarsErr::from)?,
+            min_periods,
+            closed_window: closed.0,
+            fn_params: None,
+        };
+        Ok(self
+            .inner
+            .clone()
+            .rolling_product_by(by.inner, options)
+            .into())
+    }
+
     #[pyo3(signature = (lambda, window_size, weights, min_periods, center))]
     fn rolling_map(
         &self,
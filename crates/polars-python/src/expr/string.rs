@@ -185,6 +185,73 @@ impl PyExpr {
         self.inner.clone().str().zfill(length.inner).into()
     }
 
+    #[pyo3(signature = (other, max_distance))]
+    fn str_levenshtein(&self, other: PyExpr, max_distance: Option<u32>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .levenshtein(other.inner, max_distance)
+            .into()
+    }
+
+    #[pyo3(signature = (other, max_distance))]
+    fn str_damerau_levenshtein(&self, other: PyExpr, max_distance: Option<u32>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .damerau_levenshtein(other.inner, max_distance)
+            .into()
+    }
+
+    #[pyo3(signature = (other, max_distance))]
+    fn str_hamming(&self, other: PyExpr, max_distance: Option<u32>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .hamming(other.inner, max_distance)
+            .into()
+    }
+
+    fn str_jaro_winkler(&self, other: PyExpr) -> Self {
+        self.inner.clone().str().jaro_winkler(other.inner).into()
+    }
+
+    fn str_jaccard_ngram(&self, other: PyExpr, n: usize) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .jaccard_ngram(other.inner, n)
+            .into()
+    }
+
+    fn str_split_regex(&self, pat: PyExpr) -> Self {
+        self.inner.clone().str().split_regex(pat.inner).into()
+    }
+
+    fn str_tokenize(&self) -> Self {
+        self.inner.clone().str().tokenize().into()
+    }
+
+    fn str_count_tokens(&self) -> Self {
+        self.inner.clone().str().count_tokens().into()
+    }
+
+    fn str_char_ngrams(&self, n: usize) -> Self {
+        self.inner.clone().str().char_ngrams(n).into()
+    }
+
+    fn str_word_ngrams(&self, n: usize) -> Self {
+        self.inner.clone().str().word_ngrams(n).into()
+    }
+
+    fn str_remove_stop_words(&self, stop_words: Vec<String>) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .remove_stop_words(stop_words.into_iter().map(PlSmallStr::from).collect())
+            .into()
+    }
+
     #[pyo3(signature = (pat, literal, strict))]
     #[cfg(feature = "regex")]
     fn str_contains(&self, pat: Self, literal: Option<bool>, strict: bool) -> Self {
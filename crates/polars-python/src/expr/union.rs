@@ -0,0 +1,14 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn union_type_id(&self) -> Self {
+        self.inner.clone().union_().type_id().into()
+    }
+
+    fn union_field(&self, name: &str) -> Self {
+        self.inner.clone().union_().field(name).into()
+    }
+}
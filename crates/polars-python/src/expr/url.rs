@@ -0,0 +1,14 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn url_parse(&self) -> Self {
+        self.inner.clone().url().parse().into()
+    }
+
+    fn url_query_param(&self, name: &str) -> Self {
+        self.inner.clone().url().query_param(name).into()
+    }
+}
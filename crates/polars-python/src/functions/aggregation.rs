@@ -46,3 +46,10 @@ pub fn mean_horizontal(exprs: Vec<PyExpr>, ignore_nulls: bool) -> PyResult<PyExp
     let e = dsl::mean_horizontal(exprs, ignore_nulls).map_err(PyPolarsErr::from)?;
     Ok(e.into())
 }
+
+#[pyfunction]
+pub fn stable_hash_rows(exprs: Vec<PyExpr>, seed: u64) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::stable_hash_rows(exprs, seed).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
@@ -1,7 +1,9 @@
 use polars::lazy::dsl;
+use polars::prelude::HolidayCalendar;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
+use crate::conversion::Wrap;
 
 #[pyfunction]
 pub fn business_day_count(
@@ -9,8 +11,18 @@ pub fn business_day_count(
     end: PyExpr,
     week_mask: [bool; 7],
     holidays: Vec<i32>,
+    calendar: Option<Wrap<HolidayCalendar>>,
 ) -> PyExpr {
     let start = start.inner;
     let end = end.inner;
-    dsl::business_day_count(start, end, week_mask, holidays).into()
+    dsl::business_day_count(start, end, week_mask, holidays, calendar.map(|c| c.0)).into()
+}
+
+#[pyfunction]
+pub fn holiday_calendar_holidays(
+    calendar: Wrap<HolidayCalendar>,
+    start: i32,
+    end: i32,
+) -> Vec<i32> {
+    calendar.0.holidays_between(start, end)
 }
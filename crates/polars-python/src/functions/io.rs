@@ -67,6 +67,23 @@ pub fn read_parquet_metadata(py: Python, py_f: PyObject) -> PyResult<Bound<PyDic
     Ok(dict)
 }
 
+#[cfg(feature = "parquet")]
+#[pyfunction]
+#[pyo3(signature = (uri=None))]
+pub fn clear_parquet_metadata_cache(uri: Option<&str>) {
+    #[cfg(feature = "cloud")]
+    {
+        use polars_io::parquet::metadata::FILE_METADATA_CACHE;
+
+        match uri {
+            Some(uri) => FILE_METADATA_CACHE.invalidate(uri),
+            None => FILE_METADATA_CACHE.clear(),
+        }
+    }
+    #[cfg(not(feature = "cloud"))]
+    let _ = uri;
+}
+
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 fn fields_to_pydict(schema: &ArrowSchema, dict: &Bound<'_, PyDict>) -> PyResult<()> {
     for field in schema.iter_values() {
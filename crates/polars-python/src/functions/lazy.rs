@@ -210,6 +210,16 @@ pub fn concat_list(s: Vec<PyExpr>) -> PyResult<PyExpr> {
     Ok(expr.into())
 }
 
+#[pyfunction]
+pub fn map_from_lists(keys: PyExpr, values: PyExpr) -> PyExpr {
+    dsl::map_from_lists(keys.inner, values.inner).into()
+}
+
+#[pyfunction]
+pub fn union_from_struct(expr: PyExpr) -> PyExpr {
+    dsl::union_from_struct(expr.inner).into()
+}
+
 #[pyfunction]
 pub fn concat_arr(s: Vec<PyExpr>) -> PyResult<PyExpr> {
     let s = s.into_iter().map(|e| e.inner).collect::<Vec<_>>();
@@ -362,6 +372,16 @@ pub fn concat_expr(e: Vec<PyExpr>, rechunk: bool) -> PyResult<PyExpr> {
     Ok(e.into())
 }
 
+#[pyfunction]
+pub fn corr_matrix(columns: Vec<String>, method: Wrap<CorrelationMethod>) -> PyResult<PyExpr> {
+    let columns = columns
+        .into_iter()
+        .map(PlSmallStr::from)
+        .collect::<Vec<_>>();
+    let e = dsl::corr_matrix(&columns, method.0).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
 #[pyfunction]
 pub fn dtype_cols(dtypes: Vec<Wrap<DataType>>) -> PyResult<PyExpr> {
     let dtypes = vec_extract_wrapped(dtypes);
@@ -524,6 +544,11 @@ pub fn map_mul(
     map::lazy::map_mul(&pyexpr, py, lambda, output_type, map_groups, returns_scalar)
 }
 
+#[pyfunction]
+pub fn kendall_tau_corr(a: PyExpr, b: PyExpr) -> PyExpr {
+    dsl::kendall_tau_corr(a.inner, b.inner).into()
+}
+
 #[pyfunction]
 pub fn pearson_corr(a: PyExpr, b: PyExpr) -> PyExpr {
     dsl::pearson_corr(a.inner, b.inner).into()
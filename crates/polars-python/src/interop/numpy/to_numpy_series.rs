@@ -13,6 +13,7 @@ use super::utils::{
     reshape_numpy_array, series_contains_null,
 };
 use crate::conversion::ObjectValue;
+use crate::conversion::any_value::uuid_into_py_object;
 use crate::conversion::chunked_array::{decimal_to_pyobject_iter, time_to_pyobject_iter};
 use crate::series::PySeries;
 
@@ -199,6 +200,14 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
         UInt16 => numeric_series_to_numpy::<UInt16Type, f32>(py, s),
         UInt32 => numeric_series_to_numpy::<UInt32Type, f64>(py, s),
         UInt64 => numeric_series_to_numpy::<UInt64Type, f64>(py, s),
+        UInt128 => {
+            let s = s.cast(&DataType::Float64).unwrap();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
+        Float16 => {
+            let s = s.cast(&DataType::Float32).unwrap();
+            series_to_numpy(py, &s, writable, true).unwrap()
+        },
         Float32 => numeric_series_to_numpy::<Float32Type, f32>(py, s),
         Float64 => numeric_series_to_numpy::<Float64Type, f64>(py, s),
         Boolean => boolean_series_to_numpy(py, s),
@@ -231,6 +240,15 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
                 },
             }
         },
+        Interval => {
+            let ca = s.interval().unwrap();
+            let values = ca.iter_intervals().map(|v| {
+                v.map(|v| (v.months(), v.days(), v.ns()))
+                    .into_py_any(py)
+                    .unwrap()
+            });
+            PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+        },
         Time => {
             let ca = s.time().unwrap();
             let values = time_to_pyobject_iter(ca).map(|v| v.into_py_any(py).unwrap());
@@ -241,11 +259,29 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
             let values = ca.iter().map(|s| s.into_py_any(py).unwrap());
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
+        Json => {
+            let ca = s.json().unwrap();
+            let values = ca.physical().iter().map(|s| s.into_py_any(py).unwrap());
+            PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+        },
         Binary => {
             let ca = s.binary().unwrap();
             let values = ca.iter().map(|s| s.into_py_any(py).unwrap());
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
+        FixedSizeBinary(_) => {
+            let ca = s.fixed_size_binary().unwrap();
+            let values = ca.iter().map(|s| s.into_py_any(py).unwrap());
+            PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+        },
+        Uuid => {
+            let ca = s.uuid().unwrap();
+            let values = ca.physical().iter().map(|v| match v {
+                Some(v) => uuid_into_py_object(v, py).unwrap().unbind(),
+                None => py.None(),
+            });
+            PyArray1::from_iter(py, values).into_py_any(py).unwrap()
+        },
         Categorical(_, _) | Enum(_, _) => {
             let ca = s.categorical().unwrap();
             let values = ca.iter_str().map(|s| s.into_py_any(py).unwrap());
@@ -259,7 +295,18 @@ fn series_to_numpy_with_copy(py: Python<'_>, s: &Series, writable: bool) -> PyOb
             PyArray1::from_iter(py, values).into_py_any(py).unwrap()
         },
         List(_) => list_series_to_numpy(py, s, writable),
+        Map(_, _) => {
+            let s = s.map().unwrap().entries().clone().into_series();
+            list_series_to_numpy(py, &s, writable)
+        },
         Array(_, _) => array_series_to_numpy(py, s, writable),
+        Extension { .. } => {
+            series_to_numpy_with_copy(py, s.extension().unwrap().storage(), writable)
+        },
+        Union(_) => {
+            let s = s.union().unwrap().to_struct().into_series();
+            series_to_numpy_with_copy(py, &s, writable)
+        },
         Struct(_) => {
             let ca = s.struct_().unwrap();
             let df = ca.clone().unnest();
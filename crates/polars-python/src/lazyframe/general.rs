@@ -759,6 +759,7 @@ impl PyLazyFrame {
                 SinkTarget::Partition(partition) => ldf.sink_parquet_partitioned(
                     Arc::new(partition.base_path),
                     partition.file_path_cb.map(PartitionTargetCallback::Python),
+                    partition.path_options,
                     partition.variant,
                     options,
                     cloud_options,
@@ -825,6 +826,7 @@ impl PyLazyFrame {
                 SinkTarget::Partition(partition) => ldf.sink_ipc_partitioned(
                     Arc::new(partition.base_path),
                     partition.file_path_cb.map(PartitionTargetCallback::Python),
+                    partition.path_options,
                     partition.variant,
                     options,
                     cloud_options,
@@ -919,6 +921,7 @@ impl PyLazyFrame {
                 SinkTarget::Partition(partition) => ldf.sink_csv_partitioned(
                     Arc::new(partition.base_path),
                     partition.file_path_cb.map(PartitionTargetCallback::Python),
+                    partition.path_options,
                     partition.variant,
                     options,
                     cloud_options,
@@ -972,6 +975,7 @@ impl PyLazyFrame {
                 SinkTarget::Partition(partition) => ldf.sink_json_partitioned(
                     Arc::new(partition.base_path),
                     partition.file_path_cb.map(PartitionTargetCallback::Python),
+                    partition.path_options,
                     partition.variant,
                     options,
                     cloud_options,
@@ -1063,6 +1067,7 @@ impl PyLazyFrame {
         closed: Wrap<ClosedWindow>,
         group_by: Vec<PyExpr>,
         start_by: Wrap<StartBy>,
+        fiscal_calendar: Option<Wrap<FiscalCalendar>>,
     ) -> PyResult<PyLazyGroupBy> {
         let closed_window = closed.0;
         let group_by = group_by
@@ -1081,6 +1086,7 @@ impl PyLazyFrame {
                 include_boundaries,
                 closed_window,
                 start_by: start_by.0,
+                fiscal_calendar: fiscal_calendar.map(|c| c.0),
                 ..Default::default()
             },
         );
@@ -1481,6 +1487,24 @@ impl PyLazyFrame {
         ldf.unpivot(args).into()
     }
 
+    #[pyo3(signature = (columns, method, layout))]
+    fn corr_matrix(
+        &self,
+        columns: Vec<String>,
+        method: Wrap<CorrelationMethod>,
+        layout: Wrap<CorrMatrixLayout>,
+    ) -> PyResult<Self> {
+        let columns = columns
+            .into_iter()
+            .map(PlSmallStr::from)
+            .collect::<Vec<_>>();
+        let ldf = self.ldf.clone();
+        let out = ldf
+            .corr_matrix(&columns, method.0, layout.0)
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
     #[pyo3(signature = (name, offset=None))]
     fn with_row_index(&self, name: &str, offset: Option<IdxSize>) -> Self {
         let ldf = self.ldf.clone();
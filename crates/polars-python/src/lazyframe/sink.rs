@@ -2,7 +2,10 @@ use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 use polars::prelude::sync_on_close::SyncOnCloseType;
-use polars::prelude::{PartitionVariant, SinkFinishCallback, SinkOptions, SortColumn, SpecialEq};
+use polars::prelude::{
+    PartitionOverwriteMode, PartitionPathOptions, PartitionPathTemplate, PartitionVariant,
+    SinkFinishCallback, SinkOptions, SortColumn, SpecialEq,
+};
 use polars_utils::IdxSize;
 use polars_utils::python_function::{PythonFunction, PythonObject};
 use pyo3::exceptions::PyValueError;
@@ -10,6 +13,7 @@ use pyo3::pybacked::PyBackedStr;
 use pyo3::types::{PyAnyMethods, PyDict, PyDictMethods};
 use pyo3::{Bound, FromPyObject, PyAny, PyObject, PyResult, Python, pyclass, pymethods};
 
+use crate::error::PyPolarsErr;
 use crate::expr::PyExpr;
 use crate::prelude::Wrap;
 
@@ -28,6 +32,7 @@ pub struct PyPartitioning {
     pub variant: PartitionVariant,
     pub per_partition_sort_by: Option<Vec<SortColumn>>,
     pub finish_callback: Option<SinkFinishCallback>,
+    pub path_options: PartitionPathOptions,
 }
 
 fn parse_per_partition_sort_by(sort_by: Option<Vec<PyExpr>>) -> Option<Vec<SortColumn>> {
@@ -43,6 +48,21 @@ fn parse_per_partition_sort_by(sort_by: Option<Vec<PyExpr>>) -> Option<Vec<SortC
     })
 }
 
+fn parse_path_options(
+    path_template: Option<&str>,
+    overwrite_mode: PartitionOverwriteMode,
+) -> PyResult<PartitionPathOptions> {
+    let template = path_template
+        .map(PartitionPathTemplate::new)
+        .transpose()
+        .map_err(PyPolarsErr::from)?;
+
+    Ok(PartitionPathOptions {
+        template,
+        overwrite_mode,
+    })
+}
+
 #[cfg(feature = "pymethods")]
 #[pymethods]
 impl PyPartitioning {
@@ -65,11 +85,16 @@ impl PyPartitioning {
             variant: PartitionVariant::MaxSize(max_size),
             per_partition_sort_by: parse_per_partition_sort_by(per_partition_sort_by),
             finish_callback,
+            path_options: PartitionPathOptions::default(),
         }
     }
 
     #[staticmethod]
-    #[pyo3(signature = (base_path, file_path_cb, by, include_key, per_partition_sort_by, finish_callback))]
+    #[pyo3(signature = (
+        base_path, file_path_cb, by, include_key, per_partition_sort_by, finish_callback,
+        path_template, overwrite_mode
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn new_by_key(
         base_path: PathBuf,
         file_path_cb: Option<PyObject>,
@@ -77,12 +102,14 @@ impl PyPartitioning {
         include_key: bool,
         per_partition_sort_by: Option<Vec<PyExpr>>,
         finish_callback: Option<PyObject>,
-    ) -> PyPartitioning {
+        path_template: Option<PyBackedStr>,
+        overwrite_mode: Wrap<PartitionOverwriteMode>,
+    ) -> PyResult<PyPartitioning> {
         let file_path_cb = file_path_cb.map(|f| PythonObject(f.into_any()));
         let finish_callback =
             finish_callback.map(|f| SinkFinishCallback::Python(PythonObject(f.into_any())));
 
-        PyPartitioning {
+        Ok(PyPartitioning {
             base_path,
             file_path_cb,
             variant: PartitionVariant::ByKey {
@@ -91,11 +118,16 @@ impl PyPartitioning {
             },
             per_partition_sort_by: parse_per_partition_sort_by(per_partition_sort_by),
             finish_callback,
-        }
+            path_options: parse_path_options(path_template.as_deref(), overwrite_mode.0)?,
+        })
     }
 
     #[staticmethod]
-    #[pyo3(signature = (base_path, file_path_cb, by, include_key, per_partition_sort_by, finish_callback))]
+    #[pyo3(signature = (
+        base_path, file_path_cb, by, include_key, per_partition_sort_by, finish_callback,
+        path_template, overwrite_mode
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn new_parted(
         base_path: PathBuf,
         file_path_cb: Option<PyObject>,
@@ -103,12 +135,14 @@ impl PyPartitioning {
         include_key: bool,
         per_partition_sort_by: Option<Vec<PyExpr>>,
         finish_callback: Option<PyObject>,
-    ) -> PyPartitioning {
+        path_template: Option<PyBackedStr>,
+        overwrite_mode: Wrap<PartitionOverwriteMode>,
+    ) -> PyResult<PyPartitioning> {
         let file_path_cb = file_path_cb.map(|f| PythonObject(f.into_any()));
         let finish_callback =
             finish_callback.map(|f| SinkFinishCallback::Python(PythonObject(f.into_any())));
 
-        PyPartitioning {
+        Ok(PyPartitioning {
             base_path,
             file_path_cb,
             variant: PartitionVariant::Parted {
@@ -117,7 +151,8 @@ impl PyPartitioning {
             },
             per_partition_sort_by: parse_per_partition_sort_by(per_partition_sort_by),
             finish_callback,
-        }
+            path_options: parse_path_options(path_template.as_deref(), overwrite_mode.0)?,
+        })
     }
 }
 
@@ -182,6 +217,21 @@ impl<'py> FromPyObject<'py> for Wrap<SyncOnCloseType> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<PartitionOverwriteMode> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "append" => PartitionOverwriteMode::Append,
+            "dynamic" => PartitionOverwriteMode::Dynamic,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`overwrite_mode` must be one of {{'append', 'dynamic'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
@@ -252,6 +252,11 @@ pub enum PyTemporalFunction {
     OffsetBy,
     MonthStart,
     MonthEnd,
+    FiscalYear,
+    FiscalQuarter,
+    FiscalPeriod,
+    FiscalWeek,
+    FiscalTruncate,
     BaseUtcOffset,
     DSTOffset,
     Round,
@@ -758,6 +763,21 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::ListExpr(_) => {
                     return Err(PyNotImplementedError::new_err("list expr"));
                 },
+                IRFunctionExpr::MapExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("map expr"));
+                },
+                IRFunctionExpr::UnionExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("union expr"));
+                },
+                IRFunctionExpr::JsonExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("json expr"));
+                },
+                IRFunctionExpr::UrlExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("url expr"));
+                },
+                IRFunctionExpr::IpExpr(_) => {
+                    return Err(PyNotImplementedError::new_err("ip expr"));
+                },
                 IRFunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
@@ -911,6 +931,21 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRStringFunction::EscapeRegex => {
                         (PyStringFunction::EscapeRegex,).into_py_any(py)
                     },
+                    IRStringFunction::Levenshtein { .. }
+                    | IRStringFunction::DamerauLevenshtein { .. }
+                    | IRStringFunction::Hamming { .. }
+                    | IRStringFunction::JaroWinkler
+                    | IRStringFunction::JaccardNgram(_) => {
+                        return Err(PyNotImplementedError::new_err("string similarity"));
+                    },
+                    IRStringFunction::SplitRegex
+                    | IRStringFunction::Tokenize
+                    | IRStringFunction::CountTokens
+                    | IRStringFunction::CharNgrams(_)
+                    | IRStringFunction::WordNgrams(_)
+                    | IRStringFunction::RemoveStopWords(_) => {
+                        return Err(PyNotImplementedError::new_err("tokenization"));
+                    },
                 },
                 IRFunctionExpr::StructExpr(fun) => match fun {
                     IRStructFunction::FieldByIndex(index) => {
@@ -1019,6 +1054,21 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         (PyTemporalFunction::MonthStart,).into_py_any(py)
                     },
                     IRTemporalFunction::MonthEnd => (PyTemporalFunction::MonthEnd,).into_py_any(py),
+                    IRTemporalFunction::FiscalYear(_) => {
+                        (PyTemporalFunction::FiscalYear,).into_py_any(py)
+                    },
+                    IRTemporalFunction::FiscalQuarter(_) => {
+                        (PyTemporalFunction::FiscalQuarter,).into_py_any(py)
+                    },
+                    IRTemporalFunction::FiscalPeriod(_) => {
+                        (PyTemporalFunction::FiscalPeriod,).into_py_any(py)
+                    },
+                    IRTemporalFunction::FiscalWeek(_) => {
+                        (PyTemporalFunction::FiscalWeek,).into_py_any(py)
+                    },
+                    IRTemporalFunction::FiscalTruncate(_, _) => {
+                        (PyTemporalFunction::FiscalTruncate,).into_py_any(py)
+                    },
                     #[cfg(feature = "timezones")]
                     IRTemporalFunction::BaseUtcOffset => {
                         (PyTemporalFunction::BaseUtcOffset,).into_py_any(py)
@@ -1105,6 +1155,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::Hash(seed, seed_1, seed_2, seed_3) => {
                     ("hash", seed, seed_1, seed_2, seed_3).into_py_any(py)
                 },
+                IRFunctionExpr::StableHashRows { seed } => {
+                    ("stable_hash_rows", seed).into_py_any(py)
+                },
                 IRFunctionExpr::ArgWhere => ("argwhere",).into_py_any(py),
                 #[cfg(feature = "index_of")]
                 IRFunctionExpr::IndexOf => ("index_of",).into_py_any(py),
@@ -1173,6 +1226,30 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRRollingFunctionBy::StdBy(_) => {
                         return Err(PyNotImplementedError::new_err("rolling std by"));
                     },
+                    IRRollingFunctionBy::NUniqueBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling n_unique by"));
+                    },
+                    IRRollingFunctionBy::ModeBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling mode by"));
+                    },
+                    IRRollingFunctionBy::RankBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling rank by"));
+                    },
+                    IRRollingFunctionBy::ArgMinBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling arg_min by"));
+                    },
+                    IRRollingFunctionBy::ArgMaxBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling arg_max by"));
+                    },
+                    IRRollingFunctionBy::FirstBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling first by"));
+                    },
+                    IRRollingFunctionBy::LastBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling last by"));
+                    },
+                    IRRollingFunctionBy::ProductBy(_) => {
+                        return Err(PyNotImplementedError::new_err("rolling product by"));
+                    },
                 },
                 IRFunctionExpr::ShiftAndFill => ("shift_and_fill",).into_py_any(py),
                 IRFunctionExpr::Shift => ("shift",).into_py_any(py),
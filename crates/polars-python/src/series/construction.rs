@@ -174,6 +174,7 @@ init_method_opt!(new_opt_u8, UInt8Type, u8);
 init_method_opt!(new_opt_u16, UInt16Type, u16);
 init_method_opt!(new_opt_u32, UInt32Type, u32);
 init_method_opt!(new_opt_u64, UInt64Type, u64);
+init_method_opt!(new_opt_u128, UInt128Type, u128);
 init_method_opt!(new_opt_i8, Int8Type, i8);
 init_method_opt!(new_opt_i16, Int16Type, i16);
 init_method_opt!(new_opt_i32, Int32Type, i32);
@@ -282,6 +283,26 @@ impl PySeries {
         Ok(s.into())
     }
 
+    #[staticmethod]
+    fn new_interval(name: &str, values: &Bound<PyAny>, _strict: bool) -> PyResult<Self> {
+        let len = values.len()?;
+        let mut intervals = Vec::with_capacity(len);
+
+        for res in values.try_iter()? {
+            let value = res?;
+            if value.is_none() {
+                intervals.push(None)
+            } else {
+                let (months, days, ns) = value.extract::<(i32, i32, i64)>()?;
+                intervals.push(Some(months_days_ns::new(months, days, ns)))
+            }
+        }
+
+        let ca = IntervalChunked::from_intervals(name.into(), intervals);
+        let s = ca.into_series();
+        Ok(s.into())
+    }
+
     #[staticmethod]
     fn new_decimal(name: &str, values: &Bound<PyAny>, strict: bool) -> PyResult<Self> {
         Self::new_from_any_values(name, values, strict)
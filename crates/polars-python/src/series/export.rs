@@ -5,6 +5,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyCapsule, PyList};
 
 use super::PySeries;
+use crate::conversion::any_value::uuid_into_py_object;
 use crate::error::PyPolarsErr;
 use crate::interop;
 use crate::interop::arrow::to_py::series_to_stream;
@@ -29,6 +30,15 @@ impl PySeries {
                 DataType::Int32 => PyList::new(py, series.i32().map_err(PyPolarsErr::from)?)?,
                 DataType::Int64 => PyList::new(py, series.i64().map_err(PyPolarsErr::from)?)?,
                 DataType::Int128 => PyList::new(py, series.i128().map_err(PyPolarsErr::from)?)?,
+                DataType::UInt128 => PyList::new(py, series.u128().map_err(PyPolarsErr::from)?)?,
+                DataType::Float16 => PyList::new(
+                    py,
+                    series
+                        .f16()
+                        .map_err(PyPolarsErr::from)?
+                        .iter()
+                        .map(|v| v.map(|v| v.to_f32())),
+                )?,
                 DataType::Float32 => PyList::new(py, series.f32().map_err(PyPolarsErr::from)?)?,
                 DataType::Float64 => PyList::new(py, series.f64().map_err(PyPolarsErr::from)?)?,
                 DataType::Categorical(_, _) | DataType::Enum(_, _) => PyList::new(
@@ -60,6 +70,23 @@ impl PySeries {
                     }
                     v
                 },
+                DataType::Map(_, _) => {
+                    let ca = series.map().map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, &ca.entries().clone().into_series());
+                },
+                DataType::Extension { .. } => {
+                    let ca = series.extension().map_err(PyPolarsErr::from)?;
+                    return to_list_recursive(py, ca.storage());
+                },
+                // Every row is the value of its active variant.
+                DataType::Union(_) => {
+                    let v = PyList::empty(py);
+                    for i in 0..series.len() {
+                        let av = series.get(i).map_err(PyPolarsErr::from)?;
+                        v.append(Wrap(av).into_pyobject(py)?)?;
+                    }
+                    v
+                },
                 DataType::Array(_, _) => {
                     let v = PyList::empty(py);
                     let ca = series.array().map_err(PyPolarsErr::from)?;
@@ -96,6 +123,10 @@ impl PySeries {
                     let ca = series.str().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::Json => {
+                    let ca = series.json().map_err(PyPolarsErr::from)?;
+                    return Wrap(ca.physical()).into_bound_py_any(py);
+                },
                 DataType::Struct(_) => {
                     let ca = series.struct_().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
@@ -104,10 +135,33 @@ impl PySeries {
                     let ca = series.duration().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::Interval => PyList::new(
+                    py,
+                    series
+                        .interval()
+                        .map_err(PyPolarsErr::from)?
+                        .iter_intervals()
+                        .map(|v| v.map(|v| (v.months(), v.days(), v.ns()))),
+                )?,
                 DataType::Binary => {
                     let ca = series.binary().map_err(PyPolarsErr::from)?;
                     return Wrap(ca).into_bound_py_any(py);
                 },
+                DataType::FixedSizeBinary(_) => {
+                    let ca = series.fixed_size_binary().map_err(PyPolarsErr::from)?;
+                    return Wrap(&ca.to_binary()).into_bound_py_any(py);
+                },
+                DataType::Uuid => {
+                    let ca = series.uuid().map_err(PyPolarsErr::from)?;
+                    let v = PyList::empty(py);
+                    for opt_v in ca.physical() {
+                        match opt_v {
+                            Some(u) => v.append(uuid_into_py_object(u, py)?)?,
+                            None => v.append(py.None())?,
+                        }
+                    }
+                    v
+                },
                 DataType::Null => {
                     let null: Option<u8> = None;
                     let n = series.len();
@@ -8,7 +8,7 @@ use self::encode::fixed_size;
 use self::row::{RowEncodingCategoricalContext, RowEncodingOptions};
 use self::variable::utf8::decode_str;
 use super::*;
-use crate::fixed::{boolean, decimal, numeric, packed_u32};
+use crate::fixed::{boolean, decimal, fixed_size_binary, numeric, packed_u32};
 use crate::variable::{binary, no_order, utf8};
 
 /// Decode `rows` into a arrow format
@@ -113,7 +113,6 @@ fn dtype_and_data_to_encoded_item_len(
             1 + item_len
         },
 
-        D::FixedSizeBinary(_) => todo!(),
         D::FixedSizeList(fsl_field, width) => {
             let mut data = &data[1..];
             let mut item_len = 1; // validity byte
@@ -211,6 +210,7 @@ unsafe fn decode(
     match dtype {
         D::Null => NullArray::new(D::Null, rows.len()).to_boxed(),
         D::Boolean => boolean::decode_bool(rows, opt).to_boxed(),
+        D::FixedSizeBinary(_) => fixed_size_binary::decode(rows, opt, dtype).to_boxed(),
         D::Binary | D::LargeBinary | D::BinaryView | D::Utf8 | D::LargeUtf8 | D::Utf8View
             if opt.contains(RowEncodingOptions::NO_ORDER) =>
         {
@@ -2,14 +2,14 @@
 use std::mem::MaybeUninit;
 
 use arrow::array::{
-    Array, BinaryArray, BinaryViewArray, BooleanArray, FixedSizeListArray, ListArray,
-    PrimitiveArray, StructArray, Utf8Array, Utf8ViewArray,
+    Array, BinaryArray, BinaryViewArray, BooleanArray, FixedSizeBinaryArray, FixedSizeListArray,
+    ListArray, PrimitiveArray, StructArray, Utf8Array, Utf8ViewArray,
 };
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowDataType;
 use arrow::types::Offset;
 
-use crate::fixed::{boolean, decimal, numeric, packed_u32};
+use crate::fixed::{boolean, decimal, fixed_size_binary, numeric, packed_u32};
 use crate::row::{RowEncodingOptions, RowsEncoded};
 use crate::variable::{binary, no_order, utf8};
 use crate::widths::RowWidths;
@@ -624,10 +624,17 @@ unsafe fn encode_flat_array(
             encode_strs(buffer, array.iter(), opt, offsets);
         },
 
+        D::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            fixed_size_binary::encode(buffer, array, opt, offsets);
+        },
+
         // Lexical ordered Categorical are cast to PrimitiveArray above.
         D::Dictionary(_, _, _) => todo!(),
 
-        D::FixedSizeBinary(_) => todo!(),
         D::Decimal(_, _) => todo!(),
         D::Decimal32(_, _) => todo!(),
         D::Decimal64(_, _) => todo!(),
@@ -880,6 +887,7 @@ pub fn fixed_size(dtype: &ArrowDataType, dict: Option<&RowEncodingContext>) -> O
             _ => return None,
         },
         D::UInt64 => u64::ENCODED_LEN,
+        D::UInt128 => u128::ENCODED_LEN,
 
         D::Int8 => i8::ENCODED_LEN,
         D::Int16 => i16::ENCODED_LEN,
@@ -891,8 +899,10 @@ pub fn fixed_size(dtype: &ArrowDataType, dict: Option<&RowEncodingContext>) -> O
             _ => unreachable!(),
         },
 
+        D::Float16 => arrow::types::f16::ENCODED_LEN,
         D::Float32 => f32::ENCODED_LEN,
         D::Float64 => f64::ENCODED_LEN,
+        D::FixedSizeBinary(size) => fixed_size_binary::len_from_size(*size),
         D::FixedSizeList(f, width) => 1 + width * fixed_size(f.dtype(), dict)?,
         D::Struct(fs) => match dict {
             None => {
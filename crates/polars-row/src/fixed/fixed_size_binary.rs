@@ -0,0 +1,79 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+//! Row encoding for fixed-size binary values
+//!
+//! Each value of `size` bytes is encoded as one validity byte followed by the `size` bytes, which
+//! are inverted for a descending order. Nulls are encoded as the null sentinel followed by `size`
+//! zero bytes.
+
+use std::mem::MaybeUninit;
+
+use arrow::array::FixedSizeBinaryArray;
+use arrow::datatypes::ArrowDataType;
+use polars_utils::slice::*;
+
+use super::numeric::decode_nulls;
+use crate::row::RowEncodingOptions;
+
+pub fn len_from_size(size: usize) -> usize {
+    1 + size
+}
+
+pub(crate) unsafe fn encode(
+    buffer: &mut [MaybeUninit<u8>],
+    arr: &FixedSizeBinaryArray,
+    opt: RowEncodingOptions,
+    offsets: &mut [usize],
+) {
+    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+    let null_sentinel = opt.null_sentinel();
+    let size = arr.size();
+
+    for (offset, opt_value) in offsets.iter_mut().zip(arr.iter()) {
+        let dst = buffer.get_unchecked_mut(*offset..*offset + len_from_size(size));
+        match opt_value {
+            Some(value) => {
+                dst[0] = MaybeUninit::new(1);
+                if descending {
+                    for (d, v) in dst[1..].iter_mut().zip(value) {
+                        *d = MaybeUninit::new(!*v);
+                    }
+                } else {
+                    dst[1..].copy_from_slice(value.as_uninit());
+                }
+            },
+            None => {
+                dst[0] = MaybeUninit::new(null_sentinel);
+                dst[1..].fill(MaybeUninit::new(0));
+            },
+        }
+        *offset += len_from_size(size);
+    }
+}
+
+pub(crate) unsafe fn decode(
+    rows: &mut [&[u8]],
+    opt: RowEncodingOptions,
+    dtype: &ArrowDataType,
+) -> FixedSizeBinaryArray {
+    let descending = opt.contains(RowEncodingOptions::DESCENDING);
+    let null_sentinel = opt.null_sentinel();
+    let size = FixedSizeBinaryArray::get_size(dtype);
+
+    let mut has_nulls = false;
+    let mut values = Vec::with_capacity(rows.len() * size);
+    for row in rows.iter() {
+        has_nulls |= *row.get_unchecked(0) == null_sentinel;
+        let value = row.get_unchecked(1..len_from_size(size));
+        if descending {
+            values.extend(value.iter().map(|v| !*v));
+        } else {
+            values.extend_from_slice(value);
+        }
+    }
+
+    let validity = has_nulls.then(|| decode_nulls(rows, null_sentinel));
+    for row in rows.iter_mut() {
+        *row = row.get_unchecked(len_from_size(size)..);
+    }
+    FixedSizeBinaryArray::new(dtype.clone(), values.into(), validity)
+}
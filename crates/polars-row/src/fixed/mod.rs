@@ -15,5 +15,6 @@ macro_rules! with_arms {
 
 pub mod boolean;
 pub mod decimal;
+pub mod fixed_size_binary;
 pub mod numeric;
 pub mod packed_u32;
@@ -131,7 +131,7 @@ impl SQLExprVisitor<'_> {
                 subquery,
                 negated,
             } => self.visit_in_subquery(expr, subquery, *negated),
-            SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval, true)?)),
+            SQLExpr::Interval(interval) => self.visit_interval(interval),
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
             },
@@ -785,6 +785,24 @@ impl SQLExprVisitor<'_> {
         })
     }
 
+    /// Visit a standalone SQL interval.
+    ///
+    /// e.g. INTERVAL '1 hour', INTERVAL '2 months 10 days'
+    ///
+    /// Fixed intervals become a Duration literal; intervals with years, quarters or
+    /// months have no fixed length and become an Interval literal instead.
+    fn visit_interval(&self, interval: &Interval) -> PolarsResult<Expr> {
+        #[cfg(feature = "dtype-interval")]
+        {
+            let duration = interval_to_duration(interval, false)?;
+            if duration.months() != 0 {
+                let value = AnyValue::Interval(duration.to_interval()?);
+                return Ok(lit(Scalar::new(DataType::Interval, value)));
+            }
+        }
+        Ok(lit(interval_to_duration(interval, true)?))
+    }
+
     /// Visit a SQL literal.
     ///
     /// e.g. 1, 'foo', 1.0, NULL
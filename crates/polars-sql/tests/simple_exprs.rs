@@ -219,6 +219,30 @@ fn test_literal_exprs() {
     assert!(df_sql.equals_missing(&df_pl));
 }
 
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn test_interval_literal_with_months() {
+    let df = create_sample_df();
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+    let sql = r#"
+        SELECT
+            interval '1 year 2 months 3 days 4 hours' as calendar_lit,
+            interval '3 days 4 hours' as duration_lit
+        FROM df
+        LIMIT 1"#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+
+    let calendar = df_sql.column("calendar_lit").unwrap();
+    assert_eq!(calendar.dtype(), &DataType::Interval);
+    assert_eq!(
+        calendar.get(0).unwrap(),
+        AnyValue::Interval(months_days_ns::new(14, 3, 4 * 3_600_000_000_000))
+    );
+    let duration = df_sql.column("duration_lit").unwrap();
+    assert!(matches!(duration.dtype(), DataType::Duration(_)));
+}
+
 #[test]
 fn test_implicit_date_string() {
     let df = df! {
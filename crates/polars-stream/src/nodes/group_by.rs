@@ -6,6 +6,8 @@ use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_expr::groups::Grouper;
 use polars_expr::hash_keys::HashKeys;
+#[cfg(feature = "dtype-categorical")]
+use polars_expr::hash_keys::remap_categorical_keys;
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
 use polars_utils::IdxSize;
@@ -102,6 +104,8 @@ impl LocalGroupBySinkState {
 }
 
 struct GroupBySinkState {
+    #[cfg_attr(not(feature = "dtype-categorical"), allow(dead_code))]
+    key_schema: Arc<Schema>,
     key_selectors: Vec<StreamExpr>,
     grouper: Box<dyn Grouper>,
     uniq_grouped_reduction_cols: Vec<PlSmallStr>,
@@ -122,6 +126,8 @@ impl GroupBySinkState {
     ) {
         for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
             let key_selectors = &self.key_selectors;
+            #[cfg(feature = "dtype-categorical")]
+            let key_schema = &self.key_schema;
             let uniq_grouped_reduction_cols = &self.uniq_grouped_reduction_cols;
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
@@ -140,6 +146,8 @@ impl GroupBySinkState {
                         key_columns.push(s.into_column());
                     }
                     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+                    #[cfg(feature = "dtype-categorical")]
+                    let keys = remap_categorical_keys(keys, key_schema)?;
                     let hash_keys = HashKeys::from_df(&keys, *random_state, true, false);
 
                     hot_idxs.clear();
@@ -491,6 +499,7 @@ impl GroupByNode {
         let partitioner = HashPartitioner::new(num_partitions, 0);
         Self {
             state: GroupByState::Sink(GroupBySinkState {
+                key_schema: key_schema.clone(),
                 key_selectors,
                 grouped_reductions,
                 grouper,
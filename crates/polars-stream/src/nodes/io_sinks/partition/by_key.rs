@@ -10,7 +10,10 @@ use polars_core::prelude::{Column, PlHashSet, PlIndexMap, row_encode};
 use polars_core::schema::SchemaRef;
 use polars_core::utils::arrow::buffer::Buffer;
 use polars_error::PolarsResult;
-use polars_plan::dsl::{PartitionTargetCallback, SinkFinishCallback, SinkOptions};
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{
+    PartitionPathOptions, PartitionTargetCallback, SinkFinishCallback, SinkOptions,
+};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::priority::Priority;
 
@@ -19,7 +22,7 @@ use crate::async_executor::{AbortOnDropHandle, spawn};
 use crate::execute::StreamingExecutionState;
 use crate::morsel::SourceToken;
 use crate::nodes::io_sinks::metrics::WriteMetrics;
-use crate::nodes::io_sinks::partition::{SinkSender, open_new_sink};
+use crate::nodes::io_sinks::partition::{PartitionPathState, SinkSender, open_new_sink};
 use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::io_sinks::{SinkInputPort, SinkNode, parallelize_receive_task};
 use crate::nodes::{JoinHandle, Morsel, MorselSeq, TaskPriority};
@@ -39,6 +42,7 @@ pub struct PartitionByKeySinkNode {
 
     base_path: Arc<PathBuf>,
     file_path_cb: Option<PartitionTargetCallback>,
+    path_state: PartitionPathState,
     create_new: CreateNewSinkFn,
     ext: PlSmallStr,
 
@@ -56,6 +60,8 @@ impl PartitionByKeySinkNode {
         key_cols: Arc<[PlSmallStr]>,
         base_path: Arc<PathBuf>,
         file_path_cb: Option<PartitionTargetCallback>,
+        path_options: PartitionPathOptions,
+        cloud_options: Option<CloudOptions>,
         create_new: CreateNewSinkFn,
         ext: PlSmallStr,
         sink_options: SinkOptions,
@@ -87,6 +93,9 @@ impl PartitionByKeySinkNode {
                     .expect("unable to parse POLARS_MAX_OPEN_PARTITIONS")
             });
 
+        let path_state =
+            PartitionPathState::new(path_options, base_path.as_path(), cloud_options.as_ref());
+
         Self {
             input_schema,
             sink_input_schema,
@@ -95,6 +104,7 @@ impl PartitionByKeySinkNode {
             include_key,
             base_path,
             file_path_cb,
+            path_state,
             create_new,
             ext,
             sink_options,
@@ -119,6 +129,8 @@ impl SinkNode for PartitionByKeySinkNode {
     }
 
     fn finish(&self) -> PolarsResult<()> {
+        self.path_state.finish("by-key")?;
+
         if let Some(finish_callback) = &self.finish_callback {
             let df = self.written_partitions.get().unwrap();
             finish_callback.call(df.clone())?;
@@ -208,6 +220,7 @@ impl SinkNode for PartitionByKeySinkNode {
         let max_open_partitions = self.max_open_partitions;
         let base_path = self.base_path.clone();
         let file_path_cb = self.file_path_cb.clone();
+        let path_state = self.path_state.clone();
         let create_new_sink = self.create_new.clone();
         let ext = self.ext.clone();
         let per_partition_sort_by = self.per_partition_sort_by.clone();
@@ -270,6 +283,7 @@ impl SinkNode for PartitionByKeySinkNode {
                                         verbose,
                                         &state,
                                         per_partition_sort_by.as_ref(),
+                                        &path_state,
                                     ).await?;
                                     file_idx += 1;
 
@@ -327,6 +341,7 @@ impl SinkNode for PartitionByKeySinkNode {
                             verbose,
                             &state,
                             per_partition_sort_by.as_ref(),
+                            &path_state,
                         ).await?;
                         file_idx += 1;
                         let Some((join_handles, mut sender, node)) = result else {
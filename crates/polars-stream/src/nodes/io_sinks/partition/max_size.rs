@@ -8,7 +8,10 @@ use polars_core::config;
 use polars_core::prelude::Column;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
-use polars_plan::dsl::{PartitionTargetCallback, SinkFinishCallback, SinkOptions};
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{
+    PartitionPathOptions, PartitionTargetCallback, SinkFinishCallback, SinkOptions,
+};
 use polars_utils::IdxSize;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -18,7 +21,7 @@ use crate::async_primitives::connector::Receiver;
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::execute::StreamingExecutionState;
 use crate::nodes::io_sinks::metrics::WriteMetrics;
-use crate::nodes::io_sinks::partition::{SinkSender, open_new_sink};
+use crate::nodes::io_sinks::partition::{PartitionPathState, SinkSender, open_new_sink};
 use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::io_sinks::{SinkInputPort, SinkNode};
 use crate::nodes::{JoinHandle, Morsel, TaskPriority};
@@ -29,6 +32,7 @@ pub struct MaxSizePartitionSinkNode {
 
     base_path: Arc<PathBuf>,
     file_path_cb: Option<PartitionTargetCallback>,
+    path_state: PartitionPathState,
     create_new: CreateNewSinkFn,
     ext: PlSmallStr,
 
@@ -56,6 +60,8 @@ impl MaxSizePartitionSinkNode {
         max_size: IdxSize,
         base_path: Arc<PathBuf>,
         file_path_cb: Option<PartitionTargetCallback>,
+        path_options: PartitionPathOptions,
+        cloud_options: Option<CloudOptions>,
         create_new: CreateNewSinkFn,
         ext: PlSmallStr,
         sink_options: SinkOptions,
@@ -71,11 +77,15 @@ impl MaxSizePartitionSinkNode {
                     .max(1)
             });
 
+        let path_state =
+            PartitionPathState::new(path_options, base_path.as_path(), cloud_options.as_ref());
+
         Self {
             input_schema,
             max_size,
             base_path,
             file_path_cb,
+            path_state,
             create_new,
             ext,
             sink_options,
@@ -129,6 +139,7 @@ impl SinkNode for MaxSizePartitionSinkNode {
         let max_size = self.max_size;
         let base_path = self.base_path.clone();
         let file_path_cb = self.file_path_cb.clone();
+        let path_state = self.path_state.clone();
         let create_new = self.create_new.clone();
         let ext = self.ext.clone();
         let per_partition_sort_by = self.per_partition_sort_by.clone();
@@ -171,6 +182,7 @@ impl SinkNode for MaxSizePartitionSinkNode {
                                     verbose,
                                     &state,
                                     per_partition_sort_by.as_ref(),
+                                    &path_state,
                                 )
                                 .await?;
                                 file_idx += 1;
@@ -288,6 +300,8 @@ impl SinkNode for MaxSizePartitionSinkNode {
     }
 
     fn finish(&self) -> PolarsResult<()> {
+        self.path_state.finish("max-size")?;
+
         if let Some(finish_callback) = &self.finish_callback {
             let mut partition_metrics = self.partition_metrics.lock().unwrap();
             let partition_metrics =
@@ -3,13 +3,18 @@ use std::sync::Arc;
 
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
-use polars_core::prelude::{Column, DataType, SortMultipleOptions};
+use polars_core::config;
+use polars_core::prelude::{Column, SortMultipleOptions};
 use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
+use polars_io::utils::partition_path::{
+    PartitionPathIndices, PartitionStaging, default_partition_dir,
+};
 use polars_plan::dsl::{
-    FileType, PartitionTargetCallback, PartitionTargetContext, SinkOptions, SinkTarget,
+    FileType, PartitionOverwriteMode, PartitionPathOptions, PartitionTargetCallback,
+    PartitionTargetContext, SinkOptions, SinkTarget,
 };
 use polars_utils::format_pl_smallstr;
 
@@ -128,23 +133,63 @@ fn default_by_key_file_path_cb(
     let columns = columns.unwrap();
     assert!(!columns.is_empty());
 
-    let mut file_path = PathBuf::new();
-    for c in columns {
-        let name = c.name();
-        let value = c.head(Some(1)).strict_cast(&DataType::String)?;
-        let value = value.str().unwrap();
-        let value = value
-            .get(0)
-            .unwrap_or("__HIVE_DEFAULT_PARTITION__")
-            .as_bytes();
-        let value = percent_encoding::percent_encode(value, polars_io::utils::URL_ENCODE_CHAR_SET);
-        file_path = file_path.join(format!("{name}={value}"));
-    }
-    file_path = file_path.join(format!("{in_part_idx}.{ext}"));
+    let mut file_path = default_partition_dir(columns)?;
+    file_path.push(format!("{in_part_idx}.{ext}"));
 
     Ok(file_path)
 }
 
+/// State of the output paths of a partitioned sink.
+#[derive(Clone)]
+pub struct PartitionPathState {
+    options: PartitionPathOptions,
+    /// Staging area of a dynamic partition overwrite.
+    staging: Option<Arc<PartitionStaging>>,
+}
+
+impl PartitionPathState {
+    pub fn new(
+        options: PartitionPathOptions,
+        base_path: &Path,
+        cloud_options: Option<&CloudOptions>,
+    ) -> Self {
+        let staging = (options.overwrite_mode == PartitionOverwriteMode::Dynamic)
+            .then(|| Arc::new(PartitionStaging::new(base_path, cloud_options)));
+        Self { options, staging }
+    }
+
+    /// Returns the full path of the file at `file_path` relative to the base path. Files of a
+    /// dynamic partition overwrite are written to the staging area.
+    fn full_path(
+        &self,
+        base_path: &Path,
+        file_path: &Path,
+        keys: Option<&[Column]>,
+    ) -> PolarsResult<PathBuf> {
+        match &self.staging {
+            Some(staging) => {
+                let partition_dir = self.options.partition_dir(keys.unwrap())?;
+                Ok(staging.stage(partition_dir, file_path))
+            },
+            None => Ok(base_path.join(file_path)),
+        }
+    }
+
+    /// Replaces the existing partitions with the ones that were written. Must only be called
+    /// once all sinks have finished successfully.
+    pub fn finish(&self, partition_name: &'static str) -> PolarsResult<()> {
+        if let Some(staging) = &self.staging {
+            if config::verbose() {
+                eprintln!("[partition[{partition_name}]]: Replacing overwritten partitions");
+            }
+
+            polars_io::pl_async::get_runtime().block_in_place_on(staging.commit())?;
+        }
+
+        Ok(())
+    }
+}
+
 type FilePathCallback = fn(&str, usize, usize, usize, Option<&[Column]>) -> PolarsResult<PathBuf>;
 
 #[allow(clippy::too_many_arguments)]
@@ -163,6 +208,7 @@ async fn open_new_sink(
     verbose: bool,
     state: &StreamingExecutionState,
     per_partition_sort_by: Option<&PerPartitionSortBy>,
+    path_state: &PartitionPathState,
 ) -> PolarsResult<
     Option<(
         FuturesUnordered<AbortOnDropHandle<PolarsResult<()>>>,
@@ -170,8 +216,18 @@ async fn open_new_sink(
         Box<dyn SinkNode + Send + Sync>,
     )>,
 > {
-    let file_path = default_file_path_cb(ext, file_idx, part_idx, in_part_idx, keys)?;
-    let path = base_path.join(file_path.as_path());
+    let file_path = match &path_state.options.template {
+        Some(template) => {
+            let indices = PartitionPathIndices {
+                file_idx,
+                part_idx,
+                in_part_idx,
+            };
+            template.render(keys.unwrap_or_default(), indices, ext)?
+        },
+        None => default_file_path_cb(ext, file_idx, part_idx, in_part_idx, keys)?,
+    };
+    let path = path_state.full_path(base_path, &file_path, keys)?;
 
     // If the user provided their own callback, modify the path to that.
     let target = if let Some(file_path_cb) = file_path_cb {
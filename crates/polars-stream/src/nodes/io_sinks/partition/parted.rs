@@ -9,7 +9,10 @@ use polars_core::prelude::row_encode::_get_rows_encoded_ca_unordered;
 use polars_core::prelude::{AnyValue, Column, IntoColumn, PlHashSet};
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
-use polars_plan::dsl::{PartitionTargetCallback, SinkFinishCallback, SinkOptions};
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{
+    PartitionPathOptions, PartitionTargetCallback, SinkFinishCallback, SinkOptions,
+};
 use polars_utils::pl_str::PlSmallStr;
 
 use super::{CreateNewSinkFn, PerPartitionSortBy};
@@ -18,7 +21,7 @@ use crate::async_primitives::connector::Receiver;
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::execute::StreamingExecutionState;
 use crate::nodes::io_sinks::metrics::WriteMetrics;
-use crate::nodes::io_sinks::partition::{SinkSender, open_new_sink};
+use crate::nodes::io_sinks::partition::{PartitionPathState, SinkSender, open_new_sink};
 use crate::nodes::io_sinks::phase::PhaseOutcome;
 use crate::nodes::io_sinks::{SinkInputPort, SinkNode};
 use crate::nodes::{JoinHandle, Morsel, TaskPriority};
@@ -32,6 +35,7 @@ pub struct PartedPartitionSinkNode {
     key_cols: Arc<[PlSmallStr]>,
     base_path: Arc<PathBuf>,
     file_path_cb: Option<PartitionTargetCallback>,
+    path_state: PartitionPathState,
     create_new: CreateNewSinkFn,
     ext: PlSmallStr,
 
@@ -60,6 +64,8 @@ impl PartedPartitionSinkNode {
         key_cols: Arc<[PlSmallStr]>,
         base_path: Arc<PathBuf>,
         file_path_cb: Option<PartitionTargetCallback>,
+        path_options: PartitionPathOptions,
+        cloud_options: Option<CloudOptions>,
         create_new: CreateNewSinkFn,
         ext: PlSmallStr,
         sink_options: SinkOptions,
@@ -91,12 +97,16 @@ impl PartedPartitionSinkNode {
                     .max(1)
             });
 
+        let path_state =
+            PartitionPathState::new(path_options, base_path.as_path(), cloud_options.as_ref());
+
         Self {
             input_schema,
             sink_input_schema,
             key_cols,
             base_path,
             file_path_cb,
+            path_state,
             create_new,
             ext,
             sink_options,
@@ -141,6 +151,7 @@ impl SinkNode for PartedPartitionSinkNode {
         let key_cols = self.key_cols.clone();
         let base_path = self.base_path.clone();
         let file_path_cb = self.file_path_cb.clone();
+        let path_state = self.path_state.clone();
         let create_new = self.create_new.clone();
         let ext = self.ext.clone();
         let include_key = self.include_key;
@@ -230,6 +241,7 @@ impl SinkNode for PartedPartitionSinkNode {
                                     verbose,
                                     &state,
                                     per_partition_sort_by.as_ref(),
+                                    &path_state,
                                 )
                                 .await?;
                                 file_idx += 1;
@@ -325,6 +337,8 @@ impl SinkNode for PartedPartitionSinkNode {
     }
 
     fn finish(&self) -> PolarsResult<()> {
+        self.path_state.finish("parted")?;
+
         if let Some(finish_callback) = &self.finish_callback {
             let mut written_partitions = self.partition_metrics.lock().unwrap();
             let written_partitions =
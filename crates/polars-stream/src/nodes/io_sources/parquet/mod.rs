@@ -6,6 +6,7 @@ use polars_core::prelude::ArrowSchema;
 use polars_core::schema::{Schema, SchemaExt, SchemaRef};
 use polars_error::{PolarsResult, polars_err};
 use polars_io::cloud::CloudOptions;
+use polars_io::parquet::metadata::FILE_METADATA_CACHE;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, ParquetOptions};
 use polars_io::utils::byte_source::{DynByteSource, DynByteSourceBuilder, MemSliceByteSource};
@@ -83,25 +84,68 @@ impl FileReader for ParquetFileReader {
         let file_metadata = if let Some(v) = self.metadata.clone() {
             v
         } else {
-            let (metadata_bytes, opt_full_bytes) = {
+            // Remote files go through the process-wide metadata cache, keyed by URI and validated
+            // against the remote version.
+            let (cache_key, opt_cached_metadata) = {
                 let byte_source = byte_source.clone();
 
                 pl_async::get_runtime()
                     .spawn(async move {
-                        metadata_utils::read_parquet_metadata_bytes(&byte_source, verbose).await
+                        let DynByteSource::Cloud(v) = byte_source.as_ref() else {
+                            return PolarsResult::Ok((None, None));
+                        };
+
+                        if !FILE_METADATA_CACHE.is_enabled() {
+                            return Ok((None, None));
+                        }
+
+                        let version = v.get_version().await?;
+                        let opt_cached_metadata = FILE_METADATA_CACHE.get(v.uri(), &version);
+
+                        Ok((Some((v.uri().clone(), version)), opt_cached_metadata))
                     })
                     .await
                     .unwrap()?
             };
 
-            if let Some(full_bytes) = opt_full_bytes {
-                byte_source = Arc::new(DynByteSource::MemSlice(MemSliceByteSource(full_bytes)));
+            if let Some(metadata) = opt_cached_metadata {
+                if verbose {
+                    eprintln!("[ParquetFileReader]: Using cached metadata");
+                }
+
+                metadata
+            } else {
+                let (metadata_bytes, opt_full_bytes) = {
+                    let byte_source = byte_source.clone();
+
+                    pl_async::get_runtime()
+                        .spawn(async move {
+                            metadata_utils::read_parquet_metadata_bytes(&byte_source, verbose).await
+                        })
+                        .await
+                        .unwrap()?
+                };
+
+                if let Some(full_bytes) = opt_full_bytes {
+                    byte_source = Arc::new(DynByteSource::MemSlice(MemSliceByteSource(full_bytes)));
+                }
+
+                let metadata = Arc::new(polars_parquet::parquet::read::deserialize_metadata(
+                    metadata_bytes.as_ref(),
+                    metadata_bytes.len() * 2 + 1024,
+                )?);
+
+                if let Some((uri, version)) = cache_key {
+                    FILE_METADATA_CACHE.insert(
+                        uri,
+                        version,
+                        metadata.clone(),
+                        metadata_bytes.len(),
+                    );
+                }
+
+                metadata
             }
-
-            Arc::new(polars_parquet::parquet::read::deserialize_metadata(
-                metadata_bytes.as_ref(),
-                metadata_bytes.len() * 2 + 1024,
-            )?)
         };
 
         let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
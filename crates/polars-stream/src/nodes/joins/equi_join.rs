@@ -9,6 +9,8 @@ use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
 use polars_core::{POOL, config};
 use polars_expr::hash_keys::HashKeys;
+#[cfg(feature = "dtype-categorical")]
+use polars_expr::hash_keys::remap_categorical_keys;
 use polars_expr::idx_table::{IdxTable, new_idx_table};
 use polars_io::pl_async::get_runtime;
 use polars_ops::frame::{JoinArgs, JoinType, MaintainOrderJoin};
@@ -40,6 +42,9 @@ struct EquiJoinParams {
     #[allow(dead_code)]
     right_key_schema: Arc<Schema>,
     right_key_selectors: Vec<StreamExpr>,
+    /// The key schema both sides are hashed with, which holds the merged categorical mappings.
+    #[cfg_attr(not(feature = "dtype-categorical"), allow(dead_code))]
+    unique_key_schema: Arc<Schema>,
     left_payload_select: Vec<Option<PlSmallStr>>,
     right_payload_select: Vec<Option<PlSmallStr>>,
     left_payload_schema: Arc<Schema>,
@@ -160,6 +165,8 @@ async fn select_keys(
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+    #[cfg(feature = "dtype-categorical")]
+    let keys = remap_categorical_keys(keys, &params.unique_key_schema)?;
     Ok(HashKeys::from_df(
         &keys,
         params.random_state,
@@ -1215,6 +1222,7 @@ impl EquiJoinNode {
                 left_key_selectors,
                 right_key_schema,
                 right_key_selectors,
+                unique_key_schema: unique_key_schema.clone(),
                 left_payload_select,
                 right_payload_select,
                 left_payload_schema,
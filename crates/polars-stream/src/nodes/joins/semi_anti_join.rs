@@ -6,6 +6,8 @@ use polars_core::prelude::*;
 use polars_core::schema::Schema;
 use polars_expr::groups::{Grouper, new_hash_grouper};
 use polars_expr::hash_keys::HashKeys;
+#[cfg(feature = "dtype-categorical")]
+use polars_expr::hash_keys::remap_categorical_keys;
 use polars_ops::frame::{JoinArgs, JoinType};
 use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
@@ -29,6 +31,8 @@ async fn select_keys(
         key_columns.push(selector.evaluate(df, state).await?.into_column());
     }
     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+    #[cfg(feature = "dtype-categorical")]
+    let keys = remap_categorical_keys(keys, &params.unique_key_schema)?;
     Ok(HashKeys::from_df(
         &keys,
         params.random_state,
@@ -41,6 +45,9 @@ struct SemiAntiJoinParams {
     left_is_build: bool,
     left_key_selectors: Vec<StreamExpr>,
     right_key_selectors: Vec<StreamExpr>,
+    /// The key schema both sides are hashed with, which holds the merged categorical mappings.
+    #[cfg_attr(not(feature = "dtype-categorical"), allow(dead_code))]
+    unique_key_schema: Arc<Schema>,
     nulls_equal: bool,
     is_anti: bool,
     return_bool: bool,
@@ -73,6 +80,7 @@ impl SemiAntiJoinNode {
                 left_is_build,
                 left_key_selectors,
                 right_key_selectors,
+                unique_key_schema: unique_key_schema.clone(),
                 random_state: PlRandomState::default(),
                 nulls_equal: args.nulls_equal,
                 return_bool,
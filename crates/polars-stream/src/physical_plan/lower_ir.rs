@@ -3,6 +3,8 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use polars_core::config;
 use polars_core::frame::{DataFrame, UniqueKeepStrategy};
+#[cfg(feature = "dtype-categorical")]
+use polars_core::prelude::merge_dtypes;
 use polars_core::prelude::{DataType, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap};
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_bail};
@@ -280,6 +282,7 @@ pub fn lower_ir(
                 cloud_options,
                 per_partition_sort_by,
                 finish_callback,
+                path_options,
             }) => {
                 let base_path = base_path.clone();
                 let file_path_cb = file_path_cb.clone();
@@ -289,6 +292,7 @@ pub fn lower_ir(
                 let cloud_options = cloud_options.clone();
                 let per_partition_sort_by = per_partition_sort_by.clone();
                 let finish_callback = finish_callback.clone();
+                let path_options = path_options.clone();
 
                 let mut input = lower_ir!(*input)?;
                 match &variant {
@@ -339,6 +343,7 @@ pub fn lower_ir(
                     cloud_options,
                     per_partition_sort_by,
                     finish_callback,
+                    path_options,
                 }
             },
         },
@@ -442,7 +447,30 @@ pub fn lower_ir(
                 .clone() // Needed to borrow ir_arena mutably.
                 .into_iter()
                 .map(|input| lower_ir!(input))
-                .collect::<Result<_, _>>()?;
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // The inputs may carry different categorical mappings, downstream nodes that hash
+            // the categoricals need the merged mappings of all of them.
+            #[cfg(feature = "dtype-categorical")]
+            let output_schema = if output_schema
+                .iter_values()
+                .any(|dtype| dtype.contains_categoricals())
+            {
+                let mut schema = (*output_schema).clone();
+                for input in &inputs {
+                    let input_schema = &phys_sm[input.node].output_schema;
+                    for (dtype, input_dtype) in
+                        schema.iter_values_mut().zip(input_schema.iter_values())
+                    {
+                        if dtype.contains_categoricals() {
+                            *dtype = merge_dtypes(dtype, input_dtype)?;
+                        }
+                    }
+                }
+                Arc::new(schema)
+            } else {
+                output_schema
+            };
 
             let node = phys_sm.insert(PhysNode {
                 output_schema,
@@ -4,6 +4,8 @@ use std::sync::atomic::AtomicUsize;
 
 use parking_lot::Mutex;
 use polars_core::prelude::PlRandomState;
+#[cfg(feature = "dtype-categorical")]
+use polars_core::prelude::{Field, merge_dtypes};
 use polars_core::schema::Schema;
 use polars_core::{POOL, config};
 use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
@@ -318,6 +320,7 @@ fn to_graph_rec<'a>(
             cloud_options,
             per_partition_sort_by,
             finish_callback,
+            path_options,
         } => {
             let input_schema = ctx.phys_sm[input.node].output_schema.clone();
             let input_key = to_graph_rec(input.node, ctx)?;
@@ -362,6 +365,8 @@ fn to_graph_rec<'a>(
                         *max_size,
                         base_path,
                         file_path_cb,
+                        path_options.clone(),
+                        cloud_options.clone(),
                         create_new,
                         ext,
                         sink_options.clone(),
@@ -378,6 +383,8 @@ fn to_graph_rec<'a>(
                         key_exprs.iter().map(|e| e.output_name().clone()).collect(),
                         base_path,
                         file_path_cb,
+                        path_options.clone(),
+                        cloud_options.clone(),
                         create_new,
                         ext,
                         sink_options.clone(),
@@ -395,6 +402,8 @@ fn to_graph_rec<'a>(
                         key_exprs.iter().map(|e| e.output_name().clone()).collect(),
                         base_path,
                         file_path_cb,
+                        path_options.clone(),
+                        cloud_options.clone(),
                         create_new,
                         ext,
                         sink_options.clone(),
@@ -750,6 +759,25 @@ fn to_graph_rec<'a>(
             let unique_key_schema =
                 compute_output_schema(&right_input_schema, &unique_left_on, ctx.expr_arena)?;
 
+            // Both sides hash their categorical keys with the merged mapping of the two sides.
+            #[cfg(feature = "dtype-categorical")]
+            let unique_key_schema = Arc::new(
+                unique_key_schema
+                    .iter()
+                    .zip(left_on.iter().zip(right_on.iter()))
+                    .map(|((name, dtype), (l, r))| {
+                        let dtype = if dtype.contains_categoricals() {
+                            let l_dtype = left_key_schema.get(l.output_name()).unwrap();
+                            let r_dtype = right_key_schema.get(r.output_name()).unwrap();
+                            merge_dtypes(l_dtype, r_dtype)?
+                        } else {
+                            dtype.clone()
+                        };
+                        Ok(Field::new(name.clone(), dtype))
+                    })
+                    .collect::<PolarsResult<Schema>>()?,
+            );
+
             match node.kind {
                 #[cfg(feature = "semi_anti_join")]
                 SemiAntiJoin { output_bool, .. } => ctx.graph.add_node(
@@ -153,6 +153,64 @@ where
     Series::try_from((ca.name().clone(), out))
 }
 
+/// Cast to the physical numeric type the window kernels operate on.
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn to_rolling_physical(s: &Series, op: &str) -> PolarsResult<Series> {
+    let s = match s.dtype() {
+        // Our rolling kernels don't yet support boolean, use UInt8 as a workaround for now.
+        DataType::Boolean => s.cast(&DataType::UInt8)?,
+        dt if dt.is_temporal() => s.to_physical_repr().into_owned(),
+        _ => s.clone(),
+    };
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() && !s.dtype().is_unknown(),
+        op = op,
+        s.dtype()
+    );
+    Ok(s)
+}
+
+/// Cast to the type `rolling_product` is computed in, which matches `product`.
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn to_rolling_product_dtype(s: &Series, op: &str) -> PolarsResult<Series> {
+    let s = match s.dtype() {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::UInt8
+        | DataType::Int16
+        | DataType::UInt16
+        | DataType::Int32
+        | DataType::UInt32 => s.cast(&DataType::Int64)?,
+        _ => s.clone(),
+    };
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() && !s.dtype().is_unknown(),
+        op = op,
+        s.dtype()
+    );
+    Ok(s)
+}
+
+#[cfg(feature = "rolling_window")]
+fn ensure_no_weights(options: &RollingOptionsFixedWindow, op: &str) -> PolarsResult<()> {
+    polars_ensure!(
+        options.weights.is_none(),
+        InvalidOperation: "weights are not supported for `{}`", op
+    );
+    Ok(())
+}
+
+#[cfg(any(feature = "rolling_window", feature = "rolling_window_by"))]
+fn rolling_rank_dtype(fn_params: Option<RollingFnParams>) -> DataType {
+    match fn_params {
+        Some(RollingFnParams::Rank {
+            method: RollingRankMethod::Average,
+        })
+        | None => DataType::Float64,
+        _ => IDX_DTYPE,
+    }
+}
+
 pub trait SeriesOpsTime: AsSeries {
     /// Apply a rolling mean to a Series based on another Series.
     #[cfg(feature = "rolling_window_by")]
@@ -532,6 +590,322 @@ pub trait SeriesOpsTime: AsSeries {
             s
         })
     }
+
+    /// Compute the number of unique non-null values in the window, based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_n_unique_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_rolling_physical(self.as_series(), "rolling_n_unique_by")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_n_unique,
+            )
+        })
+    }
+
+    /// Compute the number of unique non-null values in the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_n_unique(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        ensure_no_weights(&options, "rolling_n_unique")?;
+        let s = to_rolling_physical(self.as_series(), "rolling_n_unique")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_n_unique,
+                &rolling::nulls::rolling_n_unique,
+            )
+        })
+    }
+
+    /// Compute the most frequent non-null value in the window, the smallest one on ties, based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_mode_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_rolling_physical(self.as_series(), "rolling_mode_by")?;
+        let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_mode,
+            )
+        });
+        out?.cast(self.as_series().dtype())
+    }
+
+    /// Compute the most frequent non-null value in the window, the smallest one on ties.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_mode(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        ensure_no_weights(&options, "rolling_mode")?;
+        let s = to_rolling_physical(self.as_series(), "rolling_mode")?;
+        let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_mode,
+                &rolling::nulls::rolling_mode,
+            )
+        });
+        out?.cast(self.as_series().dtype())
+    }
+
+    /// Compute the rank of every value within its window, based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_rank_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_rolling_physical(self.as_series(), "rolling_rank_by")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(
+                s.name().clone(),
+                &rolling_rank_dtype(options.fn_params),
+            ));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_rank,
+            )
+        })
+    }
+
+    /// Compute the rank of every value within its window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_rank(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        ensure_no_weights(&options, "rolling_rank")?;
+        let s = to_rolling_physical(self.as_series(), "rolling_rank")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(
+                s.name().clone(),
+                &rolling_rank_dtype(options.fn_params),
+            ));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_rank,
+                &rolling::nulls::rolling_rank,
+            )
+        })
+    }
+
+    /// Compute the position of the minimum within the window, based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_arg_min_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_rolling_physical(self.as_series(), "rolling_arg_min_by")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_arg_min,
+            )
+        })
+    }
+
+    /// Compute the position of the minimum within the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_arg_min(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        ensure_no_weights(&options, "rolling_arg_min")?;
+        let s = to_rolling_physical(self.as_series(), "rolling_arg_min")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_arg_min,
+                &rolling::nulls::rolling_arg_min,
+            )
+        })
+    }
+
+    /// Compute the position of the maximum within the window, based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_arg_max_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_rolling_physical(self.as_series(), "rolling_arg_max_by")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_arg_max,
+            )
+        })
+    }
+
+    /// Compute the position of the maximum within the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_arg_max(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        ensure_no_weights(&options, "rolling_arg_max")?;
+        let s = to_rolling_physical(self.as_series(), "rolling_arg_max")?;
+        if s.is_empty() {
+            return Ok(Series::new_empty(s.name().clone(), &IDX_DTYPE));
+        }
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_arg_max,
+                &rolling::nulls::rolling_arg_max,
+            )
+        })
+    }
+
+    /// Compute the first non-null value in the window, based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_first_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_rolling_physical(self.as_series(), "rolling_first_by")?;
+        let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_first,
+            )
+        });
+        out?.cast(self.as_series().dtype())
+    }
+
+    /// Compute the first non-null value in the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_first(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        ensure_no_weights(&options, "rolling_first")?;
+        let s = to_rolling_physical(self.as_series(), "rolling_first")?;
+        let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_first,
+                &rolling::nulls::rolling_first,
+            )
+        });
+        out?.cast(self.as_series().dtype())
+    }
+
+    /// Compute the last non-null value in the window, based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_last_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_rolling_physical(self.as_series(), "rolling_last_by")?;
+        let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_last,
+            )
+        });
+        out?.cast(self.as_series().dtype())
+    }
+
+    /// Compute the last non-null value in the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_last(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        ensure_no_weights(&options, "rolling_last")?;
+        let s = to_rolling_physical(self.as_series(), "rolling_last")?;
+        let out = with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_last,
+                &rolling::nulls::rolling_last,
+            )
+        });
+        out?.cast(self.as_series().dtype())
+    }
+
+    /// Compute the product of the window, based on another Series.
+    #[cfg(feature = "rolling_window_by")]
+    fn rolling_product_by(
+        &self,
+        by: &Series,
+        options: RollingOptionsDynamicWindow,
+    ) -> PolarsResult<Series> {
+        let s = to_rolling_product_dtype(self.as_series(), "rolling_product_by")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg_by(
+                ca,
+                by,
+                options,
+                &super::rolling_kernels::no_nulls::rolling_product,
+            )
+        })
+    }
+
+    /// Compute the product of the window.
+    #[cfg(feature = "rolling_window")]
+    fn rolling_product(&self, options: RollingOptionsFixedWindow) -> PolarsResult<Series> {
+        ensure_no_weights(&options, "rolling_product")?;
+        let s = to_rolling_product_dtype(self.as_series(), "rolling_product")?;
+        with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+            rolling_agg(
+                ca,
+                options,
+                &rolling::no_nulls::rolling_product,
+                &rolling::nulls::rolling_product,
+            )
+        })
+    }
 }
 
 impl SeriesOpsTime for Series {}
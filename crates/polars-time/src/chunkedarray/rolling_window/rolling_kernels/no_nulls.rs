@@ -4,17 +4,18 @@ use arrow::bitmap::MutableBitmap;
 use bytemuck::allocation::zeroed_vec;
 #[cfg(feature = "timezones")]
 use chrono_tz::Tz;
-use num_traits::{FromPrimitive, ToPrimitive};
-use polars_compute::rolling::RollingFnParams;
+use num_traits::{FromPrimitive, One, ToPrimitive};
 use polars_compute::rolling::no_nulls::{self, RollingAggWindowNoNulls};
 use polars_compute::rolling::nulls::VarianceMoment;
 use polars_compute::rolling::quantile_filter::SealedRolling;
+use polars_compute::rolling::{RollingFnParams, RollingRankMethod, rank};
+use polars_utils::total_ord::{TotalHash, TotalOrd};
 
 use super::*;
 
 // Use an aggregation window that maintains the state.
 // Fastpath if values were known to already be sorted by time.
-pub(crate) fn rolling_apply_agg_window_sorted<'a, Agg, T, O>(
+pub(crate) fn rolling_apply_agg_window_sorted<'a, Agg, T, Out, O>(
     values: &'a [T],
     offsets: O,
     min_periods: usize,
@@ -22,14 +23,15 @@ pub(crate) fn rolling_apply_agg_window_sorted<'a, Agg, T, O>(
 ) -> PolarsResult<ArrayRef>
 where
     // items (offset, len) -> so offsets are offset, offset + len
-    Agg: RollingAggWindowNoNulls<'a, T>,
+    Agg: RollingAggWindowNoNulls<'a, T, Out>,
     O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
     T: Debug + IsFloat + NativeType,
+    Out: NativeType,
 {
     if values.is_empty() {
-        let out: Vec<T> = vec![];
+        let out: Vec<Out> = vec![];
         return Ok(Box::new(PrimitiveArray::new(
-            T::PRIMITIVE.into(),
+            Out::PRIMITIVE.into(),
             out.into(),
             None,
         )));
@@ -54,7 +56,7 @@ where
                 }
             })
         })
-        .collect::<PolarsResult<PrimitiveArray<T>>>()?;
+        .collect::<PolarsResult<PrimitiveArray<Out>>>()?;
 
     Ok(Box::new(out))
 }
@@ -75,7 +77,7 @@ fn instantiate_bitmap_if_null_and_set_false_at_idx(
 }
 
 // Use an aggregation window that maintains the state
-pub(crate) fn rolling_apply_agg_window<'a, Agg, T, O>(
+pub(crate) fn rolling_apply_agg_window<'a, Agg, T, Out, O>(
     values: &'a [T],
     offsets: O,
     min_periods: usize,
@@ -84,14 +86,15 @@ pub(crate) fn rolling_apply_agg_window<'a, Agg, T, O>(
 ) -> PolarsResult<ArrayRef>
 where
     // items (offset, len) -> so offsets are offset, offset + len
-    Agg: RollingAggWindowNoNulls<'a, T>,
+    Agg: RollingAggWindowNoNulls<'a, T, Out>,
     O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
     T: Debug + IsFloat + NativeType,
+    Out: NativeType,
 {
     if values.is_empty() {
-        let out: Vec<T> = vec![];
+        let out: Vec<Out> = vec![];
         return Ok(Box::new(PrimitiveArray::new(
-            T::PRIMITIVE.into(),
+            Out::PRIMITIVE.into(),
             out.into(),
             None,
         )));
@@ -136,7 +139,7 @@ where
         Ok::<(), PolarsError>(())
     })?;
 
-    let out = PrimitiveArray::<T>::from_vec(out).with_validity(validity.map(|x| x.into()));
+    let out = PrimitiveArray::<Out>::from_vec(out).with_validity(validity.map(|x| x.into()));
 
     Ok(Box::new(out))
 }
@@ -162,14 +165,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::MinWindow<_>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::MinWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             None,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::MinWindow<_>, _, _>(
+        rolling_apply_agg_window::<no_nulls::MinWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -200,14 +203,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::MaxWindow<_>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::MaxWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             None,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::MaxWindow<_>, _, _>(
+        rolling_apply_agg_window::<no_nulls::MaxWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -246,14 +249,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::SumWindow<T, T>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::SumWindow<T, T>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             None,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::SumWindow<T, T>, _, _>(
+        rolling_apply_agg_window::<no_nulls::SumWindow<T, T>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -284,14 +287,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::MeanWindow<_>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::MeanWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             None,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::MeanWindow<_>, _, _>(
+        rolling_apply_agg_window::<no_nulls::MeanWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -322,14 +325,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::MomentWindow<_, VarianceMoment>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::MomentWindow<_, VarianceMoment>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             params,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::MomentWindow<_, VarianceMoment>, _, _>(
+        rolling_apply_agg_window::<no_nulls::MomentWindow<_, VarianceMoment>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -360,14 +363,14 @@ where
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
-        rolling_apply_agg_window_sorted::<no_nulls::QuantileWindow<_>, _, _>(
+        rolling_apply_agg_window_sorted::<no_nulls::QuantileWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
             params,
         )
     } else {
-        rolling_apply_agg_window::<no_nulls::QuantileWindow<_>, _, _>(
+        rolling_apply_agg_window::<no_nulls::QuantileWindow<_>, _, _, _>(
             values,
             offset_iter,
             min_periods,
@@ -376,3 +379,137 @@ where
         )
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+fn rolling_apply_agg_window_by<'a, Agg, T, Out>(
+    values: &'a [T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    Agg: RollingAggWindowNoNulls<'a, T, Out>,
+    T: Debug + IsFloat + NativeType,
+    Out: NativeType,
+{
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    if sorting_indices.is_none() {
+        rolling_apply_agg_window_sorted::<Agg, _, _, _>(values, offset_iter, min_periods, params)
+    } else {
+        rolling_apply_agg_window::<Agg, _, _, _>(
+            values,
+            offset_iter,
+            min_periods,
+            params,
+            sorting_indices,
+        )
+    }
+}
+
+macro_rules! rolling_by_func {
+    ($rolling_m:ident, $window:ident, $out:ty, $($bound:tt)+) => {
+        #[allow(clippy::too_many_arguments)]
+        pub(crate) fn $rolling_m<T>(
+            values: &[T],
+            period: Duration,
+            time: &[i64],
+            closed_window: ClosedWindow,
+            min_periods: usize,
+            tu: TimeUnit,
+            tz: Option<&TimeZone>,
+            _params: Option<RollingFnParams>,
+            sorting_indices: Option<&[IdxSize]>,
+        ) -> PolarsResult<ArrayRef>
+        where
+            T: $($bound)+,
+        {
+            rolling_apply_agg_window_by::<no_nulls::$window<T>, T, $out>(
+                values,
+                period,
+                time,
+                closed_window,
+                min_periods,
+                tu,
+                tz,
+                None,
+                sorting_indices,
+            )
+        }
+    };
+}
+
+rolling_by_func!(
+    rolling_n_unique,
+    NUniqueWindow,
+    IdxSize,
+    NativeType + IsFloat + TotalOrd + TotalHash
+);
+rolling_by_func!(
+    rolling_mode,
+    ModeWindow,
+    T,
+    NativeType + IsFloat + TotalOrd + TotalHash
+);
+rolling_by_func!(rolling_arg_min, ArgMinWindow, IdxSize, NativeType + IsFloat);
+rolling_by_func!(rolling_arg_max, ArgMaxWindow, IdxSize, NativeType + IsFloat);
+rolling_by_func!(rolling_first, FirstWindow, T, NativeType + IsFloat);
+rolling_by_func!(rolling_last, LastWindow, T, NativeType + IsFloat);
+rolling_by_func!(
+    rolling_product,
+    ProductWindow,
+    T,
+    NativeType + IsFloat + One + Mul<Output = T>
+);
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_rank<T>(
+    values: &[T],
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: Option<RollingFnParams>,
+    sorting_indices: Option<&[IdxSize]>,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + TotalOrd,
+{
+    let method = match params {
+        Some(RollingFnParams::Rank { method }) => method,
+        _ => RollingRankMethod::default(),
+    };
+    let offset_iter = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        _ => group_by_values_iter(period, time, closed_window, tu, None),
+    }?;
+    let windows = offset_iter
+        .map(|result| result.map(|(start, len)| (start as usize, (start + len) as usize)))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let out = rank::rolling_rank(values, None, windows.into_iter(), min_periods, method);
+
+    match sorting_indices {
+        None => Ok(out),
+        Some(sorting_indices) => {
+            // The ranks were computed in the order of `time`, scatter them back.
+            let mut positions = vec![0 as IdxSize; sorting_indices.len()];
+            for (i, idx) in sorting_indices.iter().enumerate() {
+                positions[*idx as usize] = i as IdxSize;
+            }
+            let positions = IdxArr::from_vec(positions);
+            // SAFETY: `positions` is a permutation of the indices of `out`.
+            Ok(unsafe { polars_compute::gather::take_unchecked(out.as_ref(), &positions) })
+        },
+    }
+}
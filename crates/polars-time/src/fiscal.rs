@@ -0,0 +1,114 @@
+use arrow::legacy::time_zone::Tz;
+use polars_core::prelude::*;
+
+use crate::windows::fiscal::{is_supported_day, local_day, local_midnight, timestamp_conversions};
+use crate::{FiscalCalendar, FiscalUnit};
+
+fn check_day(day: i32) -> PolarsResult<i32> {
+    polars_ensure!(
+        is_supported_day(day),
+        ComputeError: "date {} days from the UNIX epoch is out of range for a fiscal calendar", day
+    );
+    Ok(day)
+}
+
+pub trait PolarsFiscal {
+    /// The local dates, as the number of days since the UNIX epoch.
+    fn fiscal_days(&self, time_zone: Option<&Tz>) -> PolarsResult<Int32Chunked>;
+
+    /// Truncate to the start of the fiscal year, quarter, period or week.
+    fn fiscal_truncate(
+        &self,
+        calendar: &FiscalCalendar,
+        unit: FiscalUnit,
+        time_zone: Option<&Tz>,
+    ) -> PolarsResult<Self>
+    where
+        Self: Sized;
+
+    /// Extract the fiscal year.
+    fn fiscal_year(
+        &self,
+        calendar: &FiscalCalendar,
+        time_zone: Option<&Tz>,
+    ) -> PolarsResult<Int32Chunked> {
+        let days = self.fiscal_days(time_zone)?;
+        Ok(days.apply_values(|day| calendar.year(day)))
+    }
+
+    /// Extract the fiscal quarter, ranging from 1 to 4.
+    fn fiscal_quarter(
+        &self,
+        calendar: &FiscalCalendar,
+        time_zone: Option<&Tz>,
+    ) -> PolarsResult<Int8Chunked> {
+        let days = self.fiscal_days(time_zone)?;
+        Ok(days.apply_nonnull_values_generic(DataType::Int8, |day| calendar.quarter(day)))
+    }
+
+    /// Extract the fiscal period, ranging from 1 to 12.
+    fn fiscal_period(
+        &self,
+        calendar: &FiscalCalendar,
+        time_zone: Option<&Tz>,
+    ) -> PolarsResult<Int8Chunked> {
+        let days = self.fiscal_days(time_zone)?;
+        Ok(days.apply_nonnull_values_generic(DataType::Int8, |day| calendar.period(day)))
+    }
+
+    /// Extract the week of the fiscal year, ranging from 1 to 53.
+    fn fiscal_week(
+        &self,
+        calendar: &FiscalCalendar,
+        time_zone: Option<&Tz>,
+    ) -> PolarsResult<Int8Chunked> {
+        let days = self.fiscal_days(time_zone)?;
+        Ok(days.apply_nonnull_values_generic(DataType::Int8, |day| calendar.week(day)))
+    }
+}
+
+impl PolarsFiscal for DatetimeChunked {
+    fn fiscal_days(&self, tz: Option<&Tz>) -> PolarsResult<Int32Chunked> {
+        let (timestamp_to_datetime, _) = timestamp_conversions(self.time_unit());
+        self.phys.try_apply_nonnull_values_generic(|t| {
+            check_day(local_day(t, tz, timestamp_to_datetime))
+        })
+    }
+
+    fn fiscal_truncate(
+        &self,
+        calendar: &FiscalCalendar,
+        unit: FiscalUnit,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<Self> {
+        let (timestamp_to_datetime, datetime_to_timestamp) =
+            timestamp_conversions(self.time_unit());
+        Ok(self
+            .phys
+            .try_apply_nonnull_values_generic(|t| {
+                let day = check_day(local_day(t, tz, timestamp_to_datetime))?;
+                local_midnight(calendar.truncate(day, unit), tz, datetime_to_timestamp)
+            })?
+            .into_datetime(self.time_unit(), self.time_zone().clone()))
+    }
+}
+
+impl PolarsFiscal for DateChunked {
+    fn fiscal_days(&self, _tz: Option<&Tz>) -> PolarsResult<Int32Chunked> {
+        self.phys.try_apply_nonnull_values_generic(check_day)
+    }
+
+    fn fiscal_truncate(
+        &self,
+        calendar: &FiscalCalendar,
+        unit: FiscalUnit,
+        _tz: Option<&Tz>,
+    ) -> PolarsResult<Self> {
+        Ok(self
+            .phys
+            .try_apply_nonnull_values_generic(|day| {
+                PolarsResult::Ok(calendar.truncate(check_day(day)?, unit))
+            })?
+            .into_date())
+    }
+}
@@ -34,6 +34,9 @@ pub struct DynamicGroupOptions {
     pub include_boundaries: bool,
     pub closed_window: ClosedWindow,
     pub start_by: StartBy,
+    /// Align the windows to the fiscal years, quarters, periods or weeks of this calendar.
+    #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(default))]
+    pub fiscal_calendar: Option<FiscalCalendar>,
 }
 
 impl Default for DynamicGroupOptions {
@@ -47,6 +50,7 @@ impl Default for DynamicGroupOptions {
             include_boundaries: false,
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
+            fiscal_calendar: None,
         }
     }
 }
@@ -215,6 +219,9 @@ impl Wrap<&DataFrame> {
                 time.cast(&Datetime(TimeUnit::Milliseconds, None))?,
                 TimeUnit::Milliseconds,
             ),
+            Int32 | Int64 if options.fiscal_calendar.is_some() => polars_bail!(
+                InvalidOperation: "a fiscal calendar requires a Date or Datetime index column, got {}", time_type
+            ),
             Int32 => {
                 let time_type = Datetime(TimeUnit::Nanoseconds, None);
                 let dt = time.cast(&Int64).unwrap().cast(&time_type).unwrap();
@@ -276,6 +283,17 @@ impl Wrap<&DataFrame> {
         // A requirement for the index so we can set this such that downstream code has this info.
         dt.set_sorted_flag(IsSorted::Ascending);
 
+        let fiscal_unit = match &options.fiscal_calendar {
+            Some(_) => {
+                polars_ensure!(
+                    options.period == options.every && options.offset.is_zero(),
+                    InvalidOperation: "'period' must equal 'every' and 'offset' must be zero with a fiscal calendar"
+                );
+                Some(FiscalUnit::from_duration(&options.every)?)
+            },
+            None => None,
+        };
+
         let w = Window::new(options.every, options.period, options.offset);
         let dt = dt.datetime().unwrap();
         let tz = dt.time_zone();
@@ -296,6 +314,29 @@ impl Wrap<&DataFrame> {
             include_upper_bound = true;
         }
 
+        let windows = |values: &[i64]| match (&options.fiscal_calendar, fiscal_unit) {
+            (Some(calendar), Some(unit)) => group_by_fiscal_windows(
+                calendar,
+                unit,
+                values,
+                options.closed_window,
+                tu,
+                tz,
+                include_lower_bound,
+                include_upper_bound,
+            ),
+            _ => group_by_windows(
+                w,
+                values,
+                options.closed_window,
+                tu,
+                tz,
+                include_lower_bound,
+                include_upper_bound,
+                options.start_by,
+            ),
+        };
+
         let mut update_bounds =
             |lower: Vec<i64>, upper: Vec<i64>| match (&mut lower_bound, &mut upper_bound) {
                 (None, None) => {
@@ -312,16 +353,7 @@ impl Wrap<&DataFrame> {
         let groups = if group_by.is_none() {
             let vals = dt.downcast_iter().next().unwrap();
             let ts = vals.values().as_slice();
-            let (groups, lower, upper) = group_by_windows(
-                w,
-                ts,
-                options.closed_window,
-                tu,
-                tz,
-                include_lower_bound,
-                include_upper_bound,
-                options.start_by,
-            )?;
+            let (groups, lower, upper) = windows(ts)?;
             update_bounds(lower, upper);
             PolarsResult::Ok(GroupsType::Slice {
                 groups,
@@ -340,16 +372,7 @@ impl Wrap<&DataFrame> {
                 let values = &ts[start..end];
                 check_sortedness_slice(values)?;
 
-                let (groups, lower, upper) = group_by_windows(
-                    w,
-                    values,
-                    options.closed_window,
-                    tu,
-                    tz,
-                    include_lower_bound,
-                    include_upper_bound,
-                    options.start_by,
-                )?;
+                let (groups, lower, upper) = windows(values)?;
 
                 PolarsResult::Ok((
                     groups
@@ -5,6 +5,8 @@ pub mod chunkedarray;
 mod date_range;
 #[cfg(feature = "timezones")]
 mod dst_offset;
+#[cfg(feature = "dtype-date")]
+mod fiscal;
 mod group_by;
 #[cfg(feature = "month_end")]
 mod month_end;
@@ -27,6 +29,8 @@ pub use base_utc_offset::*;
 pub use date_range::*;
 #[cfg(feature = "timezones")]
 pub use dst_offset::*;
+#[cfg(feature = "dtype-date")]
+pub use fiscal::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
 #[cfg(feature = "month_end")]
@@ -44,5 +48,8 @@ pub use upsample::*;
 #[cfg(feature = "timezones")]
 pub use utils::known_timezones;
 pub use windows::duration::Duration;
+pub use windows::fiscal::{
+    FiscalCalendar, FiscalUnit, FiscalWeekPattern, FiscalYearEnd, FiscalYearLabel,
+};
 pub use windows::group_by::ClosedWindow;
 pub use windows::window::Window;
@@ -5,7 +5,7 @@ use polars_core::series::IsSorted;
 
 use crate::Duration;
 
-fn apply_offsets_to_datetime(
+fn apply_str_offsets_to_datetime(
     datetime: &Logical<DatetimeType, Int64Type>,
     offsets: &StringChunked,
     time_zone: Option<&Tz>,
@@ -57,9 +57,65 @@ fn apply_offsets_to_datetime(
     }
 }
 
-pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
-    let offsets = offsets.str()?;
+#[cfg(feature = "dtype-interval")]
+fn apply_interval_offsets_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &IntervalChunked,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    let time_unit = datetime.time_unit();
+    broadcast_try_binary_elementwise(
+        &datetime.phys,
+        &offsets.phys,
+        |timestamp_opt, offset_opt| match (timestamp_opt, offset_opt) {
+            (Some(timestamp), Some(offset)) => {
+                Duration::add_interval(interval_from_i128(offset), timestamp, time_unit, time_zone)
+                    .map(Some)
+            },
+            _ => Ok(None),
+        },
+    )
+}
+
+fn apply_offsets_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &Series,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    match offsets.dtype() {
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            apply_interval_offsets_to_datetime(datetime, offsets.interval()?, time_zone)
+        },
+        _ => apply_str_offsets_to_datetime(datetime, offsets.str()?, time_zone),
+    }
+}
 
+/// Whether adding `offsets` is guaranteed to keep sorted timestamps sorted.
+fn offsets_preserve_sortedness(offsets: &Series, tz: Option<&TimeZone>) -> PolarsResult<bool> {
+    if offsets.len() != 1 {
+        return Ok(false);
+    }
+    match offsets.dtype() {
+        // Only intervals without a calendar component have a constant length.
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => Ok(offsets
+            .interval()?
+            .get_interval(0)
+            .is_some_and(|v| v.months() == 0 && (v.days() == 0 || tz.is_none()))),
+        _ => match offsets.str()?.get(0) {
+            Some(offset) => {
+                let offset = Duration::try_parse(offset)?;
+                Ok(offset.is_constant_duration(tz))
+            },
+            None => Ok(false),
+        },
+    }
+}
+
+/// Offset a `Date` or `Datetime` series by either duration strings (such as `"1mo2d"`) or, with
+/// the `dtype-interval` feature, an `Interval` series.
+pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
     polars_ensure!(
         ts.len() == offsets.len() || offsets.len() == 1 || ts.len() == 1,
         length_mismatch = "dt.offset_by",
@@ -77,16 +133,7 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
         DataType::Datetime(_, tz) => tz.clone(),
         _ => polars_bail!(InvalidOperation: "expected Date or Datetime, got {}", dtype),
     };
-    let preserve_sortedness = match offsets.len() {
-        1 => match offsets.get(0) {
-            Some(offset) => {
-                let offset = Duration::try_parse(offset)?;
-                offset.is_constant_duration(tz.as_ref())
-            },
-            None => false,
-        },
-        _ => false,
-    };
+    let preserve_sortedness = offsets_preserve_sortedness(offsets, tz.as_ref())?;
 
     let out = match dtype {
         DataType::Date => {
@@ -11,6 +11,8 @@ use arrow::temporal_conversions::{
 };
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use polars_core::datatypes::DataType;
+#[cfg(feature = "dtype-interval")]
+use polars_core::datatypes::{TimeUnit, months_days_ns};
 use polars_core::prelude::{
     PolarsResult, TimeZone, datetime_to_timestamp_ms, datetime_to_timestamp_ns,
     datetime_to_timestamp_us, polars_bail,
@@ -36,6 +38,8 @@ pub struct Duration {
     weeks: i64,
     // the number of days for the duration
     days: i64,
+    // the number of business days (Monday to Friday) for the duration
+    business_days: i64,
     // the number of nanoseconds for the duration
     nsecs: i64,
     // indicates if the duration is negative
@@ -64,6 +68,7 @@ impl Neg for Duration {
             months: self.months,
             weeks: self.weeks,
             days: self.days,
+            business_days: self.business_days,
             nsecs: self.nsecs,
             negative: !self.negative,
             parsed_int: self.parsed_int,
@@ -88,6 +93,9 @@ impl Display for Duration {
         if self.days > 0 {
             write!(f, "{}d", self.days)?
         }
+        if self.business_days > 0 {
+            write!(f, "{}bd", self.business_days)?
+        }
         if self.nsecs > 0 {
             let secs = self.nsecs / NANOSECONDS;
             if secs * NANOSECONDS == self.nsecs {
@@ -112,6 +120,7 @@ impl Duration {
             months: 0,
             weeks: 0,
             days: 0,
+            business_days: 0,
             nsecs: fixed_slots.abs(),
             negative: fixed_slots < 0,
             parsed_int: true,
@@ -139,6 +148,7 @@ impl Duration {
     /// * `m`:  minute
     /// * `h`:  hour
     /// * `d`:  day
+    /// * `bd`: business day (Monday to Friday)
     /// * `w`:  week
     /// * `mo`: calendar month
     /// * `q`: calendar quarter
@@ -150,6 +160,10 @@ impl Duration {
     /// Similarly for "calendar week", "calendar month", "calendar quarter",
     /// and "calendar year".
     ///
+    /// A "business day" skips Saturdays and Sundays: adding `1bd` to a Friday
+    /// gives the following Monday. Business days cannot be combined with
+    /// calendar units.
+    ///
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
@@ -191,6 +205,7 @@ impl Duration {
         let mut months = 0;
         let mut weeks = 0;
         let mut days = 0;
+        let mut business_days = 0;
         let mut nsecs = 0;
 
         let negative = s.starts_with('-');
@@ -256,6 +271,7 @@ impl Duration {
                     "m" => nsecs += n * NS_MINUTE,
                     "h" => nsecs += n * NS_HOUR,
                     "d" => days += n,
+                    "bd" if !as_interval => business_days += n,
                     "w" => weeks += n,
                     "mo" => months += n,
                     "q" => months += n * 3,
@@ -283,7 +299,7 @@ impl Duration {
                         },
                     },
                     _ => {
-                        polars_bail!(InvalidOperation: "unit: '{unit}' not supported; available units are: 'y', 'mo', 'q', 'w', 'd', 'h', 'm', 's', 'ms', 'us', 'ns'");
+                        polars_bail!(InvalidOperation: "unit: '{unit}' not supported; available units are: 'y', 'mo', 'q', 'w', 'bd', 'd', 'h', 'm', 's', 'ms', 'us', 'ns'");
                     },
                 }
                 unit.clear();
@@ -298,11 +314,18 @@ impl Duration {
                 );
             }
         };
+        if business_days != 0 && (months != 0 || weeks != 0 || days != 0) {
+            polars_bail!(InvalidOperation:
+                "business days ('bd') cannot be combined with calendar units in the {} string '{}'",
+                parse_type, s
+            );
+        }
 
         Ok(Duration {
             months: months.abs(),
             weeks: weeks.abs(),
             days: days.abs(),
+            business_days: business_days.abs(),
             nsecs: nsecs.abs(),
             negative,
             parsed_int,
@@ -365,6 +388,7 @@ impl Duration {
             months: 0,
             weeks: 0,
             days: 0,
+            business_days: 0,
             nsecs,
             negative,
             parsed_int: false,
@@ -378,6 +402,7 @@ impl Duration {
             months,
             weeks: 0,
             days: 0,
+            business_days: 0,
             nsecs: 0,
             negative,
             parsed_int: false,
@@ -391,6 +416,7 @@ impl Duration {
             months: 0,
             weeks,
             days: 0,
+            business_days: 0,
             nsecs: 0,
             negative,
             parsed_int: false,
@@ -404,15 +430,44 @@ impl Duration {
             months: 0,
             weeks: 0,
             days,
+            business_days: 0,
             nsecs: 0,
             negative,
             parsed_int: false,
         }
     }
 
+    /// Convert to the `(months, days, nanoseconds)` representation of an interval.
+    ///
+    /// Weeks are folded into days, and the sign applies to every component.
+    #[cfg(feature = "dtype-interval")]
+    pub fn to_interval(&self) -> PolarsResult<months_days_ns> {
+        polars_ensure!(
+            self.business_days == 0,
+            ComputeError: "duration '{}' cannot be represented as an interval", self
+        );
+        let sign = if self.negative { -1 } else { 1 };
+        let days = self
+            .weeks
+            .checked_mul(7)
+            .and_then(|w| w.checked_add(self.days));
+        match (i32::try_from(self.months), days.map(i32::try_from)) {
+            (Ok(months), Some(Ok(days))) => Ok(months_days_ns::new(
+                sign * months,
+                sign * days,
+                sign as i64 * self.nsecs,
+            )),
+            _ => polars_bail!(ComputeError: "duration '{}' does not fit in an interval", self),
+        }
+    }
+
     /// `true` if zero duration.
     pub fn is_zero(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.business_days == 0
+            && self.nsecs == 0
     }
 
     pub fn months_only(&self) -> bool {
@@ -439,6 +494,10 @@ impl Duration {
         self.days
     }
 
+    pub fn business_days(&self) -> i64 {
+        self.business_days
+    }
+
     /// Returns whether the duration consists of full days.
     ///
     /// Note that 24 hours is not considered a full day due to possible
@@ -449,11 +508,11 @@ impl Duration {
 
     pub fn is_constant_duration(&self, time_zone: Option<&TimeZone>) -> bool {
         if time_zone.is_none() || time_zone == Some(&TimeZone::UTC) {
-            self.months == 0
+            self.months == 0 && self.business_days == 0
         } else {
             // For non-native, non-UTC time zones, 1 calendar day is not
             // necessarily 24 hours due to daylight savings time.
-            self.months == 0 && self.weeks == 0 && self.days == 0
+            self.months == 0 && self.weeks == 0 && self.days == 0 && self.business_days == 0
         }
     }
 
@@ -472,14 +531,16 @@ impl Duration {
     pub const fn duration_ns(&self) -> i64 {
         self.months * 28 * 24 * 3600 * NANOSECONDS
             + self.weeks * NS_WEEK
-            + self.days * NS_DAY
+            + (self.days + self.business_days) * NS_DAY
             + self.nsecs
     }
 
     #[doc(hidden)]
     pub const fn duration_us(&self) -> i64 {
         self.months * 28 * 24 * 3600 * MICROSECONDS
-            + (self.weeks * NS_WEEK / 1000 + self.nsecs / 1000 + self.days * NS_DAY / 1000)
+            + (self.weeks * NS_WEEK / 1000
+                + self.nsecs / 1000
+                + (self.days + self.business_days) * NS_DAY / 1000)
     }
 
     #[doc(hidden)]
@@ -487,7 +548,7 @@ impl Duration {
         self.months * 28 * 24 * 3600 * MILLISECONDS
             + (self.weeks * NS_WEEK / 1_000_000
                 + self.nsecs / 1_000_000
-                + self.days * NS_DAY / 1_000_000)
+                + (self.days + self.business_days) * NS_DAY / 1_000_000)
     }
 
     #[doc(hidden)]
@@ -689,6 +750,59 @@ impl Duration {
             _ => Ok(result_t_local),
         }
     }
+
+    fn truncate_business_daily<G, J>(
+        &self,
+        t: i64,
+        tz: Option<&Tz>,
+        _timestamp_to_datetime: G,
+        _datetime_to_timestamp: J,
+        daily_duration: i64,
+    ) -> PolarsResult<i64>
+    where
+        G: Fn(i64) -> NaiveDateTime,
+        J: Fn(NaiveDateTime) -> i64,
+    {
+        let _original_dt_utc: Option<NaiveDateTime>;
+        let _original_dt_local: Option<NaiveDateTime>;
+        let t = match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                _original_dt_utc = Some(_timestamp_to_datetime(t));
+                _original_dt_local = Some(unlocalize_datetime(_original_dt_utc.unwrap(), tz));
+                _datetime_to_timestamp(_original_dt_local.unwrap())
+            },
+            _ => {
+                _original_dt_utc = None;
+                _original_dt_local = None;
+                t
+            },
+        };
+        // Weekend days belong to the window of the preceding Friday.
+        let day = t.div_euclid(daily_duration);
+        let weekday = (day + 3).rem_euclid(7);
+        let day = day - (weekday - 4).max(0);
+        let mut index = business_day_index(day);
+        index -= index.rem_euclid(self.business_days);
+        let result_t_local = day_from_business_day_index(index) * daily_duration;
+        match tz {
+            #[cfg(feature = "timezones")]
+            // for UTC, use fastpath below (same as naive)
+            Some(tz) if tz != &chrono_tz::UTC => {
+                let result_dt_local = _timestamp_to_datetime(result_t_local);
+                let result_dt_utc = self.localize_result(
+                    _original_dt_local.unwrap(),
+                    _original_dt_utc.unwrap(),
+                    result_dt_local,
+                    tz,
+                )?;
+                Ok(_datetime_to_timestamp(result_dt_utc))
+            },
+            _ => Ok(result_t_local),
+        }
+    }
+
     fn truncate_monthly<G, J>(
         &self,
         t: i64,
@@ -787,6 +901,19 @@ impl Duration {
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
     {
+        if self.business_days != 0 {
+            polars_ensure!(
+                self.nsecs == 0,
+                ComputeError: "cannot mix business days and sub-daily units for this operation"
+            );
+            return self.truncate_business_daily(
+                t,
+                tz,
+                timestamp_to_datetime,
+                datetime_to_timestamp,
+                nsecs_to_unit(NS_DAY),
+            );
+        }
         match (self.months, self.weeks, self.days, self.nsecs) {
             (0, 0, 0, 0) => polars_bail!(ComputeError: "duration cannot be zero"),
             // truncate by ns/us/ms
@@ -955,6 +1082,37 @@ impl Duration {
             };
         }
 
+        if d.business_days > 0 {
+            let daily_duration = nsecs_to_unit(NS_DAY);
+            let n = if d.negative {
+                -d.business_days
+            } else {
+                d.business_days
+            };
+            let shift = |t: i64| {
+                let day = t.div_euclid(daily_duration);
+                (add_business_days_to_day(day, n) - day) * daily_duration
+            };
+            match tz {
+                #[cfg(feature = "timezones")]
+                // for UTC, use fastpath below (same as naive)
+                Some(tz) if tz != &chrono_tz::UTC => {
+                    t = datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
+                    t += shift(t);
+                    t = datetime_to_timestamp(
+                        try_localize_datetime(
+                            timestamp_to_datetime(t),
+                            tz,
+                            Ambiguous::Raise,
+                            NonExistent::Raise,
+                        )?
+                        .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
+                    );
+                },
+                _ => t += shift(t),
+            };
+        }
+
         Ok(t)
     }
 
@@ -996,6 +1154,34 @@ impl Duration {
         let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
         Ok(new_t? + nsecs / 1_000_000)
     }
+
+    /// Add a calendar interval to a timestamp of the given time unit.
+    ///
+    /// The months, days and nanoseconds are applied in that order, each with its own sign, so
+    /// that month ends and DST transitions are handled like the equivalent duration strings.
+    #[cfg(feature = "dtype-interval")]
+    pub fn add_interval(
+        interval: months_days_ns,
+        mut t: i64,
+        time_unit: TimeUnit,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<i64> {
+        let add = match time_unit {
+            TimeUnit::Milliseconds => Self::add_ms,
+            TimeUnit::Microseconds => Self::add_us,
+            TimeUnit::Nanoseconds => Self::add_ns,
+        };
+        if interval.months() != 0 {
+            t = add(&Self::from_months(interval.months() as i64), t, tz)?;
+        }
+        if interval.days() != 0 {
+            t = add(&Self::from_days(interval.days() as i64), t, tz)?;
+        }
+        if interval.ns() != 0 {
+            t = add(&Self::from_nsecs(interval.ns()), t, tz)?;
+        }
+        Ok(t)
+    }
 }
 
 impl Mul<i64> for Duration {
@@ -1009,11 +1195,37 @@ impl Mul<i64> for Duration {
         self.months *= rhs;
         self.weeks *= rhs;
         self.days *= rhs;
+        self.business_days *= rhs;
         self.nsecs *= rhs;
         self
     }
 }
 
+/// Number of business days from Monday 1969-12-29 up to `day`, a business day
+/// given as the number of days since the UNIX epoch.
+fn business_day_index(day: i64) -> i64 {
+    // 1970-01-01 was a Thursday.
+    let day = day + 3;
+    day.div_euclid(7) * 5 + day.rem_euclid(7)
+}
+
+/// Inverse of [`business_day_index`].
+fn day_from_business_day_index(index: i64) -> i64 {
+    index.div_euclid(5) * 7 + index.rem_euclid(5) - 3
+}
+
+/// Shift `day` by `n` business days. A weekend day counts as the preceding Friday
+/// when moving forward and as the following Monday when moving backward.
+fn add_business_days_to_day(day: i64, n: i64) -> i64 {
+    let weekday = (day + 3).rem_euclid(7);
+    let index = match weekday {
+        0..=4 => business_day_index(day),
+        _ if n > 0 => business_day_index(day - (weekday - 4)),
+        _ => business_day_index(day + (7 - weekday)),
+    };
+    day_from_business_day_index(index + n)
+}
+
 fn new_datetime(
     year: i32,
     month: u32,
@@ -1127,4 +1339,63 @@ mod test {
         let expected = "4w";
         assert_eq!(format!("{duration}"), expected);
     }
+
+    #[test]
+    fn test_business_days() {
+        let ts =
+            |d: u32| datetime_to_timestamp_ns(NaiveDate::from_ymd_opt(2024, 1, d).unwrap().into());
+        // 2024-01-05 is a Friday.
+        let one = Duration::parse("1bd");
+        assert_eq!(one.add_ns(ts(5), None).unwrap(), ts(8));
+        assert_eq!(one.add_ns(ts(6), None).unwrap(), ts(8));
+        assert_eq!((-one).add_ns(ts(8), None).unwrap(), ts(5));
+        assert_eq!((-one).add_ns(ts(7), None).unwrap(), ts(5));
+        assert_eq!(Duration::parse("6bd").add_ns(ts(2), None).unwrap(), ts(10));
+        assert_eq!(
+            Duration::parse("1bd12h").add_ns(ts(5), None).unwrap(),
+            ts(8) + 12 * NS_HOUR
+        );
+
+        assert_eq!(one.truncate_ns(ts(7) + NS_HOUR, None).unwrap(), ts(5));
+        assert_eq!(
+            Duration::parse("2bd").truncate_ns(ts(4), None).unwrap(),
+            ts(3)
+        );
+        assert_eq!(format!("{}", Duration::parse("-3bd")), "-3bd");
+        assert!(!one.is_constant_duration(None));
+        assert!(Duration::try_parse("1bd1d").is_err());
+        assert!(Duration::try_parse_interval("1 bd").is_err());
+    }
+
+    #[cfg(feature = "dtype-interval")]
+    #[test]
+    fn test_add_interval() {
+        let t = datetime_to_timestamp_ms(
+            NaiveDate::from_ymd_opt(2024, 1, 31)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        );
+        let expected = |y, m, d, h| {
+            datetime_to_timestamp_ms(
+                NaiveDate::from_ymd_opt(y, m, d)
+                    .unwrap()
+                    .and_hms_opt(h, 0, 0)
+                    .unwrap(),
+            )
+        };
+
+        // Month ends are clamped like the "1mo" duration string.
+        let one_month = months_days_ns::new(1, 0, 0);
+        let out = Duration::add_interval(one_month, t, TimeUnit::Milliseconds, None).unwrap();
+        assert_eq!(out, expected(2024, 2, 29, 0));
+
+        // Every component carries its own sign.
+        let mixed = months_days_ns::new(1, -1, 2 * NS_HOUR);
+        let out = Duration::add_interval(mixed, t, TimeUnit::Milliseconds, None).unwrap();
+        assert_eq!(out, expected(2024, 2, 28, 2));
+
+        let interval = Duration::parse("-1mo2w3d4h").to_interval().unwrap();
+        assert_eq!(interval, months_days_ns::new(-1, -17, -4 * NS_HOUR));
+    }
 }
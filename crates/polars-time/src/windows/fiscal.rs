@@ -0,0 +1,376 @@
+use arrow::legacy::time_zone::Tz;
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use polars_core::prelude::*;
+use polars_core::utils::arrow::temporal_conversions::{
+    EPOCH_DAYS_FROM_CE, timestamp_ms_to_datetime, timestamp_ns_to_datetime,
+    timestamp_us_to_datetime,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Duration;
+#[cfg(feature = "timezones")]
+use crate::utils::{try_localize_datetime, unlocalize_datetime};
+
+/// How the weeks of a retail quarter are divided over its three periods.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FiscalWeekPattern {
+    P445,
+    P454,
+    P544,
+}
+
+impl FiscalWeekPattern {
+    fn weeks(self) -> [i32; 3] {
+        match self {
+            Self::P445 => [4, 4, 5],
+            Self::P454 => [4, 5, 4],
+            Self::P544 => [5, 4, 4],
+        }
+    }
+}
+
+/// Which weekday ends a retail fiscal year.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FiscalYearEnd {
+    /// The last `end_weekday` in the month before the start month.
+    Last,
+    /// The `end_weekday` nearest to the end of the month before the start month.
+    #[default]
+    Nearest,
+}
+
+/// Which calendar year a fiscal year is named after.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FiscalYearLabel {
+    /// The calendar year in which the fiscal year starts.
+    Start,
+    /// The calendar year in which the fiscal year ends.
+    #[default]
+    End,
+}
+
+/// The fiscal units dates can be truncated to and grouped by.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FiscalUnit {
+    Year,
+    Quarter,
+    Period,
+    Week,
+}
+
+impl FiscalUnit {
+    /// The fiscal unit denoted by a single `1y`, `1q`, `1mo` or `1w` duration.
+    pub fn from_duration(duration: &Duration) -> PolarsResult<Self> {
+        let unit = match (
+            duration.months(),
+            duration.weeks(),
+            duration.days(),
+            duration.nanoseconds(),
+        ) {
+            _ if duration.negative() || duration.business_days() != 0 => None,
+            (12, 0, 0, 0) => Some(Self::Year),
+            (3, 0, 0, 0) => Some(Self::Quarter),
+            (1, 0, 0, 0) => Some(Self::Period),
+            (0, 1, 0, 0) => Some(Self::Week),
+            _ => None,
+        };
+        unit.ok_or_else(|| {
+            polars_err!(InvalidOperation: "expected one of '1y', '1q', '1mo' or '1w' for a fiscal calendar, got '{}'", duration)
+        })
+    }
+}
+
+/// A fiscal calendar, either made of whole calendar months or of retail weeks.
+///
+/// A retail (52/53-week) fiscal year ends on the `end_weekday` that is last in, or nearest to
+/// the end of, the month before `start_month`. Its quarters consist of 13 weeks, divided over
+/// three periods according to the `week_pattern`. The occasional 53rd week is added to the last
+/// period.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FiscalCalendar {
+    /// Month (1-12) in which the fiscal year starts.
+    pub start_month: u8,
+    /// Week pattern of a retail calendar, `None` for a calendar made of whole months.
+    pub week_pattern: Option<FiscalWeekPattern>,
+    /// Weekday (0 is Monday) on which a retail fiscal year ends.
+    pub end_weekday: u8,
+    pub year_end: FiscalYearEnd,
+    pub label: FiscalYearLabel,
+}
+
+impl FiscalCalendar {
+    /// A fiscal calendar of whole months, starting on the first of `start_month`.
+    pub fn new(start_month: u8) -> PolarsResult<Self> {
+        polars_ensure!(
+            (1..=12).contains(&start_month),
+            InvalidOperation: "fiscal year start month must be between 1 and 12, got {}", start_month
+        );
+        Ok(Self {
+            start_month,
+            week_pattern: None,
+            end_weekday: 6,
+            year_end: FiscalYearEnd::default(),
+            label: FiscalYearLabel::default(),
+        })
+    }
+
+    /// A retail calendar of 52 or 53 weeks per year.
+    pub fn retail(
+        start_month: u8,
+        week_pattern: FiscalWeekPattern,
+        end_weekday: u8,
+        year_end: FiscalYearEnd,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            end_weekday < 7,
+            InvalidOperation: "fiscal year end weekday must be between 0 (Monday) and 6 (Sunday), got {}", end_weekday
+        );
+        Ok(Self {
+            week_pattern: Some(week_pattern),
+            end_weekday,
+            year_end,
+            ..Self::new(start_month)?
+        })
+    }
+
+    pub fn with_label(mut self, label: FiscalYearLabel) -> Self {
+        self.label = label;
+        self
+    }
+
+    /// First day of the fiscal year starting around `start_month` of calendar year `year`.
+    fn year_start(&self, year: i32) -> i32 {
+        let first = to_days(year, self.start_month as u32, 1);
+        if self.week_pattern.is_none() {
+            return first;
+        }
+        // The previous fiscal year ends around the last day of the preceding month.
+        let last = first - 1;
+        let back = (weekday(last) - self.end_weekday as i32).rem_euclid(7);
+        let end = match self.year_end {
+            FiscalYearEnd::Nearest if back > 3 => last + 7 - back,
+            _ => last - back,
+        };
+        end + 1
+    }
+
+    /// The calendar year in which the fiscal year containing `day` starts, together with the
+    /// first days of that fiscal year and the next one.
+    fn locate(&self, day: i32) -> (i32, i32, i32) {
+        let mut year = from_days(day).year();
+        if day < self.year_start(year) {
+            year -= 1;
+        } else if day >= self.year_start(year + 1) {
+            year += 1;
+        }
+        (year, self.year_start(year), self.year_start(year + 1))
+    }
+
+    /// First days of the periods of a fiscal year starting on `start`, relative to `start`.
+    fn period_offsets(&self, year: i32, start: i32) -> [i32; 12] {
+        let mut offsets = [0; 12];
+        match self.week_pattern {
+            None => {
+                for (i, offset) in offsets.iter_mut().enumerate() {
+                    let month0 = self.start_month as i32 - 1 + i as i32;
+                    let (year, month) = (year + month0 / 12, month0 % 12 + 1);
+                    *offset = to_days(year, month as u32, 1) - start;
+                }
+            },
+            Some(pattern) => {
+                let weeks = pattern.weeks();
+                let mut total = 0;
+                for (i, offset) in offsets.iter_mut().enumerate() {
+                    *offset = total * 7;
+                    total += weeks[i % 3];
+                }
+            },
+        }
+        offsets
+    }
+
+    /// The fiscal year containing `day`, given as the number of days since the UNIX epoch.
+    pub fn year(&self, day: i32) -> i32 {
+        let (year, _, next) = self.locate(day);
+        match self.label {
+            FiscalYearLabel::Start => year,
+            FiscalYearLabel::End => from_days(next - 1).year(),
+        }
+    }
+
+    /// The fiscal period (1-12) containing `day`.
+    pub fn period(&self, day: i32) -> i8 {
+        let (year, start, _) = self.locate(day);
+        let offsets = self.period_offsets(year, start);
+        offsets.partition_point(|&offset| offset <= day - start) as i8
+    }
+
+    /// The fiscal quarter (1-4) containing `day`.
+    pub fn quarter(&self, day: i32) -> i8 {
+        (self.period(day) - 1) / 3 + 1
+    }
+
+    /// The fiscal week (1-53) containing `day`. Weeks are counted from the start of the fiscal
+    /// year.
+    pub fn week(&self, day: i32) -> i8 {
+        let (_, start, _) = self.locate(day);
+        ((day - start) / 7 + 1) as i8
+    }
+
+    /// The first day of the `unit` containing `day` and the first day of the next one.
+    pub fn bounds(&self, day: i32, unit: FiscalUnit) -> (i32, i32) {
+        let (year, start, next) = self.locate(day);
+        match unit {
+            FiscalUnit::Year => (start, next),
+            FiscalUnit::Week => {
+                let lower = start + (day - start) / 7 * 7;
+                (lower, (lower + 7).min(next))
+            },
+            FiscalUnit::Quarter | FiscalUnit::Period => {
+                let step = if unit == FiscalUnit::Quarter { 3 } else { 1 };
+                let offsets = self.period_offsets(year, start);
+                let i =
+                    (offsets.partition_point(|&offset| offset <= day - start) - 1) / step * step;
+                let upper = offsets.get(i + step).map_or(next, |offset| start + offset);
+                (start + offsets[i], upper)
+            },
+        }
+    }
+
+    /// The first day of the `unit` containing `day`.
+    pub fn truncate(&self, day: i32, unit: FiscalUnit) -> i32 {
+        self.bounds(day, unit).0
+    }
+}
+
+/// Whether the fiscal calendar functions can be evaluated on `day`, i.e. whether it lies well
+/// within the range of dates supported by chrono.
+pub(crate) fn is_supported_day(day: i32) -> bool {
+    let min = NaiveDate::MIN.num_days_from_ce() - EPOCH_DAYS_FROM_CE + 400;
+    let max = NaiveDate::MAX.num_days_from_ce() - EPOCH_DAYS_FROM_CE - 400;
+    (min..=max).contains(&day)
+}
+
+pub(crate) fn to_days(year: i32, month: u32, day: u32) -> i32 {
+    NaiveDate::from_ymd_opt(year, month, day)
+        .expect("valid date")
+        .num_days_from_ce()
+        - EPOCH_DAYS_FROM_CE
+}
+
+pub(crate) fn from_days(day: i32) -> NaiveDate {
+    NaiveDate::from_num_days_from_ce_opt(day + EPOCH_DAYS_FROM_CE).expect("date in range")
+}
+
+type TimestampConversions = (fn(i64) -> NaiveDateTime, fn(NaiveDateTime) -> i64);
+
+pub(crate) fn timestamp_conversions(tu: TimeUnit) -> TimestampConversions {
+    match tu {
+        TimeUnit::Nanoseconds => (timestamp_ns_to_datetime, datetime_to_timestamp_ns),
+        TimeUnit::Microseconds => (timestamp_us_to_datetime, datetime_to_timestamp_us),
+        TimeUnit::Milliseconds => (timestamp_ms_to_datetime, datetime_to_timestamp_ms),
+    }
+}
+
+/// The local date of a timestamp, as the number of days since the UNIX epoch.
+pub(crate) fn local_day(
+    t: i64,
+    tz: Option<&Tz>,
+    timestamp_to_datetime: fn(i64) -> NaiveDateTime,
+) -> i32 {
+    let ndt = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz),
+        _ => timestamp_to_datetime(t),
+    };
+    ndt.date().num_days_from_ce() - EPOCH_DAYS_FROM_CE
+}
+
+/// The timestamp of local midnight at the start of `day`.
+pub(crate) fn local_midnight(
+    day: i32,
+    tz: Option<&Tz>,
+    datetime_to_timestamp: fn(NaiveDateTime) -> i64,
+) -> PolarsResult<i64> {
+    let ndt = from_days(day).and_hms_opt(0, 0, 0).unwrap();
+    Ok(match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => datetime_to_timestamp(
+            try_localize_datetime(ndt, tz, Ambiguous::Raise, NonExistent::Raise)?
+                .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
+        ),
+        _ => datetime_to_timestamp(ndt),
+    })
+}
+
+/// Day of the week, 0 is Monday. 1970-01-01 was a Thursday.
+fn weekday(day: i32) -> i32 {
+    (day + 3).rem_euclid(7)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_monthly_fiscal_calendar() {
+        // US federal government: fiscal year 2024 runs from 2023-10-01 to 2024-09-30.
+        let cal = FiscalCalendar::new(10).unwrap();
+        let day = to_days(2023, 11, 15);
+        assert_eq!(cal.year(day), 2024);
+        assert_eq!(cal.period(day), 2);
+        assert_eq!(cal.quarter(day), 1);
+        assert_eq!(cal.week(day), 7);
+        assert_eq!(cal.truncate(day, FiscalUnit::Year), to_days(2023, 10, 1));
+        assert_eq!(
+            cal.bounds(to_days(2024, 2, 10), FiscalUnit::Quarter),
+            (to_days(2024, 1, 1), to_days(2024, 4, 1))
+        );
+        assert_eq!(cal.with_label(FiscalYearLabel::Start).year(day), 2023);
+    }
+
+    #[test]
+    fn test_retail_fiscal_calendar() {
+        // NRF calendar: the year ends on the Saturday nearest to the end of January.
+        let cal = FiscalCalendar::retail(2, FiscalWeekPattern::P454, 5, FiscalYearEnd::Nearest)
+            .unwrap()
+            .with_label(FiscalYearLabel::Start);
+        // Fiscal 2023 ran from 2023-01-29 to 2024-02-03 and had 53 weeks.
+        assert_eq!(
+            cal.bounds(to_days(2023, 6, 1), FiscalUnit::Year),
+            (to_days(2023, 1, 29), to_days(2024, 2, 4))
+        );
+        assert_eq!(cal.year(to_days(2024, 2, 3)), 2023);
+        assert_eq!(cal.week(to_days(2024, 2, 3)), 53);
+        assert_eq!(cal.period(to_days(2024, 2, 3)), 12);
+        // The second period of the 4-5-4 pattern has five weeks.
+        assert_eq!(
+            cal.bounds(to_days(2023, 3, 1), FiscalUnit::Period),
+            (to_days(2023, 2, 26), to_days(2023, 4, 2))
+        );
+        assert_eq!(cal.quarter(to_days(2023, 5, 1)), 2);
+        assert_eq!(
+            cal.bounds(to_days(2023, 12, 1), FiscalUnit::Quarter),
+            (to_days(2023, 10, 29), to_days(2024, 2, 4))
+        );
+
+        let last =
+            FiscalCalendar::retail(1, FiscalWeekPattern::P445, 6, FiscalYearEnd::Last).unwrap();
+        // The last Sunday of 2023 is 2023-12-31, so fiscal 2024 starts on 2024-01-01.
+        assert_eq!(
+            last.truncate(to_days(2024, 1, 20), FiscalUnit::Year),
+            to_days(2024, 1, 1)
+        );
+    }
+}
@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
 
 use crate::prelude::*;
+use crate::windows::fiscal;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, IntoStaticStr)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -219,6 +220,68 @@ pub fn group_by_windows(
     Ok((groups, lower_bound, upper_bound))
 }
 
+/// Like [`group_by_windows`], but with windows spanning the fiscal `unit`s of `calendar`. The
+/// windows are contiguous and windows without any members are skipped.
+#[allow(clippy::too_many_arguments)]
+pub fn group_by_fiscal_windows(
+    calendar: &FiscalCalendar,
+    unit: FiscalUnit,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: &Option<TimeZone>,
+    include_lower_bound: bool,
+    include_upper_bound: bool,
+) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)> {
+    let tz = match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => tz.parse::<Tz>().ok(),
+        _ => None,
+    };
+    let tz = tz.as_ref();
+    let (timestamp_to_datetime, datetime_to_timestamp) = fiscal::timestamp_conversions(tu);
+    let closed_left = matches!(closed_window, ClosedWindow::Left | ClosedWindow::Both);
+    let closed_right = matches!(closed_window, ClosedWindow::Right | ClosedWindow::Both);
+
+    let mut lower_bound = Vec::new();
+    let mut upper_bound = Vec::new();
+    let mut groups = Vec::new();
+
+    let mut next = 0;
+    while next < time.len() {
+        let t = time[next];
+        let day = fiscal::local_day(t, tz, timestamp_to_datetime);
+        polars_ensure!(
+            fiscal::is_supported_day(day),
+            ComputeError: "timestamp {} is out of range for a fiscal calendar", t
+        );
+        let (mut start_day, mut stop_day) = calendar.bounds(day, unit);
+        let mut start = fiscal::local_midnight(start_day, tz, datetime_to_timestamp)?;
+        if t == start && !closed_left {
+            // A value on the boundary belongs to the window ending there.
+            (start_day, stop_day) = calendar.bounds(start_day - 1, unit);
+            start = fiscal::local_midnight(start_day, tz, datetime_to_timestamp)?;
+        }
+        let stop = fiscal::local_midnight(stop_day, tz, datetime_to_timestamp)?;
+
+        let first = time.partition_point(|&t| t < start || (t == start && !closed_left));
+        let end = time.partition_point(|&t| t < stop || (t == stop && closed_right));
+        if end > first {
+            if include_lower_bound {
+                lower_bound.push(start);
+            }
+            if include_upper_bound {
+                upper_bound.push(stop);
+            }
+            groups.push([first as IdxSize, (end - first) as IdxSize]);
+        }
+        // The next window starts at `stop`, values on that boundary may be members of both.
+        next = time.partition_point(|&t| t < stop || (t == stop && !closed_left));
+    }
+
+    Ok((groups, lower_bound, upper_bound))
+}
+
 // t is right at the end of the window
 // ------t---
 // [------]
@@ -7,6 +7,7 @@
 pub(crate) mod bounds;
 pub(crate) mod calendar;
 pub(crate) mod duration;
+pub(crate) mod fiscal;
 pub(crate) mod group_by;
 #[cfg(test)]
 mod test;
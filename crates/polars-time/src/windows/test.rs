@@ -77,6 +77,30 @@ fn test_feb_date_range() {
     assert_eq!(dates, expected);
 }
 
+#[test]
+fn test_business_day_date_range() {
+    // 2024-01-04 is a Thursday.
+    let ts = |d: u32| {
+        NaiveDate::from_ymd_opt(2024, 1, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap()
+    };
+    let dates = datetime_range_i64(
+        ts(4),
+        ts(10),
+        Duration::parse("1bd"),
+        ClosedWindow::Both,
+        TimeUnit::Nanoseconds,
+        None,
+    )
+    .unwrap();
+    assert_eq!(dates, [ts(4), ts(5), ts(8), ts(9), ts(10)]);
+}
+
 fn print_ns(ts: &[i64]) {
     for ts in ts {
         println!("{}", timestamp_ns_to_datetime(*ts));
@@ -942,3 +966,84 @@ fn test_group_by_windows_offsets_3776() {
     .unwrap();
     assert_eq!(groups, [[0, 1], [1, 1], [2, 1]]);
 }
+
+#[test]
+fn test_group_by_fiscal_windows() {
+    let ms = |y, m, d| {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis()
+    };
+    // A fiscal year starting in October, with quarters starting in October, January, April and
+    // July. The third quarter has no members.
+    let calendar = FiscalCalendar::new(10).unwrap();
+    let ts = [
+        ms(2023, 9, 30),
+        ms(2023, 10, 1),
+        ms(2023, 12, 31),
+        ms(2024, 1, 1),
+        ms(2024, 7, 15),
+    ];
+
+    let (groups, lower, upper) = group_by_fiscal_windows(
+        &calendar,
+        FiscalUnit::Quarter,
+        &ts,
+        ClosedWindow::Left,
+        TimeUnit::Milliseconds,
+        &None,
+        true,
+        true,
+    )
+    .unwrap();
+    assert_eq!(groups, [[0, 1], [1, 2], [3, 1], [4, 1]]);
+    assert_eq!(
+        lower,
+        [
+            ms(2023, 7, 1),
+            ms(2023, 10, 1),
+            ms(2024, 1, 1),
+            ms(2024, 7, 1)
+        ]
+    );
+    assert_eq!(
+        upper,
+        [
+            ms(2023, 10, 1),
+            ms(2024, 1, 1),
+            ms(2024, 4, 1),
+            ms(2024, 10, 1)
+        ]
+    );
+
+    // With `closed="right"` a value on a boundary belongs to the window ending there.
+    let (groups, lower, _) = group_by_fiscal_windows(
+        &calendar,
+        FiscalUnit::Quarter,
+        &ts,
+        ClosedWindow::Right,
+        TimeUnit::Milliseconds,
+        &None,
+        true,
+        false,
+    )
+    .unwrap();
+    assert_eq!(groups, [[0, 2], [2, 2], [4, 1]]);
+    assert_eq!(lower, [ms(2023, 7, 1), ms(2023, 10, 1), ms(2024, 7, 1)]);
+
+    let (groups, _, _) = group_by_fiscal_windows(
+        &calendar,
+        FiscalUnit::Year,
+        &ts,
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        &None,
+        false,
+        false,
+    )
+    .unwrap();
+    assert_eq!(groups, [[0, 2], [1, 4]]);
+}
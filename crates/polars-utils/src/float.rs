@@ -1,3 +1,5 @@
+use crate::float16::f16;
+
 /// # Safety
 /// unsafe code downstream relies on the correct is_float call
 pub unsafe trait IsFloat: private::Sealed + Sized {
@@ -42,6 +44,7 @@ unsafe impl IsFloat for u8 {}
 unsafe impl IsFloat for u16 {}
 unsafe impl IsFloat for u32 {}
 unsafe impl IsFloat for u64 {}
+unsafe impl IsFloat for u128 {}
 unsafe impl IsFloat for &str {}
 unsafe impl IsFloat for &[u8] {}
 unsafe impl IsFloat for bool {}
@@ -58,6 +61,8 @@ mod private {
     impl Sealed for u16 {}
     impl Sealed for u32 {}
     impl Sealed for u64 {}
+    impl Sealed for u128 {}
+    impl Sealed for crate::float16::f16 {}
     impl Sealed for f32 {}
     impl Sealed for f64 {}
     impl Sealed for &str {}
@@ -99,5 +104,6 @@ macro_rules! impl_is_float {
     };
 }
 
+impl_is_float!(f16, false, false);
 impl_is_float!(f32, true, false);
 impl_is_float!(f64, false, true);
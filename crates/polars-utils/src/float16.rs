@@ -0,0 +1,615 @@
+use std::cmp::Ordering;
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use bytemuck::{Pod, Zeroable};
+use num_traits::{
+    AsPrimitive, Bounded, Float, FromPrimitive, Num, NumCast, One, Pow, ToPrimitive, Zero,
+};
+
+/// Type representation of the Float16 physical type.
+///
+/// Arithmetic is done by widening to `f32` and rounding the result back to half precision.
+#[derive(Copy, Clone, Default, Zeroable, Pod)]
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub struct f16(pub u16);
+
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &f16) -> bool {
+        if self.is_nan() || other.is_nan() {
+            false
+        } else {
+            (self.0 == other.0) || ((self.0 | other.0) & 0x7FFFu16 == 0)
+        }
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &f16) -> Option<Ordering> {
+        f16::to_f32(*self).partial_cmp(&f16::to_f32(*other))
+    }
+}
+
+/// Converts an f16 into a canonical form, where -0 == 0 and all NaNs map to
+/// the same value.
+#[inline]
+pub fn canonical_f16(x: f16) -> f16 {
+    // zero out the sign bit if the f16 is zero.
+    let convert_zero = f16(x.0 & (0x7FFF | (((x.0 & 0x7FFF == 0) as u16) << 15)));
+    if convert_zero.is_nan() {
+        f16::NAN // Canonical quiet NaN.
+    } else {
+        convert_zero
+    }
+}
+
+// see https://github.com/starkat99/half-rs/blob/main/src/binary16.rs
+impl f16 {
+    /// The difference between 1.0 and the next largest representable number.
+    pub const EPSILON: f16 = f16(0x1400u16);
+    pub const NAN: f16 = f16(0x7E00u16);
+    pub const INFINITY: f16 = f16(0x7C00u16);
+    pub const NEG_INFINITY: f16 = f16(0xFC00u16);
+    /// Largest finite value.
+    pub const MAX: f16 = f16(0x7BFFu16);
+    /// Smallest finite value.
+    pub const MIN: f16 = f16(0xFBFFu16);
+    /// Smallest positive normal value.
+    pub const MIN_POSITIVE: f16 = f16(0x0400u16);
+    pub const ZERO: f16 = f16(0x0000u16);
+    pub const NEG_ZERO: f16 = f16(0x8000u16);
+    pub const ONE: f16 = f16(0x3C00u16);
+
+    #[inline]
+    #[must_use]
+    pub const fn is_nan(self) -> bool {
+        self.0 & 0x7FFFu16 > 0x7C00u16
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_finite(self) -> bool {
+        self.0 & 0x7C00u16 != 0x7C00u16
+    }
+
+    /// Casts from u16.
+    #[inline]
+    pub const fn from_bits(bits: u16) -> f16 {
+        f16(bits)
+    }
+
+    /// Casts to u16.
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Casts this `f16` to `f32`
+    pub fn to_f32(self) -> f32 {
+        let i = self.0;
+        // Check for signed zero
+        if i & 0x7FFFu16 == 0 {
+            return f32::from_bits((i as u32) << 16);
+        }
+
+        let half_sign = (i & 0x8000u16) as u32;
+        let half_exp = (i & 0x7C00u16) as u32;
+        let half_man = (i & 0x03FFu16) as u32;
+
+        // Check for an infinity or NaN when all exponent bits set
+        if half_exp == 0x7C00u32 {
+            // Check for signed infinity if mantissa is zero
+            if half_man == 0 {
+                let number = (half_sign << 16) | 0x7F80_0000u32;
+                return f32::from_bits(number);
+            } else {
+                // NaN, keep current mantissa but also set most significiant mantissa bit
+                let number = (half_sign << 16) | 0x7FC0_0000u32 | (half_man << 13);
+                return f32::from_bits(number);
+            }
+        }
+
+        // Calculate single-precision components with adjusted exponent
+        let sign = half_sign << 16;
+        // Unbias exponent
+        let unbiased_exp = ((half_exp as i32) >> 10) - 15;
+
+        // Check for subnormals, which will be normalized by adjusting exponent
+        if half_exp == 0 {
+            // Calculate how much to adjust the exponent by
+            let e = (half_man as u16).leading_zeros() - 6;
+
+            // Rebias and adjust exponent
+            let exp = (127 - 15 - e) << 23;
+            let man = (half_man << (14 + e)) & 0x7F_FF_FFu32;
+            return f32::from_bits(sign | exp | man);
+        }
+
+        // Rebias exponent for a normalized normal
+        let exp = ((unbiased_exp + 127) as u32) << 23;
+        let man = (half_man & 0x03FFu32) << 13;
+        f32::from_bits(sign | exp | man)
+    }
+
+    /// Casts an `f32` into `f16`
+    pub fn from_f32(value: f32) -> Self {
+        let x: u32 = value.to_bits();
+
+        // Extract IEEE754 components
+        let sign = x & 0x8000_0000u32;
+        let exp = x & 0x7F80_0000u32;
+        let man = x & 0x007F_FFFFu32;
+
+        // Check for all exponent bits being set, which is Infinity or NaN
+        if exp == 0x7F80_0000u32 {
+            // Set mantissa MSB for NaN (and also keep shifted mantissa bits)
+            let nan_bit = if man == 0 { 0 } else { 0x0200u32 };
+            return f16(((sign >> 16) | 0x7C00u32 | nan_bit | (man >> 13)) as u16);
+        }
+
+        // The number is normalized, start assembling half precision version
+        let half_sign = sign >> 16;
+        // Unbias the exponent, then bias for half precision
+        let unbiased_exp = ((exp >> 23) as i32) - 127;
+        let half_exp = unbiased_exp + 15;
+
+        // Check for exponent overflow, return +infinity
+        if half_exp >= 0x1F {
+            return f16((half_sign | 0x7C00u32) as u16);
+        }
+
+        // Check for underflow
+        if half_exp <= 0 {
+            // Check mantissa for what we can do
+            if 14 - half_exp > 24 {
+                // No rounding possibility, so this is a full underflow, return signed zero
+                return f16(half_sign as u16);
+            }
+            // Don't forget about hidden leading mantissa bit when assembling mantissa
+            let man = man | 0x0080_0000u32;
+            let mut half_man = man >> (14 - half_exp);
+            // Check for rounding (see comment above functions)
+            let round_bit = 1 << (13 - half_exp);
+            if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+                half_man += 1;
+            }
+            // No exponent for subnormals
+            return f16((half_sign | half_man) as u16);
+        }
+
+        // Rebias the exponent
+        let half_exp = (half_exp as u32) << 10;
+        let half_man = man >> 13;
+        // Check for rounding (see comment above functions)
+        let round_bit = 0x0000_1000u32;
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            // Round it
+            f16(((half_sign | half_exp | half_man) + 1) as u16)
+        } else {
+            f16((half_sign | half_exp | half_man) as u16)
+        }
+    }
+
+    /// Casts this `f16` to `f64`
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.to_f32() as f64
+    }
+
+    /// Casts an `f64` into `f16`
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Self::from_f32(value as f32)
+    }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().min(other.to_f32()))
+    }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self::from_f32(self.to_f32().max(other.to_f32()))
+    }
+}
+
+impl std::fmt::Debug for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", f16::to_f32(*self))
+    }
+}
+
+impl std::fmt::Display for f16 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", f16::to_f32(*self))
+    }
+}
+
+impl Neg for f16 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        f16(self.0 ^ 0x8000u16)
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($Trait:ident, $method:ident, $AssignTrait:ident, $assign_method:ident, $op:tt) => {
+        impl $Trait for f16 {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                Self::from_f32(self.to_f32() $op rhs.to_f32())
+            }
+        }
+
+        impl $AssignTrait for f16 {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, AddAssign, add_assign, +);
+impl_binary_op!(Sub, sub, SubAssign, sub_assign, -);
+impl_binary_op!(Mul, mul, MulAssign, mul_assign, *);
+impl_binary_op!(Div, div, DivAssign, div_assign, /);
+impl_binary_op!(Rem, rem, RemAssign, rem_assign, %);
+
+impl Sum for f16 {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        // Accumulate in single precision to not lose precision on every addition.
+        Self::from_f32(iter.map(f16::to_f32).sum())
+    }
+}
+
+impl Product for f16 {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::from_f32(iter.map(f16::to_f32).product())
+    }
+}
+
+impl Pow<f16> for f16 {
+    type Output = f16;
+
+    #[inline]
+    fn pow(self, rhs: f16) -> f16 {
+        Self::from_f32(f16::to_f32(self).powf(f16::to_f32(rhs)))
+    }
+}
+
+impl Zero for f16 {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 & 0x7FFFu16 == 0
+    }
+}
+
+impl One for f16 {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl Num for f16 {
+    type FromStrRadixErr = <f32 as Num>::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f32::from_str_radix(str, radix).map(Self::from_f32)
+    }
+}
+
+impl Bounded for f16 {
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl ToPrimitive for f16 {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        f16::to_f32(*self).to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        f16::to_f32(*self).to_u64()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        f16::to_f32(*self).to_i128()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        f16::to_f32(*self).to_u128()
+    }
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(f16::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(f16::to_f64(*self))
+    }
+}
+
+impl FromPrimitive for f16 {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::from_f32(n as f32))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from_f32(n as f32))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Self::from_f32(n as f32))
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Self::from_f32(n as f32))
+    }
+
+    #[inline]
+    fn from_f32(n: f32) -> Option<Self> {
+        Some(f16::from_f32(n))
+    }
+
+    #[inline]
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(f16::from_f64(n))
+    }
+}
+
+impl NumCast for f16 {
+    #[inline]
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_f32().map(Self::from_f32)
+    }
+}
+
+macro_rules! impl_as_primitive {
+    ($($T:ty),*) => {
+        $(
+            impl AsPrimitive<$T> for f16 {
+                #[inline]
+                fn as_(self) -> $T {
+                    self.to_f32() as $T
+                }
+            }
+
+            impl AsPrimitive<f16> for $T {
+                #[inline]
+                fn as_(self) -> f16 {
+                    f16::from_f32(self as f32)
+                }
+            }
+        )*
+    };
+}
+
+impl_as_primitive!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl AsPrimitive<f16> for f16 {
+    #[inline]
+    fn as_(self) -> f16 {
+        self
+    }
+}
+
+macro_rules! delegate_unary {
+    ($($method:ident),*) => {
+        $(
+            #[inline]
+            fn $method(self) -> Self {
+                Self::from_f32(self.to_f32().$method())
+            }
+        )*
+    };
+}
+
+macro_rules! delegate_binary {
+    ($($method:ident),*) => {
+        $(
+            #[inline]
+            fn $method(self, other: Self) -> Self {
+                Self::from_f32(self.to_f32().$method(other.to_f32()))
+            }
+        )*
+    };
+}
+
+impl Float for f16 {
+    delegate_unary!(
+        floor, ceil, round, trunc, fract, abs, signum, recip, sqrt, exp, exp2, ln, log2, log10,
+        cbrt, sin, cos, tan, asin, acos, atan, exp_m1, ln_1p, sinh, cosh, tanh, asinh, acosh,
+        atanh, to_degrees, to_radians
+    );
+    delegate_binary!(powf, log, max, min, hypot, atan2, copysign);
+
+    #[inline]
+    fn nan() -> Self {
+        Self::NAN
+    }
+
+    #[inline]
+    fn infinity() -> Self {
+        Self::INFINITY
+    }
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Self::NEG_INFINITY
+    }
+
+    #[inline]
+    fn neg_zero() -> Self {
+        Self::NEG_ZERO
+    }
+
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn min_positive_value() -> Self {
+        Self::MIN_POSITIVE
+    }
+
+    #[inline]
+    fn epsilon() -> Self {
+        Self::EPSILON
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+
+    #[inline]
+    fn is_nan(self) -> bool {
+        f16::is_nan(self)
+    }
+
+    #[inline]
+    fn is_infinite(self) -> bool {
+        self.0 & 0x7FFFu16 == 0x7C00u16
+    }
+
+    #[inline]
+    fn is_finite(self) -> bool {
+        f16::is_finite(self)
+    }
+
+    #[inline]
+    fn is_normal(self) -> bool {
+        self.to_f32().is_normal()
+    }
+
+    #[inline]
+    fn classify(self) -> std::num::FpCategory {
+        self.to_f32().classify()
+    }
+
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        self.0 & 0x8000u16 == 0
+    }
+
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        self.0 & 0x8000u16 != 0
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::from_f32(self.to_f32().mul_add(a.to_f32(), b.to_f32()))
+    }
+
+    #[inline]
+    fn powi(self, n: i32) -> Self {
+        Self::from_f32(self.to_f32().powi(n))
+    }
+
+    #[inline]
+    #[allow(deprecated)]
+    fn abs_sub(self, other: Self) -> Self {
+        Self::from_f32((self.to_f32() - other.to_f32()).max(0.0))
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.to_f32().sin_cos();
+        (Self::from_f32(sin), Self::from_f32(cos))
+    }
+
+    #[inline]
+    fn integer_decode(self) -> (u64, i16, i8) {
+        Float::integer_decode(self.to_f32())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for f16 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f32(f16::to_f32(*self))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for f16 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f32::deserialize(deserializer).map(Self::from_f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f16_to_f32() {
+        let f = f16::from_f32(7.0);
+        assert_eq!(f.to_f32(), 7.0f32);
+
+        // 7.1 is NOT exactly representable in 16-bit, it's rounded
+        let f = f16::from_f32(7.1);
+        let diff = (f.to_f32() - 7.1f32).abs();
+        // diff must be <= 4 * EPSILON, as 7 has two more significant bits than 1
+        assert!(diff <= 4.0 * f16::EPSILON.to_f32());
+
+        assert_eq!(f16(0x0000_0001).to_f32(), 2.0f32.powi(-24));
+        assert_eq!(f16(0x0000_0005).to_f32(), 5.0 * 2.0f32.powi(-24));
+
+        assert_eq!(f16(0x0000_0001), f16::from_f32(2.0f32.powi(-24)));
+        assert_eq!(f16(0x0000_0005), f16::from_f32(5.0 * 2.0f32.powi(-24)));
+
+        assert_eq!(format!("{}", f16::from_f32(7.0)), "7".to_string());
+        assert_eq!(format!("{:?}", f16::from_f32(7.0)), "7.0".to_string());
+    }
+
+    #[test]
+    fn test_f16_arithmetic() {
+        let a = f16::from_f32(1.5);
+        let b = f16::from_f32(2.25);
+        assert_eq!((a + b).to_f32(), 3.75);
+        assert_eq!((a * b).to_f32(), 3.375);
+        assert_eq!((-a).to_f32(), -1.5);
+        assert!(a < b);
+        assert!(f16::NAN.is_nan());
+        assert_eq!(f16::MAX.to_f32(), 65504.0);
+        assert_eq!(f16::from_f32(1e6), f16::INFINITY);
+    }
+}
@@ -53,6 +53,7 @@ pub mod vec;
 pub mod wasm;
 
 pub mod float;
+pub mod float16;
 pub mod index;
 pub mod io;
 #[cfg(feature = "mmap")]
@@ -43,6 +43,7 @@ macro_rules! impl_is_null (
 );
 
 impl_is_null!(bool);
+impl_is_null!(crate::float16::f16);
 impl_is_null!(f32);
 impl_is_null!(f64);
 impl_is_null!(i8);
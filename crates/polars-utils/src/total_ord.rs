@@ -3,6 +3,7 @@ use std::hash::{BuildHasher, Hash, Hasher};
 
 use bytemuck::TransparentWrapper;
 
+use crate::float16::{canonical_f16, f16};
 use crate::hashing::{BytesHash, DirtyHash};
 use crate::nulls::IsNull;
 
@@ -186,6 +187,13 @@ impl<T: IsNull> IsNull for TotalOrdWrap<T> {
     }
 }
 
+impl DirtyHash for f16 {
+    #[inline(always)]
+    fn dirty_hash(&self) -> u64 {
+        canonical_f16(*self).to_bits().dirty_hash()
+    }
+}
+
 impl DirtyHash for f32 {
     #[inline(always)]
     fn dirty_hash(&self) -> u64 {
@@ -334,9 +342,20 @@ macro_rules! impl_float_eq_ord {
     };
 }
 
+impl_float_eq_ord!(f16);
 impl_float_eq_ord!(f32);
 impl_float_eq_ord!(f64);
 
+impl TotalHash for f16 {
+    #[inline(always)]
+    fn tot_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        canonical_f16(*self).to_bits().hash(state)
+    }
+}
+
 impl TotalHash for f32 {
     #[inline(always)]
     fn tot_hash<H>(&self, state: &mut H)
@@ -572,6 +591,7 @@ macro_rules! impl_to_total_ord_wrapped {
     };
 }
 
+impl_to_total_ord_wrapped!(f16);
 impl_to_total_ord_wrapped!(f32);
 impl_to_total_ord_wrapped!(f64);
 
@@ -355,7 +355,7 @@ fn test_join_categorical() {
         );
     }
 
-    // Test error when joining on different string cache
+    // Joining on a different string cache remaps the physical codes
     let (mut df_a, mut df_b) = get_dfs();
     df_a.try_apply("b", |s| {
         s.cast(&DataType::Categorical(None, Default::default()))
@@ -370,8 +370,30 @@ fn test_join_categorical() {
         s.cast(&DataType::Categorical(None, Default::default()))
     })
     .unwrap();
-    let out = df_a.join(&df_b, ["b"], ["bar"], JoinType::Left.into(), None);
-    assert!(out.is_err());
+    let out = df_a
+        .join(&df_b, ["b"], ["bar"], JoinType::Left.into(), None)
+        .unwrap();
+    let ham_col = out.column("ham").unwrap();
+    assert_eq!(Vec::from(ham_col.str().unwrap()), correct_ham);
+
+    // And so does joining local categoricals
+    drop(_sc);
+    let (mut df_a, mut df_b) = get_dfs();
+    df_a.try_apply("b", |s| {
+        s.cast(&DataType::Categorical(None, Default::default()))
+    })
+    .unwrap();
+    df_b.try_apply("bar", |s| {
+        s.reverse()
+            .cast(&DataType::Categorical(None, Default::default()))
+            .map(|s| s.reverse())
+    })
+    .unwrap();
+    let out = df_a
+        .join(&df_b, ["b"], ["bar"], JoinType::Left.into(), None)
+        .unwrap();
+    let ham_col = out.column("ham").unwrap();
+    assert_eq!(Vec::from(ham_col.str().unwrap()), correct_ham);
 }
 
 #[test]
@@ -688,3 +710,36 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn test_join_uuid() -> PolarsResult<()> {
+    let ids = [
+        "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "00000000-0000-0000-0000-000000000001",
+        "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+    ];
+    let left = df!["id" => ids, "a" => [1, 2, 3]]?
+        .lazy()
+        .with_column(col("id").cast(DataType::Uuid));
+    let right = df!["id" => [ids[2], ids[0]], "b" => ["x", "y"]]?
+        .lazy()
+        .with_column(col("id").cast(DataType::Uuid));
+    let out = left
+        .join(
+            right,
+            [col("id")],
+            [col("id")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort(["a"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("id")?.dtype(), &DataType::Uuid);
+    let expected = df!["a" => [1, 3], "b" => ["y", "x"]]?;
+    assert!(out.drop("id")?.equals(&expected));
+
+    // Uuids can be compared with string literals.
+    let out = out.lazy().filter(col("id").eq(lit(ids[2]))).collect()?;
+    assert_eq!(out.column("a")?.i32()?.get(0), Some(3));
+    Ok(())
+}
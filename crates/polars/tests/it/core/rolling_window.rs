@@ -280,3 +280,107 @@ fn test_rolling_var() {
         "{out:?} is not approximately equal to {exp_res:?}"
     );
 }
+
+#[test]
+fn test_rolling_window_kernels() -> PolarsResult<()> {
+    let s = Series::new(
+        "a".into(),
+        &[Some(3), Some(1), None, Some(3), Some(2), Some(2)],
+    );
+    let options = RollingOptionsFixedWindow {
+        window_size: 3,
+        min_periods: 1,
+        ..Default::default()
+    };
+
+    let out = s.rolling_n_unique(options.clone())?;
+    assert_eq!(out.dtype(), &IDX_DTYPE);
+    let out = out.idx()?.into_no_null_iter().collect::<Vec<_>>();
+    assert_eq!(out, &[1, 2, 2, 2, 2, 2]);
+
+    let out = s.rolling_mode(options.clone())?;
+    assert_eq!(
+        Vec::from(out.i32()?),
+        &[Some(3), Some(1), Some(1), Some(1), Some(2), Some(2)]
+    );
+
+    let out = s.rolling_arg_max(options.clone())?;
+    let out = out.idx()?.into_no_null_iter().collect::<Vec<_>>();
+    assert_eq!(out, &[0, 0, 0, 2, 1, 0]);
+
+    let out = s.rolling_first(options.clone())?;
+    assert_eq!(
+        Vec::from(out.i32()?),
+        &[Some(3), Some(3), Some(3), Some(1), Some(3), Some(3)]
+    );
+
+    let out = s.rolling_last(options.clone())?;
+    assert_eq!(
+        Vec::from(out.i32()?),
+        &[Some(3), Some(1), Some(1), Some(3), Some(2), Some(2)]
+    );
+
+    let out = s.rolling_product(options.clone())?;
+    assert_eq!(out.dtype(), &DataType::Int64);
+    assert_eq!(
+        Vec::from(out.i64()?),
+        &[Some(3), Some(3), Some(3), Some(3), Some(6), Some(12)]
+    );
+
+    let out = s.rolling_rank(RollingOptionsFixedWindow {
+        fn_params: Some(RollingFnParams::Rank {
+            method: RollingRankMethod::Max,
+        }),
+        ..options.clone()
+    })?;
+    assert_eq!(
+        Vec::from(out.idx()?),
+        &[Some(1), Some(1), None, Some(2), Some(1), Some(2)]
+    );
+
+    let options = RollingOptionsFixedWindow {
+        weights: Some(vec![1.0, 1.0, 1.0]),
+        ..options
+    };
+    assert!(s.rolling_n_unique(options).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rolling_window_by")]
+fn test_rolling_window_kernels_by() -> PolarsResult<()> {
+    let s = Series::new("a".into(), &[1.0, 4.0, 2.0, 4.0, 3.0]);
+    let by = Series::new("by".into(), &[0i64, 1, 5, 2, 3]);
+    let options = RollingOptionsDynamicWindow {
+        window_size: Duration::parse("2i"),
+        min_periods: 1,
+        closed_window: ClosedWindow::Right,
+        fn_params: None,
+    };
+
+    // Sorted by `by`, the values are [1.0, 4.0, 4.0, 3.0, 2.0].
+    let out = s.rolling_arg_min_by(&by, options.clone())?;
+    assert_eq!(
+        Vec::from(out.idx()?),
+        &[Some(0), Some(0), Some(0), Some(0), Some(1)]
+    );
+
+    let out = s.rolling_product_by(&by, options.clone())?;
+    assert_eq!(
+        Vec::from(out.f64()?),
+        &[Some(1.0), Some(4.0), Some(2.0), Some(16.0), Some(12.0)]
+    );
+
+    let out = s.rolling_rank_by(&by, options.clone())?;
+    assert_eq!(
+        Vec::from(out.f64()?),
+        &[Some(1.0), Some(2.0), Some(1.0), Some(1.5), Some(1.0)]
+    );
+
+    let out = s.rolling_n_unique_by(&by, options)?;
+    assert_eq!(
+        Vec::from(out.idx()?),
+        &[Some(1), Some(2), Some(1), Some(1), Some(2)]
+    );
+    Ok(())
+}
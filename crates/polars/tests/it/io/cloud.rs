@@ -0,0 +1,89 @@
+use polars::io::HiveOptions;
+use polars::io::cloud::{CloudOptions, clear_memory_bucket};
+use polars::prelude::*;
+
+fn sorted(df: DataFrame) -> PolarsResult<DataFrame> {
+    df.sort(["a", "b"], SortMultipleOptions::default())
+}
+
+#[test]
+fn test_memory_parquet_round_trip() -> PolarsResult<()> {
+    let df = df!("a" => [1, 2, 3], "b" => ["x", "y", "z"])?;
+    let cloud_options =
+        CloudOptions::from_untyped_config("memory://test-it-round-trip", [("latency_ms", "1")])?;
+
+    for path in [
+        "memory://test-it-round-trip/data/0.parquet",
+        "memory://test-it-round-trip/data/1.parquet",
+    ] {
+        df.clone()
+            .lazy()
+            .sink_parquet(
+                SinkTarget::Path(Arc::new(path.into())),
+                ParquetWriteOptions::default(),
+                Some(cloud_options.clone()),
+                SinkOptions::default(),
+            )?
+            .collect_with_engine(Engine::Streaming)?;
+    }
+
+    let args = ScanArgsParquet {
+        cloud_options: Some(cloud_options),
+        ..Default::default()
+    };
+    let out =
+        LazyFrame::scan_parquet("memory://test-it-round-trip/data/*.parquet", args)?.collect()?;
+    assert!(sorted(out)?.equals(&sorted(df.vstack(&df)?)?));
+
+    clear_memory_bucket("test-it-round-trip");
+    Ok(())
+}
+
+#[test]
+fn test_memory_partitioned_dynamic_overwrite() -> PolarsResult<()> {
+    let base_path = "memory://test-it-partitioned/data";
+    let sink = |df: DataFrame, overwrite_mode| {
+        df.lazy()
+            .sink_parquet_partitioned(
+                Arc::new(base_path.into()),
+                None,
+                PartitionPathOptions {
+                    template: None,
+                    overwrite_mode,
+                },
+                PartitionVariant::ByKey {
+                    key_exprs: vec![col("a")],
+                    include_key: true,
+                },
+                ParquetWriteOptions::default(),
+                None,
+                SinkOptions::default(),
+                None,
+                None,
+            )?
+            .collect_with_engine(Engine::Streaming)
+    };
+    let scan = || {
+        let args = ScanArgsParquet {
+            hive_options: HiveOptions::new_disabled(),
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(format!("{base_path}/**/*.parquet"), args)?.collect()
+    };
+
+    sink(
+        df!("a" => [1, 1, 2], "b" => [1, 2, 3])?,
+        PartitionOverwriteMode::Append,
+    )?;
+    sink(
+        df!("a" => [1, 3], "b" => [4, 5])?,
+        PartitionOverwriteMode::Dynamic,
+    )?;
+
+    // Partition `a=1` is replaced, `a=2` is kept and `a=3` is added.
+    let expected = df!("a" => [1, 2, 3], "b" => [4, 3, 5])?;
+    assert!(sorted(scan()?)?.equals(&sorted(expected)?));
+
+    clear_memory_bucket("test-it-partitioned");
+    Ok(())
+}
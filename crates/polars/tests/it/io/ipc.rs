@@ -46,6 +46,146 @@ fn write_and_read_ipc() {
     assert!(df.equals(&df_read));
 }
 
+#[test]
+#[cfg(all(feature = "dtype-u128", feature = "dtype-f16"))]
+fn write_and_read_ipc_u128_f16() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df![
+        "a" => [Some(1u128), None, Some(u128::MAX)],
+        "b" => [Some(f16::from_f32(0.25)), Some(f16::from_f32(-1.0)), None],
+    ]
+    .unwrap();
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.dtypes(), &[DataType::UInt128, DataType::Float16]);
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn write_and_read_ipc_interval() {
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let intervals = IntervalChunked::from_intervals(
+        "a".into(),
+        [
+            Some(months_days_ns::new(1, -2, 3)),
+            None,
+            Some(months_days_ns::new(-14, 0, i64::MAX)),
+        ],
+    );
+    let mut df = DataFrame::new(vec![intervals.into_series().into()]).unwrap();
+
+    IpcWriter::new(&mut buf)
+        .finish(&mut df)
+        .expect("ipc writer");
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish().unwrap();
+    assert_eq!(df_read.dtypes(), &[DataType::Interval]);
+    assert!(df.equals_missing(&df_read));
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn write_and_read_ipc_extension() -> PolarsResult<()> {
+    let labels = Series::new("a".into(), ["x", "y", "z"]);
+    let labels = ExtensionChunked::new(labels, "example.label".into(), Some("{}".into()));
+    let dtype = labels.dtype().clone();
+    let df = DataFrame::new(vec![
+        labels.into_series().into(),
+        Column::new("b".into(), [1, 2, 3]),
+    ])?;
+
+    // Selections, filters, concatenation and joins keep the extension type.
+    let filtered = df.filter(&df.column("b")?.as_materialized_series().gt(1)?)?;
+    let mut stacked = df.vstack(&filtered)?;
+    let joined = df.inner_join(&filtered, ["a"], ["a"])?;
+    assert_eq!(joined.height(), 2);
+    for out in [&filtered, &stacked, &joined, &df.select(["a"])?] {
+        assert_eq!(out.column("a")?.dtype(), &dtype);
+    }
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut stacked)?;
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish()?;
+    assert_eq!(df_read.dtypes(), &[dtype, DataType::Int32]);
+    assert!(stacked.equals_missing(&df_read));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-union")]
+fn write_and_read_ipc_union() -> PolarsResult<()> {
+    use arrow::array::{PrimitiveArray, UnionArray, Utf8ViewArray};
+    use arrow::datatypes::{ArrowDataType, Field, UnionMode, UnionType};
+
+    let fields = vec![
+        Field::new("i".into(), ArrowDataType::Int64, true),
+        Field::new("s".into(), ArrowDataType::Utf8View, true),
+    ];
+    let dtype = ArrowDataType::Union(Box::new(UnionType {
+        fields,
+        ids: None,
+        mode: UnionMode::Dense,
+    }));
+    let arr = UnionArray::new(
+        dtype,
+        vec![0, 1, 0, 1].into(),
+        vec![
+            PrimitiveArray::<i64>::from([Some(1), None]).boxed(),
+            Utf8ViewArray::from_slice_values(["a", "b"]).boxed(),
+        ],
+        Some(vec![0, 0, 1, 1].into()),
+    );
+    let union = Series::try_from((PlSmallStr::from_static("u"), arr.boxed()))?;
+    let dtype = DataType::Union(vec![
+        polars::prelude::Field::new("i".into(), DataType::Int64),
+        polars::prelude::Field::new("s".into(), DataType::String),
+    ]);
+    assert_eq!(union.dtype(), &dtype);
+    assert_eq!(union.null_count(), 1);
+    assert_eq!(union.get(1)?, AnyValue::String("a"));
+
+    let df = DataFrame::new(vec![union.into(), Column::new("b".into(), [1, 2, 3, 4])])?;
+    let filtered = df.filter(&df.column("b")?.as_materialized_series().gt(2)?)?;
+    let taken = df.take(&IdxCa::new("".into(), [3, 0]))?;
+    let mut stacked = df.vstack(&filtered)?.vstack(&taken)?;
+    for out in [&filtered, &taken, &stacked, &df.select(["u"])?] {
+        assert_eq!(out.column("u")?.dtype(), &dtype);
+    }
+    assert_eq!(taken.column("u")?.get(0)?, AnyValue::String("b"));
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    IpcWriter::new(&mut buf).finish(&mut stacked)?;
+    buf.set_position(0);
+
+    let df_read = IpcReader::new(buf).finish()?;
+    assert_eq!(df_read.dtypes(), &[dtype, DataType::Int32]);
+    assert!(stacked.equals_missing(&df_read));
+
+    #[cfg(feature = "lazy")]
+    {
+        let out = df_read
+            .lazy()
+            .select([
+                col("u").union_().type_id().alias("type_id"),
+                col("u").union_().field("s"),
+            ])
+            .collect()?;
+        assert_eq!(out.column("type_id")?.dtype(), &DataType::Int8);
+        assert_eq!(out.column("s")?.str()?.get(1), Some("a"));
+        assert_eq!(out.column("s")?.null_count(), 4);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_read_ipc_with_projection() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -14,6 +14,9 @@ mod ipc;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
 
+#[cfg(all(feature = "cloud", feature = "parquet", feature = "lazy"))]
+mod cloud;
+
 use polars::prelude::*;
 
 pub(crate) fn create_df() -> DataFrame {
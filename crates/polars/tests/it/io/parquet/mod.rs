@@ -206,3 +206,102 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "dtype-u128", feature = "dtype-f16"))]
+fn test_parquet_u128_f16() -> PolarsResult<()> {
+    let mut df = df! {
+        "a" => [Some(1u128), None, Some(u128::MAX)],
+        "b" => [Some(f16::from_f32(0.25)), Some(f16::NAN), None],
+    }?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.dtypes(), &[DataType::UInt128, DataType::Float16]);
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn test_parquet_extension() -> PolarsResult<()> {
+    let storage = Series::new("a".into(), [Some(1i64), None, Some(3)]);
+    let ext = ExtensionChunked::new(storage, "example.meters".into(), None);
+    let dtype = ext.dtype().clone();
+    let mut df = DataFrame::new(vec![ext.into_series().into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.dtypes(), &[dtype]);
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn test_parquet_interval() -> PolarsResult<()> {
+    let intervals = IntervalChunked::from_intervals(
+        "a".into(),
+        [
+            Some(months_days_ns::new(1, -2, 3)),
+            None,
+            Some(months_days_ns::new(-14, 0, i64::MAX)),
+        ],
+    );
+    let mut df = DataFrame::new(vec![intervals.into_series().into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.dtypes(), &[DataType::Interval]);
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn test_parquet_fixed_size_binary_and_uuid() -> PolarsResult<()> {
+    let values: [Option<&[u8]>; 3] = [Some(b"abcd"), None, Some(b"wxyz")];
+    let fsb = FixedSizeBinaryChunked::from_iter_options("fsb".into(), 4, values)?;
+    let uuids = Series::new(
+        "uuid".into(),
+        [
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            Some("00000000-0000-0000-0000-000000000001"),
+            None,
+        ],
+    )
+    .cast(&DataType::Uuid)?;
+    let mut df = DataFrame::new(vec![fsb.into_series().into(), uuids.into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(
+        read_df.dtypes(),
+        &[DataType::FixedSizeBinary(4), DataType::Uuid]
+    );
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-json")]
+fn test_parquet_json() -> PolarsResult<()> {
+    use polars_parquet::parquet::schema::types::PrimitiveLogicalType;
+
+    let json = Series::new("json".into(), [Some(r#"{"a": 1}"#), None, Some("[1, 2]")])
+        .strict_cast(&DataType::Json)?;
+    let mut df = DataFrame::new(vec![json.into()])?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+    let mut reader = ParquetReader::new(buf);
+    let logical_type = reader.get_metadata()?.schema().columns()[0]
+        .descriptor
+        .primitive_type
+        .logical_type;
+    assert_eq!(logical_type, Some(PrimitiveLogicalType::Json));
+    let read_df = reader.finish()?;
+    assert_eq!(read_df.dtypes(), &[DataType::Json]);
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
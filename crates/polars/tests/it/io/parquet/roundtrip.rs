@@ -1,9 +1,10 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
-use arrow::array::{ArrayRef, Utf8ViewArray};
-use arrow::datatypes::{ArrowSchema, Field};
+use arrow::array::{ArrayRef, PrimitiveArray, Utf8ViewArray};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit};
 use arrow::record_batch::RecordBatchT;
+use arrow::types::{f16, months_days_ns};
 use polars_error::PolarsResult;
 use polars_parquet::arrow::write::{FileWriter, WriteOptions};
 use polars_parquet::read::read_metadata;
@@ -88,3 +89,34 @@ fn roundtrip_binview() -> PolarsResult<()> {
         ],
     )
 }
+
+#[test]
+fn roundtrip_u128_f16_interval() -> PolarsResult<()> {
+    let u128s = PrimitiveArray::<u128>::from([Some(0), Some(u128::MAX), None, Some(1 << 100)]);
+    let f16s = PrimitiveArray::<f16>::from([
+        Some(f16::from_f32(0.5)),
+        None,
+        Some(f16::NEG_INFINITY),
+        Some(f16::from_f32(-3.25)),
+    ]);
+
+    let intervals = PrimitiveArray::<months_days_ns>::from([
+        Some(months_days_ns::new(1, 2, 3)),
+        None,
+        Some(months_days_ns::new(-1, i32::MIN, i64::MAX)),
+    ])
+    .to(ArrowDataType::Interval(IntervalUnit::MonthDayNano));
+
+    for array in [u128s.boxed(), f16s.boxed(), intervals.boxed()] {
+        round_trip(
+            &array,
+            Version::V2,
+            CompressionOptions::Uncompressed,
+            vec![
+                FieldWriteOptions::default_with_encoding(Encoding::Plain)
+                    .into_default_column_write_options(),
+            ],
+        )?;
+    }
+    Ok(())
+}
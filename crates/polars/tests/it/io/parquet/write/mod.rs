@@ -260,6 +260,38 @@ fn test_parquet_datetime_round_trip() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_parquet_map_round_trip() -> PolarsResult<()> {
+    use std::io::{Cursor, Seek, SeekFrom};
+
+    let entries = |keys: &[&str], values: &[i32]| {
+        let keys = Series::new("key".into(), keys);
+        let values = Series::new("value".into(), values);
+        StructChunked::from_series("".into(), keys.len(), [keys, values].iter())
+            .unwrap()
+            .into_series()
+    };
+    let ca: ListChunked = [
+        Some(entries(&["a", "b"], &[1, 2])),
+        None,
+        Some(entries(&[], &[])),
+        Some(entries(&["c"], &[3])),
+    ]
+    .into_iter()
+    .collect();
+    let mut df = DataFrame::new(vec![ca.into_map()?.into_series().into()])?;
+
+    let mut f = Cursor::new(vec![]);
+    ParquetWriter::new(&mut f).finish(&mut df)?;
+    f.seek(SeekFrom::Start(0))?;
+
+    let read = ParquetReader::new(f).finish()?;
+    assert_eq!(read.schema(), df.schema());
+    assert!(read.equals_missing(&df));
+    Ok(())
+}
+
 #[test]
 fn test_read_parquet_with_projection() {
     let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
@@ -18,3 +18,24 @@ fn test_is_in() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "dtype-categorical")]
+fn test_is_in_categorical_remaps_physicals() -> PolarsResult<()> {
+    // Both sides build their own local mapping, so equal categories have different physicals.
+    let cat = |v: &[&str]| {
+        Series::new("a".into(), v).cast(&DataType::Categorical(None, Default::default()))
+    };
+    let df = DataFrame::new(vec![cat(&["a", "b", "c", "a"])?.into()])?;
+    let other = cat(&["d", "c", "a"])?;
+
+    let out = df
+        .lazy()
+        .select([col("a").is_in(lit(other).implode(), false)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("a")?.bool()?),
+        &[Some(true), Some(false), Some(true), Some(true)]
+    );
+    Ok(())
+}
@@ -24,3 +24,385 @@ fn test_format_str() {
 
     assert!(out.equals_missing(&expected));
 }
+
+#[test]
+#[cfg(feature = "dtype-uuid")]
+fn test_uuid4() -> PolarsResult<()> {
+    let df = df!["a" => [1, 2, 3]]?;
+    let out = df.lazy().select([uuid4(Some(0)), col("a")]).collect()?;
+    let uuids = out.column("uuid")?.uuid()?;
+    assert_eq!(uuids.dtype(), &DataType::Uuid);
+    assert_eq!(uuids.len(), 3);
+    assert_eq!(uuids.n_unique()?, 3);
+    assert!((0..uuids.len()).all(|i| uuids.get_uuid(i).unwrap().get_version_num() == 4));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-json")]
+fn test_json_get() -> PolarsResult<()> {
+    let df = df!["doc" => [r#"{"a": {"b": 1}}"#, r#"{"a": {"b": 2, "c": [1, 2]}}"#]]?;
+    let doc = col("doc").strict_cast(DataType::Json);
+    let out = df
+        .lazy()
+        .select([
+            doc.clone().json().get("$.a.b", DataType::Int64).alias("b"),
+            doc.clone().json().get("$.a.c", DataType::Json).alias("c"),
+            doc.json().pretty().alias("pretty"),
+        ])
+        .collect()?;
+    assert_eq!(
+        out.dtypes(),
+        &[DataType::Int64, DataType::Json, DataType::Json]
+    );
+    assert_eq!(Vec::from(out.column("b")?.i64()?), &[Some(1), Some(2)]);
+    assert_eq!(out.column("c")?.get(1)?, AnyValue::String("[1,2]"));
+    assert_eq!(
+        out.column("pretty")?.get(0)?,
+        AnyValue::String("{\n  \"a\": {\n    \"b\": 1\n  }\n}")
+    );
+
+    let invalid = df!["doc" => ["{"]]?
+        .lazy()
+        .select([col("doc").strict_cast(DataType::Json)])
+        .collect();
+    assert!(invalid.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "string_similarity")]
+fn test_string_similarity() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some("kitten"), Some("martha"), Some("dixon"), None],
+        "b" => [Some("sitting"), Some("marhta"), Some("dicksonx"), Some("x")],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a").str().levenshtein(col("b"), None).alias("lev"),
+            col("a")
+                .str()
+                .levenshtein(col("b"), Some(2))
+                .alias("lev_max"),
+            col("a")
+                .str()
+                .damerau_levenshtein(col("b"), None)
+                .alias("dl"),
+            col("a").str().hamming(lit("marhta"), None).alias("ham"),
+            col("a").str().jaro_winkler(col("b")).alias("jw"),
+            col("a").str().jaccard_ngram(col("b"), 2).alias("jaccard"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("lev")?.u32()?),
+        &[Some(3), Some(2), Some(4), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("lev_max")?.u32()?),
+        &[Some(3), Some(2), Some(3), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("dl")?.u32()?),
+        &[Some(3), Some(1), Some(4), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("ham")?.u32()?),
+        &[Some(6), Some(2), None, None]
+    );
+    assert_eq!(out.column("jw")?.dtype(), &DataType::Float64);
+    assert_eq!(out.column("jaccard")?.f64()?.get(1), Some(0.25));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "string_tokenize")]
+fn test_string_tokenize() -> PolarsResult<()> {
+    let df = df!["text" => [Some("Hello, world!"), Some("It's a fine day"), None]]?;
+    let out = df
+        .clone()
+        .lazy()
+        .with_columns([
+            col("text").str().tokenize().alias("tokens"),
+            col("text").str().count_tokens().alias("count"),
+            col("text").str().word_ngrams(2).alias("bigrams"),
+            col("text")
+                .str()
+                .remove_stop_words(vec!["a".into(), "It's".into()])
+                .alias("filtered"),
+        ])
+        .collect()?;
+    let list_dtype = DataType::List(Box::new(DataType::String));
+    assert_eq!(
+        out.dtypes(),
+        &[
+            DataType::String,
+            list_dtype.clone(),
+            DataType::UInt32,
+            list_dtype.clone(),
+            list_dtype.clone()
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("count")?.u32()?),
+        &[Some(2), Some(4), None]
+    );
+    let bigrams = out.column("bigrams")?.list()?.get_as_series(1).unwrap();
+    assert_eq!(
+        Vec::from(bigrams.str()?),
+        &[Some("It's a"), Some("a fine"), Some("fine day")]
+    );
+    let filtered = out.column("filtered")?.list()?.get_as_series(1).unwrap();
+    assert_eq!(Vec::from(filtered.str()?), &[Some("fine"), Some("day")]);
+
+    let out = df!["text" => ["a1b22c", "polars"]]?
+        .lazy()
+        .with_columns([
+            col("text").str().split_regex(lit(r"\d+")).alias("split"),
+            col("text").str().char_ngrams(3).alias("trigrams"),
+        ])
+        .collect()?;
+    let split = out.column("split")?.list()?.get_as_series(0).unwrap();
+    assert_eq!(Vec::from(split.str()?), &[Some("a"), Some("b"), Some("c")]);
+    let trigrams = out.column("trigrams")?.list()?.get_as_series(1).unwrap();
+    assert_eq!(trigrams.len(), 4);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "stable_hash")]
+fn test_stable_hash() -> PolarsResult<()> {
+    let df = df![
+        "s" => [Some("abc"), Some("iceberg"), None],
+        "i" => [1i64, 2, 3],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("s").binary().md5(true).alias("md5"),
+            col("s").binary().sha256(false).alias("sha256"),
+            col("s").binary().murmur3_32(0, false).alias("murmur3"),
+            col("s").binary().xxh3_64(0).alias("xxh3"),
+            col("s")
+                .cast(DataType::Binary)
+                .binary()
+                .xxh3_64(0)
+                .alias("xxh3_bin"),
+        ])
+        .collect()?;
+    assert_eq!(
+        out.column("md5")?.str()?.get(0),
+        Some("900150983cd24fb0d6963f7d28e17f72")
+    );
+    assert_eq!(out.column("md5")?.null_count(), 1);
+    assert_eq!(out.column("sha256")?.dtype(), &DataType::Binary);
+    assert_eq!(out.column("sha256")?.binary()?.get(0).unwrap().len(), 32);
+    assert_eq!(out.column("murmur3")?.i32()?.get(1), Some(1210000089));
+    assert_eq!(
+        Vec::from(out.column("xxh3")?.u64()?),
+        Vec::from(out.column("xxh3_bin")?.u64()?)
+    );
+
+    let hashes = df
+        .clone()
+        .lazy()
+        .select([stable_hash_rows([all()], 42)?.alias("h")])
+        .collect()?;
+    assert_eq!(hashes.shape(), (3, 1));
+    let again = df
+        .lazy()
+        .select([stable_hash_rows([col("s"), col("i")], 42)?.alias("h")])
+        .collect()?;
+    assert!(hashes.equals_missing(&again));
+
+    let err = df!["i" => [1]]?
+        .lazy()
+        .select([col("i").binary().md5(false)])
+        .collect();
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "net", feature = "dtype-struct"))]
+fn test_url_and_ip() -> PolarsResult<()> {
+    let df = df![
+        "url" => [Some("https://pola.rs:443/docs?q=a+b#top"), Some("/search"), None],
+        "ip" => [Some("10.1.2.3"), Some("2001:db8::1"), Some("invalid")],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("url").url().parse().struct_().field_by_name("host"),
+            col("url").url().parse().struct_().field_by_name("port"),
+            col("url").url().query_param("q"),
+            col("ip").ip().to_int(false).alias("v4"),
+            col("ip")
+                .ip()
+                .to_int(true)
+                .ip()
+                .to_string()
+                .alias("round_trip"),
+            col("ip").ip().in_network("10.0.0.0/8").alias("internal"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("host")?.str()?),
+        &[Some("pola.rs"), None, None]
+    );
+    assert_eq!(out.column("port")?.u16()?.get(0), Some(443));
+    assert_eq!(
+        Vec::from(out.column("url")?.str()?),
+        &[Some("a b"), None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("v4")?.u32()?),
+        &[Some(0x0a01_0203), None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("round_trip")?.str()?),
+        &[Some("10.1.2.3"), Some("2001:db8::1"), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("internal")?.bool()?),
+        &[Some(true), Some(false), None]
+    );
+
+    let err = df!["ip" => [1.0]]?
+        .lazy()
+        .select([col("ip").ip().to_string()])
+        .collect();
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "search_sorted", feature = "top_k"))]
+fn test_list_sort_and_search() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Series::new("".into(), [3, 1, 2]), Series::new("".into(), [5, 4])],
+        "by" => [Series::new("".into(), ["c", "a", "b"]), Series::new("".into(), ["y", "z"])],
+        "v" => [2, 9],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("a")
+                .list()
+                .sort_by(
+                    col("by"),
+                    SortOptions::default().with_order_descending(true),
+                )
+                .alias("sort_by"),
+            col("a")
+                .list()
+                .arg_sort(SortOptions::default())
+                .alias("arg_sort"),
+            col("a")
+                .list()
+                .sort(SortOptions::default())
+                .list()
+                .search_sorted(col("v"), SearchSortedSide::Left, false)
+                .alias("search_sorted"),
+            col("a").list().top_k(lit(2)).alias("top_k"),
+        ])
+        .collect()?;
+
+    let sort_by = out.column("sort_by")?.list()?;
+    assert_eq!(
+        Vec::from(sort_by.get_as_series(0).unwrap().i32()?),
+        &[Some(3), Some(2), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(sort_by.get_as_series(1).unwrap().i32()?),
+        &[Some(4), Some(5)]
+    );
+    let arg_sort = out.column("arg_sort")?.list()?;
+    assert_eq!(arg_sort.inner_dtype(), &IDX_DTYPE);
+    assert_eq!(
+        Vec::from(arg_sort.get_as_series(0).unwrap().idx()?),
+        &[Some(1), Some(2), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("search_sorted")?.idx()?),
+        &[Some(1), Some(2)]
+    );
+    let top_k = out.column("top_k")?.list()?;
+    assert_eq!(
+        Vec::from(top_k.get_as_series(0).unwrap().i32()?),
+        &[Some(3), Some(2)]
+    );
+
+    let err = df![
+        "a" => [Series::new("".into(), [3, 1, 2])],
+        "by" => [Series::new("".into(), [1, 2])],
+    ]?
+    .lazy()
+    .select([col("a").list().sort_by(col("by"), SortOptions::default())])
+    .collect();
+    assert!(err.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "list_zip", feature = "dtype-struct"))]
+fn test_list_zip() -> PolarsResult<()> {
+    let a = Series::new(
+        "a".into(),
+        [
+            Some(Series::new("".into(), [0i32])),
+            Some(Series::new("".into(), [1i32, 2])),
+            None,
+            Some(Series::new("".into(), [3i32])),
+        ],
+    );
+    let b = Series::new(
+        "b".into(),
+        [
+            Series::new("".into(), ["x"]),
+            Series::new("".into(), ["y", "z"]),
+            Series::new("".into(), ["w"]),
+            Series::new("".into(), ["v"]),
+        ],
+    );
+    // Slice to have sublists that don't start at offset 0.
+    let df = DataFrame::new(vec![a.into(), b.into()])?.slice(1, 3);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("a").list().zip([col("b")]).alias("zip"),
+            col("a").list().enumerate().alias("enumerate"),
+        ])
+        .collect()?;
+    let zip = out.column("zip")?.list()?;
+    assert_eq!(zip.len(), 3);
+    let row = zip.get_as_series(0).unwrap();
+    let fields = row.struct_()?.fields_as_series();
+    assert_eq!(fields[0].name().as_str(), "a");
+    assert_eq!(Vec::from(fields[0].i32()?), &[Some(1), Some(2)]);
+    assert_eq!(Vec::from(fields[1].str()?), &[Some("y"), Some("z")]);
+    assert!(zip.get_as_series(1).is_none());
+
+    let enumerate = out.column("enumerate")?.list()?;
+    let row = enumerate.get_as_series(0).unwrap();
+    let index = row.struct_()?.field_by_name("index")?;
+    assert_eq!(Vec::from(index.idx()?), &[Some(0), Some(1)]);
+
+    let unzipped = out
+        .lazy()
+        .select([col("zip").list().unzip()])
+        .unnest(["zip"])
+        .collect()?;
+    assert!(unzipped.column("a")?.equals_missing(df.column("a")?));
+    assert_eq!(unzipped.column("b")?.null_count(), 1);
+
+    let err = df
+        .lazy()
+        .select([col("a").list().zip([col("b").list().head(lit(0))])])
+        .collect();
+    assert!(err.is_err());
+    Ok(())
+}
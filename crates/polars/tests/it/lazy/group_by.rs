@@ -158,6 +158,123 @@ fn test_logical_mean_partitioned_group_by_block() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "dtype-decimal", feature = "new_streaming"))]
+fn test_decimal_streaming_reductions_are_exact() -> PolarsResult<()> {
+    let df = df![
+        "g" => [1, 1, 1, 2],
+        "x" => [10i128, 20, 40, 12_345_678_901_234_567_890_123],
+    ]?;
+
+    for engine in [Engine::InMemory, Engine::Streaming] {
+        let out = df
+            .clone()
+            .lazy()
+            .with_column(col("x").cast(DataType::Decimal(Some(38), Some(2))))
+            .group_by([col("g")])
+            .agg([
+                col("x").sum().alias("sum"),
+                col("x").mean().alias("mean"),
+                col("x").var(1).alias("var"),
+                col("x").std(1).alias("std"),
+            ])
+            .sort(["g"], Default::default())
+            .collect_with_engine(engine)?;
+
+        assert_eq!(
+            out.column("sum")?.dtype(),
+            &DataType::Decimal(Some(38), Some(2))
+        );
+        assert_eq!(
+            out.column("sum")?.get(1)?,
+            AnyValue::Decimal(1_234_567_890_123_456_789_012_300, 2)
+        );
+        // The mean, variance and standard deviation are rounded to at least 6 decimals.
+        for name in ["mean", "var", "std"] {
+            assert_eq!(
+                out.column(name)?.dtype(),
+                &DataType::Decimal(Some(38), Some(6))
+            );
+        }
+        // 70 / 3, 700 / 3 and sqrt(700 / 3).
+        assert_eq!(
+            out.column("mean")?.get(0)?,
+            AnyValue::Decimal(23_333_333, 6)
+        );
+        assert_eq!(
+            out.column("var")?.get(0)?,
+            AnyValue::Decimal(233_333_333, 6)
+        );
+        assert_eq!(out.column("std")?.get(0)?, AnyValue::Decimal(15_275_252, 6));
+        assert_eq!(out.column("var")?.get(1)?, AnyValue::Null);
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "dtype-categorical",
+    feature = "new_streaming",
+    feature = "semi_anti_join"
+))]
+fn test_streaming_keys_remap_local_categoricals() -> PolarsResult<()> {
+    // Both frames build their own local mapping, so the same category has different physicals.
+    let cat = |name: &str, v: &[&str]| -> PolarsResult<Column> {
+        Ok(Series::new(name.into(), v)
+            .cast(&DataType::Categorical(None, Default::default()))?
+            .into())
+    };
+    let left = DataFrame::new(vec![
+        cat("k", &["a", "b", "c"])?,
+        Column::new("x".into(), [1, 2, 3]),
+    ])?;
+    let right = DataFrame::new(vec![
+        cat("k", &["c", "a", "d"])?,
+        Column::new("y".into(), [10, 20, 30]),
+    ])?;
+
+    let out = concat(
+        [
+            left.clone().lazy(),
+            right.clone().lazy().select([col("k"), col("y").alias("x")]),
+        ],
+        Default::default(),
+    )?
+    .group_by([col("k")])
+    .agg([col("x").sum()])
+    .with_column(col("k").cast(DataType::String))
+    .sort(["k"], Default::default())
+    .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(
+        Vec::from(out.column("k")?.str()?),
+        &[Some("a"), Some("b"), Some("c"), Some("d")]
+    );
+    assert_eq!(
+        Vec::from(out.column("x")?.i32()?),
+        &[Some(21), Some(2), Some(13), Some(30)]
+    );
+
+    let out = left
+        .clone()
+        .lazy()
+        .join(
+            right.clone().lazy(),
+            [col("k")],
+            [col("k")],
+            JoinType::Inner.into(),
+        )
+        .sort(["x"], Default::default())
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(Vec::from(out.column("y")?.i32()?), &[Some(20), Some(10)]);
+
+    let out = left
+        .lazy()
+        .join(right.lazy(), [col("k")], [col("k")], JoinType::Anti.into())
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(Vec::from(out.column("x")?.i32()?), &[Some(2)]);
+    Ok(())
+}
+
 #[test]
 fn test_filter_aggregated_expression() -> PolarsResult<()> {
     let df: DataFrame = df![
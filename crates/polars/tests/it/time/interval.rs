@@ -0,0 +1,69 @@
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+fn intervals(values: &[Option<(i32, i32, i64)>]) -> Series {
+    IntervalChunked::from_intervals(
+        "interval".into(),
+        values
+            .iter()
+            .map(|v| v.map(|(months, days, ns)| months_days_ns::new(months, days, ns))),
+    )
+    .into_series()
+}
+
+#[test]
+fn test_datetime_add_interval() -> PolarsResult<()> {
+    let dt = |y, m, d, h| {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .unwrap()
+            .and_hms_opt(h, 0, 0)
+            .unwrap()
+    };
+    let df = df![
+        "dt" => [dt(2024, 1, 31, 0), dt(2024, 3, 31, 12), dt(2023, 12, 1, 0)],
+        "interval" => intervals(&[
+            Some((1, 0, 0)),
+            Some((-1, 1, 3_600_000_000_000)),
+            None,
+        ]),
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            (col("dt") + col("interval")).alias("add"),
+            (col("dt") - col("interval")).alias("sub"),
+        ])
+        .collect()?;
+
+    let expected = df![
+        "add" => [Some(dt(2024, 2, 29, 0)), Some(dt(2024, 3, 1, 13)), None],
+        "sub" => [Some(dt(2023, 12, 31, 0)), Some(dt(2024, 4, 29, 11)), None],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_interval_display_and_cast() -> PolarsResult<()> {
+    let s = intervals(&[Some((14, 3, 3_600_000_000_000)), Some((0, 0, 0)), None]);
+    let as_str = s.cast(&DataType::String)?;
+    assert_eq!(
+        as_str.str()?.into_iter().collect::<Vec<_>>(),
+        [Some("14mo 3d 1h"), Some("0d"), None]
+    );
+
+    // Only intervals without months have a fixed length.
+    let s = intervals(&[Some((0, 1, 1_000)), Some((1, 0, 0))]);
+    let out = s.cast(&DataType::Duration(TimeUnit::Microseconds))?;
+    assert_eq!(
+        out.get(0)?,
+        AnyValue::Duration(86_400_000_001, TimeUnit::Microseconds)
+    );
+    assert_eq!(out.get(1)?, AnyValue::Null);
+    assert!(
+        s.strict_cast(&DataType::Duration(TimeUnit::Microseconds))
+            .is_err()
+    );
+    Ok(())
+}
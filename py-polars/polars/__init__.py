@@ -60,6 +60,8 @@ from polars.datatypes import (
     Duration,
     Enum,
     Field,
+    FixedSizeBinary,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -67,7 +69,10 @@ from polars.datatypes import (
     Int32,
     Int64,
     Int128,
+    Json,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -77,11 +82,17 @@ from polars.datatypes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
+    Union,
     Unknown,
     Utf8,
+    Uuid,
 )
 from polars.expr import Expr
 from polars.functions import (
+    FiscalCalendar,
+    Holiday,
+    HolidayCalendar,
     align_frames,
     all,
     all_horizontal,
@@ -103,6 +114,7 @@ from polars.functions import (
     concat_list,
     concat_str,
     corr,
+    corr_matrix,
     count,
     cov,
     cum_count,
@@ -138,6 +150,7 @@ from polars.functions import (
     linear_spaces,
     lit,
     map_batches,
+    map_from_lists,
     map_groups,
     max,
     max_horizontal,
@@ -157,6 +170,7 @@ from polars.functions import (
     select,
     set_random_seed,
     sql_expr,
+    stable_hash_rows,
     std,
     struct,
     sum,
@@ -165,6 +179,8 @@ from polars.functions import (
     time,
     time_range,
     time_ranges,
+    union_from_struct,
+    uuid4,
     var,
     when,
     zeros,
@@ -262,6 +278,8 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "FixedSizeBinary",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
@@ -269,7 +287,10 @@ __all__ = [
     "Int32",
     "Int64",
     "Int128",
+    "Json",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -279,8 +300,11 @@ __all__ = [
     "UInt16",
     "UInt32",
     "UInt64",
+    "UInt128",
+    "Union",
     "Unknown",
     "Utf8",
+    "Uuid",
     # polars.io
     "defer",
     "KeyedPartition",
@@ -332,6 +356,9 @@ __all__ = [
     # polars.functions.whenthen
     "when",
     # polars.functions
+    "FiscalCalendar",
+    "Holiday",
+    "HolidayCalendar",
     "align_frames",
     "arg_where",
     "business_day_count",
@@ -360,6 +387,7 @@ __all__ = [
     "mean_horizontal",
     "min",
     "min_horizontal",
+    "stable_hash_rows",
     "sum",
     "sum_horizontal",
     # polars.functions.lazy
@@ -376,6 +404,7 @@ __all__ = [
     "concat_list",
     "concat_str",
     "corr",
+    "corr_matrix",
     "count",
     "cov",
     "cum_count",
@@ -401,6 +430,7 @@ __all__ = [
     "linear_spaces",
     "lit",
     "map_batches",
+    "map_from_lists",
     "map_groups",
     "mean",
     "median",
@@ -415,11 +445,13 @@ __all__ = [
     "struct",
     "tail",
     "time",
+    "union_from_struct",
     "var",
     # polars.functions.len
     "len",
     # polars.functions.random
     "set_random_seed",
+    "uuid4",
     # polars.convert
     "from_arrow",
     "from_dataframe",
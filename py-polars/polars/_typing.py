@@ -135,8 +135,14 @@ QuantileMethod: TypeAlias = Literal[
     "nearest", "higher", "lower", "midpoint", "linear", "equiprobable"
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
+RollingRankMethod: TypeAlias = Literal["average", "min", "max", "dense"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
-RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero"]
+HolidayObservance: TypeAlias = Literal[
+    "none", "nearest_weekday", "next_monday", "sunday_to_monday"
+]
+FiscalUnit: TypeAlias = Literal["year", "quarter", "period", "week"]
+FiscalWeekPattern: TypeAlias = Literal["445", "454", "544"]
+RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero", "truncate"]
 SerializationFormat: TypeAlias = Literal["binary", "json"]
 Endianness: TypeAlias = Literal["little", "big"]
 SizeUnit: TypeAlias = Literal[
@@ -191,7 +197,8 @@ ConcatMethod = Literal[
     "align_left",
     "align_right",
 ]
-CorrelationMethod: TypeAlias = Literal["pearson", "spearman"]
+CorrelationMethod: TypeAlias = Literal["pearson", "spearman", "kendall"]
+CorrMatrixLayout: TypeAlias = Literal["square", "tidy"]
 DbReadEngine: TypeAlias = Literal["adbc", "connectorx"]
 DbWriteEngine: TypeAlias = Literal["sqlalchemy", "adbc"]
 DbWriteMode: TypeAlias = Literal["replace", "append", "fail"]
@@ -372,6 +379,7 @@ __all__ = [
     "ConditionalFormatDict",
     "ConnectionOrCursor",
     "CorrelationMethod",
+    "CorrMatrixLayout",
     "CsvEncoding",
     "CsvQuoteStyle",
     "Cursor",
@@ -424,6 +432,7 @@ __all__ = [
     "PythonLiteral",
     "QuantileMethod",
     "RankMethod",
+    "RollingRankMethod",
     "Roll",
     "RowTotalsDefinition",
     "SchemaDefinition",
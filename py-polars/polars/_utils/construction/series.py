@@ -34,12 +34,17 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    FixedSizeBinary,
+    Float16,
     List,
+    Map,
     Null,
     Object,
     Struct,
     Time,
+    Union,
     Unknown,
+    Uuid,
     dtype_to_py_type,
     is_polars_dtype,
     numpy_char_code_to_dtype,
@@ -96,6 +101,22 @@ def sequence_to_pyseries(
     elif dtype in (List, Array):
         python_dtype = list
 
+    # maps are built from their list of `{key, value}` entries
+    elif dtype == Map:
+        python_dtype = list
+        values = [
+            [{"key": k, "value": v} for k, v in value.items()]
+            if isinstance(value, Mapping)
+            else value
+            for value in values
+        ]
+
+    # unions are built from the value of the active variant of every row
+    elif dtype == Union:
+        return PySeries.new_from_any_values_and_dtype(
+            name, list(values), dtype, strict=strict
+        )
+
     # infer temporal type handling
     py_temporal_types = {date, datetime, timedelta, time}
     pl_temporal_types = {Date, Datetime, Duration, Time}
@@ -165,6 +186,9 @@ def sequence_to_pyseries(
             Boolean,
             Enum,
             Decimal,
+            FixedSizeBinary,
+            Uuid,
+            Float16,
         ):
             if pyseries.dtype() != dtype:
                 pyseries = pyseries.cast(dtype, strict=strict, wrap_numerical=False)
@@ -51,6 +51,7 @@ TableFormatNames: TypeAlias = Literal[
 _POLARS_CFG_ENV_VARS = {
     "POLARS_WARN_UNSTABLE",
     "POLARS_AUTO_STRUCTIFY",
+    "POLARS_DECIMAL_OVERFLOW",
     "POLARS_FMT_MAX_COLS",
     "POLARS_FMT_MAX_ROWS",
     "POLARS_FMT_NUM_DECIMAL",
@@ -95,6 +96,7 @@ class ConfigParameters(TypedDict, total=False):
 
     ascii_tables: bool | None
     auto_structify: bool | None
+    decimal_overflow: Literal["raise", "null"] | None
     decimal_separator: str | None
     thousands_separator: str | bool | None
     float_precision: int | None
@@ -120,6 +122,7 @@ class ConfigParameters(TypedDict, total=False):
 
     set_ascii_tables: bool | None
     set_auto_structify: bool | None
+    set_decimal_overflow: Literal["raise", "null"] | None
     set_decimal_separator: str | None
     set_thousands_separator: str | bool | None
     set_float_precision: int | None
@@ -540,6 +543,45 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_AUTO_STRUCTIFY"] = str(int(active))
         return cls
 
+    @classmethod
+    def set_decimal_overflow(
+        cls, behavior: Literal["raise", "null"] | None = "raise"
+    ) -> type[Config]:
+        """
+        Set how Decimal arithmetic and aggregations handle overflow.
+
+        A result overflows if it does not fit in the precision of the output
+        Decimal type. Results never silently wrap around.
+
+        Parameters
+        ----------
+        behavior : {'raise', 'null'}
+            * "raise": raise an error (default).
+            * "null": set the overflowing values to null.
+
+            Set to ``None`` to revert to the default.
+
+        Notes
+        -----
+        Group-by sums on the in-memory engine cannot raise, and always set
+        overflowing groups to null.
+
+        Examples
+        --------
+        >>> s = pl.Series([10**37], dtype=pl.Decimal(38, 0))
+        >>> with pl.Config(decimal_overflow="null"):
+        ...     (s * s).to_list()
+        [None]
+        """
+        if behavior is None:
+            os.environ.pop("POLARS_DECIMAL_OVERFLOW", None)
+        elif behavior not in ("raise", "null"):
+            msg = f"`behavior` must be one of {{'raise', 'null'}}, got {behavior!r}"
+            raise ValueError(msg)
+        else:
+            os.environ["POLARS_DECIMAL_OVERFLOW"] = behavior
+        return cls
+
     @classmethod
     def set_decimal_separator(cls, separator: str | None = None) -> type[Config]:
         """
@@ -131,7 +131,7 @@ if TYPE_CHECKING:
     from xlsxwriter import Workbook
     from xlsxwriter.worksheet import Worksheet
 
-    from polars import DataType, Expr, LazyFrame, Series
+    from polars import DataType, Expr, FiscalCalendar, LazyFrame, Series
     from polars._typing import (
         AsofJoinStrategy,
         AvroCompression,
@@ -143,6 +143,8 @@ if TYPE_CHECKING:
         ComparisonOperator,
         ConditionalFormatDict,
         ConnectionOrCursor,
+        CorrelationMethod,
+        CorrMatrixLayout,
         CsvQuoteStyle,
         DbWriteEngine,
         EngineType,
@@ -6912,6 +6914,7 @@ class DataFrame:
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        fiscal_calendar: FiscalCalendar | None = None,
     ) -> DynamicGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -6985,6 +6988,15 @@ class DataFrame:
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        fiscal_calendar
+            Align the windows to the fiscal years, quarters, periods or weeks of
+            this :class:`FiscalCalendar`, for which `every` must be `'1y'`, `'1q'`,
+            `'1mo'` or `'1w'` respectively. `period` must then equal `every`, and
+            `offset` and `start_by` are not used.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Returns
         -------
@@ -7027,6 +7039,7 @@ class DataFrame:
            - 1m    (1 minute)
            - 1h    (1 hour)
            - 1d    (1 calendar day)
+           - 1bd   (1 business day, skipping weekends)
            - 1w    (1 calendar week)
            - 1mo   (1 calendar month)
            - 1q    (1 calendar quarter)
@@ -7177,6 +7190,37 @@ class DataFrame:
         │ b      ┆ 2021-12-16 02:00:00 ┆ 2021-12-16 03:00:00 ┆ 2021-12-16 02:00:00 ┆ [4]       │
         └────────┴─────────────────────┴─────────────────────┴─────────────────────┴───────────┘
 
+        Group by the quarters of a fiscal year starting in October. Quarters without
+        any data are skipped.
+
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [
+        ...             date(2023, 9, 30),
+        ...             date(2023, 10, 1),
+        ...             date(2023, 12, 31),
+        ...             date(2024, 1, 1),
+        ...             date(2024, 7, 15),
+        ...         ],
+        ...         "sales": [10, 20, 30, 40, 50],
+        ...     }
+        ... )
+        >>> df.group_by_dynamic(
+        ...     "date", every="1q", fiscal_calendar=pl.FiscalCalendar(start_month=10)
+        ... ).agg(pl.col("sales").sum())
+        shape: (4, 2)
+        ┌────────────┬───────┐
+        │ date       ┆ sales │
+        │ ---        ┆ ---   │
+        │ date       ┆ i64   │
+        ╞════════════╪═══════╡
+        │ 2023-07-01 ┆ 10    │
+        │ 2023-10-01 ┆ 50    │
+        │ 2024-01-01 ┆ 40    │
+        │ 2024-07-01 ┆ 50    │
+        └────────────┴───────┘
+
         Dynamic group by on an index column
 
         >>> df = pl.DataFrame(
@@ -7217,6 +7261,7 @@ class DataFrame:
             closed=closed,
             group_by=group_by,
             start_by=start_by,
+            fiscal_calendar=fiscal_calendar,
         )
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -11832,6 +11877,76 @@ class DataFrame:
             correlation_matrix = np.array([correlation_matrix])
         return DataFrame(correlation_matrix, schema=self.columns)
 
+    def corr_matrix(
+        self,
+        columns: Sequence[str] | None = None,
+        *,
+        method: CorrelationMethod = "pearson",
+        layout: CorrMatrixLayout = "square",
+    ) -> DataFrame:
+        """
+        Compute the correlation between every pair of columns in a single pass.
+
+        Unlike :meth:`corr`, this does not require numpy, supports the Spearman rank
+        and Kendall's tau correlation and ignores missing values pairwise.
+
+        Parameters
+        ----------
+        columns
+            Names of the columns to correlate, at least two. Defaults to all numeric
+            columns.
+        method : {'pearson', 'spearman', 'kendall'}
+            Correlation method.
+        layout : {'square', 'tidy'}
+            Shape of the result:
+
+            - 'square' : A `column` label column followed by one column per input
+              column.
+            - 'tidy' : The columns `column_1`, `column_2` and `correlation`, with one
+              row per pair of distinct columns.
+
+        See Also
+        --------
+        polars.corr_matrix : Compute the correlations within a `group_by` context.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "x": [1, 2, 3, 4, 5],
+        ...         "y": [3, 1, 2, 5, 4],
+        ...         "z": [5, 4, 3, 2, 1],
+        ...     }
+        ... )
+        >>> df.corr_matrix(method="kendall")
+        shape: (3, 4)
+        ┌────────┬──────┬──────┬──────┐
+        │ column ┆ x    ┆ y    ┆ z    │
+        │ ---    ┆ ---  ┆ ---  ┆ ---  │
+        │ str    ┆ f64  ┆ f64  ┆ f64  │
+        ╞════════╪══════╪══════╪══════╡
+        │ x      ┆ 1.0  ┆ 0.4  ┆ -1.0 │
+        │ y      ┆ 0.4  ┆ 1.0  ┆ -0.4 │
+        │ z      ┆ -1.0 ┆ -0.4 ┆ 1.0  │
+        └────────┴──────┴──────┴──────┘
+        >>> df.corr_matrix(["x", "y"], layout="tidy")
+        shape: (1, 3)
+        ┌──────────┬──────────┬─────────────┐
+        │ column_1 ┆ column_2 ┆ correlation │
+        │ ---      ┆ ---      ┆ ---         │
+        │ str      ┆ str      ┆ f64         │
+        ╞══════════╪══════════╪═════════════╡
+        │ x        ┆ y        ┆ 0.6         │
+        └──────────┴──────────┴─────────────┘
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.lazy()
+            .corr_matrix(columns, method=method, layout=layout)
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
     def merge_sorted(self, other: DataFrame, key: str) -> DataFrame:
         """
         Take two sorted DataFrames and merge them by the sorted key.
@@ -11,7 +11,7 @@ if TYPE_CHECKING:
     from collections.abc import Iterable
     from datetime import timedelta
 
-    from polars import DataFrame
+    from polars import DataFrame, FiscalCalendar
     from polars._typing import (
         ClosedInterval,
         IntoExpr,
@@ -929,6 +929,7 @@ class DynamicGroupBy:
         label: Label,
         group_by: IntoExpr | Iterable[IntoExpr] | None,
         start_by: StartBy,
+        fiscal_calendar: FiscalCalendar | None = None,
     ) -> None:
         every = parse_as_duration_string(every)
         period = parse_as_duration_string(period)
@@ -944,6 +945,7 @@ class DynamicGroupBy:
         self.closed = closed
         self.group_by = group_by
         self.start_by = start_by
+        self.fiscal_calendar = fiscal_calendar
 
     def __iter__(self) -> Self:
         from polars.lazyframe.opt_flags import QueryOptFlags
@@ -961,6 +963,7 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                fiscal_calendar=self.fiscal_calendar,
             )
             .agg(F.first().agg_groups().alias(temp_col))
             .collect(optimizations=QueryOptFlags.none())
@@ -1014,6 +1017,7 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                fiscal_calendar=self.fiscal_calendar,
             )
             .agg(*aggs, **named_aggs)
             .collect(optimizations=QueryOptFlags.none())
@@ -1061,6 +1065,7 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                fiscal_calendar=self.fiscal_calendar,
             )
             .map_groups(function, schema)
             .collect(optimizations=QueryOptFlags.none())
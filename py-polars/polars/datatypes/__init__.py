@@ -16,6 +16,8 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    FixedSizeBinary,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -23,8 +25,11 @@ from polars.datatypes.classes import (
     Int32,
     Int64,
     Int128,
+    Json,
     IntegerType,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -35,8 +40,11 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
+    Union,
     Unknown,
     Utf8,
+    Uuid,
 )
 from polars.datatypes.constants import (
     DTYPE_TEMPORAL_UNITS,
@@ -73,15 +81,20 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "FixedSizeBinary",
+    "Float16",
     "Float32",
     "Float64",
     "Int16",
     "Int128",
+    "Json",
     "Int32",
     "Int64",
     "Int8",
     "IntegerType",
+    "Interval",
     "List",
+    "Map",
     "Null",
     "Object",
     "String",
@@ -91,9 +104,12 @@ __all__ = [
     "UInt16",
     "UInt32",
     "UInt64",
+    "UInt128",
     "UInt8",
+    "Union",
     "Unknown",
     "Utf8",
+    "Uuid",
     # constants
     "N_INFER_DEFAULT",
     "DTYPE_TEMPORAL_UNITS",
@@ -385,6 +385,28 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class UInt128(UnsignedIntegerType):
+    """
+    128-bit unsigned integer type.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+    """
+
+
+class Float16(FloatType):
+    """
+    16-bit floating point type.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+    """
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -465,6 +487,60 @@ class Binary(DataType):
     """Binary type."""
 
 
+class FixedSizeBinary(DataType):
+    """
+    Binary type where every value has the same number of bytes.
+
+    Parameters
+    ----------
+    size
+        The number of bytes of every value.
+    """
+
+    size: int
+
+    def __init__(self, size: int) -> None:
+        if size <= 0:
+            msg = f"`size` must be positive, got {size}"
+            raise ValueError(msg)
+        self.size = size
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, FixedSizeBinary):
+            return True
+        elif isinstance(other, FixedSizeBinary):
+            return self.size == other.size
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.size))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}(size={self.size!r})"
+
+
+class Uuid(DataType):
+    """
+    UUID type, stored as a 128-bit integer.
+
+    Values are represented in Python as `uuid.UUID` objects and sort in the order of
+    their bytes.
+    """
+
+
+class Json(DataType):
+    """
+    JSON type, stored as the text of the documents.
+
+    Every non-null value is a valid JSON document, which is checked when casting from
+    `String`. The cast stores the documents without whitespace outside of strings.
+    Values are represented in Python as the text of the documents.
+    """
+
+
 class Date(TemporalType):
     """
     Data type representing a calendar date.
@@ -633,6 +709,19 @@ class Duration(TemporalType):
         return f"{class_name}(time_unit={self.time_unit!r})"
 
 
+class Interval(TemporalType):
+    """
+    Calendar interval type, stored as a number of months, days and nanoseconds.
+
+    Values are represented in Python as `(months, days, nanoseconds)` tuples.
+
+    .. warning::
+        This functionality is considered **unstable**.
+        It is a work-in-progress feature and may not always work as expected.
+        It may be changed at any point without it being considered a breaking change.
+    """
+
+
 class Categorical(DataType):
     """
     A categorical encoding of a set of strings.
@@ -822,6 +911,60 @@ class List(NestedType):
         return f"{class_name}({self.inner!r})"
 
 
+class Map(NestedType):
+    """
+    Map type, mapping unique keys to values in every row.
+
+    Maps are stored as a list of `{key, value}` structs. Python values of this type
+    can be given as a dictionary or as a list of `{"key": ..., "value": ...}`
+    dictionaries, and are returned as the latter.
+
+    Parameters
+    ----------
+    key
+        The `DataType` of the keys. Keys cannot be null.
+    value
+        The `DataType` of the values.
+
+    Examples
+    --------
+    >>> s = pl.Series(
+    ...     "m", [{"a": 1, "b": 2}, {"c": 3}], dtype=pl.Map(pl.String, pl.Int64)
+    ... )
+    >>> s.dtype
+    Map(String, Int64)
+    >>> s.map.get("a").to_list()
+    [1, None]
+    """
+
+    key: PolarsDataType
+    value: PolarsDataType
+
+    def __init__(
+        self,
+        key: PolarsDataType | PythonDataType,
+        value: PolarsDataType | PythonDataType,
+    ) -> None:
+        self.key = polars.datatypes.parse_into_dtype(key)
+        self.value = polars.datatypes.parse_into_dtype(value)
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if type(other) is DataTypeClass and issubclass(other, Map):
+            return True
+        elif isinstance(other, Map):
+            return self.key == other.key and self.value == other.value
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, self.key, self.value))
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}({self.key!r}, {self.value!r})"
+
+
 class Array(NestedType):
     """
     Fixed length list type.
@@ -1041,3 +1184,56 @@ class Struct(NestedType):
     def to_schema(self) -> OrderedDict[str, PolarsDataType]:
         """Return Struct dtype as a schema dict."""
         return OrderedDict(self)
+
+
+class Union(NestedType):
+    """
+    Union type, holding a value of one of several variants in every row.
+
+    Python values of this type are the values of the active variant. A value is
+    assigned to the first variant of its data type, or with `strict=False`, to the
+    first variant it can be cast to.
+
+    Parameters
+    ----------
+    variants
+        The variants of the union. Can be either a sequence of Field objects or a
+        mapping of variant names to data types.
+
+    Examples
+    --------
+    >>> dtype = pl.Union({"int": pl.Int64, "str": pl.String})
+    >>> s = pl.Series("u", [1, "x", None], dtype=dtype)
+    >>> s.dtype
+    Union({'int': Int64, 'str': String})
+    >>> s.union.type_id().to_list()
+    [0, 1, None]
+    """
+
+    variants: list[Field]
+
+    def __init__(self, variants: Sequence[Field] | SchemaDict) -> None:
+        if isinstance(variants, Mapping):
+            self.variants = [Field(name, dtype) for name, dtype in variants.items()]
+        else:
+            self.variants = list(variants)
+
+    def __eq__(self, other: PolarsDataType) -> bool:  # type: ignore[override]
+        # allow comparing object instances to class
+        if isclass(other) and issubclass(other, Union):
+            return True
+        elif isinstance(other, Union):
+            return self.variants == other.variants
+        else:
+            return False
+
+    def __hash__(self) -> int:
+        return hash((self.__class__, tuple(self.variants)))
+
+    def __iter__(self) -> Iterator[tuple[str, PolarsDataType]]:
+        for fld in self.variants:
+            yield fld.name, fld.dtype
+
+    def __repr__(self) -> str:
+        class_name = self.__class__.__name__
+        return f"{class_name}({dict(self)})"
@@ -8,6 +8,7 @@ from collections.abc import Collection
 from datetime import date, datetime, time, timedelta
 from decimal import Decimal as PyDecimal
 from typing import TYPE_CHECKING, Any, Optional, Union
+from uuid import UUID
 
 from polars.datatypes.classes import (
     Array,
@@ -22,6 +23,8 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    FixedSizeBinary,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -29,7 +32,10 @@ from polars.datatypes.classes import (
     Int32,
     Int64,
     Int128,
+    Json,
+    Interval,
     List,
+    Map,
     Null,
     Object,
     String,
@@ -39,7 +45,9 @@ from polars.datatypes.classes import (
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
     Unknown,
+    Uuid,
 )
 from polars.dependencies import numpy as np
 from polars.dependencies import pyarrow as pa
@@ -125,6 +133,12 @@ def unpack_dtypes(
             if include_compound:
                 unpacked.add(tp)
             unpacked.update(unpack_dtypes(tp.inner, include_compound=include_compound))
+        elif isinstance(tp, Map):
+            if include_compound:
+                unpacked.add(tp)
+            unpacked.update(
+                unpack_dtypes(tp.key, tp.value, include_compound=include_compound)
+            )
         elif isinstance(tp, Struct):
             if include_compound:
                 unpacked.add(tp)
@@ -177,6 +191,8 @@ class _DataTypeMappings:
             Datetime: datetime,
             Decimal: PyDecimal,
             Duration: timedelta,
+            FixedSizeBinary: bytes,
+            Float16: float,
             Float32: float,
             Float64: float,
             Int128: int,
@@ -184,7 +200,10 @@ class _DataTypeMappings:
             Int32: int,
             Int64: int,
             Int8: int,
+            Interval: tuple,
+            Json: str,
             List: list,
+            Map: list,
             Null: None.__class__,
             Object: object,
             String: str,
@@ -193,7 +212,9 @@ class _DataTypeMappings:
             UInt16: int,
             UInt32: int,
             UInt64: int,
+            UInt128: int,
             UInt8: int,
+            Uuid: UUID,
             # the below mappings are appropriate as we restrict cat/enum to strings
             Enum: str,
             Categorical: str,
@@ -10,6 +10,7 @@ from polars.datatypes.classes import (
     Datetime,
     Decimal,
     Duration,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -18,12 +19,15 @@ from polars.datatypes.classes import (
     Int64,
     Int128,
     List,
+    Map,
     Struct,
     Time,
     UInt8,
     UInt16,
     UInt32,
     UInt64,
+    UInt128,
+    Union,
 )
 
 if TYPE_CHECKING:
@@ -92,12 +96,13 @@ UNSIGNED_INTEGER_DTYPES: frozenset[PolarsIntegerType] = DataTypeGroup(
         UInt16,
         UInt32,
         UInt64,
+        UInt128,
     ]
 )
 INTEGER_DTYPES: frozenset[PolarsIntegerType] = (
     SIGNED_INTEGER_DTYPES | UNSIGNED_INTEGER_DTYPES
 )
-FLOAT_DTYPES: frozenset[PolarsDataType] = DataTypeGroup([Float32, Float64])
+FLOAT_DTYPES: frozenset[PolarsDataType] = DataTypeGroup([Float16, Float32, Float64])
 NUMERIC_DTYPES: frozenset[PolarsDataType] = DataTypeGroup(
     FLOAT_DTYPES | INTEGER_DTYPES | frozenset([Decimal])
 )
@@ -125,4 +130,6 @@ TEMPORAL_DTYPES: frozenset[PolarsTemporalType] = DataTypeGroup(
     frozenset([Date, Time]) | DATETIME_DTYPES | DURATION_DTYPES
 )
 
-NESTED_DTYPES: frozenset[PolarsDataType] = DataTypeGroup([List, Struct, Array])
+NESTED_DTYPES: frozenset[PolarsDataType] = DataTypeGroup(
+    [List, Map, Struct, Union, Array]
+)
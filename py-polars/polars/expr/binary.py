@@ -338,3 +338,228 @@ class ExprBinaryNameSpace:
         dtype = parse_into_datatype_expr(dtype)
 
         return wrap_expr(self._pyexpr.from_buffer(dtype._pydatatype_expr, endianness))
+
+    def md5(self, *, hex: bool = False) -> Expr:
+        """
+        Compute the MD5 digest of every value.
+
+        String values are hashed by their UTF-8 bytes, so the digests match those
+        computed by other systems.
+
+        Parameters
+        ----------
+        hex
+            Return the digest as a lowercase hexadecimal string instead of raw bytes.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`, or :class:`String` if `hex`
+            is set.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["abc", "polars"]})
+        >>> df.with_columns(md5=pl.col("text").bin.md5(hex=True))
+        shape: (2, 2)
+        ┌────────┬─────────────────────────────────┐
+        │ text   ┆ md5                             │
+        │ ---    ┆ ---                             │
+        │ str    ┆ str                             │
+        ╞════════╪═════════════════════════════════╡
+        │ abc    ┆ 900150983cd24fb0d6963f7d28e17f… │
+        │ polars ┆ 33f83688ff9c10a6eb6a9b34d8fac7… │
+        └────────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_md5(hex))
+
+    def sha1(self, *, hex: bool = False) -> Expr:
+        """
+        Compute the SHA-1 digest of every value.
+
+        String values are hashed by their UTF-8 bytes, so the digests match those
+        computed by other systems.
+
+        Parameters
+        ----------
+        hex
+            Return the digest as a lowercase hexadecimal string instead of raw bytes.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`, or :class:`String` if `hex`
+            is set.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["abc", "polars"]})
+        >>> df.with_columns(sha1=pl.col("text").bin.sha1(hex=True))
+        shape: (2, 2)
+        ┌────────┬─────────────────────────────────┐
+        │ text   ┆ sha1                            │
+        │ ---    ┆ ---                             │
+        │ str    ┆ str                             │
+        ╞════════╪═════════════════════════════════╡
+        │ abc    ┆ a9993e364706816aba3e25717850c2… │
+        │ polars ┆ 8d086de772d98be7aaf9379faf9041… │
+        └────────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_sha1(hex))
+
+    def sha256(self, *, hex: bool = False) -> Expr:
+        """
+        Compute the SHA-256 digest of every value.
+
+        String values are hashed by their UTF-8 bytes, so the digests match those
+        computed by other systems.
+
+        Parameters
+        ----------
+        hex
+            Return the digest as a lowercase hexadecimal string instead of raw bytes.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`, or :class:`String` if `hex`
+            is set.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["abc", "polars"]})
+        >>> df.with_columns(sha256=pl.col("text").bin.sha256(hex=True))
+        shape: (2, 2)
+        ┌────────┬─────────────────────────────────┐
+        │ text   ┆ sha256                          │
+        │ ---    ┆ ---                             │
+        │ str    ┆ str                             │
+        ╞════════╪═════════════════════════════════╡
+        │ abc    ┆ ba7816bf8f01cfea414140de5dae22… │
+        │ polars ┆ e37e7e864c58e1e96380229f66040d… │
+        └────────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_sha256(hex))
+
+    def xxh3_64(self, seed: int = 0) -> Expr:
+        """
+        Compute the 64-bit XXH3 hash of every value.
+
+        Unlike :meth:`Expr.hash`, the result is stable across Polars versions and
+        matches other XXH3 implementations.
+
+        Parameters
+        ----------
+        seed
+            The seed of the hash.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt64`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["abc", "polars"]})
+        >>> df.with_columns(hash=pl.col("text").bin.xxh3_64())
+        shape: (2, 2)
+        ┌────────┬──────────────────────┐
+        │ text   ┆ hash                 │
+        │ ---    ┆ ---                  │
+        │ str    ┆ u64                  │
+        ╞════════╪══════════════════════╡
+        │ abc    ┆ 8696274497037089104  │
+        │ polars ┆ 16500643583958147475 │
+        └────────┴──────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_xxh3_64(seed))
+
+    def xxh3_128(self, seed: int = 0, *, hex: bool = False) -> Expr:
+        """
+        Compute the 128-bit XXH3 hash of every value.
+
+        The hash is returned in its canonical, big-endian byte order.
+
+        Parameters
+        ----------
+        seed
+            The seed of the hash.
+        hex
+            Return the hash as a lowercase hexadecimal string instead of raw bytes.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`, or :class:`String` if `hex`
+            is set.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["abc", "polars"]})
+        >>> df.with_columns(hash=pl.col("text").bin.xxh3_128(hex=True))
+        shape: (2, 2)
+        ┌────────┬─────────────────────────────────┐
+        │ text   ┆ hash                            │
+        │ ---    ┆ ---                             │
+        │ str    ┆ str                             │
+        ╞════════╪═════════════════════════════════╡
+        │ abc    ┆ 06b05ab6733a618578af5f94892f39… │
+        │ polars ┆ 650e6b0344b42868c9259c68de7530… │
+        └────────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.bin_xxh3_128(seed, hex))
+
+    def murmur3_32(self, seed: int | None = None, *, spark: bool = False) -> Expr:
+        """
+        Compute the 32-bit MurmurHash3 (x86 variant) of every value.
+
+        By default this is the hash used by the Iceberg bucket transform, which
+        assigns a value to bucket `(murmur3_32 & 2147483647) % num_buckets`.
+        With `spark=True` this is the hash computed by Spark's `hash` function for
+        a single string or binary column.
+
+        Parameters
+        ----------
+        seed
+            The seed of the hash. Defaults to 0, or to 42 (the seed of Spark's
+            `hash`) if `spark` is set.
+        spark
+            Hash the trailing bytes that do not fill a 4-byte block like Spark
+            does, which mixes in every byte on its own as a signed integer.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["iceberg", "polars"]})
+        >>> df.with_columns(
+        ...     hash=pl.col("text").bin.murmur3_32(),
+        ...     bucket=(pl.col("text").bin.murmur3_32() & 0x7FFFFFFF) % 16,
+        ... )
+        shape: (2, 3)
+        ┌─────────┬────────────┬────────┐
+        │ text    ┆ hash       ┆ bucket │
+        │ ---     ┆ ---        ┆ ---    │
+        │ str     ┆ i32        ┆ i32    │
+        ╞═════════╪════════════╪════════╡
+        │ iceberg ┆ 1210000089 ┆ 9      │
+        │ polars  ┆ -56604853  ┆ 11     │
+        └─────────┴────────────┴────────┘
+
+        >>> df.with_columns(spark_hash=pl.col("text").bin.murmur3_32(spark=True))
+        shape: (2, 2)
+        ┌─────────┬────────────┐
+        │ text    ┆ spark_hash │
+        │ ---     ┆ ---        │
+        │ str     ┆ i32        │
+        ╞═════════╪════════════╡
+        │ iceberg ┆ 772410301  │
+        │ polars  ┆ 204088373  │
+        └─────────┴────────────┘
+        """
+        if seed is None:
+            seed = 42 if spark else 0
+        return wrap_expr(self._pyexpr.bin_murmur3_32(seed, spark))
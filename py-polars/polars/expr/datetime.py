@@ -17,10 +17,11 @@ if TYPE_CHECKING:
     import sys
     from collections.abc import Iterable
 
-    from polars import Expr
+    from polars import Expr, FiscalCalendar, HolidayCalendar
     from polars._typing import (
         Ambiguous,
         EpochTimeUnit,
+        FiscalUnit,
         IntoExpr,
         IntoExprColumn,
         NonExistent,
@@ -48,8 +49,10 @@ class ExprDateTimeNameSpace:
         self,
         n: int | IntoExpr,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        calendar: HolidayCalendar | None = None,
     ) -> Expr:
         """
         Offset by `n` business days.
@@ -89,6 +92,9 @@ class ExprDateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            A :class:`HolidayCalendar` whose holidays are skipped, in addition to
+            `holidays`.
 
         Returns
         -------
@@ -166,6 +172,7 @@ class ExprDateTimeNameSpace:
                 n_pyexpr,
                 week_mask,
                 [(holiday - unix_epoch).days for holiday in holidays],
+                calendar,
                 roll,
             )
         )
@@ -202,6 +209,7 @@ class ExprDateTimeNameSpace:
         - 1m    (1 minute)
         - 1h    (1 hour)
         - 1d    (1 calendar day)
+        - 1bd   (1 business day, skipping weekends)
         - 1w    (1 calendar week)
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
@@ -903,7 +911,9 @@ class ExprDateTimeNameSpace:
         self,
         *,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+
         holidays: Iterable[dt.date] = (),
+        calendar: HolidayCalendar | None = None,
     ) -> Expr:
         """
         Determine whether each day lands on a business day.
@@ -931,6 +941,9 @@ class ExprDateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `is_business_day`.
+        calendar
+            A :class:`HolidayCalendar` whose holidays are not business days, in
+            addition to `holidays`.
 
         Returns
         -------
@@ -990,6 +1003,7 @@ class ExprDateTimeNameSpace:
             self._pyexpr.dt_is_business_day(
                 week_mask,
                 [(holiday - unix_epoch).days for holiday in holidays],
+                calendar,
             )
         )
 
@@ -2333,6 +2347,7 @@ class ExprDateTimeNameSpace:
             - 1m    (1 minute)
             - 1h    (1 hour)
             - 1d    (1 calendar day)
+            - 1bd   (1 business day, skipping weekends)
             - 1w    (1 calendar week)
             - 1mo   (1 calendar month)
             - 1q    (1 calendar quarter)
@@ -2500,6 +2515,221 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_month_end())
 
+    @unstable()
+    def fiscal_year(self, calendar: FiscalCalendar) -> Expr:
+        """
+        Extract the fiscal year according to a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal years.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.FiscalCalendar(start_month=10)
+        >>> df = pl.DataFrame({"date": [date(2023, 9, 30), date(2023, 10, 1)]})
+        >>> df.with_columns(fiscal_year=pl.col("date").dt.fiscal_year(calendar))
+        shape: (2, 2)
+        ┌────────────┬─────────────┐
+        │ date       ┆ fiscal_year │
+        │ ---        ┆ ---         │
+        │ date       ┆ i32         │
+        ╞════════════╪═════════════╡
+        │ 2023-09-30 ┆ 2023        │
+        │ 2023-10-01 ┆ 2024        │
+        └────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_year(calendar))
+
+    @unstable()
+    def fiscal_quarter(self, calendar: FiscalCalendar) -> Expr:
+        """
+        Extract the fiscal quarter according to a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns the quarter ranging from 1 to 4.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal quarters.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.FiscalCalendar(start_month=10)
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2023, 10, 1), date(2024, 1, 15), date(2024, 9, 30)]}
+        ... )
+        >>> df.with_columns(quarter=pl.col("date").dt.fiscal_quarter(calendar))
+        shape: (3, 2)
+        ┌────────────┬─────────┐
+        │ date       ┆ quarter │
+        │ ---        ┆ ---     │
+        │ date       ┆ i8      │
+        ╞════════════╪═════════╡
+        │ 2023-10-01 ┆ 1       │
+        │ 2024-01-15 ┆ 2       │
+        │ 2024-09-30 ┆ 4       │
+        └────────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_quarter(calendar))
+
+    @unstable()
+    def fiscal_period(self, calendar: FiscalCalendar) -> Expr:
+        """
+        Extract the fiscal period according to a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns the period ranging from 1 to 12. For a calendar of whole months,
+        periods are months counted from the start of the fiscal year.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal periods.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.FiscalCalendar(start_month=10)
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2023, 10, 1), date(2024, 1, 15), date(2024, 9, 30)]}
+        ... )
+        >>> df.with_columns(period=pl.col("date").dt.fiscal_period(calendar))
+        shape: (3, 2)
+        ┌────────────┬────────┐
+        │ date       ┆ period │
+        │ ---        ┆ ---    │
+        │ date       ┆ i8     │
+        ╞════════════╪════════╡
+        │ 2023-10-01 ┆ 1      │
+        │ 2024-01-15 ┆ 4      │
+        │ 2024-09-30 ┆ 12     │
+        └────────────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_period(calendar))
+
+    @unstable()
+    def fiscal_week(self, calendar: FiscalCalendar) -> Expr:
+        """
+        Extract the week of the fiscal year according to a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Weeks are counted from the first day of the fiscal year and range from 1
+        to 53.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal years.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.FiscalCalendar(
+        ...     start_month=1, week_pattern="445", end_weekday=7, year_end="last"
+        ... )
+        >>> df = pl.DataFrame(
+        ...     {"date": [date(2024, 1, 1), date(2024, 3, 31), date(2024, 12, 29)]}
+        ... )
+        >>> df.with_columns(week=pl.col("date").dt.fiscal_week(calendar))
+        shape: (3, 2)
+        ┌────────────┬──────┐
+        │ date       ┆ week │
+        │ ---        ┆ ---  │
+        │ date       ┆ i8   │
+        ╞════════════╪══════╡
+        │ 2024-01-01 ┆ 1    │
+        │ 2024-03-31 ┆ 13   │
+        │ 2024-12-29 ┆ 52   │
+        └────────────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_week(calendar))
+
+    @unstable()
+    def fiscal_truncate(self, calendar: FiscalCalendar, unit: FiscalUnit) -> Expr:
+        """
+        Truncate to the start of the fiscal year, quarter, period or week.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        For datetimes, the result is midnight at the start of the fiscal unit.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal units.
+        unit : {'year', 'quarter', 'period', 'week'}
+            The fiscal unit to truncate to.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Date` or :class:`Datetime`.
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> calendar = pl.FiscalCalendar(
+        ...     start_month=1, week_pattern="445", end_weekday=7, year_end="last"
+        ... )
+        >>> df = pl.DataFrame(
+        ...     {"time": [datetime(2024, 2, 14, 10), datetime(2024, 5, 3, 8, 30)]}
+        ... )
+        >>> df.with_columns(
+        ...     period=pl.col("time").dt.fiscal_truncate(calendar, "period"),
+        ...     quarter=pl.col("time").dt.fiscal_truncate(calendar, "quarter"),
+        ... )
+        shape: (2, 3)
+        ┌─────────────────────┬─────────────────────┬─────────────────────┐
+        │ time                ┆ period              ┆ quarter             │
+        │ ---                 ┆ ---                 ┆ ---                 │
+        │ datetime[μs]        ┆ datetime[μs]        ┆ datetime[μs]        │
+        ╞═════════════════════╪═════════════════════╪═════════════════════╡
+        │ 2024-02-14 10:00:00 ┆ 2024-01-29 00:00:00 ┆ 2024-01-01 00:00:00 │
+        │ 2024-05-03 08:30:00 ┆ 2024-04-29 00:00:00 ┆ 2024-04-01 00:00:00 │
+        └─────────────────────┴─────────────────────┴─────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.dt_fiscal_truncate(calendar, unit))
+
     def base_utc_offset(self) -> Expr:
         """
         Base offset from UTC.
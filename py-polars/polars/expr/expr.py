@@ -43,9 +43,11 @@ from polars._utils.various import (
     warn_null_comparison,
 )
 from polars.datatypes import (
+    Decimal,
     Int64,
     is_polars_dtype,
     parse_into_datatype_expr,
+    parse_into_dtype,
 )
 from polars.dependencies import _check_for_numpy
 from polars.dependencies import numpy as np
@@ -58,11 +60,16 @@ from polars.expr.array import ExprArrayNameSpace
 from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
 from polars.expr.datetime import ExprDateTimeNameSpace
+from polars.expr.ip import ExprIpNameSpace
+from polars.expr.json import ExprJsonNameSpace
 from polars.expr.list import ExprListNameSpace
+from polars.expr.map import ExprMapNameSpace
 from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
 from polars.expr.string import ExprStringNameSpace
 from polars.expr.struct import ExprStructNameSpace
+from polars.expr.union import ExprUnionNameSpace
+from polars.expr.url import ExprUrlNameSpace
 from polars.meta import thread_pool_size
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -88,6 +95,7 @@ if TYPE_CHECKING:
         PolarsDataType,
         QuantileMethod,
         RankMethod,
+        RollingRankMethod,
         RoundMode,
         SchemaDict,
         SearchSortedSide,
@@ -133,6 +141,11 @@ class Expr:
         "str",
         "bin",
         "struct",
+        "url",
+        "ip",
+        "map",
+        "json",
+        "union",
     }
 
     @classmethod
@@ -1670,13 +1683,20 @@ class Expr:
         ----------
         decimals
             Number of decimals to round by.
-        mode : {'half_to_even', 'half_away_from_zero'}
+        mode : {'half_to_even', 'half_away_from_zero', 'truncate'}
             RoundMode.
 
             * *half_to_even*
                 round to the nearest even number
             * *half_away_from_zero*
-                round to the nearest number away from zero
+                round to the nearest number away from zero (also known as "half up")
+            * *truncate*
+                drop the digits beyond `decimals`, i.e. round towards zero
+
+        Notes
+        -----
+        Casting a Decimal to a smaller scale truncates by default. Pass
+        `round_mode` to :meth:`cast` to round with one of these modes instead.
 
         Examples
         --------
@@ -1811,6 +1831,7 @@ class Expr:
         *,
         strict: bool = True,
         wrap_numerical: bool = False,
+        round_mode: RoundMode | None = None,
     ) -> Expr:
         r"""
         Cast between data types.
@@ -1825,6 +1846,10 @@ class Expr:
         wrap_numerical
             If True numeric casts wrap overflowing values instead of
             marking the cast as invalid.
+        round_mode : {'half_to_even', 'half_away_from_zero', 'truncate'}, optional
+            How to round when casting to a Decimal with a smaller scale than the
+            input; see :meth:`round` for the modes. By default the extra digits
+            are truncated. Requires `dtype` to be a concrete data type.
 
         Examples
         --------
@@ -1848,10 +1873,37 @@ class Expr:
         │ 2.0 ┆ 5   │
         │ 3.0 ┆ 6   │
         └─────┴─────┘
-        """
+
+        Round half away from zero when casting to a Decimal with a smaller scale.
+
+        >>> df = pl.DataFrame({"c": ["1.25", "-1.25"]}).cast(pl.Decimal(5, 2))
+        >>> df.with_columns(
+        ...     truncated=pl.col("c").cast(pl.Decimal(5, 1)),
+        ...     rounded=pl.col("c").cast(
+        ...         pl.Decimal(5, 1), round_mode="half_away_from_zero"
+        ...     ),
+        ... )
+        shape: (2, 3)
+        ┌──────────────┬──────────────┬──────────────┐
+        │ c            ┆ truncated    ┆ rounded      │
+        │ ---          ┆ ---          ┆ ---          │
+        │ decimal[5,2] ┆ decimal[5,1] ┆ decimal[5,1] │
+        ╞══════════════╪══════════════╪══════════════╡
+        │ 1.25         ┆ 1.2          ┆ 1.3          │
+        │ -1.25        ┆ -1.2         ┆ -1.3         │
+        └──────────────┴──────────────┴──────────────┘
+        """
+        expr = self
+        if round_mode is not None:
+            if isinstance(dtype, pl.DataTypeExpr):
+                msg = "`round_mode` requires a concrete data type, got a DataTypeExpr"
+                raise TypeError(msg)
+            target = parse_into_dtype(dtype)
+            if isinstance(target, Decimal):
+                expr = expr.round(target.scale, mode=round_mode)
         dtype = parse_into_datatype_expr(dtype)
         return self._from_pyexpr(
-            self._pyexpr.cast(dtype._pydatatype_expr, strict, wrap_numerical)
+            expr._pyexpr.cast(dtype._pydatatype_expr, strict, wrap_numerical)
         )
 
     def sort(self, *, descending: bool = False, nulls_last: bool = False) -> Expr:
@@ -7476,241 +7528,1577 @@ class Expr:
             )
         )
 
-    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
-    def rolling_min(
+    @unstable()
+    def rolling_n_unique_by(
         self,
-        window_size: int,
-        weights: list[float] | None = None,
+        by: IntoExpr,
+        window_size: timedelta | str,
         *,
-        min_samples: int | None = None,
-        center: bool = False,
+        min_samples: int = 1,
+        closed: ClosedInterval = "right",
     ) -> Expr:
         """
-        Apply a rolling min (moving min) over the values in this array.
+        Compute a rolling count of the unique values based on another column.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their min.
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
-        The window at a given row will include the row itself, and the `window_size - 1`
-        elements before it.
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
 
-        .. versionchanged:: 1.21.0
-            The `min_periods` parameter was renamed `min_samples`.
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Null values are ignored.
 
         Parameters
         ----------
+        by
+            Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+            or ``Int32`` data type (note that the integral ones require using `'i'`
+            in `window size`).
         window_size
-            The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
         min_samples
             The number of values in the window that should be non-null before computing
-            a result. If set to `None` (default), it will be set equal to `window_size`.
-        center
-            Set the labels at the center of the window.
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
+        See Also
+        --------
+        Expr.rolling_n_unique
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_min=pl.col("A").rolling_min(window_size=2),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_min │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.0         │
-        │ 3.0 ┆ 2.0         │
-        │ 4.0 ┆ 3.0         │
-        │ 5.0 ┆ 4.0         │
-        │ 6.0 ┆ 5.0         │
-        └─────┴─────────────┘
-
-        Specify weights to multiply the values in the window with:
-
-        >>> df.with_columns(
-        ...     rolling_min=pl.col("A").rolling_min(
-        ...         window_size=2, weights=[0.25, 0.75]
-        ...     ),
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2025, 1, d) for d in (1, 2, 3, 6, 7)],
+        ...         "a": [3, 1, 3, 2, 5],
+        ...     }
         ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_min │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.25        │
-        │ 3.0 ┆ 0.5         │
-        │ 4.0 ┆ 0.75        │
-        │ 5.0 ┆ 1.0         │
-        │ 6.0 ┆ 1.25        │
-        └─────┴─────────────┘
-
-        Center the values in the window
-
         >>> df.with_columns(
-        ...     rolling_min=pl.col("A").rolling_min(window_size=3, center=True),
+        ...     n_unique=pl.col("a").rolling_n_unique_by("date", window_size="3d"),
         ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_min │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.0         │
-        │ 3.0 ┆ 2.0         │
-        │ 4.0 ┆ 3.0         │
-        │ 5.0 ┆ 4.0         │
-        │ 6.0 ┆ null        │
-        └─────┴─────────────┘
+        shape: (5, 3)
+        ┌────────────┬─────┬──────────┐
+        │ date       ┆ a   ┆ n_unique │
+        │ ---        ┆ --- ┆ ---      │
+        │ date       ┆ i64 ┆ u32      │
+        ╞════════════╪═════╪══════════╡
+        │ 2025-01-01 ┆ 3   ┆ 1        │
+        │ 2025-01-02 ┆ 1   ┆ 2        │
+        │ 2025-01-03 ┆ 3   ┆ 2        │
+        │ 2025-01-06 ┆ 2   ┆ 1        │
+        │ 2025-01-07 ┆ 5   ┆ 2        │
+        └────────────┴─────┴──────────┘
         """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
         return self._from_pyexpr(
-            self._pyexpr.rolling_min(
-                window_size,
-                weights,
-                min_samples,
-                center=center,
-            )
+            self._pyexpr.rolling_n_unique_by(by, window_size, min_samples, closed)
         )
 
-    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
-    def rolling_max(
+    @unstable()
+    def rolling_mode_by(
         self,
-        window_size: int,
-        weights: list[float] | None = None,
+        by: IntoExpr,
+        window_size: timedelta | str,
         *,
-        min_samples: int | None = None,
-        center: bool = False,
+        min_samples: int = 1,
+        closed: ClosedInterval = "right",
     ) -> Expr:
         """
-        Apply a rolling max (moving max) over the values in this array.
+        Compute a rolling mode based on another column.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their max.
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
-        The window at a given row will include the row itself, and the `window_size - 1`
-        elements before it.
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
 
-        .. versionchanged:: 1.21.0
-            The `min_periods` parameter was renamed `min_samples`.
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Null values are ignored. If several values are equally frequent within a
+        window, the smallest of them is returned.
 
         Parameters
         ----------
+        by
+            Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+            or ``Int32`` data type (note that the integral ones require using `'i'`
+            in `window size`).
         window_size
-            The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
         min_samples
             The number of values in the window that should be non-null before computing
-            a result. If set to `None` (default), it will be set equal to `window_size`.
-        center
-            Set the labels at the center of the window.
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
+        See Also
+        --------
+        Expr.rolling_mode
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2025, 1, d) for d in (1, 2, 3, 6, 7)],
+        ...         "a": [3, 1, 3, 2, 5],
+        ...     }
+        ... )
         >>> df.with_columns(
-        ...     rolling_max=pl.col("A").rolling_max(window_size=2),
+        ...     mode=pl.col("a").rolling_mode_by("date", window_size="3d"),
         ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_max │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 2.0         │
-        │ 3.0 ┆ 3.0         │
-        │ 4.0 ┆ 4.0         │
-        │ 5.0 ┆ 5.0         │
-        │ 6.0 ┆ 6.0         │
-        └─────┴─────────────┘
+        shape: (5, 3)
+        ┌────────────┬─────┬──────┐
+        │ date       ┆ a   ┆ mode │
+        │ ---        ┆ --- ┆ ---  │
+        │ date       ┆ i64 ┆ i64  │
+        ╞════════════╪═════╪══════╡
+        │ 2025-01-01 ┆ 3   ┆ 3    │
+        │ 2025-01-02 ┆ 1   ┆ 1    │
+        │ 2025-01-03 ┆ 3   ┆ 3    │
+        │ 2025-01-06 ┆ 2   ┆ 2    │
+        │ 2025-01-07 ┆ 5   ┆ 2    │
+        └────────────┴─────┴──────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_mode_by(by, window_size, min_samples, closed)
+        )
 
-        Specify weights to multiply the values in the window with:
+    @unstable()
+    def rolling_rank_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        method: RollingRankMethod = "average",
+        min_samples: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Compute a rolling rank of every value within its window based on another column.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Every value is ranked against the values of its own window, the smallest
+        value being ranked 1. Null values are not ranked. The result is `Float64`
+        for the `'average'` method and `UInt32` otherwise.
+
+        Parameters
+        ----------
+        by
+            Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+            or ``Int32`` data type (note that the integral ones require using `'i'`
+            in `window size`).
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        method : {'average', 'min', 'max', 'dense'}
+            The method used to assign ranks to tied elements.
+            The following methods are available (default is 'average'):
+
+            - 'average' : The average of the ranks that would have been assigned to
+              all the tied values is assigned to each value.
+            - 'min' : The minimum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'max' : The maximum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'dense' : Like 'min', but the rank of the next highest element is
+              assigned the rank immediately after those assigned to the tied
+              elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+        See Also
+        --------
+        Expr.rolling_rank
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2025, 1, d) for d in (1, 2, 3, 6, 7)],
+        ...         "a": [3, 1, 3, 2, 5],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     rank=pl.col("a").rolling_rank_by("date", window_size="3d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬─────┬──────┐
+        │ date       ┆ a   ┆ rank │
+        │ ---        ┆ --- ┆ ---  │
+        │ date       ┆ i64 ┆ f64  │
+        ╞════════════╪═════╪══════╡
+        │ 2025-01-01 ┆ 3   ┆ 1.0  │
+        │ 2025-01-02 ┆ 1   ┆ 1.0  │
+        │ 2025-01-03 ┆ 3   ┆ 2.5  │
+        │ 2025-01-06 ┆ 2   ┆ 1.0  │
+        │ 2025-01-07 ┆ 5   ┆ 2.0  │
+        └────────────┴─────┴──────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_rank_by(by, window_size, method, min_samples, closed)
+        )
+
+    @unstable()
+    def rolling_arg_min_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_samples: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Compute the rolling index of the minimum value based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        The index is relative to the start of the window. Null values are ignored
+        and ties are resolved in favor of the first occurrence.
+
+        Parameters
+        ----------
+        by
+            Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+            or ``Int32`` data type (note that the integral ones require using `'i'`
+            in `window size`).
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+        See Also
+        --------
+        Expr.rolling_arg_min
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2025, 1, d) for d in (1, 2, 3, 6, 7)],
+        ...         "a": [3, 1, 3, 2, 5],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     arg_min=pl.col("a").rolling_arg_min_by("date", window_size="3d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬─────┬─────────┐
+        │ date       ┆ a   ┆ arg_min │
+        │ ---        ┆ --- ┆ ---     │
+        │ date       ┆ i64 ┆ u32     │
+        ╞════════════╪═════╪═════════╡
+        │ 2025-01-01 ┆ 3   ┆ 0       │
+        │ 2025-01-02 ┆ 1   ┆ 1       │
+        │ 2025-01-03 ┆ 3   ┆ 1       │
+        │ 2025-01-06 ┆ 2   ┆ 0       │
+        │ 2025-01-07 ┆ 5   ┆ 0       │
+        └────────────┴─────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_arg_min_by(by, window_size, min_samples, closed)
+        )
+
+    @unstable()
+    def rolling_arg_max_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_samples: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Compute the rolling index of the maximum value based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        The index is relative to the start of the window. Null values are ignored
+        and ties are resolved in favor of the first occurrence.
+
+        Parameters
+        ----------
+        by
+            Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+            or ``Int32`` data type (note that the integral ones require using `'i'`
+            in `window size`).
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+        See Also
+        --------
+        Expr.rolling_arg_max
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2025, 1, d) for d in (1, 2, 3, 6, 7)],
+        ...         "a": [3, 1, 3, 2, 5],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     arg_max=pl.col("a").rolling_arg_max_by("date", window_size="3d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬─────┬─────────┐
+        │ date       ┆ a   ┆ arg_max │
+        │ ---        ┆ --- ┆ ---     │
+        │ date       ┆ i64 ┆ u32     │
+        ╞════════════╪═════╪═════════╡
+        │ 2025-01-01 ┆ 3   ┆ 0       │
+        │ 2025-01-02 ┆ 1   ┆ 0       │
+        │ 2025-01-03 ┆ 3   ┆ 0       │
+        │ 2025-01-06 ┆ 2   ┆ 0       │
+        │ 2025-01-07 ┆ 5   ┆ 1       │
+        └────────────┴─────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_arg_max_by(by, window_size, min_samples, closed)
+        )
+
+    @unstable()
+    def rolling_first_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_samples: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Get the first non-null value of a rolling window based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+            or ``Int32`` data type (note that the integral ones require using `'i'`
+            in `window size`).
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+        See Also
+        --------
+        Expr.rolling_first
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2025, 1, d) for d in (1, 2, 3, 6, 7)],
+        ...         "a": [3, 1, 3, 2, 5],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     first=pl.col("a").rolling_first_by("date", window_size="3d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬─────┬───────┐
+        │ date       ┆ a   ┆ first │
+        │ ---        ┆ --- ┆ ---   │
+        │ date       ┆ i64 ┆ i64   │
+        ╞════════════╪═════╪═══════╡
+        │ 2025-01-01 ┆ 3   ┆ 3     │
+        │ 2025-01-02 ┆ 1   ┆ 3     │
+        │ 2025-01-03 ┆ 3   ┆ 3     │
+        │ 2025-01-06 ┆ 2   ┆ 2     │
+        │ 2025-01-07 ┆ 5   ┆ 2     │
+        └────────────┴─────┴───────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_first_by(by, window_size, min_samples, closed)
+        )
+
+    @unstable()
+    def rolling_last_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_samples: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Get the last non-null value of a rolling window based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Parameters
+        ----------
+        by
+            Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+            or ``Int32`` data type (note that the integral ones require using `'i'`
+            in `window size`).
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+        See Also
+        --------
+        Expr.rolling_last
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2025, 1, d) for d in (1, 2, 3, 6, 7)],
+        ...         "a": [3, 1, 3, 2, 5],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     last=pl.col("a").rolling_last_by("date", window_size="3d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬─────┬──────┐
+        │ date       ┆ a   ┆ last │
+        │ ---        ┆ --- ┆ ---  │
+        │ date       ┆ i64 ┆ i64  │
+        ╞════════════╪═════╪══════╡
+        │ 2025-01-01 ┆ 3   ┆ 3    │
+        │ 2025-01-02 ┆ 1   ┆ 1    │
+        │ 2025-01-03 ┆ 3   ┆ 3    │
+        │ 2025-01-06 ┆ 2   ┆ 2    │
+        │ 2025-01-07 ┆ 5   ┆ 5    │
+        └────────────┴─────┴──────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_last_by(by, window_size, min_samples, closed)
+        )
+
+    @unstable()
+    def rolling_product_by(
+        self,
+        by: IntoExpr,
+        window_size: timedelta | str,
+        *,
+        min_samples: int = 1,
+        closed: ClosedInterval = "right",
+    ) -> Expr:
+        """
+        Compute a rolling product based on another column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Given a `by` column `<t_0, t_1, ..., t_n>`, then `closed="right"`
+        (the default) means the windows will be:
+
+            - (t_0 - window_size, t_0]
+            - (t_1 - window_size, t_1]
+            - ...
+            - (t_n - window_size, t_n]
+
+        Null values are ignored. Boolean and integer inputs smaller than 64 bits are
+        cast to `Int64` before the product is computed, to prevent overflow.
+
+        Parameters
+        ----------
+        by
+            Should be ``DateTime``, ``Date``, ``UInt64``, ``UInt32``, ``Int64``,
+            or ``Int32`` data type (note that the integral ones require using `'i'`
+            in `window size`).
+        window_size
+            The length of the window. Can be a dynamic temporal
+            size indicated by a timedelta or the following string language:
+
+            - 1ns   (1 nanosecond)
+            - 1us   (1 microsecond)
+            - 1ms   (1 millisecond)
+            - 1s    (1 second)
+            - 1m    (1 minute)
+            - 1h    (1 hour)
+            - 1d    (1 calendar day)
+            - 1w    (1 calendar week)
+            - 1mo   (1 calendar month)
+            - 1q    (1 calendar quarter)
+            - 1y    (1 calendar year)
+            - 1i    (1 index count)
+
+            By "calendar day", we mean the corresponding time on the next day
+            (which may not be 24 hours, due to daylight savings). Similarly for
+            "calendar week", "calendar month", "calendar quarter", and
+            "calendar year".
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result.
+        closed : {'left', 'right', 'both', 'none'}
+            Define which sides of the temporal interval are closed (inclusive),
+            defaults to `'right'`.
+
+        See Also
+        --------
+        Expr.rolling_product
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "date": [date(2025, 1, d) for d in (1, 2, 3, 6, 7)],
+        ...         "a": [3, 1, 3, 2, 5],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     product=pl.col("a").rolling_product_by("date", window_size="3d"),
+        ... )
+        shape: (5, 3)
+        ┌────────────┬─────┬─────────┐
+        │ date       ┆ a   ┆ product │
+        │ ---        ┆ --- ┆ ---     │
+        │ date       ┆ i64 ┆ i64     │
+        ╞════════════╪═════╪═════════╡
+        │ 2025-01-01 ┆ 3   ┆ 3       │
+        │ 2025-01-02 ┆ 1   ┆ 3       │
+        │ 2025-01-03 ┆ 3   ┆ 9       │
+        │ 2025-01-06 ┆ 2   ┆ 2       │
+        │ 2025-01-07 ┆ 5   ┆ 10      │
+        └────────────┴─────┴─────────┘
+        """
+        window_size = _prepare_rolling_by_window_args(window_size)
+        by = parse_into_expression(by)
+        return self._from_pyexpr(
+            self._pyexpr.rolling_product_by(by, window_size, min_samples, closed)
+        )
+
+    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
+    def rolling_min(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling min (moving min) over the values in this array.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their min.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        .. versionchanged:: 1.21.0
+            The `min_periods` parameter was renamed `min_samples`.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_min=pl.col("A").rolling_min(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_min │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.0         │
+        │ 3.0 ┆ 2.0         │
+        │ 4.0 ┆ 3.0         │
+        │ 5.0 ┆ 4.0         │
+        │ 6.0 ┆ 5.0         │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_min=pl.col("A").rolling_min(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_min │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.25        │
+        │ 3.0 ┆ 0.5         │
+        │ 4.0 ┆ 0.75        │
+        │ 5.0 ┆ 1.0         │
+        │ 6.0 ┆ 1.25        │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_min=pl.col("A").rolling_min(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_min │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.0         │
+        │ 3.0 ┆ 2.0         │
+        │ 4.0 ┆ 3.0         │
+        │ 5.0 ┆ 4.0         │
+        │ 6.0 ┆ null        │
+        └─────┴─────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_min(
+                window_size,
+                weights,
+                min_samples,
+                center=center,
+            )
+        )
+
+    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
+    def rolling_max(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling max (moving max) over the values in this array.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their max.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        .. versionchanged:: 1.21.0
+            The `min_periods` parameter was renamed `min_samples`.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_max=pl.col("A").rolling_max(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_max │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 2.0         │
+        │ 3.0 ┆ 3.0         │
+        │ 4.0 ┆ 4.0         │
+        │ 5.0 ┆ 5.0         │
+        │ 6.0 ┆ 6.0         │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_max=pl.col("A").rolling_max(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_max │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.5         │
+        │ 3.0 ┆ 2.25        │
+        │ 4.0 ┆ 3.0         │
+        │ 5.0 ┆ 3.75        │
+        │ 6.0 ┆ 4.5         │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_max=pl.col("A").rolling_max(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_max │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 3.0         │
+        │ 3.0 ┆ 4.0         │
+        │ 4.0 ┆ 5.0         │
+        │ 5.0 ┆ 6.0         │
+        │ 6.0 ┆ null        │
+        └─────┴─────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_max(
+                window_size,
+                weights,
+                min_samples,
+                center,
+            )
+        )
+
+    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
+    def rolling_mean(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling mean (moving mean) over the values in this array.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their mean.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        .. versionchanged:: 1.21.0
+            The `min_periods` parameter was renamed `min_samples`.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_mean=pl.col("A").rolling_mean(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬──────────────┐
+        │ A   ┆ rolling_mean │
+        │ --- ┆ ---          │
+        │ f64 ┆ f64          │
+        ╞═════╪══════════════╡
+        │ 1.0 ┆ null         │
+        │ 2.0 ┆ 1.5          │
+        │ 3.0 ┆ 2.5          │
+        │ 4.0 ┆ 3.5          │
+        │ 5.0 ┆ 4.5          │
+        │ 6.0 ┆ 5.5          │
+        └─────┴──────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_mean=pl.col("A").rolling_mean(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬──────────────┐
+        │ A   ┆ rolling_mean │
+        │ --- ┆ ---          │
+        │ f64 ┆ f64          │
+        ╞═════╪══════════════╡
+        │ 1.0 ┆ null         │
+        │ 2.0 ┆ 1.75         │
+        │ 3.0 ┆ 2.75         │
+        │ 4.0 ┆ 3.75         │
+        │ 5.0 ┆ 4.75         │
+        │ 6.0 ┆ 5.75         │
+        └─────┴──────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_mean=pl.col("A").rolling_mean(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬──────────────┐
+        │ A   ┆ rolling_mean │
+        │ --- ┆ ---          │
+        │ f64 ┆ f64          │
+        ╞═════╪══════════════╡
+        │ 1.0 ┆ null         │
+        │ 2.0 ┆ 2.0          │
+        │ 3.0 ┆ 3.0          │
+        │ 4.0 ┆ 4.0          │
+        │ 5.0 ┆ 5.0          │
+        │ 6.0 ┆ null         │
+        └─────┴──────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_mean(
+                window_size,
+                weights,
+                min_samples,
+                center,
+            )
+        )
+
+    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
+    def rolling_sum(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Apply a rolling sum (moving sum) over the values in this array.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their sum.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        .. versionchanged:: 1.21.0
+            The `min_periods` parameter was renamed `min_samples`.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_sum=pl.col("A").rolling_sum(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_sum │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 3.0         │
+        │ 3.0 ┆ 5.0         │
+        │ 4.0 ┆ 7.0         │
+        │ 5.0 ┆ 9.0         │
+        │ 6.0 ┆ 11.0        │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_sum=pl.col("A").rolling_sum(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_sum │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.75        │
+        │ 3.0 ┆ 2.75        │
+        │ 4.0 ┆ 3.75        │
+        │ 5.0 ┆ 4.75        │
+        │ 6.0 ┆ 5.75        │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_sum=pl.col("A").rolling_sum(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_sum │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 6.0         │
+        │ 3.0 ┆ 9.0         │
+        │ 4.0 ┆ 12.0        │
+        │ 5.0 ┆ 15.0        │
+        │ 6.0 ┆ null        │
+        └─────┴─────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_sum(
+                window_size,
+                weights,
+                min_samples,
+                center,
+            )
+        )
+
+    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
+    def rolling_std(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> Expr:
+        """
+        Compute a rolling standard deviation.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their std.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        .. versionchanged:: 1.21.0
+            The `min_periods` parameter was renamed `min_samples`.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_std=pl.col("A").rolling_std(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_std │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.707107    │
+        │ 3.0 ┆ 0.707107    │
+        │ 4.0 ┆ 0.707107    │
+        │ 5.0 ┆ 0.707107    │
+        │ 6.0 ┆ 0.707107    │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_std=pl.col("A").rolling_std(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_std │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.433013    │
+        │ 3.0 ┆ 0.433013    │
+        │ 4.0 ┆ 0.433013    │
+        │ 5.0 ┆ 0.433013    │
+        │ 6.0 ┆ 0.433013    │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_std=pl.col("A").rolling_std(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_std │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.0         │
+        │ 3.0 ┆ 1.0         │
+        │ 4.0 ┆ 1.0         │
+        │ 5.0 ┆ 1.0         │
+        │ 6.0 ┆ null        │
+        └─────┴─────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_std(
+                window_size,
+                weights,
+                min_samples,
+                center=center,
+                ddof=ddof,
+            )
+        )
+
+    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
+    def rolling_var(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+        ddof: int = 1,
+    ) -> Expr:
+        """
+        Compute a rolling variance.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their var.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        .. versionchanged:: 1.21.0
+            The `min_periods` parameter was renamed `min_samples`.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+        ddof
+            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
+        >>> df.with_columns(
+        ...     rolling_var=pl.col("A").rolling_var(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_var │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.5         │
+        │ 3.0 ┆ 0.5         │
+        │ 4.0 ┆ 0.5         │
+        │ 5.0 ┆ 0.5         │
+        │ 6.0 ┆ 0.5         │
+        └─────┴─────────────┘
+
+        Specify weights to multiply the values in the window with:
+
+        >>> df.with_columns(
+        ...     rolling_var=pl.col("A").rolling_var(
+        ...         window_size=2, weights=[0.25, 0.75]
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_var │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 0.1875      │
+        │ 3.0 ┆ 0.1875      │
+        │ 4.0 ┆ 0.1875      │
+        │ 5.0 ┆ 0.1875      │
+        │ 6.0 ┆ 0.1875      │
+        └─────┴─────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_var=pl.col("A").rolling_var(window_size=3, center=True),
+        ... )
+        shape: (6, 2)
+        ┌─────┬─────────────┐
+        │ A   ┆ rolling_var │
+        │ --- ┆ ---         │
+        │ f64 ┆ f64         │
+        ╞═════╪═════════════╡
+        │ 1.0 ┆ null        │
+        │ 2.0 ┆ 1.0         │
+        │ 3.0 ┆ 1.0         │
+        │ 4.0 ┆ 1.0         │
+        │ 5.0 ┆ 1.0         │
+        │ 6.0 ┆ null        │
+        └─────┴─────────────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_var(
+                window_size,
+                weights,
+                min_samples,
+                center=center,
+                ddof=ddof,
+            )
+        )
+
+    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
+    def rolling_median(
+        self,
+        window_size: int,
+        weights: list[float] | None = None,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute a rolling median.
+
+        A window of length `window_size` will traverse the array. The values that fill
+        this window will (optionally) be multiplied with the weights given by the
+        `weights` vector. The resulting values will be aggregated to their median.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        .. versionchanged:: 1.21.0
+            The `min_periods` parameter was renamed `min_samples`.
+
+        Parameters
+        ----------
+        window_size
+            The length of the window in number of elements.
+        weights
+            An optional slice with the same length as the window that will be multiplied
+            elementwise with the values in the window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        Notes
+        -----
+        If you want to compute multiple aggregation statistics over the same dynamic
+        window, consider using `rolling` - this method can cache the window size
+        computation.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
         >>> df.with_columns(
-        ...     rolling_max=pl.col("A").rolling_max(
+        ...     rolling_median=pl.col("A").rolling_median(window_size=2),
+        ... )
+        shape: (6, 2)
+        ┌─────┬────────────────┐
+        │ A   ┆ rolling_median │
+        │ --- ┆ ---            │
+        │ f64 ┆ f64            │
+        ╞═════╪════════════════╡
+        │ 1.0 ┆ null           │
+        │ 2.0 ┆ 1.5            │
+        │ 3.0 ┆ 2.5            │
+        │ 4.0 ┆ 3.5            │
+        │ 5.0 ┆ 4.5            │
+        │ 6.0 ┆ 5.5            │
+        └─────┴────────────────┘
+
+        Specify weights for the values in each window:
+
+        >>> df.with_columns(
+        ...     rolling_median=pl.col("A").rolling_median(
         ...         window_size=2, weights=[0.25, 0.75]
         ...     ),
         ... )
         shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_max │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.5         │
-        │ 3.0 ┆ 2.25        │
-        │ 4.0 ┆ 3.0         │
-        │ 5.0 ┆ 3.75        │
-        │ 6.0 ┆ 4.5         │
-        └─────┴─────────────┘
+        ┌─────┬────────────────┐
+        │ A   ┆ rolling_median │
+        │ --- ┆ ---            │
+        │ f64 ┆ f64            │
+        ╞═════╪════════════════╡
+        │ 1.0 ┆ null           │
+        │ 2.0 ┆ 1.5            │
+        │ 3.0 ┆ 2.5            │
+        │ 4.0 ┆ 3.5            │
+        │ 5.0 ┆ 4.5            │
+        │ 6.0 ┆ 5.5            │
+        └─────┴────────────────┘
 
         Center the values in the window
 
         >>> df.with_columns(
-        ...     rolling_max=pl.col("A").rolling_max(window_size=3, center=True),
+        ...     rolling_median=pl.col("A").rolling_median(window_size=3, center=True),
         ... )
         shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_max │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 3.0         │
-        │ 3.0 ┆ 4.0         │
-        │ 4.0 ┆ 5.0         │
-        │ 5.0 ┆ 6.0         │
-        │ 6.0 ┆ null        │
-        └─────┴─────────────┘
+        ┌─────┬────────────────┐
+        │ A   ┆ rolling_median │
+        │ --- ┆ ---            │
+        │ f64 ┆ f64            │
+        ╞═════╪════════════════╡
+        │ 1.0 ┆ null           │
+        │ 2.0 ┆ 2.0            │
+        │ 3.0 ┆ 3.0            │
+        │ 4.0 ┆ 4.0            │
+        │ 5.0 ┆ 5.0            │
+        │ 6.0 ┆ null           │
+        └─────┴────────────────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_max(
+            self._pyexpr.rolling_median(
                 window_size,
                 weights,
                 min_samples,
-                center,
+                center=center,
             )
         )
 
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
-    def rolling_mean(
+    def rolling_quantile(
         self,
-        window_size: int,
+        quantile: float,
+        interpolation: QuantileMethod = "nearest",
+        window_size: int = 2,
         weights: list[float] | None = None,
         *,
         min_samples: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Apply a rolling mean (moving mean) over the values in this array.
+        Compute a rolling quantile.
 
         A window of length `window_size` will traverse the array. The values that fill
         this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their mean.
+        `weights` vector. The resulting values will be aggregated to their quantile.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
@@ -7720,6 +9108,10 @@ class Expr:
 
         Parameters
         ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable'}
+            Interpolation method.
         window_size
             The length of the window in number of elements.
         weights
@@ -7741,676 +9133,606 @@ class Expr:
         --------
         >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
         >>> df.with_columns(
-        ...     rolling_mean=pl.col("A").rolling_mean(window_size=2),
+        ...     rolling_quantile=pl.col("A").rolling_quantile(
+        ...         quantile=0.25, window_size=4
+        ...     ),
         ... )
         shape: (6, 2)
-        ┌─────┬──────────────┐
-        │ A   ┆ rolling_mean │
-        │ --- ┆ ---          │
-        │ f64 ┆ f64          │
-        ╞═════╪══════════════╡
-        │ 1.0 ┆ null         │
-        │ 2.0 ┆ 1.5          │
-        │ 3.0 ┆ 2.5          │
-        │ 4.0 ┆ 3.5          │
-        │ 5.0 ┆ 4.5          │
-        │ 6.0 ┆ 5.5          │
-        └─────┴──────────────┘
+        ┌─────┬──────────────────┐
+        │ A   ┆ rolling_quantile │
+        │ --- ┆ ---              │
+        │ f64 ┆ f64              │
+        ╞═════╪══════════════════╡
+        │ 1.0 ┆ null             │
+        │ 2.0 ┆ null             │
+        │ 3.0 ┆ null             │
+        │ 4.0 ┆ 2.0              │
+        │ 5.0 ┆ 3.0              │
+        │ 6.0 ┆ 4.0              │
+        └─────┴──────────────────┘
 
-        Specify weights to multiply the values in the window with:
+        Specify weights for the values in each window:
 
         >>> df.with_columns(
-        ...     rolling_mean=pl.col("A").rolling_mean(
-        ...         window_size=2, weights=[0.25, 0.75]
+        ...     rolling_quantile=pl.col("A").rolling_quantile(
+        ...         quantile=0.25, window_size=4, weights=[0.2, 0.4, 0.4, 0.2]
         ...     ),
         ... )
         shape: (6, 2)
-        ┌─────┬──────────────┐
-        │ A   ┆ rolling_mean │
-        │ --- ┆ ---          │
-        │ f64 ┆ f64          │
-        ╞═════╪══════════════╡
-        │ 1.0 ┆ null         │
-        │ 2.0 ┆ 1.75         │
-        │ 3.0 ┆ 2.75         │
-        │ 4.0 ┆ 3.75         │
-        │ 5.0 ┆ 4.75         │
-        │ 6.0 ┆ 5.75         │
-        └─────┴──────────────┘
+        ┌─────┬──────────────────┐
+        │ A   ┆ rolling_quantile │
+        │ --- ┆ ---              │
+        │ f64 ┆ f64              │
+        ╞═════╪══════════════════╡
+        │ 1.0 ┆ null             │
+        │ 2.0 ┆ null             │
+        │ 3.0 ┆ null             │
+        │ 4.0 ┆ 2.0              │
+        │ 5.0 ┆ 3.0              │
+        │ 6.0 ┆ 4.0              │
+        └─────┴──────────────────┘
+
+        Specify weights and interpolation method
+
+        >>> df.with_columns(
+        ...     rolling_quantile=pl.col("A").rolling_quantile(
+        ...         quantile=0.25,
+        ...         window_size=4,
+        ...         weights=[0.2, 0.4, 0.4, 0.2],
+        ...         interpolation="linear",
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬──────────────────┐
+        │ A   ┆ rolling_quantile │
+        │ --- ┆ ---              │
+        │ f64 ┆ f64              │
+        ╞═════╪══════════════════╡
+        │ 1.0 ┆ null             │
+        │ 2.0 ┆ null             │
+        │ 3.0 ┆ null             │
+        │ 4.0 ┆ 1.625            │
+        │ 5.0 ┆ 2.625            │
+        │ 6.0 ┆ 3.625            │
+        └─────┴──────────────────┘
+
+        Center the values in the window
+
+        >>> df.with_columns(
+        ...     rolling_quantile=pl.col("A").rolling_quantile(
+        ...         quantile=0.2, window_size=5, center=True
+        ...     ),
+        ... )
+        shape: (6, 2)
+        ┌─────┬──────────────────┐
+        │ A   ┆ rolling_quantile │
+        │ --- ┆ ---              │
+        │ f64 ┆ f64              │
+        ╞═════╪══════════════════╡
+        │ 1.0 ┆ null             │
+        │ 2.0 ┆ null             │
+        │ 3.0 ┆ 2.0              │
+        │ 4.0 ┆ 3.0              │
+        │ 5.0 ┆ null             │
+        │ 6.0 ┆ null             │
+        └─────┴──────────────────┘
+        """  # noqa: W505
+        return self._from_pyexpr(
+            self._pyexpr.rolling_quantile(
+                quantile,
+                interpolation,
+                window_size,
+                weights,
+                min_samples,
+                center=center,
+            )
+        )
+
+    @unstable()
+    def rolling_skew(
+        self,
+        window_size: int,
+        *,
+        bias: bool = True,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute a rolling skew.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        bias
+            If False, the calculations are corrected for statistical bias.
+                     bias: bool = True,
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Expr.skew
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").rolling_skew(3))
+        shape: (4, 1)
+        ┌──────────┐
+        │ a        │
+        │ ---      │
+        │ f64      │
+        ╞══════════╡
+        │ null     │
+        │ null     │
+        │ 0.381802 │
+        │ 0.47033  │
+        └──────────┘
 
-        Center the values in the window
+        Note how the values match the following:
 
-        >>> df.with_columns(
-        ...     rolling_mean=pl.col("A").rolling_mean(window_size=3, center=True),
-        ... )
-        shape: (6, 2)
-        ┌─────┬──────────────┐
-        │ A   ┆ rolling_mean │
-        │ --- ┆ ---          │
-        │ f64 ┆ f64          │
-        ╞═════╪══════════════╡
-        │ 1.0 ┆ null         │
-        │ 2.0 ┆ 2.0          │
-        │ 3.0 ┆ 3.0          │
-        │ 4.0 ┆ 4.0          │
-        │ 5.0 ┆ 5.0          │
-        │ 6.0 ┆ null         │
-        └─────┴──────────────┘
+        >>> pl.Series([1, 4, 2]).skew(), pl.Series([4, 2, 9]).skew()
+        (0.38180177416060584, 0.47033046033698594)
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_mean(
-                window_size,
-                weights,
-                min_samples,
-                center,
+            self._pyexpr.rolling_skew(
+                window_size, bias=bias, min_periods=min_samples, center=center
             )
         )
 
-    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
-    def rolling_sum(
+    @unstable()
+    def rolling_kurtosis(
         self,
         window_size: int,
-        weights: list[float] | None = None,
         *,
+        fisher: bool = True,
+        bias: bool = True,
         min_samples: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Apply a rolling sum (moving sum) over the values in this array.
+        Compute a rolling kurtosis.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their sum.
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
 
-        .. versionchanged:: 1.21.0
-            The `min_periods` parameter was renamed `min_samples`.
-
         Parameters
         ----------
         window_size
-            The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
+            Integer size of the rolling window.
+        fisher : bool, optional
+            If True, Fisher's definition is used (normal ==> 0.0). If False,
+            Pearson's definition is used (normal ==> 3.0).
+        bias : bool, optional
+            If False, the calculations are corrected for statistical bias.
         min_samples
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
+        See Also
+        --------
+        Expr.kurtosis
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_sum=pl.col("A").rolling_sum(window_size=2),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_sum │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 3.0         │
-        │ 3.0 ┆ 5.0         │
-        │ 4.0 ┆ 7.0         │
-        │ 5.0 ┆ 9.0         │
-        │ 6.0 ┆ 11.0        │
-        └─────┴─────────────┘
-
-        Specify weights to multiply the values in the window with:
-
-        >>> df.with_columns(
-        ...     rolling_sum=pl.col("A").rolling_sum(
-        ...         window_size=2, weights=[0.25, 0.75]
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_sum │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.75        │
-        │ 3.0 ┆ 2.75        │
-        │ 4.0 ┆ 3.75        │
-        │ 5.0 ┆ 4.75        │
-        │ 6.0 ┆ 5.75        │
-        └─────┴─────────────┘
-
-        Center the values in the window
-
-        >>> df.with_columns(
-        ...     rolling_sum=pl.col("A").rolling_sum(window_size=3, center=True),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_sum │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 6.0         │
-        │ 3.0 ┆ 9.0         │
-        │ 4.0 ┆ 12.0        │
-        │ 5.0 ┆ 15.0        │
-        │ 6.0 ┆ null        │
-        └─────┴─────────────┘
+        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
+        >>> df.select(pl.col("a").rolling_kurtosis(3))
+        shape: (4, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ f64  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ -1.5 │
+        │ -1.5 │
+        └──────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_sum(
+            self._pyexpr.rolling_kurtosis(
                 window_size,
-                weights,
-                min_samples,
-                center,
+                fisher=fisher,
+                bias=bias,
+                min_periods=min_samples,
+                center=center,
             )
         )
 
-    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
-    def rolling_std(
+    @unstable()
+    def rolling_n_unique(
         self,
         window_size: int,
-        weights: list[float] | None = None,
         *,
         min_samples: int | None = None,
         center: bool = False,
-        ddof: int = 1,
     ) -> Expr:
         """
-        Compute a rolling standard deviation.
+        Compute a rolling count of the unique values.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their std.
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
 
-        .. versionchanged:: 1.21.0
-            The `min_periods` parameter was renamed `min_samples`.
+        Null values are ignored.
 
         Parameters
         ----------
         window_size
-            The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
+            Integer size of the rolling window.
         min_samples
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
-        ddof
-            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
+        See Also
+        --------
+        Expr.n_unique
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_std=pl.col("A").rolling_std(window_size=2),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_std │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.707107    │
-        │ 3.0 ┆ 0.707107    │
-        │ 4.0 ┆ 0.707107    │
-        │ 5.0 ┆ 0.707107    │
-        │ 6.0 ┆ 0.707107    │
-        └─────┴─────────────┘
+        >>> df = pl.DataFrame({"a": [1, 2, 2, 3, 1, 1]})
+        >>> df.select(pl.col("a").rolling_n_unique(3))
+        shape: (6, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ u32  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ 2    │
+        │ 2    │
+        │ 3    │
+        │ 2    │
+        └──────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_n_unique(
+                window_size, min_periods=min_samples, center=center
+            )
+        )
 
-        Specify weights to multiply the values in the window with:
+    @unstable()
+    def rolling_mode(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute a rolling mode.
 
-        >>> df.with_columns(
-        ...     rolling_std=pl.col("A").rolling_std(
-        ...         window_size=2, weights=[0.25, 0.75]
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_std │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.433013    │
-        │ 3.0 ┆ 0.433013    │
-        │ 4.0 ┆ 0.433013    │
-        │ 5.0 ┆ 0.433013    │
-        │ 6.0 ┆ 0.433013    │
-        └─────┴─────────────┘
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
-        Center the values in the window
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
 
-        >>> df.with_columns(
-        ...     rolling_std=pl.col("A").rolling_std(window_size=3, center=True),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_std │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.0         │
-        │ 3.0 ┆ 1.0         │
-        │ 4.0 ┆ 1.0         │
-        │ 5.0 ┆ 1.0         │
-        │ 6.0 ┆ null        │
-        └─────┴─────────────┘
+        Null values are ignored. If several values are equally frequent within a
+        window, the smallest of them is returned.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Expr.mode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 2, 3, 1, 1]})
+        >>> df.select(pl.col("a").rolling_mode(3))
+        shape: (6, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ 2    │
+        │ 2    │
+        │ 1    │
+        │ 1    │
+        └──────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_std(
-                window_size,
-                weights,
-                min_samples,
-                center=center,
-                ddof=ddof,
+            self._pyexpr.rolling_mode(
+                window_size, min_periods=min_samples, center=center
             )
         )
 
-    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
-    def rolling_var(
+    @unstable()
+    def rolling_rank(
         self,
         window_size: int,
-        weights: list[float] | None = None,
         *,
+        method: RollingRankMethod = "average",
         min_samples: int | None = None,
         center: bool = False,
-        ddof: int = 1,
     ) -> Expr:
         """
-        Compute a rolling variance.
+        Compute a rolling rank of every value within its window.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their var.
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
 
-        .. versionchanged:: 1.21.0
-            The `min_periods` parameter was renamed `min_samples`.
+        Every value is ranked against the values of its own window, the smallest
+        value being ranked 1. Null values are not ranked. The result is `Float64`
+        for the `'average'` method and `UInt32` otherwise.
 
         Parameters
         ----------
         window_size
-            The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
+            Integer size of the rolling window.
+        method : {'average', 'min', 'max', 'dense'}
+            The method used to assign ranks to tied elements.
+            The following methods are available (default is 'average'):
+
+            - 'average' : The average of the ranks that would have been assigned to
+              all the tied values is assigned to each value.
+            - 'min' : The minimum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'max' : The maximum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'dense' : Like 'min', but the rank of the next highest element is
+              assigned the rank immediately after those assigned to the tied
+              elements.
         min_samples
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
-        ddof
-            "Delta Degrees of Freedom": The divisor for a length N window is N - ddof
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
+        See Also
+        --------
+        Expr.rank
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_var=pl.col("A").rolling_var(window_size=2),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_var │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.5         │
-        │ 3.0 ┆ 0.5         │
-        │ 4.0 ┆ 0.5         │
-        │ 5.0 ┆ 0.5         │
-        │ 6.0 ┆ 0.5         │
-        └─────┴─────────────┘
-
-        Specify weights to multiply the values in the window with:
-
-        >>> df.with_columns(
-        ...     rolling_var=pl.col("A").rolling_var(
-        ...         window_size=2, weights=[0.25, 0.75]
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_var │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 0.1875      │
-        │ 3.0 ┆ 0.1875      │
-        │ 4.0 ┆ 0.1875      │
-        │ 5.0 ┆ 0.1875      │
-        │ 6.0 ┆ 0.1875      │
-        └─────┴─────────────┘
-
-        Center the values in the window
-
+        >>> df = pl.DataFrame({"a": [3, 1, 3, 2, 3, 0]})
         >>> df.with_columns(
-        ...     rolling_var=pl.col("A").rolling_var(window_size=3, center=True),
+        ...     average=pl.col("a").rolling_rank(3),
+        ...     dense=pl.col("a").rolling_rank(3, method="dense"),
         ... )
-        shape: (6, 2)
-        ┌─────┬─────────────┐
-        │ A   ┆ rolling_var │
-        │ --- ┆ ---         │
-        │ f64 ┆ f64         │
-        ╞═════╪═════════════╡
-        │ 1.0 ┆ null        │
-        │ 2.0 ┆ 1.0         │
-        │ 3.0 ┆ 1.0         │
-        │ 4.0 ┆ 1.0         │
-        │ 5.0 ┆ 1.0         │
-        │ 6.0 ┆ null        │
-        └─────┴─────────────┘
+        shape: (6, 3)
+        ┌─────┬─────────┬───────┐
+        │ a   ┆ average ┆ dense │
+        │ --- ┆ ---     ┆ ---   │
+        │ i64 ┆ f64     ┆ u32   │
+        ╞═════╪═════════╪═══════╡
+        │ 3   ┆ null    ┆ null  │
+        │ 1   ┆ null    ┆ null  │
+        │ 3   ┆ 2.5     ┆ 2     │
+        │ 2   ┆ 2.0     ┆ 2     │
+        │ 3   ┆ 2.5     ┆ 2     │
+        │ 0   ┆ 1.0     ┆ 1     │
+        └─────┴─────────┴───────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_var(
-                window_size,
-                weights,
-                min_samples,
-                center=center,
-                ddof=ddof,
+            self._pyexpr.rolling_rank(
+                window_size, method, min_periods=min_samples, center=center
             )
         )
 
-    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
-    def rolling_median(
+    @unstable()
+    def rolling_arg_min(
         self,
         window_size: int,
-        weights: list[float] | None = None,
         *,
         min_samples: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Compute a rolling median.
+        Compute the rolling index of the minimum value.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their median.
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
 
-        .. versionchanged:: 1.21.0
-            The `min_periods` parameter was renamed `min_samples`.
+        The index is relative to the start of the window. Null values are ignored
+        and ties are resolved in favor of the first occurrence.
 
         Parameters
         ----------
         window_size
-            The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
+            Integer size of the rolling window.
         min_samples
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
+        See Also
+        --------
+        Expr.arg_min
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_median=pl.col("A").rolling_median(window_size=2),
-        ... )
-        shape: (6, 2)
-        ┌─────┬────────────────┐
-        │ A   ┆ rolling_median │
-        │ --- ┆ ---            │
-        │ f64 ┆ f64            │
-        ╞═════╪════════════════╡
-        │ 1.0 ┆ null           │
-        │ 2.0 ┆ 1.5            │
-        │ 3.0 ┆ 2.5            │
-        │ 4.0 ┆ 3.5            │
-        │ 5.0 ┆ 4.5            │
-        │ 6.0 ┆ 5.5            │
-        └─────┴────────────────┘
+        >>> df = pl.DataFrame({"a": [4, 1, 5, 2, 8, 3]})
+        >>> df.select(pl.col("a").rolling_arg_min(3))
+        shape: (6, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ u32  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ 1    │
+        │ 0    │
+        │ 1    │
+        │ 0    │
+        └──────┘
+        """
+        return self._from_pyexpr(
+            self._pyexpr.rolling_arg_min(
+                window_size, min_periods=min_samples, center=center
+            )
+        )
 
-        Specify weights for the values in each window:
+    @unstable()
+    def rolling_arg_max(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Expr:
+        """
+        Compute the rolling index of the maximum value.
 
-        >>> df.with_columns(
-        ...     rolling_median=pl.col("A").rolling_median(
-        ...         window_size=2, weights=[0.25, 0.75]
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬────────────────┐
-        │ A   ┆ rolling_median │
-        │ --- ┆ ---            │
-        │ f64 ┆ f64            │
-        ╞═════╪════════════════╡
-        │ 1.0 ┆ null           │
-        │ 2.0 ┆ 1.5            │
-        │ 3.0 ┆ 2.5            │
-        │ 4.0 ┆ 3.5            │
-        │ 5.0 ┆ 4.5            │
-        │ 6.0 ┆ 5.5            │
-        └─────┴────────────────┘
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
-        Center the values in the window
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
 
-        >>> df.with_columns(
-        ...     rolling_median=pl.col("A").rolling_median(window_size=3, center=True),
-        ... )
-        shape: (6, 2)
-        ┌─────┬────────────────┐
-        │ A   ┆ rolling_median │
-        │ --- ┆ ---            │
-        │ f64 ┆ f64            │
-        ╞═════╪════════════════╡
-        │ 1.0 ┆ null           │
-        │ 2.0 ┆ 2.0            │
-        │ 3.0 ┆ 3.0            │
-        │ 4.0 ┆ 4.0            │
-        │ 5.0 ┆ 5.0            │
-        │ 6.0 ┆ null           │
-        └─────┴────────────────┘
+        The index is relative to the start of the window. Null values are ignored
+        and ties are resolved in favor of the first occurrence.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Expr.arg_max
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [4, 1, 5, 2, 8, 3]})
+        >>> df.select(pl.col("a").rolling_arg_max(3))
+        shape: (6, 1)
+        ┌──────┐
+        │ a    │
+        │ ---  │
+        │ u32  │
+        ╞══════╡
+        │ null │
+        │ null │
+        │ 2    │
+        │ 1    │
+        │ 2    │
+        │ 1    │
+        └──────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_median(
-                window_size,
-                weights,
-                min_samples,
-                center=center,
+            self._pyexpr.rolling_arg_max(
+                window_size, min_periods=min_samples, center=center
             )
         )
 
-    @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
-    def rolling_quantile(
+    @unstable()
+    def rolling_first(
         self,
-        quantile: float,
-        interpolation: QuantileMethod = "nearest",
-        window_size: int = 2,
-        weights: list[float] | None = None,
+        window_size: int,
         *,
         min_samples: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Compute a rolling quantile.
+        Get the first non-null value of a rolling window.
 
-        A window of length `window_size` will traverse the array. The values that fill
-        this window will (optionally) be multiplied with the weights given by the
-        `weights` vector. The resulting values will be aggregated to their quantile.
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
 
-        .. versionchanged:: 1.21.0
-            The `min_periods` parameter was renamed `min_samples`.
-
         Parameters
         ----------
-        quantile
-            Quantile between 0.0 and 1.0.
-        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable'}
-            Interpolation method.
         window_size
-            The length of the window in number of elements.
-        weights
-            An optional slice with the same length as the window that will be multiplied
-            elementwise with the values in the window.
+            Integer size of the rolling window.
         min_samples
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
         center
             Set the labels at the center of the window.
 
-        Notes
-        -----
-        If you want to compute multiple aggregation statistics over the same dynamic
-        window, consider using `rolling` - this method can cache the window size
-        computation.
+        See Also
+        --------
+        Expr.first
 
         Examples
         --------
-        >>> df = pl.DataFrame({"A": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]})
-        >>> df.with_columns(
-        ...     rolling_quantile=pl.col("A").rolling_quantile(
-        ...         quantile=0.25, window_size=4
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬──────────────────┐
-        │ A   ┆ rolling_quantile │
-        │ --- ┆ ---              │
-        │ f64 ┆ f64              │
-        ╞═════╪══════════════════╡
-        │ 1.0 ┆ null             │
-        │ 2.0 ┆ null             │
-        │ 3.0 ┆ null             │
-        │ 4.0 ┆ 2.0              │
-        │ 5.0 ┆ 3.0              │
-        │ 6.0 ┆ 4.0              │
-        └─────┴──────────────────┘
-
-        Specify weights for the values in each window:
-
-        >>> df.with_columns(
-        ...     rolling_quantile=pl.col("A").rolling_quantile(
-        ...         quantile=0.25, window_size=4, weights=[0.2, 0.4, 0.4, 0.2]
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬──────────────────┐
-        │ A   ┆ rolling_quantile │
-        │ --- ┆ ---              │
-        │ f64 ┆ f64              │
-        ╞═════╪══════════════════╡
-        │ 1.0 ┆ null             │
-        │ 2.0 ┆ null             │
-        │ 3.0 ┆ null             │
-        │ 4.0 ┆ 2.0              │
-        │ 5.0 ┆ 3.0              │
-        │ 6.0 ┆ 4.0              │
-        └─────┴──────────────────┘
-
-        Specify weights and interpolation method
-
-        >>> df.with_columns(
-        ...     rolling_quantile=pl.col("A").rolling_quantile(
-        ...         quantile=0.25,
-        ...         window_size=4,
-        ...         weights=[0.2, 0.4, 0.4, 0.2],
-        ...         interpolation="linear",
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬──────────────────┐
-        │ A   ┆ rolling_quantile │
-        │ --- ┆ ---              │
-        │ f64 ┆ f64              │
-        ╞═════╪══════════════════╡
-        │ 1.0 ┆ null             │
-        │ 2.0 ┆ null             │
-        │ 3.0 ┆ null             │
-        │ 4.0 ┆ 1.625            │
-        │ 5.0 ┆ 2.625            │
-        │ 6.0 ┆ 3.625            │
-        └─────┴──────────────────┘
-
-        Center the values in the window
-
-        >>> df.with_columns(
-        ...     rolling_quantile=pl.col("A").rolling_quantile(
-        ...         quantile=0.2, window_size=5, center=True
-        ...     ),
-        ... )
-        shape: (6, 2)
-        ┌─────┬──────────────────┐
-        │ A   ┆ rolling_quantile │
-        │ --- ┆ ---              │
-        │ f64 ┆ f64              │
-        ╞═════╪══════════════════╡
-        │ 1.0 ┆ null             │
-        │ 2.0 ┆ null             │
-        │ 3.0 ┆ 2.0              │
-        │ 4.0 ┆ 3.0              │
-        │ 5.0 ┆ null             │
-        │ 6.0 ┆ null             │
-        └─────┴──────────────────┘
-        """  # noqa: W505
+        >>> df = pl.DataFrame({"a": [1, None, 3, 4, None]})
+        >>> df.select(pl.col("a").rolling_first(2, min_samples=1))
+        shape: (5, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 1   │
+        │ 3   │
+        │ 3   │
+        │ 4   │
+        └─────┘
+        """
         return self._from_pyexpr(
-            self._pyexpr.rolling_quantile(
-                quantile,
-                interpolation,
-                window_size,
-                weights,
-                min_samples,
-                center=center,
+            self._pyexpr.rolling_first(
+                window_size, min_periods=min_samples, center=center
             )
         )
 
     @unstable()
-    def rolling_skew(
+    def rolling_last(
         self,
         window_size: int,
         *,
-        bias: bool = True,
         min_samples: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Compute a rolling skew.
+        Get the last non-null value of a rolling window.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
@@ -8423,9 +9745,6 @@ class Expr:
         ----------
         window_size
             Integer size of the rolling window.
-        bias
-            If False, the calculations are corrected for statistical bias.
-                     bias: bool = True,
         min_samples
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
@@ -8434,47 +9753,41 @@ class Expr:
 
         See Also
         --------
-        Expr.skew
+        Expr.last
 
         Examples
         --------
-        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
-        >>> df.select(pl.col("a").rolling_skew(3))
-        shape: (4, 1)
-        ┌──────────┐
-        │ a        │
-        │ ---      │
-        │ f64      │
-        ╞══════════╡
-        │ null     │
-        │ null     │
-        │ 0.381802 │
-        │ 0.47033  │
-        └──────────┘
-
-        Note how the values match the following:
-
-        >>> pl.Series([1, 4, 2]).skew(), pl.Series([4, 2, 9]).skew()
-        (0.38180177416060584, 0.47033046033698594)
+        >>> df = pl.DataFrame({"a": [1, None, 3, 4, None]})
+        >>> df.select(pl.col("a").rolling_last(2, min_samples=1))
+        shape: (5, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 1   │
+        │ 3   │
+        │ 4   │
+        │ 4   │
+        └─────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_skew(
-                window_size, bias=bias, min_periods=min_samples, center=center
+            self._pyexpr.rolling_last(
+                window_size, min_periods=min_samples, center=center
             )
         )
 
     @unstable()
-    def rolling_kurtosis(
+    def rolling_product(
         self,
         window_size: int,
         *,
-        fisher: bool = True,
-        bias: bool = True,
         min_samples: int | None = None,
         center: bool = False,
     ) -> Expr:
         """
-        Compute a rolling kurtosis.
+        Compute a rolling product.
 
         .. warning::
             This functionality is considered **unstable**. It may be changed
@@ -8483,15 +9796,13 @@ class Expr:
         The window at a given row will include the row itself, and the `window_size - 1`
         elements before it.
 
+        Null values are ignored. Boolean and integer inputs smaller than 64 bits are
+        cast to `Int64` before the product is computed, to prevent overflow.
+
         Parameters
         ----------
         window_size
             Integer size of the rolling window.
-        fisher : bool, optional
-            If True, Fisher's definition is used (normal ==> 0.0). If False,
-            Pearson's definition is used (normal ==> 3.0).
-        bias : bool, optional
-            If False, the calculations are corrected for statistical bias.
         min_samples
             The number of values in the window that should be non-null before computing
             a result. If set to `None` (default), it will be set equal to `window_size`.
@@ -8500,31 +9811,28 @@ class Expr:
 
         See Also
         --------
-        Expr.kurtosis
+        Expr.product
 
         Examples
         --------
-        >>> df = pl.DataFrame({"a": [1, 4, 2, 9]})
-        >>> df.select(pl.col("a").rolling_kurtosis(3))
-        shape: (4, 1)
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").rolling_product(3))
+        shape: (5, 1)
         ┌──────┐
         │ a    │
         │ ---  │
-        │ f64  │
+        │ i64  │
         ╞══════╡
         │ null │
         │ null │
-        │ -1.5 │
-        │ -1.5 │
+        │ 6    │
+        │ 24   │
+        │ 60   │
         └──────┘
         """
         return self._from_pyexpr(
-            self._pyexpr.rolling_kurtosis(
-                window_size,
-                fisher=fisher,
-                bias=bias,
-                min_periods=min_samples,
-                center=center,
+            self._pyexpr.rolling_product(
+                window_size, min_periods=min_samples, center=center
             )
         )
 
@@ -11152,6 +12460,51 @@ class Expr:
         """
         return ExprStructNameSpace(self)
 
+    @property
+    def url(self) -> ExprUrlNameSpace:
+        """
+        Create an object namespace of all URL related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprUrlNameSpace(self)
+
+    @property
+    def ip(self) -> ExprIpNameSpace:
+        """
+        Create an object namespace of all IP address related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprIpNameSpace(self)
+
+    @property
+    def map(self) -> ExprMapNameSpace:
+        """
+        Create an object namespace of all map related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprMapNameSpace(self)
+
+    @property
+    def json(self) -> ExprJsonNameSpace:
+        """
+        Create an object namespace of all JSON related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprJsonNameSpace(self)
+
+    @property
+    def union(self) -> ExprUnionNameSpace:
+        """
+        Create an object namespace of all union related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprUnionNameSpace(self)
+
     def _skip_batch_predicate(self, schema: SchemaDict) -> Expr | None:
         result = self._pyexpr.skip_batch_predicate(schema)
         if result is None:
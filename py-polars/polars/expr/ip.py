@@ -0,0 +1,159 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprIpNameSpace:
+    """
+    Namespace for IP address related expressions.
+
+    IP addresses can be given as strings, as 4 (IPv4) or 16 (IPv6) bytes of
+    :class:`Binary` in network byte order, or as :class:`UInt32` (IPv4) and
+    :class:`UInt128` (IPv6) integers. Invalid addresses are treated as null and
+    IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`) as their IPv4 address.
+    """
+
+    _accessor = "ip"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def to_int(self, *, ipv6: bool = False) -> Expr:
+        """
+        Convert IP addresses to integers.
+
+        Parameters
+        ----------
+        ipv6
+            Return :class:`UInt128` integers that can hold IPv6 addresses, with
+            IPv4 addresses mapped into IPv6. By default, :class:`UInt32` integers
+            are returned and IPv6 addresses are null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32` or :class:`UInt128`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["192.168.0.1", "10.0.0.255", "::1", "invalid"]})
+        >>> df.with_columns(
+        ...     v4=pl.col("ip").ip.to_int(),
+        ...     v6=pl.col("ip").ip.to_int(ipv6=True),
+        ... )
+        shape: (4, 3)
+        ┌─────────────┬────────────┬─────────────────┐
+        │ ip          ┆ v4         ┆ v6              │
+        │ ---         ┆ ---        ┆ ---             │
+        │ str         ┆ u32        ┆ u128            │
+        ╞═════════════╪════════════╪═════════════════╡
+        │ 192.168.0.1 ┆ 3232235521 ┆ 281473913978881 │
+        │ 10.0.0.255  ┆ 167772415  ┆ 281470849515775 │
+        │ ::1         ┆ null       ┆ 1               │
+        │ invalid     ┆ null       ┆ null            │
+        └─────────────┴────────────┴─────────────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_to_int(ipv6))
+
+    def to_binary(self) -> Expr:
+        """
+        Convert IP addresses to their bytes in network byte order.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Binary`, with 4 bytes for IPv4 and 16
+            bytes for IPv6 addresses.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["192.168.0.1", "2001:db8::1", None]})
+        >>> df.with_columns(bytes=pl.col("ip").ip.to_binary())
+        shape: (3, 2)
+        ┌─────────────┬─────────────────────────────────┐
+        │ ip          ┆ bytes                           │
+        │ ---         ┆ ---                             │
+        │ str         ┆ binary                          │
+        ╞═════════════╪═════════════════════════════════╡
+        │ 192.168.0.1 ┆ b"\xc0\xa8\x00\x01"             │
+        │ 2001:db8::1 ┆ b"\x20\x01\x0d\xb8\x00\x00\x00… │
+        │ null        ┆ null                            │
+        └─────────────┴─────────────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_to_binary())
+
+    def to_string(self) -> Expr:
+        """
+        Format IP addresses as strings.
+
+        IPv6 addresses are written in their canonical, compressed form.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"ip": ["10.0.0.1", "2001:0DB8:0:0:0:0:0:1", "::ffff:192.168.0.1"]}
+        ... )
+        >>> df.with_columns(
+        ...     canonical=pl.col("ip").ip.to_string(),
+        ...     roundtrip=pl.col("ip").ip.to_int(ipv6=True).ip.to_string(),
+        ... )
+        shape: (3, 3)
+        ┌───────────────────────┬─────────────┬─────────────┐
+        │ ip                    ┆ canonical   ┆ roundtrip   │
+        │ ---                   ┆ ---         ┆ ---         │
+        │ str                   ┆ str         ┆ str         │
+        ╞═══════════════════════╪═════════════╪═════════════╡
+        │ 10.0.0.1              ┆ 10.0.0.1    ┆ 10.0.0.1    │
+        │ 2001:0DB8:0:0:0:0:0:1 ┆ 2001:db8::1 ┆ 2001:db8::1 │
+        │ ::ffff:192.168.0.1    ┆ 192.168.0.1 ┆ 192.168.0.1 │
+        └───────────────────────┴─────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_to_string())
+
+    def in_network(self, cidr: str) -> Expr:
+        """
+        Check whether IP addresses lie in a network.
+
+        Parameters
+        ----------
+        cidr
+            The network in CIDR notation, e.g. `"10.0.0.0/8"` or `"fc00::/7"`.
+            Host bits set in the network address are ignored and an address without
+            a prefix length is a network of a single address.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`. IPv4 networks only contain
+            IPv4 addresses and IPv6 networks only IPv6 addresses.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ip": ["10.1.2.3", "192.168.0.1", "fd00::1", None]})
+        >>> df.with_columns(
+        ...     internal=pl.col("ip").ip.in_network("10.0.0.0/8"),
+        ...     unique_local=pl.col("ip").ip.in_network("fc00::/7"),
+        ... )
+        shape: (4, 3)
+        ┌─────────────┬──────────┬──────────────┐
+        │ ip          ┆ internal ┆ unique_local │
+        │ ---         ┆ ---      ┆ ---          │
+        │ str         ┆ bool     ┆ bool         │
+        ╞═════════════╪══════════╪══════════════╡
+        │ 10.1.2.3    ┆ true     ┆ false        │
+        │ 192.168.0.1 ┆ false    ┆ false        │
+        │ fd00::1     ┆ false    ┆ true         │
+        │ null        ┆ null     ┆ null         │
+        └─────────────┴──────────┴──────────────┘
+        """
+        return wrap_expr(self._pyexpr.ip_in_network(cidr))
@@ -0,0 +1,122 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
+from polars.datatypes import parse_into_datatype_expr
+
+if TYPE_CHECKING:
+    import polars as pl
+    from polars import Expr
+    from polars._typing import PolarsDataType
+
+
+class ExprJsonNameSpace:
+    """Namespace for JSON related expressions."""
+
+    _accessor = "json"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def get(self, path: str, dtype: PolarsDataType | pl.DataTypeExpr) -> Expr:
+        """
+        Extract the value at a JSONPath of every document.
+
+        Only the selected values are decoded. Paths that only access object members
+        and array elements, such as `$.a.b[0]`, are resolved without parsing the rest
+        of the documents.
+
+        Parameters
+        ----------
+        path
+            A valid JSONPath query string. If it selects more than one value in a
+            document, the values are combined into an array.
+        dtype
+            The data type to decode the selected values as. Select :class:`Json` to
+            keep them as JSON documents.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `dtype`. Documents in which nothing matches are
+            null.
+
+        Raises
+        ------
+        ComputeError
+            If a document that has to be read is not valid JSON.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"doc": ['{"a": {"b": 1}}', '{"a": {"b": 2, "c": [1, 2]}}', "{}"]}
+        ... ).cast(pl.Json)
+        >>> df.select(
+        ...     b=pl.col("doc").json.get("$.a.b", pl.Int64),
+        ...     c=pl.col("doc").json.get("$.a.c", pl.List(pl.Int64)),
+        ... )
+        shape: (3, 2)
+        ┌──────┬───────────┐
+        │ b    ┆ c         │
+        │ ---  ┆ ---       │
+        │ i64  ┆ list[i64] │
+        ╞══════╪═══════════╡
+        │ 1    ┆ null      │
+        │ 2    ┆ [1, 2]    │
+        │ null ┆ null      │
+        └──────┴───────────┘
+        """
+        dtype_expr = parse_into_datatype_expr(dtype)._pydatatype_expr
+        return wrap_expr(self._pyexpr.json_get(path, dtype_expr))
+
+    def compact(self) -> Expr:
+        """
+        Remove all whitespace outside of strings.
+
+        Documents are already stored in this form after a cast to :class:`Json`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Json`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"doc": ['{"a": [1, 2]}']}).cast(pl.Json)
+        >>> df.select(pl.col("doc").json.pretty().json.compact())
+        shape: (1, 1)
+        ┌─────────────┐
+        │ doc         │
+        │ ---         │
+        │ json        │
+        ╞═════════════╡
+        │ {"a":[1,2]} │
+        └─────────────┘
+        """
+        return wrap_expr(self._pyexpr.json_compact())
+
+    def pretty(self) -> Expr:
+        """
+        Format the documents with one object member or array element per line.
+
+        Members and elements are indented by two spaces per level. The order of
+        object keys is preserved.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Json`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"doc": ['{"a": [1, 2]}']}).cast(pl.Json)
+        >>> print(df.select(pl.col("doc").json.pretty()).item())
+        {
+          "a": [
+            1,
+            2
+          ]
+        }
+        """
+        return wrap_expr(self._pyexpr.json_pretty())
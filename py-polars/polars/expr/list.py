@@ -7,17 +7,23 @@ from typing import TYPE_CHECKING, Any, Callable
 
 import polars._reexport as pl
 from polars import functions as F
-from polars._utils.parse import parse_into_expression
+from polars._utils.parse import (
+    parse_into_expression,
+    parse_into_list_of_expressions,
+)
 from polars._utils.various import find_stacklevel
 from polars._utils.wrap import wrap_expr
 
 if TYPE_CHECKING:
+    from collections.abc import Iterable
+
     from polars import Expr, Series
     from polars._typing import (
         IntoExpr,
         IntoExprColumn,
         ListToStructWidthStrategy,
         NullBehavior,
+        SearchSortedSide,
     )
 
 
@@ -399,6 +405,180 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_sort(descending, nulls_last))
 
+    def sort_by(
+        self,
+        by: IntoExpr,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        maintain_order: bool = False,
+    ) -> Expr:
+        """
+        Sort the lists in this column by the lists of another column.
+
+        Every list is reordered such that the list in the same row of `by` is
+        sorted. Both lists must have the same length.
+
+        Parameters
+        ----------
+        by
+            Column of lists to sort by. Accepts expression input. Strings are
+            parsed as column names.
+        descending
+            Sort in descending order.
+        nulls_last
+            Place null values of `by` last.
+        maintain_order
+            Keep the original order of elements that have equal values in `by`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "events": [["login", "buy", "view"], ["view", "logout"]],
+        ...         "ts": [[30, 10, 20], [5, 1]],
+        ...     }
+        ... )
+        >>> df.with_columns(sorted=pl.col("events").list.sort_by("ts"))
+        shape: (2, 3)
+        ┌──────────────────────────┬──────────────┬──────────────────────────┐
+        │ events                   ┆ ts           ┆ sorted                   │
+        │ ---                      ┆ ---          ┆ ---                      │
+        │ list[str]                ┆ list[i64]    ┆ list[str]                │
+        ╞══════════════════════════╪══════════════╪══════════════════════════╡
+        │ ["login", "buy", "view"] ┆ [30, 10, 20] ┆ ["buy", "view", "login"] │
+        │ ["view", "logout"]       ┆ [5, 1]       ┆ ["logout", "view"]       │
+        └──────────────────────────┴──────────────┴──────────────────────────┘
+        """
+        by = parse_into_expression(by)
+        return wrap_expr(
+            self._pyexpr.list_sort_by(by, descending, nulls_last, maintain_order)
+        )
+
+    def arg_sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        maintain_order: bool = False,
+    ) -> Expr:
+        """
+        Get the indices that would sort the lists in this column.
+
+        Parameters
+        ----------
+        descending
+            Sort in descending order.
+        nulls_last
+            Place null values last.
+        maintain_order
+            Keep the original order of elements that have equal values.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List` of :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[3, 1, 2], [9, None, 4]]})
+        >>> df.with_columns(arg_sort=pl.col("a").list.arg_sort())
+        shape: (2, 2)
+        ┌──────────────┬───────────┐
+        │ a            ┆ arg_sort  │
+        │ ---          ┆ ---       │
+        │ list[i64]    ┆ list[u32] │
+        ╞══════════════╪═══════════╡
+        │ [3, 1, 2]    ┆ [1, 2, 0] │
+        │ [9, null, 4] ┆ [1, 2, 0] │
+        └──────────────┴───────────┘
+        """
+        return wrap_expr(
+            self._pyexpr.list_arg_sort(descending, nulls_last, maintain_order)
+        )
+
+    def search_sorted(
+        self,
+        element: IntoExpr,
+        side: SearchSortedSide = "any",
+        *,
+        descending: bool = False,
+    ) -> Expr:
+        """
+        Find the index where an element should be inserted into every sorted list.
+
+        The lists must already be sorted. The element can differ per row.
+
+        Parameters
+        ----------
+        element
+            Expression or scalar value to search for. Strings are parsed as
+            literals.
+        side : {'any', 'left', 'right'}
+            If 'any', the index of the first suitable location found is given.
+            If 'left', the index of the leftmost suitable location found is given.
+            If 'right', return the rightmost suitable location found is given.
+        descending
+            Boolean indicating whether the lists are sorted in descending order.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`, null where the list or the
+            element is null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"ts": [[1, 5, 9], [2, 2, 8], []], "t": [6, 2, 3]})
+        >>> df.with_columns(
+        ...     left=pl.col("ts").list.search_sorted(pl.col("t"), "left"),
+        ...     right=pl.col("ts").list.search_sorted(pl.col("t"), "right"),
+        ... )
+        shape: (3, 4)
+        ┌───────────┬─────┬──────┬───────┐
+        │ ts        ┆ t   ┆ left ┆ right │
+        │ ---       ┆ --- ┆ ---  ┆ ---   │
+        │ list[i64] ┆ i64 ┆ u32  ┆ u32   │
+        ╞═══════════╪═════╪══════╪═══════╡
+        │ [1, 5, 9] ┆ 6   ┆ 2    ┆ 2     │
+        │ [2, 2, 8] ┆ 2   ┆ 0    ┆ 2     │
+        │ []        ┆ 3   ┆ 0    ┆ 0     │
+        └───────────┴─────┴──────┴───────┘
+        """
+        element = parse_into_expression(element, str_as_lit=True)
+        return wrap_expr(self._pyexpr.list_search_sorted(element, side, descending))
+
+    def top_k(self, k: int | IntoExprColumn = 5) -> Expr:
+        """
+        Return the `k` largest elements of every list.
+
+        The elements are returned in descending order and non-null elements are
+        preferred over null elements.
+
+        Parameters
+        ----------
+        k
+            Number of elements to return per list, either a single value or one
+            value per row.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"scores": [[3, 9, 1, 7], [4], [None, 2, 5]]})
+        >>> df.with_columns(top_2=pl.col("scores").list.top_k(2))
+        shape: (3, 2)
+        ┌──────────────┬───────────┐
+        │ scores       ┆ top_2     │
+        │ ---          ┆ ---       │
+        │ list[i64]    ┆ list[i64] │
+        ╞══════════════╪═══════════╡
+        │ [3, 9, … 7]  ┆ [9, 7]    │
+        │ [4]          ┆ [4]       │
+        │ [null, 2, 5] ┆ [5, 2]    │
+        └──────────────┴───────────┘
+        """
+        k = parse_into_expression(k)
+        return wrap_expr(self._pyexpr.list_top_k(k))
+
     def reverse(self) -> Expr:
         """
         Reverse the arrays in the list.
@@ -1209,6 +1389,106 @@ class ExprListNameSpace:
             pyexpr = self._pyexpr.list_to_struct(n_field_strategy, fields, upper_bound)
             return wrap_expr(pyexpr)
 
+    def zip(self, *others: IntoExpr | Iterable[IntoExpr]) -> Expr:
+        """
+        Zip the lists in this column with the lists of other columns.
+
+        The result is a list of structs, where the struct fields are named after
+        the columns. Lists in the same row must have the same length, and a row
+        is null if any of its lists is null.
+
+        Parameters
+        ----------
+        *others
+            Columns of lists to zip with. Accepts expression input. Strings are
+            parsed as column names.
+
+        See Also
+        --------
+        unzip
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "item": [["apple", "pear"], ["fig"], None],
+        ...         "qty": [[3, 1], [7], [2]],
+        ...     }
+        ... )
+        >>> df.select(pl.col("item").list.zip("qty"))
+        shape: (3, 1)
+        ┌───────────────────────────┐
+        │ item                      │
+        │ ---                       │
+        │ list[struct[2]]           │
+        ╞═══════════════════════════╡
+        │ [{"apple",3}, {"pear",1}] │
+        │ [{"fig",7}]               │
+        │ null                      │
+        └───────────────────────────┘
+        """
+        pyexprs = parse_into_list_of_expressions(*others)
+        return wrap_expr(self._pyexpr.list_zip(pyexprs))
+
+    def unzip(self) -> Expr:
+        """
+        Unzip a column of lists of structs into a struct of lists.
+
+        The result has a list field for every field of the structs.
+
+        See Also
+        --------
+        zip
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "items": [
+        ...             [{"item": "apple", "qty": 3}, {"item": "pear", "qty": 1}],
+        ...             [{"item": "fig", "qty": 7}],
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(pl.col("items").list.unzip()).unnest("items")
+        shape: (2, 2)
+        ┌───────────────────┬───────────┐
+        │ item              ┆ qty       │
+        │ ---               ┆ ---       │
+        │ list[str]         ┆ list[i64] │
+        ╞═══════════════════╪═══════════╡
+        │ ["apple", "pear"] ┆ [3, 1]    │
+        │ ["fig"]           ┆ [7]       │
+        └───────────────────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.list_unzip())
+
+    def enumerate(self) -> Expr:
+        """
+        Pair every element of the lists with its index in the list.
+
+        The result is a list of structs with the fields `index` and `value`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"letters": [["a", "b", "c"], ["d"]]})
+        >>> df.select(pl.col("letters").list.enumerate()).explode("letters").unnest(
+        ...     "letters"
+        ... )
+        shape: (4, 2)
+        ┌───────┬───────┐
+        │ index ┆ value │
+        │ ---   ┆ ---   │
+        │ u32   ┆ str   │
+        ╞═══════╪═══════╡
+        │ 0     ┆ a     │
+        │ 1     ┆ b     │
+        │ 2     ┆ c     │
+        │ 0     ┆ d     │
+        └───────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.list_enumerate())
+
     def eval(self, expr: Expr, *, parallel: bool = False) -> Expr:
         """
         Run any polars expression against the lists' elements.
@@ -0,0 +1,150 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprMapNameSpace:
+    """Namespace for map related expressions."""
+
+    _accessor = "map"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def get(self, key: IntoExpr) -> Expr:
+        """
+        Get the value of a key in every map.
+
+        Parameters
+        ----------
+        key
+            The key to look up. Accepts expression input, so a different key can be
+            used for every row. Strings are parsed as literals.
+
+        Returns
+        -------
+        Expr
+            Expression with the data type of the map values. Maps without the key
+            are null. If a key occurs more than once, the first value is returned.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [{"a": 1, "b": 2}, {"b": 3}, None]},
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.get("b"))
+        shape: (3, 1)
+        ┌──────┐
+        │ m    │
+        │ ---  │
+        │ i64  │
+        ╞══════╡
+        │ 2    │
+        │ 3    │
+        │ null │
+        └──────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_get(key_pyexpr))
+
+    def contains_key(self, key: IntoExpr) -> Expr:
+        """
+        Check whether every map contains a key.
+
+        Parameters
+        ----------
+        key
+            The key to look for. Accepts expression input, so a different key can be
+            used for every row. Strings are parsed as literals.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [{"a": 1, "b": 2}, {"b": 3}, None]},
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.contains_key("a"))
+        shape: (3, 1)
+        ┌───────┐
+        │ m     │
+        │ ---   │
+        │ bool  │
+        ╞═══════╡
+        │ true  │
+        │ false │
+        │ null  │
+        └───────┘
+        """
+        key_pyexpr = parse_into_expression(key, str_as_lit=True)
+        return wrap_expr(self._pyexpr.map_contains_key(key_pyexpr))
+
+    def keys(self) -> Expr:
+        """
+        Get the keys of every map as a list.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List` of the map key type.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [{"a": 1, "b": 2}, {"b": 3}, None]},
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.keys())
+        shape: (3, 1)
+        ┌────────────┐
+        │ m          │
+        │ ---        │
+        │ list[str]  │
+        ╞════════════╡
+        │ ["a", "b"] │
+        │ ["b"]      │
+        │ null       │
+        └────────────┘
+        """
+        return wrap_expr(self._pyexpr.map_keys())
+
+    def values(self) -> Expr:
+        """
+        Get the values of every map as a list.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`List` of the map value type.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"m": [{"a": 1, "b": 2}, {"b": 3}, None]},
+        ...     schema={"m": pl.Map(pl.String, pl.Int64)},
+        ... )
+        >>> df.select(pl.col("m").map.values())
+        shape: (3, 1)
+        ┌───────────┐
+        │ m         │
+        │ ---       │
+        │ list[i64] │
+        ╞═══════════╡
+        │ [1, 2]    │
+        │ [3]       │
+        │ null      │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.map_values())
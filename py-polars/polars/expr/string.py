@@ -17,6 +17,7 @@ from polars.exceptions import ChronoFormatWarning
 
 if TYPE_CHECKING:
     import sys
+    from collections.abc import Sequence
 
     from polars import Expr
     from polars._typing import (
@@ -2998,6 +2999,417 @@ class ExprStringNameSpace:
         """  # noqa: RUF002
         return wrap_expr(self._pyexpr.str_normalize(form))
 
+    def levenshtein(self, other: IntoExpr, *, max_distance: int | None = None) -> Expr:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The distance is the minimum number of single character insertions, deletions
+        and substitutions needed to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input. Strings are parsed
+            as column names.
+        max_distance
+            Stop computing a distance once it is known to exceed this value. Such
+            distances are reported as `max_distance + 1`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        See Also
+        --------
+        damerau_levenshtein
+        hamming
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "martha", "dixon", None],
+        ...         "b": ["sitting", "marhta", "dicksonx", "x"],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     dist=pl.col("a").str.levenshtein("b"),
+        ...     capped=pl.col("a").str.levenshtein("b", max_distance=2),
+        ... )
+        shape: (4, 4)
+        ┌────────┬──────────┬──────┬────────┐
+        │ a      ┆ b        ┆ dist ┆ capped │
+        │ ---    ┆ ---      ┆ ---  ┆ ---    │
+        │ str    ┆ str      ┆ u32  ┆ u32    │
+        ╞════════╪══════════╪══════╪════════╡
+        │ kitten ┆ sitting  ┆ 3    ┆ 3      │
+        │ martha ┆ marhta   ┆ 2    ┆ 2      │
+        │ dixon  ┆ dicksonx ┆ 4    ┆ 3      │
+        │ null   ┆ x        ┆ null ┆ null   │
+        └────────┴──────────┴──────┴────────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.str_levenshtein(other, max_distance))
+
+    def damerau_levenshtein(
+        self, other: IntoExpr, *, max_distance: int | None = None
+    ) -> Expr:
+        """
+        Compute the Damerau-Levenshtein distance to another string.
+
+        Like :func:`levenshtein`, but swapping two characters counts as a single edit.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input. Strings are parsed
+            as column names.
+        max_distance
+            Stop computing a distance once it is known to exceed this value. Such
+            distances are reported as `max_distance + 1`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["martha", "kitten"], "b": ["marhta", "sitting"]})
+        >>> df.with_columns(dist=pl.col("a").str.damerau_levenshtein("b"))
+        shape: (2, 3)
+        ┌────────┬─────────┬──────┐
+        │ a      ┆ b       ┆ dist │
+        │ ---    ┆ ---     ┆ ---  │
+        │ str    ┆ str     ┆ u32  │
+        ╞════════╪═════════╪══════╡
+        │ martha ┆ marhta  ┆ 1    │
+        │ kitten ┆ sitting ┆ 3    │
+        └────────┴─────────┴──────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.str_damerau_levenshtein(other, max_distance))
+
+    def hamming(self, other: IntoExpr, *, max_distance: int | None = None) -> Expr:
+        """
+        Compute the Hamming distance to another string.
+
+        The distance is the number of positions at which the characters differ.
+        Strings of different lengths result in null.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input. Strings are parsed
+            as column names.
+        max_distance
+            Stop computing a distance once it is known to exceed this value. Such
+            distances are reported as `max_distance + 1`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["kitten", "martha", "dixon"]})
+        >>> df.with_columns(dist=pl.col("a").str.hamming(pl.lit("marhta")))
+        shape: (3, 2)
+        ┌────────┬──────┐
+        │ a      ┆ dist │
+        │ ---    ┆ ---  │
+        │ str    ┆ u32  │
+        ╞════════╪══════╡
+        │ kitten ┆ 6    │
+        │ martha ┆ 2    │
+        │ dixon  ┆ null │
+        └────────┴──────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.str_hamming(other, max_distance))
+
+    def jaro_winkler(self, other: IntoExpr) -> Expr:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity lies between 0 (nothing in common) and 1 (equal strings).
+        Strings sharing a prefix of up to 4 characters get a boost.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input. Strings are parsed
+            as column names.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": ["kitten", "martha", "dixon"],
+        ...         "b": ["sitting", "marhta", "dicksonx"],
+        ...     }
+        ... )
+        >>> df.with_columns(sim=pl.col("a").str.jaro_winkler("b"))
+        shape: (3, 3)
+        ┌────────┬──────────┬──────────┐
+        │ a      ┆ b        ┆ sim      │
+        │ ---    ┆ ---      ┆ ---      │
+        │ str    ┆ str      ┆ f64      │
+        ╞════════╪══════════╪══════════╡
+        │ kitten ┆ sitting  ┆ 0.746032 │
+        │ martha ┆ marhta   ┆ 0.961111 │
+        │ dixon  ┆ dicksonx ┆ 0.813333 │
+        └────────┴──────────┴──────────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.str_jaro_winkler(other))
+
+    def jaccard_ngram(self, other: IntoExpr, n: int = 2) -> Expr:
+        """
+        Compute the Jaccard similarity of the character n-grams to another string.
+
+        The similarity is the number of distinct n-grams both strings share, divided
+        by the number of distinct n-grams in either string. Strings shorter than `n`
+        form a single n-gram.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with. Accepts expression input. Strings are parsed
+            as column names.
+        n
+            The number of characters per n-gram.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["martha", "kitten"], "b": ["marhta", "sitting"]})
+        >>> df.with_columns(sim=pl.col("a").str.jaccard_ngram("b", n=2))
+        shape: (2, 3)
+        ┌────────┬─────────┬──────────┐
+        │ a      ┆ b       ┆ sim      │
+        │ ---    ┆ ---     ┆ ---      │
+        │ str    ┆ str     ┆ f64      │
+        ╞════════╪═════════╪══════════╡
+        │ martha ┆ marhta  ┆ 0.25     │
+        │ kitten ┆ sitting ┆ 0.222222 │
+        └────────┴─────────┴──────────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.str_jaccard_ngram(other, n))
+
+    def split_regex(self, pattern: str | Expr) -> Expr:
+        r"""
+        Split the string on every match of a regular expression.
+
+        Parameters
+        ----------
+        pattern
+            A valid regular expression pattern, compatible with the `regex crate
+            <https://docs.rs/regex/latest/regex/>`_. Accepts expression input.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `List(String)`.
+
+        See Also
+        --------
+        split
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["a1b22c", "x"]})
+        >>> df.with_columns(parts=pl.col("text").str.split_regex(r"\d+"))
+        shape: (2, 2)
+        ┌────────┬─────────────────┐
+        │ text   ┆ parts           │
+        │ ---    ┆ ---             │
+        │ str    ┆ list[str]       │
+        ╞════════╪═════════════════╡
+        │ a1b22c ┆ ["a", "b", "c"] │
+        │ x      ┆ ["x"]           │
+        └────────┴─────────────────┘
+        """
+        pattern = parse_into_expression(pattern, str_as_lit=True)
+        return wrap_expr(self._pyexpr.str_split_regex(pattern))
+
+    def tokenize(self) -> Expr:
+        """
+        Split the string into words.
+
+        Words are found at the Unicode word boundaries (UAX #29). Whitespace and
+        punctuation between the words is dropped.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `List(String)`.
+
+        See Also
+        --------
+        count_tokens
+        word_ngrams
+        remove_stop_words
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["Hello, world!", "It's fine"]})
+        >>> df.with_columns(
+        ...     tokens=pl.col("text").str.tokenize(),
+        ...     count=pl.col("text").str.count_tokens(),
+        ... )
+        shape: (2, 3)
+        ┌───────────────┬────────────────────┬───────┐
+        │ text          ┆ tokens             ┆ count │
+        │ ---           ┆ ---                ┆ ---   │
+        │ str           ┆ list[str]          ┆ u32   │
+        ╞═══════════════╪════════════════════╪═══════╡
+        │ Hello, world! ┆ ["Hello", "world"] ┆ 2     │
+        │ It's fine     ┆ ["It's", "fine"]   ┆ 2     │
+        └───────────────┴────────────────────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.str_tokenize())
+
+    def count_tokens(self) -> Expr:
+        """
+        Count the words in the string, as found by :func:`tokenize`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["Hello, world!", "It's fine"]})
+        >>> df.with_columns(
+        ...     tokens=pl.col("text").str.tokenize(),
+        ...     count=pl.col("text").str.count_tokens(),
+        ... )
+        shape: (2, 3)
+        ┌───────────────┬────────────────────┬───────┐
+        │ text          ┆ tokens             ┆ count │
+        │ ---           ┆ ---                ┆ ---   │
+        │ str           ┆ list[str]          ┆ u32   │
+        ╞═══════════════╪════════════════════╪═══════╡
+        │ Hello, world! ┆ ["Hello", "world"] ┆ 2     │
+        │ It's fine     ┆ ["It's", "fine"]   ┆ 2     │
+        └───────────────┴────────────────────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.str_count_tokens())
+
+    def char_ngrams(self, n: int) -> Expr:
+        """
+        Get all overlapping substrings of `n` characters.
+
+        Strings shorter than `n` characters have no n-grams.
+
+        Parameters
+        ----------
+        n
+            The number of characters per n-gram.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `List(String)`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["polar", "ok"]})
+        >>> df.with_columns(trigrams=pl.col("text").str.char_ngrams(3))
+        shape: (2, 2)
+        ┌───────┬───────────────────────┐
+        │ text  ┆ trigrams              │
+        │ ---   ┆ ---                   │
+        │ str   ┆ list[str]             │
+        ╞═══════╪═══════════════════════╡
+        │ polar ┆ ["pol", "ola", "lar"] │
+        │ ok    ┆ []                    │
+        └───────┴───────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_char_ngrams(n))
+
+    def word_ngrams(self, n: int) -> Expr:
+        """
+        Get all overlapping sequences of `n` words, joined by a single space.
+
+        Words are found as in :func:`tokenize`. Strings with fewer than `n` words
+        have no n-grams.
+
+        Parameters
+        ----------
+        n
+            The number of words per n-gram.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `List(String)`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["a fine day", "hi"]})
+        >>> df.with_columns(bigrams=pl.col("text").str.word_ngrams(2))
+        shape: (2, 2)
+        ┌────────────┬────────────────────────┐
+        │ text       ┆ bigrams                │
+        │ ---        ┆ ---                    │
+        │ str        ┆ list[str]              │
+        ╞════════════╪════════════════════════╡
+        │ a fine day ┆ ["a fine", "fine day"] │
+        │ hi         ┆ []                     │
+        └────────────┴────────────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_word_ngrams(n))
+
+    def remove_stop_words(self, stop_words: Sequence[str]) -> Expr:
+        """
+        Split the string into words, leaving out the given stop words.
+
+        Words are found as in :func:`tokenize` and compared exactly, so lowercase the
+        strings first to ignore case.
+
+        Parameters
+        ----------
+        stop_words
+            The words to leave out.
+
+        Returns
+        -------
+        Expr
+            Expression of data type `List(String)`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["the cat sat", "on a mat"]})
+        >>> df.with_columns(
+        ...     words=pl.col("text").str.remove_stop_words(["the", "on", "a"])
+        ... )
+        shape: (2, 2)
+        ┌─────────────┬────────────────┐
+        │ text        ┆ words          │
+        │ ---         ┆ ---            │
+        │ str         ┆ list[str]      │
+        ╞═════════════╪════════════════╡
+        │ the cat sat ┆ ["cat", "sat"] │
+        │ on a mat    ┆ ["mat"]        │
+        └─────────────┴────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_remove_stop_words(list(stop_words)))
+
 
 def _validate_format_argument(format: str | None) -> None:
     if format is not None and ".%f" in format:
@@ -0,0 +1,81 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprUnionNameSpace:
+    """Namespace for union related expressions."""
+
+    _accessor = "union"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def type_id(self) -> Expr:
+        """
+        Get the index of the active variant of every row.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Int8`. Null rows are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"u": [1, "x", None]},
+        ...     schema={"u": pl.Union({"int": pl.Int64, "str": pl.String})},
+        ... )
+        >>> df.select(pl.col("u").union.type_id())
+        shape: (3, 1)
+        ┌──────┐
+        │ u    │
+        │ ---  │
+        │ i8   │
+        ╞══════╡
+        │ 0    │
+        │ 1    │
+        │ null │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.union_type_id())
+
+    def field(self, name: str) -> Expr:
+        """
+        Get the values of a variant.
+
+        Parameters
+        ----------
+        name
+            Name of the variant.
+
+        Returns
+        -------
+        Expr
+            Expression with the data type of the variant. Rows in which another
+            variant is active are null.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"u": [1, "x", None]},
+        ...     schema={"u": pl.Union({"int": pl.Int64, "str": pl.String})},
+        ... )
+        >>> df.select(pl.col("u").union.field("str"))
+        shape: (3, 1)
+        ┌──────┐
+        │ str  │
+        │ ---  │
+        │ str  │
+        ╞══════╡
+        │ null │
+        │ x    │
+        │ null │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.union_field(name))
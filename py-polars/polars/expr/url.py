@@ -0,0 +1,108 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprUrlNameSpace:
+    """Namespace for URL related expressions."""
+
+    _accessor = "url"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    def parse(self) -> Expr:
+        """
+        Split URLs into their components.
+
+        Absolute and relative URLs are split as described in RFC 3986. The
+        components are not decoded or normalized; the user information is dropped
+        from the host and the brackets are removed from IPv6 hosts.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the fields `scheme`,
+            `host`, `port` (:class:`UInt16`), `path`, `query` and `fragment`.
+            Missing components are null, except for the path, which is empty. Ports
+            that are not a valid `UInt16` are null.
+
+        See Also
+        --------
+        query_param : Extract a single query parameter.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://pola.rs:443/docs?lang=en#top",
+        ...             "http://[::1]/",
+        ...             "/search?q=polars",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(pl.col("url").url.parse()).unnest("url")
+        shape: (3, 6)
+        ┌────────┬─────────┬──────┬─────────┬──────────┬──────────┐
+        │ scheme ┆ host    ┆ port ┆ path    ┆ query    ┆ fragment │
+        │ ---    ┆ ---     ┆ ---  ┆ ---     ┆ ---      ┆ ---      │
+        │ str    ┆ str     ┆ u16  ┆ str     ┆ str      ┆ str      │
+        ╞════════╪═════════╪══════╪═════════╪══════════╪══════════╡
+        │ https  ┆ pola.rs ┆ 443  ┆ /docs   ┆ lang=en  ┆ top      │
+        │ http   ┆ ::1     ┆ null ┆ /       ┆ null     ┆ null     │
+        │ null   ┆ null    ┆ null ┆ /search ┆ q=polars ┆ null     │
+        └────────┴─────────┴──────┴─────────┴──────────┴──────────┘
+        """
+        return wrap_expr(self._pyexpr.url_parse())
+
+    def query_param(self, name: str) -> Expr:
+        """
+        Extract the value of a query parameter.
+
+        Parameters are separated by `&`. Both names and values are percent-decoded
+        and a `+` decodes to a space, as in HTML forms. If the parameter is given
+        more than once, the first value is returned.
+
+        Parameters
+        ----------
+        name
+            The name of the query parameter.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`. URLs without the parameter
+            give null and a parameter without a value gives an empty string.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "/search?q=data+frames&page=2",
+        ...             "/search?page=3",
+        ...             "/search?q=caf%C3%A9",
+        ...             "/search?q",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(q=pl.col("url").url.query_param("q"))
+        shape: (4, 2)
+        ┌──────────────────────────────┬─────────────┐
+        │ url                          ┆ q           │
+        │ ---                          ┆ ---         │
+        │ str                          ┆ str         │
+        ╞══════════════════════════════╪═════════════╡
+        │ /search?q=data+frames&page=2 ┆ data frames │
+        │ /search?page=3               ┆ null        │
+        │ /search?q=caf%C3%A9          ┆ café        │
+        │ /search?q                    ┆             │
+        └──────────────────────────────┴─────────────┘
+        """
+        return wrap_expr(self._pyexpr.url_query_param(name))
@@ -10,6 +10,7 @@ from polars.functions.aggregation import (
     mean_horizontal,
     min,
     min_horizontal,
+    stable_hash_rows,
     sum,
     sum_horizontal,
 )
@@ -19,16 +20,19 @@ from polars.functions.as_datatype import (
     concat_str,
     duration,
     format,
+    map_from_lists,
     struct,
+    union_from_struct,
 )
 from polars.functions.as_datatype import date_ as date
 from polars.functions.as_datatype import datetime_ as datetime
 from polars.functions.as_datatype import time_ as time
-from polars.functions.business import business_day_count
+from polars.functions.business import Holiday, HolidayCalendar, business_day_count
 from polars.functions.col import col
 from polars.functions.datatype import dtype_of
 from polars.functions.eager import align_frames, concat
 from polars.functions.escape_regex import escape_regex
+from polars.functions.fiscal import FiscalCalendar
 from polars.functions.lazy import (
     approx_n_unique,
     arctan2,
@@ -39,6 +43,7 @@ from polars.functions.lazy import (
     collect_all,
     collect_all_async,
     corr,
+    corr_matrix,
     count,
     cov,
     cum_count,
@@ -73,7 +78,7 @@ from polars.functions.lazy import (
 )
 from polars.functions.len import len
 from polars.functions.lit import lit
-from polars.functions.random import set_random_seed
+from polars.functions.random import set_random_seed, uuid4
 from polars.functions.range import (
     arange,
     date_range,
@@ -103,6 +108,7 @@ __all__ = [
     "cum_sum_horizontal",
     "max_horizontal",
     "min_horizontal",
+    "stable_hash_rows",
     "sum_horizontal",
     # polars.functions.datatype
     "dtype_of",
@@ -127,6 +133,9 @@ __all__ = [
     "arctan2d",
     "arg_sort_by",
     "business_day_count",
+    "FiscalCalendar",
+    "Holiday",
+    "HolidayCalendar",
     "coalesce",
     "col",
     "collect_all",
@@ -135,6 +144,7 @@ __all__ = [
     "concat_list",
     "concat_str",
     "corr",
+    "corr_matrix",
     "count",
     "cov",
     "cum_count",
@@ -160,6 +170,7 @@ __all__ = [
     "linear_spaces",
     "lit",
     "map_batches",
+    "map_from_lists",
     "map_groups",
     "mean",
     "mean_horizontal",
@@ -176,6 +187,8 @@ __all__ = [
     "struct",
     "tail",
     "time",
+    "union_from_struct",
+    "uuid4",
     "var",
     # polars.functions.len
     "len",
@@ -296,3 +296,56 @@ def cum_sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     return F.cum_fold(F.lit(0).cast(UInt32), lambda a, b: a + b, exprs_wrapped).alias(
         "cum_sum"
     )
+
+
+def stable_hash_rows(*exprs: IntoExpr | Iterable[IntoExpr], seed: int = 0) -> Expr:
+    """
+    Hash every row of the given columns into a stable 64-bit value.
+
+    Unlike :meth:`DataFrame.hash_rows`, the hash is stable across runs, platforms
+    and Polars versions, so it can be stored or compared with hashes computed
+    elsewhere.
+
+    Every row starts from `seed`, after which the columns are folded in order: each
+    value is hashed with XXH3 (64-bit), seeded by the hash so far. A value is
+    encoded as a `0` byte when null, otherwise as a `1` byte followed by:
+
+    - booleans: a single `0` or `1` byte,
+    - numbers: the little-endian bytes of the value, with `-0.0` and NaNs
+      canonicalized,
+    - strings and binary: the raw bytes,
+    - categoricals and enums: the bytes of the string value,
+    - other types: the encoding of their physical representation.
+
+    Nested data types are not supported.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to hash. Accepts expression input. Strings are parsed as column
+        names, other non-expression inputs are parsed as literals.
+    seed
+        The seed of the hash.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`UInt64`.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2, None], "b": ["x", "y", "z"]})
+    >>> df.with_columns(hash=pl.stable_hash_rows("a", "b"))
+    shape: (3, 3)
+    ┌──────┬─────┬──────────────────────┐
+    │ a    ┆ b   ┆ hash                 │
+    │ ---  ┆ --- ┆ ---                  │
+    │ i64  ┆ str ┆ u64                  │
+    ╞══════╪═════╪══════════════════════╡
+    │ 1    ┆ x   ┆ 9522937652473371038  │
+    │ 2    ┆ y   ┆ 3285309881530051729  │
+    │ null ┆ z   ┆ 12119009896031128474 │
+    └──────┴─────┴──────────────────────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(plr.stable_hash_rows(pyexprs, seed))
@@ -503,6 +503,79 @@ def concat_list(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) ->
     return wrap_expr(plr.concat_list(exprs))
 
 
+def map_from_lists(keys: IntoExpr, values: IntoExpr) -> Expr:
+    """
+    Create a map column from a list column of keys and a list column of values.
+
+    The keys and values must have the same length in every row. Rows where either
+    list is null are null.
+
+    Parameters
+    ----------
+    keys
+        List column with the keys of every map. Accepts expression input. Strings
+        are parsed as column names. Keys cannot be null.
+    values
+        List column with the values of every map. Accepts expression input. Strings
+        are parsed as column names.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"k": [["a", "b"], ["c"], None], "v": [[1, 2], [3], [4]]})
+    >>> df.select(pl.map_from_lists("k", "v").map.get("a"))
+    shape: (3, 1)
+    ┌──────┐
+    │ k    │
+    │ ---  │
+    │ i64  │
+    ╞══════╡
+    │ 1    │
+    │ null │
+    │ null │
+    └──────┘
+    """
+    keys_pyexpr = parse_into_expression(keys)
+    values_pyexpr = parse_into_expression(values)
+    return wrap_expr(plr.map_from_lists(keys_pyexpr, values_pyexpr))
+
+
+def union_from_struct(expr: IntoExpr) -> Expr:
+    """
+    Create a union column from a struct column.
+
+    Every field of the struct becomes a variant of the union. At most one field can
+    be non-null in every row, that field is the active variant. Rows in which all
+    fields are null are null.
+
+    Parameters
+    ----------
+    expr
+        Struct column to convert. Accepts expression input. Strings are parsed as
+        column names.
+
+    Raises
+    ------
+    InvalidOperationError
+        If more than one field is non-null in a row.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, None, None], "b": [None, "x", None]})
+    >>> df.select(pl.union_from_struct(pl.struct("a", "b")).alias("u"))
+    shape: (3, 1)
+    ┌──────────┐
+    │ u        │
+    │ ---      │
+    │ union[2] │
+    ╞══════════╡
+    │ 1        │
+    │ x        │
+    │ null     │
+    └──────────┘
+    """
+    return wrap_expr(plr.union_from_struct(parse_into_expression(expr)))
+
+
 def concat_arr(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> Expr:
     """
     Horizontally concatenate columns into a single array column.
@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 import contextlib
-from datetime import date
+from datetime import date, timedelta
 from typing import TYPE_CHECKING
 
 from polars._utils.deprecation import deprecate_nonkeyword_arguments
@@ -16,7 +16,7 @@ if TYPE_CHECKING:
     from collections.abc import Iterable
 
     from polars import Expr
-    from polars._typing import IntoExprColumn
+    from polars._typing import HolidayObservance, IntoExprColumn
 
 
 @unstable()
@@ -26,6 +26,7 @@ def business_day_count(
     end: date | IntoExprColumn,
     week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
     holidays: Iterable[date] = (),
+    calendar: HolidayCalendar | None = None,
 ) -> Expr:
     """
     Count the number of business days between `start` and `end` (not including `end`).
@@ -60,6 +61,9 @@ def business_day_count(
             my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
         and pass `holidays=my_holidays` when you call `business_day_count`.
+    calendar
+        A :class:`HolidayCalendar` whose holidays are excluded from the count, in
+        addition to `holidays`.
 
     Returns
     -------
@@ -134,5 +138,185 @@ def business_day_count(
             end_pyexpr,
             week_mask,
             [(holiday - unix_epoch).days for holiday in holidays],
+            calendar,
         )
     )
+
+
+class Holiday:
+    """
+    A rule-based holiday, for use in a :class:`HolidayCalendar`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    The date of the holiday is given by exactly one of:
+
+    - `month` and `day`, for a holiday on the same date every year;
+    - `month`, `weekday` and `n`, for the `n`-th weekday of a month;
+    - `easter_offset`, for a holiday relative to (Western) Easter Sunday.
+
+    Parameters
+    ----------
+    name
+        Name of the holiday.
+    month
+        Month of the holiday (1-12).
+    day
+        Day of the month of the holiday.
+    weekday
+        Day of the week of the holiday, from 1 (Monday) to 7 (Sunday).
+    n
+        Which occurrence of `weekday` in `month` the holiday falls on. Negative
+        values count from the end of the month, so `-1` is the last one.
+    easter_offset
+        Number of days after Easter Sunday, e.g. `-2` for Good Friday.
+    observance
+        How a holiday falling on a weekend is moved:
+
+        - `'none'`: observe the holiday on its actual date
+        - `'nearest_weekday'`: Saturday moves to Friday, Sunday to Monday
+        - `'next_monday'`: Saturday and Sunday move to Monday
+        - `'sunday_to_monday'`: only Sunday moves to Monday
+    start_year
+        First year in which the holiday is observed.
+    end_year
+        Last year in which the holiday is observed.
+
+    Examples
+    --------
+    >>> pl.Holiday("Christmas Day", month=12, day=25, observance="nearest_weekday")
+    Holiday('Christmas Day', month=12, day=25, observance='nearest_weekday')
+    >>> pl.Holiday("Thanksgiving", month=11, weekday=4, n=4)
+    Holiday('Thanksgiving', month=11, weekday=4, n=4)
+    >>> pl.Holiday("Good Friday", easter_offset=-2)
+    Holiday('Good Friday', easter_offset=-2)
+    """
+
+    def __init__(
+        self,
+        name: str,
+        *,
+        month: int | None = None,
+        day: int | None = None,
+        weekday: int | None = None,
+        n: int = 1,
+        easter_offset: int | None = None,
+        observance: HolidayObservance = "none",
+        start_year: int | None = None,
+        end_year: int | None = None,
+    ) -> None:
+        if easter_offset is not None:
+            valid = month is None and day is None and weekday is None
+        elif weekday is not None:
+            valid = month is not None and day is None
+        else:
+            valid = month is not None and day is not None
+        if not valid:
+            msg = (
+                f"holiday {name!r} must specify either `month` and `day`, "
+                "`month` and `weekday`, or `easter_offset`"
+            )
+            raise ValueError(msg)
+
+        self.name = name
+        self.month = month
+        self.day = day
+        self.weekday = weekday
+        self.n = n
+        self.easter_offset = easter_offset
+        self.observance = observance
+        self.start_year = start_year
+        self.end_year = end_year
+
+    def __repr__(self) -> str:
+        if self.easter_offset is not None:
+            rule = f"easter_offset={self.easter_offset}"
+        elif self.weekday is not None:
+            rule = f"month={self.month}, weekday={self.weekday}, n={self.n}"
+        else:
+            rule = f"month={self.month}, day={self.day}"
+        extra = ""
+        if self.observance != "none":
+            extra += f", observance={self.observance!r}"
+        if self.start_year is not None:
+            extra += f", start_year={self.start_year}"
+        if self.end_year is not None:
+            extra += f", end_year={self.end_year}"
+        return f"Holiday({self.name!r}, {rule}{extra})"
+
+
+class HolidayCalendar:
+    """
+    A named, reusable set of rule-based holidays.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    A calendar can be passed as `calendar` to :func:`business_day_count`,
+    :meth:`Expr.dt.add_business_days` and :meth:`Expr.dt.is_business_day`, in which
+    case the holidays are generated for whichever dates are being processed.
+
+    Parameters
+    ----------
+    name
+        Name of the calendar.
+    holidays
+        The holidays making up the calendar.
+
+    Examples
+    --------
+    >>> from datetime import date
+    >>> calendar = pl.HolidayCalendar(
+    ...     "example",
+    ...     [
+    ...         pl.Holiday("New Year's Day", month=1, day=1, observance="next_monday"),
+    ...         pl.Holiday("Easter Monday", easter_offset=1),
+    ...         pl.Holiday("Labour Day", month=5, weekday=1, n=1),
+    ...     ],
+    ... )
+    >>> calendar.holidays_between(date(2022, 1, 1), date(2022, 12, 31))
+    [datetime.date(2022, 1, 3), datetime.date(2022, 4, 18), datetime.date(2022, 5, 2)]
+
+    >>> df = pl.DataFrame({"start": [date(2021, 12, 31), date(2022, 4, 14)]})
+    >>> df.with_columns(
+    ...     result=pl.col("start").dt.add_business_days(1, calendar=calendar)
+    ... )
+    shape: (2, 2)
+    ┌────────────┬────────────┐
+    │ start      ┆ result     │
+    │ ---        ┆ ---        │
+    │ date       ┆ date       │
+    ╞════════════╪════════════╡
+    │ 2021-12-31 ┆ 2022-01-04 │
+    │ 2022-04-14 ┆ 2022-04-15 │
+    └────────────┴────────────┘
+    """
+
+    def __init__(self, name: str, holidays: Iterable[Holiday]) -> None:
+        self.name = name
+        self.holidays = list(holidays)
+
+    def __repr__(self) -> str:
+        return f"HolidayCalendar({self.name!r}, {self.holidays!r})"
+
+    def holidays_between(self, start: date, end: date) -> list[date]:
+        """
+        Generate the observed holidays between `start` and `end` (inclusive).
+
+        Parameters
+        ----------
+        start
+            First date to consider.
+        end
+            Last date to consider.
+        """
+        unix_epoch = date(1970, 1, 1)
+        return [
+            unix_epoch + timedelta(days=days)
+            for days in plr.holiday_calendar_holidays(
+                self, (start - unix_epoch).days, (end - unix_epoch).days
+            )
+        ]
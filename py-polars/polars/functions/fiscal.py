@@ -0,0 +1,124 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Literal
+
+if TYPE_CHECKING:
+    from polars._typing import FiscalWeekPattern
+
+
+class FiscalCalendar:
+    """
+    A fiscal calendar, made of either whole months or retail (52/53-week) years.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Without a `week_pattern`, the fiscal year starts on the first day of
+    `start_month` and its periods are calendar months.
+
+    With a `week_pattern`, the fiscal year ends on the `end_weekday` that is last
+    in, or nearest to the end of, the month before `start_month`. Each quarter then
+    consists of 13 weeks, divided over its three periods according to the pattern.
+    The 53rd week that occurs every five or six years is added to the last period.
+
+    A calendar can be passed to :meth:`Expr.dt.fiscal_year`,
+    :meth:`Expr.dt.fiscal_quarter`, :meth:`Expr.dt.fiscal_period`,
+    :meth:`Expr.dt.fiscal_week`, :meth:`Expr.dt.fiscal_truncate` and
+    :meth:`DataFrame.group_by_dynamic`.
+
+    Parameters
+    ----------
+    start_month
+        Month (1-12) in which the fiscal year starts.
+    week_pattern : {None, '445', '454', '544'}
+        Number of weeks in each of the three periods of a quarter, for a retail
+        calendar. `None` (default) makes a calendar of whole months.
+    end_weekday
+        Day of the week on which a retail fiscal year ends, from 1 (Monday)
+        to 7 (Sunday).
+    year_end : {'nearest', 'last'}
+        Whether a retail fiscal year ends on the `end_weekday` nearest to the end
+        of the month before `start_month`, or on the last one in that month.
+    label : {'end', 'start'}
+        Whether a fiscal year is named after the calendar year in which it ends
+        or in which it starts.
+
+    Examples
+    --------
+    A fiscal year starting in October, as used by the US federal government:
+
+    >>> from datetime import date
+    >>> calendar = pl.FiscalCalendar(start_month=10)
+    >>> df = pl.DataFrame({"date": [date(2023, 9, 30), date(2023, 11, 15)]})
+    >>> df.with_columns(
+    ...     year=pl.col("date").dt.fiscal_year(calendar),
+    ...     quarter=pl.col("date").dt.fiscal_quarter(calendar),
+    ...     period=pl.col("date").dt.fiscal_period(calendar),
+    ... )
+    shape: (2, 4)
+    ┌────────────┬──────┬─────────┬────────┐
+    │ date       ┆ year ┆ quarter ┆ period │
+    │ ---        ┆ ---  ┆ ---     ┆ ---    │
+    │ date       ┆ i32  ┆ i8      ┆ i8     │
+    ╞════════════╪══════╪═════════╪════════╡
+    │ 2023-09-30 ┆ 2023 ┆ 4       ┆ 12     │
+    │ 2023-11-15 ┆ 2024 ┆ 1       ┆ 2      │
+    └────────────┴──────┴─────────┴────────┘
+
+    A 4-5-4 retail calendar ending on the Saturday nearest to the end of January,
+    named after the year in which it starts:
+
+    >>> retail = pl.FiscalCalendar(
+    ...     start_month=2, week_pattern="454", end_weekday=6, label="start"
+    ... )
+    >>> df = pl.DataFrame({"date": [date(2023, 1, 28), date(2024, 2, 3)]})
+    >>> df.with_columns(
+    ...     year=pl.col("date").dt.fiscal_year(retail),
+    ...     week=pl.col("date").dt.fiscal_week(retail),
+    ...     period_start=pl.col("date").dt.fiscal_truncate(retail, "period"),
+    ... )
+    shape: (2, 4)
+    ┌────────────┬──────┬──────┬──────────────┐
+    │ date       ┆ year ┆ week ┆ period_start │
+    │ ---        ┆ ---  ┆ ---  ┆ ---          │
+    │ date       ┆ i32  ┆ i8   ┆ date         │
+    ╞════════════╪══════╪══════╪══════════════╡
+    │ 2023-01-28 ┆ 2022 ┆ 52   ┆ 2023-01-01   │
+    │ 2024-02-03 ┆ 2023 ┆ 53   ┆ 2023-12-31   │
+    └────────────┴──────┴──────┴──────────────┘
+    """
+
+    def __init__(
+        self,
+        start_month: int = 1,
+        *,
+        week_pattern: FiscalWeekPattern | None = None,
+        end_weekday: int = 7,
+        year_end: Literal["nearest", "last"] = "nearest",
+        label: Literal["end", "start"] = "end",
+    ) -> None:
+        if not 1 <= start_month <= 12:
+            msg = f"`start_month` must be between 1 and 12, got {start_month}"
+            raise ValueError(msg)
+        if not 1 <= end_weekday <= 7:
+            msg = f"`end_weekday` must be between 1 and 7, got {end_weekday}"
+            raise ValueError(msg)
+
+        self.start_month = start_month
+        self.week_pattern = week_pattern
+        self.end_weekday = end_weekday
+        self.year_end = year_end
+        self.label = label
+
+    def __repr__(self) -> str:
+        args = f"start_month={self.start_month}"
+        if self.week_pattern is not None:
+            args += (
+                f", week_pattern={self.week_pattern!r}"
+                f", end_weekday={self.end_weekday}"
+                f", year_end={self.year_end!r}"
+            )
+        if self.label != "end":
+            args += f", label={self.label!r}"
+        return f"FiscalCalendar({args})"
@@ -850,7 +850,7 @@ def corr(
     eager: bool = False,
 ) -> Expr | Series:
     """
-    Compute the Pearson's or Spearman rank correlation between two columns.
+    Compute the Pearson's, Spearman rank or Kendall's tau correlation of two columns.
 
     Parameters
     ----------
@@ -863,8 +863,9 @@ def corr(
 
         .. deprecated:: 1.17.0
 
-    method : {'pearson', 'spearman'}
-        Correlation method.
+    method : {'pearson', 'spearman', 'kendall'}
+        Correlation method. `'kendall'` computes Kendall's tau-b, which accounts
+        for ties.
     propagate_nans
         If `True` any `NaN` encountered will lead to `NaN` in the output.
         Defaults to `False` where `NaN` are regarded as larger than any finite number
@@ -907,6 +908,18 @@ def corr(
     │ 0.5 │
     └─────┘
 
+    Kendall's tau correlation:
+
+    >>> df.select(pl.corr("a", "b", method="kendall"))
+    shape: (1, 1)
+    ┌──────────┐
+    │ a        │
+    │ ---      │
+    │ f64      │
+    ╞══════════╡
+    │ 0.333333 │
+    └──────────┘
+
     Eager evaluation:
 
     >>> s1 = pl.Series("a", [1, 8, 3])
@@ -948,11 +961,74 @@ def corr(
             return wrap_expr(plr.pearson_corr(a, b))
         elif method == "spearman":
             return wrap_expr(plr.spearman_rank_corr(a, b, propagate_nans))
+        elif method == "kendall":
+            return wrap_expr(plr.kendall_tau_corr(a, b))
         else:
-            msg = f"method must be one of {{'pearson', 'spearman'}}, got {method!r}"
+            msg = (
+                "method must be one of {'pearson', 'spearman', 'kendall'},"
+                f" got {method!r}"
+            )
             raise ValueError(msg)
 
 
+def corr_matrix(
+    *columns: str | Iterable[str],
+    method: CorrelationMethod = "pearson",
+) -> Expr:
+    """
+    Compute the correlation between every pair of the given columns.
+
+    The result is a single list of structs with the fields `column_1`, `column_2`
+    and `correlation`, holding one element per pair of distinct columns. As it is an
+    aggregation, it can be used in a `group_by` context; explode and unnest it to get
+    a tidy frame. Use :meth:`DataFrame.corr_matrix` to compute the correlations over
+    a whole frame.
+
+    Parameters
+    ----------
+    *columns
+        Names of the columns to correlate, at least two.
+    method : {'pearson', 'spearman', 'kendall'}
+        Correlation method.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "g": ["a", "a", "a", "b", "b", "b"],
+    ...         "x": [1, 2, 3, 4, 5, 6],
+    ...         "y": [3, 1, 2, 5, 4, 6],
+    ...         "z": [6, 5, 4, 3, 2, 1],
+    ...     }
+    ... )
+    >>> (
+    ...     df.group_by("g", maintain_order=True)
+    ...     .agg(pl.corr_matrix("x", "y", "z", method="kendall"))
+    ...     .explode("corr_matrix")
+    ...     .unnest("corr_matrix")
+    ... )
+    shape: (6, 4)
+    ┌─────┬──────────┬──────────┬─────────────┐
+    │ g   ┆ column_1 ┆ column_2 ┆ correlation │
+    │ --- ┆ ---      ┆ ---      ┆ ---         │
+    │ str ┆ str      ┆ str      ┆ f64         │
+    ╞═════╪══════════╪══════════╪═════════════╡
+    │ a   ┆ x        ┆ y        ┆ -0.333333   │
+    │ a   ┆ x        ┆ z        ┆ -1.0        │
+    │ a   ┆ y        ┆ z        ┆ 0.333333    │
+    │ b   ┆ x        ┆ y        ┆ 0.333333    │
+    │ b   ┆ x        ┆ z        ┆ -1.0        │
+    │ b   ┆ y        ┆ z        ┆ -0.333333   │
+    └─────┴──────────┴──────────┴─────────────┘
+    """
+    names = [
+        name
+        for column in columns
+        for name in ([column] if isinstance(column, str) else column)
+    ]
+    return wrap_expr(plr.corr_matrix(names, method))
+
+
 @overload
 def cov(
     a: IntoExpr,
@@ -1,10 +1,16 @@
 from __future__ import annotations
 
 import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_expr
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     import polars.polars as plr
 
+if TYPE_CHECKING:
+    from polars import Expr
+
 
 def set_random_seed(seed: int) -> None:
     r"""
@@ -20,3 +26,36 @@ def set_random_seed(seed: int) -> None:
         random number generator.
     """
     plr.set_random_seed(seed)
+
+
+def uuid4(seed: int | None = None) -> Expr:
+    """
+    Generate a random version 4 UUID for every row in the context.
+
+    Parameters
+    ----------
+    seed
+        Seed for the random number generator. If set to None (default), a random
+        seed is generated every time the expression is evaluated.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`Uuid`, named `uuid`.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2, 3]})
+    >>> df.select(pl.uuid4(seed=42))
+    shape: (3, 1)
+    ┌─────────────────────────────────┐
+    │ uuid                            │
+    │ ---                             │
+    │ uuid                            │
+    ╞═════════════════════════════════╡
+    │ 42e56270-889a-474d-ba7c-849b22… │
+    │ 138e2dcf-c278-4448-8c62-34dab6… │
+    │ 1572d381-4fac-45e8-b432-efe69f… │
+    └─────────────────────────────────┘
+    """
+    return wrap_expr(plr.uuid4(seed))
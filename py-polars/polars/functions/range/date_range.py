@@ -94,6 +94,7 @@ def date_range(
     `interval` is created according to the following string language:
 
     - 1d    (1 calendar day)
+    - 1bd   (1 business day, skipping weekends)
     - 1w    (1 calendar week)
     - 1mo   (1 calendar month)
     - 1q    (1 calendar quarter)
@@ -258,6 +259,7 @@ def date_ranges(
     `interval` is created according to the following string language:
 
     - 1d    (1 calendar day)
+    - 1bd   (1 business day, skipping weekends)
     - 1w    (1 calendar week)
     - 1mo   (1 calendar month)
     - 1q    (1 calendar quarter)
@@ -111,6 +111,7 @@ def datetime_range(
     - 1m    (1 minute)
     - 1h    (1 hour)
     - 1d    (1 calendar day)
+    - 1bd   (1 business day, skipping weekends)
     - 1w    (1 calendar week)
     - 1mo   (1 calendar month)
     - 1q    (1 calendar quarter)
@@ -308,6 +309,7 @@ def datetime_ranges(
     - 1m    (1 minute)
     - 1h    (1 hour)
     - 1d    (1 calendar day)
+    - 1bd   (1 business day, skipping weekends)
     - 1w    (1 calendar week)
     - 1mo   (1 calendar month)
     - 1q    (1 calendar quarter)
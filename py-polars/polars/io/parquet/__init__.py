@@ -2,6 +2,7 @@ from polars.io.parquet.field_overwrites import (
     ParquetFieldOverwrites,
 )
 from polars.io.parquet.functions import (
+    clear_parquet_metadata_cache,
     read_parquet,
     read_parquet_metadata,
     read_parquet_schema,
@@ -10,6 +11,7 @@ from polars.io.parquet.functions import (
 
 __all__ = [
     "ParquetFieldOverwrites",
+    "clear_parquet_metadata_cache",
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
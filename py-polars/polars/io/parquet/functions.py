@@ -31,6 +31,9 @@ from polars.io.scan_options._options import ScanOptions
 
 with contextlib.suppress(ImportError):
     from polars.polars import PyLazyFrame
+    from polars.polars import (
+        clear_parquet_metadata_cache as _clear_parquet_metadata_cache,
+    )
     from polars.polars import read_parquet_metadata as _read_parquet_metadata
     from polars.polars import read_parquet_schema as _read_parquet_schema
 
@@ -387,6 +390,33 @@ def read_parquet_metadata(source: str | Path | IO[bytes] | bytes) -> dict[str, s
     return _read_parquet_metadata(source)
 
 
+def clear_parquet_metadata_cache(source: str | None = None) -> None:
+    """
+    Clear the cache of Parquet metadata of cloud files.
+
+    The metadata (footer) of Parquet files read from cloud storage is cached for
+    the lifetime of the process, and is only re-read once the remote file changes.
+    The size of the cache is bounded by the encoded size of the cached footers,
+    which is set with the `POLARS_PARQUET_METADATA_CACHE_MAX_SIZE` environment
+    variable (64 MiB by default, `0` disables the cache).
+
+    .. warning::
+        This functionality is considered **experimental**. It may be removed or
+        changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        URI of the file to remove from the cache, e.g. `"s3://bucket/file.parquet"`.
+        Clears the whole cache if not given.
+
+    Examples
+    --------
+    >>> pl.io.parquet.clear_parquet_metadata_cache()  # doctest: +SKIP
+    """
+    _clear_parquet_metadata_cache(source)
+
+
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
 @deprecate_renamed_parameter("row_count_offset", "row_index_offset", version="0.20.4")
 def scan_parquet(
@@ -14,7 +14,7 @@ if TYPE_CHECKING:
     with contextlib.suppress(ImportError):  # Module not available when building docs
         from polars.polars import PyDataFrame, PyExpr
 
-    from typing import IO, Any, Callable
+    from typing import IO, Any, Callable, Literal
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars.polars import PyPartitioning
@@ -339,6 +339,24 @@ class PartitionByKey(PartitioningScheme):
 
         For parquet files, the callback is given a dataframe with metrics about all
         files written files.
+    path_template
+        A template for the output path of each file relative to the `base_path`,
+        e.g. `"year={year}/month={month:02}/part-{uuid}.{ext}"`.
+
+        Placeholders refer to the key columns or to one of `{uuid}`, `{file_idx}`,
+        `{part_idx}`, `{in_part_idx}` and `{ext}`. Integers can be zero-padded with
+        `{name:0N}`. Key values are escaped the same way as Hive and Spark. Use `{{`
+        and `}}` for literal braces.
+    overwrite_mode : {'append', 'dynamic'}
+        What to do with existing data in the output directory.
+
+        - `"append"`: Keep existing files. Files with the same path are overwritten.
+        - `"dynamic"`: Replace the existing data of each partition that is
+          written to. New files are written to a staging directory first and
+          only replace the existing partitions once all files have been written
+          successfully. Other partitions are left untouched. This requires all
+          keys to be in the directories of `path_template` and cannot be
+          combined with `file_path`.
 
     Examples
     --------
@@ -384,6 +402,8 @@ class PartitionByKey(PartitioningScheme):
         include_key: bool = True,
         per_partition_sort_by: str | Expr | Iterable[str | Expr] | None = None,
         finish_callback: Callable[[DataFrame], None] | None = None,
+        path_template: str | None = None,
+        overwrite_mode: Literal["append", "dynamic"] = "append",
     ) -> None:
         issue_unstable_warning("partitioning strategies are considered unstable.")
 
@@ -398,6 +418,8 @@ class PartitionByKey(PartitioningScheme):
                     per_partition_sort_by
                 ),
                 finish_callback=_prepare_finish_callback(finish_callback),
+                path_template=path_template,
+                overwrite_mode=overwrite_mode,
             )
         )
 
@@ -446,6 +468,24 @@ class PartitionParted(PartitioningScheme):
 
         For parquet files, the callback is given a dataframe with metrics about all
         files written files.
+    path_template
+        A template for the output path of each file relative to the `base_path`,
+        e.g. `"year={year}/month={month:02}/part-{uuid}.{ext}"`.
+
+        Placeholders refer to the key columns or to one of `{uuid}`, `{file_idx}`,
+        `{part_idx}`, `{in_part_idx}` and `{ext}`. Integers can be zero-padded with
+        `{name:0N}`. Key values are escaped the same way as Hive and Spark. Use `{{`
+        and `}}` for literal braces.
+    overwrite_mode : {'append', 'dynamic'}
+        What to do with existing data in the output directory.
+
+        - `"append"`: Keep existing files. Files with the same path are overwritten.
+        - `"dynamic"`: Replace the existing data of each partition that is
+          written to. New files are written to a staging directory first and
+          only replace the existing partitions once all files have been written
+          successfully. Other partitions are left untouched. This requires all
+          keys to be in the directories of `path_template` and cannot be
+          combined with `file_path`.
 
     Examples
     --------
@@ -473,6 +513,8 @@ class PartitionParted(PartitioningScheme):
         include_key: bool = True,
         per_partition_sort_by: str | Expr | Iterable[str | Expr] | None = None,
         finish_callback: Callable[[DataFrame], None] | None = None,
+        path_template: str | None = None,
+        overwrite_mode: Literal["append", "dynamic"] = "append",
     ) -> None:
         issue_unstable_warning("partitioning strategies are considered unstable.")
 
@@ -487,5 +529,7 @@ class PartitionParted(PartitioningScheme):
                     per_partition_sort_by
                 ),
                 finish_callback=_prepare_finish_callback(finish_callback),
+                path_template=path_template,
+                overwrite_mode=overwrite_mode,
             )
         )
@@ -116,11 +116,13 @@ if TYPE_CHECKING:
     with contextlib.suppress(ImportError):  # Module not available when building docs
         from polars.polars import PyExpr, PyPartitioning
 
-    from polars import DataFrame, DataType, Expr
+    from polars import DataFrame, DataType, Expr, FiscalCalendar
     from polars._typing import (
         AsofJoinStrategy,
         ClosedInterval,
         ColumnNameOrSelector,
+        CorrelationMethod,
+        CorrMatrixLayout,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
@@ -4673,6 +4675,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        fiscal_calendar: FiscalCalendar | None = None,
     ) -> LazyGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -4746,6 +4749,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        fiscal_calendar
+            Align the windows to the fiscal years, quarters, periods or weeks of
+            this :class:`FiscalCalendar`, for which `every` must be `'1y'`, `'1q'`,
+            `'1mo'` or `'1w'` respectively. `period` must then equal `every`, and
+            `offset` and `start_by` are not used.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Returns
         -------
@@ -4788,6 +4800,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
            - 1m    (1 minute)
            - 1h    (1 hour)
            - 1d    (1 calendar day)
+           - 1bd   (1 business day, skipping weekends)
            - 1w    (1 calendar week)
            - 1mo   (1 calendar month)
            - 1q    (1 calendar quarter)
@@ -4995,6 +5008,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             closed,
             pyexprs_by,
             start_by,
+            fiscal_calendar,
         )
         return LazyGroupBy(lgb)
 
@@ -7393,6 +7407,73 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             subset = parse_into_list_of_expressions(subset)
         return self._from_pyldf(self._ldf.drop_nulls(subset))
 
+    def corr_matrix(
+        self,
+        columns: Sequence[str] | None = None,
+        *,
+        method: CorrelationMethod = "pearson",
+        layout: CorrMatrixLayout = "square",
+    ) -> LazyFrame:
+        """
+        Compute the correlation between every pair of columns in a single pass.
+
+        Parameters
+        ----------
+        columns
+            Names of the columns to correlate, at least two. Defaults to all numeric
+            columns.
+        method : {'pearson', 'spearman', 'kendall'}
+            Correlation method.
+        layout : {'square', 'tidy'}
+            Shape of the result:
+
+            - 'square' : A `column` label column followed by one column per input
+              column.
+            - 'tidy' : The columns `column_1`, `column_2` and `correlation`, with one
+              row per pair of distinct columns.
+
+        See Also
+        --------
+        polars.corr_matrix : Compute the correlations within a `group_by` context.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "x": [1, 2, 3, 4, 5],
+        ...         "y": [3, 1, 2, 5, 4],
+        ...         "z": [5, 4, 3, 2, 1],
+        ...     }
+        ... )
+        >>> lf.corr_matrix(method="kendall").collect()
+        shape: (3, 4)
+        ┌────────┬──────┬──────┬──────┐
+        │ column ┆ x    ┆ y    ┆ z    │
+        │ ---    ┆ ---  ┆ ---  ┆ ---  │
+        │ str    ┆ f64  ┆ f64  ┆ f64  │
+        ╞════════╪══════╪══════╪══════╡
+        │ x      ┆ 1.0  ┆ 0.4  ┆ -1.0 │
+        │ y      ┆ 0.4  ┆ 1.0  ┆ -0.4 │
+        │ z      ┆ -1.0 ┆ -0.4 ┆ 1.0  │
+        └────────┴──────┴──────┴──────┘
+        >>> lf.corr_matrix(["x", "y"], layout="tidy").collect()
+        shape: (1, 3)
+        ┌──────────┬──────────┬─────────────┐
+        │ column_1 ┆ column_2 ┆ correlation │
+        │ ---      ┆ ---      ┆ ---         │
+        │ str      ┆ str      ┆ f64         │
+        ╞══════════╪══════════╪═════════════╡
+        │ x        ┆ y        ┆ 0.6         │
+        └──────────┴──────────┴─────────────┘
+        """
+        if columns is None:
+            columns = [
+                name
+                for name, dtype in self.collect_schema().items()
+                if dtype.is_numeric()
+            ]
+        return self._from_pyldf(self._ldf.corr_matrix(list(columns), method, layout))
+
     def unpivot(
         self,
         on: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
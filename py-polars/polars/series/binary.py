@@ -248,3 +248,184 @@ class BinaryNameSpace:
         ]
 
         """
+
+    def md5(self, *, hex: bool = False) -> Series:
+        """
+        Compute the MD5 digest of every value.
+
+        String values are hashed by their UTF-8 bytes, so the digests match those
+        computed by other systems.
+
+        Parameters
+        ----------
+        hex
+            Return the digest as a lowercase hexadecimal string instead of raw bytes.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`, or :class:`String` if `hex` is set.
+
+        Examples
+        --------
+        >>> s = pl.Series(["abc", "polars"])
+        >>> s.bin.md5(hex=True)
+        shape: (2,)
+        Series: '' [str]
+        [
+            "900150983cd24fb0d6963f7d28e17f…
+            "33f83688ff9c10a6eb6a9b34d8fac7…
+        ]
+        """
+
+    def sha1(self, *, hex: bool = False) -> Series:
+        """
+        Compute the SHA-1 digest of every value.
+
+        String values are hashed by their UTF-8 bytes, so the digests match those
+        computed by other systems.
+
+        Parameters
+        ----------
+        hex
+            Return the digest as a lowercase hexadecimal string instead of raw bytes.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`, or :class:`String` if `hex` is set.
+
+        Examples
+        --------
+        >>> s = pl.Series(["abc", "polars"])
+        >>> s.bin.sha1(hex=True)
+        shape: (2,)
+        Series: '' [str]
+        [
+            "a9993e364706816aba3e25717850c2…
+            "8d086de772d98be7aaf9379faf9041…
+        ]
+        """
+
+    def sha256(self, *, hex: bool = False) -> Series:
+        """
+        Compute the SHA-256 digest of every value.
+
+        String values are hashed by their UTF-8 bytes, so the digests match those
+        computed by other systems.
+
+        Parameters
+        ----------
+        hex
+            Return the digest as a lowercase hexadecimal string instead of raw bytes.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`, or :class:`String` if `hex` is set.
+
+        Examples
+        --------
+        >>> s = pl.Series(["abc", "polars"])
+        >>> s.bin.sha256(hex=True)
+        shape: (2,)
+        Series: '' [str]
+        [
+            "ba7816bf8f01cfea414140de5dae22…
+            "e37e7e864c58e1e96380229f66040d…
+        ]
+        """
+
+    def xxh3_64(self, seed: int = 0) -> Series:
+        """
+        Compute the 64-bit XXH3 hash of every value.
+
+        Unlike :meth:`Series.hash`, the result is stable across Polars versions and
+        matches other XXH3 implementations.
+
+        Parameters
+        ----------
+        seed
+            The seed of the hash.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt64`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["abc", "polars"])
+        >>> s.bin.xxh3_64()
+        shape: (2,)
+        Series: '' [u64]
+        [
+            8696274497037089104
+            16500643583958147475
+        ]
+        """
+
+    def xxh3_128(self, seed: int = 0, *, hex: bool = False) -> Series:
+        """
+        Compute the 128-bit XXH3 hash of every value.
+
+        The hash is returned in its canonical, big-endian byte order.
+
+        Parameters
+        ----------
+        seed
+            The seed of the hash.
+        hex
+            Return the hash as a lowercase hexadecimal string instead of raw bytes.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`, or :class:`String` if `hex` is set.
+
+        Examples
+        --------
+        >>> s = pl.Series(["abc", "polars"])
+        >>> s.bin.xxh3_128(hex=True)
+        shape: (2,)
+        Series: '' [str]
+        [
+            "06b05ab6733a618578af5f94892f39…
+            "650e6b0344b42868c9259c68de7530…
+        ]
+        """
+
+    def murmur3_32(self, seed: int | None = None, *, spark: bool = False) -> Series:
+        """
+        Compute the 32-bit MurmurHash3 (x86 variant) of every value.
+
+        By default this is the hash used by the Iceberg bucket transform, which
+        assigns a value to bucket `(murmur3_32 & 2147483647) % num_buckets`.
+        With `spark=True` this is the hash computed by Spark's `hash` function for
+        a single string or binary column.
+
+        Parameters
+        ----------
+        seed
+            The seed of the hash. Defaults to 0, or to 42 (the seed of Spark's
+            `hash`) if `spark` is set.
+        spark
+            Hash the trailing bytes that do not fill a 4-byte block like Spark
+            does, which mixes in every byte on its own as a signed integer.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["iceberg", "polars"])
+        >>> s.bin.murmur3_32()
+        shape: (2,)
+        Series: '' [i32]
+        [
+            1210000089
+            -56604853
+        ]
+        """
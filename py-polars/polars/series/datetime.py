@@ -12,10 +12,11 @@ if TYPE_CHECKING:
     import sys
     from collections.abc import Iterable
 
-    from polars import Series
+    from polars import FiscalCalendar, HolidayCalendar, Series
     from polars._typing import (
         Ambiguous,
         EpochTimeUnit,
+        FiscalUnit,
         IntoExpr,
         IntoExprColumn,
         NonExistent,
@@ -50,8 +51,10 @@ class DateTimeNameSpace:
         self,
         n: int | IntoExpr,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+
         holidays: Iterable[dt.date] = (),
         roll: Roll = "raise",
+        calendar: HolidayCalendar | None = None,
     ) -> Series:
         """
         Offset by `n` business days.
@@ -91,6 +94,9 @@ class DateTimeNameSpace:
             - `'raise'`: raise an error
             - `'forward'`: move to the next business day
             - `'backward'`: move to the previous business day
+        calendar
+            A :class:`HolidayCalendar` whose holidays are skipped, in addition to
+            `holidays`.
 
         Returns
         -------
@@ -495,7 +501,9 @@ class DateTimeNameSpace:
         self,
         *,
         week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+
         holidays: Iterable[dt.date] = (),
+        calendar: HolidayCalendar | None = None,
     ) -> Series:
         """
         Determine whether each day lands on a business day.
@@ -523,6 +531,9 @@ class DateTimeNameSpace:
                 my_holidays = holidays.country_holidays("NL", years=range(2020, 2025))
 
             and pass `holidays=my_holidays` when you call `is_business_day`.
+        calendar
+            A :class:`HolidayCalendar` whose holidays are not business days, in
+            addition to `holidays`.
 
         Returns
         -------
@@ -1735,6 +1746,7 @@ class DateTimeNameSpace:
             - 1m    (1 minute)
             - 1h    (1 hour)
             - 1d    (1 calendar day)
+            - 1bd   (1 business day, skipping weekends)
             - 1w    (1 calendar week)
             - 1mo   (1 calendar month)
             - 1q    (1 calendar quarter)
@@ -1823,6 +1835,7 @@ class DateTimeNameSpace:
         - 1m    (1 minute)
         - 1h    (1 hour)
         - 1d    (1 calendar day)
+        - 1bd   (1 business day, skipping weekends)
         - 1w    (1 calendar week)
         - 1mo   (1 calendar month)
         - 1q    (1 calendar quarter)
@@ -2100,6 +2113,181 @@ class DateTimeNameSpace:
         ]
         """
 
+    @unstable()
+    def fiscal_year(self, calendar: FiscalCalendar) -> Series:
+        """
+        Extract the fiscal year according to a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal years.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int32`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("date", [date(2023, 9, 30), date(2023, 10, 1)])
+        >>> s.dt.fiscal_year(pl.FiscalCalendar(start_month=10))
+        shape: (2,)
+        Series: 'date' [i32]
+        [
+                2023
+                2024
+        ]
+        """
+
+    @unstable()
+    def fiscal_quarter(self, calendar: FiscalCalendar) -> Series:
+        """
+        Extract the fiscal quarter according to a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns the quarter ranging from 1 to 4.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal quarters.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("date", [date(2023, 10, 1), date(2024, 1, 15)])
+        >>> s.dt.fiscal_quarter(pl.FiscalCalendar(start_month=10))
+        shape: (2,)
+        Series: 'date' [i8]
+        [
+                1
+                2
+        ]
+        """
+
+    @unstable()
+    def fiscal_period(self, calendar: FiscalCalendar) -> Series:
+        """
+        Extract the fiscal period according to a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Returns the period ranging from 1 to 12.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal periods.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> s = pl.Series("date", [date(2023, 10, 1), date(2024, 1, 15)])
+        >>> s.dt.fiscal_period(pl.FiscalCalendar(start_month=10))
+        shape: (2,)
+        Series: 'date' [i8]
+        [
+                1
+                4
+        ]
+        """
+
+    @unstable()
+    def fiscal_week(self, calendar: FiscalCalendar) -> Series:
+        """
+        Extract the week of the fiscal year according to a fiscal calendar.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Weeks are counted from the first day of the fiscal year and range from 1
+        to 53.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal years.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.FiscalCalendar(
+        ...     start_month=1, week_pattern="445", end_weekday=7, year_end="last"
+        ... )
+        >>> s = pl.Series("date", [date(2024, 1, 1), date(2024, 12, 29)])
+        >>> s.dt.fiscal_week(calendar)
+        shape: (2,)
+        Series: 'date' [i8]
+        [
+                1
+                52
+        ]
+        """
+
+    @unstable()
+    def fiscal_truncate(self, calendar: FiscalCalendar, unit: FiscalUnit) -> Series:
+        """
+        Truncate to the start of the fiscal year, quarter, period or week.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        calendar
+            The :class:`FiscalCalendar` defining the fiscal units.
+        unit : {'year', 'quarter', 'period', 'week'}
+            The fiscal unit to truncate to.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Date` or :class:`Datetime`.
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> calendar = pl.FiscalCalendar(
+        ...     start_month=1, week_pattern="445", end_weekday=7, year_end="last"
+        ... )
+        >>> s = pl.Series("date", [date(2024, 2, 14), date(2024, 5, 3)])
+        >>> s.dt.fiscal_truncate(calendar, "period")
+        shape: (2,)
+        Series: 'date' [date]
+        [
+                2024-01-29
+                2024-04-29
+        ]
+        """
+
     def base_utc_offset(self) -> Series:
         """
         Base offset from UTC.
@@ -0,0 +1,131 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class IpNameSpace:
+    """
+    Series.ip namespace.
+
+    IP addresses can be given as strings, as 4 (IPv4) or 16 (IPv6) bytes of
+    :class:`Binary` in network byte order, or as :class:`UInt32` (IPv4) and
+    :class:`UInt128` (IPv6) integers. Invalid addresses are treated as null and
+    IPv4-mapped IPv6 addresses (`::ffff:a.b.c.d`) as their IPv4 address.
+    """
+
+    _accessor = "ip"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def to_int(self, *, ipv6: bool = False) -> Series:
+        """
+        Convert IP addresses to integers.
+
+        Parameters
+        ----------
+        ipv6
+            Return :class:`UInt128` integers that can hold IPv6 addresses, with
+            IPv4 addresses mapped into IPv6. By default, :class:`UInt32` integers
+            are returned and IPv6 addresses are null.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32` or :class:`UInt128`.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", ["192.168.0.1", "10.0.0.255", "::1"])
+        >>> s.ip.to_int()
+        shape: (3,)
+        Series: 'ip' [u32]
+        [
+            3232235521
+            167772415
+            null
+        ]
+        """
+
+    def to_binary(self) -> Series:
+        """
+        Convert IP addresses to their bytes in network byte order.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Binary`, with 4 bytes for IPv4 and 16 bytes
+            for IPv6 addresses.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", ["192.168.0.1", "10.0.0.255"])
+        >>> s.ip.to_binary()
+        shape: (2,)
+        Series: 'ip' [binary]
+        [
+            b"\xc0\xa8\x00\x01"
+            b"\x0a\x00\x00\xff"
+        ]
+        """
+
+    def to_string(self) -> Series:
+        """
+        Format IP addresses as strings.
+
+        IPv6 addresses are written in their canonical, compressed form.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", [3232235521, 167772415], dtype=pl.UInt32)
+        >>> s.ip.to_string()
+        shape: (2,)
+        Series: 'ip' [str]
+        [
+            "192.168.0.1"
+            "10.0.0.255"
+        ]
+        """
+
+    def in_network(self, cidr: str) -> Series:
+        """
+        Check whether IP addresses lie in a network.
+
+        Parameters
+        ----------
+        cidr
+            The network in CIDR notation, e.g. `"10.0.0.0/8"` or `"fc00::/7"`.
+            Host bits set in the network address are ignored and an address without
+            a prefix length is a network of a single address.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`. IPv4 networks only contain IPv4
+            addresses and IPv6 networks only IPv6 addresses.
+
+        Examples
+        --------
+        >>> s = pl.Series("ip", ["10.1.2.3", "192.168.0.1", "fd00::1", None])
+        >>> s.ip.in_network("192.168.0.0/16")
+        shape: (4,)
+        Series: 'ip' [bool]
+        [
+            false
+            true
+            false
+            null
+        ]
+        """
@@ -0,0 +1,106 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    import polars as pl
+    from polars import Series
+    from polars._typing import PolarsDataType
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class JsonNameSpace:
+    """Series.json namespace."""
+
+    _accessor = "json"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def get(self, path: str, dtype: PolarsDataType | pl.DataTypeExpr) -> Series:
+        """
+        Extract the value at a JSONPath of every document.
+
+        Only the selected values are decoded. Paths that only access object members
+        and array elements, such as `$.a.b[0]`, are resolved without parsing the rest
+        of the documents.
+
+        Parameters
+        ----------
+        path
+            A valid JSONPath query string. If it selects more than one value in a
+            document, the values are combined into an array.
+        dtype
+            The data type to decode the selected values as. Select :class:`Json` to
+            keep them as JSON documents.
+
+        Returns
+        -------
+        Series
+            Series of data type `dtype`. Documents in which nothing matches are null.
+
+        Raises
+        ------
+        ComputeError
+            If a document that has to be read is not valid JSON.
+
+        Examples
+        --------
+        >>> s = pl.Series("doc", ['{"a": [1, 2]}', '{"a": []}']).cast(pl.Json)
+        >>> s.json.get("$.a[1]", pl.Int64)
+        shape: (2,)
+        Series: 'doc' [i64]
+        [
+            2
+            null
+        ]
+        """
+
+    def compact(self) -> Series:
+        """
+        Remove all whitespace outside of strings.
+
+        Documents are already stored in this form after a cast to :class:`Json`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Json`.
+
+        Examples
+        --------
+        >>> s = pl.Series("doc", ['{"a": [1, 2]}']).cast(pl.Json)
+        >>> s.json.pretty().json.compact()
+        shape: (1,)
+        Series: 'doc' [json]
+        [
+            "{"a":[1,2]}"
+        ]
+        """
+
+    def pretty(self) -> Series:
+        """
+        Format the documents with one object member or array element per line.
+
+        Members and elements are indented by two spaces per level. The order of
+        object keys is preserved.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Json`.
+
+        Examples
+        --------
+        >>> s = pl.Series("doc", ['{"a": [1, 2]}']).cast(pl.Json)
+        >>> print(s.json.pretty().item())
+        {
+          "a": [
+            1,
+            2
+          ]
+        }
+        """
@@ -7,7 +7,7 @@ from polars._utils.wrap import wrap_s
 from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
-    from collections.abc import Collection, Sequence
+    from collections.abc import Collection, Iterable, Sequence
 
     from polars import Expr, Series
     from polars._typing import (
@@ -15,6 +15,7 @@ if TYPE_CHECKING:
         IntoExprColumn,
         ListToStructWidthStrategy,
         NullBehavior,
+        SearchSortedSide,
     )
     from polars.polars import PySeries
 
@@ -327,6 +328,148 @@ class ListNameSpace:
         ]
         """
 
+    def sort_by(
+        self,
+        by: IntoExpr,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        maintain_order: bool = False,
+    ) -> Series:
+        """
+        Sort the lists in this Series by the lists of another Series.
+
+        Every list is reordered such that the list in the same row of `by` is
+        sorted. Both lists must have the same length.
+
+        Parameters
+        ----------
+        by
+            Series of lists to sort by.
+        descending
+            Sort in descending order.
+        nulls_last
+            Place null values of `by` last.
+        maintain_order
+            Keep the original order of elements that have equal values in `by`.
+
+        Examples
+        --------
+        >>> s = pl.Series("events", [["login", "buy", "view"], ["view", "logout"]])
+        >>> ts = pl.Series([[30, 10, 20], [5, 1]])
+        >>> s.list.sort_by(ts, descending=True)
+        shape: (2,)
+        Series: 'events' [list[str]]
+        [
+                ["login", "view", "buy"]
+                ["view", "logout"]
+        ]
+        """
+
+    def arg_sort(
+        self,
+        *,
+        descending: bool = False,
+        nulls_last: bool = False,
+        maintain_order: bool = False,
+    ) -> Series:
+        """
+        Get the indices that would sort the lists in this Series.
+
+        Parameters
+        ----------
+        descending
+            Sort in descending order.
+        nulls_last
+            Place null values last.
+        maintain_order
+            Keep the original order of elements that have equal values.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List` of :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 1, 2], [9, None, 4]])
+        >>> s.list.arg_sort(descending=True, nulls_last=True)
+        shape: (2,)
+        Series: 'a' [list[u32]]
+        [
+                [0, 2, 1]
+                [0, 2, 1]
+        ]
+        """
+
+    def search_sorted(
+        self,
+        element: IntoExpr,
+        side: SearchSortedSide = "any",
+        *,
+        descending: bool = False,
+    ) -> Series:
+        """
+        Find the index where an element should be inserted into every sorted list.
+
+        The lists must already be sorted. The element can differ per row.
+
+        Parameters
+        ----------
+        element
+            Series or scalar value to search for.
+        side : {'any', 'left', 'right'}
+            If 'any', the index of the first suitable location found is given.
+            If 'left', the index of the leftmost suitable location found is given.
+            If 'right', return the rightmost suitable location found is given.
+        descending
+            Boolean indicating whether the lists are sorted in descending order.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`, null where the list or the element
+            is null.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 5, 9], [2, 2, 8], None])
+        >>> s.list.search_sorted(5, "right")
+        shape: (3,)
+        Series: 'a' [u32]
+        [
+                2
+                2
+                null
+        ]
+        """
+
+    def top_k(self, k: int | IntoExprColumn = 5) -> Series:
+        """
+        Return the `k` largest elements of every list.
+
+        The elements are returned in descending order and non-null elements are
+        preferred over null elements.
+
+        Parameters
+        ----------
+        k
+            Number of elements to return per list, either a single value or one
+            value per row.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[3, 9, 1, 7], [4], [None, 2, 5]])
+        >>> s.list.top_k(2)
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+                [9, 7]
+                [4]
+                [5, 2]
+        ]
+        """
+
     def reverse(self) -> Series:
         """
         Reverse the arrays in the list.
@@ -937,6 +1080,81 @@ class ListNameSpace:
             .to_series()
         )
 
+    def zip(self, *others: IntoExpr | Iterable[IntoExpr]) -> Series:
+        """
+        Zip the lists in this Series with the lists of other Series.
+
+        The result is a list of structs, where the struct fields are named after
+        the Series. Lists in the same row must have the same length, and a row
+        is null if any of its lists is null.
+
+        Parameters
+        ----------
+        *others
+            Series of lists to zip with.
+
+        See Also
+        --------
+        unzip
+
+        Examples
+        --------
+        >>> s = pl.Series("x", [[1, 2], [3]])
+        >>> y = pl.Series("y", [["a", "b"], ["c"]])
+        >>> s.list.zip(y)
+        shape: (2,)
+        Series: 'x' [list[struct[2]]]
+        [
+                [{1,"a"}, {2,"b"}]
+                [{3,"c"}]
+        ]
+        """
+
+    def unzip(self) -> Series:
+        """
+        Unzip a Series of lists of structs into a struct of lists.
+
+        The result has a list field for every field of the structs.
+
+        See Also
+        --------
+        zip
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "points", [[{"x": 1, "y": 2}, {"x": 3, "y": 4}], [{"x": 5, "y": 6}]]
+        ... )
+        >>> s.list.unzip().struct.unnest()
+        shape: (2, 2)
+        ┌───────────┬───────────┐
+        │ x         ┆ y         │
+        │ ---       ┆ ---       │
+        │ list[i64] ┆ list[i64] │
+        ╞═══════════╪═══════════╡
+        │ [1, 3]    ┆ [2, 4]    │
+        │ [5]       ┆ [6]       │
+        └───────────┴───────────┘
+        """
+
+    def enumerate(self) -> Series:
+        """
+        Pair every element of the lists with its index in the list.
+
+        The result is a list of structs with the fields `index` and `value`.
+
+        Examples
+        --------
+        >>> s = pl.Series("letters", [["a", "b"], ["c"]])
+        >>> s.list.enumerate()
+        shape: (2,)
+        Series: 'letters' [list[struct[2]]]
+        [
+                [{0,"a"}, {1,"b"}]
+                [{0,"c"}]
+        ]
+        """
+
     def eval(self, expr: Expr, *, parallel: bool = False) -> Series:
         """
         Run any polars expression against the lists' elements.
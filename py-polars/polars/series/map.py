@@ -0,0 +1,123 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._typing import IntoExpr
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class MapNameSpace:
+    """Series.map namespace."""
+
+    _accessor = "map"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def get(self, key: IntoExpr) -> Series:
+        """
+        Get the value of a key in every map.
+
+        Parameters
+        ----------
+        key
+            The key to look up. Strings are parsed as literals.
+
+        Returns
+        -------
+        Series
+            Series with the data type of the map values. Maps without the key are
+            null. If a key occurs more than once, the first value is returned.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [{"a": 1, "b": 2}, {"b": 3}], dtype=pl.Map(pl.String, pl.Int64)
+        ... )
+        >>> s.map.get("a")
+        shape: (2,)
+        Series: 'm' [i64]
+        [
+            1
+            null
+        ]
+        """
+
+    def contains_key(self, key: IntoExpr) -> Series:
+        """
+        Check whether every map contains a key.
+
+        Parameters
+        ----------
+        key
+            The key to look for. Strings are parsed as literals.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Boolean`.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [{"a": 1, "b": 2}, {"b": 3}], dtype=pl.Map(pl.String, pl.Int64)
+        ... )
+        >>> s.map.contains_key("a")
+        shape: (2,)
+        Series: 'm' [bool]
+        [
+            true
+            false
+        ]
+        """
+
+    def keys(self) -> Series:
+        """
+        Get the keys of every map as a list.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List` of the map key type.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [{"a": 1, "b": 2}, {"b": 3}], dtype=pl.Map(pl.String, pl.Int64)
+        ... )
+        >>> s.map.keys()
+        shape: (2,)
+        Series: 'm' [list[str]]
+        [
+            ["a", "b"]
+            ["b"]
+        ]
+        """
+
+    def values(self) -> Series:
+        """
+        Get the values of every map as a list.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`List` of the map value type.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "m", [{"a": 1, "b": 2}, {"b": 3}], dtype=pl.Map(pl.String, pl.Int64)
+        ... )
+        >>> s.map.values()
+        shape: (2,)
+        Series: 'm' [list[i64]]
+        [
+            [1, 2]
+            [3]
+        ]
+        """
@@ -104,10 +104,15 @@ from polars.series.array import ArrayNameSpace
 from polars.series.binary import BinaryNameSpace
 from polars.series.categorical import CatNameSpace
 from polars.series.datetime import DateTimeNameSpace
+from polars.series.ip import IpNameSpace
+from polars.series.json import JsonNameSpace
 from polars.series.list import ListNameSpace
+from polars.series.map import MapNameSpace
 from polars.series.plotting import SeriesPlot
 from polars.series.string import StringNameSpace
 from polars.series.struct import StructNameSpace
+from polars.series.union import UnionNameSpace
+from polars.series.url import UrlNameSpace
 from polars.series.utils import expr_dispatch, get_ffi_func
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -138,6 +143,7 @@ if TYPE_CHECKING:
         PythonLiteral,
         QuantileMethod,
         RankMethod,
+        RollingRankMethod,
         RoundMode,
         SearchSortedSide,
         SeriesBuffers,
@@ -264,6 +270,11 @@ class Series:
         "str",
         "bin",
         "struct",
+        "url",
+        "ip",
+        "map",
+        "json",
+        "union",
         "plot",
     }
 
@@ -2197,7 +2208,7 @@ class Series:
         """
         return self.to_frame().select_seq(F.col(self.name).nan_min()).item()
 
-    def std(self, ddof: int = 1) -> float | timedelta | None:
+    def std(self, ddof: int = 1) -> float | timedelta | PyDecimal | None:
         """
         Get the standard deviation of this Series.
 
@@ -2208,6 +2219,11 @@ class Series:
             where N represents the number of elements.
             By default ddof is 1.
 
+        Notes
+        -----
+        The standard deviation of a Decimal is a Decimal with at least 6 decimals,
+        rounded half away from zero.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 3])
@@ -2216,7 +2232,7 @@ class Series:
         """
         return self._s.std(ddof)
 
-    def var(self, ddof: int = 1) -> float | timedelta | None:
+    def var(self, ddof: int = 1) -> float | timedelta | PyDecimal | None:
         """
         Get variance of this Series.
 
@@ -2227,6 +2243,11 @@ class Series:
             where N represents the number of elements.
             By default ddof is 1.
 
+        Notes
+        -----
+        The variance of a Decimal is a Decimal with at least 6 decimals, rounded
+        half away from zero.
+
         Examples
         --------
         >>> s = pl.Series("a", [1, 2, 3])
@@ -4273,6 +4294,7 @@ class Series:
         *,
         strict: bool = True,
         wrap_numerical: bool = False,
+        round_mode: RoundMode | None = None,
     ) -> Self:
         r"""
         Cast between data types.
@@ -4286,6 +4308,10 @@ class Series:
         wrap_numerical
             If True numeric casts wrap overflowing values instead of
             marking the cast as invalid.
+        round_mode : {'half_to_even', 'half_away_from_zero', 'truncate'}, optional
+            How to round when casting to a Decimal with a smaller scale than the
+            input; see :meth:`round` for the modes. By default the extra digits
+            are truncated.
 
         Examples
         --------
@@ -4310,7 +4336,10 @@ class Series:
         """
         # Do not dispatch cast as it is expensive and used in other functions.
         dtype = parse_into_dtype(dtype)
-        return self._from_pyseries(self._s.cast(dtype, strict, wrap_numerical))
+        s = self
+        if round_mode is not None and isinstance(dtype, Decimal):
+            s = s.round(dtype.scale, mode=round_mode)
+        return self._from_pyseries(s._s.cast(dtype, strict, wrap_numerical))
 
     def to_physical(self) -> Series:
         """
@@ -5294,7 +5323,7 @@ class Series:
         ----------
         decimals
             Number of decimals to round by.
-        mode : {'half_to_even', 'half_away_from_zero'}
+        mode : {'half_to_even', 'half_away_from_zero', 'truncate'}
             Rounding mode.
 
         Examples
@@ -6433,6 +6462,411 @@ class Series:
         ]
         """
 
+    @unstable()
+    def rolling_n_unique(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute a rolling count of the unique values.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Null values are ignored.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.n_unique
+
+        Examples
+        --------
+        >>> pl.Series([1, 2, 2, 3, 1, 1]).rolling_n_unique(3)
+        shape: (6,)
+        Series: '' [u32]
+        [
+            null
+            null
+            2
+            2
+            3
+            2
+        ]
+        """
+
+    @unstable()
+    def rolling_mode(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute a rolling mode.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Null values are ignored. If several values are equally frequent within a
+        window, the smallest of them is returned.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.mode
+
+        Examples
+        --------
+        >>> pl.Series([1, 2, 2, 3, 1, 1]).rolling_mode(3)
+        shape: (6,)
+        Series: '' [i64]
+        [
+            null
+            null
+            2
+            2
+            1
+            1
+        ]
+        """
+
+    @unstable()
+    def rolling_rank(
+        self,
+        window_size: int,
+        *,
+        method: RollingRankMethod = "average",
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute a rolling rank of every value within its window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Every value is ranked against the values of its own window, the smallest
+        value being ranked 1. Null values are not ranked. The result is `Float64`
+        for the `'average'` method and `UInt32` otherwise.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        method : {'average', 'min', 'max', 'dense'}
+            The method used to assign ranks to tied elements.
+            The following methods are available (default is 'average'):
+
+            - 'average' : The average of the ranks that would have been assigned to
+              all the tied values is assigned to each value.
+            - 'min' : The minimum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'max' : The maximum of the ranks that would have been assigned to all
+              the tied values is assigned to each value.
+            - 'dense' : Like 'min', but the rank of the next highest element is
+              assigned the rank immediately after those assigned to the tied
+              elements.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.rank
+
+        Examples
+        --------
+        >>> pl.Series([3, 1, 3, 2, 3, 0]).rolling_rank(3, method="dense")
+        shape: (6,)
+        Series: '' [u32]
+        [
+            null
+            null
+            2
+            2
+            2
+            1
+        ]
+        """
+
+    @unstable()
+    def rolling_arg_min(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute the rolling index of the minimum value.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        The index is relative to the start of the window. Null values are ignored
+        and ties are resolved in favor of the first occurrence.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.arg_min
+
+        Examples
+        --------
+        >>> pl.Series([4, 1, 5, 2, 8, 3]).rolling_arg_min(3)
+        shape: (6,)
+        Series: '' [u32]
+        [
+            null
+            null
+            1
+            0
+            1
+            0
+        ]
+        """
+
+    @unstable()
+    def rolling_arg_max(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute the rolling index of the maximum value.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        The index is relative to the start of the window. Null values are ignored
+        and ties are resolved in favor of the first occurrence.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.arg_max
+
+        Examples
+        --------
+        >>> pl.Series([4, 1, 5, 2, 8, 3]).rolling_arg_max(3)
+        shape: (6,)
+        Series: '' [u32]
+        [
+            null
+            null
+            2
+            1
+            2
+            1
+        ]
+        """
+
+    @unstable()
+    def rolling_first(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Get the first non-null value of a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.first
+
+        Examples
+        --------
+        >>> pl.Series([1, None, 3, 4, None]).rolling_first(2, min_samples=1)
+        shape: (5,)
+        Series: '' [i64]
+        [
+            1
+            1
+            3
+            3
+            4
+        ]
+        """
+
+    @unstable()
+    def rolling_last(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Get the last non-null value of a rolling window.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.last
+
+        Examples
+        --------
+        >>> pl.Series([1, None, 3, 4, None]).rolling_last(2, min_samples=1)
+        shape: (5,)
+        Series: '' [i64]
+        [
+            1
+            1
+            3
+            4
+            4
+        ]
+        """
+
+    @unstable()
+    def rolling_product(
+        self,
+        window_size: int,
+        *,
+        min_samples: int | None = None,
+        center: bool = False,
+    ) -> Series:
+        """
+        Compute a rolling product.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The window at a given row will include the row itself, and the `window_size - 1`
+        elements before it.
+
+        Null values are ignored. Boolean and integer inputs smaller than 64 bits are
+        cast to `Int64` before the product is computed, to prevent overflow.
+
+        Parameters
+        ----------
+        window_size
+            Integer size of the rolling window.
+        min_samples
+            The number of values in the window that should be non-null before computing
+            a result. If set to `None` (default), it will be set equal to `window_size`.
+        center
+            Set the labels at the center of the window.
+
+        See Also
+        --------
+        Series.product
+
+        Examples
+        --------
+        >>> pl.Series([1, 2, 3, 4, 5]).rolling_product(3)
+        shape: (5,)
+        Series: '' [i64]
+        [
+            null
+            null
+            6
+            24
+            60
+        ]
+        """
+
     def sample(
         self,
         n: int | None = None,
@@ -7928,6 +8362,31 @@ class Series:
         """Create an object namespace of all struct related methods."""
         return StructNameSpace(self)
 
+    @property
+    def url(self) -> UrlNameSpace:
+        """Create an object namespace of all URL related methods."""
+        return UrlNameSpace(self)
+
+    @property
+    def ip(self) -> IpNameSpace:
+        """Create an object namespace of all IP address related methods."""
+        return IpNameSpace(self)
+
+    @property
+    def map(self) -> MapNameSpace:
+        """Create an object namespace of all map related methods."""
+        return MapNameSpace(self)
+
+    @property
+    def json(self) -> JsonNameSpace:
+        """Create an object namespace of all JSON related methods."""
+        return JsonNameSpace(self)
+
+    @property
+    def union(self) -> UnionNameSpace:
+        """Create an object namespace of all union related methods."""
+        return UnionNameSpace(self)
+
     @property
     @unstable()
     def plot(self) -> SeriesPlot:
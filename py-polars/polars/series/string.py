@@ -10,7 +10,7 @@ from polars.series.utils import expr_dispatch
 
 if TYPE_CHECKING:
     import sys
-    from collections.abc import Mapping
+    from collections.abc import Mapping, Sequence
 
     from polars import Expr, Series
     from polars._typing import (
@@ -2263,3 +2263,323 @@ class StringNameSpace:
                 "KADOKAWA"
         ]
         """  # noqa: RUF002
+
+    def levenshtein(
+        self, other: IntoExpr, *, max_distance: int | None = None
+    ) -> Series:
+        """
+        Compute the Levenshtein distance to another string.
+
+        The distance is the minimum number of single character insertions, deletions
+        and substitutions needed to turn one string into the other.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with.
+        max_distance
+            Stop computing a distance once it is known to exceed this value. Such
+            distances are reported as `max_distance + 1`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "martha", None])
+        >>> s.str.levenshtein(pl.Series(["sitting", "marhta", "x"]))
+        shape: (3,)
+        Series: '' [u32]
+        [
+            3
+            2
+            null
+        ]
+        """
+
+    def damerau_levenshtein(
+        self, other: IntoExpr, *, max_distance: int | None = None
+    ) -> Series:
+        """
+        Compute the Damerau-Levenshtein distance to another string.
+
+        Like :func:`levenshtein`, but swapping two characters counts as a single edit.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with.
+        max_distance
+            Stop computing a distance once it is known to exceed this value. Such
+            distances are reported as `max_distance + 1`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["martha", "kitten"])
+        >>> s.str.damerau_levenshtein(pl.Series(["marhta", "sitting"]))
+        shape: (2,)
+        Series: '' [u32]
+        [
+            1
+            3
+        ]
+        """
+
+    def hamming(self, other: IntoExpr, *, max_distance: int | None = None) -> Series:
+        """
+        Compute the Hamming distance to another string.
+
+        The distance is the number of positions at which the characters differ.
+        Strings of different lengths result in null.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with.
+        max_distance
+            Stop computing a distance once it is known to exceed this value. Such
+            distances are reported as `max_distance + 1`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["kitten", "martha", "dixon"])
+        >>> s.str.hamming(pl.lit("marhta"))
+        shape: (3,)
+        Series: '' [u32]
+        [
+            6
+            2
+            null
+        ]
+        """
+
+    def jaro_winkler(self, other: IntoExpr) -> Series:
+        """
+        Compute the Jaro-Winkler similarity to another string.
+
+        The similarity lies between 0 (nothing in common) and 1 (equal strings).
+        Strings sharing a prefix of up to 4 characters get a boost.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["martha", "dixon"])
+        >>> s.str.jaro_winkler(pl.Series(["marhta", "dicksonx"]))
+        shape: (2,)
+        Series: '' [f64]
+        [
+            0.961111
+            0.813333
+        ]
+        """
+
+    def jaccard_ngram(self, other: IntoExpr, n: int = 2) -> Series:
+        """
+        Compute the Jaccard similarity of the character n-grams to another string.
+
+        The similarity is the number of distinct n-grams both strings share, divided
+        by the number of distinct n-grams in either string. Strings shorter than `n`
+        form a single n-gram.
+
+        Parameters
+        ----------
+        other
+            The strings to compare with.
+        n
+            The number of characters per n-gram.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["martha", "kitten"])
+        >>> s.str.jaccard_ngram(pl.Series(["marhta", "sitting"]))
+        shape: (2,)
+        Series: '' [f64]
+        [
+            0.25
+            0.222222
+        ]
+        """
+
+    def split_regex(self, pattern: str | Expr) -> Series:
+        r"""
+        Split the string on every match of a regular expression.
+
+        Parameters
+        ----------
+        pattern
+            A valid regular expression pattern, compatible with the `regex crate
+            <https://docs.rs/regex/latest/regex/>`_. Accepts expression input.
+
+        Returns
+        -------
+        Series
+            Series of data type `List(String)`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["a1b22c", "x"])
+        >>> s.str.split_regex(r"\d+")
+        shape: (2,)
+        Series: '' [list[str]]
+        [
+            ["a", "b", "c"]
+            ["x"]
+        ]
+        """
+
+    def tokenize(self) -> Series:
+        """
+        Split the string into words.
+
+        Words are found at the Unicode word boundaries (UAX #29). Whitespace and
+        punctuation between the words is dropped.
+
+        Returns
+        -------
+        Series
+            Series of data type `List(String)`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["Hello, world!", "It's fine"])
+        >>> s.str.tokenize()
+        shape: (2,)
+        Series: '' [list[str]]
+        [
+            ["Hello", "world"]
+            ["It's", "fine"]
+        ]
+        """
+
+    def count_tokens(self) -> Series:
+        """
+        Count the words in the string, as found by :func:`tokenize`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["Hello, world!", "It's fine"])
+        >>> s.str.count_tokens()
+        shape: (2,)
+        Series: '' [u32]
+        [
+            2
+            2
+        ]
+        """
+
+    def char_ngrams(self, n: int) -> Series:
+        """
+        Get all overlapping substrings of `n` characters.
+
+        Strings shorter than `n` characters have no n-grams.
+
+        Parameters
+        ----------
+        n
+            The number of characters per n-gram.
+
+        Returns
+        -------
+        Series
+            Series of data type `List(String)`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["polar", "ok"])
+        >>> s.str.char_ngrams(3)
+        shape: (2,)
+        Series: '' [list[str]]
+        [
+            ["pol", "ola", "lar"]
+            []
+        ]
+        """
+
+    def word_ngrams(self, n: int) -> Series:
+        """
+        Get all overlapping sequences of `n` words, joined by a single space.
+
+        Words are found as in :func:`tokenize`. Strings with fewer than `n` words
+        have no n-grams.
+
+        Parameters
+        ----------
+        n
+            The number of words per n-gram.
+
+        Returns
+        -------
+        Series
+            Series of data type `List(String)`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["a fine day", "hi"])
+        >>> s.str.word_ngrams(2)
+        shape: (2,)
+        Series: '' [list[str]]
+        [
+            ["a fine", "fine day"]
+            []
+        ]
+        """
+
+    def remove_stop_words(self, stop_words: Sequence[str]) -> Series:
+        """
+        Split the string into words, leaving out the given stop words.
+
+        Words are found as in :func:`tokenize` and compared exactly, so lowercase the
+        strings first to ignore case.
+
+        Parameters
+        ----------
+        stop_words
+            The words to leave out.
+
+        Returns
+        -------
+        Series
+            Series of data type `List(String)`.
+
+        Examples
+        --------
+        >>> s = pl.Series(["the cat sat", "on a mat"])
+        >>> s.str.remove_stop_words(["the", "on", "a"])
+        shape: (2,)
+        Series: '' [list[str]]
+        [
+            ["cat", "sat"]
+            ["mat"]
+        ]
+        """
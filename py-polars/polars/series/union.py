@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class UnionNameSpace:
+    """Series.union namespace."""
+
+    _accessor = "union"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def type_id(self) -> Series:
+        """
+        Get the index of the active variant of every row.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Int8`. Null rows are null.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "u", [1, "x", None], dtype=pl.Union({"int": pl.Int64, "str": pl.String})
+        ... )
+        >>> s.union.type_id()
+        shape: (3,)
+        Series: 'u' [i8]
+        [
+            0
+            1
+            null
+        ]
+        """
+
+    def field(self, name: str) -> Series:
+        """
+        Get the values of a variant.
+
+        Parameters
+        ----------
+        name
+            Name of the variant.
+
+        Returns
+        -------
+        Series
+            Series with the data type of the variant. Rows in which another variant
+            is active are null.
+
+        Examples
+        --------
+        >>> s = pl.Series(
+        ...     "u", [1, "x", None], dtype=pl.Union({"int": pl.Int64, "str": pl.String})
+        ... )
+        >>> s.union.field("int")
+        shape: (3,)
+        Series: 'int' [i64]
+        [
+            1
+            null
+            null
+        ]
+        """
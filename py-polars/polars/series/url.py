@@ -0,0 +1,82 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars.series.utils import expr_dispatch
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars.polars import PySeries
+
+
+@expr_dispatch
+class UrlNameSpace:
+    """Series.url namespace."""
+
+    _accessor = "url"
+
+    def __init__(self, series: Series) -> None:
+        self._s: PySeries = series._s
+
+    def parse(self) -> Series:
+        """
+        Split URLs into their components.
+
+        Absolute and relative URLs are split as described in RFC 3986. The
+        components are not decoded or normalized; the user information is dropped
+        from the host and the brackets are removed from IPv6 hosts.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Struct` with the fields `scheme`, `host`,
+            `port` (:class:`UInt16`), `path`, `query` and `fragment`. Missing
+            components are null, except for the path, which is empty. Ports that are
+            not a valid `UInt16` are null.
+
+        Examples
+        --------
+        >>> s = pl.Series("url", ["https://user@pola.rs/docs#api", "mailto:a@b.c"])
+        >>> s.url.parse().struct.unnest()
+        shape: (2, 6)
+        ┌────────┬─────────┬──────┬───────┬───────┬──────────┐
+        │ scheme ┆ host    ┆ port ┆ path  ┆ query ┆ fragment │
+        │ ---    ┆ ---     ┆ ---  ┆ ---   ┆ ---   ┆ ---      │
+        │ str    ┆ str     ┆ u16  ┆ str   ┆ str   ┆ str      │
+        ╞════════╪═════════╪══════╪═══════╪═══════╪══════════╡
+        │ https  ┆ pola.rs ┆ null ┆ /docs ┆ null  ┆ api      │
+        │ mailto ┆ null    ┆ null ┆ a@b.c ┆ null  ┆ null     │
+        └────────┴─────────┴──────┴───────┴───────┴──────────┘
+        """
+
+    def query_param(self, name: str) -> Series:
+        """
+        Extract the value of a query parameter.
+
+        Parameters are separated by `&`. Both names and values are percent-decoded
+        and a `+` decodes to a space, as in HTML forms. If the parameter is given
+        more than once, the first value is returned.
+
+        Parameters
+        ----------
+        name
+            The name of the query parameter.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`String`. URLs without the parameter give
+            null and a parameter without a value gives an empty string.
+
+        Examples
+        --------
+        >>> s = pl.Series("url", ["/?utm_source=mail&id=1", "/?id=2", "/"])
+        >>> s.url.query_param("utm_source")
+        shape: (3,)
+        Series: 'url' [str]
+        [
+            "mail"
+            null
+            null
+        ]
+        """
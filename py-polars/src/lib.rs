@@ -15,8 +15,8 @@ use polars_python::cloud_client;
 #[cfg(feature = "polars_cloud_server")]
 use polars_python::cloud_server;
 use polars_python::dataframe::PyDataFrame;
-use polars_python::expr::datatype::PyDataTypeExpr;
 use polars_python::expr::PyExpr;
+use polars_python::expr::datatype::PyDataTypeExpr;
 use polars_python::functions::PyStringCacheHolder;
 #[cfg(not(target_arch = "wasm32"))]
 use polars_python::lazyframe::PyInProcessQuery;
@@ -150,6 +150,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - business
     m.add_wrapped(wrap_pyfunction!(functions::business_day_count))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::holiday_calendar_holidays))
+        .unwrap();
 
     // Functions - aggregation
     m.add_wrapped(wrap_pyfunction!(functions::all_horizontal))
@@ -164,6 +166,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::mean_horizontal))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::stable_hash_rows))
+        .unwrap();
 
     // Functions - lazy
     m.add_wrapped(wrap_pyfunction!(functions::arg_sort_by))
@@ -203,6 +207,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_expr))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::corr_matrix))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_lf_diagonal))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::concat_lf_horizontal))
@@ -218,7 +224,13 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::last)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::lit)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::map_mul)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::map_from_lists))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::union_from_struct))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::nth)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::kendall_tau_corr))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::pearson_corr))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::rolling_corr))
@@ -251,6 +263,9 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(feature = "parquet")]
     m.add_wrapped(wrap_pyfunction!(functions::read_parquet_metadata))
         .unwrap();
+    #[cfg(feature = "parquet")]
+    m.add_wrapped(wrap_pyfunction!(functions::clear_parquet_metadata_cache))
+        .unwrap();
     #[cfg(feature = "clipboard")]
     m.add_wrapped(wrap_pyfunction!(functions::read_clipboard_string))
         .unwrap();
@@ -304,6 +319,7 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     // Functions - random
     m.add_wrapped(wrap_pyfunction!(functions::set_random_seed))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::uuid4)).unwrap();
 
     // Functions - escape_regex
     m.add_wrapped(wrap_pyfunction!(functions::escape_regex))
@@ -12,7 +12,6 @@ from polars import StringCache
 from polars.exceptions import (
     CategoricalRemappingWarning,
     ComputeError,
-    StringCacheMismatchError,
 )
 from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import with_string_cache_if_auto_streaming
@@ -395,18 +394,15 @@ def test_compare_categorical_single_none(
     assert_series_equal(op(s, s2.cast(pl.String)), expected)
 
 
-def test_categorical_error_on_local_cmp() -> None:
+def test_categorical_local_cmp_remaps() -> None:
     df_cat = pl.DataFrame(
         [
             pl.Series("a_cat", ["c", "a", "b", "c", "b"], dtype=pl.Categorical),
-            pl.Series("b_cat", ["F", "G", "E", "G", "G"], dtype=pl.Categorical),
+            pl.Series("b_cat", ["F", "G", "E", "G", "b"], dtype=pl.Categorical),
         ]
     )
-    with pytest.raises(
-        StringCacheMismatchError,
-        match="cannot compare categoricals coming from different sources",
-    ):
-        df_cat.filter(pl.col("a_cat") == pl.col("b_cat"))
+    result = df_cat.filter(pl.col("a_cat") == pl.col("b_cat"))
+    assert result.rows() == [("b", "b")]
 
 
 @pytest.mark.usefixtures("test_global_and_local")
@@ -545,7 +541,7 @@ def test_categorical_sort_order(row_fmt_sort_enabled: bool, monkeypatch: Any) ->
     assert result["x"].to_list() == ["bar", "baz", "foo"]
 
 
-def test_err_on_categorical_asof_join_by_arg() -> None:
+def test_categorical_asof_join_by_arg_remaps() -> None:
     df1 = pl.DataFrame(
         [
             pl.Series("cat", ["a", "foo", "bar", "foo", "bar"], dtype=pl.Categorical),
@@ -563,11 +559,13 @@ def test_err_on_categorical_asof_join_by_arg() -> None:
             pl.Series("x", [1, 2, 3, 4] * 2, dtype=pl.Int32),
         ]
     )
-    with pytest.raises(
-        StringCacheMismatchError,
-        match="cannot compare categoricals coming from different sources",
+    with pytest.warns(
+        CategoricalRemappingWarning,
+        match="Local categoricals have different encodings",
     ):
-        df1.join_asof(df2, on=pl.col("time").set_sorted(), by="cat")
+        result = df1.join_asof(df2, on=pl.col("time").set_sorted(), by="cat")
+    assert result["x"].to_list() == [None, 1, 2, 3, 4]
+    assert result["cat"].to_list() == ["a", "foo", "bar", "foo", "bar"]
 
 
 @pytest.mark.usefixtures("test_global_and_local")
@@ -914,8 +912,8 @@ def test_nested_categorical_concat(
     a = pl.DataFrame({"x": [va]}, schema={"x": dt})
     b = pl.DataFrame({"x": [vb]}, schema={"x": dt})
 
-    with pytest.raises(pl.exceptions.StringCacheMismatchError):
-        pl.concat([a, b])
+    result = pl.concat([a, b])
+    assert result["x"].to_list() == [va, vb]
 
 
 @with_string_cache_if_auto_streaming
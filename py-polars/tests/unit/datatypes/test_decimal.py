@@ -7,7 +7,7 @@ from dataclasses import dataclass
 from decimal import Decimal as D
 from math import ceil, floor
 from random import choice, randrange, seed
-from typing import Any, Callable, NamedTuple
+from typing import TYPE_CHECKING, Any, Callable, NamedTuple
 
 import pyarrow as pa
 import pytest
@@ -15,6 +15,9 @@ import pytest
 import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import EngineType, RoundMode
+
 
 @pytest.fixture(scope="module")
 def permutations_int_dec_none() -> list[tuple[D | int | None, ...]]:
@@ -249,7 +252,7 @@ def test_decimal_arithmetic() -> None:
         pl.Decimal(precision=None, scale=2),
         pl.Decimal(precision=None, scale=6),
         pl.Decimal(precision=None, scale=2),
-        pl.Decimal(precision=None, scale=14),
+        pl.Decimal(precision=38, scale=18),
     ]
 
     assert out.to_dict(as_series=False) == {
@@ -257,7 +260,11 @@ def test_decimal_arithmetic() -> None:
         "out2": [D("20.20"), D("20.29"), D("139.22")],
         "out3": [D("0.004975"), D("0.991167"), D("2.550624")],
         "out4": [D("-20.00"), D("-0.09"), D("60.80")],
-        "out5": [D("0.00497512437810"), D("0.99116781157998"), D("2.55062484060188")],
+        "out5": [
+            D("0.004975124378109452"),
+            D("0.991167811579980372"),
+            D("2.550624840601887273"),
+        ],
     }
 
 
@@ -274,7 +281,7 @@ def test_decimal_series_value_arithmetic() -> None:
     assert out1.dtype == pl.Decimal(precision=None, scale=2)
     assert out2.dtype == pl.Decimal(precision=None, scale=2)
     assert out3.dtype == pl.Decimal(precision=None, scale=4)
-    assert out4.dtype == pl.Decimal(precision=None, scale=8)
+    assert out4.dtype == pl.Decimal(precision=None, scale=6)
     assert out5.dtype == pl.Decimal(precision=None, scale=6)
     assert out6.dtype == pl.Decimal(precision=None, scale=2)
 
@@ -282,9 +289,9 @@ def test_decimal_series_value_arithmetic() -> None:
     assert out2.to_list() == [D("10.1"), D("20.1"), D("110.01")]
     assert out3.to_list() == [D("10.1001"), D("20.1001"), D("110.0101")]
     assert out4.to_list() == [
-        D("0.06666666"),
-        D("6.73333333"),
-        D("66.67333333"),
+        D("0.066666"),
+        D("6.733333"),
+        D("66.673333"),
     ]  # TODO: do we want floor instead of round?
     assert out5.to_list() == [D("0.066666"), D("6.733333"), D("66.673333")]
     assert out6.to_list() == [D("-4.9"), D("5.1"), D("95.01")]
@@ -317,7 +324,9 @@ def test_decimal_aggregations() -> None:
             "sum": [D("10.20"), D("9100.13")],
             "min": [D("0.10"), D("100.01")],
             "max": [D("10.10"), D("9000.12")],
-            "mean": [5.1, 4550.065],
+            "mean": pl.Series(
+                [D("5.100000"), D("4550.065000")], dtype=pl.Decimal(38, 6)
+            ),
             "median": [5.1, 4550.065],
         }
     )
@@ -335,7 +344,7 @@ def test_decimal_aggregations() -> None:
             "sum": [D("9110.33")],
             "min": [D("0.10")],
             "max": [D("9000.12")],
-            "mean": [2277.5825],
+            "mean": pl.Series([D("2277.582500")], dtype=pl.Decimal(38, 6)),
             "median": [55.055],
         }
     )
@@ -588,24 +597,25 @@ def test_decimal_arithmetic_schema() -> None:
 
 def test_decimal_arithmetic_schema_float_20369() -> None:
     s = pl.Series("x", [1.0], dtype=pl.Decimal(15, 2))
-    assert_series_equal((s - 1.0), pl.Series("x", [0.0], dtype=pl.Decimal(None, 2)))
+    assert_series_equal((s - 1.0), pl.Series("x", [0.0], dtype=pl.Decimal(16, 2)))
     assert_series_equal(
-        (3.0 - s), pl.Series("literal", [2.0], dtype=pl.Decimal(None, 2))
+        (3.0 - s), pl.Series("literal", [2.0], dtype=pl.Decimal(16, 2))
     )
     assert_series_equal(
-        (3.0 / s), pl.Series("literal", [3.0], dtype=pl.Decimal(None, 6))
+        (3.0 / s), pl.Series("literal", [3.0], dtype=pl.Decimal(33, 18))
     )
     assert_series_equal(
-        (s / 3.0), pl.Series("x", [0.333333], dtype=pl.Decimal(None, 6))
+        (s / 3.0),
+        pl.Series("x", [D("0.333333333333333333")], dtype=pl.Decimal(33, 18)),
     )
 
-    assert_series_equal((s + 1.0), pl.Series("x", [2.0], dtype=pl.Decimal(None, 2)))
+    assert_series_equal((s + 1.0), pl.Series("x", [2.0], dtype=pl.Decimal(16, 2)))
     assert_series_equal(
-        (1.0 + s), pl.Series("literal", [2.0], dtype=pl.Decimal(None, 2))
+        (1.0 + s), pl.Series("literal", [2.0], dtype=pl.Decimal(16, 2))
     )
-    assert_series_equal((s * 1.0), pl.Series("x", [1.0], dtype=pl.Decimal(None, 4)))
+    assert_series_equal((s * 1.0), pl.Series("x", [1.0], dtype=pl.Decimal(31, 4)))
     assert_series_equal(
-        (1.0 * s), pl.Series("literal", [1.0], dtype=pl.Decimal(None, 4))
+        (1.0 * s), pl.Series("literal", [1.0], dtype=pl.Decimal(31, 4))
     )
 
 
@@ -758,3 +768,120 @@ def test_decimal32_decimal64_22946() -> None:
             ]
         ),
     )
+
+
+def test_decimal_precision_propagation() -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series([D("123.45")], dtype=pl.Decimal(5, 2)),
+            "b": pl.Series([D("1.5")], dtype=pl.Decimal(4, 1)),
+        }
+    )
+    q = df.lazy().select(
+        add=pl.col("a") + pl.col("b"),
+        mul=pl.col("a") * pl.col("b"),
+        div=pl.col("a") / pl.col("b"),
+        sum=pl.col("a").sum(),
+    )
+    # Both sides are first cast to their supertype Decimal(5, 2).
+    expected_schema = {
+        "add": pl.Decimal(6, 2),
+        "mul": pl.Decimal(11, 4),
+        "div": pl.Decimal(13, 8),
+        "sum": pl.Decimal(15, 2),
+    }
+    assert q.collect_schema() == expected_schema
+    out = q.collect()
+    assert out.schema == expected_schema
+    assert out.row(0) == (D("124.95"), D("185.1750"), D("82.30000000"), D("123.45"))
+
+
+def test_decimal_supertype_keeps_integer_digits() -> None:
+    a = pl.Series([D("1234567890")], dtype=pl.Decimal(10, 0))
+    b = pl.Series([D("0.0001")], dtype=pl.Decimal(5, 4))
+    out = a + b
+    assert out.dtype == pl.Decimal(15, 4)
+    assert out.to_list() == [D("1234567890.0001")]
+
+
+def test_decimal_overflow() -> None:
+    s = pl.Series([6 * 10**37, 1], dtype=pl.Decimal(38, 0))
+    big = pl.Series([6 * 10**37, 6 * 10**37], dtype=pl.Decimal(38, 0))
+
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        s * s
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        big.sum()
+
+    with pl.Config(decimal_overflow="null"):
+        assert (s * s).to_list() == [None, 1]
+        assert big.sum() is None
+
+
+@pytest.mark.parametrize(
+    ("mode", "expected"),
+    [
+        ("half_to_even", ["1.2", "1.4", "-1.2"]),
+        ("half_away_from_zero", ["1.3", "1.4", "-1.3"]),
+        ("truncate", ["1.2", "1.3", "-1.2"]),
+    ],
+)
+def test_decimal_round_modes(mode: RoundMode, expected: list[str]) -> None:
+    s = pl.Series([D("1.25"), D("1.35"), D("-1.25")], dtype=pl.Decimal(5, 2))
+    out = s.round(1, mode=mode).cast(pl.Decimal(5, 1))
+    assert out.to_list() == [D(v) for v in expected]
+
+    expected_s = pl.Series([D(v) for v in expected], dtype=pl.Decimal(5, 1))
+    assert_series_equal(s.cast(pl.Decimal(5, 1), round_mode=mode), expected_s)
+    out_df = s.to_frame("a").select(
+        pl.col("a").cast(pl.Decimal(5, 1), round_mode=mode)
+    )
+    assert_series_equal(out_df.to_series(), expected_s.alias("a"))
+
+
+def test_decimal_cast_round_mode_defaults_to_truncate() -> None:
+    s = pl.Series([D("1.29"), D("-1.29")], dtype=pl.Decimal(5, 2))
+    assert s.cast(pl.Decimal(5, 1)).to_list() == [D("1.2"), D("-1.2")]
+    # Upscaling is exact regardless of the rounding mode.
+    out = s.cast(pl.Decimal(6, 3), round_mode="half_away_from_zero")
+    assert out.to_list() == [D("1.290"), D("-1.290")]
+
+    with pytest.raises(TypeError, match="concrete data type"):
+        pl.col("a").cast(pl.dtype_of("a"), round_mode="half_to_even")
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_decimal_reductions_exact(engine: EngineType) -> None:
+    values = [D("0.1"), D("0.2"), D("0.3"), D("12345678901234567890.12")]
+    lf = pl.LazyFrame({"a": pl.Series(values, dtype=pl.Decimal(38, 2))})
+    out = lf.select(
+        sum=pl.col("a").sum(), mean=pl.col("a").mean(), std=pl.col("a").std()
+    ).collect(engine=engine)
+    assert out.schema == {
+        "sum": pl.Decimal(38, 2),
+        "mean": pl.Decimal(38, 6),
+        "std": pl.Decimal(38, 6),
+    }
+    assert out.row(0) == (
+        D("12345678901234567890.72"),
+        D("3086419725308641972.680000"),
+        D("6172839450617283944.960000"),
+    )
+
+    # The variance doesn't fit in a Decimal(38, 6).
+    q = lf.select(pl.col("a").var())
+    with pytest.raises(pl.exceptions.ComputeError, match="overflow"):
+        q.collect(engine=engine)
+    with pl.Config(decimal_overflow="null"):
+        assert q.collect(engine=engine).item() is None
+
+
+def test_decimal_mean_var_std_rounding() -> None:
+    s = pl.Series([D("0.10"), D("0.20"), D("0.40")], dtype=pl.Decimal(5, 2))
+    assert s.mean() == D("0.233333")
+    assert s.var() == D("0.023333")
+    assert s.std() == D("0.152753")
+
+    s = pl.Series([D("1.5"), D("2.5")], dtype=pl.Decimal(10, 8))
+    assert s.mean() == D("2.00000000")
+    assert s.to_frame().select(pl.all().var()).dtypes == [pl.Decimal(38, 8)]
@@ -0,0 +1,84 @@
+from __future__ import annotations
+
+import io
+from typing import TYPE_CHECKING
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+
+class LabelType(pa.ExtensionType):
+    def __init__(self) -> None:
+        super().__init__(pa.int64(), "polars.test.label")
+
+    def __arrow_ext_serialize__(self) -> bytes:
+        return b""
+
+    @classmethod
+    def __arrow_ext_deserialize__(
+        cls, storage_type: pa.DataType, serialized: bytes
+    ) -> LabelType:
+        return cls()
+
+
+@pytest.fixture(autouse=True)
+def _register_label_type() -> Iterator[None]:
+    pa.register_extension_type(LabelType())
+    yield
+    pa.unregister_extension_type("polars.test.label")
+
+
+@pytest.fixture
+def label_series() -> pl.Series:
+    storage = pa.array([1, None, 3], type=pa.int64())
+    return pl.Series("a", pa.ExtensionArray.from_storage(LabelType(), storage))
+
+
+def test_extension_construction(label_series: pl.Series) -> None:
+    # Python has no extension dtype, so the storage type is shown.
+    assert label_series.dtype == pl.Int64
+    assert label_series.to_list() == [1, None, 3]
+    assert label_series.to_arrow().type == LabelType()
+
+    # Operations keep the extension type.
+    assert label_series.filter(label_series.is_not_null()).to_arrow().type == (
+        LabelType()
+    )
+    assert pl.concat([label_series, label_series]).to_arrow().type == LabelType()
+
+
+def test_extension_cast(label_series: pl.Series) -> None:
+    # Casting, also to the storage type, drops the extension type.
+    out = label_series.cast(pl.Int64)
+    assert out.to_arrow().type == pa.int64()
+    assert_series_equal(out, pl.Series("a", [1, None, 3]))
+
+    out = label_series.cast(pl.Float64)
+    assert out.to_arrow().type == pa.float64()
+    assert out.to_list() == [1.0, None, 3.0]
+
+
+def test_extension_ipc_roundtrip(label_series: pl.Series) -> None:
+    df = label_series.to_frame()
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    out = pl.read_ipc(f)
+    assert out["a"].to_arrow().type == LabelType()
+    assert_frame_equal(out, df)
+
+
+def test_extension_parquet_roundtrip(label_series: pl.Series) -> None:
+    df = label_series.to_frame()
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    out = pl.read_parquet(f)
+    assert out["a"].to_arrow().type == LabelType()
+    assert_frame_equal(out, df)
@@ -0,0 +1,101 @@
+from __future__ import annotations
+
+import io
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def fsb_series() -> pl.Series:
+    return pl.Series("k", [b"ab", None, b"cd", b"ab"], dtype=pl.FixedSizeBinary(2))
+
+
+def test_fixed_size_binary_dtype() -> None:
+    assert pl.FixedSizeBinary(2) == pl.FixedSizeBinary(2)
+    assert pl.FixedSizeBinary(2) != pl.FixedSizeBinary(3)
+    assert pl.FixedSizeBinary(2) == pl.FixedSizeBinary
+    assert repr(pl.FixedSizeBinary(2)) == "FixedSizeBinary(size=2)"
+    with pytest.raises(ValueError, match="must be positive"):
+        pl.FixedSizeBinary(0)
+
+
+def test_fixed_size_binary_construction(fsb_series: pl.Series) -> None:
+    assert fsb_series.dtype == pl.FixedSizeBinary(2)
+    assert fsb_series.to_list() == [b"ab", None, b"cd", b"ab"]
+    assert fsb_series[2] == b"cd"
+
+    df = pl.DataFrame({"k": [b"xyz", None]}, schema={"k": pl.FixedSizeBinary(3)})
+    assert df.schema == pl.Schema({"k": pl.FixedSizeBinary(3)})
+
+    with pytest.raises(InvalidOperationError):
+        pl.Series([b"abc"], dtype=pl.FixedSizeBinary(2))
+
+
+def test_fixed_size_binary_cast(fsb_series: pl.Series) -> None:
+    binary = fsb_series.cast(pl.Binary)
+    assert binary.dtype == pl.Binary
+    assert binary.to_list() == [b"ab", None, b"cd", b"ab"]
+    assert_series_equal(binary.cast(pl.FixedSizeBinary(2)), fsb_series)
+
+    with pytest.raises(InvalidOperationError):
+        binary.cast(pl.FixedSizeBinary(3))
+    assert binary.cast(pl.FixedSizeBinary(3), strict=False).null_count() == 4
+
+    arr = fsb_series.cast(pl.Array(pl.UInt8, 2))
+    assert arr.to_list() == [[97, 98], None, [99, 100], [97, 98]]
+    assert_series_equal(arr.cast(pl.FixedSizeBinary(2)), fsb_series)
+
+    with pytest.raises(InvalidOperationError, match="cannot cast"):
+        fsb_series.cast(pl.String)
+
+
+def test_fixed_size_binary_operations(fsb_series: pl.Series) -> None:
+    df = fsb_series.to_frame().with_columns(v=pl.Series([1, 2, 3, 4]))
+
+    out = df.group_by("k", maintain_order=True).agg(pl.col("v").sum())
+    expected = pl.DataFrame(
+        {"k": [b"ab", None, b"cd"], "v": [5, 2, 3]},
+        schema={"k": pl.FixedSizeBinary(2), "v": pl.Int64},
+    )
+    assert_frame_equal(out, expected)
+
+    assert df.sort("k", "v", nulls_last=True)["v"].to_list() == [1, 4, 3, 2]
+    assert df.filter(pl.col("k") == b"ab")["v"].to_list() == [1, 4]
+    assert fsb_series.n_unique() == 3
+
+    joined = df.join(df, on="k").sort("v", "v_right")
+    assert joined["v"].to_list() == [1, 1, 3, 4, 4]
+    assert joined["v_right"].to_list() == [1, 4, 3, 1, 4]
+
+
+def test_fixed_size_binary_arrow_roundtrip(fsb_series: pl.Series) -> None:
+    arr = fsb_series.to_arrow()
+    assert arr.type == pa.binary(2)
+
+    s = pl.from_arrow(pa.array([b"ab", None], type=pa.binary(2)))
+    assert isinstance(s, pl.Series)
+    assert s.dtype == pl.FixedSizeBinary(2)
+    assert s.to_list() == [b"ab", None]
+
+
+def test_fixed_size_binary_ipc_roundtrip(fsb_series: pl.Series) -> None:
+    df = fsb_series.to_frame()
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
+
+
+def test_fixed_size_binary_parquet_roundtrip(fsb_series: pl.Series) -> None:
+    df = fsb_series.to_frame()
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    assert pl.read_parquet_schema(f) == {"k": pl.FixedSizeBinary(2)}
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
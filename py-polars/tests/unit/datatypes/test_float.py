@@ -1,8 +1,12 @@
+from __future__ import annotations
+
+import io
+
 import pyarrow as pa
 import pytest
 
 import polars as pl
-from polars.testing import assert_series_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_nan_in_group_by_agg() -> None:
@@ -308,4 +312,41 @@ def test_arrow_float16_read_empty_20946() -> None:
 
     df = pl.from_arrow(table)
     assert df.shape == (0, 1)
-    assert df.schema == pl.Schema([("float_column", pl.Float32)])  # type: ignore[union-attr]
+    assert df.schema == pl.Schema([("float_column", pl.Float16)])  # type: ignore[union-attr]
+
+
+def test_float16() -> None:
+    values = [1.5, None, -0.25, 65504.0]
+    s = pl.Series("a", values, dtype=pl.Float16)
+    assert s.dtype == pl.Float16
+    assert s.to_list() == values
+    assert s.max() == 65504.0
+    assert s.head(3).sum() == 1.25
+
+    df = pl.DataFrame({"a": values}, schema={"a": pl.Float16})
+    assert_series_equal(df["a"], s)
+
+
+def test_float16_cast() -> None:
+    s = pl.Series("a", [1.5, None, -0.25], dtype=pl.Float16)
+    assert_series_equal(s.cast(pl.Float64), pl.Series("a", [1.5, None, -0.25]))
+    assert_series_equal(pl.Series("a", [1.5, None, -0.25]).cast(pl.Float16), s)
+
+    # Values are rounded to the nearest float16, or infinity if out of range.
+    out = pl.Series([0.1, 1e6, 3]).cast(pl.Float16).cast(pl.Float64)
+    assert out.to_list() == [0.0999755859375, float("inf"), 3.0]
+
+
+def test_float16_arrow() -> None:
+    s = pl.Series("a", [1.5, None], dtype=pl.Float16)
+    assert s.to_arrow().type == pa.float16()
+    assert_series_equal(pl.Series(s.to_arrow()).alias("a"), s)
+
+
+@pytest.mark.parametrize("fmt", ["ipc", "parquet"])
+def test_float16_roundtrip(fmt: str) -> None:
+    df = pl.DataFrame({"a": [1.5, None, -0.25]}, schema={"a": pl.Float16})
+    f = io.BytesIO()
+    getattr(df, f"write_{fmt}")(f)
+    f.seek(0)
+    assert_frame_equal(getattr(pl, f"read_{fmt}")(f), df)
@@ -1,4 +1,12 @@
+from __future__ import annotations
+
+import io
+
+import pytest
+
 import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
 
 
 def test_integer_float_functions() -> None:
@@ -29,3 +37,41 @@ def test_compare_zero_with_uint64_16798() -> None:
     df = pl.Series("a", [(1 << 63), 0], dtype=pl.UInt64).to_frame()
     assert df.select(pl.col("a") >= 0).item(0, 0)
     assert df.select(pl.col("a") == 0).item(0, 0) is False
+
+
+def test_uint128() -> None:
+    values = [0, 2**128 - 1, None, 2**64]
+    s = pl.Series("a", values, dtype=pl.UInt128)
+    assert s.dtype == pl.UInt128
+    assert s.to_list() == values
+    assert s.max() == 2**128 - 1
+
+    df = pl.DataFrame({"a": values}, schema={"a": pl.UInt128})
+    assert_series_equal(df["a"], s)
+
+
+def test_uint128_cast() -> None:
+    s = pl.Series("a", [1, 2**128 - 1, None], dtype=pl.UInt128)
+
+    strings = s.cast(pl.String)
+    assert strings.to_list() == ["1", str(2**128 - 1), None]
+    assert_series_equal(strings.cast(pl.UInt128), s)
+
+    with pytest.raises(InvalidOperationError):
+        s.cast(pl.UInt64)
+    assert s.cast(pl.UInt64, strict=False).to_list() == [1, None, None]
+
+    ints = pl.Series("a", [1, None, 2**63], dtype=pl.UInt64)
+    assert ints.cast(pl.UInt128).to_list() == [1, None, 2**63]
+
+
+@pytest.mark.parametrize("fmt", ["ipc", "parquet"])
+def test_uint128_roundtrip(fmt: str) -> None:
+    df = pl.DataFrame(
+        {"a": [0, 2**128 - 1, None]},
+        schema={"a": pl.UInt128},
+    )
+    f = io.BytesIO()
+    getattr(df, f"write_{fmt}")(f)
+    f.seek(0)
+    assert_frame_equal(getattr(pl, f"read_{fmt}")(f), df)
@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+import io
+from datetime import timedelta
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+INTERVALS = [(1, 2, 3_000_000_000), None, (-14, 0, 0)]
+
+
+@pytest.fixture
+def interval_series() -> pl.Series:
+    return pl.Series("i", INTERVALS, dtype=pl.Interval)
+
+
+def test_interval_construction(interval_series: pl.Series) -> None:
+    assert interval_series.dtype == pl.Interval
+    assert interval_series.to_list() == INTERVALS
+    assert interval_series[0] == (1, 2, 3_000_000_000)
+
+    df = pl.DataFrame({"i": INTERVALS}, schema={"i": pl.Interval})
+    assert df.schema == pl.Schema({"i": pl.Interval})
+    assert_series_equal(df["i"], interval_series)
+
+
+def test_interval_cast(interval_series: pl.Series) -> None:
+    assert interval_series.cast(pl.String).to_list() == ["1mo 2d 3s", None, "-14mo"]
+
+    # Only intervals without months have a fixed length.
+    with pytest.raises(InvalidOperationError):
+        interval_series.cast(pl.Duration("us"))
+    assert interval_series.cast(pl.Duration("us"), strict=False).null_count() == 3
+
+    s = pl.Series("i", [(0, 1, 500_000)], dtype=pl.Interval)
+    assert s.cast(pl.Duration("us")).to_list() == [timedelta(days=1, microseconds=500)]
+
+    durations = pl.Series("d", [timedelta(seconds=90), None])
+    assert durations.cast(pl.Interval).to_list() == [(0, 0, 90_000_000_000), None]
+
+
+def test_interval_sort(interval_series: pl.Series) -> None:
+    assert interval_series.sort(nulls_last=True).to_list() == [
+        (-14, 0, 0),
+        (1, 2, 3_000_000_000),
+        None,
+    ]
+
+
+def test_interval_ipc_roundtrip(interval_series: pl.Series) -> None:
+    df = interval_series.to_frame()
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
+
+
+def test_interval_parquet_roundtrip(interval_series: pl.Series) -> None:
+    df = interval_series.to_frame()
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
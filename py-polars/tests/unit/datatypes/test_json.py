@@ -0,0 +1,112 @@
+from __future__ import annotations
+
+import io
+from typing import Any
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def json_series() -> pl.Series:
+    return pl.Series(
+        "doc",
+        ['{"a": {"b": 1}}', '{"a": {"b": 2, "c": [1, 2]}}', "{}", None],
+    ).cast(pl.Json)
+
+
+def test_json_dtype() -> None:
+    assert pl.Json == pl.Json
+    assert pl.Json != pl.String
+    assert repr(pl.Json) == "Json"
+
+
+def test_json_cast_validates_and_compacts() -> None:
+    s = pl.Series("doc", ['{"a": 1, "b": "x y"}', " [ 1, 2 ] ", None])
+    result = s.cast(pl.Json)
+    assert result.dtype == pl.Json
+    assert result.to_list() == ['{"a":1,"b":"x y"}', "[1,2]", None]
+
+    invalid = pl.Series("doc", ['{"a": 1}', '{"a": [1 2]}'])
+    with pytest.raises(InvalidOperationError, match="to `json` failed"):
+        invalid.cast(pl.Json)
+    assert invalid.cast(pl.Json, strict=False).to_list() == ['{"a":1}', None]
+
+
+def test_json_get(json_series: pl.Series) -> None:
+    result = json_series.to_frame().select(
+        b=pl.col("doc").json.get("$.a.b", pl.Int64),
+        c=pl.col("doc").json.get("$.a.c", pl.List(pl.Int64)),
+        c0=pl.col("doc").json.get("$['a'].c[0]", pl.Float64),
+        a=pl.col("doc").json.get("$.a", pl.Json),
+    )
+    expected = pl.DataFrame(
+        {
+            "b": [1, 2, None, None],
+            "c": [None, [1, 2], None, None],
+            "c0": [None, 1.0, None, None],
+            "a": ['{"b":1}', '{"b":2,"c":[1,2]}', None, None],
+        },
+        schema={
+            "b": pl.Int64,
+            "c": pl.List(pl.Int64),
+            "c0": pl.Float64,
+            "a": pl.String,
+        },
+    ).with_columns(pl.col("a").cast(pl.Json))
+    assert_frame_equal(result, expected)
+
+    assert_series_equal(
+        json_series.json.get("$.a.b", pl.Int64),
+        pl.Series("doc", [1, 2, None, None]),
+    )
+
+
+def test_json_get_jsonpath(json_series: pl.Series) -> None:
+    # several matches are combined into an array
+    assert json_series.json.get("$.a.*", pl.Json).to_list() == [
+        "1",
+        "[2,[1,2]]",
+        None,
+        None,
+    ]
+    assert json_series.json.get("$..b", pl.List(pl.Int64)).to_list() == [
+        [1],
+        [2],
+        None,
+        None,
+    ]
+
+
+def test_json_pretty_compact(json_series: pl.Series) -> None:
+    pretty = json_series.json.pretty()
+    assert pretty.dtype == pl.Json
+    assert pretty[1] == (
+        '{\n  "a": {\n    "b": 2,\n    "c": [\n      1,\n      2\n    ]\n  }\n}'
+    )
+    assert pretty[2] == "{}"
+    assert_series_equal(pretty.json.compact(), json_series)
+
+    # paths are also resolved in documents that are not compact
+    result = pretty.to_frame().select(pl.col("doc").json.get("$.a.c", pl.Json))
+    assert result.to_series().to_list() == [None, "[1,2]", None, None]
+
+
+@pytest.mark.parametrize(
+    ("write", "read"),
+    [
+        (pl.DataFrame.write_parquet, pl.read_parquet),
+        (pl.DataFrame.write_ipc, pl.read_ipc),
+    ],
+)
+def test_json_io_round_trip(json_series: pl.Series, write: Any, read: Any) -> None:
+    df = json_series.to_frame()
+    f = io.BytesIO()
+    write(df, f)
+    f.seek(0)
+    result = read(f)
+    assert result.schema == {"doc": pl.Json}
+    assert_frame_equal(result, df)
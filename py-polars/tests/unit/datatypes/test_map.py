@@ -0,0 +1,158 @@
+from __future__ import annotations
+
+import io
+from typing import Any
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def map_series() -> pl.Series:
+    return pl.Series(
+        "m",
+        [{"a": 1, "b": 2}, {}, None, {"c": None}],
+        dtype=pl.Map(pl.String, pl.Int64),
+    )
+
+
+def test_map_dtype() -> None:
+    dtype = pl.Map(pl.String, pl.Int64)
+
+    assert dtype == pl.Map
+    assert dtype == pl.Map(str, int)
+    assert dtype != pl.Map(pl.String, pl.Int32)
+    assert dtype != pl.List(pl.Struct({"key": pl.String, "value": pl.Int64}))
+    assert hash(dtype) == hash(pl.Map(pl.String, pl.Int64))
+    assert repr(dtype) == "Map(String, Int64)"
+    assert dtype.is_nested()
+    assert pl.Map(pl.String, pl.List(pl.Int8)).value == pl.List(pl.Int8)
+
+
+def test_map_construction(map_series: pl.Series) -> None:
+    assert map_series.dtype == pl.Map(pl.String, pl.Int64)
+    assert map_series.to_list() == [
+        [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        [],
+        None,
+        [{"key": "c", "value": None}],
+    ]
+
+    # maps can also be given as their list of entries
+    from_entries = pl.Series(
+        "m", map_series.to_list(), dtype=pl.Map(pl.String, pl.Int64)
+    )
+    assert_series_equal(from_entries, map_series)
+
+
+def test_map_cast_from_list_of_structs(map_series: pl.Series) -> None:
+    entries = map_series.cast(
+        pl.List(pl.Struct({"key": pl.String, "value": pl.Int64}))
+    )
+    assert_series_equal(entries.cast(pl.Map(pl.String, pl.Int64)), map_series)
+
+
+@pytest.mark.parametrize(
+    ("write", "read"),
+    [
+        (pl.DataFrame.write_parquet, pl.read_parquet),
+        (pl.DataFrame.write_ipc, pl.read_ipc),
+        (pl.DataFrame.write_ipc_stream, pl.read_ipc_stream),
+    ],
+)
+def test_map_io_round_trip(map_series: pl.Series, write: Any, read: Any) -> None:
+    df = map_series.to_frame().with_row_index()
+    f = io.BytesIO()
+    write(df, f)
+    f.seek(0)
+    assert_frame_equal(read(f), df)
+
+
+def test_map_parquet_scan_round_trip(map_series: pl.Series) -> None:
+    df = map_series.to_frame()
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    result = pl.scan_parquet(f).select(pl.col("m").map.get("a")).collect()
+    assert result.to_series().to_list() == [1, None, None, None]
+
+
+def test_map_arrow_round_trip(map_series: pl.Series) -> None:
+    arr = map_series.to_arrow()
+    assert pa.types.is_map(arr.type)
+    assert arr.to_pylist() == [[("a", 1), ("b", 2)], [], None, [("c", None)]]
+    assert_series_equal(pl.Series("m", arr), map_series)
+
+    tbl = pa.table(
+        {
+            "m": pa.array(
+                [[("x", 1.5)], None], type=pa.map_(pa.string(), pa.float64())
+            )
+        }
+    )
+    df = pl.from_arrow(tbl)
+    assert isinstance(df, pl.DataFrame)
+    assert df.schema == {"m": pl.Map(pl.String, pl.Float64)}
+    assert pa.types.is_map(df.to_arrow().schema.field("m").type)
+    assert_frame_equal(pl.from_arrow(df.to_arrow()), df)  # type: ignore[arg-type]
+
+
+def test_map_namespace(map_series: pl.Series) -> None:
+    df = map_series.to_frame()
+    result = df.select(
+        get=pl.col("m").map.get("a"),
+        contains=pl.col("m").map.contains_key("c"),
+        keys=pl.col("m").map.keys(),
+        values=pl.col("m").map.values(),
+    )
+    expected = pl.DataFrame(
+        {
+            "get": [1, None, None, None],
+            "contains": [False, False, None, True],
+            "keys": [["a", "b"], [], None, ["c"]],
+            "values": [[1, 2], [], None, [None]],
+        },
+        schema={
+            "get": pl.Int64,
+            "contains": pl.Boolean,
+            "keys": pl.List(pl.String),
+            "values": pl.List(pl.Int64),
+        },
+    )
+    assert_frame_equal(result, expected)
+
+    assert map_series.map.get("b").to_list() == [2, None, None, None]
+    assert map_series.map.contains_key("a").to_list() == [True, False, None, False]
+
+
+def test_map_get_per_row_key(map_series: pl.Series) -> None:
+    df = map_series.to_frame().with_columns(k=pl.Series(["b", "a", "a", "c"]))
+    result = df.select(pl.col("m").map.get(pl.col("k")))
+    assert result.to_series().to_list() == [2, None, None, None]
+
+
+def test_map_from_lists() -> None:
+    df = pl.DataFrame(
+        {
+            "k": [["a", "b"], ["c"], None],
+            "v": [[1, 2], [3], [4]],
+        }
+    )
+    result = df.select(pl.map_from_lists("k", "v"))
+    assert result.schema == {"k": pl.Map(pl.String, pl.Int64)}
+    assert result.to_series().to_list() == [
+        [{"key": "a", "value": 1}, {"key": "b", "value": 2}],
+        [{"key": "c", "value": 3}],
+        None,
+    ]
+    assert_series_equal(
+        result.select(pl.col("k").map.keys()).to_series(), df["k"], check_names=False
+    )
+
+    with pytest.raises(pl.exceptions.ShapeError, match="same length"):
+        pl.DataFrame({"k": [["a"]], "v": [[1, 2]]}).select(
+            pl.map_from_lists("k", "v")
+        )
@@ -0,0 +1,127 @@
+from __future__ import annotations
+
+import io
+from typing import Any
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from polars.exceptions import (
+    ComputeError,
+    InvalidOperationError,
+    StructFieldNotFoundError,
+)
+from polars.testing import assert_frame_equal, assert_series_equal
+
+UNION = pl.Union({"int": pl.Int64, "str": pl.String})
+
+
+@pytest.fixture
+def union_series() -> pl.Series:
+    return pl.Series("u", [1, "x", None, "y"], dtype=UNION)
+
+
+def test_union_dtype() -> None:
+    assert UNION == pl.Union
+    assert UNION == pl.Union([pl.Field("int", pl.Int64), pl.Field("str", pl.String)])
+    assert UNION != pl.Union({"int": pl.Int32, "str": pl.String})
+    assert UNION != pl.Struct({"int": pl.Int64, "str": pl.String})
+    assert hash(UNION) == hash(pl.Union({"int": pl.Int64, "str": pl.String}))
+    assert repr(UNION) == "Union({'int': Int64, 'str': String})"
+    assert UNION.is_nested()
+    assert list(UNION) == [("int", pl.Int64), ("str", pl.String)]
+
+
+def test_union_construction(union_series: pl.Series) -> None:
+    assert union_series.dtype == UNION
+    assert union_series.to_list() == [1, "x", None, "y"]
+    assert union_series[1] == "x"
+    assert union_series.null_count() == 1
+
+    empty = pl.Series("u", [], dtype=UNION)
+    assert empty.dtype == UNION
+    assert empty.len() == 0
+
+
+def test_union_construction_strict() -> None:
+    dtype = pl.Union({"a": pl.Int32, "b": pl.String})
+    with pytest.raises(TypeError, match="does not match any variant"):
+        pl.Series("u", [1, "z"], dtype=dtype)
+
+    # without `strict`, values are cast to the first variant that fits
+    s = pl.Series("u", [1, "z"], dtype=dtype, strict=False)
+    assert s.to_list() == [1, "z"]
+    assert s.union.field("a").dtype == pl.Int32
+
+
+def test_union_namespace(union_series: pl.Series) -> None:
+    result = union_series.to_frame().select(
+        pl.col("u").union.type_id(),
+        pl.col("u").union.field("int"),
+        pl.col("u").union.field("str"),
+    )
+    expected = pl.DataFrame(
+        {
+            "u": [0, 1, None, 1],
+            "int": [1, None, None, None],
+            "str": [None, "x", None, "y"],
+        },
+        schema={"u": pl.Int8, "int": pl.Int64, "str": pl.String},
+    )
+    assert_frame_equal(result, expected)
+
+    assert_series_equal(
+        union_series.union.type_id(), pl.Series("u", [0, 1, None, 1], dtype=pl.Int8)
+    )
+    with pytest.raises(StructFieldNotFoundError, match="union has no variant 'c'"):
+        union_series.union.field("c")
+
+
+def test_union_from_struct(union_series: pl.Series) -> None:
+    df = pl.DataFrame({"int": [1, None, None, None], "str": [None, "x", None, "y"]})
+    result = df.select(u=pl.union_from_struct(pl.struct("int", "str")))
+    assert_series_equal(result.to_series(), union_series)
+
+    overlapping = pl.DataFrame({"a": [1], "b": ["x"]})
+    with pytest.raises(InvalidOperationError, match="more than one non-null field"):
+        overlapping.select(pl.union_from_struct(pl.struct("a", "b")))
+
+
+def test_union_cast_struct(union_series: pl.Series) -> None:
+    struct_dtype = pl.Struct({"int": pl.Int64, "str": pl.String})
+    as_struct = union_series.cast(struct_dtype)
+    assert as_struct.to_list() == [
+        {"int": 1, "str": None},
+        {"int": None, "str": "x"},
+        None,
+        {"int": None, "str": "y"},
+    ]
+    assert_series_equal(as_struct.cast(UNION), union_series)
+
+
+@pytest.mark.parametrize(
+    ("write", "read"),
+    [
+        (pl.DataFrame.write_ipc, pl.read_ipc),
+        (pl.DataFrame.write_ipc_stream, pl.read_ipc_stream),
+    ],
+)
+def test_union_ipc_round_trip(union_series: pl.Series, write: Any, read: Any) -> None:
+    df = union_series.to_frame().with_row_index()
+    f = io.BytesIO()
+    write(df, f)
+    f.seek(0)
+    assert_frame_equal(read(f), df)
+
+
+def test_union_parquet_unsupported(union_series: pl.Series) -> None:
+    with pytest.raises(ComputeError, match="not yet implemented"):
+        union_series.to_frame().write_parquet(io.BytesIO())
+
+
+def test_union_arrow_round_trip(union_series: pl.Series) -> None:
+    arr = union_series.to_arrow()
+    assert pa.types.is_union(arr.type)
+    assert arr.type.mode == "sparse"
+    assert_series_equal(pl.Series("u", arr), union_series)
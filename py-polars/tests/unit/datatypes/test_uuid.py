@@ -0,0 +1,88 @@
+from __future__ import annotations
+
+import io
+from uuid import UUID
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+UUIDS = [
+    UUID("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+    None,
+    UUID("00000000-0000-0000-0000-000000000001"),
+]
+
+
+@pytest.fixture
+def uuid_series() -> pl.Series:
+    return pl.Series("u", UUIDS)
+
+
+def test_uuid_construction(uuid_series: pl.Series) -> None:
+    assert uuid_series.dtype == pl.Uuid
+    assert uuid_series.to_list() == UUIDS
+    assert uuid_series[0] == UUIDS[0]
+
+    s = pl.Series("u", [str(u) if u else None for u in UUIDS], dtype=pl.Uuid)
+    assert_series_equal(s, uuid_series)
+
+
+def test_uuid_cast(uuid_series: pl.Series) -> None:
+    strings = uuid_series.cast(pl.String)
+    assert strings.to_list() == [
+        "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        None,
+        "00000000-0000-0000-0000-000000000001",
+    ]
+    assert_series_equal(strings.cast(pl.Uuid), uuid_series)
+
+    fsb = uuid_series.cast(pl.FixedSizeBinary(16))
+    assert fsb.to_list() == [u.bytes if u else None for u in UUIDS]
+    assert_series_equal(fsb.cast(pl.Uuid), uuid_series)
+
+    ints = uuid_series.cast(pl.UInt128)
+    assert ints.to_list() == [u.int if u else None for u in UUIDS]
+    assert_series_equal(ints.cast(pl.Uuid), uuid_series)
+
+    invalid = pl.Series("u", ["67e55044-10b1-426f-9247-bb680e5fe0c8", "xyz"])
+    with pytest.raises(InvalidOperationError, match="to `uuid` failed"):
+        invalid.cast(pl.Uuid)
+    assert invalid.cast(pl.Uuid, strict=False).to_list() == [UUIDS[0], None]
+
+
+def test_uuid_sort(uuid_series: pl.Series) -> None:
+    assert uuid_series.sort(nulls_last=True).to_list() == [UUIDS[2], UUIDS[0], None]
+
+
+def test_uuid_ipc_roundtrip(uuid_series: pl.Series) -> None:
+    df = uuid_series.to_frame()
+    f = io.BytesIO()
+    df.write_ipc(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_ipc(f), df)
+
+
+def test_uuid_parquet_roundtrip(uuid_series: pl.Series) -> None:
+    df = uuid_series.to_frame()
+    f = io.BytesIO()
+    df.write_parquet(f)
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+
+def test_uuid4() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    out = df.select(pl.uuid4(seed=42))
+    assert out.schema == pl.Schema({"uuid": pl.Uuid})
+    assert out.height == 3
+
+    uuids = out["uuid"].to_list()
+    assert len(set(uuids)) == 3
+    assert all(u.version == 4 for u in uuids)
+    assert str(uuids[0]).startswith("42e56270-889a-474d-ba7c-849b22")
+
+    assert_frame_equal(df.select(pl.uuid4(seed=42)), out)
+    assert df.select(pl.uuid4()).schema == pl.Schema({"uuid": pl.Uuid})
@@ -30,6 +30,25 @@ def test_date_range_invalid_time_unit() -> None:
         )
 
 
+
+def test_date_range_business_days() -> None:
+    # 2024-01-04 is a Thursday
+    result = pl.date_range(date(2024, 1, 4), date(2024, 1, 12), "1bd", eager=True)
+    expected = pl.Series(
+        "literal", [date(2024, 1, d) for d in (4, 5, 8, 9, 10, 11, 12)]
+    )
+    assert_series_equal(result, expected)
+
+    result = pl.date_range(date(2024, 1, 4), date(2024, 1, 12), "2bd", eager=True)
+    assert result.to_list() == [date(2024, 1, d) for d in (4, 8, 10, 12)]
+
+    # a weekend start is kept, after which the range moves to business days
+    result = pl.date_range(date(2024, 1, 6), date(2024, 1, 9), "1bd", eager=True)
+    assert result.to_list() == [date(2024, 1, d) for d in (6, 8, 9)]
+
+    with pytest.raises(InvalidOperationError, match="cannot be combined"):
+        pl.date_range(date(2024, 1, 4), date(2024, 1, 12), "1bd1d", eager=True)
+
 def test_date_range_lazy_with_literals() -> None:
     df = pl.DataFrame({"misc": ["x"]}).with_columns(
         pl.date_ranges(
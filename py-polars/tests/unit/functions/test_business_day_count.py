@@ -130,6 +130,37 @@ def test_business_day_count_w_holidays() -> None:
     assert_series_equal(result, expected)
 
 
+
+def test_business_day_count_w_calendar() -> None:
+    # Christmas Day 2022 and New Year's Day 2023 fall on a Sunday
+    calendar = pl.HolidayCalendar(
+        "example",
+        [
+            pl.Holiday("New Year's Day", month=1, day=1, observance="nearest_weekday"),
+            pl.Holiday("Christmas Day", month=12, day=25, observance="nearest_weekday"),
+        ],
+    )
+    df = pl.DataFrame(
+        {
+            "start": [date(2022, 12, 23), date(2022, 1, 1)],
+            "end": [date(2023, 1, 3), date(2022, 1, 5)],
+        }
+    )
+    result = df.select(
+        business_day_count=pl.business_day_count("start", "end", calendar=calendar),
+    )["business_day_count"]
+    expected = pl.Series("business_day_count", [5, 2], pl.Int32)
+    assert_series_equal(result, expected)
+
+    # the calendar is combined with `holidays`
+    result = df.select(
+        business_day_count=pl.business_day_count(
+            "start", "end", holidays=[date(2022, 12, 23)], calendar=calendar
+        ),
+    )["business_day_count"]
+    expected = pl.Series("business_day_count", [4, 2], pl.Int32)
+    assert_series_equal(result, expected)
+
 @given(
     start=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
     end=st.dates(min_value=dt.date(1969, 1, 1), max_value=dt.date(1970, 12, 31)),
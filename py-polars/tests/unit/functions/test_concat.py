@@ -94,3 +94,43 @@ def test_concat_zip_series_21980() -> None:
     df = pl.DataFrame({"x": 1, "y": 2})
     out = df.select(pl.concat([pl.col.x, pl.col.y]), pl.Series([3, 4]))
     assert_frame_equal(out, pl.DataFrame({"x": [1, 2], "": [3, 4]}))
+
+
+@pytest.mark.parametrize("lazy", [False, True])
+def test_concat_reordered_struct_fields(lazy: bool) -> None:
+    a = pl.DataFrame({"s": [{"a": 1, "b": "x"}]})
+    b = pl.DataFrame({"s": [{"b": "y", "a": 2}]})
+
+    if lazy:
+        out = pl.concat([a.lazy(), b.lazy()]).collect()
+    else:
+        out = pl.concat([a, b])
+    expected = pl.DataFrame({"s": [{"a": 1, "b": "x"}, {"a": 2, "b": "y"}]})
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("how", ["vertical_relaxed", "diagonal_relaxed"])
+def test_concat_relaxed_nested_struct_fields(how: str) -> None:
+    a = pl.DataFrame(
+        {"l": [[{"a": 1, "b": "x"}]]},
+        schema={"l": pl.List(pl.Struct({"a": pl.Int32, "b": pl.String}))},
+    )
+    # Reordered, widened and with an added field.
+    b = pl.DataFrame(
+        {"l": [[{"c": 1.5, "b": "y", "a": 2}]]},
+        schema={
+            "l": pl.List(pl.Struct({"c": pl.Float64, "b": pl.String, "a": pl.Int64}))
+        },
+    )
+
+    with pytest.raises(pl.exceptions.PolarsError):
+        pl.concat([a, b], how="vertical")
+
+    out = pl.concat([a, b], how=how)  # type: ignore[arg-type]
+    expected = pl.DataFrame(
+        {"l": [[{"a": 1, "b": "x", "c": None}], [{"a": 2, "b": "y", "c": 1.5}]]},
+        schema={
+            "l": pl.List(pl.Struct({"a": pl.Int64, "b": pl.String, "c": pl.Float64}))
+        },
+    )
+    assert_frame_equal(out, expected)
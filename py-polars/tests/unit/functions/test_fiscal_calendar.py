@@ -0,0 +1,37 @@
+from __future__ import annotations
+
+from datetime import date
+
+import pytest
+
+import polars as pl
+
+
+def test_fiscal_calendar_repr() -> None:
+    assert repr(pl.FiscalCalendar()) == "FiscalCalendar(start_month=1)"
+    assert repr(pl.FiscalCalendar(start_month=10, label="start")) == (
+        "FiscalCalendar(start_month=10, label='start')"
+    )
+    retail = pl.FiscalCalendar(start_month=2, week_pattern="454", end_weekday=6)
+    assert repr(retail) == (
+        "FiscalCalendar(start_month=2, week_pattern='454', end_weekday=6, "
+        "year_end='nearest')"
+    )
+
+
+def test_fiscal_calendar_invalid() -> None:
+    with pytest.raises(ValueError, match="`start_month` must be between 1 and 12"):
+        pl.FiscalCalendar(start_month=13)
+    with pytest.raises(ValueError, match="`end_weekday` must be between 1 and 7"):
+        pl.FiscalCalendar(week_pattern="445", end_weekday=0)
+
+    s = pl.Series([date(2024, 1, 1)])
+    with pytest.raises(ValueError, match="`week_pattern` must be one of"):
+        pattern = "444"
+        s.dt.fiscal_year(pl.FiscalCalendar(week_pattern=pattern))  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="`year_end` must be one of"):
+        year_end = "first"
+        s.dt.fiscal_year(pl.FiscalCalendar(year_end=year_end))  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="`label` must be one of"):
+        label = "middle"
+        s.dt.fiscal_year(pl.FiscalCalendar(label=label))  # type: ignore[arg-type]
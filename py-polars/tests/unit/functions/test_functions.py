@@ -263,6 +263,59 @@ def test_corr() -> None:
         assert_series_equal(r1, r2)
 
 
+def test_corr_kendall() -> None:
+    s1 = pl.Series("a", [10, 37, -40])
+    s2 = pl.Series("b", [70, -10, 35])
+
+    result = pl.DataFrame([s1, s2]).select(pl.corr("a", "b", method="kendall")).item()
+    assert result == pytest.approx(-1 / 3)
+    assert pl.corr(s1, s2, method="kendall", eager=True).item() == result
+
+    # tau-b corrects for the tie in both columns
+    df = pl.DataFrame({"a": [1, 2, 2, 3], "b": [1, 3, 2, 3]})
+    assert df.select(pl.corr("a", "b", method="kendall")).item() == pytest.approx(0.8)
+
+
+@pytest.mark.parametrize("values", [[], [1], [1, None], [None, None]])
+def test_corr_kendall_too_few_rows(values: list[int | None]) -> None:
+    df = pl.DataFrame(
+        {"a": values, "b": list(range(len(values)))},
+        schema={"a": pl.Int64, "b": pl.Int64},
+    )
+    assert df.select(pl.corr("a", "b", method="kendall")).item() is None
+
+
+def test_corr_invalid_method() -> None:
+    with pytest.raises(ValueError, match="method must be one of"):
+        pl.corr("a", "b", method="foo")  # type: ignore[arg-type]
+
+
+def test_corr_matrix_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "b"],
+            "x": [1, 2, 3, 4, 5, 6],
+            "y": [3, 1, 2, 5, 4, 6],
+            "z": [6, 5, 4, 3, 2, 1],
+        }
+    )
+    result = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.corr_matrix("x", ["y", "z"], method="kendall"))
+        .explode("corr_matrix")
+        .unnest("corr_matrix")
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["a", "a", "a", "b", "b", "b"],
+            "column_1": ["x", "x", "y", "x", "x", "y"],
+            "column_2": ["y", "z", "z", "y", "z", "z"],
+            "correlation": [-1 / 3, -1.0, 1 / 3, 1 / 3, -1.0, -1 / 3],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
 def test_extend_ints() -> None:
     a = pl.DataFrame({"a": [1 for _ in range(1)]}, schema={"a": pl.Int64})
     with pytest.raises(pl.exceptions.SchemaError):
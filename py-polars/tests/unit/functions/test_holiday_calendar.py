@@ -0,0 +1,124 @@
+from __future__ import annotations
+
+from datetime import date
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+
+if TYPE_CHECKING:
+    from polars._typing import HolidayObservance
+
+
+def test_holiday_repr() -> None:
+    christmas = pl.Holiday(
+        "Christmas Day", month=12, day=25, observance="nearest_weekday"
+    )
+    assert repr(christmas) == (
+        "Holiday('Christmas Day', month=12, day=25, observance='nearest_weekday')"
+    )
+    assert repr(pl.Holiday("Thanksgiving", month=11, weekday=4, n=4)) == (
+        "Holiday('Thanksgiving', month=11, weekday=4, n=4)"
+    )
+    assert repr(pl.Holiday("Juneteenth", month=6, day=19, start_year=2021)) == (
+        "Holiday('Juneteenth', month=6, day=19, start_year=2021)"
+    )
+    good_friday = pl.Holiday("Good Friday", easter_offset=-2)
+    calendar = pl.HolidayCalendar("easter", [good_friday])
+    assert repr(calendar) == (
+        "HolidayCalendar('easter', [Holiday('Good Friday', easter_offset=-2)])"
+    )
+
+
+def test_holidays_between() -> None:
+    calendar = pl.HolidayCalendar(
+        "example",
+        [
+            pl.Holiday("New Year's Day", month=1, day=1, observance="nearest_weekday"),
+            pl.Holiday("Good Friday", easter_offset=-2),
+            pl.Holiday("Memorial Day", month=5, weekday=1, n=-1),
+            pl.Holiday("Independence Day", month=7, day=4),
+            pl.Holiday("Thanksgiving", month=11, weekday=4, n=4),
+            pl.Holiday("Christmas Day", month=12, day=25, observance="nearest_weekday"),
+        ],
+    )
+    # New Year's Day 2022 falls on a Saturday and is observed in 2021
+    assert calendar.holidays_between(date(2021, 12, 1), date(2022, 12, 31)) == [
+        date(2021, 12, 24),
+        date(2021, 12, 31),
+        date(2022, 4, 15),
+        date(2022, 5, 30),
+        date(2022, 7, 4),
+        date(2022, 11, 24),
+        date(2022, 12, 26),
+    ]
+    assert calendar.holidays_between(date(2022, 7, 4), date(2022, 7, 4)) == [
+        date(2022, 7, 4)
+    ]
+    assert calendar.holidays_between(date(2022, 2, 1), date(2022, 1, 1)) == []
+
+
+@pytest.mark.parametrize(
+    ("observance", "expected"),
+    [
+        ("none", [date(2022, 1, 1), date(2023, 1, 1)]),
+        ("nearest_weekday", [date(2021, 12, 31), date(2023, 1, 2)]),
+        ("next_monday", [date(2022, 1, 3), date(2023, 1, 2)]),
+        ("sunday_to_monday", [date(2022, 1, 1), date(2023, 1, 2)]),
+    ],
+)
+def test_holiday_observance(
+    observance: HolidayObservance, expected: list[date]
+) -> None:
+    # 2022-01-01 is a Saturday and 2023-01-01 a Sunday
+    calendar = pl.HolidayCalendar(
+        "new year",
+        [pl.Holiday("New Year's Day", month=1, day=1, observance=observance)],
+    )
+    result = calendar.holidays_between(date(2021, 12, 1), date(2023, 1, 31))
+    assert result == expected
+
+
+def test_holiday_years() -> None:
+    calendar = pl.HolidayCalendar(
+        "juneteenth",
+        [
+            pl.Holiday(
+                "Juneteenth",
+                month=6,
+                day=19,
+                observance="nearest_weekday",
+                start_year=2021,
+                end_year=2022,
+            )
+        ],
+    )
+    assert calendar.holidays_between(date(2020, 1, 1), date(2023, 12, 31)) == [
+        date(2021, 6, 18),
+        date(2022, 6, 20),
+    ]
+
+
+def test_holiday_invalid() -> None:
+    with pytest.raises(ValueError, match="must specify either"):
+        pl.Holiday("Invalid", month=1)
+    with pytest.raises(ValueError, match="must specify either"):
+        pl.Holiday("Invalid", month=1, day=1, weekday=1)
+    with pytest.raises(ValueError, match="must specify either"):
+        pl.Holiday("Invalid", month=4, easter_offset=1)
+
+    def holidays(holiday: pl.Holiday) -> list[date]:
+        calendar = pl.HolidayCalendar("invalid", [holiday])
+        return calendar.holidays_between(date(2024, 1, 1), date(2024, 12, 31))
+
+    with pytest.raises(InvalidOperationError, match="invalid date for holiday"):
+        holidays(pl.Holiday("Invalid", month=2, day=30))
+    with pytest.raises(InvalidOperationError, match="invalid rule for holiday"):
+        holidays(pl.Holiday("Invalid", month=1, weekday=1, n=6))
+    with pytest.raises(ValueError, match="must specify either"):
+        holidays(pl.Holiday("Invalid", month=1, weekday=8))
+    with pytest.raises(ValueError, match="`observance` must be one of"):
+        observance = "weekly"
+        holidays(pl.Holiday("Invalid", month=1, day=1, observance=observance))  # type: ignore[arg-type]
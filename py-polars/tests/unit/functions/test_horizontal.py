@@ -19,3 +19,37 @@ def test_shape_mismatch_19336(f: str) -> None:
 
     with pytest.raises(pl.exceptions.ShapeError):
         pl.select((fn)(a, b))
+
+
+def test_stable_hash_rows() -> None:
+    df = pl.DataFrame({"a": [1, 2, None], "b": ["x", "y", "z"]})
+    result = df.select(pl.stable_hash_rows("a", "b"))
+    assert result.schema == {"a": pl.UInt64}
+    assert result["a"].to_list() == [
+        9522937652473371038,
+        3285309881530051729,
+        12119009896031128474,
+    ]
+
+    # the seed and the column order matter
+    assert df.select(pl.stable_hash_rows("a", "b", seed=1))["a"][0] != result["a"][0]
+    assert df.select(pl.stable_hash_rows("b", "a"))["b"][0] != result["a"][0]
+
+    # categoricals hash like their string values
+    as_cat = df.select(pl.stable_hash_rows("a", pl.col("b").cast(pl.Categorical)))
+    assert as_cat["a"].to_list() == result["a"].to_list()
+
+
+def test_stable_hash_rows_canonical_floats() -> None:
+    s = pl.Series("f", [0.0, -0.0, float("nan"), -float("nan")])
+    hashes = pl.select(pl.stable_hash_rows(s)).to_series()
+    assert hashes[0] == hashes[1]
+    assert hashes[2] == hashes[3]
+
+
+def test_stable_hash_rows_nested_unsupported() -> None:
+    df = pl.DataFrame({"a": [[1], [2]]})
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="cannot compute a stable hash"
+    ):
+        df.select(pl.stable_hash_rows("a"))
@@ -0,0 +1,68 @@
+from __future__ import annotations
+
+from typing import Any
+
+import pytest
+
+import polars as pl
+from polars.io.partition import PartitionByKey
+from polars.testing import assert_frame_equal
+
+
+@pytest.mark.parametrize(
+    ("scan", "sink"),
+    [
+        (pl.scan_parquet, pl.LazyFrame.sink_parquet),
+        (pl.scan_ipc, pl.LazyFrame.sink_ipc),
+        (pl.scan_csv, pl.LazyFrame.sink_csv),
+        (pl.scan_ndjson, pl.LazyFrame.sink_ndjson),
+    ],
+)
+@pytest.mark.parametrize("storage_options", [None, {"latency_ms": "1"}])
+def test_memory_scan_sink_round_trip(
+    scan: Any, sink: Any, storage_options: dict[str, str] | None
+) -> None:
+    bucket = f"memory://round-trip-{scan.__name__}"
+    df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+
+    for i in range(2):
+        sink(df.lazy(), f"{bucket}/{i}.data", storage_options=storage_options)
+
+    assert_frame_equal(
+        scan(f"{bucket}/*.data", storage_options=storage_options).collect(),
+        pl.concat([df, df]),
+    )
+    assert_frame_equal(
+        scan(f"{bucket}/1.data", storage_options=storage_options)
+        .filter(pl.col("a") > 1)
+        .collect(),
+        df.slice(1),
+    )
+
+
+def test_memory_partitioned_round_trip() -> None:
+    base = "memory://partitioned"
+    df = pl.DataFrame({"a": [1, 1, 2], "b": [1, 2, 3]})
+
+    df.lazy().sink_parquet(PartitionByKey(base, by="a"))
+    pl.DataFrame({"a": [1, 3], "b": [4, 5]}).lazy().sink_parquet(
+        PartitionByKey(base, by="a", overwrite_mode="dynamic")
+    )
+
+    assert_frame_equal(
+        pl.scan_parquet(f"{base}/**/*.parquet", hive_partitioning=False)
+        .collect()
+        .sort("a", "b"),
+        pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 5]}),
+        check_column_order=False,
+    )
+
+
+def test_memory_injected_failures() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    df.lazy().sink_parquet("memory://failures/0.parquet")
+
+    with pytest.raises(OSError, match="injected transient error"):
+        pl.scan_parquet(
+            "memory://failures/0.parquet", storage_options={"fail_every_n": "1"}
+        ).collect()
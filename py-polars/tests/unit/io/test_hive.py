@@ -1046,3 +1046,63 @@ print("OK", end="")
     )
 
     assert out == b"OK"
+
+
+@pytest.mark.write_disk
+def test_hive_listing_pruned_by_filter(
+    tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    for x in range(5):
+        (tmp_path / f"x={x}").mkdir()
+        pl.DataFrame({"v": [10 + x]}).write_parquet(tmp_path / f"x={x}/0.parquet")
+    capfd.readouterr()
+
+    lf = pl.scan_parquet(tmp_path, hive_partitioning=True)
+
+    out = lf.filter(pl.col("x") >= 3).collect()
+    assert "listed 2 paths after hive partition pruning" in capfd.readouterr().err
+    assert_frame_equal(out.sort("x"), pl.DataFrame({"v": [13, 14], "x": [3, 4]}))
+
+    # Nothing matches: the schema is still resolved, but no data is listed.
+    out = lf.filter(pl.col("x") == 10).collect()
+    assert "listed 0 paths after hive partition pruning" in capfd.readouterr().err
+    assert out.is_empty()
+    assert out.schema == lf.collect_schema()
+
+
+@pytest.mark.write_disk
+def test_hive_listing_pruned_inferred_types(
+    tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    for i, d, b, s in [
+        (1, "2024-01-01", "true", "a%20b%2Fc"),
+        (2, "2024-01-02", "false", "d"),
+    ]:
+        path = tmp_path / f"i={i}/d={d}/b={b}/s={s}"
+        path.mkdir(parents=True)
+        pl.DataFrame({"v": [i]}).write_parquet(path / "0.parquet")
+    capfd.readouterr()
+
+    lf = pl.scan_parquet(tmp_path, hive_partitioning=True)
+    assert lf.collect_schema() == pl.Schema(
+        {
+            "v": pl.Int64,
+            "i": pl.Int64,
+            "d": pl.Date,
+            "b": pl.Boolean,
+            "s": pl.String,
+        }
+    )
+
+    for predicate, expect in [
+        (pl.col("i") == 2, 2),
+        (pl.col("i").is_in([1]), 1),
+        (pl.col("d") > date(2024, 1, 1), 2),
+        (pl.col("b").eq(True), 1),
+        (pl.col("s") == "a b/c", 1),
+    ]:
+        out = lf.filter(predicate).collect()
+        assert "listed 1 paths after hive partition pruning" in capfd.readouterr().err
+        assert out.get_column("v").to_list() == [expect]
@@ -3395,3 +3395,19 @@ def test_read_parquet_duplicate_range_start_fetch_23139(tmp_path: Path) -> None:
     df.write_parquet(path, use_pyarrow=True)
 
     assert_frame_equal(pl.read_parquet(path), df)
+
+
+@pytest.mark.write_disk
+def test_clear_parquet_metadata_cache(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    df.write_parquet(path)
+    assert_frame_equal(pl.scan_parquet(path).collect(), df)
+
+    pl.io.parquet.clear_parquet_metadata_cache(path.as_uri())
+    pl.io.parquet.clear_parquet_metadata_cache()
+
+    # The file is read from scratch after clearing the cache.
+    df = pl.DataFrame({"a": [4, 5]})
+    df.write_parquet(path)
+    assert_frame_equal(pl.scan_parquet(path).collect(), df)
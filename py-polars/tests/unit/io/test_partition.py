@@ -494,3 +494,122 @@ def test_partition_to_memory_finish_callback(io_type: IOType) -> None:
             ),
         )
     assert num_calls == 1  # Should not get called here
+
+
+@pytest.mark.parametrize("io_type", io_types)
+@pytest.mark.write_disk
+def test_partition_by_key_path_template(tmp_path: Path, io_type: IOType) -> None:
+    ext = io_type["ext"]
+    df = pl.DataFrame(
+        {
+            "year": [2024, 2024, 2025],
+            "month": [3, 3, 11],
+            "city": ["a/b=c", "a/b=c", None],
+            "x": [1, 2, 3],
+        }
+    )
+
+    io_type["sink"](
+        df.lazy(),
+        PartitionByKey(
+            tmp_path,
+            by=["year", "month", "city"],
+            include_key=False,
+            path_template="y={year}/m={month:02}/c={city}/{{{in_part_idx}}}.{ext}",
+        ),
+        engine="streaming",
+        sync_on_close="data",
+    )
+
+    files = [p for p in tmp_path.rglob("*") if p.is_file()]
+    assert sorted(p.relative_to(tmp_path).as_posix() for p in files) == [
+        f"y=2024/m=03/c=a%2Fb%3Dc/{{0}}.{ext}",
+        f"y=2025/m=11/c=__HIVE_DEFAULT_PARTITION__/{{0}}.{ext}",
+    ]
+    assert_series_equal(
+        io_type["scan"](tmp_path / f"y=2024/m=03/c=a%2Fb%3Dc/{{0}}.{ext}")
+        .collect()
+        .to_series(),
+        pl.Series("x", [1, 2]),
+    )
+
+
+@pytest.mark.write_disk
+def test_partition_by_key_path_template_uuid(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 1, 2], "b": [1, 2, 3]})
+
+    df.lazy().sink_parquet(
+        PartitionByKey(tmp_path, by="a", path_template="{a}/part-{uuid}.{ext}"),
+        engine="streaming",
+    )
+
+    files = sorted(tmp_path.rglob("*.parquet"))
+    assert [f.parent.name for f in files] == ["1", "2"]
+    for f in files:
+        assert f.name.startswith("part-")
+        assert len(f.name) == len("part-.parquet") + 36
+
+    assert_frame_equal(
+        pl.read_parquet(files), df, check_row_order=False, check_column_order=False
+    )
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [
+        {"path_template": "{a}-{uuid}.{ext}", "overwrite_mode": "dynamic"},
+        {"path_template": "{file_idx}/{a}.{ext}", "overwrite_mode": "dynamic"},
+        {"path_template": "{missing}.{ext}"},
+        {"path_template": "{a"},
+    ],
+)
+@pytest.mark.write_disk
+def test_partition_by_key_path_template_invalid(
+    tmp_path: Path, kwargs: dict[str, Any]
+) -> None:
+    lf = pl.LazyFrame({"a": [1], "b": [2]})
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        lf.sink_parquet(PartitionByKey(tmp_path, by="a", **kwargs), engine="streaming")
+
+
+@pytest.mark.parametrize("io_type", io_types)
+@pytest.mark.parametrize("path_template", [None, "a={a}/{file_idx}.{ext}"])
+@pytest.mark.write_disk
+def test_partition_by_key_dynamic_overwrite(
+    tmp_path: Path, io_type: IOType, path_template: str | None
+) -> None:
+    def sink(df: pl.LazyFrame, overwrite_mode: Any) -> None:
+        io_type["sink"](
+            df,
+            PartitionByKey(
+                tmp_path,
+                by="a",
+                path_template=path_template,
+                overwrite_mode=overwrite_mode,
+            ),
+            engine="streaming",
+            sync_on_close="data",
+        )
+
+    def read_partitions() -> dict[str, list[int]]:
+        # A leftover staging directory would show up here as well.
+        return {
+            d.name: sorted(io_type["scan"](list(d.iterdir())).collect()["b"].to_list())
+            for d in sorted(tmp_path.iterdir())
+        }
+
+    sink(pl.LazyFrame({"a": [1, 1, 2], "b": [1, 2, 3]}), "append")
+    assert read_partitions() == {"a=1": [1, 2], "a=2": [3]}
+
+    # Only the partitions that are written to are replaced.
+    sink(pl.LazyFrame({"a": [1, 3], "b": [4, 5]}), "dynamic")
+    assert read_partitions() == {"a=1": [4], "a=2": [3], "a=3": [5]}
+
+    # A failed write leaves the existing partitions untouched.
+    lf = pl.LazyFrame({"a": [1, 2], "b": ["6", "x"]}).with_columns(
+        pl.col("b").cast(pl.Int64)
+    )
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        sink(lf, "dynamic")
+    assert read_partitions() == {"a=1": [4], "a=2": [3], "a=3": [5]}
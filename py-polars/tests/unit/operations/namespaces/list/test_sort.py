@@ -0,0 +1,128 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ShapeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_list_sort_by() -> None:
+    df = pl.DataFrame(
+        {
+            "events": [["login", "buy", "view"], ["view", "logout"], None, []],
+            "ts": [[30, 10, 20], [5, 1], [1], []],
+        }
+    )
+    result = df.select(
+        asc=pl.col("events").list.sort_by("ts"),
+        desc=pl.col("events").list.sort_by("ts", descending=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "asc": [["buy", "view", "login"], ["logout", "view"], None, []],
+            "desc": [["login", "view", "buy"], ["view", "logout"], None, []],
+        },
+        schema={"asc": pl.List(pl.String), "desc": pl.List(pl.String)},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_list_sort_by_nulls_and_ties() -> None:
+    s = pl.Series("a", [["a", "b", "c", "d"]])
+    by = pl.Series([[2, None, 1, 3]])
+    assert s.list.sort_by(by).to_list() == [["b", "c", "a", "d"]]
+    assert s.list.sort_by(by, nulls_last=True).to_list() == [["c", "a", "d", "b"]]
+
+    # ties in `by`
+    by = pl.Series([[2, None, 1, 1]])
+    assert s.list.sort_by(by, maintain_order=True).to_list() == [["b", "c", "d", "a"]]
+    assert s.list.sort_by(by, descending=True, maintain_order=True).to_list() == [
+        ["b", "a", "c", "d"]
+    ]
+
+
+def test_list_sort_by_length_mismatch() -> None:
+    df = pl.DataFrame({"a": [[3, 1, 2]], "by": [[1, 2]]})
+    with pytest.raises(ShapeError, match="cannot be sorted by each other"):
+        df.select(pl.col("a").list.sort_by("by"))
+
+
+def test_list_arg_sort() -> None:
+    idx_type = pl.get_index_type()
+    df = pl.DataFrame({"a": [[3, 1, 2], [9, None, 4], None, []]})
+    result = df.select(
+        asc=pl.col("a").list.arg_sort(),
+        desc=pl.col("a").list.arg_sort(descending=True),
+        nulls_last=pl.col("a").list.arg_sort(nulls_last=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "asc": [[1, 2, 0], [1, 2, 0], None, []],
+            "desc": [[0, 2, 1], [1, 0, 2], None, []],
+            "nulls_last": [[1, 2, 0], [2, 0, 1], None, []],
+        },
+        schema={
+            "asc": pl.List(idx_type),
+            "desc": pl.List(idx_type),
+            "nulls_last": pl.List(idx_type),
+        },
+    )
+    assert_frame_equal(result, expected)
+
+    # gathering by the indices sorts the lists
+    assert_series_equal(
+        df.select(pl.col("a").list.gather(pl.col("a").list.arg_sort())).to_series(),
+        df.select(pl.col("a").list.sort()).to_series(),
+    )
+
+
+def test_list_search_sorted() -> None:
+    idx_type = pl.get_index_type()
+    df = pl.DataFrame(
+        {
+            "ts": [[1, 5, 9], [2, 2, 8], [], None, [1, 2]],
+            "t": [6, 2, 3, 1, None],
+        }
+    )
+    result = df.select(
+        left=pl.col("ts").list.search_sorted(pl.col("t"), "left"),
+        right=pl.col("ts").list.search_sorted(pl.col("t"), "right"),
+        scalar=pl.col("ts").list.search_sorted(2, "left"),
+    )
+    expected = pl.DataFrame(
+        {
+            "left": [2, 0, 0, None, None],
+            "right": [2, 2, 0, None, None],
+            "scalar": [1, 0, 0, None, 1],
+        },
+        schema={"left": idx_type, "right": idx_type, "scalar": idx_type},
+    )
+    assert_frame_equal(result, expected)
+
+    s = pl.Series([[9, 5, 1]])
+    assert s.list.search_sorted(6, "left", descending=True).to_list() == [1]
+
+    words = pl.Series([["apple", "fig", "pear"]])
+    assert words.list.search_sorted("grape", "left").to_list() == [2]
+
+
+def test_list_top_k() -> None:
+    df = pl.DataFrame(
+        {
+            "scores": [[3, 9, 1, 7], [4], [None, 2, 5], None],
+            "k": [1, 2, 3, 1],
+        }
+    )
+    result = df.select(
+        top_2=pl.col("scores").list.top_k(2),
+        per_row=pl.col("scores").list.top_k(pl.col("k")),
+    )
+    expected = pl.DataFrame(
+        {
+            "top_2": [[9, 7], [4], [5, 2], None],
+            "per_row": [[9], [4], [5, 2, None], None],
+        }
+    )
+    assert_frame_equal(result, expected)
+    assert_series_equal(df["scores"].list.top_k(2), result["top_2"].alias("scores"))
@@ -0,0 +1,121 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import DuplicateError, InvalidOperationError, ShapeError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_list_zip() -> None:
+    df = pl.DataFrame(
+        {
+            "item": [["apple", "pear"], ["fig"], None, []],
+            "qty": [[3, 1], [7], [2], []],
+            "price": [[1.5, 2.0], None, [0.5], []],
+        }
+    )
+    result = df.select(
+        pair=pl.col("item").list.zip("qty"),
+        triple=pl.col("item").list.zip(["qty", "price"]),
+    )
+    assert result.schema == {
+        "pair": pl.List(pl.Struct({"item": pl.String, "qty": pl.Int64})),
+        "triple": pl.List(
+            pl.Struct({"item": pl.String, "qty": pl.Int64, "price": pl.Float64})
+        ),
+    }
+    assert result.to_dict(as_series=False) == {
+        "pair": [
+            [{"item": "apple", "qty": 3}, {"item": "pear", "qty": 1}],
+            [{"item": "fig", "qty": 7}],
+            None,
+            [],
+        ],
+        "triple": [
+            [
+                {"item": "apple", "qty": 3, "price": 1.5},
+                {"item": "pear", "qty": 1, "price": 2.0},
+            ],
+            None,
+            None,
+            [],
+        ],
+    }
+
+    s = pl.Series("x", [[1, 2], [3]])
+    y = pl.Series("y", [["a", "b"], ["c"]])
+    assert s.list.zip(y).to_list() == [
+        [{"x": 1, "y": "a"}, {"x": 2, "y": "b"}],
+        [{"x": 3, "y": "c"}],
+    ]
+
+
+def test_list_zip_broadcast_literal() -> None:
+    df = pl.DataFrame({"a": [[1, 2], [3, 4]]})
+    result = df.select(pl.col("a").list.zip(pl.lit(pl.Series("b", [["x", "y"]]))))
+    assert result.to_series().to_list() == [
+        [{"a": 1, "b": "x"}, {"a": 2, "b": "y"}],
+        [{"a": 3, "b": "x"}, {"a": 4, "b": "y"}],
+    ]
+
+
+def test_list_zip_errors() -> None:
+    df = pl.DataFrame({"a": [[1, 2], [3]], "b": [["x"], ["y"]], "c": [1, 2]})
+    with pytest.raises(ShapeError, match="cannot be zipped"):
+        df.select(pl.col("a").list.zip("b"))
+    with pytest.raises(DuplicateError, match="multiple fields"):
+        df.select(pl.col("a").list.zip(pl.col("a")))
+    with pytest.raises(InvalidOperationError):
+        df.select(pl.col("a").list.zip("c"))
+
+
+def test_list_unzip() -> None:
+    df = pl.DataFrame(
+        {
+            "item": [["apple", "pear"], ["fig"], None, []],
+            "qty": [[3, 1], [7], [2], []],
+        }
+    )
+    result = df.select(pl.col("item").list.zip("qty").list.unzip()).unnest("item")
+    expected = pl.DataFrame(
+        {
+            "item": [["apple", "pear"], ["fig"], None, []],
+            "qty": [[3, 1], [7], None, []],
+        },
+        schema={"item": pl.List(pl.String), "qty": pl.List(pl.Int64)},
+    )
+    assert_frame_equal(result, expected)
+
+    s = pl.Series("points", [[{"x": 1, "y": 2}, {"x": 3, "y": 4}], [{"x": 5, "y": 6}]])
+    assert_frame_equal(
+        s.list.unzip().struct.unnest(),
+        pl.DataFrame({"x": [[1, 3], [5]], "y": [[2, 4], [6]]}),
+    )
+
+    with pytest.raises(InvalidOperationError, match="expected a list of structs"):
+        pl.Series([[1, 2]]).list.unzip()
+
+
+def test_list_enumerate() -> None:
+    idx_type = pl.get_index_type()
+    s = pl.Series("letters", [["a", "b", "c"], None, [], ["d"]])
+    result = s.list.enumerate()
+    assert result.dtype == pl.List(pl.Struct({"index": idx_type, "value": pl.String}))
+    assert result.to_list() == [
+        [
+            {"index": 0, "value": "a"},
+            {"index": 1, "value": "b"},
+            {"index": 2, "value": "c"},
+        ],
+        None,
+        [],
+        [{"index": 0, "value": "d"}],
+    ]
+
+    # the indices restart in every list, also for sliced input
+    df = s.to_frame().slice(2)
+    assert_series_equal(
+        df.select(pl.col("letters").list.enumerate()).to_series(),
+        result.slice(2),
+    )
@@ -0,0 +1,105 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_str_levenshtein() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["kitten", "", "café", "a", None],
+            "b": ["sitting", "abc", "cafe", "abcdef", "x"],
+        }
+    )
+    result = df.select(
+        dist=pl.col("a").str.levenshtein("b"),
+        capped=pl.col("a").str.levenshtein("b", max_distance=2),
+        lit=pl.col("a").str.levenshtein(pl.lit("kitten")),
+    )
+    expected = pl.DataFrame(
+        {
+            "dist": [3, 3, 1, 5, None],
+            "capped": [3, 3, 1, 3, None],
+            "lit": [0, 6, 6, 6, None],
+        },
+        schema={"dist": pl.UInt32, "capped": pl.UInt32, "lit": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_str_damerau_levenshtein() -> None:
+    s = pl.Series("a", ["ca", "abcdef", "martha", "kitten", None])
+    other = pl.Series("b", ["abc", "badcfe", "marhta", "sitting", "x"])
+
+    assert_series_equal(
+        s.str.damerau_levenshtein(other),
+        pl.Series("a", [2, 3, 1, 3, None], dtype=pl.UInt32),
+    )
+    assert_series_equal(
+        s.str.damerau_levenshtein(other, max_distance=1),
+        pl.Series("a", [2, 2, 1, 2, None], dtype=pl.UInt32),
+    )
+    # a transposition is a single edit, unlike in the levenshtein distance
+    assert s.str.levenshtein(other).to_list() == [3, 4, 2, 3, None]
+
+
+def test_str_hamming() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["karolin", "abc", "", None],
+            "b": ["kathrin", "ab", "", "x"],
+        }
+    )
+    result = df.select(
+        dist=pl.col("a").str.hamming("b"),
+        capped=pl.col("a").str.hamming("b", max_distance=1),
+    )
+    expected = pl.DataFrame(
+        {"dist": [3, None, 0, None], "capped": [2, None, 0, None]},
+        schema={"dist": pl.UInt32, "capped": pl.UInt32},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_str_jaro_winkler() -> None:
+    s = pl.Series("a", ["martha", "dixon", "", "same", None])
+    other = pl.Series(["marhta", "dicksonx", "", "same", "x"])
+
+    result = s.str.jaro_winkler(other)
+    assert_series_equal(
+        result,
+        pl.Series("a", [0.961111, 0.813333, 1.0, 1.0, None]),
+        check_exact=False,
+    )
+
+    # the similarity is symmetric
+    assert_series_equal(
+        other.str.jaro_winkler(s).rename("a"), result, check_exact=False
+    )
+
+
+def test_str_jaccard_ngram() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["martha", "kitten", "", "ab", None],
+            "b": ["marhta", "sitting", "", "ab", "x"],
+        }
+    )
+    result = df.select(
+        bigram=pl.col("a").str.jaccard_ngram("b"),
+        trigram=pl.col("a").str.jaccard_ngram("b", n=3),
+    )
+    # {mar, art, rth, tha} vs {mar, arh, rht, hta}; short strings form one n-gram
+    expected = pl.DataFrame(
+        {
+            "bigram": [2 / 8, 2 / 9, 1.0, 1.0, None],
+            "trigram": [1 / 7, 1 / 8, 1.0, 1.0, None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(InvalidOperationError, match="n-gram size must be positive"):
+        df.select(pl.col("a").str.jaccard_ngram("b", n=0))
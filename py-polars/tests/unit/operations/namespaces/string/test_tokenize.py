@@ -0,0 +1,99 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_str_split_regex() -> None:
+    df = pl.DataFrame({"a": ["a1b22c", "x", None], "p": ["1", None, "y"]})
+    result = df.select(
+        lit=pl.col("a").str.split_regex(r"\d+"),
+        col=pl.col("a").str.split_regex(pl.col("p")),
+    )
+    expected = pl.DataFrame(
+        {
+            "lit": [["a", "b", "c"], ["x"], None],
+            "col": [["a", "b22c"], None, None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(ComputeError, match="regex error"):
+        df.select(pl.col("a").str.split_regex("("))
+
+
+def test_str_tokenize() -> None:
+    s = pl.Series(
+        "a",
+        ["The quick (\"brown\") fox can't jump 32.3 feet, right?", "", None],
+    )
+    assert_series_equal(
+        s.str.tokenize(),
+        pl.Series(
+            "a",
+            [
+                [
+                    "The",
+                    "quick",
+                    "brown",
+                    "fox",
+                    "can't",
+                    "jump",
+                    "32.3",
+                    "feet",
+                    "right",
+                ],
+                [],
+                None,
+            ],
+        ),
+    )
+    assert_series_equal(
+        s.str.count_tokens(), pl.Series("a", [9, 0, None], dtype=pl.UInt32)
+    )
+    assert s.str.remove_stop_words(["The", "can't", "feet"]).to_list() == [
+        ["quick", "brown", "fox", "jump", "32.3", "right"],
+        [],
+        None,
+    ]
+
+
+def test_str_ngrams() -> None:
+    df = pl.DataFrame({"a": ["héllo", "hi", "one two three", None]})
+    result = df.select(
+        chars=pl.col("a").str.char_ngrams(3),
+        words=pl.col("a").str.word_ngrams(2),
+    )
+    expected = pl.DataFrame(
+        {
+            "chars": [
+                ["hél", "éll", "llo"],
+                [],
+                [
+                    "one",
+                    "ne ",
+                    "e t",
+                    " tw",
+                    "two",
+                    "wo ",
+                    "o t",
+                    " th",
+                    "thr",
+                    "hre",
+                    "ree",
+                ],
+                None,
+            ],
+            "words": [[], [], ["one two", "two three"], None],
+        },
+        schema={"chars": pl.List(pl.String), "words": pl.List(pl.String)},
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(InvalidOperationError, match="n-gram size must be positive"):
+        df.select(pl.col("a").str.char_ngrams(0))
+    with pytest.raises(InvalidOperationError, match="n-gram size must be positive"):
+        df.select(pl.col("a").str.word_ngrams(0))
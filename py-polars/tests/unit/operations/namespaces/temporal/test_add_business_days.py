@@ -148,6 +148,38 @@ def test_add_business_days_w_holidays() -> None:
     assert_series_equal(result, expected)
 
 
+
+def test_add_business_days_w_calendar() -> None:
+    # Christmas Day 2022 and New Year's Day 2023 fall on a Sunday
+    calendar = pl.HolidayCalendar(
+        "example",
+        [
+            pl.Holiday("New Year's Day", month=1, day=1, observance="nearest_weekday"),
+            pl.Holiday("Christmas Day", month=12, day=25, observance="nearest_weekday"),
+        ],
+    )
+    df = pl.DataFrame(
+        {
+            "start": [date(2022, 12, 23), date(2022, 12, 30), date(2023, 1, 3)],
+            "n": [1, 1, -1],
+        }
+    )
+    result = df.select(
+        result=pl.col("start").dt.add_business_days("n", calendar=calendar),
+    )["result"]
+    expected = pl.Series(
+        "result", [date(2022, 12, 27), date(2023, 1, 3), date(2022, 12, 30)]
+    )
+    assert_series_equal(result, expected)
+
+    result = df["start"].dt.add_business_days(
+        1, holidays=[date(2022, 12, 27)], calendar=calendar
+    )
+    expected = pl.Series(
+        "start", [date(2022, 12, 28), date(2023, 1, 3), date(2023, 1, 4)]
+    )
+    assert_series_equal(result, expected)
+
 def test_add_business_days_w_roll() -> None:
     df = pl.DataFrame(
         {
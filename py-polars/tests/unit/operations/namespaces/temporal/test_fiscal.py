@@ -0,0 +1,217 @@
+from __future__ import annotations
+
+from datetime import date, datetime
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_fiscal_calendar_months() -> None:
+    calendar = pl.FiscalCalendar(start_month=10)
+    df = pl.DataFrame(
+        {
+            "date": [
+                date(2023, 9, 30),
+                date(2023, 10, 1),
+                date(2023, 11, 15),
+                date(2024, 1, 15),
+                date(2024, 9, 30),
+                None,
+            ]
+        }
+    )
+    result = df.select(
+        year=pl.col("date").dt.fiscal_year(calendar),
+        quarter=pl.col("date").dt.fiscal_quarter(calendar),
+        period=pl.col("date").dt.fiscal_period(calendar),
+        week=pl.col("date").dt.fiscal_week(calendar),
+        year_start_label=pl.col("date").dt.fiscal_year(
+            pl.FiscalCalendar(start_month=10, label="start")
+        ),
+    )
+    expected = pl.DataFrame(
+        {
+            "year": [2023, 2024, 2024, 2024, 2024, None],
+            "quarter": [4, 1, 1, 2, 4, None],
+            "period": [12, 1, 2, 4, 12, None],
+            # the last week of the fiscal year may be shorter than seven days
+            "week": [53, 1, 7, 16, 53, None],
+            "year_start_label": [2022, 2023, 2023, 2023, 2023, None],
+        },
+        schema={
+            "year": pl.Int32,
+            "quarter": pl.Int8,
+            "period": pl.Int8,
+            "week": pl.Int8,
+            "year_start_label": pl.Int32,
+        },
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_fiscal_truncate_months() -> None:
+    calendar = pl.FiscalCalendar(start_month=10)
+    s = pl.Series(
+        "date",
+        [date(2023, 9, 30), date(2023, 11, 15), date(2024, 1, 15), None],
+    )
+    assert s.dt.fiscal_truncate(calendar, "year").to_list() == [
+        date(2022, 10, 1),
+        date(2023, 10, 1),
+        date(2023, 10, 1),
+        None,
+    ]
+    assert s.dt.fiscal_truncate(calendar, "quarter").to_list() == [
+        date(2023, 7, 1),
+        date(2023, 10, 1),
+        date(2024, 1, 1),
+        None,
+    ]
+    assert s.dt.fiscal_truncate(calendar, "period").to_list() == [
+        date(2023, 9, 1),
+        date(2023, 11, 1),
+        date(2024, 1, 1),
+        None,
+    ]
+    assert s.dt.fiscal_truncate(calendar, "week").to_list() == [
+        date(2023, 9, 30),
+        date(2023, 11, 12),
+        date(2024, 1, 14),
+        None,
+    ]
+
+
+def test_fiscal_calendar_retail() -> None:
+    # the NRF calendar: fiscal 2023 runs from 2023-01-29 to 2024-02-03 (53 weeks)
+    retail = pl.FiscalCalendar(
+        start_month=2, week_pattern="454", end_weekday=6, label="start"
+    )
+    df = pl.DataFrame(
+        {
+            "date": [
+                date(2023, 1, 28),
+                date(2023, 1, 29),
+                date(2023, 3, 1),
+                date(2023, 5, 1),
+                date(2023, 12, 1),
+                date(2024, 2, 3),
+            ]
+        }
+    )
+    result = df.select(
+        year=pl.col("date").dt.fiscal_year(retail),
+        quarter=pl.col("date").dt.fiscal_quarter(retail),
+        period=pl.col("date").dt.fiscal_period(retail),
+        week=pl.col("date").dt.fiscal_week(retail),
+        year_start=pl.col("date").dt.fiscal_truncate(retail, "year"),
+        quarter_start=pl.col("date").dt.fiscal_truncate(retail, "quarter"),
+        period_start=pl.col("date").dt.fiscal_truncate(retail, "period"),
+    )
+    expected = pl.DataFrame(
+        {
+            "year": [2022, 2023, 2023, 2023, 2023, 2023],
+            "quarter": [4, 1, 1, 2, 4, 4],
+            "period": [12, 1, 2, 4, 11, 12],
+            "week": [52, 1, 5, 14, 44, 53],
+            "year_start": [
+                date(2022, 1, 30),
+                date(2023, 1, 29),
+                date(2023, 1, 29),
+                date(2023, 1, 29),
+                date(2023, 1, 29),
+                date(2023, 1, 29),
+            ],
+            "quarter_start": [
+                date(2022, 10, 30),
+                date(2023, 1, 29),
+                date(2023, 1, 29),
+                date(2023, 4, 30),
+                date(2023, 10, 29),
+                date(2023, 10, 29),
+            ],
+            # the 53rd week is added to the last period
+            "period_start": [
+                date(2023, 1, 1),
+                date(2023, 1, 29),
+                date(2023, 2, 26),
+                date(2023, 4, 30),
+                date(2023, 11, 26),
+                date(2023, 12, 31),
+            ],
+        },
+        schema_overrides={
+            "year": pl.Int32,
+            "quarter": pl.Int8,
+            "period": pl.Int8,
+            "week": pl.Int8,
+        },
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_fiscal_calendar_retail_year_end() -> None:
+    # 4-4-5 weeks, ending on the last Sunday of December
+    calendar = pl.FiscalCalendar(week_pattern="445", end_weekday=7, year_end="last")
+    s = pl.Series(
+        "date",
+        [date(2024, 1, 1), date(2024, 3, 31), date(2024, 12, 29)],
+    )
+    assert_series_equal(
+        s.dt.fiscal_week(calendar), pl.Series("date", [1, 13, 52], dtype=pl.Int8)
+    )
+    assert_series_equal(
+        s.dt.fiscal_period(calendar), pl.Series("date", [1, 3, 12], dtype=pl.Int8)
+    )
+    assert s.dt.fiscal_truncate(calendar, "quarter").to_list() == [
+        date(2024, 1, 1),
+        date(2024, 1, 1),
+        date(2024, 9, 30),
+    ]
+
+
+@pytest.mark.parametrize("time_zone", [None, "Asia/Kathmandu"])
+def test_fiscal_truncate_datetime(time_zone: str | None) -> None:
+    calendar = pl.FiscalCalendar(week_pattern="445", end_weekday=7, year_end="last")
+    s = pl.Series(
+        [datetime(2024, 2, 14, 10), datetime(2024, 5, 3, 8, 30)]
+    ).dt.replace_time_zone(time_zone)
+    result = pl.DataFrame({"ts": s}).select(
+        period=pl.col("ts").dt.fiscal_truncate(calendar, "period"),
+        quarter=pl.col("ts").dt.fiscal_truncate(calendar, "quarter"),
+        week=pl.col("ts").dt.fiscal_week(calendar),
+    )
+    expected = pl.DataFrame(
+        {
+            "period": [datetime(2024, 1, 29), datetime(2024, 4, 29)],
+            "quarter": [datetime(2024, 1, 1), datetime(2024, 4, 1)],
+            "week": pl.Series([7, 18], dtype=pl.Int8),
+        }
+    ).with_columns(pl.col("period", "quarter").dt.replace_time_zone(time_zone))
+    assert_frame_equal(result, expected)
+
+
+def test_fiscal_datetime_local_date() -> None:
+    # 2024-03-31 22:30 UTC is already 2024-04-01 in Kathmandu
+    calendar = pl.FiscalCalendar(week_pattern="445", end_weekday=7, year_end="last")
+    s = pl.Series([datetime(2024, 3, 31, 22, 30)]).dt.replace_time_zone("UTC")
+    assert s.dt.fiscal_week(calendar).to_list() == [13]
+    local = s.dt.convert_time_zone("Asia/Kathmandu")
+    assert local.dt.fiscal_week(calendar).to_list() == [14]
+    assert_series_equal(
+        local.dt.fiscal_truncate(calendar, "period"),
+        pl.Series([datetime(2024, 4, 1)]).dt.replace_time_zone("Asia/Kathmandu"),
+    )
+
+
+def test_fiscal_invalid() -> None:
+    calendar = pl.FiscalCalendar(start_month=10)
+    with pytest.raises(ValueError, match="`unit` must be one of"):
+        unit = "month"
+        pl.Series([date(2024, 1, 1)]).dt.fiscal_truncate(calendar, unit)  # type: ignore[arg-type]
+    with pytest.raises(InvalidOperationError, match="not supported for dtype"):
+        pl.Series([1, 2]).dt.fiscal_year(calendar)
+    with pytest.raises(ComputeError, match="out of range for a fiscal calendar"):
+        pl.Series([2**31 - 1]).cast(pl.Date).dt.fiscal_year(calendar)
@@ -74,6 +74,22 @@ def test_is_business_day(
     assert_series_equal(result, expected)
 
 
+
+def test_is_business_day_w_calendar() -> None:
+    calendar = pl.HolidayCalendar(
+        "example",
+        [pl.Holiday("Christmas Day", month=12, day=25, observance="nearest_weekday")],
+    )
+    # Christmas Day 2022 falls on a Sunday and is observed on the Monday
+    dates = [date(2022, 12, d) for d in (23, 26, 27, 31)]
+    s = pl.Series("date", dates)
+    expected = pl.Series("date", [True, False, True, False])
+    assert_series_equal(s.dt.is_business_day(calendar=calendar), expected)
+
+    result = s.dt.is_business_day(holidays=[date(2022, 12, 23)], calendar=calendar)
+    expected = pl.Series("date", [False, False, True, False])
+    assert_series_equal(result, expected)
+
 def test_is_business_day_invalid() -> None:
     df = pl.DataFrame({"date": [date(2020, 1, 1), date(2020, 1, 2)]})
     with pytest.raises(ComputeError):
@@ -6,6 +6,7 @@ from typing import TYPE_CHECKING
 import pytest
 
 import polars as pl
+from polars.exceptions import InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
@@ -45,6 +46,22 @@ if TYPE_CHECKING:
             "-2mo",
             [date(2019, 11, 1), date(2019, 11, 2)],
         ),
+        # 2024-01-05 is a Friday
+        (
+            [date(2024, 1, 5), date(2024, 1, 6)],
+            "1bd",
+            [date(2024, 1, 8), date(2024, 1, 8)],
+        ),
+        (
+            [date(2024, 1, 8), date(2024, 1, 7)],
+            "-1bd",
+            [date(2024, 1, 5), date(2024, 1, 5)],
+        ),
+        (
+            [date(2024, 1, 2), date(2024, 1, 3)],
+            "6bd",
+            [date(2024, 1, 10), date(2024, 1, 11)],
+        ),
     ],
 )
 def test_date_offset_by(inputs: list[date], offset: str, outputs: list[date]) -> None:
@@ -96,6 +113,11 @@ def test_date_offset_by(inputs: list[date], offset: str, outputs: list[date]) ->
             "2mo",
             [datetime(2020, 3, 1), datetime(2020, 3, 2)],
         ),
+        (
+            [date(2024, 1, 5), date(2024, 1, 6)],
+            "1bd12h",
+            [datetime(2024, 1, 8, 12), datetime(2024, 1, 8, 12)],
+        ),
     ],
 )
 @pytest.mark.parametrize("time_unit", ["ms", "us", "ns"])
@@ -171,3 +193,20 @@ def test_offset_by_unequal_length_22018() -> None:
         pl.Series([datetime(2088, 8, 8, 8, 8, 8, 8)] * 2).dt.offset_by(
             pl.Series([f"{h}y" for h in range(3)])
         )
+
+
+def test_offset_by_business_days() -> None:
+    df = pl.DataFrame(
+        {
+            "date": [date(2024, 1, 5), date(2024, 1, 6), date(2024, 1, 8), None],
+            "offset": ["1bd", "-1bd", "-3bd", "1bd"],
+        }
+    )
+    result = df.select(pl.col("date").dt.offset_by(pl.col("offset")))
+    expected = pl.DataFrame(
+        {"date": [date(2024, 1, 8), date(2024, 1, 5), date(2024, 1, 3), None]}
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(InvalidOperationError, match="cannot be combined"):
+        df.select(pl.col("date").dt.offset_by("1bd1d"))
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import hashlib
 import random
 import struct
 from typing import TYPE_CHECKING
@@ -7,7 +8,7 @@ from typing import TYPE_CHECKING
 import pytest
 
 import polars as pl
-from polars.testing import assert_frame_equal
+from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
     from polars._typing import SizeUnit, TransferEncoding
@@ -280,3 +281,86 @@ def test_bin_contains_unequal_lengths_22018(func: str) -> None:
     f = getattr(s, func)
     with pytest.raises(pl.exceptions.ShapeError):
         f(pl.Series([b"x", b"y", b"z"]))
+
+
+@pytest.mark.parametrize("algorithm", ["md5", "sha1", "sha256"])
+def test_bin_digests(algorithm: str) -> None:
+    values = [b"abc", b"", "héllo".encode(), None]
+    expected = [None if v is None else hashlib.new(algorithm, v) for v in values]
+
+    s = pl.Series("a", values, dtype=pl.Binary)
+    digest = getattr(s.bin, algorithm)
+    assert digest().to_list() == [None if h is None else h.digest() for h in expected]
+    assert digest(hex=True).to_list() == [
+        None if h is None else h.hexdigest() for h in expected
+    ]
+
+    # strings are hashed by their UTF-8 bytes
+    df = pl.DataFrame({"a": s, "str": s.cast(pl.String)})
+    result = df.select(getattr(pl.col("a", "str").bin, algorithm)(hex=True))
+    assert result["a"].to_list() == result["str"].to_list()
+
+
+def test_bin_xxh3() -> None:
+    df = pl.DataFrame({"a": ["abc", "polars", "", None]})
+    result = df.select(
+        xxh3_64=pl.col("a").bin.xxh3_64(),
+        seeded=pl.col("a").bin.xxh3_64(seed=1),
+        from_binary=pl.col("a").cast(pl.Binary).bin.xxh3_64(),
+        xxh3_128=pl.col("a").bin.xxh3_128(),
+        xxh3_128_hex=pl.col("a").bin.xxh3_128(hex=True),
+    )
+    assert result.schema == {
+        "xxh3_64": pl.UInt64,
+        "seeded": pl.UInt64,
+        "from_binary": pl.UInt64,
+        "xxh3_128": pl.Binary,
+        "xxh3_128_hex": pl.String,
+    }
+    assert result["xxh3_64"].to_list() == [
+        8696274497037089104,
+        16500643583958147475,
+        3244421341483603138,
+        None,
+    ]
+    assert result["xxh3_64"].to_list() == result["from_binary"].to_list()
+    assert result["seeded"][0] != result["xxh3_64"][0]
+    assert [None if v is None else len(v) for v in result["xxh3_128"]] == [
+        16,
+        16,
+        16,
+        None,
+    ]
+    assert result["xxh3_128_hex"].to_list() == [
+        None if v is None else v.hex() for v in result["xxh3_128"]
+    ]
+
+
+def test_bin_murmur3_32() -> None:
+    s = pl.Series("a", [b"iceberg", bytes([0, 1, 2, 3]), b"", None])
+    assert_series_equal(
+        s.bin.murmur3_32(), pl.Series("a", [1210000089, -188683207, 0, None], pl.Int32)
+    )
+    assert s.bin.murmur3_32(seed=1)[2] == 0x514E28B7
+    assert s.cast(pl.String, strict=False).bin.murmur3_32()[0] == 1210000089
+
+
+def test_bin_murmur3_32_spark() -> None:
+    # Spark: `SELECT hash('Spark'), hash('')`.
+    s = pl.Series("a", ["Spark", "", None])
+    assert_series_equal(
+        s.bin.murmur3_32(spark=True),
+        pl.Series("a", [228093765, 142593372, None], pl.Int32),
+    )
+    # Trailing bytes are sign-extended, blocks of 4 bytes hash like Iceberg.
+    b = pl.Series("b", [bytes([0xFF, 0x80, 0x01]), bytes([0, 1, 2, 3])])
+    assert b.bin.murmur3_32(spark=True).to_list() == [-1586349443, -1066376783]
+    assert b.bin.murmur3_32(seed=0, spark=True)[1] == -188683207
+
+
+def test_bin_hash_invalid_dtype() -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError, match="expects a String or Binary input"
+    ):
+        df.select(pl.col("a").bin.md5())
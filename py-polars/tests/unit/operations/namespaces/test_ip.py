@@ -0,0 +1,122 @@
+from __future__ import annotations
+
+import ipaddress
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def ips() -> pl.Series:
+    return pl.Series(
+        "ip",
+        ["192.168.0.1", "2001:db8::1", "::ffff:10.0.0.1", "not an ip", None],
+    )
+
+
+def test_ip_to_int(ips: pl.Series) -> None:
+    assert_series_equal(
+        ips.ip.to_int(),
+        pl.Series(
+            "ip",
+            [
+                int(ipaddress.IPv4Address("192.168.0.1")),
+                None,
+                int(ipaddress.IPv4Address("10.0.0.1")),
+                None,
+                None,
+            ],
+            dtype=pl.UInt32,
+        ),
+    )
+    assert_series_equal(
+        ips.ip.to_int(ipv6=True),
+        pl.Series(
+            "ip",
+            [
+                int(ipaddress.IPv6Address("::ffff:192.168.0.1")),
+                int(ipaddress.IPv6Address("2001:db8::1")),
+                int(ipaddress.IPv6Address("::ffff:10.0.0.1")),
+                None,
+                None,
+            ],
+            dtype=pl.UInt128,
+        ),
+    )
+
+
+def test_ip_round_trip(ips: pl.Series) -> None:
+    expected = pl.Series(
+        "ip", ["192.168.0.1", "2001:db8::1", "10.0.0.1", None, None]
+    )
+    assert_series_equal(ips.ip.to_string(), expected)
+
+    df = ips.to_frame()
+    result = df.select(
+        from_u32=pl.col("ip").ip.to_int().ip.to_string(),
+        from_u128=pl.col("ip").ip.to_int(ipv6=True).ip.to_string(),
+        from_binary=pl.col("ip").ip.to_binary().ip.to_string(),
+    )
+    assert_frame_equal(
+        result,
+        pl.DataFrame(
+            {
+                "from_u32": ["192.168.0.1", None, "10.0.0.1", None, None],
+                "from_u128": expected,
+                "from_binary": expected,
+            }
+        ),
+    )
+
+
+def test_ip_to_binary(ips: pl.Series) -> None:
+    assert ips.ip.to_binary().to_list() == [
+        ipaddress.IPv4Address("192.168.0.1").packed,
+        ipaddress.IPv6Address("2001:db8::1").packed,
+        ipaddress.IPv4Address("10.0.0.1").packed,
+        None,
+        None,
+    ]
+
+    # binary values that are neither 4 nor 16 bytes long are invalid
+    s = pl.Series([b"\x0a\x00\x00\x01", b"\x01\x02\x03", b""])
+    assert s.ip.to_string().to_list() == ["10.0.0.1", None, None]
+
+
+def test_ip_in_network() -> None:
+    df = pl.DataFrame(
+        {"ip": ["10.1.2.3", "11.0.0.1", "::ffff:10.0.0.1", "fd00::1", "x", None]}
+    )
+    result = df.select(
+        v4=pl.col("ip").ip.in_network("10.0.0.0/8"),
+        v6=pl.col("ip").ip.in_network("fc00::/7"),
+        host_bits=pl.col("ip").ip.in_network("10.255.255.255/8"),
+        single=pl.col("ip").ip.in_network("10.1.2.3"),
+        all_v4=pl.col("ip").ip.in_network("0.0.0.0/0"),
+    )
+    expected = pl.DataFrame(
+        {
+            "v4": [True, False, True, False, None, None],
+            "v6": [False, False, False, True, None, None],
+            "host_bits": [True, False, True, False, None, None],
+            "single": [True, False, False, False, None, None],
+            "all_v4": [True, True, True, False, None, None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("cidr", ["10.0.0.0/33", "10.0.0/8", "fc00::/129", ""])
+def test_ip_in_network_invalid(cidr: str) -> None:
+    with pytest.raises(pl.exceptions.ComputeError, match="invalid CIDR network"):
+        pl.Series(["10.0.0.1"]).ip.in_network(cidr)
+
+
+def test_ip_invalid_dtype() -> None:
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError,
+        match="expects String, Binary, UInt32 or UInt128 IP addresses",
+    ):
+        pl.Series([1, 2]).ip.to_string()
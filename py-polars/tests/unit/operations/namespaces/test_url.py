@@ -0,0 +1,113 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_url_parse() -> None:
+    s = pl.Series(
+        "url",
+        [
+            "https://user:pw@Example.com:8080/a/b.html?x=1&y=2#top",
+            "http://[::1]:80",
+            "/search?q=polars",
+            "mailto:someone@example.com",
+            "http://host:99999/",
+            None,
+        ],
+    )
+    result = s.url.parse()
+    assert result.dtype == pl.Struct(
+        {
+            "scheme": pl.String,
+            "host": pl.String,
+            "port": pl.UInt16,
+            "path": pl.String,
+            "query": pl.String,
+            "fragment": pl.String,
+        }
+    )
+    assert result.to_list() == [
+        {
+            "scheme": "https",
+            "host": "Example.com",
+            "port": 8080,
+            "path": "/a/b.html",
+            "query": "x=1&y=2",
+            "fragment": "top",
+        },
+        {
+            "scheme": "http",
+            "host": "::1",
+            "port": 80,
+            "path": "",
+            "query": None,
+            "fragment": None,
+        },
+        {
+            "scheme": None,
+            "host": None,
+            "port": None,
+            "path": "/search",
+            "query": "q=polars",
+            "fragment": None,
+        },
+        {
+            "scheme": "mailto",
+            "host": None,
+            "port": None,
+            "path": "someone@example.com",
+            "query": None,
+            "fragment": None,
+        },
+        {
+            "scheme": "http",
+            "host": "host",
+            "port": None,
+            "path": "/",
+            "query": None,
+            "fragment": None,
+        },
+        None,
+    ]
+
+
+def test_url_parse_expr() -> None:
+    df = pl.DataFrame({"url": ["http://a.com/x?q=1", None]})
+    result = df.select(pl.col("url").url.parse().struct.field("host", "path"))
+    expected = pl.DataFrame({"host": ["a.com", None], "path": ["/x", None]})
+    assert_frame_equal(result, expected)
+
+
+def test_url_query_param() -> None:
+    df = pl.DataFrame(
+        {
+            "url": [
+                "/s?q=hello+world%21&page=2&q=again",
+                "/s?flag&q",
+                "/s?page=1",
+                "/s#q=1",
+                "/s?q=caf%C3%A9",
+                None,
+            ]
+        }
+    )
+    result = df.select(
+        q=pl.col("url").url.query_param("q"),
+        page=pl.col("url").url.query_param("page"),
+    )
+    expected = pl.DataFrame(
+        {
+            "q": ["hello world!", "", None, None, "café", None],
+            "page": ["2", None, "1", None, None, None],
+        }
+    )
+    assert_frame_equal(result, expected)
+    assert_series_equal(df["url"].url.query_param("q"), result["q"].alias("url"))
+
+
+def test_url_invalid_dtype() -> None:
+    with pytest.raises(pl.exceptions.SchemaError):
+        pl.Series([1, 2]).url.parse()
@@ -1530,3 +1530,212 @@ def test_rolling_quantile_with_nulls_22781(method: QuantileMethod) -> None:
     )
     expected = pl.Series("a", [None, None, 1.0, 1.0, None, 1.0, 1.0, 1.0, None])
     assert_series_equal(out["a"], expected)
+
+
+def test_rolling_n_unique_mode() -> None:
+    idx_type = pl.get_index_type()
+    df = pl.DataFrame({"a": [3, 1, None, 3, 2, 2]})
+    result = df.select(
+        n_unique=pl.col("a").rolling_n_unique(3),
+        n_unique_center=pl.col("a").rolling_n_unique(3, min_samples=1, center=True),
+        mode=pl.col("a").rolling_mode(3, min_samples=2),
+        mode_center=pl.col("a").rolling_mode(3, min_samples=1, center=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "n_unique": [None, None, None, None, None, 2],
+            "n_unique_center": [2, 2, 2, 2, 2, 1],
+            # ties resolve to the smallest value
+            "mode": [None, 1, 1, 1, 2, 2],
+            "mode_center": [1, 1, 1, 2, 2, 2],
+        },
+        schema={
+            "n_unique": idx_type,
+            "n_unique_center": idx_type,
+            "mode": pl.Int64,
+            "mode_center": pl.Int64,
+        },
+    )
+    assert_frame_equal(result, expected)
+
+    assert_series_equal(
+        df["a"].rolling_n_unique(3, min_samples=1),
+        pl.Series("a", [1, 2, 2, 2, 2, 2], dtype=idx_type),
+    )
+    assert_series_equal(
+        df["a"].rolling_mode(3, min_samples=1),
+        pl.Series("a", [3, 1, 1, 1, 2, 2]),
+    )
+
+
+def test_rolling_rank() -> None:
+    idx_type = pl.get_index_type()
+    df = pl.DataFrame({"a": [3, 1, None, 3, 2, 2]})
+    result = df.select(
+        average=pl.col("a").rolling_rank(3, min_samples=1),
+        min=pl.col("a").rolling_rank(3, method="min", min_samples=1),
+        dense=pl.col("a").rolling_rank(3, method="dense", min_samples=1),
+        max_center=pl.col("a").rolling_rank(
+            3, method="max", min_samples=1, center=True
+        ),
+    )
+    expected = pl.DataFrame(
+        {
+            "average": [1.0, 1.0, None, 2.0, 1.0, 1.5],
+            "min": [1, 1, None, 2, 1, 1],
+            "dense": [1, 1, None, 2, 1, 1],
+            "max_center": [2, 1, None, 2, 2, 2],
+        },
+        schema={
+            "average": pl.Float64,
+            "min": idx_type,
+            "dense": idx_type,
+            "max_center": idx_type,
+        },
+    )
+    assert_frame_equal(result, expected)
+
+    s = pl.Series("a", [3, 1, 3, 2, 3, 0])
+    assert s.rolling_rank(3).to_list() == [None, None, 2.5, 2.0, 2.5, 1.0]
+    assert s.rolling_rank(3, method="max").to_list() == [None, None, 3, 2, 3, 1]
+
+    with pytest.raises(ValueError, match="rolling rank `method` must be one of"):
+        s.rolling_rank(3, method="ordinal")  # type: ignore[arg-type]
+
+
+def test_rolling_arg_min_max() -> None:
+    idx_type = pl.get_index_type()
+    df = pl.DataFrame(
+        {
+            "a": [3, 1, None, 3, 2, 2],
+            "f": [1.5, 2.0, None, -1.0, 4.0, 0.5],
+        }
+    )
+    result = df.select(
+        arg_min=pl.col("a").rolling_arg_min(3, min_samples=1),
+        arg_min_center=pl.col("a").rolling_arg_min(3, min_samples=1, center=True),
+        arg_max=pl.col("f").rolling_arg_max(2, min_samples=1),
+    )
+    # the index is relative to the start of the window
+    expected = pl.DataFrame(
+        {
+            "arg_min": [0, 1, 1, 0, 2, 1],
+            "arg_min_center": [1, 1, 0, 2, 1, 0],
+            "arg_max": [0, 1, 0, 1, 1, 0],
+        },
+        schema={"arg_min": idx_type, "arg_min_center": idx_type, "arg_max": idx_type},
+    )
+    assert_frame_equal(result, expected)
+
+    s = pl.Series("a", [4, 1, 5, 2, 8, 3])
+    assert s.rolling_arg_min(3).to_list() == [None, None, 1, 0, 1, 0]
+    assert s.rolling_arg_max(3).to_list() == [None, None, 2, 1, 2, 1]
+    # ties resolve to the first occurrence
+    assert pl.Series([1, 1, 1]).rolling_arg_max(3).to_list() == [None, None, 0]
+
+
+def test_rolling_first_last() -> None:
+    df = pl.DataFrame({"a": [3, 1, None, 3, 2, 2]})
+    result = df.select(
+        first=pl.col("a").rolling_first(3, min_samples=2),
+        last=pl.col("a").rolling_last(3, min_samples=1, center=True),
+    )
+    expected = pl.DataFrame(
+        {"first": [None, 3, 3, 1, 3, 3], "last": [1, 1, 3, 2, 2, 2]}
+    )
+    assert_frame_equal(result, expected)
+
+    s = pl.Series("a", [1, None, 3, 4, None])
+    assert s.rolling_first(2, min_samples=1).to_list() == [1, 1, 3, 3, 4]
+    assert s.rolling_last(2, min_samples=1).to_list() == [1, 1, 3, 4, 4]
+
+
+def test_rolling_product() -> None:
+    df = pl.DataFrame(
+        {
+            "i": pl.Series([3, 1, None, 3, 2, 2], dtype=pl.Int32),
+            "f": [1.5, 2.0, None, -1.0, 4.0, 0.5],
+            "b": [True, False, True, True, None, True],
+        }
+    )
+    result = df.select(
+        pl.col("i").rolling_product(2, min_samples=1),
+        pl.col("f").rolling_product(2, min_samples=1),
+        pl.col("b").rolling_product(3, min_samples=1),
+        f3=pl.col("f").rolling_product(3),
+    )
+    # small integers and booleans are cast to Int64
+    expected = pl.DataFrame(
+        {
+            "i": [3, 3, 1, 3, 6, 4],
+            "f": [1.5, 3.0, 2.0, -1.0, -4.0, 2.0],
+            "b": [1, 0, 0, 0, 1, 1],
+            "f3": [None, None, None, None, None, -2.0],
+        },
+        schema={"i": pl.Int64, "f": pl.Float64, "b": pl.Int64, "f3": pl.Float64},
+    )
+    assert_frame_equal(result, expected)
+
+    assert pl.Series([1, 2, 3, 4, 5]).rolling_product(3).to_list() == [
+        None,
+        None,
+        6,
+        24,
+        60,
+    ]
+
+
+def test_rolling_kernels_by() -> None:
+    idx_type = pl.get_index_type()
+    df = pl.DataFrame(
+        {
+            "t": [1, 2, 3, 6, 7, 8],
+            "a": [3, 1, 1, 3, 2, 5],
+        }
+    )
+    a = pl.col("a")
+    result = df.select(
+        n_unique=a.rolling_n_unique_by("t", "3i"),
+        mode=a.rolling_mode_by("t", "3i"),
+        rank=a.rolling_rank_by("t", "3i"),
+        dense=a.rolling_rank_by("t", "3i", method="dense"),
+        arg_min=a.rolling_arg_min_by("t", "3i"),
+        arg_max=a.rolling_arg_max_by("t", "3i"),
+        first=a.rolling_first_by("t", "3i"),
+        last=a.rolling_last_by("t", "3i"),
+        product=a.cast(pl.Int32).rolling_product_by("t", "3i"),
+    )
+    expected = pl.DataFrame(
+        {
+            "n_unique": [1, 2, 2, 1, 2, 3],
+            "mode": [3, 1, 1, 3, 2, 2],
+            "rank": [1.0, 1.0, 1.5, 1.0, 1.0, 3.0],
+            "dense": [1, 1, 1, 1, 1, 3],
+            "arg_min": [0, 1, 1, 0, 1, 1],
+            "arg_max": [0, 0, 0, 0, 0, 2],
+            "first": [3, 3, 3, 3, 3, 3],
+            "last": [3, 1, 1, 3, 2, 5],
+            "product": [3, 3, 3, 3, 6, 30],
+        },
+        schema={
+            "n_unique": idx_type,
+            "mode": pl.Int64,
+            "rank": pl.Float64,
+            "dense": idx_type,
+            "arg_min": idx_type,
+            "arg_max": idx_type,
+            "first": pl.Int64,
+            "last": pl.Int64,
+            "product": pl.Int64,
+        },
+    )
+    assert_frame_equal(result, expected)
+
+    # the result is the same for unsorted input
+    shuffled = df.select(pl.all().gather([3, 0, 5, 1, 4, 2]))
+    assert_frame_equal(
+        shuffled.select(
+            "t", a.rolling_rank_by("t", "3i"), last=a.rolling_last_by("t", "3i")
+        ).sort("t"),
+        df.select("t", result["rank"].alias("a"), result["last"]),
+    )
@@ -1082,3 +1082,127 @@ def test_group_by_dynamic_single_row_22585() -> None:
         {"group": ["x"], "date": [date(2025, 1, 1)], "len": [1]}
     ).with_columns(pl.col("len").cast(pl.UInt32))
     assert_frame_equal(expected, out)
+
+
+def test_group_by_dynamic_business_days() -> None:
+    # 2024-01-05 is a Friday, weekend rows fall in the window of the Friday
+    df = pl.DataFrame(
+        {
+            "date": pl.date_range(date(2024, 1, 3), date(2024, 1, 10), eager=True),
+            "value": [1, 2, 3, 4, 5, 6, 7, 8],
+        }
+    )
+    result = df.group_by_dynamic("date", every="1bd").agg(pl.col("value").sum())
+    expected = pl.DataFrame(
+        {
+            "date": [date(2024, 1, d) for d in (3, 4, 5, 8, 9, 10)],
+            "value": [1, 2, 12, 6, 7, 8],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.group_by_dynamic("date", every="2bd").agg(pl.col("value"))
+    expected = pl.DataFrame(
+        {
+            "date": [date(2024, 1, 3), date(2024, 1, 5), date(2024, 1, 9)],
+            "value": [[1, 2], [3, 4, 5, 6], [7, 8]],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_fiscal_calendar() -> None:
+    # fiscal quarters start in October, January, April and July
+    calendar = pl.FiscalCalendar(start_month=10)
+    df = pl.DataFrame(
+        {
+            "date": [
+                date(2023, 9, 30),
+                date(2023, 10, 1),
+                date(2023, 12, 31),
+                date(2024, 1, 1),
+                date(2024, 7, 15),
+            ],
+            "sales": [10, 20, 30, 40, 50],
+        }
+    )
+    result = df.group_by_dynamic("date", every="1q", fiscal_calendar=calendar).agg(
+        pl.col("sales").sum()
+    )
+    expected = pl.DataFrame(
+        {
+            "date": [
+                date(2023, 7, 1),
+                date(2023, 10, 1),
+                date(2024, 1, 1),
+                date(2024, 7, 1),
+            ],
+            "sales": [10, 50, 40, 50],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.group_by_dynamic(
+        "date", every="1q", closed="right", fiscal_calendar=calendar
+    ).agg(pl.col("sales").sum())
+    expected = pl.DataFrame(
+        {
+            "date": [date(2023, 7, 1), date(2023, 10, 1), date(2024, 7, 1)],
+            "sales": [30, 70, 50],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.group_by_dynamic(
+        "date", every="1y", closed="both", label="right", fiscal_calendar=calendar
+    ).agg(pl.col("sales").sum())
+    expected = pl.DataFrame(
+        {"date": [date(2023, 10, 1), date(2024, 10, 1)], "sales": [30, 140]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_fiscal_calendar_retail() -> None:
+    # the periods of fiscal 2023 start on 2023-01-29 and 2023-02-26
+    retail = pl.FiscalCalendar(
+        start_month=2, week_pattern="454", end_weekday=6, label="start"
+    )
+    df = pl.DataFrame(
+        {
+            "date": pl.date_range(
+                date(2023, 1, 20), date(2023, 3, 24), "1w", eager=True
+            ),
+            "value": range(1, 11),
+        }
+    )
+    result = df.group_by_dynamic("date", every="1mo", fiscal_calendar=retail).agg(
+        pl.col("value")
+    )
+    expected = pl.DataFrame(
+        {
+            "date": [date(2023, 1, 1), date(2023, 1, 29), date(2023, 2, 26)],
+            "value": [[1, 2], [3, 4, 5, 6], [7, 8, 9, 10]],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_group_by_dynamic_fiscal_calendar_invalid() -> None:
+    calendar = pl.FiscalCalendar(start_month=10)
+    df = pl.DataFrame({"date": [date(2024, 1, 1)], "idx": [1], "value": [1]})
+    with pytest.raises(InvalidOperationError, match="expected one of '1y', '1q'"):
+        df.group_by_dynamic("date", every="2q", fiscal_calendar=calendar).agg(
+            pl.col("value").sum()
+        )
+    with pytest.raises(InvalidOperationError, match="'period' must equal 'every'"):
+        df.group_by_dynamic(
+            "date", every="1q", period="1y", fiscal_calendar=calendar
+        ).agg(pl.col("value").sum())
+    with pytest.raises(InvalidOperationError, match="'offset' must be zero"):
+        df.group_by_dynamic(
+            "date", every="1q", offset="1d", fiscal_calendar=calendar
+        ).agg(pl.col("value").sum())
+    with pytest.raises(InvalidOperationError, match="requires a Date or Datetime"):
+        df.group_by_dynamic("idx", every="1i", fiscal_calendar=calendar).agg(
+            pl.col("value").sum()
+        )
@@ -36,6 +36,50 @@ def test_corr_nan() -> None:
     assert str(df.select(pl.corr("a", "b"))[0, 0]) == "nan"
 
 
+def test_corr_matrix() -> None:
+    df = pl.DataFrame(
+        {
+            "x": [1, 2, 3, 4, 5],
+            "y": [3, 1, 2, 5, 4],
+            "z": [5, 4, 3, 2, 1],
+            "s": ["a", "b", "c", "d", "e"],
+        }
+    )
+    result = df.corr_matrix(method="kendall")
+    expected = pl.DataFrame(
+        {
+            "column": ["x", "y", "z"],
+            "x": [1.0, 0.4, -1.0],
+            "y": [0.4, 1.0, -0.4],
+            "z": [-1.0, -0.4, 1.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+    assert_frame_equal(df.lazy().corr_matrix(method="kendall").collect(), expected)
+
+    result = df.corr_matrix(["x", "y"], layout="tidy")
+    expected = pl.DataFrame(
+        {"column_1": ["x"], "column_2": ["y"], "correlation": [0.6]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_corr_matrix_pairwise_nulls() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3, None, 4], "b": [1, 2, 3, 10, 4]})
+    for method in ("pearson", "spearman", "kendall"):
+        result = df.corr_matrix(method=method)  # type: ignore[arg-type]
+        expected = pl.DataFrame(
+            {"column": ["a", "b"], "a": [1.0, 1.0], "b": [1.0, 1.0]}
+        )
+        assert_frame_equal(result, expected)
+
+
+def test_corr_matrix_too_few_columns() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [1, 2, 3]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="at least two"):
+        df.corr_matrix(["a"])
+
+
 def test_median_quantile_duration() -> None:
     df = pl.DataFrame({"A": [timedelta(days=0), timedelta(days=1)]})
 